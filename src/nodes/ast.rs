@@ -75,7 +75,10 @@ fn module(node: &mut ModuleNode, m: YangModuleStmt) {
                 let n = extension(&m.extension_stmt);
                 node.extension.push(n);
             }
-            BodyStmts::FeatureStmt(_m) => {}
+            BodyStmts::FeatureStmt(m) => {
+                let n = feature(&m.feature_stmt);
+                node.feature.push(n);
+            }
             BodyStmts::IdentityStmt(m) => {
                 let n = identity(m);
                 node.identity.push(n);
@@ -95,9 +98,18 @@ fn module(node: &mut ModuleNode, m: YangModuleStmt) {
                 let n = augment(&m.augment_stmt);
                 node.augment.push(n);
             }
-            BodyStmts::RpcStmt(_m) => {}
-            BodyStmts::NotificationStmt(_m) => {}
-            BodyStmts::DeviationStmt(_m) => {}
+            BodyStmts::RpcStmt(m) => {
+                let n = rpc(&m.rpc_stmt);
+                node.rpc.push(n);
+            }
+            BodyStmts::NotificationStmt(m) => {
+                let n = notification(&m.notification_stmt);
+                node.notification.push(n);
+            }
+            BodyStmts::DeviationStmt(m) => {
+                let n = deviation(&m.deviation_stmt);
+                node.deviation.push(n);
+            }
             BodyStmts::UnknownStmt(m) => {
                 let n = unknown(&m.unknown_stmt);
                 node.unknown.push(n);
@@ -152,7 +164,10 @@ fn submodule(node: &mut SubmoduleNode, m: YangSubmoduleStmt) {
     for m in m.submodule_stmt.submodule_stmt_list3.iter() {
         match &*m.body_stmts {
             BodyStmts::ExtensionStmt(_m) => {}
-            BodyStmts::FeatureStmt(_m) => {}
+            BodyStmts::FeatureStmt(m) => {
+                let n = feature(&m.feature_stmt);
+                node.feature.push(n);
+            }
             BodyStmts::IdentityStmt(m) => {
                 let n = identity(m);
                 node.identity.push(n);
@@ -172,9 +187,18 @@ fn submodule(node: &mut SubmoduleNode, m: YangSubmoduleStmt) {
                 let n = augment(&m.augment_stmt);
                 node.augment.push(n);
             }
-            BodyStmts::RpcStmt(_m) => {}
-            BodyStmts::NotificationStmt(_m) => {}
-            BodyStmts::DeviationStmt(_m) => {}
+            BodyStmts::RpcStmt(m) => {
+                let n = rpc(&m.rpc_stmt);
+                node.rpc.push(n);
+            }
+            BodyStmts::NotificationStmt(m) => {
+                let n = notification(&m.notification_stmt);
+                node.notification.push(n);
+            }
+            BodyStmts::DeviationStmt(m) => {
+                let n = deviation(&m.deviation_stmt);
+                node.deviation.push(n);
+            }
             BodyStmts::UnknownStmt(m) => {
                 let n = unknown(&m.unknown_stmt);
                 node.unknown.push(n);
@@ -274,7 +298,9 @@ fn augment(m: &AugmentStmt) -> AugmentNode {
             }
             // notification is parsed but not yet modeled; see
             // AugmentNode's doc comment for the reason.
-            AugmentStmtListGroup::NotificationStmt(_) => {}
+            AugmentStmtListGroup::NotificationStmt(m) => {
+                node.notification.push(notification(&m.notification_stmt));
+            }
         }
     }
     node
@@ -330,34 +356,44 @@ fn datadef(node: &mut DatadefNode, m: &DataDefStmt) {
     match m {
         DataDefStmt::ContainerStmt(m) => {
             let n = container(&m.container_stmt);
+            node.order
+                .push((DatadefKind::Container, node.container.len()));
             node.container.push(n);
         }
         DataDefStmt::ListStmt(m) => {
             let n = list(&m.list_stmt);
+            node.order.push((DatadefKind::List, node.list.len()));
             node.list.push(n);
         }
         DataDefStmt::LeafStmt(m) => {
             let n = leaf(&m.leaf_stmt);
+            node.order.push((DatadefKind::Leaf, node.leaf.len()));
             node.leaf.push(n);
         }
         DataDefStmt::AnydataStmt(m) => {
             let n = anydata(&m.anydata_stmt);
+            node.order.push((DatadefKind::Anydata, node.anydata.len()));
             node.anydata.push(n);
         }
         DataDefStmt::AnyxmlStmt(m) => {
             let n = anyxml(&m.anyxml_stmt);
+            node.order.push((DatadefKind::Anyxml, node.anyxml.len()));
             node.anyxml.push(n);
         }
         DataDefStmt::ChoiceStmt(m) => {
             let n = choice(&m.choice_stmt);
+            node.order.push((DatadefKind::Choice, node.choice.len()));
             node.choice.push(n);
         }
         DataDefStmt::LeafListStmt(m) => {
             let n = leaf_list(&m.leaf_list_stmt);
+            node.order
+                .push((DatadefKind::LeafList, node.leaf_list.len()));
             node.leaf_list.push(n);
         }
         DataDefStmt::UsesStmt(m) => {
             let n = uses(&m.uses_stmt);
+            node.order.push((DatadefKind::Uses, node.uses.len()));
             node.uses.push(n);
         }
     }
@@ -401,7 +437,9 @@ fn container(m: &ContainerStmt) -> ContainerNode {
                     let n = when(&m.when_stmt);
                     node.when = Some(n);
                 }
-                ContainerStmtListGroup::IfFeatureStmt(_m) => {}
+                ContainerStmtListGroup::IfFeatureStmt(m) => {
+                    node.if_feature.push(if_feature(&m.if_feature_stmt));
+                }
                 ContainerStmtListGroup::MustStmt(m) => {
                     let n = must(&m.must_stmt);
                     node.must.push(n);
@@ -424,6 +462,14 @@ fn container(m: &ContainerStmt) -> ContainerNode {
                 ContainerStmtListGroup::ReferenceStmt(m) => {
                     node.reference = Some(ystring(&m.reference_stmt.ystring));
                 }
+                ContainerStmtListGroup::TypedefStmt(m) => {
+                    let n = typedef(&m.typedef_stmt);
+                    node.typedef.push(n);
+                }
+                ContainerStmtListGroup::GroupingStmt(m) => {
+                    let n = grouping(&m.grouping_stmt);
+                    node.grouping.push(n);
+                }
                 ContainerStmtListGroup::DataDefStmt(m) => {
                     datadef(&mut node.d, &m.data_def_stmt);
                 }
@@ -431,7 +477,10 @@ fn container(m: &ContainerStmt) -> ContainerNode {
                     let n = action(&m.action_stmt);
                     node.action.push(n);
                 }
-                ContainerStmtListGroup::NotificationStmt(_m) => {}
+                ContainerStmtListGroup::NotificationStmt(m) => {
+                    let n = notification(&m.notification_stmt);
+                    node.notification.push(n);
+                }
                 ContainerStmtListGroup::UnknownStmt(m) => {
                     let n = unknown(&m.unknown_stmt);
                     node.unknown.push(n);
@@ -455,17 +504,23 @@ fn leaf(m: &LeafStmt) -> LeafNode {
                 let n = status(&m.status_stmt);
                 node.status = Some(n);
             }
-            LeafStmtListGroup::IfFeatureStmt(_m) => {}
+            LeafStmtListGroup::IfFeatureStmt(m) => {
+                node.if_feature.push(if_feature(&m.if_feature_stmt));
+            }
             LeafStmtListGroup::TypeStmt(m) => {
                 let n = type_stmt(&m.type_stmt);
                 node.type_stmt = Some(n);
             }
-            LeafStmtListGroup::UnitsStmt(_m) => {}
+            LeafStmtListGroup::UnitsStmt(m) => {
+                node.units = Some(ystring(&m.units_stmt.ystring));
+            }
             LeafStmtListGroup::MustStmt(m) => {
                 let n = must(&m.must_stmt);
                 node.must.push(n);
             }
-            LeafStmtListGroup::DefaultStmt(_m) => {}
+            LeafStmtListGroup::DefaultStmt(m) => {
+                node.default = Some(default_stmt(&m.default_stmt));
+            }
             LeafStmtListGroup::ConfigStmt(m) => {
                 let n = config(&m.config_stmt);
                 node.config = Some(n);
@@ -519,7 +574,9 @@ fn list(m: &ListStmt) -> ListNode {
                 let n = when(&m.when_stmt);
                 node.when = Some(n);
             }
-            ListStmtListGroup::IfFeatureStmt(_m) => {}
+            ListStmtListGroup::IfFeatureStmt(m) => {
+                node.if_feature.push(if_feature(&m.if_feature_stmt));
+            }
             ListStmtListGroup::MustStmt(m) => {
                 let n = must(&m.must_stmt);
                 node.must.push(n)
@@ -548,11 +605,25 @@ fn list(m: &ListStmt) -> ListNode {
             ListStmtListGroup::ReferenceStmt(m) => {
                 node.reference = Some(ystring(&m.reference_stmt.ystring));
             }
+            ListStmtListGroup::TypedefStmt(m) => {
+                let n = typedef(&m.typedef_stmt);
+                node.typedef.push(n);
+            }
+            ListStmtListGroup::GroupingStmt(m) => {
+                let n = grouping(&m.grouping_stmt);
+                node.grouping.push(n);
+            }
             ListStmtListGroup::DataDefStmt(m) => {
                 datadef(&mut node.d, &m.data_def_stmt);
             }
-            ListStmtListGroup::ActionStmt(_m) => {}
-            ListStmtListGroup::NotificationStmt(_m) => {}
+            ListStmtListGroup::ActionStmt(m) => {
+                let n = action(&m.action_stmt);
+                node.action.push(n);
+            }
+            ListStmtListGroup::NotificationStmt(m) => {
+                let n = notification(&m.notification_stmt);
+                node.notification.push(n);
+            }
             ListStmtListGroup::UnknownStmt(m) => {
                 let n = unknown(&m.unknown_stmt);
                 node.unknown.push(n);
@@ -571,12 +642,16 @@ fn leaf_list(m: &LeafListStmt) -> LeafListNode {
                 let n = when(&m.when_stmt);
                 node.when = Some(n);
             }
-            LeafListStmtListGroup::IfFeatureStmt(_m) => {}
+            LeafListStmtListGroup::IfFeatureStmt(m) => {
+                node.if_feature.push(if_feature(&m.if_feature_stmt));
+            }
             LeafListStmtListGroup::TypeStmt(m) => {
                 let n = type_stmt(&m.type_stmt);
                 node.type_stmt = Some(n);
             }
-            LeafListStmtListGroup::UnitsStmt(_m) => {}
+            LeafListStmtListGroup::UnitsStmt(m) => {
+                node.units = Some(ystring(&m.units_stmt.ystring));
+            }
             LeafListStmtListGroup::MustStmt(_m) => {}
             LeafListStmtListGroup::DefaultStmt(_m) => {}
             LeafListStmtListGroup::ConfigStmt(m) => {
@@ -628,13 +703,25 @@ fn grouping(m: &GroupingStmt) -> GroupingNode {
             GroupingStmtListGroup::ReferenceStmt(m) => {
                 node.reference = Some(ystring(&m.reference_stmt.ystring));
             }
-            GroupingStmtListGroup::TypedefStmt(_m) => {}
-            GroupingStmtListGroup::GroupingStmt(_m) => {}
+            GroupingStmtListGroup::TypedefStmt(m) => {
+                let n = typedef(&m.typedef_stmt);
+                node.typedef.push(n);
+            }
+            GroupingStmtListGroup::GroupingStmt(m) => {
+                let n = grouping(&m.grouping_stmt);
+                node.grouping.push(n);
+            }
             GroupingStmtListGroup::DataDefStmt(m) => {
                 datadef(&mut node.d, &m.data_def_stmt);
             }
-            GroupingStmtListGroup::ActionStmt(_m) => {}
-            GroupingStmtListGroup::NotificationStmt(_m) => {}
+            GroupingStmtListGroup::ActionStmt(m) => {
+                let n = action(&m.action_stmt);
+                node.action.push(n);
+            }
+            GroupingStmtListGroup::NotificationStmt(m) => {
+                let n = notification(&m.notification_stmt);
+                node.notification.push(n);
+            }
             GroupingStmtListGroup::UnknownStmt(_m) => {}
         }
     }
@@ -718,6 +805,9 @@ fn identity(m: &BodyStmtsIdentityStmt) -> IdentityNode {
                 let base = identifier_ref_arg_str(&m.base_stmt.identifier_ref_arg_str);
                 node.base.push(base);
             }
+            IdentityStmtListGroup::StatusStmt(m) => {
+                node.status = Some(status(&m.status_stmt));
+            }
             IdentityStmtListGroup::DescriptionStmt(m) => {
                 node.description = Some(ystring(&m.description_stmt.ystring));
             }
@@ -731,8 +821,8 @@ fn identity(m: &BodyStmtsIdentityStmt) -> IdentityNode {
 
 fn namespace(m: &ModuleHeaderStmtsNamespaceStmt) -> String {
     match &*m.namespace_stmt.uri_str {
-        UriStr::UriArg(m) => m.uri_arg.uri_arg.to_string(),
-        UriStr::DoubleQuotationUriArgDoubleQuotation(m) => m.uri_arg.uri_arg.to_string(),
+        UriStr::UriArg(m) => m.uri_arg.uri_arg.text().to_string(),
+        UriStr::DoubleQuotationUriArgDoubleQuotation(m) => m.uri_arg.uri_arg.text().to_string(),
     }
 }
 
@@ -863,25 +953,31 @@ fn range_boundary<T: FromStr>(m: &RangeBoundary) -> Result<RangeVal<T>, ()> {
                 Err(())
             }
         }
+        // A decimal64 boundary; decimal64 ranges are only kept as text.
+        RangeBoundary::MinusQuestLBracket0Minus9RBracketPlusDotLBracket0Minus9RBracketPlus(_) => {
+            Err(())
+        }
     }
 }
 
-fn range_part<T: FromStr>(m: &RangePart) -> Range<T> {
-    let start = range_boundary(&m.range_boundary).unwrap();
+fn range_part<T: FromStr>(m: &RangePart) -> Result<Range<T>, ()> {
+    let start = range_boundary(&m.range_boundary)?;
     if let Some(end) = &m.range_part_opt {
-        let end = range_boundary(&end.range_boundary).unwrap();
-        Range {
+        let end = range_boundary(&end.range_boundary)?;
+        Ok(Range {
             start,
             end: Some(end),
-        }
+        })
     } else {
-        Range { start, end: None }
+        Ok(Range { start, end: None })
     }
 }
 
+// A part whose boundaries are not values of `T` is left out.
 fn range_arg_type<T: FromStr>(v: &mut Vec<Range<T>>, m: &RangeArg) {
-    let range = range_part::<T>(&m.range_part);
-    v.push(range);
+    if let Ok(range) = range_part::<T>(&m.range_part) {
+        v.push(range);
+    }
     if let Some(m) = &m.range_arg_opt {
         range_arg_type(v, &m.range_arg);
     }
@@ -943,6 +1039,43 @@ fn range(m: &RangeStmt, kind: YangType) -> RangeNode {
     }
 }
 
+/// The argument of a `range` statement, rebuilt from its parts.
+fn range_text(m: &RangeStmt) -> String {
+    fn boundary<'a>(m: &'a RangeBoundary<'a>) -> &'a str {
+        match m {
+            RangeBoundary::Min(_) => "min",
+            RangeBoundary::Max(_) => "max",
+            RangeBoundary::MinusQuestLBracket0Minus9RBracketPlus(m) => {
+                m.minus_quest_l_bracket0_minus9_r_bracket_plus.text()
+            }
+            RangeBoundary::MinusQuestLBracket0Minus9RBracketPlusDotLBracket0Minus9RBracketPlus(
+                m,
+            ) => m
+                .minus_quest_l_bracket0_minus9_r_bracket_plus_dot_l_bracket0_minus9_r_bracket_plus
+                .text(),
+        }
+    }
+    let mut arg = match &*m.range_arg_str {
+        RangeArgStr::RangeArg(m) => &m.range_arg,
+        RangeArgStr::DoubleQuotationRangeArgDoubleQuotation(m) => &m.range_arg,
+    };
+    let mut parts = Vec::new();
+    loop {
+        let part = &arg.range_part;
+        let mut text = boundary(&part.range_boundary).to_string();
+        if let Some(end) = &part.range_part_opt {
+            text.push_str("..");
+            text.push_str(boundary(&end.range_boundary));
+        }
+        parts.push(text);
+        match &arg.range_arg_opt {
+            Some(next) => arg = &next.range_arg,
+            None => break,
+        }
+    }
+    parts.join("|")
+}
+
 fn length(m: &LengthStmt) -> RangeNode {
     match &*m.range_arg_str {
         RangeArgStr::RangeArg(m) => range_arg(&m.range_arg, YangType::Uint64),
@@ -962,6 +1095,22 @@ fn enum_stmt(m: &EnumStmt) -> EnumNode {
     EnumNode::new(name)
 }
 
+fn default_stmt(m: &DefaultStmt) -> String {
+    match &*m.default_stmt_suffix {
+        // The unquoted token runs from just after the keyword, so it
+        // carries the separating whitespace.
+        DefaultStmtSuffix::AsciiNoSemicolonSemicolon(m) => m
+            .ascii_no_semicolon
+            .ascii_no_semicolon
+            .text()
+            .trim()
+            .to_string(),
+        DefaultStmtSuffix::DoubleQuotationAsciiNoSemicolonDoubleQuotationSemicolon(m) => {
+            m.ascii_no_semicolon.ascii_no_semicolon.text().to_string()
+        }
+    }
+}
+
 fn base_stmt(m: &BaseStmt) -> String {
     identifier_ref_arg_str(&m.identifier_ref_arg_str)
 }
@@ -974,7 +1123,13 @@ fn type_stmt(m: &TypeStmt) -> TypeNode {
     if let TypeStmtSuffix::LBraceTypeStmtListRBrace(m) = &*m.type_stmt_suffix {
         for m in m.type_stmt_list.iter() {
             match &*m.type_stmt_list_group {
-                TypeStmtListGroup::FractionDigitsStmt(_m) => {}
+                TypeStmtListGroup::FractionDigitsStmt(m) => {
+                    let digits = &m
+                        .fraction_digits_stmt
+                        .fraction_digits_arg
+                        .fraction_digits_arg;
+                    node.fraction_digits = digits.text().parse().ok();
+                }
                 TypeStmtListGroup::EnumStmt(m) => {
                     let n = enum_stmt(&m.enum_stmt);
                     node.enum_stmt.push(n);
@@ -990,15 +1145,21 @@ fn type_stmt(m: &TypeStmt) -> TypeNode {
                 }
                 TypeStmtListGroup::StringRestrictions(m) => match &*m.string_restrictions {
                     StringRestrictions::PatternStmt(p) => {
-                        node.pattern = Some(ystring(&p.pattern_stmt.ystring));
+                        let pattern = ystring(&p.pattern_stmt.ystring);
+                        node.patterns.push(pattern.clone());
+                        node.pattern = Some(pattern);
                     }
                     StringRestrictions::LengthStmt(l) => {
                         node.length = Some(length(&l.length_stmt));
                     }
                 },
                 TypeStmtListGroup::RangeStmt(m) => {
-                    let n = range(&m.range_stmt, kind);
-                    node.range = Some(n);
+                    if matches!(kind, YangType::Path | YangType::Decimal64) {
+                        node.range_arg = Some(range_text(&m.range_stmt));
+                    } else {
+                        let n = range(&m.range_stmt, kind);
+                        node.range = Some(n);
+                    }
                 }
                 TypeStmtListGroup::BitStmt(_m) => {}
                 TypeStmtListGroup::TypeStmt(m) => {
@@ -1011,6 +1172,103 @@ fn type_stmt(m: &TypeStmt) -> TypeNode {
     node
 }
 
+fn feature(m: &FeatureStmt) -> FeatureNode {
+    let name = identifier_arg_str(&m.identifier_arg_str);
+    let mut node = FeatureNode::new(name);
+
+    if let FeatureStmtSuffix::LBraceFeatureStmtListRBrace(m) = &*m.feature_stmt_suffix {
+        for m in m.feature_stmt_list.iter() {
+            match &*m.feature_stmt_list_group {
+                FeatureStmtListGroup::IfFeatureStmt(m) => {
+                    node.if_feature.push(if_feature(&m.if_feature_stmt));
+                }
+                FeatureStmtListGroup::StatusStmt(m) => {
+                    node.status = Some(status(&m.status_stmt));
+                }
+                FeatureStmtListGroup::DescriptionStmt(m) => {
+                    node.description = Some(ystring(&m.description_stmt.ystring));
+                }
+                FeatureStmtListGroup::ReferenceStmt(m) => {
+                    node.reference = Some(ystring(&m.reference_stmt.ystring));
+                }
+            }
+        }
+    }
+
+    node
+}
+
+fn deviation(m: &DeviationStmt) -> DeviationNode {
+    let target = ystring(&m.augment_arg_str.ystring);
+    let mut node = DeviationNode::new(target);
+
+    if let DeviationStmtSuffix::LBraceDeviationStmtListRBrace(m) = &*m.deviation_stmt_suffix {
+        for m in m.deviation_stmt_list.iter() {
+            match &*m.deviation_stmt_list_group {
+                DeviationStmtListGroup::DescriptionStmt(m) => {
+                    node.description = Some(ystring(&m.description_stmt.ystring));
+                }
+                DeviationStmtListGroup::ReferenceStmt(m) => {
+                    node.reference = Some(ystring(&m.reference_stmt.ystring));
+                }
+                DeviationStmtListGroup::DeviateStmt(m) => {
+                    node.deviate.push(deviate(&m.deviate_stmt));
+                }
+            }
+        }
+    }
+
+    node
+}
+
+fn deviate(m: &DeviateStmt) -> DeviateNode {
+    let arg = match &*m.deviate_arg_str {
+        DeviateArgStr::DeviateArg(m) => &m.deviate_arg,
+        DeviateArgStr::DoubleQuotationDeviateArgDoubleQuotation(m) => &m.deviate_arg,
+    };
+    let kind = match arg.deviate_arg.text() {
+        "add" => DeviateKind::Add,
+        "replace" => DeviateKind::Replace,
+        "delete" => DeviateKind::Delete,
+        _ => DeviateKind::NotSupported,
+    };
+    let mut node = DeviateNode::new(kind);
+
+    if let DeviateStmtSuffix::LBraceDeviateStmtListRBrace(m) = &*m.deviate_stmt_suffix {
+        for m in m.deviate_stmt_list.iter() {
+            match &*m.deviate_stmt_list_group {
+                DeviateStmtListGroup::UnitsStmt(m) => {
+                    node.units = Some(ystring(&m.units_stmt.ystring));
+                }
+                DeviateStmtListGroup::MustStmt(m) => {
+                    node.must.push(must(&m.must_stmt));
+                }
+                DeviateStmtListGroup::UnknownStmt(_m) => {}
+                DeviateStmtListGroup::DefaultStmt(m) => {
+                    node.default.push(default_stmt(&m.default_stmt));
+                }
+                DeviateStmtListGroup::ConfigStmt(m) => {
+                    node.config = Some(config(&m.config_stmt));
+                }
+                DeviateStmtListGroup::MandatoryStmt(m) => {
+                    node.mandatory = Some(mandatory(&m.mandatory_stmt));
+                }
+                DeviateStmtListGroup::MinElementsStmt(m) => {
+                    node.min_elements = Some(min_elements(&m.min_elements_stmt));
+                }
+                DeviateStmtListGroup::MaxElementsStmt(m) => {
+                    node.max_elements = Some(max_elements(&m.max_elements_stmt));
+                }
+                DeviateStmtListGroup::TypeStmt(m) => {
+                    node.type_stmt = Some(type_stmt(&m.type_stmt));
+                }
+            }
+        }
+    }
+
+    node
+}
+
 fn typedef(m: &TypedefStmt) -> TypedefNode {
     let name = identifier_arg_str(&m.identifier_arg_str);
     let mut node = TypedefNode::new(name);
@@ -1021,8 +1279,12 @@ fn typedef(m: &TypedefStmt) -> TypedefNode {
                 let n = type_stmt(&m.type_stmt);
                 node.type_node = Some(n);
             }
-            TypedefStmtListGroup::UnitsStmt(_m) => {}
-            TypedefStmtListGroup::DefaultStmt(_m) => {}
+            TypedefStmtListGroup::UnitsStmt(m) => {
+                node.units = Some(ystring(&m.units_stmt.ystring));
+            }
+            TypedefStmtListGroup::DefaultStmt(m) => {
+                node.default = Some(default_stmt(&m.default_stmt));
+            }
             TypedefStmtListGroup::StatusStmt(m) => {
                 let n = status(&m.status_stmt);
                 node.status = Some(n);
@@ -1041,12 +1303,74 @@ fn typedef(m: &TypedefStmt) -> TypedefNode {
 
 fn anydata(m: &AnydataStmt) -> AnydataNode {
     let name = identifier_arg_str(&m.identifier_arg_str);
-    AnydataNode::new(name)
+    let mut node = AnydataNode::new(name);
+    if let AnydataStmtSuffix::LBraceAnydataStmtListRBrace(m) = &*m.anydata_stmt_suffix {
+        for m in m.anydata_stmt_list.iter() {
+            match &*m.anydata_stmt_list_group {
+                AnydataStmtListGroup::WhenStmt(m) => {
+                    node.when = Some(when(&m.when_stmt));
+                }
+                AnydataStmtListGroup::IfFeatureStmt(m) => {
+                    node.if_feature.push(if_feature(&m.if_feature_stmt));
+                }
+                AnydataStmtListGroup::MustStmt(m) => {
+                    node.must.push(must(&m.must_stmt));
+                }
+                AnydataStmtListGroup::ConfigStmt(m) => {
+                    node.config = Some(config(&m.config_stmt));
+                }
+                AnydataStmtListGroup::MandatoryStmt(m) => {
+                    node.mandatory = Some(mandatory(&m.mandatory_stmt));
+                }
+                AnydataStmtListGroup::StatusStmt(m) => {
+                    node.status = Some(status(&m.status_stmt));
+                }
+                AnydataStmtListGroup::DescriptionStmt(m) => {
+                    node.description = Some(ystring(&m.description_stmt.ystring));
+                }
+                AnydataStmtListGroup::ReferenceStmt(m) => {
+                    node.reference = Some(ystring(&m.reference_stmt.ystring));
+                }
+            }
+        }
+    }
+    node
 }
 
 fn anyxml(m: &AnyxmlStmt) -> AnyxmlNode {
     let name = identifier_arg_str(&m.identifier_arg_str);
-    AnyxmlNode::new(name)
+    let mut node = AnyxmlNode::new(name);
+    if let AnyxmlStmtSuffix::LBraceAnyxmlStmtListRBrace(m) = &*m.anyxml_stmt_suffix {
+        for m in m.anyxml_stmt_list.iter() {
+            match &*m.anyxml_stmt_list_group {
+                AnyxmlStmtListGroup::WhenStmt(m) => {
+                    node.when = Some(when(&m.when_stmt));
+                }
+                AnyxmlStmtListGroup::IfFeatureStmt(m) => {
+                    node.if_feature.push(if_feature(&m.if_feature_stmt));
+                }
+                AnyxmlStmtListGroup::MustStmt(m) => {
+                    node.must.push(must(&m.must_stmt));
+                }
+                AnyxmlStmtListGroup::ConfigStmt(m) => {
+                    node.config = Some(config(&m.config_stmt));
+                }
+                AnyxmlStmtListGroup::MandatoryStmt(m) => {
+                    node.mandatory = Some(mandatory(&m.mandatory_stmt));
+                }
+                AnyxmlStmtListGroup::StatusStmt(m) => {
+                    node.status = Some(status(&m.status_stmt));
+                }
+                AnyxmlStmtListGroup::DescriptionStmt(m) => {
+                    node.description = Some(ystring(&m.description_stmt.ystring));
+                }
+                AnyxmlStmtListGroup::ReferenceStmt(m) => {
+                    node.reference = Some(ystring(&m.reference_stmt.ystring));
+                }
+            }
+        }
+    }
+    node
 }
 
 fn choice(m: &ChoiceStmt) -> ChoiceNode {
@@ -1059,8 +1383,12 @@ fn choice(m: &ChoiceStmt) -> ChoiceNode {
                     let n = when(&m.when_stmt);
                     node.when = Some(n);
                 }
-                ChoiceStmtListGroup::IfFeatureStmt(_m) => {}
-                ChoiceStmtListGroup::DefaultStmt(_m) => {}
+                ChoiceStmtListGroup::IfFeatureStmt(m) => {
+                    node.if_feature.push(if_feature(&m.if_feature_stmt));
+                }
+                ChoiceStmtListGroup::DefaultStmt(m) => {
+                    node.default = Some(default_stmt(&m.default_stmt));
+                }
                 ChoiceStmtListGroup::ConfigStmt(m) => {
                     let n = config(&m.config_stmt);
                     node.config = Some(n);
@@ -1142,7 +1470,12 @@ fn case(m: &CaseStmt) -> CaseNode {
                     let n = when(&m.when_stmt);
                     node.when = Some(n);
                 }
-                CaseStmtListGroup::IfFeatureStmt(_m) => {}
+                CaseStmtListGroup::IfFeatureStmt(m) => {
+                    node.if_feature.push(if_feature(&m.if_feature_stmt));
+                }
+                CaseStmtListGroup::StatusStmt(m) => {
+                    node.status = Some(status(&m.status_stmt));
+                }
                 CaseStmtListGroup::DataDefStmt(m) => {
                     datadef(&mut node.d, &m.data_def_stmt);
                 }
@@ -1208,7 +1541,9 @@ fn uses(m: &UsesStmt) -> UsesNode {
                     let n = when(&m.when_stmt);
                     node.when = Some(n);
                 }
-                UsesStmtListGroup::IfFeatureStmt(_m) => {}
+                UsesStmtListGroup::IfFeatureStmt(m) => {
+                    node.if_feature.push(if_feature(&m.if_feature_stmt));
+                }
                 UsesStmtListGroup::StatusStmt(m) => {
                     let n = status(&m.status_stmt);
                     node.status = Some(n);
@@ -1325,7 +1660,9 @@ fn action(m: &ActionStmt) -> ActionNode {
     if let ActionStmtSuffix::LBraceActionStmtListRBrace(m) = &*m.action_stmt_suffix {
         for m in m.action_stmt_list.iter() {
             match &*m.action_stmt_list_group {
-                ActionStmtListGroup::IfFeatureStmt(_m) => {}
+                ActionStmtListGroup::IfFeatureStmt(m) => {
+                    node.if_feature.push(if_feature(&m.if_feature_stmt));
+                }
                 ActionStmtListGroup::StatusStmt(m) => {
                     let n = status(&m.status_stmt);
                     node.status = Some(n);
@@ -1336,6 +1673,14 @@ fn action(m: &ActionStmt) -> ActionNode {
                 ActionStmtListGroup::ReferenceStmt(m) => {
                     node.reference = Some(ystring(&m.reference_stmt.ystring));
                 }
+                ActionStmtListGroup::TypedefStmt(m) => {
+                    let n = typedef(&m.typedef_stmt);
+                    node.typedef.push(n);
+                }
+                ActionStmtListGroup::GroupingStmt(m) => {
+                    let n = grouping(&m.grouping_stmt);
+                    node.grouping.push(n);
+                }
                 ActionStmtListGroup::InputStmt(m) => {
                     let n = input(&m.input_stmt);
                     node.input = Some(n);
@@ -1351,10 +1696,104 @@ fn action(m: &ActionStmt) -> ActionNode {
     node
 }
 
+// An `rpc` has the same substatements as an `action`, so it is kept
+// as an ActionNode.
+fn rpc(m: &RpcStmt) -> ActionNode {
+    let name = identifier_arg_str(&m.identifier_arg_str);
+    let mut node = ActionNode::new(name);
+
+    if let RpcStmtSuffix::LBraceRpcStmtListRBrace(m) = &*m.rpc_stmt_suffix {
+        for m in m.rpc_stmt_list.iter() {
+            match &*m.rpc_stmt_list_group {
+                RpcStmtListGroup::IfFeatureStmt(m) => {
+                    node.if_feature.push(if_feature(&m.if_feature_stmt));
+                }
+                RpcStmtListGroup::StatusStmt(m) => {
+                    let n = status(&m.status_stmt);
+                    node.status = Some(n);
+                }
+                RpcStmtListGroup::DescriptionStmt(m) => {
+                    node.description = Some(ystring(&m.description_stmt.ystring));
+                }
+                RpcStmtListGroup::ReferenceStmt(m) => {
+                    node.reference = Some(ystring(&m.reference_stmt.ystring));
+                }
+                RpcStmtListGroup::TypedefStmt(m) => {
+                    let n = typedef(&m.typedef_stmt);
+                    node.typedef.push(n);
+                }
+                RpcStmtListGroup::GroupingStmt(m) => {
+                    let n = grouping(&m.grouping_stmt);
+                    node.grouping.push(n);
+                }
+                RpcStmtListGroup::InputStmt(m) => {
+                    let n = input(&m.input_stmt);
+                    node.input = Some(n);
+                }
+                RpcStmtListGroup::OutputStmt(m) => {
+                    let n = output(&m.output_stmt);
+                    node.output = Some(n);
+                }
+            }
+        }
+    }
+
+    node
+}
+
+fn notification(m: &NotificationStmt) -> NotificationNode {
+    let name = identifier_arg_str(&m.identifier_arg_str);
+    let mut node = NotificationNode::new(name);
+
+    for m in m.notification_stmt_list.iter() {
+        match &*m.notification_stmt_list_group {
+            NotificationStmtListGroup::IfFeatureStmt(m) => {
+                node.if_feature.push(if_feature(&m.if_feature_stmt));
+            }
+            NotificationStmtListGroup::MustStmt(_m) => {}
+            NotificationStmtListGroup::StatusStmt(m) => {
+                let n = status(&m.status_stmt);
+                node.status = Some(n);
+            }
+            NotificationStmtListGroup::DescriptionStmt(m) => {
+                node.description = Some(ystring(&m.description_stmt.ystring));
+            }
+            NotificationStmtListGroup::ReferenceStmt(m) => {
+                node.reference = Some(ystring(&m.reference_stmt.ystring));
+            }
+            NotificationStmtListGroup::TypedefStmt(m) => {
+                let n = typedef(&m.typedef_stmt);
+                node.typedef.push(n);
+            }
+            NotificationStmtListGroup::GroupingStmt(m) => {
+                let n = grouping(&m.grouping_stmt);
+                node.grouping.push(n);
+            }
+            NotificationStmtListGroup::DataDefStmt(m) => {
+                datadef(&mut node.d, &m.data_def_stmt);
+            }
+        }
+    }
+
+    node
+}
+
 fn input(m: &InputStmt) -> InputNode {
     let mut node = InputNode::new();
     for m in m.input_stmt_list.iter() {
-        datadef(&mut node.d, &m.data_def_stmt);
+        match &*m.input_stmt_list_group {
+            InputStmtListGroup::TypedefStmt(m) => {
+                let n = typedef(&m.typedef_stmt);
+                node.typedef.push(n);
+            }
+            InputStmtListGroup::GroupingStmt(m) => {
+                let n = grouping(&m.grouping_stmt);
+                node.grouping.push(n);
+            }
+            InputStmtListGroup::DataDefStmt(m) => {
+                datadef(&mut node.d, &m.data_def_stmt);
+            }
+        }
     }
     node
 }
@@ -1362,7 +1801,19 @@ fn input(m: &InputStmt) -> InputNode {
 fn output(m: &OutputStmt) -> OutputNode {
     let mut node = OutputNode::new();
     for m in m.output_stmt_list.iter() {
-        datadef(&mut node.d, &m.data_def_stmt);
+        match &*m.output_stmt_list_group {
+            OutputStmtListGroup::TypedefStmt(m) => {
+                let n = typedef(&m.typedef_stmt);
+                node.typedef.push(n);
+            }
+            OutputStmtListGroup::GroupingStmt(m) => {
+                let n = grouping(&m.grouping_stmt);
+                node.grouping.push(n);
+            }
+            OutputStmtListGroup::DataDefStmt(m) => {
+                datadef(&mut node.d, &m.data_def_stmt);
+            }
+        }
     }
     node
 }
@@ -1,5 +1,5 @@
 use crate::RangeNode;
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::HashMap, fmt, fmt::Debug};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum Node {
@@ -25,7 +25,11 @@ pub struct ModuleNode {
     pub typedef: Vec<TypedefNode>,
     pub extension: Vec<ExtensionNode>,
     pub grouping: Vec<GroupingNode>,
+    pub feature: Vec<FeatureNode>,
+    pub deviation: Vec<DeviationNode>,
     pub augment: Vec<AugmentNode>,
+    pub rpc: Vec<ActionNode>,
+    pub notification: Vec<NotificationNode>,
     pub unknown: Vec<UnknownNode>,
     pub identities: HashMap<String, Vec<String>>,
 }
@@ -37,6 +41,12 @@ impl ModuleNode {
             ..Default::default()
         }
     }
+
+    /// The most recent of the `revision` statements, which is the
+    /// revision of the module itself.
+    pub fn latest_revision(&self) -> Option<&str> {
+        self.revision.iter().map(|r| r.name.as_str()).max()
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -55,7 +65,11 @@ pub struct SubmoduleNode {
     pub identity: Vec<IdentityNode>,
     pub typedef: Vec<TypedefNode>,
     pub grouping: Vec<GroupingNode>,
+    pub feature: Vec<FeatureNode>,
+    pub deviation: Vec<DeviationNode>,
     pub augment: Vec<AugmentNode>,
+    pub rpc: Vec<ActionNode>,
+    pub notification: Vec<NotificationNode>,
     pub unknown: Vec<UnknownNode>,
     pub identities: HashMap<String, Vec<String>>,
 }
@@ -67,6 +81,12 @@ impl SubmoduleNode {
             ..Default::default()
         }
     }
+
+    /// The most recent of the `revision` statements, which is the
+    /// revision of the module itself.
+    pub fn latest_revision(&self) -> Option<&str> {
+        self.revision.iter().map(|r| r.name.as_str()).max()
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -148,7 +168,7 @@ pub struct IdentityNode {
     pub base: Vec<String>,
     pub description: Option<String>,
     pub reference: Option<String>,
-    pub status: Option<String>,
+    pub status: Option<StatusNode>,
 }
 
 impl IdentityNode {
@@ -170,6 +190,52 @@ pub struct DatadefNode {
     pub anydata: Vec<AnydataNode>,
     pub anyxml: Vec<AnyxmlNode>,
     pub uses: Vec<UsesNode>,
+    /// The statements in the order they were written, as a kind and an
+    /// index into that kind's vector. The per-kind vectors alone lose
+    /// document order, which RFC 7950 XML encoding and RPC input need.
+    pub order: Vec<(DatadefKind, usize)>,
+}
+
+/// Which vector of a [`DatadefNode`] a statement is in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DatadefKind {
+    Container,
+    Leaf,
+    LeafList,
+    List,
+    Choice,
+    Anydata,
+    Anyxml,
+    Uses,
+}
+
+/// A data definition statement of a [`DatadefNode`].
+#[derive(Debug, Clone, Copy)]
+pub enum Datadef<'a> {
+    Container(&'a ContainerNode),
+    Leaf(&'a LeafNode),
+    LeafList(&'a LeafListNode),
+    List(&'a ListNode),
+    Choice(&'a ChoiceNode),
+    Anydata(&'a AnydataNode),
+    Anyxml(&'a AnyxmlNode),
+    Uses(&'a UsesNode),
+}
+
+impl Datadef<'_> {
+    /// The `if-feature` statements of the node.
+    pub fn if_feature(&self) -> &[IfFeatureNode] {
+        match self {
+            Datadef::Container(n) => &n.if_feature,
+            Datadef::Leaf(n) => &n.if_feature,
+            Datadef::LeafList(n) => &n.if_feature,
+            Datadef::List(n) => &n.if_feature,
+            Datadef::Choice(n) => &n.if_feature,
+            Datadef::Anydata(n) => &n.if_feature,
+            Datadef::Anyxml(n) => &n.if_feature,
+            Datadef::Uses(n) => &n.if_feature,
+        }
+    }
 }
 
 impl DatadefNode {
@@ -178,6 +244,59 @@ impl DatadefNode {
             ..Default::default()
         }
     }
+
+    fn get(&self, kind: DatadefKind, i: usize) -> Option<Datadef<'_>> {
+        match kind {
+            DatadefKind::Container => self.container.get(i).map(Datadef::Container),
+            DatadefKind::Leaf => self.leaf.get(i).map(Datadef::Leaf),
+            DatadefKind::LeafList => self.leaf_list.get(i).map(Datadef::LeafList),
+            DatadefKind::List => self.list.get(i).map(Datadef::List),
+            DatadefKind::Choice => self.choice.get(i).map(Datadef::Choice),
+            DatadefKind::Anydata => self.anydata.get(i).map(Datadef::Anydata),
+            DatadefKind::Anyxml => self.anyxml.get(i).map(Datadef::Anyxml),
+            DatadefKind::Uses => self.uses.get(i).map(Datadef::Uses),
+        }
+    }
+
+    fn len(&self, kind: DatadefKind) -> usize {
+        match kind {
+            DatadefKind::Container => self.container.len(),
+            DatadefKind::Leaf => self.leaf.len(),
+            DatadefKind::LeafList => self.leaf_list.len(),
+            DatadefKind::List => self.list.len(),
+            DatadefKind::Choice => self.choice.len(),
+            DatadefKind::Anydata => self.anydata.len(),
+            DatadefKind::Anyxml => self.anyxml.len(),
+            DatadefKind::Uses => self.uses.len(),
+        }
+    }
+
+    /// Every statement, in the order it was written. Statements pushed
+    /// onto the vectors without an `order` entry follow, kind by kind.
+    pub fn ordered(&self) -> Vec<Datadef<'_>> {
+        let mut out: Vec<Datadef<'_>> = self
+            .order
+            .iter()
+            .filter_map(|&(kind, i)| self.get(kind, i))
+            .collect();
+        for kind in [
+            DatadefKind::Uses,
+            DatadefKind::Container,
+            DatadefKind::Leaf,
+            DatadefKind::List,
+            DatadefKind::LeafList,
+            DatadefKind::Choice,
+            DatadefKind::Anydata,
+            DatadefKind::Anyxml,
+        ] {
+            for i in 0..self.len(kind) {
+                if !self.order.contains(&(kind, i)) {
+                    out.extend(self.get(kind, i));
+                }
+            }
+        }
+        out
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Default)]
@@ -186,6 +305,7 @@ pub struct ContainerNode {
     pub description: Option<String>,
     pub reference: Option<String>,
     pub when: Option<WhenNode>,
+    pub if_feature: Vec<IfFeatureNode>,
     pub status: Option<StatusNode>,
     pub presence: Option<PresenceNode>,
     pub config: Option<ConfigNode>,
@@ -193,6 +313,11 @@ pub struct ContainerNode {
     pub must: Vec<MustNode>,
     pub unknown: Vec<UnknownNode>,
     pub action: Vec<ActionNode>,
+    pub notification: Vec<NotificationNode>,
+    /// Typedefs and groupings defined in the container, visible to it
+    /// and its descendants (RFC 7950 §5.5).
+    pub typedef: Vec<TypedefNode>,
+    pub grouping: Vec<GroupingNode>,
 }
 
 impl ContainerNode {
@@ -210,9 +335,12 @@ pub struct LeafNode {
     pub description: Option<String>,
     pub reference: Option<String>,
     pub when: Option<WhenNode>,
+    pub if_feature: Vec<IfFeatureNode>,
     pub status: Option<StatusNode>,
     pub config: Option<ConfigNode>,
     pub type_stmt: Option<TypeNode>,
+    pub units: Option<String>,
+    pub default: Option<String>,
     pub mandatory: Option<MandatoryNode>,
     pub must: Vec<MustNode>,
     pub unknown: Vec<UnknownNode>,
@@ -241,9 +369,11 @@ pub struct LeafListNode {
     pub description: Option<String>,
     pub reference: Option<String>,
     pub when: Option<WhenNode>,
+    pub if_feature: Vec<IfFeatureNode>,
     pub status: Option<StatusNode>,
     pub config: Option<ConfigNode>,
     pub type_stmt: Option<TypeNode>,
+    pub units: Option<String>,
     pub min_elements: Option<MinElementsNode>,
     pub max_elements: Option<MaxElementsNode>,
     pub unknown: Vec<UnknownNode>,
@@ -265,6 +395,7 @@ pub struct ListNode {
     pub description: Option<String>,
     pub reference: Option<String>,
     pub when: Option<WhenNode>,
+    pub if_feature: Vec<IfFeatureNode>,
     pub status: Option<StatusNode>,
     pub config: Option<ConfigNode>,
     pub d: DatadefNode,
@@ -272,6 +403,12 @@ pub struct ListNode {
     pub max_elements: Option<MaxElementsNode>,
     pub must: Vec<MustNode>,
     pub unknown: Vec<UnknownNode>,
+    pub action: Vec<ActionNode>,
+    pub notification: Vec<NotificationNode>,
+    /// Typedefs and groupings defined in the list, visible to it and
+    /// its descendants (RFC 7950 §5.5).
+    pub typedef: Vec<TypedefNode>,
+    pub grouping: Vec<GroupingNode>,
 }
 
 impl ListNode {
@@ -303,7 +440,9 @@ pub struct ChoiceNode {
     pub status: Option<StatusNode>,
     pub mandatory: Option<MandatoryNode>,
     pub when: Option<WhenNode>,
+    pub if_feature: Vec<IfFeatureNode>,
     pub config: Option<ConfigNode>,
+    pub default: Option<String>,
     pub cases: Vec<CaseNode>,
 }
 
@@ -369,10 +508,19 @@ impl ChoiceNode {
     }
 }
 
+/// YANG 1.1 §7.10. The node holds an unknown chunk of data; only the
+/// statements about the node itself are modeled.
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct AnydataNode {
     pub name: String,
     pub description: Option<String>,
+    pub reference: Option<String>,
+    pub when: Option<WhenNode>,
+    pub if_feature: Vec<IfFeatureNode>,
+    pub must: Vec<MustNode>,
+    pub config: Option<ConfigNode>,
+    pub mandatory: Option<MandatoryNode>,
+    pub status: Option<StatusNode>,
 }
 
 impl AnydataNode {
@@ -384,10 +532,18 @@ impl AnydataNode {
     }
 }
 
+/// YANG 1.1 §7.11, with the same substatements as `anydata`.
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct AnyxmlNode {
     pub name: String,
     pub description: Option<String>,
+    pub reference: Option<String>,
+    pub when: Option<WhenNode>,
+    pub if_feature: Vec<IfFeatureNode>,
+    pub must: Vec<MustNode>,
+    pub config: Option<ConfigNode>,
+    pub mandatory: Option<MandatoryNode>,
+    pub status: Option<StatusNode>,
 }
 
 impl AnyxmlNode {
@@ -473,12 +629,14 @@ impl PresenceNode {
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct InputNode {
     pub d: DatadefNode,
+    pub typedef: Vec<TypedefNode>,
+    pub grouping: Vec<GroupingNode>,
 }
 
 impl InputNode {
     pub fn new() -> Self {
         Self {
-            d: DatadefNode::new(),
+            ..Default::default()
         }
     }
 }
@@ -486,12 +644,14 @@ impl InputNode {
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct OutputNode {
     pub d: DatadefNode,
+    pub typedef: Vec<TypedefNode>,
+    pub grouping: Vec<GroupingNode>,
 }
 
 impl OutputNode {
     pub fn new() -> Self {
         Self {
-            d: DatadefNode::new(),
+            ..Default::default()
         }
     }
 }
@@ -502,8 +662,11 @@ pub struct ActionNode {
     pub description: Option<String>,
     pub reference: Option<String>,
     pub status: Option<StatusNode>,
+    pub if_feature: Vec<IfFeatureNode>,
     pub input: Option<InputNode>,
     pub output: Option<OutputNode>,
+    pub typedef: Vec<TypedefNode>,
+    pub grouping: Vec<GroupingNode>,
 }
 
 impl ActionNode {
@@ -515,6 +678,29 @@ impl ActionNode {
     }
 }
 
+/// A `notification` statement. Its body is data definitions, like an
+/// `input` or `output`.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct NotificationNode {
+    pub name: String,
+    pub description: Option<String>,
+    pub reference: Option<String>,
+    pub status: Option<StatusNode>,
+    pub if_feature: Vec<IfFeatureNode>,
+    pub typedef: Vec<TypedefNode>,
+    pub grouping: Vec<GroupingNode>,
+    pub d: DatadefNode,
+}
+
+impl NotificationNode {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            ..Default::default()
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct CaseNode {
     pub name: String,
@@ -522,6 +708,7 @@ pub struct CaseNode {
     pub reference: Option<String>,
     pub status: Option<StatusNode>,
     pub when: Option<WhenNode>,
+    pub if_feature: Vec<IfFeatureNode>,
     pub d: DatadefNode,
 }
 
@@ -538,6 +725,8 @@ impl CaseNode {
 pub struct TypeNode {
     pub name: String,
     pub kind: YangType,
+    // The `description` of the most derived typedef in the chain that
+    // has one. Only filled in when the entry tree keeps help text.
     pub description: Option<String>,
     pub pattern: Option<String>,
     pub length: Option<RangeNode>,
@@ -550,6 +739,32 @@ pub struct TypeNode {
     // the `leafref` type specification. Survives typedef resolution
     // (typedefs wrapping a leafref carry the underlying path).
     pub path: Option<String>,
+    // Populated for YangType::Identityref: the `base` identity
+    // qualified with the module that defines it, and every identity
+    // derived from it, directly or transitively, across the loaded
+    // modules. Names stay module-qualified because two modules may
+    // define identities with the same name.
+    pub identity_base: Option<QualifiedName>,
+    pub identities: Vec<QualifiedName>,
+    // The `range` argument as written on a type derived from a typedef
+    // (`kind == Path`). Its integer width is only known once the
+    // typedef resolves, when it is parsed into `range`. A decimal64
+    // range is kept here as text; once resolved, it is the most
+    // derived range narrowed by those of its base types.
+    pub range_arg: Option<String>,
+    // The `fraction-digits` of a decimal64 type.
+    pub fraction_digits: Option<u8>,
+    // Every pattern the value must match: those written here and, once
+    // resolved, those of each typedef in the chain. `pattern` holds the
+    // most derived one.
+    pub patterns: Vec<String>,
+    // `units` and `default` of the typedef chain; the most derived
+    // typedef that has one wins.
+    pub units: Option<String>,
+    pub default: Option<String>,
+    // The typedefs this type was resolved through, outermost first, as
+    // each reference was written (`["high-port", "inet:port-number"]`).
+    pub typedef_chain: Vec<String>,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy, Default, Eq, Hash)]
@@ -583,6 +798,24 @@ pub enum YangType {
     NsapAddr,
 }
 
+impl YangType {
+    /// Whether this is one of the integer built-in types, the ones that
+    /// take a `range`.
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            YangType::Int8
+                | YangType::Int16
+                | YangType::Int32
+                | YangType::Int64
+                | YangType::Uint8
+                | YangType::Uint16
+                | YangType::Uint32
+                | YangType::Uint64
+        )
+    }
+}
+
 impl TypeNode {
     pub fn new(name: String, kind: YangType) -> Self {
         Self {
@@ -591,12 +824,61 @@ impl TypeNode {
             ..Default::default()
         }
     }
+
+    /// Find the identity an identityref value names among the resolved
+    /// `identities`. The value may be qualified with the defining
+    /// module's name (`iana-bgp-types:ipv4-unicast`, the RFC 7951 form)
+    /// or bare (`ipv4-unicast`); a bare name only matches when exactly
+    /// one module defines an identity by that name. XML callers map
+    /// their namespace prefix to the module name first.
+    pub fn find_identity(&self, value: &str) -> Option<&QualifiedName> {
+        match value.split_once(':') {
+            Some((module, name)) => self
+                .identities
+                .iter()
+                .find(|i| i.module == module && i.name == name),
+            None => {
+                let mut found = self.identities.iter().filter(|i| i.name == value);
+                let first = found.next()?;
+                match found.next() {
+                    Some(_) => None,
+                    None => Some(first),
+                }
+            }
+        }
+    }
+}
+
+/// A name qualified with the module whose namespace it lives in, such
+/// as an identity (`iana-bgp-types:ipv4-unicast`). Displays in the
+/// RFC 7951 `module:name` form.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Default)]
+pub struct QualifiedName {
+    pub module: String,
+    pub name: String,
+}
+
+impl QualifiedName {
+    pub fn new(module: &str, name: &str) -> Self {
+        Self {
+            module: module.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for QualifiedName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.module, self.name)
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct TypedefNode {
     pub name: String,
     pub type_node: Option<TypeNode>,
+    pub units: Option<String>,
+    pub default: Option<String>,
     pub description: Option<String>,
     pub reference: Option<String>,
     pub status: Option<StatusNode>,
@@ -627,7 +909,7 @@ pub struct StatusNode {
     pub status: StatusNodeEnum,
 }
 
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
 pub enum StatusNodeEnum {
     #[default]
     Current,
@@ -641,6 +923,36 @@ impl StatusNode {
     }
 }
 
+impl StatusNodeEnum {
+    /// The status of a definition with status `self` inside one with
+    /// status `outer`: the less current of the two, so a child of a
+    /// deprecated node is at least deprecated (RFC 7950 §7.21.2).
+    pub fn within(self, outer: StatusNodeEnum) -> StatusNodeEnum {
+        fn rank(status: StatusNodeEnum) -> u8 {
+            match status {
+                StatusNodeEnum::Current => 0,
+                StatusNodeEnum::Deprecated => 1,
+                StatusNodeEnum::Obsolete => 2,
+            }
+        }
+        if rank(outer) > rank(self) {
+            outer
+        } else {
+            self
+        }
+    }
+}
+
+impl fmt::Display for StatusNodeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StatusNodeEnum::Current => write!(f, "current"),
+            StatusNodeEnum::Deprecated => write!(f, "deprecated"),
+            StatusNodeEnum::Obsolete => write!(f, "obsolete"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct ExtensionNode {
     pub name: String,
@@ -665,6 +977,7 @@ pub struct UsesNode {
     pub description: Option<String>,
     pub reference: Option<String>,
     pub when: Option<WhenNode>,
+    pub if_feature: Vec<IfFeatureNode>,
     pub status: Option<StatusNode>,
     /// `augment` substatements (RFC 7950 §7.17, descendant form) that
     /// add nodes to the grouping this `uses` instantiates. Applied
@@ -692,6 +1005,8 @@ pub struct GroupingNode {
     pub typedef: Vec<TypedefNode>,
     pub grouping: Vec<GroupingNode>,
     pub d: DatadefNode,
+    pub action: Vec<ActionNode>,
+    pub notification: Vec<NotificationNode>,
 }
 
 /// YANG 1.1 §7.17 `augment` statement. Carries the raw target path
@@ -700,14 +1015,12 @@ pub struct GroupingNode {
 /// against the augmenting module's imports.
 ///
 /// `when` carries the conditional that, per §7.17, MUST guard an
-/// augment that adds mandatory config to another module. `cases` and
-/// `action` hold the `case`/`action` substatements allowed when the
-/// target is a choice (case) or a container/list (action).
-/// `if_feature` holds the parsed `if-feature` expressions (captured but
-/// not yet evaluated — there is no feature-support context).
-///
-/// Not yet modeled: `notification` (no notification node type exists in
-/// the AST at all). It is parsed by the grammar and currently dropped.
+/// augment that adds mandatory config to another module. `cases`,
+/// `action` and `notification` hold the `case`/`action`/`notification`
+/// substatements allowed when the target is a choice (case) or a
+/// container/list (action, notification). `if_feature` holds the parsed
+/// `if-feature` expressions, evaluated against the features the store
+/// enables.
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct AugmentNode {
     pub target: String,
@@ -718,6 +1031,7 @@ pub struct AugmentNode {
     pub if_feature: Vec<IfFeatureNode>,
     pub cases: Vec<CaseNode>,
     pub action: Vec<ActionNode>,
+    pub notification: Vec<NotificationNode>,
     pub d: DatadefNode,
 }
 
@@ -730,6 +1044,80 @@ impl AugmentNode {
     }
 }
 
+/// A `feature` statement (RFC 7950 §7.20.1). A feature is itself
+/// conditional on its `if-feature` statements.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct FeatureNode {
+    pub name: String,
+    pub description: Option<String>,
+    pub reference: Option<String>,
+    pub status: Option<StatusNode>,
+    pub if_feature: Vec<IfFeatureNode>,
+}
+
+impl FeatureNode {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            ..Default::default()
+        }
+    }
+}
+
+/// A `deviation` statement (RFC 7950 §7.20.3): how the node at
+/// `target`, an absolute schema-node identifier as written, departs
+/// from its definition on a device.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DeviationNode {
+    pub target: String,
+    pub description: Option<String>,
+    pub reference: Option<String>,
+    pub deviate: Vec<DeviateNode>,
+}
+
+impl DeviationNode {
+    pub fn new(target: String) -> Self {
+        Self {
+            target,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum DeviateKind {
+    #[default]
+    NotSupported,
+    Add,
+    Replace,
+    Delete,
+}
+
+/// One `deviate` statement and the properties it adds, replaces or
+/// deletes. `type_stmt` is only valid for `replace`; `unique` is
+/// parsed along with other unknown statements and not kept.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DeviateNode {
+    pub kind: DeviateKind,
+    pub units: Option<String>,
+    pub must: Vec<MustNode>,
+    pub default: Vec<String>,
+    pub config: Option<ConfigNode>,
+    pub mandatory: Option<MandatoryNode>,
+    pub min_elements: Option<MinElementsNode>,
+    pub max_elements: Option<MaxElementsNode>,
+    pub type_stmt: Option<TypeNode>,
+}
+
+impl DeviateNode {
+    pub fn new(kind: DeviateKind) -> Self {
+        Self {
+            kind,
+            ..Default::default()
+        }
+    }
+}
+
 impl GroupingNode {
     pub fn new(name: String) -> Self {
         Self {
@@ -1,4 +1,5 @@
-use std::fmt;
+use crate::YangType;
+use std::{fmt, str::FromStr};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum RangeVal<T> {
@@ -70,6 +71,278 @@ impl fmt::Display for RangeNode {
     }
 }
 
+impl RangeNode {
+    /// Parse a `range` argument (`"1..10 | 20 | 100..max"`) for a value
+    /// of the integer type `kind`. `None` when `kind` is not an integer
+    /// type or a boundary is not a value of it.
+    pub fn parse(text: &str, kind: YangType) -> Option<RangeNode> {
+        let node = match kind {
+            YangType::Int8 => RangeNode::I8(parse_ranges(text)?),
+            YangType::Int16 => RangeNode::I16(parse_ranges(text)?),
+            YangType::Int32 => RangeNode::I32(parse_ranges(text)?),
+            YangType::Int64 => RangeNode::I64(parse_ranges(text)?),
+            YangType::Uint8 => RangeNode::U8(parse_ranges(text)?),
+            YangType::Uint16 => RangeNode::U16(parse_ranges(text)?),
+            YangType::Uint32 => RangeNode::U32(parse_ranges(text)?),
+            YangType::Uint64 => RangeNode::U64(parse_ranges(text)?),
+            _ => return None,
+        };
+        Some(node)
+    }
+
+    /// This range, restricting `base`, with its `min` and `max` written
+    /// out as the lowest and highest values `base` admits, which is
+    /// what they mean in a derived type (RFC 7950 §9.2.4). Over
+    /// different widths, `self` is returned unchanged.
+    pub fn resolve(&self, base: &RangeNode) -> RangeNode {
+        match (self, base) {
+            (RangeNode::I8(d), RangeNode::I8(b)) => RangeNode::I8(ranges_resolve(d, b)),
+            (RangeNode::I16(d), RangeNode::I16(b)) => RangeNode::I16(ranges_resolve(d, b)),
+            (RangeNode::I32(d), RangeNode::I32(b)) => RangeNode::I32(ranges_resolve(d, b)),
+            (RangeNode::I64(d), RangeNode::I64(b)) => RangeNode::I64(ranges_resolve(d, b)),
+            (RangeNode::U8(d), RangeNode::U8(b)) => RangeNode::U8(ranges_resolve(d, b)),
+            (RangeNode::U16(d), RangeNode::U16(b)) => RangeNode::U16(ranges_resolve(d, b)),
+            (RangeNode::U32(d), RangeNode::U32(b)) => RangeNode::U32(ranges_resolve(d, b)),
+            (RangeNode::U64(d), RangeNode::U64(b)) => RangeNode::U64(ranges_resolve(d, b)),
+            _ => self.clone(),
+        }
+    }
+
+    /// Whether every value `self` admits is also admitted by `base`, as
+    /// a derived type's range or length must be (RFC 7950 §9.2.4). Two
+    /// ranges over different integer widths are never subsets. A `min`
+    /// or `max` in `self` is taken as the type's; see
+    /// [`resolve`](Self::resolve) for those of `base`.
+    pub fn is_subset(&self, base: &RangeNode) -> bool {
+        match (self, base) {
+            (RangeNode::I8(d), RangeNode::I8(b)) => ranges_subset(d, b),
+            (RangeNode::I16(d), RangeNode::I16(b)) => ranges_subset(d, b),
+            (RangeNode::I32(d), RangeNode::I32(b)) => ranges_subset(d, b),
+            (RangeNode::I64(d), RangeNode::I64(b)) => ranges_subset(d, b),
+            (RangeNode::U8(d), RangeNode::U8(b)) => ranges_subset(d, b),
+            (RangeNode::U16(d), RangeNode::U16(b)) => ranges_subset(d, b),
+            (RangeNode::U32(d), RangeNode::U32(b)) => ranges_subset(d, b),
+            (RangeNode::U64(d), RangeNode::U64(b)) => ranges_subset(d, b),
+            _ => false,
+        }
+    }
+
+    /// The values admitted by both `self` and `base`, with `min` and
+    /// `max` written out as the bounds of the integer type. Over
+    /// different widths, `self` is returned unchanged.
+    pub fn intersect(&self, base: &RangeNode) -> RangeNode {
+        match (self, base) {
+            (RangeNode::I8(d), RangeNode::I8(b)) => RangeNode::I8(ranges_intersect(d, b)),
+            (RangeNode::I16(d), RangeNode::I16(b)) => RangeNode::I16(ranges_intersect(d, b)),
+            (RangeNode::I32(d), RangeNode::I32(b)) => RangeNode::I32(ranges_intersect(d, b)),
+            (RangeNode::I64(d), RangeNode::I64(b)) => RangeNode::I64(ranges_intersect(d, b)),
+            (RangeNode::U8(d), RangeNode::U8(b)) => RangeNode::U8(ranges_intersect(d, b)),
+            (RangeNode::U16(d), RangeNode::U16(b)) => RangeNode::U16(ranges_intersect(d, b)),
+            (RangeNode::U32(d), RangeNode::U32(b)) => RangeNode::U32(ranges_intersect(d, b)),
+            (RangeNode::U64(d), RangeNode::U64(b)) => RangeNode::U64(ranges_intersect(d, b)),
+            _ => self.clone(),
+        }
+    }
+}
+
+fn parse_ranges<T: FromStr>(text: &str) -> Option<Vec<Range<T>>> {
+    fn boundary<T: FromStr>(s: &str) -> Option<RangeVal<T>> {
+        match s.trim() {
+            "min" => Some(RangeVal::Min),
+            "max" => Some(RangeVal::Max),
+            s => s.parse().ok().map(RangeVal::Val),
+        }
+    }
+    text.split('|')
+        .map(|part| {
+            let (start, end) = match part.split_once("..") {
+                Some((start, end)) => (boundary(start)?, Some(boundary(end)?)),
+                None => (boundary(part)?, None),
+            };
+            Some(Range { start, end })
+        })
+        .collect()
+}
+
+/// `derived` with `min` and `max` resolved to the lowest and highest
+/// values of `base`.
+fn ranges_resolve<T>(derived: &[Range<T>], base: &[Range<T>]) -> Vec<Range<T>>
+where
+    T: MinMax<T> + Default + Copy + PartialOrd,
+{
+    let mut lowest = None;
+    let mut highest = None;
+    for b in base.iter() {
+        let (lo, hi) = bounds(b);
+        if lowest.is_none_or(|l| lo < l) {
+            lowest = Some(lo);
+        }
+        if highest.is_none_or(|h| hi > h) {
+            highest = Some(hi);
+        }
+    }
+    let value = |v: &RangeVal<T>| match (v, lowest, highest) {
+        (RangeVal::Min, Some(lo), _) => RangeVal::Val(lo),
+        (RangeVal::Max, _, Some(hi)) => RangeVal::Val(hi),
+        _ => v.clone(),
+    };
+    derived
+        .iter()
+        .map(|r| Range {
+            start: value(&r.start),
+            end: r.end.as_ref().map(value),
+        })
+        .collect()
+}
+
+/// The inclusive bounds of `r`, with `min`/`max` resolved for `T`.
+fn bounds<T: MinMax<T> + Default + Copy>(r: &Range<T>) -> (T, T) {
+    let value = |b: &RangeVal<T>| match b {
+        RangeVal::Min => T::default().min_value(),
+        RangeVal::Max => T::default().max_value(),
+        RangeVal::Val(v) => *v,
+    };
+    let start = value(&r.start);
+    (start, r.end.as_ref().map(value).unwrap_or(start))
+}
+
+fn ranges_subset<T>(derived: &[Range<T>], base: &[Range<T>]) -> bool
+where
+    T: MinMax<T> + Default + Copy + PartialOrd,
+{
+    derived.iter().all(|d| {
+        let (lo, hi) = bounds(d);
+        base.iter().any(|b| {
+            let (base_lo, base_hi) = bounds(b);
+            lo >= base_lo && hi <= base_hi
+        })
+    })
+}
+
+fn ranges_intersect<T>(derived: &[Range<T>], base: &[Range<T>]) -> Vec<Range<T>>
+where
+    T: MinMax<T> + Default + Copy + PartialOrd,
+{
+    let mut out = Vec::new();
+    for d in derived.iter() {
+        let (lo, hi) = bounds(d);
+        for b in base.iter() {
+            let (base_lo, base_hi) = bounds(b);
+            let lo = if lo > base_lo { lo } else { base_lo };
+            let hi = if hi < base_hi { hi } else { base_hi };
+            if lo <= hi {
+                out.push(Range {
+                    start: RangeVal::Val(lo),
+                    end: (lo != hi).then_some(RangeVal::Val(hi)),
+                });
+            }
+        }
+    }
+    out
+}
+
+/// Narrow the decimal64 range `derived` to `base`, both written as
+/// `range` arguments, with `min` and `max` in `derived` taken as the
+/// bounds of `base`. `base` is `None` when the base type has no range
+/// of its own, and the bounds are then those of a decimal64 with
+/// `fraction_digits`. Returns the narrowed range as text, and whether
+/// `derived` was within `base`; `None` when either does not parse. A
+/// `min` or `max` in `base` itself is a bound of the decimal64.
+pub(crate) fn decimal_narrow(
+    derived: &str,
+    base: Option<&str>,
+    fraction_digits: Option<u8>,
+) -> Option<(String, bool)> {
+    let (lo, hi) = Decimal::bounds(fraction_digits.unwrap_or(1));
+    let bounds = vec![Range {
+        start: RangeVal::Val(lo),
+        end: Some(RangeVal::Val(hi)),
+    }];
+    let base = match base {
+        Some(base) => ranges_resolve(&parse_ranges::<Decimal>(base)?, &bounds),
+        None => bounds,
+    };
+    let derived = ranges_resolve(&parse_ranges::<Decimal>(derived)?, &base);
+    let join = |ranges: &[Range<Decimal>]| {
+        ranges
+            .iter()
+            .map(|r| r.to_string())
+            .collect::<Vec<_>>()
+            .join("|")
+    };
+    if ranges_subset(&derived, &base) {
+        Some((join(&derived), true))
+    } else {
+        Some((join(&ranges_intersect(&derived, &base)), false))
+    }
+}
+
+/// A decimal64 value, scaled by 10^18 so that values of any
+/// `fraction-digits` compare exactly.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+struct Decimal(i128);
+
+impl Decimal {
+    const SCALE: i128 = 1_000_000_000_000_000_000;
+
+    /// The lowest and highest decimal64 with `fraction_digits` digits.
+    fn bounds(fraction_digits: u8) -> (Decimal, Decimal) {
+        let unit = 10i128.pow(18 - u32::from(fraction_digits.min(18)));
+        (
+            Decimal(i128::from(i64::MIN) * unit),
+            Decimal(i128::from(i64::MAX) * unit),
+        )
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s),
+        };
+        let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+        if int.is_empty()
+            || frac.len() > 18
+            || !(int.chars().chain(frac.chars())).all(|c| c.is_ascii_digit())
+        {
+            return Err(());
+        }
+        let int: i128 = int.parse().map_err(|_| ())?;
+        let frac: i128 = format!("{frac:0<18}").parse().map_err(|_| ())?;
+        let value = int.checked_mul(Decimal::SCALE).ok_or(())? + frac;
+        Ok(Decimal(if negative { -value } else { value }))
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let value = self.0.unsigned_abs();
+        let scale = Decimal::SCALE.unsigned_abs();
+        let int = value / scale;
+        let frac = format!("{:018}", value % scale);
+        let frac = frac.trim_end_matches('0');
+        if frac.is_empty() {
+            write!(f, "{sign}{int}")
+        } else {
+            write!(f, "{sign}{int}.{frac}")
+        }
+    }
+}
+
+impl MinMax<Decimal> for Decimal {
+    fn min_value(&self) -> Decimal {
+        Decimal::bounds(1).0
+    }
+
+    fn max_value(&self) -> Decimal {
+        Decimal::bounds(1).1
+    }
+}
+
 pub trait RangeExtract<T> {
     fn extract(&self) -> Option<Vec<Range<T>>>;
 }
@@ -0,0 +1,109 @@
+use crate::*;
+use flate2::read::GzDecoder;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Modules in the `.yang` files of a `.tar`, `.tar.gz` (or `.tgz`) or
+/// `.zip` archive, in any directory of it, read without extracting it.
+/// Files are selected by name as in a [`DirectorySource`]; when two
+/// directories hold the same file name, the first in the archive wins.
+///
+/// The archive is read once, when opened. The origin of a module is the
+/// archive's path joined with the file's path inside it, such as
+/// `bundle.tar.gz/vendor/ietf-ip@2018-02-22.yang`.
+#[derive(Debug, Clone)]
+pub struct ArchiveSource {
+    path: PathBuf,
+    // `.yang` files by their path in the archive, in archive order.
+    files: Vec<(String, String)>,
+}
+
+impl ArchiveSource {
+    /// Read the `.yang` files of the archive at `path`, whose format is
+    /// told by its extension.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, YangError> {
+        let path = path.into();
+        let name = path.to_string_lossy().to_ascii_lowercase();
+        let io_error = |source| YangError::IoError {
+            path: path.clone(),
+            source,
+        };
+        let file = File::open(&path).map_err(io_error)?;
+        let files = if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            read_tar(GzDecoder::new(file))
+        } else if name.ends_with(".tar") {
+            read_tar(file)
+        } else if name.ends_with(".zip") {
+            read_zip(file)
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "not a .tar, .tar.gz or .zip archive",
+            ))
+        }
+        .map_err(io_error)?;
+        Ok(Self { path, files })
+    }
+}
+
+impl ModuleSource for ArchiveSource {
+    fn find(&self, name: &str, revision: Option<&str>) -> Result<Option<ModuleText>, YangError> {
+        let names = self.files.iter().map(|(path, _)| file_name(path));
+        let Some(found) = select_revision(names, name, revision) else {
+            return Ok(None);
+        };
+        let (path, text) = self
+            .files
+            .iter()
+            .find(|(path, _)| file_name(path) == found)
+            .unwrap();
+        Ok(Some(ModuleText {
+            text: text.clone(),
+            origin: self.path.join(path),
+            revision: file_revision(found, name).map(str::to_string),
+        }))
+    }
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn is_yang(path: &Path) -> bool {
+    path.extension().is_some_and(|e| e == "yang")
+}
+
+fn read_tar(input: impl Read) -> io::Result<Vec<(String, String)>> {
+    let mut files = Vec::new();
+    let mut archive = tar::Archive::new(input);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+        if !entry.header().entry_type().is_file() || !is_yang(&path) {
+            continue;
+        }
+        let mut text = String::new();
+        entry.read_to_string(&mut text)?;
+        files.push((path.to_string_lossy().into_owned(), text));
+    }
+    Ok(files)
+}
+
+fn read_zip(input: File) -> io::Result<Vec<(String, String)>> {
+    let mut files = Vec::new();
+    let mut archive = zip::ZipArchive::new(input)?;
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let Some(path) = entry.enclosed_name() else {
+            continue;
+        };
+        if !entry.is_file() || !is_yang(&path) {
+            continue;
+        }
+        let mut text = String::new();
+        entry.read_to_string(&mut text)?;
+        files.push((path.to_string_lossy().into_owned(), text));
+    }
+    Ok(files)
+}
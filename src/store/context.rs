@@ -0,0 +1,178 @@
+use crate::*;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+/// The schema of a whole device: every implemented module's top-level
+/// data nodes, RPCs and notifications, with the augments of the
+/// implemented modules applied.
+///
+/// A tree from [`to_entry`] holds a single module. Here the top-level
+/// nodes of all implemented modules share one root, which is where a
+/// leafref path or `must` expression that crosses modules resolves.
+/// Modules loaded into the store but not named as implemented are
+/// import-only (RFC 7950 §5.6.5): their typedefs, groupings and
+/// identities are used, but they contribute no nodes and their
+/// augments are not applied.
+#[derive(Debug)]
+pub struct SchemaContext {
+    root: Rc<Entry>,
+    rpc: Vec<Rc<Entry>>,
+    notification: Vec<Rc<Entry>>,
+    // Top-level data nodes, RPCs and notifications by qualified name.
+    data_index: BTreeMap<QualifiedName, Rc<Entry>>,
+    rpc_index: BTreeMap<QualifiedName, Rc<Entry>>,
+    notification_index: BTreeMap<QualifiedName, Rc<Entry>>,
+    implemented: Vec<String>,
+    import_only: Vec<String>,
+}
+
+impl SchemaContext {
+    /// Build the context of the `implemented` modules, each already
+    /// loaded into `store`, with every data node kept (see
+    /// [`EntryTree::Full`]).
+    pub fn new(store: &YangStore, implemented: &[&str]) -> Result<Self, YangError> {
+        let options = EntryOptions::new().tree(EntryTree::Full);
+        Self::new_with(store, implemented, &options)
+    }
+
+    /// Build the context of the `implemented` modules with the data
+    /// nodes `options` ask for.
+    pub fn new_with(
+        store: &YangStore,
+        implemented: &[&str],
+        options: &EntryOptions,
+    ) -> Result<Self, YangError> {
+        let mut modules: Vec<&ModuleNode> = Vec::new();
+        for name in implemented {
+            let module = store
+                .find_module(name)
+                .ok_or_else(|| YangError::ModuleNotLoaded {
+                    name: name.to_string(),
+                })?;
+            match modules.iter().find(|m| m.name == module.name) {
+                None => modules.push(module),
+                Some(m) if std::ptr::eq(*m, module) => {}
+                Some(m) => {
+                    return Err(YangError::RevisionConflict {
+                        name: module.name.clone(),
+                        revision: m.latest_revision().unwrap_or_default().to_string(),
+                        other: module.latest_revision().unwrap_or_default().to_string(),
+                    });
+                }
+            }
+        }
+        let is_implemented = |m: &ModuleNode| modules.iter().any(|i| std::ptr::eq(*i, m));
+
+        let root = Rc::new(Entry::new_dir(String::new()));
+        let mut context = SchemaContext {
+            root: root.clone(),
+            rpc: Vec::new(),
+            notification: Vec::new(),
+            data_index: BTreeMap::new(),
+            rpc_index: BTreeMap::new(),
+            notification_index: BTreeMap::new(),
+            implemented: modules.iter().map(|m| m.name.clone()).collect(),
+            import_only: store
+                .modules
+                .iter()
+                .filter(|(_, m)| !is_implemented(m))
+                .map(|(name, _)| name.clone())
+                .collect(),
+        };
+
+        for module in modules.iter() {
+            let tree = module_entry(store, module, options, true, &is_implemented);
+            // The tree holds the module's RPCs and notifications after
+            // its data nodes; they are kept apart from the root's data.
+            let mut rpcs = Vec::new();
+            let mut notifications = Vec::new();
+            for child in tree.dir.take() {
+                child.parent.replace(Rc::downgrade(&root));
+                if child.is_action() {
+                    rpcs.push(child);
+                    continue;
+                }
+                if child.is_notification() {
+                    notifications.push(child);
+                    continue;
+                }
+                let name = QualifiedName::new(&module.name, &child.name);
+                context.data_index.insert(name, child.clone());
+                root.dir.borrow_mut().push(child);
+            }
+            for choice in tree.choices.take() {
+                choice.parent.replace(Rc::downgrade(&root));
+                root.choices.borrow_mut().push(choice);
+            }
+            root.choice_defs
+                .borrow_mut()
+                .extend(tree.choice_defs.take());
+
+            for rpc in rpcs {
+                let name = QualifiedName::new(&module.name, &rpc.name);
+                context.rpc_index.insert(name, rpc.clone());
+                context.rpc.push(rpc);
+            }
+            for notification in notifications {
+                let name = QualifiedName::new(&module.name, &notification.name);
+                context
+                    .notification_index
+                    .insert(name, notification.clone());
+                context.notification.push(notification);
+            }
+        }
+        Ok(context)
+    }
+
+    /// The root holding every implemented module's top-level data
+    /// nodes, in the order the modules were named.
+    pub fn root(&self) -> &Rc<Entry> {
+        &self.root
+    }
+
+    /// The top-level data node `module:name`.
+    pub fn find(&self, name: &str) -> Option<Rc<Entry>> {
+        Self::lookup(&self.data_index, name)
+    }
+
+    /// The RPC `module:name`.
+    pub fn find_rpc(&self, name: &str) -> Option<Rc<Entry>> {
+        Self::lookup(&self.rpc_index, name)
+    }
+
+    /// The notification `module:name`.
+    pub fn find_notification(&self, name: &str) -> Option<Rc<Entry>> {
+        Self::lookup(&self.notification_index, name)
+    }
+
+    /// Every RPC of the implemented modules.
+    pub fn rpcs(&self) -> &[Rc<Entry>] {
+        &self.rpc
+    }
+
+    /// Every top-level notification of the implemented modules.
+    pub fn notifications(&self) -> &[Rc<Entry>] {
+        &self.notification
+    }
+
+    /// The implemented modules, in the order they were named.
+    pub fn implemented(&self) -> &[String] {
+        &self.implemented
+    }
+
+    /// The modules loaded into the store that are not implemented. A
+    /// module loaded at more than one revision is listed once for each,
+    /// the older ones as `name@revision`.
+    pub fn import_only(&self) -> &[String] {
+        &self.import_only
+    }
+
+    pub fn is_implemented(&self, module: &str) -> bool {
+        self.implemented.iter().any(|m| m == module)
+    }
+
+    fn lookup(index: &BTreeMap<QualifiedName, Rc<Entry>>, name: &str) -> Option<Rc<Entry>> {
+        let (module, name) = path_module(name)?;
+        index.get(&QualifiedName::new(&module, &name)).cloned()
+    }
+}
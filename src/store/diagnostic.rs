@@ -1,3 +1,4 @@
+use crate::StatusNodeEnum;
 use std::fmt;
 
 /// Which flavour of `augment` a diagnostic came from. The two differ in
@@ -20,18 +21,47 @@ impl fmt::Display for AugmentKind {
     }
 }
 
+/// What makes an `action` or `notification` illegal where it is
+/// defined (RFC 7950 §7.15, §7.16).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Enclosing {
+    /// An action at the top level of a module, which `rpc` is for.
+    TopLevel,
+    /// Inside the named rpc.
+    Rpc(String),
+    /// Inside the named action.
+    Action(String),
+    /// Inside the named notification.
+    Notification(String),
+    /// Below the named list, which has no key.
+    KeylessList(String),
+}
+
+impl fmt::Display for Enclosing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Enclosing::TopLevel => write!(f, "at the top level"),
+            Enclosing::Rpc(name) => write!(f, "inside rpc \"{name}\""),
+            Enclosing::Action(name) => write!(f, "inside action \"{name}\""),
+            Enclosing::Notification(name) => write!(f, "inside notification \"{name}\""),
+            Enclosing::KeylessList(name) => write!(f, "below list \"{name}\", which has no key"),
+        }
+    }
+}
+
 /// A problem found while building an [`Entry`](crate::Entry) tree.
 ///
 /// These are warnings rather than errors: `to_entry` still returns a
-/// tree, with the offending augment skipped or its duplicate child
-/// removed. They are collected on the [`YangStore`](crate::YangStore)
+/// tree, with the offending augment skipped, its duplicate child
+/// removed, or the reference it could not follow left unresolved.
+/// They are collected on the [`YangStore`](crate::YangStore)
 /// (see [`YangStore::diagnostics`](crate::YangStore::diagnostics)) so
 /// the caller decides whether to log them, fail a build, or ignore
 /// them — previously they were written straight to stderr, which left
 /// a library deciding how an application reports its problems.
 ///
-/// Each variant names the module whose augment is at fault, so a
-/// diagnostic is actionable without re-deriving where it came from.
+/// Each variant names the module at fault, so a diagnostic is
+/// actionable without re-deriving where it came from.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum Diagnostic {
@@ -52,9 +82,9 @@ pub enum Diagnostic {
         missing: String,
     },
 
-    /// The target resolved to a leaf or leaf-list. Data nodes and
-    /// actions may only be added to a container, list, choice, case,
-    /// input, output or notification.
+    /// The target resolved to a leaf, leaf-list, anydata or anyxml.
+    /// Data nodes and actions may only be added to a container, list,
+    /// choice, case, input, output or notification.
     AugmentIntoLeaf {
         module: String,
         target: String,
@@ -62,13 +92,138 @@ pub enum Diagnostic {
     },
 
     /// The augment introduced a child whose name already existed in the
-    /// target, so it was dropped. An augment must not shadow a node
-    /// already present.
+    /// target in the same module's namespace, so it was dropped. An
+    /// augment must not shadow a node already present.
     AugmentDuplicateNode {
         module: String,
         target: String,
         name: String,
     },
+
+    /// A `prefix:name` reference used a prefix that is neither the
+    /// module's own (or, in a submodule, its `belongs-to` prefix) nor
+    /// one of its imports. `reference` is the whole reference, so the
+    /// typedef, grouping, identity, leafref path or augment target that
+    /// could not be followed is named.
+    UnknownPrefix {
+        module: String,
+        prefix: String,
+        reference: String,
+    },
+
+    /// `node`'s `type` names a typedef that no module in scope defines.
+    /// The node is left without a type. A typedef that cannot be
+    /// followed names itself as `node`.
+    UnresolvedTypedef {
+        module: String,
+        node: String,
+        name: String,
+    },
+
+    /// A `uses` in `node` names a grouping that no module in scope
+    /// defines, so it contributes nothing.
+    UnresolvedGrouping {
+        module: String,
+        node: String,
+        name: String,
+    },
+
+    /// An identityref `base` in `node`'s type names an identity that
+    /// its module does not define. The identityref admits no values.
+    UnresolvedIdentity {
+        module: String,
+        node: String,
+        name: String,
+    },
+
+    /// The `base` of identity `node` names an identity that its module
+    /// does not define.
+    UnresolvedBase {
+        module: String,
+        node: String,
+        name: String,
+    },
+
+    /// A `range` or `length` on `node`'s type admits values its base
+    /// type does not, or is not valid for the base type at all. A
+    /// derived type may only narrow its base (RFC 7950 §9.2.4); the
+    /// restriction is cut back to the values both admit. A typedef
+    /// names itself as `node`.
+    RestrictionNotSubset {
+        module: String,
+        node: String,
+        restriction: String,
+        value: String,
+        base: String,
+    },
+
+    /// A grouping `uses` itself, directly or through other groupings.
+    /// `cycle` names the groupings around it, ending where it began;
+    /// the expansion stops where the cycle closes.
+    GroupingCycle { module: String, cycle: Vec<String> },
+
+    /// A typedef's type refers back to the typedef itself, directly or
+    /// through other typedefs. `cycle` is as for
+    /// [`GroupingCycle`](Self::GroupingCycle); the type is left
+    /// unresolved.
+    TypedefCycle { module: String, cycle: Vec<String> },
+
+    /// A typedef defined in `node` reuses the name of one visible from
+    /// there — in an enclosing scope, at the top level of the module or
+    /// one of its submodules, or earlier in the same scope (RFC 7950
+    /// §5.5). References find the nearest one.
+    ShadowedTypedef {
+        module: String,
+        node: String,
+        name: String,
+    },
+
+    /// A grouping defined in `node` reuses the name of one visible from
+    /// there, as for [`ShadowedTypedef`](Self::ShadowedTypedef).
+    ShadowedGrouping {
+        module: String,
+        node: String,
+        name: String,
+    },
+
+    /// `node` says `config true` but sits below `config false` data,
+    /// which RFC 7950 §7.21.1 forbids. The node is kept as state.
+    ConfigUnderState { module: String, node: String },
+
+    /// An `action` or `notification` (`statement`) defined where
+    /// RFC 7950 §7.15 or §7.16 forbids it, typically through a
+    /// grouping. It is left out of the tree.
+    MisplacedOperation {
+        module: String,
+        statement: String,
+        name: String,
+        within: Enclosing,
+    },
+
+    /// A `deviation` target did not resolve to any node in the tree.
+    /// `missing` is the first path segment that failed to match.
+    DeviationTargetNotFound {
+        module: String,
+        target: String,
+        missing: String,
+    },
+
+    /// `node`, a current definition, references the `definition`
+    /// (typedef, grouping or identity) `name` of the same module, which
+    /// is deprecated or obsolete (RFC 7950 §7.21.2). References into
+    /// other modules are not reported.
+    StatusReference {
+        module: String,
+        node: String,
+        definition: String,
+        name: String,
+        status: StatusNodeEnum,
+    },
+
+    /// An `if-feature` names the feature `name`, which the module its
+    /// prefix binds to does not define. The feature does not hold, so
+    /// what it guards is left out.
+    UndefinedFeature { module: String, name: String },
 }
 
 impl fmt::Display for Diagnostic {
@@ -110,6 +265,89 @@ impl fmt::Display for Diagnostic {
                 "{module}: augment node \"{name}\" already exists in target \"{target}\"; \
                  not added"
             ),
+            Diagnostic::UnknownPrefix {
+                module,
+                prefix,
+                reference,
+            } => write!(
+                f,
+                "{module}: unknown prefix \"{prefix}\" in \"{reference}\""
+            ),
+            Diagnostic::UnresolvedTypedef { module, node, name } => write!(
+                f,
+                "{module}: typedef \"{name}\" used by \"{node}\" not found"
+            ),
+            Diagnostic::UnresolvedGrouping { module, node, name } => write!(
+                f,
+                "{module}: grouping \"{name}\" used in \"{node}\" not found"
+            ),
+            Diagnostic::UnresolvedIdentity { module, node, name } => write!(
+                f,
+                "{module}: identity \"{name}\" used as base by \"{node}\" not found"
+            ),
+            Diagnostic::UnresolvedBase { module, node, name } => write!(
+                f,
+                "{module}: base identity \"{name}\" of identity \"{node}\" not found"
+            ),
+            Diagnostic::RestrictionNotSubset {
+                module,
+                node,
+                restriction,
+                value,
+                base,
+            } => write!(
+                f,
+                "{module}: {restriction} {value} of \"{node}\" is not within its base type's {base}"
+            ),
+            Diagnostic::GroupingCycle { module, cycle } => {
+                write!(f, "{module}: grouping cycle {}", cycle.join(" -> "))
+            }
+            Diagnostic::TypedefCycle { module, cycle } => {
+                write!(f, "{module}: typedef cycle {}", cycle.join(" -> "))
+            }
+            Diagnostic::ShadowedTypedef { module, node, name } => write!(
+                f,
+                "{module}: typedef \"{name}\" in \"{node}\" is already defined in scope"
+            ),
+            Diagnostic::ShadowedGrouping { module, node, name } => write!(
+                f,
+                "{module}: grouping \"{name}\" in \"{node}\" is already defined in scope"
+            ),
+            Diagnostic::ConfigUnderState { module, node } => write!(
+                f,
+                "{module}: \"{node}\" is config true below config false data"
+            ),
+            Diagnostic::MisplacedOperation {
+                module,
+                statement,
+                name,
+                within,
+            } => write!(
+                f,
+                "{module}: {statement} \"{name}\" may not be defined {within}"
+            ),
+            Diagnostic::DeviationTargetNotFound {
+                module,
+                target,
+                missing,
+            } => write!(
+                f,
+                "{module}: deviation target \"{target}\" not found \
+                 (no node matching \"{missing}\")"
+            ),
+            Diagnostic::StatusReference {
+                module,
+                node,
+                definition,
+                name,
+                status,
+            } => write!(
+                f,
+                "{module}: \"{node}\" is current but references {status} {definition} \"{name}\""
+            ),
+            Diagnostic::UndefinedFeature { module, name } => {
+                write!(f, "{module}: if-feature names undefined feature \"{name}\"")
+            }
         }
     }
 }
@@ -1,18 +1,23 @@
 use crate::*;
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::rc::Rc;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, HashMap};
+use std::rc::{self, Rc};
+use std::sync::{Arc, Weak};
 
-#[derive(Debug, PartialEq, Default)]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
 pub enum EntryKind {
     #[default]
     LeafEntry,
     DirectoryEntry,
     ChoiceEntry,
+    CaseEntry,
     ActionEntry,
+    NotificationEntry,
+    AnydataEntry,
+    AnyxmlEntry,
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct ListAttr {
     pub min_elements: u64,
     pub max_elements: u64,
@@ -27,7 +32,7 @@ impl ListAttr {
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct Entry {
     pub name: String,
     pub kind: EntryKind,
@@ -36,10 +41,12 @@ pub struct Entry {
     pub dir: RefCell<Vec<Rc<Entry>>>,
     pub key: Vec<String>,
     pub extension: HashMap<String, String>,
-    pub parent: RefCell<Option<Rc<Entry>>>,
+    // Weak, since the parent owns this entry through its `dir`: a strong
+    // pointer back would make every tree a cycle that is never freed.
+    // Reach it through [`Entry::parent`].
+    pub parent: RefCell<rc::Weak<Entry>>,
     pub type_node: Option<TypeNode>,
     pub list_attr: Option<ListAttr>,
-    pub choice_cases: Option<Vec<Rc<Entry>>>,
 
     // When this entry was introduced by a `case` inside a `choice`,
     // these record the choice and case names so consumers can reason
@@ -51,16 +58,111 @@ pub struct Entry {
     pub choice: RefCell<Option<String>>,
     pub case: RefCell<Option<String>>,
 
-    // Names of `choice` nodes defined directly under this entry. A
-    // choice is otherwise invisible in the flattened tree (only its
-    // cases' children appear), so recording the names lets an augment
-    // target a choice — including one that has no cases yet.
-    pub choice_defs: RefCell<Vec<String>>,
+    // The `choice` nodes defined directly under this entry, by module
+    // and name. A choice is otherwise invisible in the flattened tree
+    // (only its cases' children appear), so recording them lets an
+    // augment target a choice — including one that has no cases yet.
+    pub choice_defs: RefCell<Vec<QualifiedName>>,
+
+    // The same choices as entries of their own, alongside the flattened
+    // view. A choice entry holds its cases in `dir`; a case holds the
+    // data nodes it contributed to this entry (shared with this entry's
+    // `dir`, and still parented here) and the choices nested in it.
+    pub choices: RefCell<Vec<Rc<Entry>>>,
+
+    // The `when` condition of a choice, case, anydata or anyxml, and
+    // the default case of a choice.
+    pub when: Option<String>,
+    pub default: Option<String>,
+
+    // The `must` expressions of an anydata or anyxml.
+    pub must: Vec<String>,
+
+    // The node's effective status: its own `status`, or that of the
+    // node, `uses`, augment or case it is defined in when that is less
+    // current (RFC 7950 §7.21.2).
+    pub status: StatusNodeEnum,
+
+    // The module whose namespace this node is in: the module that
+    // defines it, or for a node from a grouping, the module of the
+    // `uses` that instantiated it (RFC 7950 §7.13). Two modules may
+    // add same-named nodes to one parent, told apart by this.
+    pub module: String,
+
+    // Whether this node is configuration (`true`) or state data, after
+    // RFC 7950 §7.21.1 inheritance: a node without a `config` statement
+    // takes its parent's value, and the top of the tree is config.
+    pub config: bool,
+
+    // The `config` value the node's own statement sets, or `false` for
+    // a node in a `config false` choice: what the node keeps when a
+    // deviation puts an ancestor back to `config true`. `None` when it
+    // only inherits.
+    pub(crate) config_stmt: Option<bool>,
+
+    // Where the statement that produced this node came from; see
+    // `Entry::provenance`.
+    pub(crate) provenance: Provenance,
+
+    // The node's help text, when the tree was built with
+    // `EntryOptions::help`. Boxed so a tree without it pays one
+    // pointer per node.
+    pub help: Option<Box<EntryHelp>>,
+}
+
+/// The help text of an [`Entry`]: what a CLI shows for `?` and what
+/// generated documentation prints.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntryHelp {
+    /// The node's `description`, or for a leaf or leaf-list without
+    /// one, that of the nearest typedef in its type's chain.
+    pub description: Option<String>,
+    pub reference: Option<String>,
+    /// The `units` of a leaf or leaf-list, or of its type's typedef
+    /// chain when the node sets none.
+    pub units: Option<String>,
+}
+
+/// Where the statement behind an [`Entry`] is written and how it got
+/// into the tree.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Provenance {
+    /// The module or submodule whose text defines the node. For a node
+    /// from a grouping, this is where the grouping is written, which
+    /// may not be the module whose namespace the node is in.
+    pub module: String,
+    /// The `uses` the node was expanded through, outermost first.
+    pub uses: Vec<UsesSite>,
+    /// The augment, top-level or inside a `uses`, that added the node
+    /// or one of its ancestors.
+    pub augment: Option<AugmentSite>,
+    /// The modules or submodules whose `deviation`s changed the node,
+    /// in the order they were applied.
+    pub deviation: Vec<String>,
+}
+
+/// A `uses` a node was expanded through.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsesSite {
+    /// The module or submodule the `uses` is written in.
+    pub module: String,
+    /// The grouping it names.
+    pub grouping: QualifiedName,
+}
+
+/// An `augment` that added a node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AugmentSite {
+    /// The module or submodule the `augment` is written in.
+    pub module: String,
+    pub kind: AugmentKind,
+    pub target: String,
 }
 
 impl Entry {
     pub fn new() -> Self {
         Self {
+            config: true,
             ..Default::default()
         }
     }
@@ -69,6 +171,7 @@ impl Entry {
         Self {
             name,
             kind: EntryKind::DirectoryEntry,
+            config: true,
             ..Default::default()
         }
     }
@@ -77,6 +180,7 @@ impl Entry {
         Self {
             name,
             kind: EntryKind::LeafEntry,
+            config: true,
             ..Default::default()
         }
     }
@@ -86,6 +190,7 @@ impl Entry {
             name,
             kind: EntryKind::DirectoryEntry,
             key,
+            config: true,
             ..Default::default()
         }
     }
@@ -94,7 +199,16 @@ impl Entry {
         Self {
             name,
             kind: EntryKind::ChoiceEntry,
-            choice_cases: Some(Vec::new()),
+            config: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn new_case(name: String) -> Self {
+        Self {
+            name,
+            kind: EntryKind::CaseEntry,
+            config: true,
             ..Default::default()
         }
     }
@@ -103,10 +217,50 @@ impl Entry {
         Self {
             name,
             kind: EntryKind::ActionEntry,
+            config: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn new_notification(name: String) -> Self {
+        Self {
+            name,
+            kind: EntryKind::NotificationEntry,
+            config: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn new_anydata(name: String) -> Self {
+        Self {
+            name,
+            kind: EntryKind::AnydataEntry,
+            config: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn new_anyxml(name: String) -> Self {
+        Self {
+            name,
+            kind: EntryKind::AnyxmlEntry,
+            config: true,
             ..Default::default()
         }
     }
 
+    /// The entry this one is a child of, or `None` at the top of the
+    /// tree or once the tree has been dropped.
+    pub fn parent(&self) -> Option<Rc<Entry>> {
+        self.parent.borrow().upgrade()
+    }
+
+    /// A frozen, thread-safe copy of the tree below this entry, to
+    /// build once the tree is complete. This entry is its root.
+    pub fn freeze(&self) -> Arc<SchemaNode> {
+        SchemaNode::freeze(self, Weak::new())
+    }
+
     pub fn has_key(&self) -> bool {
         !self.key.is_empty()
     }
@@ -149,9 +303,204 @@ impl Entry {
         self.kind == EntryKind::ChoiceEntry
     }
 
+    pub fn is_case(&self) -> bool {
+        self.kind == EntryKind::CaseEntry
+    }
+
+    /// The choice named `name` defined directly under this entry.
+    pub fn find_choice(&self, name: &str) -> Option<Rc<Entry>> {
+        self.choices
+            .borrow()
+            .iter()
+            .find(|c| c.name == name)
+            .cloned()
+    }
+
+    /// The names of the choices defined directly under this entry,
+    /// without their modules.
+    pub fn choice_def_names(&self) -> Vec<String> {
+        self.choice_defs
+            .borrow()
+            .iter()
+            .map(|q| q.name.clone())
+            .collect()
+    }
+
+    /// The case of this choice named `name`.
+    pub fn find_case(&self, name: &str) -> Option<Rc<Entry>> {
+        self.dir
+            .borrow()
+            .iter()
+            .filter(|c| c.is_case())
+            .find(|c| c.name == name)
+            .cloned()
+    }
+
     pub fn is_action(&self) -> bool {
         self.kind == EntryKind::ActionEntry
     }
+
+    pub fn is_notification(&self) -> bool {
+        self.kind == EntryKind::NotificationEntry
+    }
+
+    pub fn is_anydata(&self) -> bool {
+        self.kind == EntryKind::AnydataEntry
+    }
+
+    pub fn is_anyxml(&self) -> bool {
+        self.kind == EntryKind::AnyxmlEntry
+    }
+
+    /// Which module defines this node, and the groupings and augment it
+    /// came through.
+    pub fn provenance(&self) -> &Provenance {
+        &self.provenance
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.help.as_ref()?.description.as_deref()
+    }
+
+    pub fn reference(&self) -> Option<&str> {
+        self.help.as_ref()?.reference.as_deref()
+    }
+
+    pub fn units(&self) -> Option<&str> {
+        self.help.as_ref()?.units.as_deref()
+    }
+
+    /// The absolute schema node identifier of this entry (RFC 7950
+    /// §6.5), with every segment qualified by its module name and the
+    /// choices and cases a node sits in included:
+    /// `/m:top/m:mode/m:fast/m:speed`. The root of the tree is not a
+    /// segment.
+    pub fn schema_path(&self) -> String {
+        let mut segments = Vec::new();
+        self.path_segments(true, &mut segments);
+        segments
+            .iter()
+            .map(|(module, name)| format!("/{module}:{name}"))
+            .collect()
+    }
+
+    /// The data path of this entry in the RFC 7951 style: choices,
+    /// cases and the `input`/`output` of an operation are left out, and
+    /// a segment is qualified by its module name only where the
+    /// namespace changes: `/m:top/speed`. There are no key predicates.
+    pub fn data_path(&self) -> String {
+        let mut segments = Vec::new();
+        self.path_segments(false, &mut segments);
+        let mut path = String::new();
+        let mut namespace = "";
+        for (module, name) in segments.iter() {
+            if module == namespace {
+                path.push_str(&format!("/{name}"));
+            } else {
+                path.push_str(&format!("/{module}:{name}"));
+                namespace = module;
+            }
+        }
+        path
+    }
+
+    /// The segments from the top of the tree down to this entry, as
+    /// (module, name) pairs.
+    fn path_segments(&self, schema: bool, segments: &mut Vec<(String, String)>) {
+        let Some(parent) = self.parent() else {
+            return;
+        };
+        parent.path_segments(schema, segments);
+        if schema {
+            segments.extend(parent.choice_path(self, &parent.choices.borrow()));
+        } else if parent.is_action() {
+            return;
+        }
+        segments.push((self.module.clone(), self.name.clone()));
+    }
+
+    /// The choices and cases among `choices`, outermost first, that lead
+    /// to `node`, one of this entry's children.
+    fn choice_path(&self, node: &Entry, choices: &[Rc<Entry>]) -> Vec<(String, String)> {
+        for choice in choices {
+            for case in choice.dir.borrow().iter() {
+                let dir = case.dir.borrow();
+                let mut path = vec![
+                    (choice.module.clone(), choice.name.clone()),
+                    (case.module.clone(), case.name.clone()),
+                ];
+                if dir.iter().any(|e| std::ptr::eq(Rc::as_ptr(e), node)) {
+                    return path;
+                }
+                let nested: Vec<Rc<Entry>> =
+                    dir.iter().filter(|e| e.is_choice()).cloned().collect();
+                let inner = self.choice_path(node, &nested);
+                if !inner.is_empty() {
+                    path.extend(inner);
+                    return path;
+                }
+            }
+        }
+        Vec::new()
+    }
+
+    /// Find the entry below this one at `path`, written as
+    /// [`data_path`](Self::data_path) or
+    /// [`schema_path`](Self::schema_path) write it. An unqualified
+    /// segment is in the namespace of the one before it, and the first
+    /// in this entry's.
+    pub fn find_by_path(&self, path: &str) -> Option<Rc<Entry>> {
+        let mut current: Option<Rc<Entry>> = None;
+        let mut namespace = self.module.clone();
+        // The choice or case a schema path is walking through. Neither
+        // is a data node, so `current` stays at the data node they sit
+        // under.
+        let mut within: Option<Rc<Entry>> = None;
+        for seg in path.split('/').filter(|s| !s.is_empty()) {
+            let (module, name) = match seg.split_once(':') {
+                Some((module, name)) => (module.to_string(), name),
+                None => (namespace.clone(), seg),
+            };
+            let name = QualifiedName::new(&module, name);
+            namespace = module;
+
+            if let Some(member) = within.take() {
+                // A choice is followed by one of its cases; a case by
+                // one of its data nodes or nested choices.
+                let next = find_child(&member, &name)?;
+                if next.is_choice() || next.is_case() {
+                    within = Some(next);
+                } else {
+                    current = Some(next);
+                }
+                continue;
+            }
+            let at = current.as_deref().unwrap_or(self);
+            // The input or output of an operation is left out of a data
+            // path, so look into both.
+            let next = find_child(at, &name).or_else(|| {
+                at.dir
+                    .borrow()
+                    .iter()
+                    .filter(|_| at.is_action())
+                    .find_map(|io| find_child(io, &name))
+            });
+            if next.is_some() {
+                current = next;
+            } else {
+                let choices = at.choices.borrow();
+                let choice = choices
+                    .iter()
+                    .find(|c| c.name == name.name && c.module == name.module)?;
+                within = Some(choice.clone());
+            }
+        }
+        // A path that ends on a choice or case names no data node.
+        if within.is_some() {
+            return None;
+        }
+        current
+    }
 }
 
 pub fn path_split(path: String) -> (String, String) {
@@ -172,29 +521,206 @@ pub fn path_module(path: &str) -> Option<(String, String)> {
     }
 }
 
-pub fn to_entry(store: &YangStore, module: &ModuleNode) -> Rc<Entry> {
-    let entry = Rc::new(Entry::new_dir(module.name.clone()));
-    for c in module.d.container.iter() {
-        container_entry(module, store, c, entry.clone());
+/// Which data nodes an entry tree built by [`to_entry_with`] holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EntryTree {
+    /// Configuration only: every `config false` node, and everything
+    /// below one, is left out.
+    #[default]
+    Config,
+    /// State only: `config false` nodes, with the configuration
+    /// containers and lists leading to them and the keys of those
+    /// lists. Actions are left out.
+    State,
+    /// Every data node, each tagged with [`Entry::config`].
+    Full,
+}
+
+/// Options for [`to_entry_with`]. Start from [`EntryOptions::new`]
+/// and set what differs from the defaults.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct EntryOptions {
+    pub tree: EntryTree,
+    /// Leave out obsolete nodes, and everything below one. Deprecated
+    /// nodes are kept, flagged by [`Entry::status`].
+    pub omit_obsolete: bool,
+    /// Keep each node's description, reference and units (see
+    /// [`Entry::help`]).
+    pub help: bool,
+}
+
+impl EntryOptions {
+    /// The default options: the configuration tree, obsolete nodes
+    /// kept, no help text.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tree(mut self, tree: EntryTree) -> Self {
+        self.tree = tree;
+        self
+    }
+
+    pub fn omit_obsolete(mut self, omit_obsolete: bool) -> Self {
+        self.omit_obsolete = omit_obsolete;
+        self
     }
-    for leaf in module.d.leaf.iter() {
-        leaf_entry(module, store, leaf, entry.clone());
+
+    pub fn help(mut self, help: bool) -> Self {
+        self.help = help;
+        self
+    }
+}
+
+/// Build the configuration tree of `module`. Same as [`to_entry_with`]
+/// with the default options.
+pub fn to_entry(store: &YangStore, module: &ModuleNode) -> Rc<Entry> {
+    to_entry_with(store, module, &EntryOptions::default())
+}
+
+/// Build the entry tree of `module`: its data nodes, those of its
+/// submodules, and every loaded module's augments of them, filtered as
+/// `options` ask. The module's top-level RPCs and notifications are
+/// not built, and the augments and deviations of them are skipped; a
+/// [`SchemaContext`](crate::SchemaContext) holds those.
+///
+/// Every loaded module's deviations of the tree are applied too, as its
+/// augments are: loading a deviation module changes the trees of the
+/// modules it deviates. To leave a loaded module's deviations (or
+/// augments) out, build a `SchemaContext` that does not implement it.
+pub fn to_entry_with(store: &YangStore, module: &ModuleNode, options: &EntryOptions) -> Rc<Entry> {
+    // Of each other module, the latest revision is implemented.
+    let latest = |m: &ModuleNode| {
+        store
+            .find_module(&m.name)
+            .is_some_and(|l| std::ptr::eq(l, m))
+    };
+    module_entry(store, module, options, false, &latest)
+}
+
+/// The state of one entry-tree build: the store it reads and what the
+/// walk is in the middle of. It derefs to the store, so the builders
+/// look definitions up through it.
+pub(crate) struct Builder<'a> {
+    store: &'a YangStore,
+    // Whether the tree keeps help text.
+    pub(crate) help: bool,
+    // The groupings being expanded and typedefs being resolved, so a
+    // definition that reaches itself is caught instead of recursing
+    // until the stack overflows.
+    pub(crate) expanding_groupings: ExpansionStack,
+    pub(crate) resolving_typedefs: ExpansionStack,
+    // Set while the cases of a `config false` choice are built. A choice
+    // has no entry of its own, so its children cannot inherit from it
+    // through their parent entry.
+    pub(crate) state_choice: Cell<bool>,
+    // While a `uses` is expanded, the module of the outermost one: the
+    // namespace its grouping's nodes are placed in.
+    pub(crate) uses_namespace: RefCell<Option<String>>,
+    // The `uses` being expanded, outermost first, and the augment being
+    // applied: the provenance of the nodes built meanwhile.
+    pub(crate) uses_sites: RefCell<Vec<UsesSite>>,
+    pub(crate) augment_site: RefCell<Option<AugmentSite>>,
+    // The status of the definition being built or resolved: the
+    // `uses`, augment or case whose nodes are built straight into an
+    // entry, or the node or typedef whose type is resolved. Nodes built
+    // meanwhile are at least this deprecated, and the definitions
+    // referenced are checked against it.
+    pub(crate) status: Cell<StatusNodeEnum>,
+    // Whether the module's top-level RPCs and notifications are built.
+    // When they are not, the ones left out are recorded, so the
+    // augments and deviations of them are skipped instead of reported
+    // as not found.
+    pub(crate) operations: bool,
+    pub(crate) left_out: RefCell<Vec<QualifiedName>>,
+}
+
+impl<'a> Builder<'a> {
+    pub(crate) fn new(store: &'a YangStore, options: &EntryOptions) -> Self {
+        Self {
+            store,
+            help: options.help,
+            expanding_groupings: ExpansionStack::default(),
+            resolving_typedefs: ExpansionStack::default(),
+            state_choice: Cell::new(false),
+            uses_namespace: RefCell::new(None),
+            uses_sites: RefCell::new(Vec::new()),
+            augment_site: RefCell::new(None),
+            status: Cell::new(StatusNodeEnum::default()),
+            operations: true,
+            left_out: RefCell::new(Vec::new()),
+        }
     }
-    for list in module.d.list.iter() {
-        list_entry(module, store, list, entry.clone());
+}
+
+impl std::ops::Deref for Builder<'_> {
+    type Target = YangStore;
+
+    fn deref(&self) -> &YangStore {
+        self.store
     }
-    for leaf_list in module.d.leaf_list.iter() {
-        leaf_list_entry(module, store, leaf_list, entry.clone());
+}
+
+/// Build the entry tree of `module`, applying the augments of the
+/// loaded modules `augments_from` accepts. With `operations`, the
+/// module's RPCs and notifications follow its data nodes in the tree's
+/// `dir`, so that augments and deviations reach into them too;
+/// without, they are neither built nor reported on.
+pub(crate) fn module_entry(
+    store: &YangStore,
+    module: &ModuleNode,
+    options: &EntryOptions,
+    operations: bool,
+    augments_from: &dyn Fn(&ModuleNode) -> bool,
+) -> Rc<Entry> {
+    let mut builder = Builder::new(store, options);
+    builder.operations = operations;
+    let store = &builder;
+    let mut entry = Entry::new_dir(module.name.clone());
+    entry.module = module.name.clone();
+    let entry = Rc::new(entry);
+    // A module and its (transitively) included submodules form one
+    // schema tree: the submodules' top-level data nodes are the
+    // module's. Each submodule is walked as its own `top`, since the
+    // prefixes its definitions use are bound by its own imports.
+    let submodules = store.submodules_of_module(module);
+    datadef_build(module, store, &module.d, entry.clone());
+    for sub in submodules.iter() {
+        datadef_build(*sub, store, &sub.d, entry.clone());
+    }
+    for rpc in module.rpc.iter() {
+        operation_build(module, store, &rpc.name, &entry, rpc_build, rpc);
+    }
+    for sub in submodules.iter() {
+        for rpc in sub.rpc.iter() {
+            operation_build(*sub, store, &rpc.name, &entry, rpc_build, rpc);
+        }
     }
-    for choice in module.d.choice.iter() {
-        choice_entry(module, store, choice, entry.clone());
+    for notification in module.notification.iter() {
+        let name = &notification.name;
+        operation_build(
+            module,
+            store,
+            name,
+            &entry,
+            notification_build,
+            notification,
+        );
+    }
+    for sub in submodules.iter() {
+        for notification in sub.notification.iter() {
+            let name = &notification.name;
+            operation_build(*sub, store, name, &entry, notification_build, notification);
+        }
     }
 
     // Apply YANG 1.1 §7.17 augment statements. Each augment may live
     // in the root module itself or in any loaded module — typically
     // a sibling module that imports the target. Walk all modules
     // once the primary tree is built so augment targets are
-    // resolvable.
+    // resolvable. A module's augments include those written in its
+    // submodules.
     //
     // Augmented nodes are appended to their target's `dir` as each
     // augment is applied, so this walk decides the child order of every
@@ -202,65 +728,461 @@ pub fn to_entry(store: &YangStore, module: &ModuleNode) -> Rc<Entry> {
     // order deterministic (by module name) rather than dependent on
     // hash seeding — see the note on `YangStore`. RFC 7950 does not
     // prescribe an order across augmenting modules, so any stable one
-    // is conformant.
+    // is conformant. `augments_from` accepts at most one revision of
+    // each module, the implemented one; another revision, kept only for
+    // a pinned import (under `name@revision`), is import-only, so its
+    // augments and deviations are not applied.
     for aug in module.augment.iter() {
         apply_augment(module, store, entry.clone(), aug);
     }
-    for (name, m) in store.modules.iter() {
-        if name == &module.name {
+    for sub in submodules.iter() {
+        for aug in sub.augment.iter() {
+            apply_augment(*sub, store, entry.clone(), aug);
+        }
+    }
+    for m in store.modules.values() {
+        if m.name == module.name || !augments_from(m) {
             continue;
         }
         for aug in m.augment.iter() {
             apply_augment(m, store, entry.clone(), aug);
         }
+        for sub in store.submodules_of_module(m) {
+            for aug in sub.augment.iter() {
+                apply_augment(sub, store, entry.clone(), aug);
+            }
+        }
     }
 
+    // RFC 7950 §7.20.3 deviations go last, so they can reach nodes
+    // that augments added. They are taken from the same modules as the
+    // augments.
+    for dev in module.deviation.iter() {
+        apply_deviation(module, store, &entry, dev);
+    }
+    for sub in submodules.iter() {
+        for dev in sub.deviation.iter() {
+            apply_deviation(*sub, store, &entry, dev);
+        }
+    }
+    for m in store.modules.values() {
+        if m.name == module.name || !augments_from(m) {
+            continue;
+        }
+        for dev in m.deviation.iter() {
+            apply_deviation(m, store, &entry, dev);
+        }
+        for sub in store.submodules_of_module(m) {
+            for dev in sub.deviation.iter() {
+                apply_deviation(sub, store, &entry, dev);
+            }
+        }
+    }
+
+    // The whole tree is built before filtering, so an augment can reach
+    // into state data whichever tree is asked for.
+    prune(&entry, options);
     entry.clone()
 }
 
+/// Drop the children of `ent`, recursively, that the `options` leave
+/// out.
+fn prune(ent: &Rc<Entry>, options: &EntryOptions) {
+    let tree = options.tree;
+    if tree == EntryTree::Full && !options.omit_obsolete {
+        return;
+    }
+    ent.dir.borrow_mut().retain(|child| {
+        if options.omit_obsolete && child.status == StatusNodeEnum::Obsolete {
+            return false;
+        }
+        // An RPC or notification is an operation, not configuration or
+        // state: it stays whole in every tree, less what is obsolete.
+        if ent.parent().is_none() && (child.is_action() || child.is_notification()) {
+            let options = EntryOptions {
+                tree: EntryTree::Full,
+                ..options.clone()
+            };
+            prune(child, &options);
+            return true;
+        }
+        prune(child, options);
+        match tree {
+            EntryTree::Config => child.config,
+            EntryTree::State if !child.config => true,
+            EntryTree::State if child.is_action() || child.is_notification() => false,
+            EntryTree::State if child.is_directory_entry() => child
+                .dir
+                .borrow()
+                .iter()
+                .any(|c| !child.key.contains(&c.name)),
+            EntryTree::State => ent.key.contains(&child.name),
+            EntryTree::Full => true,
+        }
+    });
+    ent.choices
+        .borrow_mut()
+        .retain(|choice| prune_choice(ent, choice, options));
+}
+
+/// Drop from the cases of `choice`, defined under `ent`, the nodes
+/// `prune` dropped from `ent`, and say whether the choice itself stays.
+fn prune_choice(ent: &Entry, choice: &Rc<Entry>, options: &EntryOptions) -> bool {
+    let obsolete = |e: &Entry| options.omit_obsolete && e.status == StatusNodeEnum::Obsolete;
+    if obsolete(choice) {
+        return false;
+    }
+    choice.dir.borrow_mut().retain(|case| !obsolete(case));
+    for case in choice.dir.borrow().iter() {
+        case.dir.borrow_mut().retain(|child| {
+            if child.is_choice() {
+                prune_choice(ent, child, options)
+            } else {
+                ent.dir.borrow().iter().any(|e| Rc::ptr_eq(e, child))
+            }
+        });
+    }
+    match options.tree {
+        EntryTree::Config => choice.config,
+        EntryTree::State => {
+            !choice.config
+                || choice
+                    .dir
+                    .borrow()
+                    .iter()
+                    .any(|c| !c.dir.borrow().is_empty())
+        }
+        EntryTree::Full => true,
+    }
+}
+
+/// The help text of a node, if the tree keeps it.
+fn help_of(
+    store: &Builder,
+    description: &Option<String>,
+    reference: &Option<String>,
+    units: &Option<String>,
+) -> Option<Box<EntryHelp>> {
+    if !store.help {
+        return None;
+    }
+    Some(Box::new(EntryHelp {
+        description: description.clone(),
+        reference: reference.clone(),
+        units: units.clone(),
+    }))
+}
+
+/// The help text of a leaf or leaf-list `e`, whose type is resolved:
+/// the description and units it does not set come from the type.
+fn leaf_help(
+    store: &Builder,
+    e: &Entry,
+    description: &Option<String>,
+    reference: &Option<String>,
+    units: &Option<String>,
+) -> Option<Box<EntryHelp>> {
+    let mut help = help_of(store, description, reference, units)?;
+    if let Some(t) = e.type_node.as_ref() {
+        if help.description.is_none() {
+            help.description = t.description.clone();
+        }
+        if help.units.is_none() {
+            help.units = t.units.clone();
+        }
+    }
+    Some(help)
+}
+
+/// Whether the `if-feature` statements of a statement written in `top`
+/// all hold for the features the store enables (RFC 7950 §7.20.2). A
+/// feature whose prefix binds to nothing, or that is not defined, does
+/// not hold.
+fn features_hold<T>(top: &T, store: &Builder, if_feature: &[IfFeatureNode]) -> bool
+where
+    T: ModuleCommon + ?Sized,
+{
+    if_features_hold(top, store, if_feature, &mut Vec::new())
+}
+
+/// The feature `feature` of `module`, with the module or submodule that
+/// defines it.
+fn find_feature<'a>(
+    store: &'a YangStore,
+    module: &str,
+    feature: &str,
+) -> Option<(&'a dyn ModuleCommon, &'a FeatureNode)> {
+    let m = store.find_module(module)?;
+    if let Some(f) = m.feature.iter().find(|f| f.name == feature) {
+        return Some((m, f));
+    }
+    store.submodules_of_module(m).into_iter().find_map(|sub| {
+        let f = sub.feature.iter().find(|f| f.name == feature)?;
+        Some((sub as &dyn ModuleCommon, f))
+    })
+}
+
+/// Whether `feature` of `module` is defined and enabled, and each of
+/// its own `if-feature` statements holds. `visiting` holds the features
+/// being evaluated; one that depends on itself is taken as not
+/// supported.
+pub(crate) fn feature_supported(
+    store: &YangStore,
+    module: &str,
+    feature: &str,
+    visiting: &mut Vec<QualifiedName>,
+) -> bool {
+    let Some((top, f)) = find_feature(store, module, feature) else {
+        return false;
+    };
+    if !store.feature_listed(module, feature) {
+        return false;
+    }
+    let name = QualifiedName::new(module, feature);
+    if visiting.contains(&name) {
+        return false;
+    }
+    visiting.push(name);
+    // The feature's `if-feature` is written with the prefixes of the
+    // module or submodule that defines it.
+    let holds = if_features_hold(top, store, &f.if_feature, visiting);
+    visiting.pop();
+    holds
+}
+
+fn if_features_hold<T>(
+    top: &T,
+    store: &YangStore,
+    if_feature: &[IfFeatureNode],
+    visiting: &mut Vec<QualifiedName>,
+) -> bool
+where
+    T: ModuleCommon + ?Sized,
+{
+    if_feature
+        .iter()
+        .all(|f| feature_expr_holds(top, store, &f.expr, visiting))
+}
+
+fn feature_expr_holds<T>(
+    top: &T,
+    store: &YangStore,
+    expr: &IfFeatureExprNode,
+    visiting: &mut Vec<QualifiedName>,
+) -> bool
+where
+    T: ModuleCommon + ?Sized,
+{
+    match expr {
+        IfFeatureExprNode::Feature(name) => {
+            let Some(f) = qualify(top, store, name) else {
+                return false;
+            };
+            if find_feature(store, &f.module, &f.name).is_none() {
+                store.diag(Diagnostic::UndefinedFeature {
+                    module: top.get_name().to_string(),
+                    name: name.clone(),
+                });
+                return false;
+            }
+            feature_supported(store, &f.module, &f.name, visiting)
+        }
+        IfFeatureExprNode::Not(e) => !feature_expr_holds(top, store, e, visiting),
+        IfFeatureExprNode::And(a, b) => {
+            feature_expr_holds(top, store, a, visiting)
+                && feature_expr_holds(top, store, b, visiting)
+        }
+        IfFeatureExprNode::Or(a, b) => {
+            feature_expr_holds(top, store, a, visiting)
+                || feature_expr_holds(top, store, b, visiting)
+        }
+    }
+}
+
+/// The effective status of a node about to be added under `ent`, with
+/// its own `status` statement if it has one.
+fn status_of(store: &Builder, status: &Option<StatusNode>, ent: &Entry) -> StatusNodeEnum {
+    status
+        .as_ref()
+        .map(|s| s.status)
+        .unwrap_or_default()
+        .within(ent.status)
+        .within(store.status.get())
+}
+
+/// Report a reference from `node`, when the definition being built or
+/// resolved is current, to the `definition` `name` of module `module`
+/// with `status`. Only a reference within the same module is an error
+/// (RFC 7950 §7.21.2).
+fn status_check<T>(
+    top: &T,
+    store: &Builder,
+    node: &str,
+    definition: &str,
+    name: &str,
+    module: &str,
+    status: StatusNodeEnum,
+) where
+    T: ModuleCommon + ?Sized,
+{
+    if store.status.get() != StatusNodeEnum::Current
+        || status == StatusNodeEnum::Current
+        || module != top.get_module_name()
+    {
+        return;
+    }
+    store.diag(Diagnostic::StatusReference {
+        module: top.get_name().to_string(),
+        node: node.to_string(),
+        definition: definition.to_string(),
+        name: name.to_string(),
+        status,
+    });
+}
+
+/// Set the `config` value of `e`, a node about to be added under
+/// `ent`: its own `config` statement if it has one, otherwise the
+/// value it inherits. `config true` below state data is reported, and
+/// the node stays state.
+fn config_of<T>(top: &T, store: &Builder, e: &mut Entry, config: &Option<ConfigNode>, ent: &Entry)
+where
+    T: ModuleCommon + ?Sized,
+{
+    let state_choice = store.state_choice.get();
+    let inherited = ent.config && !state_choice;
+    e.config = match config {
+        Some(config) if config.config && !inherited => {
+            store.diag(Diagnostic::ConfigUnderState {
+                module: top.get_module_name().to_string(),
+                node: e.name.clone(),
+            });
+            false
+        }
+        Some(config) => config.config,
+        None => inherited,
+    };
+    e.config_stmt = match config {
+        Some(config) => Some(config.config),
+        None => state_choice.then_some(false),
+    };
+}
+
+/// The module `module` and its loaded submodules: every (sub)module
+/// whose top-level typedefs, groupings and identities are visible to
+/// one another. `module` is taken at the revision `top` imports it at,
+/// so a definition is found in the revision its user pinned.
+fn namespace<'a, T>(top: &T, store: &'a YangStore, module: &str) -> Vec<&'a dyn ModuleCommon>
+where
+    T: ModuleCommon + ?Sized,
+{
+    let mut members: Vec<&dyn ModuleCommon> = Vec::new();
+    let revision = top
+        .get_import()
+        .iter()
+        .find(|i| i.name == module)
+        .and_then(|i| i.revision_date.as_deref());
+    let Some(m) = store.find_module_revision(module, revision) else {
+        members.extend(
+            store
+                .submodules_of(module)
+                .into_iter()
+                .map(|s| s as &dyn ModuleCommon),
+        );
+        return members;
+    };
+    members.push(m);
+    for sub in store.submodules_of_module(m) {
+        members.push(sub);
+    }
+    members
+}
+
 /// Resolve the name of the module whose schema tree `target` roots
 /// in, as seen from the augmenting module `top`. The leading segment
 /// of an absolute schema-node-identifier carries the prefix that
-/// binds the rest of the path to a module (RFC 7950 §6.5): the
-/// augmenting module's own prefix maps to its own name, an imported
-/// prefix maps to the imported module's name, and a bare (prefixless)
-/// first segment defaults to the augmenting module itself.
-fn augment_target_module<T>(top: &T, target: &str) -> String
+/// binds the rest of the path to a module (RFC 7950 §6.5), resolved
+/// like any other prefix `top` uses; a bare (prefixless) first segment
+/// defaults to the augmenting module itself. `None` means the prefix
+/// binds to nothing, which has been reported.
+fn augment_target_module<T>(top: &T, store: &Builder, target: &str) -> Option<String>
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
     let first = target.split('/').find(|s| !s.is_empty()).unwrap_or("");
     match first.split_once(':') {
-        Some((prefix, _)) => {
-            if Some(prefix) == top.get_prefix() {
-                top.get_name().to_string()
-            } else {
-                // Maps an imported prefix to its module name, or
-                // returns the prefix unchanged when it binds to
-                // nothing — in which case the caller's module-name
-                // comparison fails and the augment is skipped.
-                prefix_resolve(top, prefix.to_string())
-            }
-        }
-        None => top.get_name().to_string(),
+        Some((prefix, _)) => prefix_resolve(top, store, prefix, target),
+        None => Some(top.get_module_name().to_string()),
+    }
+}
+
+/// The namespace that nodes built from definitions written in `top`
+/// go into: while a `uses` is being expanded, the module of the
+/// outermost `uses` (RFC 7950 §7.13), otherwise `top`'s own module.
+fn node_module<T>(top: &T, store: &Builder) -> String
+where
+    T: ModuleCommon + ?Sized,
+{
+    store
+        .uses_namespace
+        .borrow()
+        .clone()
+        .unwrap_or_else(|| top.get_module_name().to_string())
+}
+
+/// Record on `e`, a node built from a statement written in `top`, its
+/// namespace and where it came from.
+fn set_origin<T>(top: &T, store: &Builder, e: &mut Entry)
+where
+    T: ModuleCommon + ?Sized,
+{
+    e.module = node_module(top, store);
+    e.provenance = Provenance {
+        module: top.get_name().to_string(),
+        uses: store.uses_sites.borrow().clone(),
+        augment: store.augment_site.borrow().clone(),
+        deviation: Vec::new(),
+    };
+}
+
+/// Qualify one `[prefix:]name` segment of an augment target written in
+/// `top`. A name in `top`'s own module is in the namespace its nodes
+/// were built into, which differs inside a grouping expansion.
+fn target_segment<T>(top: &T, store: &Builder, seg: &str) -> Option<QualifiedName>
+where
+    T: ModuleCommon + ?Sized,
+{
+    let mut name = qualify(top, store, seg)?;
+    if name.module == top.get_module_name() {
+        name.module = node_module(top, store);
     }
+    Some(name)
 }
 
-/// Walk `target` from `root`, matching each `[prefix:]name` segment
-/// by its bare name against the Entry tree (the tree carries no
-/// per-node namespace, and names are effectively unique within a
-/// container). Returns the resolved entry, or the first segment that
-/// did not match so callers can report where the path broke.
-fn resolve_target(root: Rc<Entry>, target: &str) -> Result<Rc<Entry>, String> {
+/// The child of `ent` named `name` in `name.module`'s namespace.
+fn find_child(ent: &Entry, name: &QualifiedName) -> Option<Rc<Entry>> {
+    ent.dir
+        .borrow()
+        .iter()
+        .find(|e| e.name == name.name && e.module == name.module)
+        .cloned()
+}
+
+/// Walk `target` from `root`, matching each `[prefix:]name` segment,
+/// with its prefix resolved against `top`'s imports, by module and
+/// name. Returns the resolved entry, or the first segment that did not
+/// match so callers can report where the path broke.
+fn resolve_target<T>(
+    top: &T,
+    store: &Builder,
+    root: Rc<Entry>,
+    target: &str,
+) -> Result<Rc<Entry>, String>
+where
+    T: ModuleCommon + ?Sized,
+{
     let mut current = root;
     for seg in target.split('/').filter(|s| !s.is_empty()) {
-        let name = seg.rsplit(':').next().unwrap_or(seg);
-        let next = current
-            .dir
-            .borrow()
-            .iter()
-            .find(|e| e.name == name)
-            .cloned();
+        let next = target_segment(top, store, seg).and_then(|name| find_child(&current, &name));
         match next {
             Some(e) => current = e,
             None => return Err(seg.to_string()),
@@ -277,11 +1199,13 @@ fn resolve_target(root: Rc<Entry>, target: &str) -> Result<Rc<Entry>, String> {
 /// both prevents an augment from bleeding into an unrelated module
 /// that happens to share a node name and keeps the not-found
 /// diagnostic meaningful.
-fn apply_augment<T>(top: &T, store: &YangStore, root: Rc<Entry>, aug: &AugmentNode)
+fn apply_augment<T>(top: &T, store: &Builder, root: Rc<Entry>, aug: &AugmentNode)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    if augment_target_module(top, &aug.target) != root.name {
+    if augment_target_module(top, store, &aug.target).as_deref() != Some(&root.name)
+        || targets_left_out(top, store, &aug.target)
+    {
         return;
     }
     // RFC 7950 §7.17: a top-level augment's target is an
@@ -295,19 +1219,189 @@ where
     resolve_and_inject(top, store, root, aug, AugmentKind::Augment);
 }
 
+/// Apply a `deviation` against the tree rooted at `root`, if it targets
+/// this tree's module. A `not-supported` target is removed; otherwise
+/// the target is replaced by a copy with each `deviate` applied in turn.
+fn apply_deviation<T>(top: &T, store: &Builder, root: &Rc<Entry>, dev: &DeviationNode)
+where
+    T: ModuleCommon + ?Sized,
+{
+    if augment_target_module(top, store, &dev.target).as_deref() != Some(&root.name)
+        || targets_left_out(top, store, &dev.target)
+    {
+        return;
+    }
+    let target = match resolve_target(top, store, root.clone(), &dev.target) {
+        Ok(target) => target,
+        Err(missing) => {
+            store.diag(Diagnostic::DeviationTargetNotFound {
+                module: top.get_name().to_string(),
+                target: dev.target.clone(),
+                missing,
+            });
+            return;
+        }
+    };
+    let Some(parent) = target.parent() else {
+        return;
+    };
+    if dev
+        .deviate
+        .iter()
+        .any(|d| d.kind == DeviateKind::NotSupported)
+    {
+        swap_entry(&parent, &target, None);
+        return;
+    }
+    let mut e = (*target).clone();
+    e.provenance.deviation.push(top.get_name().to_string());
+    let mut config = None;
+    for deviate in dev.deviate.iter() {
+        deviate_entry(top, store, deviate, &mut e);
+        if let Some(c) = deviate.config.as_ref() {
+            config = Some(c.config);
+        }
+    }
+    let e = Rc::new(e);
+    swap_entry(&parent, &target, Some(e.clone()));
+    if let Some(config) = config {
+        set_config(&e, config);
+    }
+}
+
+/// Apply one `deviate add`, `replace` or `delete` to `e`.
+fn deviate_entry<T>(top: &T, store: &Builder, deviate: &DeviateNode, e: &mut Entry)
+where
+    T: ModuleCommon + ?Sized,
+{
+    let delete = deviate.kind == DeviateKind::Delete;
+    if let Some(t) = deviate.type_stmt.as_ref() {
+        // The new type is written in the deviating module, so its
+        // references are resolved there.
+        type_resolve(top, store, t, e);
+    }
+    if let Some(units) = deviate.units.as_ref() {
+        let units = (!delete).then(|| units.clone());
+        if let Some(t) = e.type_node.as_mut() {
+            t.units = units.clone();
+        }
+        if let Some(help) = e.help.as_mut() {
+            help.units = units;
+        }
+    }
+    for must in deviate.must.iter() {
+        e.must.retain(|m| m != &must.name);
+        if !delete {
+            e.must.push(must.name.clone());
+        }
+    }
+    for default in deviate.default.iter() {
+        // A leaf's default is kept on its type; a choice's on itself.
+        let slot = match e.type_node.as_mut() {
+            Some(t) => &mut t.default,
+            None => &mut e.default,
+        };
+        *slot = (!delete).then(|| default.clone());
+    }
+    if let Some(c) = deviate.config.as_ref() {
+        e.config = c.config;
+        e.config_stmt = Some(c.config);
+    }
+    if let Some(m) = deviate.mandatory.as_ref() {
+        e.mandatory = m.mandatory;
+    }
+    if let Some(attr) = e.list_attr.as_mut() {
+        if let Some(min) = deviate.min_elements.as_ref() {
+            attr.min_elements = min.num;
+        }
+        if let Some(max) = deviate.max_elements.as_ref() {
+            attr.max_elements = max.num;
+        }
+    }
+}
+
+/// Set the `config` of the children of `ent`, recursively, as a
+/// deviation of `ent` to `config` requires (RFC 7950 §7.21.1). Under
+/// `config false` every child is state; under `config true` a child
+/// takes the value its own `config` statement sets, and is otherwise
+/// configuration again. A child that already has its value is left as
+/// it is, with everything below it.
+fn set_config(ent: &Rc<Entry>, config: bool) {
+    let children: Vec<Rc<Entry>> = ent.dir.borrow().clone();
+    for child in children {
+        let value = config && child.config_stmt.unwrap_or(true);
+        if child.config == value {
+            continue;
+        }
+        let mut e = (*child).clone();
+        e.config = value;
+        let e = Rc::new(e);
+        swap_entry(ent, &child, Some(e.clone()));
+        set_config(&e, value);
+    }
+}
+
+/// Replace `old`, a child of `parent`, with `new`, or remove it, both
+/// in `parent`'s `dir` and in the cases of `parent`'s choices. The
+/// children and choices of `old` are re-parented to `new`.
+fn swap_entry(parent: &Rc<Entry>, old: &Rc<Entry>, new: Option<Rc<Entry>>) {
+    fn swap_in(dir: &RefCell<Vec<Rc<Entry>>>, old: &Rc<Entry>, new: &Option<Rc<Entry>>) {
+        let mut dir = dir.borrow_mut();
+        match new {
+            Some(new) => {
+                for e in dir.iter_mut().filter(|e| Rc::ptr_eq(e, old)) {
+                    *e = new.clone();
+                }
+            }
+            None => dir.retain(|e| !Rc::ptr_eq(e, old)),
+        }
+    }
+    fn swap_in_choice(choice: &Rc<Entry>, old: &Rc<Entry>, new: &Option<Rc<Entry>>) {
+        for case in choice.dir.borrow().iter() {
+            swap_in(&case.dir, old, new);
+            for nested in case.dir.borrow().iter().filter(|e| e.is_choice()) {
+                swap_in_choice(nested, old, new);
+            }
+        }
+    }
+    swap_in(&parent.dir, old, &new);
+    for choice in parent.choices.borrow().iter() {
+        swap_in_choice(choice, old, &new);
+    }
+    if let Some(new) = new.as_ref() {
+        new.parent.replace(Rc::downgrade(parent));
+        for child in new.dir.borrow().iter().chain(new.choices.borrow().iter()) {
+            child.parent.replace(Rc::downgrade(new));
+        }
+    }
+}
+
 /// Expand a `uses` into `ent`: instantiate the referenced grouping,
 /// then apply any uses-substatement augments. Every site that expands
 /// a `uses` (module/container/list bodies, choice cases, rpc/action
 /// input and output) goes through here so uses-augments are applied
 /// consistently.
-fn uses_entry<T>(top: &T, store: &YangStore, uses: &UsesNode, ent: Rc<Entry>)
+fn uses_entry<T>(top: &T, store: &Builder, uses: &UsesNode, ent: Rc<Entry>)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    group_resolve(top, store, &uses.name, ent.clone());
+    // The grouping's nodes, and those of groupings it uses in turn, go
+    // into the namespace of the outermost `uses`.
+    let outer = store.uses_namespace.borrow().clone();
+    if outer.is_none() {
+        store
+            .uses_namespace
+            .replace(Some(top.get_module_name().to_string()));
+    }
+    // The grouping's nodes are built straight into `ent`, so the
+    // status of the `uses` is marked on the build for them to inherit.
+    let outer_status = store.status.replace(status_of(store, &uses.status, &ent));
+    group_resolve_build(top, store, &uses.name, ent.clone());
     for aug in uses.augment.iter() {
         apply_uses_augment(top, store, ent.clone(), aug);
     }
+    store.status.set(outer_status);
+    store.uses_namespace.replace(outer);
 }
 
 /// Apply a `uses`-substatement augment (RFC 7950 §7.17, descendant
@@ -315,9 +1409,9 @@ where
 /// grouping was just expanded, so resolve from there directly. Unlike
 /// a top-level augment there is no cross-module targeting to gate on —
 /// the augment only ever reaches nodes the grouping contributed.
-fn apply_uses_augment<T>(top: &T, store: &YangStore, ent: Rc<Entry>, aug: &AugmentNode)
+fn apply_uses_augment<T>(top: &T, store: &Builder, ent: Rc<Entry>, aug: &AugmentNode)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
     // RFC 7950 §7.17: a uses-substatement augment's target is a
     // descendant-schema-nodeid (no leading '/').
@@ -337,14 +1431,24 @@ where
 /// not addressable as entries), add the augment's cases to that choice.
 fn resolve_and_inject<T>(
     top: &T,
-    store: &YangStore,
+    store: &Builder,
     root: Rc<Entry>,
     aug: &AugmentNode,
     kind: AugmentKind,
 ) where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    match resolve_target(root.clone(), &aug.target) {
+    if !features_hold(top, store, &aug.if_feature) {
+        return;
+    }
+    let outer = store.augment_site.replace(Some(AugmentSite {
+        module: top.get_name().to_string(),
+        kind,
+        target: aug.target.clone(),
+    }));
+    let status = aug.status.as_ref().map(|s| s.status).unwrap_or_default();
+    let outer_status = store.status.replace(status.within(store.status.get()));
+    match resolve_target(top, store, root.clone(), &aug.target) {
         Ok(current) => inject_augment_body(top, store, current, aug),
         Err(seg) => {
             if !augment_into_choice(top, store, root, aug) {
@@ -357,22 +1461,25 @@ fn resolve_and_inject<T>(
             }
         }
     }
+    store.status.set(outer_status);
+    store.augment_site.replace(outer);
 }
 
 /// Inject an augment body into an already-resolved data node: its
-/// data-def children (RFC 7950 §7.17) and any `action` substatements
-/// (valid when the target is a container or list). Explicit `case`
-/// substatements are handled separately by `augment_into_choice` since
-/// they only apply to choice targets, which are not data nodes.
-fn inject_augment_body<T>(top: &T, store: &YangStore, current: Rc<Entry>, aug: &AugmentNode)
+/// data-def children (RFC 7950 §7.17) and any `action` and
+/// `notification` substatements (valid when the target is a container
+/// or list). Explicit `case` substatements are handled separately by
+/// `augment_into_choice` since they only apply to choice targets,
+/// which are not data nodes.
+fn inject_augment_body<T>(top: &T, store: &Builder, current: Rc<Entry>, aug: &AugmentNode)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
     // RFC 7950 §7.17: data nodes and actions may only be added to a
     // container/list/choice/case/input/output/notification — never to a
-    // leaf or leaf-list. Resolution lands on a leaf only when the
-    // augment is malformed.
-    if current.is_leaf_entry() {
+    // leaf, leaf-list, anydata or anyxml. Resolution lands on one only
+    // when the augment is malformed.
+    if current.is_leaf_entry() || current.is_anydata() || current.is_anyxml() {
         store.diag(Diagnostic::AugmentIntoLeaf {
             module: top.get_name().to_string(),
             target: aug.target.clone(),
@@ -384,23 +1491,27 @@ where
     // Snapshot existing child names so duplicates the augment introduces
     // can be rejected afterwards (RFC 7950 §7.17: an augment MUST NOT add
     // a node with the same name as one already present in the target).
-    let existing: Vec<String> = current
+    // A same-named node in another module's namespace is no duplicate.
+    let existing: Vec<(String, String)> = current
         .dir
         .borrow()
         .iter()
-        .map(|e| e.name.clone())
+        .map(|e| (e.module.clone(), e.name.clone()))
         .collect();
     let before_len = existing.len();
 
-    datadef_entry(top, store, &aug.d, current.clone());
+    datadef_build(top, store, &aug.d, current.clone());
     for a in aug.action.iter() {
-        action_entry(top, store, a, current.clone());
+        action_build(top, store, a, current.clone());
+    }
+    for n in aug.notification.iter() {
+        notification_build(top, store, n, current.clone());
     }
 
     let mut dir = current.dir.borrow_mut();
     let mut i = before_len;
     while i < dir.len() {
-        if existing.contains(&dir[i].name) {
+        if existing.contains(&(dir[i].module.clone(), dir[i].name.clone())) {
             store.diag(Diagnostic::AugmentDuplicateNode {
                 module: top.get_name().to_string(),
                 target: aug.target.clone(),
@@ -413,141 +1524,233 @@ where
     }
 }
 
-/// Handle an augment whose target is a choice. A choice node is not an
-/// addressable entry — `choice_entry` flattens each case's children
+/// Handle an augment whose target is a choice. A choice is not in the
+/// flattened data view — `choice_build` flattens each case's children
 /// into the choice's parent, tagged with the choice/case names — so
-/// resolve the parent (every segment but the last) and treat the final
-/// segment as the choice name. The choice is recognised via the
-/// parent's recorded `choice_defs`, which lists every choice defined
-/// there whether or not it has cases. Returns true if it handled the
-/// augment.
-fn augment_into_choice<T>(top: &T, store: &YangStore, root: Rc<Entry>, aug: &AugmentNode) -> bool
+/// resolve the parent (every segment but the last) and find the final
+/// segment among the choice entries defined there, which exist whether
+/// or not the choice has cases. Returns true if it handled the augment.
+fn augment_into_choice<T>(top: &T, store: &Builder, root: Rc<Entry>, aug: &AugmentNode) -> bool
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
     let segs: Vec<&str> = aug.target.split('/').filter(|s| !s.is_empty()).collect();
     let Some((last, parents)) = segs.split_last() else {
         return false;
     };
-    let choice_name = last.rsplit(':').next().unwrap_or(last);
+    let Some(name) = target_segment(top, store, last) else {
+        return false;
+    };
 
     let mut parent = root;
-    for seg in parents {
-        let name = seg.rsplit(':').next().unwrap_or(seg);
-        let next = parent.dir.borrow().iter().find(|e| e.name == name).cloned();
-        match next {
-            Some(e) => parent = e,
-            None => return false,
+    let mut parents = parents.iter();
+    while let Some(seg) = parents.next() {
+        let Some(seg) = target_segment(top, store, seg) else {
+            return false;
+        };
+        if let Some(e) = find_child(&parent, &seg) {
+            parent = e;
+        } else if find_choice_in(&parent, &seg).is_none() || parents.next().is_none() {
+            // Only a choice and its case may stand between data nodes.
+            return false;
         }
     }
 
-    let is_choice = parent.choice_defs.borrow().iter().any(|n| n == choice_name);
-    if !is_choice {
+    let Some(choice) = find_choice_in(&parent, &name) else {
         return false;
-    }
+    };
 
+    let outer = store.state_choice.replace(!choice.config);
     // Explicit `case` substatements.
     for case in aug.cases.iter() {
-        inject_case(top, store, parent.clone(), choice_name, &case.name, &case.d);
+        inject_case(top, store, parent.clone(), &choice, case);
     }
     // Shorthand cases: each direct data node forms its own case named
     // after the node (RFC 7950 §7.9.2).
-    for c in aug.d.container.iter() {
-        inject_case_node(parent.clone(), choice_name, &c.name, |e| {
-            container_entry(top, store, c, e)
-        });
-    }
-    for leaf in aug.d.leaf.iter() {
-        inject_case_node(parent.clone(), choice_name, &leaf.name, |e| {
-            leaf_entry(top, store, leaf, e)
-        });
-    }
-    for list in aug.d.list.iter() {
-        inject_case_node(parent.clone(), choice_name, &list.name, |e| {
-            list_entry(top, store, list, e)
-        });
-    }
-    for leaf_list in aug.d.leaf_list.iter() {
-        inject_case_node(parent.clone(), choice_name, &leaf_list.name, |e| {
-            leaf_list_entry(top, store, leaf_list, e)
-        });
+    for def in aug.d.ordered() {
+        match def {
+            Datadef::Container(c) => {
+                inject_case_node(top, store, parent.clone(), &choice, &c.name, |e| {
+                    container_build(top, store, c, e)
+                })
+            }
+            Datadef::Leaf(leaf) => {
+                inject_case_node(top, store, parent.clone(), &choice, &leaf.name, |e| {
+                    leaf_entry(top, store, leaf, e)
+                })
+            }
+            Datadef::List(list) => {
+                inject_case_node(top, store, parent.clone(), &choice, &list.name, |e| {
+                    list_entry(top, store, list, e)
+                })
+            }
+            Datadef::LeafList(leaf_list) => {
+                inject_case_node(top, store, parent.clone(), &choice, &leaf_list.name, |e| {
+                    leaf_list_entry(top, store, leaf_list, e)
+                })
+            }
+            _ => {}
+        }
     }
+    store.state_choice.set(outer);
     true
 }
 
+/// The choice `name` defined under `ent`, directly or nested in the
+/// case of another choice there.
+fn find_choice_in(ent: &Entry, name: &QualifiedName) -> Option<Rc<Entry>> {
+    fn search(choices: &[Rc<Entry>], name: &QualifiedName) -> Option<Rc<Entry>> {
+        for choice in choices {
+            if choice.name == name.name && choice.module == name.module {
+                return Some(choice.clone());
+            }
+            for case in choice.dir.borrow().iter() {
+                let nested: Vec<Rc<Entry>> = case
+                    .dir
+                    .borrow()
+                    .iter()
+                    .filter(|e| e.is_choice())
+                    .cloned()
+                    .collect();
+                if let Some(found) = search(&nested, name) {
+                    return Some(found);
+                }
+            }
+        }
+        None
+    }
+    search(&ent.choices.borrow(), name)
+}
+
 /// Inject an explicit `case`'s data-def children into `ent` (the
-/// choice's parent) and tag the newly added entries with the choice
-/// and case names — the flattened representation used throughout for
-/// choice/case membership.
-fn inject_case<T>(
+/// choice's parent), tagged with the choice and case names — the
+/// flattened representation used throughout for choice/case membership
+/// — and add the case to `choice`.
+fn inject_case<T>(top: &T, store: &Builder, ent: Rc<Entry>, choice: &Rc<Entry>, case: &CaseNode)
+where
+    T: ModuleCommon + ?Sized,
+{
+    if !features_hold(top, store, &case.if_feature) {
+        return;
+    }
+    let rc = case_entry(top, store, choice, &case.name, Some(case));
+    let before = (ent.dir.borrow().len(), ent.choices.borrow().len());
+    let outer = store.status.replace(rc.status);
+    datadef_build(top, store, &case.d, ent.clone());
+    store.status.set(outer);
+    fill_case(&ent, choice, &rc, before);
+}
+
+/// Inject a single shorthand-case node via `build`, as a case named
+/// after the node.
+fn inject_case_node<T, F>(
     top: &T,
-    store: &YangStore,
+    store: &Builder,
     ent: Rc<Entry>,
-    choice_name: &str,
+    choice: &Rc<Entry>,
     case_name: &str,
-    d: &DatadefNode,
+    build: F,
 ) where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
+    F: FnOnce(Rc<Entry>),
 {
-    let before = ent.dir.borrow().len();
-    datadef_entry(top, store, d, ent.clone());
-    tag_case_children(&ent, before, choice_name, case_name);
+    let rc = case_entry(top, store, choice, case_name, None);
+    let before = (ent.dir.borrow().len(), ent.choices.borrow().len());
+    let outer = store.status.replace(rc.status);
+    build(ent.clone());
+    store.status.set(outer);
+    fill_case(&ent, choice, &rc, before);
 }
 
-/// Inject a single shorthand-case node via `build` and tag the entries
-/// it added with the choice name and the node's own name (the implicit
-/// case name for a shorthand case).
-fn inject_case_node<F>(ent: Rc<Entry>, choice_name: &str, case_name: &str, build: F)
+/// Add a case entry named `name` to `choice`, from the `case`
+/// statement if there is one (a shorthand case has none).
+fn case_entry<T>(
+    top: &T,
+    store: &Builder,
+    choice: &Rc<Entry>,
+    name: &str,
+    case: Option<&CaseNode>,
+) -> Rc<Entry>
 where
-    F: FnOnce(Rc<Entry>),
+    T: ModuleCommon + ?Sized,
 {
-    let before = ent.dir.borrow().len();
-    build(ent.clone());
-    tag_case_children(&ent, before, choice_name, case_name);
+    let mut e = Entry::new_case(name.to_string());
+    set_origin(top, store, &mut e);
+    e.config = choice.config;
+    e.status = status_of(store, &case.and_then(|c| c.status.clone()), choice);
+    if let Some(case) = case {
+        e.when = case.when.as_ref().map(|w| w.name.clone());
+        e.help = help_of(store, &case.description, &case.reference, &None);
+    }
+    let rc = Rc::new(e);
+    choice.dir.borrow_mut().push(rc.clone());
+    rc.parent.replace(Rc::downgrade(choice));
+    rc
 }
 
-/// Tag entries appended to `ent.dir` at or after index `before` with
-/// the given choice/case names, leaving any already tagged by a nested
-/// choice recursion untouched (innermost wins).
-fn tag_case_children(ent: &Rc<Entry>, before: usize, choice_name: &str, case_name: &str) {
-    let dir = ent.dir.borrow();
-    for child in dir[before..].iter() {
+/// Hand the entries and choices added to `ent` since `before` (the
+/// lengths of its `dir` and `choices`) to `case`. Data nodes are tagged
+/// with the choice and case names, except those a nested choice has
+/// already tagged (innermost wins), which belong to the nested choice's
+/// cases. Nested choices move from `ent` into the case.
+fn fill_case(ent: &Rc<Entry>, choice: &Rc<Entry>, case: &Rc<Entry>, before: (usize, usize)) {
+    for child in ent.dir.borrow()[before.0..].iter() {
         if child.choice.borrow().is_none() {
-            *child.choice.borrow_mut() = Some(choice_name.to_string());
-            *child.case.borrow_mut() = Some(case_name.to_string());
+            *child.choice.borrow_mut() = Some(choice.name.clone());
+            *child.case.borrow_mut() = Some(case.name.clone());
+            case.dir.borrow_mut().push(child.clone());
         }
     }
+    let nested: Vec<Rc<Entry>> = ent.choices.borrow_mut().drain(before.1..).collect();
+    for n in nested {
+        n.parent.replace(Rc::downgrade(case));
+        case.dir.borrow_mut().push(n);
+    }
 }
 
 /// Process a DatadefNode's children (uses, container, leaf, list,
-/// leaf-list, choice) into `ent.dir`. Shared between groupings and
-/// augments.
+/// leaf-list, choice) into `ent.dir`, in the order they were written.
+/// A `uses` contributes the grouping's nodes at its own position.
 pub fn datadef_entry<T>(top: &T, store: &YangStore, d: &DatadefNode, ent: Rc<Entry>)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    for uses in d.uses.iter() {
-        uses_entry(top, store, uses, ent.clone());
-    }
-    for c in d.container.iter() {
-        container_entry(top, store, c, ent.clone());
-    }
-    for leaf in d.leaf.iter() {
-        leaf_entry(top, store, leaf, ent.clone());
-    }
-    for list in d.list.iter() {
-        list_entry(top, store, list, ent.clone());
-    }
-    for leaf_list in d.leaf_list.iter() {
-        leaf_list_entry(top, store, leaf_list, ent.clone());
-    }
-    for choice in d.choice.iter() {
-        choice_entry(top, store, choice, ent.clone());
+    datadef_build(top, &Builder::new(store, &EntryOptions::default()), d, ent)
+}
+
+/// [`datadef_entry`] as part of the build `store` is in the middle of.
+fn datadef_build<T>(top: &T, store: &Builder, d: &DatadefNode, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
+{
+    for def in d.ordered() {
+        if !features_hold(top, store, def.if_feature()) {
+            continue;
+        }
+        match def {
+            Datadef::Uses(uses) => uses_entry(top, store, uses, ent.clone()),
+            Datadef::Container(c) => container_build(top, store, c, ent.clone()),
+            Datadef::Leaf(leaf) => leaf_entry(top, store, leaf, ent.clone()),
+            Datadef::List(list) => list_entry(top, store, list, ent.clone()),
+            Datadef::LeafList(leaf_list) => leaf_list_entry(top, store, leaf_list, ent.clone()),
+            Datadef::Choice(choice) => choice_build(top, store, choice, ent.clone()),
+            Datadef::Anydata(any) => anydata_entry(top, store, any, ent.clone()),
+            Datadef::Anyxml(any) => anyxml_entry(top, store, any, ent.clone()),
+        }
     }
 }
 
 pub trait ModuleCommon {
+    fn as_common(&self) -> &dyn ModuleCommon;
+    /// The scope enclosing this one, for a scope nested in a module;
+    /// `None` for a module or submodule.
+    fn get_parent(&self) -> Option<&dyn ModuleCommon> {
+        None
+    }
     fn get_name(&self) -> &str;
+    /// The module whose namespace these definitions live in: the module
+    /// itself, or for a submodule the module it belongs to.
+    fn get_module_name(&self) -> &str;
     fn get_prefix(&self) -> Option<&str>;
     fn get_identity(&self) -> &Vec<IdentityNode>;
     fn get_identities_mut(&mut self) -> &mut HashMap<String, Vec<String>>;
@@ -560,97 +1763,420 @@ pub trait ModuleCommon {
 
 pub fn group_entry<T>(top: &T, store: &YangStore, g: &GroupingNode, ent: Rc<Entry>)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
+{
+    group_build(top, &Builder::new(store, &EntryOptions::default()), g, ent)
+}
+
+/// [`group_entry`] as part of the build `store` is in the middle of.
+fn group_build<T>(top: &T, store: &Builder, g: &GroupingNode, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
 {
-    datadef_entry(top, store, &g.d, ent);
+    // The grouping's body resolves names where the grouping is
+    // defined, with its own typedefs and groupings innermost.
+    let scope = Scope::enter(top.as_common(), store, &g.name, &g.typedef, &g.grouping);
+    datadef_build(&scope, store, &g.d, ent.clone());
+    for action in g.action.iter() {
+        operation_build(&scope, store, &action.name, &ent, action_build, action);
+    }
+    for notification in g.notification.iter() {
+        let name = &notification.name;
+        operation_build(&scope, store, name, &ent, notification_build, notification);
+    }
+}
+
+/// Build the operation `name` with `build`, unless it would be a
+/// top-level one of a build that leaves those out; that one is only
+/// recorded.
+fn operation_build<T, N>(
+    top: &T,
+    store: &Builder,
+    name: &str,
+    ent: &Rc<Entry>,
+    build: fn(&T, &Builder, &N, Rc<Entry>),
+    node: &N,
+) where
+    T: ModuleCommon + ?Sized,
+{
+    if store.operations || ent.parent().is_some() {
+        build(top, store, node, ent.clone());
+        return;
+    }
+    let name = QualifiedName::new(&node_module(top, store), name);
+    store.left_out.borrow_mut().push(name);
+}
+
+/// Whether `target`, an absolute path written in `top`, leads into a
+/// top-level operation the build left out.
+fn targets_left_out<T>(top: &T, store: &Builder, target: &str) -> bool
+where
+    T: ModuleCommon + ?Sized,
+{
+    let Some(first) = target.split('/').find(|s| !s.is_empty()) else {
+        return false;
+    };
+    target_segment(top, store, first).is_some_and(|name| store.left_out.borrow().contains(&name))
 }
 
 pub fn group_resolve<T>(top: &T, store: &YangStore, name: &str, ent: Rc<Entry>)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    if let Some((m, n)) = path_module(name) {
-        let prefix = prefix_resolve(top, m);
-        if let Some(m) = store.find_module(&prefix) {
-            for g in m.grouping.iter() {
-                if g.name == n {
-                    group_entry(m, store, g, ent);
-                    return;
-                }
+    group_resolve_build(
+        top,
+        &Builder::new(store, &EntryOptions::default()),
+        name,
+        ent,
+    )
+}
+
+/// [`group_resolve`] as part of the build `store` is in the middle of.
+fn group_resolve_build<T>(top: &T, store: &Builder, name: &str, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
+{
+    match lookup::<_, GroupingNode>(top, store, name) {
+        Lookup::Found(scope, g) => {
+            if let Err(cycle) = store.expanding_groupings.enter(g, &g.name) {
+                store.diag(Diagnostic::GroupingCycle {
+                    module: top.get_name().to_string(),
+                    cycle,
+                });
+                return;
             }
+            let status = g.status.as_ref().map(|s| s.status).unwrap_or_default();
+            status_check(
+                top,
+                store,
+                &ent.name,
+                "grouping",
+                name,
+                scope.get_module_name(),
+                status,
+            );
+            store.uses_sites.borrow_mut().push(UsesSite {
+                module: top.get_name().to_string(),
+                grouping: QualifiedName::new(scope.get_module_name(), &g.name),
+            });
+            let outer = store.status.replace(status.within(store.status.get()));
+            group_build(scope, store, g, ent);
+            store.status.set(outer);
+            store.uses_sites.borrow_mut().pop();
+            store.expanding_groupings.leave();
         }
-    } else {
-        for g in top.get_grouping().iter() {
-            if g.name == name {
-                group_entry(top, store, g, ent);
-                return;
+        Lookup::NotFound => store.diag(Diagnostic::UnresolvedGrouping {
+            module: top.get_name().to_string(),
+            node: ent.name.clone(),
+            name: name.to_string(),
+        }),
+        Lookup::UnknownPrefix => {}
+    }
+}
+
+/// The definitions of one kind being expanded, outermost first. Keyed
+/// by address rather than name, since two scopes may each define a
+/// grouping or typedef by the same name.
+#[derive(Debug, Default)]
+pub(crate) struct ExpansionStack(RefCell<Vec<(usize, String)>>);
+
+impl ExpansionStack {
+    /// Record that `def`, named `name`, is being expanded. If it already
+    /// is, it has reached itself: return the names around the cycle,
+    /// starting and ending with the alphabetically first so the same
+    /// cycle reads the same wherever it was entered.
+    fn enter<D>(&self, def: &D, name: &str) -> Result<(), Vec<String>> {
+        let key = def as *const D as usize;
+        let mut stack = self.0.borrow_mut();
+        if let Some(i) = stack.iter().position(|(k, _)| *k == key) {
+            let mut cycle: Vec<String> = stack[i..].iter().map(|(_, n)| n.clone()).collect();
+            let first = (0..cycle.len()).min_by_key(|&j| &cycle[j]).unwrap_or(0);
+            cycle.rotate_left(first);
+            cycle.push(cycle[0].clone());
+            return Err(cycle);
+        }
+        stack.push((key, name.to_string()));
+        Ok(())
+    }
+
+    fn leave(&self) {
+        self.0.borrow_mut().pop();
+    }
+}
+
+/// A definition that is looked up by name through the scopes a
+/// reference is made in.
+trait Definition: Sized {
+    fn find<'a>(scope: &'a dyn ModuleCommon, name: &str) -> Option<&'a Self>;
+}
+
+impl Definition for TypedefNode {
+    fn find<'a>(scope: &'a dyn ModuleCommon, name: &str) -> Option<&'a Self> {
+        scope.get_typedef().iter().find(|t| t.name == name)
+    }
+}
+
+impl Definition for GroupingNode {
+    fn find<'a>(scope: &'a dyn ModuleCommon, name: &str) -> Option<&'a Self> {
+        scope.get_grouping().iter().find(|g| g.name == name)
+    }
+}
+
+enum Lookup<'a, D> {
+    /// The definition, with the scope it is made in.
+    Found(&'a dyn ModuleCommon, &'a D),
+    NotFound,
+    /// The reference's prefix binds to no module; already reported.
+    UnknownPrefix,
+}
+
+/// Find the typedef or grouping a `[prefix:]name` reference made in
+/// `top` refers to. A reference into the referring module itself,
+/// prefixed or not, searches the nearest enclosing scope first and
+/// works outwards (RFC 7950 §5.5); any reference then falls back to the
+/// top level of the module it names, including every submodule of it,
+/// not just those `top` includes.
+fn lookup<'a, T, D>(top: &'a T, store: &'a YangStore, name: &str) -> Lookup<'a, D>
+where
+    T: ModuleCommon + ?Sized,
+    D: Definition,
+{
+    let (module, local) = match path_module(name) {
+        Some((prefix, local)) => match prefix_resolve(top, store, &prefix, name) {
+            Some(module) => (module, local),
+            None => return Lookup::UnknownPrefix,
+        },
+        None => (top.get_module_name().to_string(), name.to_string()),
+    };
+    if module == top.get_module_name() {
+        let mut scope = Some(top.as_common());
+        while let Some(s) = scope {
+            if let Some(d) = D::find(s, &local) {
+                return Lookup::Found(s, d);
             }
+            scope = s.get_parent();
         }
-        for include in top.get_include().iter() {
-            if let Some(submodule) = store.find_submodule(&include.name) {
-                for g in submodule.grouping.iter() {
-                    if g.name == *name {
-                        group_entry(submodule, store, g, ent);
-                        return;
+    }
+    for m in namespace(top, store, &module) {
+        if let Some(d) = D::find(m, &local) {
+            return Lookup::Found(m, d);
+        }
+    }
+    Lookup::NotFound
+}
+
+pub fn identity_resolve<T>(top: &mut T)
+where
+    T: ModuleCommon + ?Sized,
+{
+    // Iterate over node.identity and collect the necessary information
+    let mut identity_bases: HashMap<String, Vec<String>> = HashMap::new();
+    {
+        let identity = top.get_identity();
+        for identity in identity.iter() {
+            if !identity.base.is_empty() {
+                for base in &identity.base {
+                    if let Some((_module, _name)) = path_module(base) {
+                        // TODO: Resolve only local identity reference
+                    } else {
+                        for i in top.get_identity().iter() {
+                            if base == &i.name {
+                                let items = identity_bases.entry(base.clone()).or_default();
+                                items.push(identity.name.clone());
+                            }
+                        }
                     }
                 }
             }
         }
     }
+    for (key, value) in identity_bases.iter() {
+        top.get_identities_mut().insert(key.clone(), value.to_vec());
+    }
+}
+
+/// Record, for each identity `top` defines, the base(s) it derives
+/// from. Bases are qualified against `top`, so a base in an imported
+/// module lands under that module's name.
+pub(crate) fn identity_derive<T>(
+    top: &T,
+    store: &YangStore,
+    derived: &mut BTreeMap<QualifiedName, Vec<QualifiedName>>,
+) where
+    T: ModuleCommon + ?Sized,
+{
+    let store = &Builder::new(store, &EntryOptions::default());
+    for identity in top.get_identity().iter() {
+        let name = QualifiedName::new(top.get_module_name(), &identity.name);
+        let status = identity
+            .status
+            .as_ref()
+            .map(|s| s.status)
+            .unwrap_or_default();
+        let outer = store.status.replace(status);
+        for base in identity.base.iter() {
+            let Some(qualified) = qualify(top, store, base) else {
+                continue;
+            };
+            match find_identity(top, store, &qualified) {
+                Some(b) => status_check(
+                    top,
+                    store,
+                    &identity.name,
+                    "identity",
+                    base,
+                    &qualified.module,
+                    b.status.as_ref().map(|s| s.status).unwrap_or_default(),
+                ),
+                None => store.diag(Diagnostic::UnresolvedBase {
+                    module: top.get_name().to_string(),
+                    node: identity.name.clone(),
+                    name: base.clone(),
+                }),
+            }
+            // Two loaded revisions of a module may both define it.
+            let derived = derived.entry(qualified).or_default();
+            if !derived.contains(&name) {
+                derived.push(name.clone());
+            }
+        }
+        store.status.set(outer);
+    }
+}
+
+/// Qualify a `[prefix:]name` reference written in `top` with the module
+/// it names. A bare name stays in `top`'s module; `None` means the
+/// prefix binds to nothing, which has been reported.
+fn qualify<T>(top: &T, store: &YangStore, name: &str) -> Option<QualifiedName>
+where
+    T: ModuleCommon + ?Sized,
+{
+    match name.split_once(':') {
+        Some((prefix, local)) => {
+            let module = prefix_resolve(top, store, prefix, name)?;
+            Some(QualifiedName::new(&module, local))
+        }
+        None => Some(QualifiedName::new(top.get_module_name(), name)),
+    }
+}
+
+/// Resolve an identityref's `base` to the identity it names and collect
+/// every identity derived from it. The kind stays `Identityref`: the
+/// derived set is kept module-qualified, where an enumeration of bare
+/// names would let two modules' same-named identities collide.
+fn identityref_resolve<T>(
+    top: &T,
+    store: &Builder,
+    node_name: &str,
+    type_node: &TypeNode,
+) -> TypeNode
+where
+    T: ModuleCommon + ?Sized,
+{
+    let mut node = type_node.clone();
+    let Some(name) = type_node.base.as_ref() else {
+        return node;
+    };
+    if let Some(base) = qualify(top, store, name) {
+        match find_identity(top, store, &base) {
+            Some(identity) => status_check(
+                top,
+                store,
+                node_name,
+                "identity",
+                name,
+                &base.module,
+                identity
+                    .status
+                    .as_ref()
+                    .map(|s| s.status)
+                    .unwrap_or_default(),
+            ),
+            None => store.diag(Diagnostic::UnresolvedIdentity {
+                module: top.get_name().to_string(),
+                node: node_name.to_string(),
+                name: name.clone(),
+            }),
+        }
+        node.identities = store.derived_identities(&base);
+        node.identity_base = Some(base);
+    }
+    node
 }
 
-pub fn identity_resolve<T>(top: &mut T)
+/// The identity `name`, defined by its module or one of its
+/// submodules.
+fn find_identity<'a, T>(
+    top: &T,
+    store: &'a YangStore,
+    name: &QualifiedName,
+) -> Option<&'a IdentityNode>
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    // Iterate over node.identity and collect the necessary information
-    let mut identity_bases: HashMap<String, Vec<String>> = HashMap::new();
-    {
-        let identity = top.get_identity();
-        for identity in identity.iter() {
-            if !identity.base.is_empty() {
-                for base in &identity.base {
-                    if let Some((_module, _name)) = path_module(base) {
-                        // TODO: Resolve only local identity reference
-                    } else {
-                        for i in top.get_identity().iter() {
-                            if base == &i.name {
-                                let items = identity_bases.entry(base.clone()).or_default();
-                                items.push(identity.name.clone());
-                            }
-                        }
-                    }
-                }
-            }
-        }
+    namespace(top, store, &name.module)
+        .into_iter()
+        .find_map(|m| m.get_identity().iter().find(|i| i.name == name.name))
+}
+
+/// Map a prefix used in `top` to the name of the module it binds
+/// (RFC 7950 §7.1.4). `top`'s own prefix — for a submodule, the one
+/// its `belongs-to` declares — names `top`'s own module, and an
+/// imported prefix names the imported module. Every prefixed reference
+/// (typedef, grouping, identity, leafref path, augment target) resolves
+/// through here, so a prefix that binds to nothing is reported once,
+/// as `UnknownPrefix` naming `reference`, rather than making the lookup
+/// quietly find nothing.
+fn prefix_resolve<T>(top: &T, store: &YangStore, prefix: &str, reference: &str) -> Option<String>
+where
+    T: ModuleCommon + ?Sized,
+{
+    if Some(prefix) == top.get_prefix() {
+        return Some(top.get_module_name().to_string());
     }
-    for (key, value) in identity_bases.iter() {
-        top.get_identities_mut().insert(key.clone(), value.to_vec());
+    for import in top.get_import().iter() {
+        if import.prefix.as_deref() == Some(prefix) {
+            return Some(import.name.clone());
+        }
     }
+    store.diag(Diagnostic::UnknownPrefix {
+        module: top.get_name().to_string(),
+        prefix: prefix.to_string(),
+        reference: reference.to_string(),
+    });
+    None
 }
 
-fn prefix_resolve<T>(node: &T, name: String) -> String
+/// Check that every prefix in a leafref `path` binds to a module.
+/// The path is not otherwise resolved here; this only keeps a typo in
+/// a prefix from going unnoticed.
+fn leafref_resolve<T>(top: &T, store: &Builder, path: &str)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    for import in node.get_import().iter() {
-        if let Some(prefix) = &import.prefix
-            && name == *prefix
+    let is_name = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ':');
+    for token in path.split(|c: char| !is_name(c)) {
+        if let Some((prefix, _)) = token.split_once(':')
+            && !prefix.is_empty()
         {
-            return import.name.clone();
+            prefix_resolve(top, store, prefix, path);
         }
     }
-    name
 }
 
-fn type_union_resolve<T>(top: &T, store: &YangStore, type_node: &TypeNode) -> Option<TypeNode>
+fn type_union_resolve<T>(
+    top: &T,
+    store: &Builder,
+    node_name: &str,
+    type_node: &TypeNode,
+) -> Option<TypeNode>
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
     let mut nodes = Vec::<TypeNode>::new();
     for node in type_node.union.iter() {
         if node.kind == YangType::Path {
-            if let Some(n) = type_path_resolve(top, store, node) {
+            if let Some(n) = type_path_resolve(top, store, node_name, node) {
                 if n.kind == YangType::Union {
                     // A typedef'd arm whose base is itself a union
                     // (e.g. `type union { type some-union; … }`).
@@ -685,7 +2211,7 @@ where
             // the previous drop-on-the-floor behavior was the reason
             // inline pattern-restricted string arms in unions never
             // engaged.
-            nodes.push(node.clone());
+            nodes.push(builtin_resolve(top, store, node_name, node));
         }
     }
     let mut type_node = type_node.clone();
@@ -693,87 +2219,254 @@ where
     Some(type_node)
 }
 
-fn type_path_resolve<T>(top: &T, store: &YangStore, type_node: &TypeNode) -> Option<TypeNode>
+/// A built-in type (an inline union arm, or a typedef's underlying
+/// type) needs no lookup, except that an identityref still has its
+/// derived identities collected and a leafref has its path prefixes
+/// checked.
+fn builtin_resolve<T>(top: &T, store: &Builder, node_name: &str, node: &TypeNode) -> TypeNode
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    if let Some((module, name)) = path_module(&type_node.name) {
-        let prefix = prefix_resolve(top, module);
-        let module = store.find_module(&prefix);
-        if let Some(m) = module {
-            for typedef in m.typedef.iter() {
-                if typedef.name == name
-                    && let Some(node) = &typedef.type_node
-                {
-                    let mut node = node.clone();
-                    node.typedef = Some(type_node.name.clone());
-                    if node.kind == YangType::Union {
-                        return type_union_resolve(top, store, &node);
-                    } else {
-                        return Some(node);
-                    }
-                }
+    match node.kind {
+        YangType::Identityref => identityref_resolve(top, store, node_name, node),
+        YangType::Leafref => {
+            if let Some(path) = &node.path {
+                leafref_resolve(top, store, path);
             }
+            node.clone()
         }
-    } else {
-        for typedef in top.get_typedef().iter() {
-            if typedef.name == type_node.name
-                && let Some(node) = &typedef.type_node
-            {
-                let mut node = node.clone();
-                node.typedef = Some(type_node.name.clone());
-                if node.kind == YangType::Union {
-                    // A local typedef whose underlying type is a
-                    // union: resolve its Path arms the same way
-                    // the prefixed branch does, otherwise a leaf
-                    // like `type peer-id-or-all` reaches the
-                    // matcher with every arm still `kind = Path`
-                    // and nothing dispatches.
-                    return type_union_resolve(top, store, &node);
-                }
-                return Some(node);
+        _ => node.clone(),
+    }
+}
+
+fn type_path_resolve<T>(
+    top: &T,
+    store: &Builder,
+    node_name: &str,
+    type_node: &TypeNode,
+) -> Option<TypeNode>
+where
+    T: ModuleCommon + ?Sized,
+{
+    match lookup::<_, TypedefNode>(top, store, &type_node.name) {
+        Lookup::Found(scope, typedef) => {
+            let status = typedef
+                .status
+                .as_ref()
+                .map(|s| s.status)
+                .unwrap_or_default();
+            status_check(
+                top,
+                store,
+                node_name,
+                "typedef",
+                &type_node.name,
+                scope.get_module_name(),
+                status,
+            );
+            if let Err(cycle) = store.resolving_typedefs.enter(typedef, &typedef.name) {
+                store.diag(Diagnostic::TypedefCycle {
+                    module: top.get_name().to_string(),
+                    cycle,
+                });
+                return None;
             }
+            let base = typedef_resolve(scope, store, typedef, type_node);
+            store.resolving_typedefs.leave();
+            Some(restrict(top, store, node_name, base?, type_node))
         }
+        Lookup::NotFound => {
+            store.diag(Diagnostic::UnresolvedTypedef {
+                module: top.get_name().to_string(),
+                node: node_name.to_string(),
+                name: type_node.name.clone(),
+            });
+            None
+        }
+        Lookup::UnknownPrefix => None,
     }
-    None
 }
 
-fn type_resolve<T>(top: &T, store: &YangStore, type_node: &TypeNode, ent: &mut Entry)
+/// The type `type_node` (a reference to `typedef`) stands for. `top`
+/// is the scope defining the typedef: the names inside its type are
+/// resolved there, with that module's prefixes.
+fn typedef_resolve<T>(
+    top: &T,
+    store: &Builder,
+    typedef: &TypedefNode,
+    type_node: &TypeNode,
+) -> Option<TypeNode>
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    if type_node.kind == YangType::Path {
-        if let Some(node) = type_path_resolve(top, store, type_node) {
-            ent.type_node = Some(node);
+    let mut node = typedef.type_node.as_ref()?.clone();
+    node.typedef = Some(type_node.name.clone());
+    // The typedef's own type is checked against the typedef's status.
+    let status = typedef
+        .status
+        .as_ref()
+        .map(|s| s.status)
+        .unwrap_or_default();
+    let outer = store.status.replace(status);
+    let node = match node.kind {
+        // A typedef whose underlying type is a union: resolve its Path
+        // arms too, otherwise a leaf like `type peer-id-or-all` reaches
+        // the matcher with every arm still `kind = Path` and nothing
+        // dispatches.
+        YangType::Union => type_union_resolve(top, store, &typedef.name, &node),
+        // A typedef of another typedef: follow the chain, written with
+        // this typedef's module's prefixes. The innermost typedef is
+        // the one named in `typedef`, as it is the one that fixes the
+        // built-in type.
+        YangType::Path => type_path_resolve(top, store, &typedef.name, &node),
+        _ => Some(builtin_resolve(top, store, &typedef.name, &node)),
+    };
+    store.status.set(outer);
+    let mut node = node?;
+    node.typedef_chain.insert(0, type_node.name.clone());
+    // A derived typedef's units and default override its base's
+    // (RFC 7950 §7.3.3, §7.3.4).
+    if typedef.units.is_some() {
+        node.units = typedef.units.clone();
+    }
+    if typedef.default.is_some() {
+        node.default = typedef.default.clone();
+    }
+    if store.help && typedef.description.is_some() {
+        node.description = typedef.description.clone();
+    }
+    Some(node)
+}
+
+/// Apply the restrictions written on `derived` — a reference to a
+/// typedef, possibly with its own `range`, `length` or `pattern` — to
+/// `base`, the type that typedef resolved to. A derived type may only
+/// narrow its base (RFC 7950 §9.2.4, §9.4.4): a range or length that
+/// reaches outside the base's is reported and cut back to the values
+/// both admit, and its `min` and `max` are the base's bounds. Patterns
+/// accumulate, and a value must match them all.
+fn restrict<T>(
+    top: &T,
+    store: &Builder,
+    node_name: &str,
+    mut base: TypeNode,
+    derived: &TypeNode,
+) -> TypeNode
+where
+    T: ModuleCommon + ?Sized,
+{
+    if let Some(text) = &derived.range_arg
+        && base.kind.is_integer()
+    {
+        match RangeNode::parse(text, base.kind) {
+            Some(range) => {
+                let range = narrow(top, store, node_name, "range", range, base.range.take());
+                base.range = Some(range);
+            }
+            None => store.diag(Diagnostic::RestrictionNotSubset {
+                module: top.get_name().to_string(),
+                node: node_name.to_string(),
+                restriction: "range".to_string(),
+                value: text.clone(),
+                base: base
+                    .range
+                    .as_ref()
+                    .map(|r| r.to_string())
+                    .unwrap_or_else(|| "<min..max>".to_string()),
+            }),
         }
-    } else if type_node.kind == YangType::Identityref {
-        if let Some(base) = &type_node.base {
-            if let Some((module, name)) = path_module(base) {
-                let prefix = prefix_resolve(top, module);
-                let module = store.find_module(&prefix);
-                if let Some(m) = module {
-                    let mut node = type_node.clone();
-                    node.kind = YangType::Enumeration;
-                    if let Some(identities) = m.identities.get(&name) {
-                        for i in identities.iter() {
-                            node.enum_stmt.push(EnumNode { name: i.clone() });
-                        }
-                    }
-                    ent.type_node = Some(node);
-                    return;
-                } else {
-                    // println!("XXX: module not found {}", name);
+    }
+    // RangeNode has no decimal64 width, so a decimal64 range is
+    // narrowed and kept as text.
+    if let Some(text) = &derived.range_arg
+        && base.kind == YangType::Decimal64
+    {
+        let base_text = base.range_arg.as_deref();
+        match decimal_narrow(text, base_text, base.fraction_digits) {
+            Some((range, within)) => {
+                if !within {
+                    store.diag(Diagnostic::RestrictionNotSubset {
+                        module: top.get_name().to_string(),
+                        node: node_name.to_string(),
+                        restriction: "range".to_string(),
+                        value: format!("<{text}>"),
+                        base: format!("<{}>", base_text.unwrap_or("min..max")),
+                    });
                 }
-            } else {
-                // println!("XXX: self {}", base);
+                base.range_arg = Some(range);
             }
+            None => store.diag(Diagnostic::RestrictionNotSubset {
+                module: top.get_name().to_string(),
+                node: node_name.to_string(),
+                restriction: "range".to_string(),
+                value: text.clone(),
+                base: format!("<{}>", base_text.unwrap_or("min..max")),
+            }),
+        }
+    }
+    if let Some(length) = &derived.length {
+        let length = narrow(
+            top,
+            store,
+            node_name,
+            "length",
+            length.clone(),
+            base.length.take(),
+        );
+        base.length = Some(length);
+    }
+    base.patterns.extend(derived.patterns.iter().cloned());
+    if derived.pattern.is_some() {
+        base.pattern = derived.pattern.clone();
+    }
+    base
+}
+
+/// Narrow `base` (if the base type has such a restriction at all) to
+/// `derived`, reporting a `derived` that is not within it.
+fn narrow<T>(
+    top: &T,
+    store: &Builder,
+    node_name: &str,
+    restriction: &str,
+    derived: RangeNode,
+    base: Option<RangeNode>,
+) -> RangeNode
+where
+    T: ModuleCommon + ?Sized,
+{
+    let Some(base) = base else {
+        return derived;
+    };
+    let resolved = derived.resolve(&base);
+    if resolved.is_subset(&base) {
+        return resolved;
+    }
+    store.diag(Diagnostic::RestrictionNotSubset {
+        module: top.get_name().to_string(),
+        node: node_name.to_string(),
+        restriction: restriction.to_string(),
+        value: derived.to_string(),
+        base: base.to_string(),
+    });
+    resolved.intersect(&base)
+}
+
+fn type_resolve<T>(top: &T, store: &Builder, type_node: &TypeNode, ent: &mut Entry)
+where
+    T: ModuleCommon + ?Sized,
+{
+    // The references in the type are checked against the node's status.
+    let outer = store.status.replace(ent.status);
+    if type_node.kind == YangType::Path {
+        if let Some(node) = type_path_resolve(top, store, &ent.name, type_node) {
+            ent.type_node = Some(node);
         }
-        ent.type_node = Some(type_node.clone());
     } else if type_node.kind == YangType::Union {
         let mut union_node = TypeNode::new(type_node.name.clone(), YangType::Union);
         for node in type_node.union.iter() {
             if node.kind == YangType::Path {
-                if let Some(node) = type_path_resolve(top, store, node) {
+                if let Some(node) = type_path_resolve(top, store, &ent.name, node) {
                     union_node.union.push(node);
                 }
             } else {
@@ -784,167 +2477,285 @@ where
                 // scalar / patterned-string arms silently disappear and
                 // a union like `union { uint32; inet:ipv4-address; }`
                 // only matches the ipv4-address arm.
-                union_node.union.push(node.clone());
+                union_node
+                    .union
+                    .push(builtin_resolve(top, store, &ent.name, node));
             }
         }
         ent.type_node = Some(union_node);
     } else {
-        ent.type_node = Some(type_node.clone());
+        ent.type_node = Some(builtin_resolve(top, store, &ent.name, type_node));
     }
+    store.status.set(outer);
+}
+
+/// Why `ent` cannot hold an action or notification, if it cannot: one
+/// of its ancestors (itself included) is an rpc, action, notification
+/// or keyless list. A top-level node, whose parent is the root, is an
+/// rpc when it is an action.
+fn operation_enclosing(ent: &Rc<Entry>) -> Option<Enclosing> {
+    let mut node = Some(ent.clone());
+    while let Some(e) = node {
+        let parent = e.parent();
+        if e.is_action() {
+            return Some(match parent.as_ref().and_then(|p| p.parent()) {
+                Some(_) => Enclosing::Action(e.name.clone()),
+                None => Enclosing::Rpc(e.name.clone()),
+            });
+        }
+        if e.is_notification() {
+            return Some(Enclosing::Notification(e.name.clone()));
+        }
+        if e.is_list() && e.key.is_empty() {
+            return Some(Enclosing::KeylessList(e.name.clone()));
+        }
+        node = parent;
+    }
+    None
+}
+
+/// Report an `action` or `notification` that may not be defined under
+/// `ent`, and say whether it may.
+fn operation_allowed<T>(
+    top: &T,
+    store: &Builder,
+    statement: &str,
+    name: &str,
+    ent: &Rc<Entry>,
+) -> bool
+where
+    T: ModuleCommon + ?Sized,
+{
+    let within = match operation_enclosing(ent) {
+        Some(within) => within,
+        None if statement == "action" && ent.parent().is_none() => Enclosing::TopLevel,
+        None => return true,
+    };
+    store.diag(Diagnostic::MisplacedOperation {
+        module: top.get_name().to_string(),
+        statement: statement.to_string(),
+        name: name.to_string(),
+        within,
+    });
+    false
 }
 
 pub fn action_entry<T>(top: &T, store: &YangStore, a: &ActionNode, ent: Rc<Entry>)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
+{
+    action_build(top, &Builder::new(store, &EntryOptions::default()), a, ent)
+}
+
+/// [`action_entry`] as part of the build `store` is in the middle of.
+fn action_build<T>(top: &T, store: &Builder, a: &ActionNode, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
+{
+    if features_hold(top, store, &a.if_feature)
+        && operation_allowed(top, store, "action", &a.name, &ent)
+    {
+        operation_entry(top, store, a, ent);
+    }
+}
+
+/// Build a top-level `rpc` under the root `ent`.
+pub fn rpc_entry<T>(top: &T, store: &YangStore, a: &ActionNode, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
 {
-    let e = Entry::new_action(a.name.clone());
+    rpc_build(top, &Builder::new(store, &EntryOptions::default()), a, ent)
+}
+
+/// [`rpc_entry`] as part of the build `store` is in the middle of.
+fn rpc_build<T>(top: &T, store: &Builder, a: &ActionNode, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
+{
+    if features_hold(top, store, &a.if_feature) {
+        operation_entry(top, store, a, ent);
+    }
+}
+
+fn operation_entry<T>(top: &T, store: &Builder, a: &ActionNode, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
+{
+    let mut e = Entry::new_action(a.name.clone());
+    set_origin(top, store, &mut e);
+    e.config = ent.config;
+    e.status = status_of(store, &a.status, &ent);
+    e.help = help_of(store, &a.description, &a.reference, &None);
     let rc = Rc::new(e);
+    rc.parent.replace(Rc::downgrade(&ent));
+    let scope = Scope::enter(top.as_common(), store, &a.name, &a.typedef, &a.grouping);
 
     // Process input parameters if present
     if let Some(input) = &a.input {
         let mut input_entry = Entry::new_dir("input".to_string());
+        input_entry.module = rc.module.clone();
+        input_entry.provenance = rc.provenance.clone();
+        input_entry.config = rc.config;
+        input_entry.status = rc.status;
         input_entry
             .extension
             .insert("input".to_string(), "true".to_string());
         let input_rc = Rc::new(input_entry);
+        input_rc.parent.replace(Rc::downgrade(&rc));
 
         // Process input data definitions
-        for uses in input.d.uses.iter() {
-            uses_entry(top, store, uses, input_rc.clone());
-        }
-        for c in input.d.container.iter() {
-            container_entry(top, store, c, input_rc.clone());
-        }
-        for leaf in input.d.leaf.iter() {
-            leaf_entry(top, store, leaf, input_rc.clone());
-        }
-        for list in input.d.list.iter() {
-            list_entry(top, store, list, input_rc.clone());
-        }
-        for leaf_list in input.d.leaf_list.iter() {
-            leaf_list_entry(top, store, leaf_list, input_rc.clone());
-        }
-        for choice in input.d.choice.iter() {
-            choice_entry(top, store, choice, input_rc.clone());
-        }
+        let input_scope = Scope::enter(&scope, store, "input", &input.typedef, &input.grouping);
+        datadef_build(&input_scope, store, &input.d, input_rc.clone());
 
-        rc.dir.borrow_mut().push(input_rc.clone());
-        input_rc.parent.replace(Some(rc.clone()));
+        rc.dir.borrow_mut().push(input_rc);
     }
 
     // Process output parameters if present
     if let Some(output) = &a.output {
         let mut output_entry = Entry::new_dir("output".to_string());
+        output_entry.module = rc.module.clone();
+        output_entry.provenance = rc.provenance.clone();
+        output_entry.config = rc.config;
+        output_entry.status = rc.status;
         output_entry
             .extension
             .insert("output".to_string(), "true".to_string());
         let output_rc = Rc::new(output_entry);
+        output_rc.parent.replace(Rc::downgrade(&rc));
 
         // Process output data definitions
-        for uses in output.d.uses.iter() {
-            uses_entry(top, store, uses, output_rc.clone());
-        }
-        for c in output.d.container.iter() {
-            container_entry(top, store, c, output_rc.clone());
-        }
-        for leaf in output.d.leaf.iter() {
-            leaf_entry(top, store, leaf, output_rc.clone());
-        }
-        for list in output.d.list.iter() {
-            list_entry(top, store, list, output_rc.clone());
-        }
-        for leaf_list in output.d.leaf_list.iter() {
-            leaf_list_entry(top, store, leaf_list, output_rc.clone());
-        }
-        for choice in output.d.choice.iter() {
-            choice_entry(top, store, choice, output_rc.clone());
-        }
+        let output_scope = Scope::enter(&scope, store, "output", &output.typedef, &output.grouping);
+        datadef_build(&output_scope, store, &output.d, output_rc.clone());
 
-        rc.dir.borrow_mut().push(output_rc.clone());
-        output_rc.parent.replace(Some(rc.clone()));
+        rc.dir.borrow_mut().push(output_rc);
     }
 
-    ent.dir.borrow_mut().push(rc.clone());
-    rc.parent.replace(Some(ent.clone()));
+    ent.dir.borrow_mut().push(rc);
 }
 
-pub fn choice_entry<T>(top: &T, store: &YangStore, c: &ChoiceNode, ent: Rc<Entry>)
+pub fn notification_entry<T>(top: &T, store: &YangStore, n: &NotificationNode, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
+{
+    notification_build(top, &Builder::new(store, &EntryOptions::default()), n, ent)
+}
+
+/// [`notification_entry`] as part of the build `store` is in the middle of.
+fn notification_build<T>(top: &T, store: &Builder, n: &NotificationNode, ent: Rc<Entry>)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    if let Some(config) = &c.config
-        && !config.config
+    if !features_hold(top, store, &n.if_feature)
+        || !operation_allowed(top, store, "notification", &n.name, &ent)
     {
         return;
     }
+    let mut e = Entry::new_notification(n.name.clone());
+    set_origin(top, store, &mut e);
+    e.config = ent.config;
+    e.status = status_of(store, &n.status, &ent);
+    e.help = help_of(store, &n.description, &n.reference, &None);
+    let rc = Rc::new(e);
+    rc.parent.replace(Rc::downgrade(&ent));
+
+    let scope = Scope::enter(top.as_common(), store, &n.name, &n.typedef, &n.grouping);
+    datadef_build(&scope, store, &n.d, rc.clone());
+
+    ent.dir.borrow_mut().push(rc);
+}
+
+pub fn choice_entry<T>(top: &T, store: &YangStore, c: &ChoiceNode, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
+{
+    choice_build(top, &Builder::new(store, &EntryOptions::default()), c, ent)
+}
 
-    // Record the choice name on its parent so it stays addressable for
+/// [`choice_entry`] as part of the build `store` is in the middle of.
+fn choice_build<T>(top: &T, store: &Builder, c: &ChoiceNode, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
+{
+    let mut e = Entry::new_choice(c.name.clone());
+    set_origin(top, store, &mut e);
+    config_of(top, store, &mut e, &c.config, &ent);
+    e.status = status_of(store, &c.status, &ent);
+    e.help = help_of(store, &c.description, &c.reference, &None);
+    e.mandatory = c.mandatory.as_ref().is_some_and(|m| m.mandatory);
+    e.when = c.when.as_ref().map(|w| w.name.clone());
+    e.default = c.default.clone();
+    let choice = Rc::new(e);
+
+    // Record the choice on its parent so it stays addressable for
     // augments even before (or without) any case contributing children.
-    ent.choice_defs.borrow_mut().push(c.name.clone());
+    ent.choice_defs
+        .borrow_mut()
+        .push(QualifiedName::new(&choice.module, &c.name));
+    ent.choices.borrow_mut().push(choice.clone());
+    choice.parent.replace(Rc::downgrade(&ent));
 
     // Per RFC 7950 §7.9.2, neither the `choice` node nor its `case`
     // nodes appear in the data tree — only the case's direct data
     // children do. We flatten each case's children into the choice's
     // parent `ent.dir` and tag each added entry with (choice, case)
-    // metadata so consumers can enforce mutual exclusion later. The
-    // same flattening is reused by `augment_into_choice`.
+    // metadata so consumers can enforce mutual exclusion later; the
+    // choice and case entries keep the structure. The same flattening
+    // is reused by `augment_into_choice`.
+    //
+    // The cases' children are built straight into `ent`, so a state
+    // choice is marked on the build for them to inherit from.
+    let outer = store.state_choice.replace(!choice.config);
     for case in c.cases.iter() {
-        inject_case(top, store, ent.clone(), &c.name, &case.name, &case.d);
+        inject_case(top, store, ent.clone(), &choice, case);
     }
+    store.state_choice.set(outer);
 }
 
 pub fn container_entry<T>(top: &T, store: &YangStore, c: &ContainerNode, ent: Rc<Entry>)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
+{
+    container_build(top, &Builder::new(store, &EntryOptions::default()), c, ent)
+}
+
+/// [`container_entry`] as part of the build `store` is in the middle of.
+fn container_build<T>(top: &T, store: &Builder, c: &ContainerNode, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
 {
-    if let Some(config) = &c.config
-        && !config.config
-    {
-        return;
-    }
     let mut e = Entry::new_dir(c.name.clone());
+    set_origin(top, store, &mut e);
+    config_of(top, store, &mut e, &c.config, &ent);
+    e.status = status_of(store, &c.status, &ent);
+    e.help = help_of(store, &c.description, &c.reference, &None);
     for u in c.unknown.iter() {
         e.extension.insert(u.name.clone(), u.argument.clone());
     }
     e.presence = c.presence.is_some();
     let rc = Rc::new(e);
+    rc.parent.replace(Rc::downgrade(&ent));
 
-    for uses in c.d.uses.iter() {
-        uses_entry(top, store, uses, rc.clone());
-    }
-    for c in c.d.container.iter() {
-        container_entry(top, store, c, rc.clone());
-    }
-    for leaf in c.d.leaf.iter() {
-        leaf_entry(top, store, leaf, rc.clone());
-    }
-    for list in c.d.list.iter() {
-        list_entry(top, store, list, rc.clone());
-    }
-    for leaf_list in c.d.leaf_list.iter() {
-        leaf_list_entry(top, store, leaf_list, rc.clone());
-    }
-    for choice in c.d.choice.iter() {
-        choice_entry(top, store, choice, rc.clone());
-    }
+    let scope = Scope::enter(top.as_common(), store, &c.name, &c.typedef, &c.grouping);
+    datadef_build(&scope, store, &c.d, rc.clone());
     for action in c.action.iter() {
-        action_entry(top, store, action, rc.clone());
+        action_build(&scope, store, action, rc.clone());
+    }
+    for notification in c.notification.iter() {
+        notification_build(&scope, store, notification, rc.clone());
     }
 
-    ent.dir.borrow_mut().push(rc.clone());
-    rc.parent.replace(Some(ent.clone()));
+    ent.dir.borrow_mut().push(rc);
 }
 
-fn list_entry<T>(top: &T, store: &YangStore, l: &ListNode, ent: Rc<Entry>)
+fn list_entry<T>(top: &T, store: &Builder, l: &ListNode, ent: Rc<Entry>)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    if let Some(config) = &l.config
-        && !config.config
-    {
-        return;
-    }
     let mut e = Entry::new_list(l.name.clone(), l.key.keys.clone());
+    set_origin(top, store, &mut e);
+    config_of(top, store, &mut e, &l.config, &ent);
+    e.status = status_of(store, &l.status, &ent);
+    e.help = help_of(store, &l.description, &l.reference, &None);
     for u in l.unknown.iter() {
         if u.name == "ext:presence" {
             e.presence = true;
@@ -954,40 +2765,28 @@ where
     let list_attr = ListAttr::new();
     e.list_attr = Some(list_attr);
     let rc = Rc::new(e);
+    rc.parent.replace(Rc::downgrade(&ent));
 
-    for uses in l.d.uses.iter() {
-        uses_entry(top, store, uses, rc.clone());
-    }
-    for c in l.d.container.iter() {
-        container_entry(top, store, c, rc.clone());
-    }
-    for leaf in l.d.leaf.iter() {
-        leaf_entry(top, store, leaf, rc.clone());
-    }
-    for list in l.d.list.iter() {
-        list_entry(top, store, list, rc.clone());
+    let scope = Scope::enter(top.as_common(), store, &l.name, &l.typedef, &l.grouping);
+    datadef_build(&scope, store, &l.d, rc.clone());
+    for action in l.action.iter() {
+        action_build(&scope, store, action, rc.clone());
     }
-    for leaf_list in l.d.leaf_list.iter() {
-        leaf_list_entry(top, store, leaf_list, rc.clone());
-    }
-    for choice in l.d.choice.iter() {
-        choice_entry(top, store, choice, rc.clone());
+    for notification in l.notification.iter() {
+        notification_build(&scope, store, notification, rc.clone());
     }
 
-    ent.dir.borrow_mut().push(rc.clone());
-    rc.parent.replace(Some(ent.clone()));
+    ent.dir.borrow_mut().push(rc);
 }
 
-fn leaf_entry<T>(top: &T, store: &YangStore, leaf: &LeafNode, ent: Rc<Entry>)
+fn leaf_entry<T>(top: &T, store: &Builder, leaf: &LeafNode, ent: Rc<Entry>)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    if let Some(config) = &leaf.config
-        && !config.config
-    {
-        return;
-    }
     let mut e = Entry::new_leaf(leaf.name.to_owned());
+    set_origin(top, store, &mut e);
+    config_of(top, store, &mut e, &leaf.config, &ent);
+    e.status = status_of(store, &leaf.status, &ent);
     e.mandatory = leaf.is_mandatory();
     for u in leaf.unknown.iter() {
         e.extension.insert(u.name.clone(), u.argument.clone());
@@ -995,40 +2794,86 @@ where
     if let Some(t) = leaf.type_stmt.as_ref() {
         type_resolve(top, store, t, &mut e);
     }
+    // The leaf's own default takes precedence over its type's.
+    if let (Some(default), Some(t)) = (&leaf.default, e.type_node.as_mut()) {
+        t.default = Some(default.clone());
+    }
+    e.help = leaf_help(store, &e, &leaf.description, &leaf.reference, &leaf.units);
     let rc = Rc::new(e);
     ent.dir.borrow_mut().push(rc.clone());
-    rc.parent.replace(Some(ent.clone()));
+    rc.parent.replace(Rc::downgrade(&ent));
 }
 
-fn leaf_list_entry<T>(top: &T, store: &YangStore, leaf: &LeafListNode, ent: Rc<Entry>)
+fn leaf_list_entry<T>(top: &T, store: &Builder, leaf: &LeafListNode, ent: Rc<Entry>)
 where
-    T: ModuleCommon,
+    T: ModuleCommon + ?Sized,
 {
-    if let Some(config) = &leaf.config
-        && !config.config
-    {
-        return;
-    }
     let mut e = Entry::new_leaf(leaf.name.clone());
+    set_origin(top, store, &mut e);
+    config_of(top, store, &mut e, &leaf.config, &ent);
+    e.status = status_of(store, &leaf.status, &ent);
     for u in leaf.unknown.iter() {
         e.extension.insert(u.name.clone(), u.argument.clone());
     }
     if let Some(t) = leaf.type_stmt.as_ref() {
         type_resolve(top, store, t, &mut e);
     }
+    e.help = leaf_help(store, &e, &leaf.description, &leaf.reference, &leaf.units);
     let list_attr = ListAttr::new();
     e.list_attr = Some(list_attr);
 
     let rc = Rc::new(e);
     ent.dir.borrow_mut().push(rc.clone());
-    rc.parent.replace(Some(ent.clone()));
+    rc.parent.replace(Rc::downgrade(&ent));
+}
+
+fn anydata_entry<T>(top: &T, store: &Builder, any: &AnydataNode, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
+{
+    let mut e = Entry::new_anydata(any.name.clone());
+    set_origin(top, store, &mut e);
+    config_of(top, store, &mut e, &any.config, &ent);
+    e.mandatory = any.mandatory.as_ref().is_some_and(|m| m.mandatory);
+    e.when = any.when.as_ref().map(|w| w.name.clone());
+    e.must = any.must.iter().map(|m| m.name.clone()).collect();
+    e.status = status_of(store, &any.status, &ent);
+    e.help = help_of(store, &any.description, &any.reference, &None);
+    let rc = Rc::new(e);
+    ent.dir.borrow_mut().push(rc.clone());
+    rc.parent.replace(Rc::downgrade(&ent));
+}
+
+fn anyxml_entry<T>(top: &T, store: &Builder, any: &AnyxmlNode, ent: Rc<Entry>)
+where
+    T: ModuleCommon + ?Sized,
+{
+    let mut e = Entry::new_anyxml(any.name.clone());
+    set_origin(top, store, &mut e);
+    config_of(top, store, &mut e, &any.config, &ent);
+    e.mandatory = any.mandatory.as_ref().is_some_and(|m| m.mandatory);
+    e.when = any.when.as_ref().map(|w| w.name.clone());
+    e.must = any.must.iter().map(|m| m.name.clone()).collect();
+    e.status = status_of(store, &any.status, &ent);
+    e.help = help_of(store, &any.description, &any.reference, &None);
+    let rc = Rc::new(e);
+    ent.dir.borrow_mut().push(rc.clone());
+    rc.parent.replace(Rc::downgrade(&ent));
 }
 
 impl ModuleCommon for ModuleNode {
+    fn as_common(&self) -> &dyn ModuleCommon {
+        self
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 
+    fn get_module_name(&self) -> &str {
+        &self.name
+    }
+
     fn get_prefix(&self) -> Option<&str> {
         self.prefix.as_deref()
     }
@@ -1063,10 +2908,23 @@ impl ModuleCommon for ModuleNode {
 }
 
 impl ModuleCommon for SubmoduleNode {
+    fn as_common(&self) -> &dyn ModuleCommon {
+        self
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
 
+    fn get_module_name(&self) -> &str {
+        // Definitions in a submodule belong to the namespace of the
+        // module it is part of (RFC 7950 §7.2.2).
+        self.belongs_to
+            .as_ref()
+            .map(|b| b.name.as_str())
+            .unwrap_or(&self.name)
+    }
+
     fn get_prefix(&self) -> Option<&str> {
         // A submodule has no prefix of its own; per RFC 7950 §7.2.2 it
         // shares the prefix of the module it belongs to.
@@ -1102,33 +2960,169 @@ impl ModuleCommon for SubmoduleNode {
     }
 }
 
+/// A scope nested in a module: the typedefs and groupings defined
+/// directly in a container, list, grouping, action, input or output
+/// (RFC 7950 §5.5). It stands in for `top` while that node's children
+/// are built, so their references find these definitions before the
+/// enclosing scopes'. Everything else — the module, its prefix and
+/// imports — is the enclosing module's.
+struct Scope<'a> {
+    parent: &'a dyn ModuleCommon,
+    typedef: &'a Vec<TypedefNode>,
+    grouping: &'a Vec<GroupingNode>,
+    // Identities are resolved per module, so a scope's own map stays
+    // empty; it only gives `get_identities_mut` something to return.
+    identities: HashMap<String, Vec<String>>,
+}
+
+impl<'a> Scope<'a> {
+    /// Open the scope of node `name` inside `parent`. A definition may
+    /// not reuse the name of one already visible there, nor of another
+    /// in the same scope.
+    fn enter(
+        parent: &'a dyn ModuleCommon,
+        store: &Builder,
+        name: &str,
+        typedef: &'a Vec<TypedefNode>,
+        grouping: &'a Vec<GroupingNode>,
+    ) -> Self {
+        for (i, t) in typedef.iter().enumerate() {
+            if typedef[..i].iter().any(|p| p.name == t.name)
+                || matches!(
+                    lookup::<_, TypedefNode>(parent, store, &t.name),
+                    Lookup::Found(..)
+                )
+            {
+                store.diag(Diagnostic::ShadowedTypedef {
+                    module: parent.get_name().to_string(),
+                    node: name.to_string(),
+                    name: t.name.clone(),
+                });
+            }
+        }
+        for (i, g) in grouping.iter().enumerate() {
+            if grouping[..i].iter().any(|p| p.name == g.name)
+                || matches!(
+                    lookup::<_, GroupingNode>(parent, store, &g.name),
+                    Lookup::Found(..)
+                )
+            {
+                store.diag(Diagnostic::ShadowedGrouping {
+                    module: parent.get_name().to_string(),
+                    node: name.to_string(),
+                    name: g.name.clone(),
+                });
+            }
+        }
+        Self {
+            parent,
+            typedef,
+            grouping,
+            identities: HashMap::new(),
+        }
+    }
+}
+
+impl ModuleCommon for Scope<'_> {
+    fn as_common(&self) -> &dyn ModuleCommon {
+        self
+    }
+
+    fn get_parent(&self) -> Option<&dyn ModuleCommon> {
+        Some(self.parent)
+    }
+
+    fn get_name(&self) -> &str {
+        self.parent.get_name()
+    }
+
+    fn get_module_name(&self) -> &str {
+        self.parent.get_module_name()
+    }
+
+    fn get_prefix(&self) -> Option<&str> {
+        self.parent.get_prefix()
+    }
+
+    fn get_identity(&self) -> &Vec<IdentityNode> {
+        self.parent.get_identity()
+    }
+
+    fn get_identities_mut(&mut self) -> &mut HashMap<String, Vec<String>> {
+        &mut self.identities
+    }
+
+    fn get_include(&self) -> &Vec<IncludeNode> {
+        self.parent.get_include()
+    }
+
+    fn get_import(&self) -> &Vec<ImportNode> {
+        self.parent.get_import()
+    }
+
+    fn get_typedef(&self) -> &Vec<TypedefNode> {
+        self.typedef
+    }
+
+    fn get_grouping(&self) -> &Vec<GroupingNode> {
+        self.grouping
+    }
+
+    fn get_d(&self) -> &DatadefNode {
+        self.parent.get_d()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn dir(name: &str) -> Rc<Entry> {
-        Rc::new(Entry::new_dir(name.to_string()))
+    fn dir(module: &str, name: &str) -> Rc<Entry> {
+        let mut e = Entry::new_dir(name.to_string());
+        e.module = module.to_string();
+        Rc::new(e)
+    }
+
+    fn module_a() -> ModuleNode {
+        let mut m = ModuleNode::new("a".to_string());
+        m.prefix = Some("a".to_string());
+        m.import.push(ImportNode {
+            name: "b".to_string(),
+            prefix: Some("b".to_string()),
+            ..Default::default()
+        });
+        m
     }
 
     #[test]
-    fn resolve_target_walks_prefixed_path_by_bare_name() {
-        let root = dir("m");
-        let top = dir("top");
-        let item = dir("item");
-        top.dir.borrow_mut().push(item);
+    fn resolve_target_walks_prefixed_path_by_qualified_name() {
+        let root = dir("a", "a");
+        let top = dir("a", "top");
+        top.dir.borrow_mut().push(dir("b", "item"));
+        top.dir.borrow_mut().push(dir("a", "item"));
         root.dir.borrow_mut().push(top);
 
-        // Each `prefix:name` segment is matched on its bare name.
-        let found = resolve_target(root, "/a:top/a:item").expect("resolved");
-        assert_eq!(found.name, "item");
+        // Each `prefix:name` segment is matched on module and name.
+        let store = YangStore::new();
+        let store = Builder::new(&store, &EntryOptions::default());
+        let found =
+            resolve_target(&module_a(), &store, root.clone(), "/a:top/a:item").expect("resolved");
+        assert_eq!((found.module.as_str(), found.name.as_str()), ("a", "item"));
+        let found = resolve_target(&module_a(), &store, root, "/a:top/b:item").expect("resolved");
+        assert_eq!((found.module.as_str(), found.name.as_str()), ("b", "item"));
     }
 
     #[test]
     fn resolve_target_reports_first_missing_segment() {
-        let root = dir("m");
-        root.dir.borrow_mut().push(dir("top"));
+        let root = dir("a", "a");
+        root.dir.borrow_mut().push(dir("a", "top"));
 
-        let err = resolve_target(root, "/a:top/a:missing").unwrap_err();
+        let store = YangStore::new();
+        let store = Builder::new(&store, &EntryOptions::default());
+        let err =
+            resolve_target(&module_a(), &store, root.clone(), "/a:top/a:missing").unwrap_err();
         assert_eq!(err, "a:missing");
+        let err = resolve_target(&module_a(), &store, root, "/b:top").unwrap_err();
+        assert_eq!(err, "b:top");
     }
 }
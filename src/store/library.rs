@@ -0,0 +1,648 @@
+use crate::*;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeSet;
+
+const YANG_LIBRARY: &str = "ietf-yang-library:yang-library";
+const MODULES_STATE: &str = "ietf-yang-library:modules-state";
+
+/// The revision of `ietf-yang-library` in RFC 8525, which the NETCONF
+/// capability names.
+const YANG_LIBRARY_REVISION: &str = "2019-01-04";
+
+/// The module sets a server implements, as an `ietf-yang-library`
+/// instance (RFC 8525) describes them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct YangLibrary {
+    pub module_set: Vec<ModuleSet>,
+    pub schema: Vec<LibrarySchema>,
+    pub datastore: Vec<LibraryDatastore>,
+    /// Changes whenever the rest of the library does.
+    pub content_id: String,
+}
+
+/// A `schema`: the module sets that together make up the schema of
+/// one or more datastores.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LibrarySchema {
+    pub name: String,
+    pub module_set: Vec<String>,
+}
+
+/// A `datastore`, such as `ietf-datastores:running`, and the schema
+/// it uses.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LibraryDatastore {
+    pub name: String,
+    pub schema: String,
+}
+
+/// The modules of one `module-set`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModuleSet {
+    pub name: String,
+    /// The implemented modules.
+    pub module: Vec<LibraryModule>,
+    /// The modules present only for their definitions to be imported.
+    pub import_only_module: Vec<LibraryModule>,
+}
+
+/// A module of a [`ModuleSet`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LibraryModule {
+    pub name: String,
+    /// `None` for a module without a `revision` statement.
+    pub revision: Option<String>,
+    pub namespace: Option<String>,
+    pub location: Vec<String>,
+    pub submodule: Vec<LibrarySubmodule>,
+    /// The features enabled. Always empty for an import-only module.
+    pub feature: Vec<String>,
+    /// The modules with deviations of this one. Always empty for an
+    /// import-only module.
+    pub deviation: Vec<String>,
+}
+
+/// A submodule of a [`LibraryModule`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LibrarySubmodule {
+    pub name: String,
+    pub revision: Option<String>,
+    pub location: Vec<String>,
+}
+
+impl YangLibrary {
+    /// Read a JSON-encoded (RFC 7951) instance of `ietf-yang-library`:
+    /// its `yang-library` container, or failing that, the legacy
+    /// `modules-state` of RFC 7895, read as a single module set named
+    /// after it.
+    pub fn from_json(text: &str) -> Result<Self, YangError> {
+        let value: Value = serde_json::from_str(text).map_err(|e| invalid(e.to_string()))?;
+        let top = object(&value, "document")?;
+        if let Some(library) = top.get(YANG_LIBRARY) {
+            return yang_library(object(library, YANG_LIBRARY)?);
+        }
+        if let Some(state) = top.get(MODULES_STATE) {
+            return modules_state(object(state, MODULES_STATE)?);
+        }
+        Err(invalid(format!(
+            "neither \"{YANG_LIBRARY}\" nor \"{MODULES_STATE}\" is present"
+        )))
+    }
+
+    /// The library of the modules a [`SchemaContext`] implements, and
+    /// the modules it imports them with, as loaded into `store`: one
+    /// module set and one schema, both named `default`, used by the
+    /// `ietf-datastores:running` and `ietf-datastores:operational`
+    /// datastores.
+    ///
+    /// Each implemented module lists the features the store enables
+    /// and the implemented modules with deviations of it. The content
+    /// id is computed from the rest; see
+    /// [`update_content_id`](Self::update_content_id).
+    pub fn from_context(store: &YangStore, context: &SchemaContext) -> Self {
+        let implemented: Vec<&ModuleNode> = context
+            .implemented()
+            .iter()
+            .filter_map(|name| store.find_module(name))
+            .collect();
+        let mut set = ModuleSet {
+            name: "default".to_string(),
+            ..Default::default()
+        };
+        for m in implemented.iter() {
+            let mut module = library_module_of(store, m);
+            let submodules = store.submodules_of_module(m);
+            let features = m
+                .feature
+                .iter()
+                .chain(submodules.iter().flat_map(|sub| sub.feature.iter()));
+            module.feature = features
+                .filter(|f| store.feature_enabled(&m.name, &f.name))
+                .map(|f| f.name.clone())
+                .collect();
+            module.deviation = implemented
+                .iter()
+                .filter(|d| deviates(store, d, &m.name))
+                .map(|d| d.name.clone())
+                .collect();
+            set.module.push(module);
+        }
+        for key in context.import_only() {
+            if let Some(m) = store.find_module(key) {
+                set.import_only_module.push(library_module_of(store, m));
+            }
+        }
+        let datastore = |name: &str| LibraryDatastore {
+            name: format!("ietf-datastores:{name}"),
+            schema: "default".to_string(),
+        };
+        let mut library = YangLibrary {
+            module_set: vec![set],
+            schema: vec![LibrarySchema {
+                name: "default".to_string(),
+                module_set: vec!["default".to_string()],
+            }],
+            datastore: vec![datastore("running"), datastore("operational")],
+            content_id: String::new(),
+        };
+        library.update_content_id();
+        library
+    }
+
+    /// Set the content id to a digest of the module sets, schemas and
+    /// datastores, so it changes whenever they do and is the same for
+    /// the same library on every run. Call it after changing them.
+    pub fn update_content_id(&mut self) {
+        let mut value = self.yang_library_value();
+        value.as_object_mut().unwrap().remove("content-id");
+        // FNV-1a: stable across runs and Rust releases, which the
+        // standard library's hashers do not promise.
+        let digest = value
+            .to_string()
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325, |h, b| {
+                (h ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+            });
+        self.content_id = format!("{digest:016x}");
+    }
+
+    /// The JSON encoding (RFC 7951) of the library as an instance of
+    /// the `yang-library` container.
+    pub fn to_json(&self) -> String {
+        let value = json!({ YANG_LIBRARY: self.yang_library_value() });
+        serde_json::to_string_pretty(&value).unwrap()
+    }
+
+    /// The JSON encoding of the library as the legacy `modules-state`
+    /// of RFC 7895, for clients that predate RFC 8525. The modules of
+    /// every module set are listed once, and the content id is the
+    /// module set id.
+    pub fn to_modules_state_json(&self) -> String {
+        let mut modules: Vec<Value> = Vec::new();
+        let mut seen = BTreeSet::new();
+        let sets = self.module_set.iter();
+        let implemented = sets.clone().flat_map(|s| s.module.iter());
+        let import_only = sets.flat_map(|s| s.import_only_module.iter());
+        let conformance = implemented
+            .map(|m| (m, "implement"))
+            .chain(import_only.map(|m| (m, "import")));
+        for (m, conformance) in conformance {
+            if !seen.insert((&m.name, &m.revision)) {
+                continue;
+            }
+            let mut module = json!({
+                "name": m.name,
+                "revision": m.revision.clone().unwrap_or_default(),
+                "conformance-type": conformance,
+            });
+            let obj = module.as_object_mut().unwrap();
+            if let Some(namespace) = m.namespace.as_ref() {
+                obj.insert("namespace".to_string(), json!(namespace));
+            }
+            if let Some(location) = m.location.first() {
+                obj.insert("schema".to_string(), json!(location));
+            }
+            if !m.feature.is_empty() {
+                obj.insert("feature".to_string(), json!(m.feature));
+            }
+            let deviation: Vec<Value> = m
+                .deviation
+                .iter()
+                .map(|name| {
+                    let revision = self
+                        .module_set
+                        .iter()
+                        .flat_map(|s| s.module.iter())
+                        .find(|d| &d.name == name)
+                        .and_then(|d| d.revision.clone());
+                    json!({ "name": name, "revision": revision.unwrap_or_default() })
+                })
+                .collect();
+            if !deviation.is_empty() {
+                obj.insert("deviation".to_string(), json!(deviation));
+            }
+            let submodule: Vec<Value> = m
+                .submodule
+                .iter()
+                .map(|sub| {
+                    let mut value = json!({
+                        "name": sub.name,
+                        "revision": sub.revision.clone().unwrap_or_default(),
+                    });
+                    if let Some(location) = sub.location.first() {
+                        value["schema"] = json!(location);
+                    }
+                    value
+                })
+                .collect();
+            if !submodule.is_empty() {
+                obj.insert("submodule".to_string(), json!(submodule));
+            }
+            modules.push(module);
+        }
+        let value = json!({
+            MODULES_STATE: {
+                "module-set-id": self.content_id,
+                "module": modules,
+            }
+        });
+        serde_json::to_string_pretty(&value).unwrap()
+    }
+
+    /// The capabilities a NETCONF server advertises in its `<hello>`
+    /// for this library: the YANG library capability of RFC 8526 and,
+    /// as RFC 7950 §5.6.4 requires, the RFC 6020 §5.6.4 capability of
+    /// each implemented YANG 1.0 module, which `store` tells apart.
+    /// The base and protocol capabilities are the server's to add.
+    pub fn capabilities(&self, store: &YangStore) -> Vec<String> {
+        let mut capabilities = vec![format!(
+            "urn:ietf:params:netconf:capability:yang-library:1.1\
+             ?revision={YANG_LIBRARY_REVISION}&content-id={}",
+            self.content_id
+        )];
+        let mut seen = BTreeSet::new();
+        for m in self.module_set.iter().flat_map(|s| s.module.iter()) {
+            let node = store.find_module_revision(&m.name, m.revision.as_deref());
+            let yang_1_1 = node.is_some_and(|n| n.version.as_deref() == Some("1.1"));
+            if yang_1_1 || !seen.insert(&m.name) {
+                continue;
+            }
+            let mut uri = format!(
+                "{}?module={}",
+                m.namespace.as_deref().unwrap_or_default(),
+                m.name
+            );
+            if let Some(revision) = m.revision.as_ref() {
+                uri.push_str(&format!("&revision={revision}"));
+            }
+            if !m.feature.is_empty() {
+                uri.push_str(&format!("&features={}", m.feature.join(",")));
+            }
+            if !m.deviation.is_empty() {
+                uri.push_str(&format!("&deviations={}", m.deviation.join(",")));
+            }
+            capabilities.push(uri);
+        }
+        capabilities
+    }
+
+    fn yang_library_value(&self) -> Value {
+        let module = |m: &LibraryModule, implemented: bool| {
+            let mut value = json!({ "name": m.name });
+            let obj = value.as_object_mut().unwrap();
+            if let Some(revision) = m.revision.as_ref() {
+                obj.insert("revision".to_string(), json!(revision));
+            }
+            if let Some(namespace) = m.namespace.as_ref() {
+                obj.insert("namespace".to_string(), json!(namespace));
+            }
+            if !m.location.is_empty() {
+                obj.insert("location".to_string(), json!(m.location));
+            }
+            if !m.submodule.is_empty() {
+                let submodule: Vec<Value> = m
+                    .submodule
+                    .iter()
+                    .map(|sub| {
+                        let mut value = json!({ "name": sub.name });
+                        if let Some(revision) = sub.revision.as_ref() {
+                            value["revision"] = json!(revision);
+                        }
+                        if !sub.location.is_empty() {
+                            value["location"] = json!(sub.location);
+                        }
+                        value
+                    })
+                    .collect();
+                obj.insert("submodule".to_string(), json!(submodule));
+            }
+            if implemented && !m.feature.is_empty() {
+                obj.insert("feature".to_string(), json!(m.feature));
+            }
+            if implemented && !m.deviation.is_empty() {
+                obj.insert("deviation".to_string(), json!(m.deviation));
+            }
+            value
+        };
+        let module_set: Vec<Value> = self
+            .module_set
+            .iter()
+            .map(|set| {
+                let mut value = json!({
+                    "name": set.name,
+                    "module": set.module.iter().map(|m| module(m, true)).collect::<Vec<_>>(),
+                });
+                if !set.import_only_module.is_empty() {
+                    let import_only: Vec<Value> = set
+                        .import_only_module
+                        .iter()
+                        .map(|m| module(m, false))
+                        .collect();
+                    value["import-only-module"] = json!(import_only);
+                }
+                value
+            })
+            .collect();
+        let schema: Vec<Value> = self
+            .schema
+            .iter()
+            .map(|s| json!({ "name": s.name, "module-set": s.module_set }))
+            .collect();
+        let datastore: Vec<Value> = self
+            .datastore
+            .iter()
+            .map(|d| json!({ "name": d.name, "schema": d.schema }))
+            .collect();
+        json!({
+            "module-set": module_set,
+            "schema": schema,
+            "datastore": datastore,
+            "content-id": self.content_id,
+        })
+    }
+
+    /// The module set named `name`.
+    pub fn find_module_set(&self, name: &str) -> Option<&ModuleSet> {
+        self.module_set.iter().find(|s| s.name == name)
+    }
+
+    /// The names of the modules implemented in any module set, as
+    /// [`SchemaContext::new`] takes them.
+    pub fn implemented(&self) -> Vec<&str> {
+        let names: BTreeSet<&str> = self
+            .module_set
+            .iter()
+            .flat_map(|s| s.module.iter())
+            .map(|m| m.name.as_str())
+            .collect();
+        names.into_iter().collect()
+    }
+}
+
+impl YangStore {
+    /// Load the modules of every module set of `library` from the
+    /// store's sources, each at the revision listed, with its
+    /// submodules and import-only modules at theirs. The features
+    /// listed are the ones enabled in each implemented module; an
+    /// import-only module has none. A module's deviations come from
+    /// other implemented modules of the set, which are loaded with it,
+    /// and apply once its entry tree is built.
+    ///
+    /// An import or include that reaches a module the library does
+    /// not list is an error: a module set is complete.
+    pub fn load_yang_library(&mut self, library: &YangLibrary) -> Result<(), YangError> {
+        for set in library.module_set.iter() {
+            self.load_module_set(set)?;
+        }
+        Ok(())
+    }
+
+    fn load_module_set(&mut self, set: &ModuleSet) -> Result<(), YangError> {
+        let modules = set.module.iter().chain(set.import_only_module.iter());
+        let mut listed = BTreeSet::new();
+        for m in modules.clone() {
+            listed.insert(m.name.as_str());
+            if let Some(revision) = m.revision.as_ref() {
+                self.revisions.insert(m.name.clone(), revision.clone());
+            }
+            for sub in m.submodule.iter() {
+                listed.insert(sub.name.as_str());
+                if let Some(revision) = sub.revision.as_ref() {
+                    self.revisions.insert(sub.name.clone(), revision.clone());
+                }
+            }
+        }
+
+        let before = self.loaded_names();
+        for m in set.module.iter() {
+            self.read_revision(&m.name, m.revision.as_deref())?;
+            self.enable_features(&m.name, m.feature.iter().cloned());
+            for deviation in m.deviation.iter() {
+                self.read_revision(deviation, None)?;
+            }
+        }
+        for m in set.import_only_module.iter() {
+            self.read_revision(&m.name, m.revision.as_deref())?;
+            self.enable_features(&m.name, Vec::<String>::new());
+        }
+
+        let after = self.loaded_names();
+        if let Some(name) = after
+            .difference(&before)
+            .find(|name| !listed.contains(name.as_str()))
+        {
+            return Err(invalid(format!(
+                "module `{name}` is loaded but not listed in module set \"{}\"",
+                set.name
+            )));
+        }
+        Ok(())
+    }
+
+    // The names of the loaded modules and submodules, without revision.
+    fn loaded_names(&self) -> BTreeSet<String> {
+        let names = self.modules.values().map(|m| m.name.clone());
+        names
+            .chain(self.submodules.values().map(|m| m.name.clone()))
+            .collect()
+    }
+}
+
+// A module's entry in a module set, without the features and
+// deviations only an implemented module has.
+fn library_module_of(store: &YangStore, m: &ModuleNode) -> LibraryModule {
+    LibraryModule {
+        name: m.name.clone(),
+        revision: m.latest_revision().map(str::to_string),
+        namespace: m.namespace.clone(),
+        submodule: store
+            .submodules_of_module(m)
+            .iter()
+            .map(|sub| LibrarySubmodule {
+                name: sub.name.clone(),
+                revision: sub.latest_revision().map(str::to_string),
+                location: Vec::new(),
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+// Whether `m`, or a submodule of it, has a deviation of a node in
+// `target`.
+fn deviates(store: &YangStore, m: &ModuleNode, target: &str) -> bool {
+    let targets = |top: &dyn ModuleCommon, deviation: &[DeviationNode]| {
+        deviation
+            .iter()
+            .any(|d| deviation_module(top, &d.target).as_deref() == Some(target))
+    };
+    targets(m, &m.deviation)
+        || store
+            .submodules_of_module(m)
+            .iter()
+            .any(|sub| targets(*sub, &sub.deviation))
+}
+
+// The module of the node a deviation written in `top` targets, from
+// the prefix of its first segment.
+fn deviation_module(top: &dyn ModuleCommon, target: &str) -> Option<String> {
+    let first = target.split('/').find(|s| !s.is_empty())?;
+    let Some((prefix, _)) = first.split_once(':') else {
+        return Some(top.get_module_name().to_string());
+    };
+    if Some(prefix) == top.get_prefix() {
+        return Some(top.get_module_name().to_string());
+    }
+    top.get_import()
+        .iter()
+        .find(|i| i.prefix.as_deref() == Some(prefix))
+        .map(|i| i.name.clone())
+}
+
+fn invalid(reason: impl Into<String>) -> YangError {
+    YangError::InvalidLibrary {
+        reason: reason.into(),
+    }
+}
+
+fn object<'a>(value: &'a Value, what: &str) -> Result<&'a Map<String, Value>, YangError> {
+    value
+        .as_object()
+        .ok_or_else(|| invalid(format!("\"{what}\" is not an object")))
+}
+
+fn list<'a>(obj: &'a Map<String, Value>, key: &str) -> Result<&'a [Value], YangError> {
+    match obj.get(key) {
+        None => Ok(&[]),
+        Some(Value::Array(values)) => Ok(values),
+        Some(_) => Err(invalid(format!("\"{key}\" is not a list"))),
+    }
+}
+
+fn string(obj: &Map<String, Value>, key: &str) -> Result<Option<String>, YangError> {
+    match obj.get(key) {
+        None => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.clone())),
+        Some(_) => Err(invalid(format!("\"{key}\" is not a string"))),
+    }
+}
+
+fn required(obj: &Map<String, Value>, key: &str) -> Result<String, YangError> {
+    string(obj, key)?.ok_or_else(|| invalid(format!("\"{key}\" is missing")))
+}
+
+fn strings(obj: &Map<String, Value>, key: &str) -> Result<Vec<String>, YangError> {
+    list(obj, key)?
+        .iter()
+        .map(|v| {
+            v.as_str()
+                .map(str::to_string)
+                .ok_or_else(|| invalid(format!("\"{key}\" holds a value that is not a string")))
+        })
+        .collect()
+}
+
+fn yang_library(obj: &Map<String, Value>) -> Result<YangLibrary, YangError> {
+    let mut library = YangLibrary {
+        content_id: required(obj, "content-id")?,
+        ..Default::default()
+    };
+    for set in list(obj, "module-set")? {
+        let set = object(set, "module-set")?;
+        let mut module_set = ModuleSet {
+            name: required(set, "name")?,
+            ..Default::default()
+        };
+        for m in list(set, "module")? {
+            module_set
+                .module
+                .push(library_module(object(m, "module")?)?);
+        }
+        for m in list(set, "import-only-module")? {
+            let m = object(m, "import-only-module")?;
+            module_set.import_only_module.push(library_module(m)?);
+        }
+        library.module_set.push(module_set);
+    }
+    for schema in list(obj, "schema")? {
+        let schema = object(schema, "schema")?;
+        library.schema.push(LibrarySchema {
+            name: required(schema, "name")?,
+            module_set: strings(schema, "module-set")?,
+        });
+    }
+    for datastore in list(obj, "datastore")? {
+        let datastore = object(datastore, "datastore")?;
+        library.datastore.push(LibraryDatastore {
+            name: required(datastore, "name")?,
+            schema: required(datastore, "schema")?,
+        });
+    }
+    Ok(library)
+}
+
+fn library_module(obj: &Map<String, Value>) -> Result<LibraryModule, YangError> {
+    let mut module = LibraryModule {
+        name: required(obj, "name")?,
+        revision: string(obj, "revision")?,
+        namespace: string(obj, "namespace")?,
+        location: strings(obj, "location")?,
+        feature: strings(obj, "feature")?,
+        deviation: strings(obj, "deviation")?,
+        ..Default::default()
+    };
+    for sub in list(obj, "submodule")? {
+        let sub = object(sub, "submodule")?;
+        module.submodule.push(LibrarySubmodule {
+            name: required(sub, "name")?,
+            revision: string(sub, "revision")?,
+            location: strings(sub, "location")?,
+        });
+    }
+    Ok(module)
+}
+
+// RFC 7895 lists every module once, marked implemented or import-only,
+// with an empty revision for a module without one, and names each
+// deviation module with its revision.
+fn modules_state(obj: &Map<String, Value>) -> Result<YangLibrary, YangError> {
+    let revision = |obj| string(obj, "revision").map(|r| r.filter(|r| !r.is_empty()));
+    let mut module_set = ModuleSet {
+        name: "modules-state".to_string(),
+        ..Default::default()
+    };
+    for m in list(obj, "module")? {
+        let m = object(m, "module")?;
+        let mut module = LibraryModule {
+            name: required(m, "name")?,
+            revision: revision(m)?,
+            namespace: string(m, "namespace")?,
+            location: string(m, "schema")?.into_iter().collect(),
+            feature: strings(m, "feature")?,
+            ..Default::default()
+        };
+        for d in list(m, "deviation")? {
+            module
+                .deviation
+                .push(required(object(d, "deviation")?, "name")?);
+        }
+        for sub in list(m, "submodule")? {
+            let sub = object(sub, "submodule")?;
+            module.submodule.push(LibrarySubmodule {
+                name: required(sub, "name")?,
+                revision: revision(sub)?,
+                location: string(sub, "schema")?.into_iter().collect(),
+            });
+        }
+        match string(m, "conformance-type")?.as_deref() {
+            Some("implement") => module_set.module.push(module),
+            Some("import") => module_set.import_only_module.push(module),
+            _ => return Err(invalid("\"conformance-type\" is missing or unknown")),
+        }
+    }
+    Ok(YangLibrary {
+        module_set: vec![module_set],
+        content_id: required(obj, "module-set-id")?,
+        ..Default::default()
+    })
+}
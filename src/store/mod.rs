@@ -4,8 +4,25 @@ pub use diagnostic::*;
 pub mod reader;
 pub use reader::*;
 
+pub mod source;
+pub use source::*;
+
+#[cfg(feature = "archive")]
+pub mod archive;
+#[cfg(feature = "archive")]
+pub use archive::*;
+
+pub mod library;
+pub use library::*;
+
 pub mod yerror;
 pub use yerror::*;
 
 pub mod entry;
 pub use entry::*;
+
+pub mod schema;
+pub use schema::*;
+
+pub mod context;
+pub use context::*;
@@ -2,14 +2,15 @@ use crate::yang_grammar::YangGrammar;
 use crate::yang_parser::parse;
 use crate::*;
 use std::cell::{Ref, RefCell};
-use std::collections::BTreeMap;
-use std::ffi::OsStr;
-use std::fs::{self};
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 #[derive(Debug, Default)]
 pub struct YangStore {
-    paths: Vec<PathBuf>,
+    // Searched in order for the modules to load: the texts added with
+    // `add_module_text` first, then the sources added, then the
+    // current directory.
+    memory: MemorySource,
+    sources: Vec<Box<dyn ModuleSource>>,
     // Crate-private: reach these through `find_module` /
     // `find_submodule`. Keeping the storage an implementation detail
     // means its type can change without breaking callers — which is
@@ -23,13 +24,26 @@ pub struct YangStore {
     // same program over the same files. Key order (module name) is
     // arbitrary but stable, which is what consumers need to produce
     // reproducible output.
+    //
+    // A module's latest loaded revision is keyed by its name, and any
+    // older one an importer pins by `name@revision`.
     pub(crate) modules: BTreeMap<String, ModuleNode>,
     pub(crate) submodules: BTreeMap<String, SubmoduleNode>,
+    // Identities keyed by the base they name, across every loaded
+    // module and submodule; filled by `identity_resolve`. Only direct
+    // derivations are recorded — `derived_identities` walks the rest.
+    derived: BTreeMap<QualifiedName, Vec<QualifiedName>>,
     // Problems found while building entry trees. `to_entry` takes the
     // store by shared reference and the whole augment path already has
     // it in scope, so a `RefCell` collects diagnostics here without
     // threading a channel through 20 functions.
     diagnostics: RefCell<Vec<Diagnostic>>,
+    // The features enabled in each module that has had them set; every
+    // feature of any other module is enabled.
+    features: BTreeMap<String, BTreeSet<String>>,
+    // The revision an unpinned import or include loads, by module or
+    // submodule name, as a yang-library module set lists it.
+    pub(crate) revisions: BTreeMap<String, String>,
 }
 
 impl YangStore {
@@ -39,111 +53,292 @@ impl YangStore {
         }
     }
 
+    /// Search the `:`-separated directories in `paths` for modules, in
+    /// order, after the sources already added (see [`DirectorySource`]).
     pub fn add_path(&mut self, paths: &str) {
         for path in paths.split(':') {
-            //if let Ok(path) = PathBuf::from(path).expand_home() {
-            let path = PathBuf::from(path);
-            self.paths.push(path);
-            //}
+            self.add_source(DirectorySource::new(path));
         }
     }
 
+    /// Search `source` for modules, after the sources already added.
+    pub fn add_source(&mut self, source: impl ModuleSource + 'static) {
+        self.sources.push(Box::new(source));
+    }
+
+    /// Add the text of module or submodule `name`, which may carry a
+    /// revision as `name@revision`. Texts added this way are found
+    /// before any source's, so they need no file to be installed.
+    pub fn add_module_text(&mut self, name: &str, text: impl Into<String>) {
+        self.memory.add(name, text);
+    }
+
+    /// Enable exactly `features` of `module`, disabling the rest. Nodes
+    /// whose `if-feature` does not hold are left out of entry trees.
+    /// Until this is called for a module, all its features are enabled.
+    pub fn enable_features<I, S>(&mut self, module: &str, features: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let features = features.into_iter().map(Into::into).collect();
+        self.features.insert(module.to_string(), features);
+    }
+
+    /// Whether `feature` of `module` is enabled: it is among the
+    /// module's enabled features, and the feature's own `if-feature`
+    /// statements hold too (RFC 7950 §7.20.1).
+    pub fn feature_enabled(&self, module: &str, feature: &str) -> bool {
+        feature_supported(self, module, feature, &mut Vec::new())
+    }
+
+    /// Whether `feature` is among the enabled features of `module`.
+    pub(crate) fn feature_listed(&self, module: &str, feature: &str) -> bool {
+        self.features
+            .get(module)
+            .is_none_or(|enabled| enabled.contains(feature))
+    }
+
     pub fn identity_resolve(&mut self) {
+        // Identity derivation crosses module boundaries (an identity in
+        // one module may name a base in any module it imports), so it
+        // is resolved once over the whole store rather than per module.
+        let mut derived = BTreeMap::new();
+        for m in self.modules.values() {
+            identity_derive(m, self, &mut derived);
+        }
+        for m in self.submodules.values() {
+            identity_derive(m, self, &mut derived);
+        }
+        self.derived = derived;
+
+        // Each module's and submodule's `identities` map lists, by bare
+        // base name, the identities derived from it within the same
+        // namespace. A module and its submodules share one namespace,
+        // so they all see the same map: an identity in one submodule
+        // may derive from a base in the module or another submodule.
+        let mut local: BTreeMap<String, HashMap<String, Vec<String>>> = BTreeMap::new();
+        for (base, derived) in self.derived.iter() {
+            let names: Vec<String> = derived
+                .iter()
+                .filter(|d| d.module == base.module)
+                .map(|d| d.name.clone())
+                .collect();
+            if !names.is_empty() {
+                local
+                    .entry(base.module.clone())
+                    .or_default()
+                    .insert(base.name.clone(), names);
+            }
+        }
         for m in self.modules.values_mut() {
-            identity_resolve(m);
+            m.identities = local.get(&m.name).cloned().unwrap_or_default();
         }
         for m in self.submodules.values_mut() {
-            identity_resolve(m);
+            m.identities = local.get(m.get_module_name()).cloned().unwrap_or_default();
+        }
+    }
+
+    /// Every identity derived from `base`, directly or through other
+    /// derived identities (RFC 7950 §7.18.2), in any loaded module.
+    /// `base` itself is not included. Empty until
+    /// [`identity_resolve`](Self::identity_resolve) has run.
+    pub fn derived_identities(&self, base: &QualifiedName) -> Vec<QualifiedName> {
+        let mut found: Vec<QualifiedName> = Vec::new();
+        let mut pending = vec![base.clone()];
+        while let Some(next) = pending.pop() {
+            for d in self.derived.get(&next).into_iter().flatten() {
+                // A malformed module can make derivation circular;
+                // skipping what was already seen keeps this finite.
+                if d != base && !found.contains(d) {
+                    found.push(d.clone());
+                    pending.push(d.clone());
+                }
+            }
         }
+        found.sort();
+        found
     }
 
+    /// Load the module or submodule `name`, then everything it imports
+    /// and includes, recursively. `name` may pin a revision as
+    /// `name@revision-date`; without one, the latest revision on the
+    /// search path is loaded.
+    ///
+    /// An import or include with a `revision-date` loads exactly that
+    /// revision (RFC 7950 §7.1.5). When importers pin different
+    /// revisions of one module, each is loaded: the latest stays under
+    /// the module's name and the others are kept as `name@revision`.
     pub fn read_with_resolve(&mut self, name: &str) -> Result<(), YangError> {
-        let node = self.load_module(name)?;
-        let mut imports = Vec::<String>::new();
-        let mut includes = Vec::<String>::new();
+        let (name, revision) = split_revision(name);
+        self.read_revision(name, revision)
+    }
+
+    pub(crate) fn read_revision(
+        &mut self,
+        name: &str,
+        revision: Option<&str>,
+    ) -> Result<(), YangError> {
+        let listed = self.revisions.get(name).cloned();
+        let revision = revision.or(listed.as_deref());
+        if revision.is_some() && self.is_loaded(name, revision) {
+            return Ok(());
+        }
+        let found = self.find_text(name, revision)?;
+        // Which revision is the latest is only known once it is found;
+        // it is the one to load unless already loaded.
+        if revision.is_none() && self.is_loaded(name, found.revision.as_deref()) {
+            return Ok(());
+        }
+        let node = load_text(found)?;
+        let mut imports = Vec::<(String, Option<String>)>::new();
+        let mut includes = Vec::<(String, Option<String>)>::new();
         match node {
             Node::Module(m) => {
+                check_revision(name, revision, m.latest_revision())?;
                 for import in m.import.iter() {
-                    imports.push(import.name.clone());
+                    imports.push((import.name.clone(), import.revision_date.clone()));
                 }
                 for include in m.include.iter() {
-                    includes.push(include.name.clone());
+                    includes.push((include.name.clone(), include.revision_date.clone()));
                 }
-                self.modules.insert(name.to_string(), *m);
+                insert_revision(&mut self.modules, *m, |m| (&m.name, m.latest_revision()));
             }
             Node::Submodule(m) => {
+                check_revision(name, revision, m.latest_revision())?;
                 for import in m.import.iter() {
-                    imports.push(import.name.clone());
+                    imports.push((import.name.clone(), import.revision_date.clone()));
                 }
                 for include in m.include.iter() {
-                    includes.push(include.name.clone());
+                    includes.push((include.name.clone(), include.revision_date.clone()));
                 }
-                self.submodules.insert(name.to_string(), *m);
+                insert_revision(&mut self.submodules, *m, |m| (&m.name, m.latest_revision()));
             }
         }
-        for import in imports.iter() {
-            if !self.modules.contains_key(import) {
-                self.read_with_resolve(import)?;
-            }
+        // Each is skipped if already loaded, which also ends import
+        // cycles: this (sub)module is stored before they are read.
+        for (import, revision) in imports.iter() {
+            self.read_revision(import, revision.as_deref())?;
         }
-        for include in includes.iter() {
-            if !self.submodules.contains_key(include) {
-                self.read_with_resolve(include)?;
-            }
+        for (include, revision) in includes.iter() {
+            self.read_revision(include, revision.as_deref())?;
         }
         Ok(())
     }
 
+    fn is_loaded(&self, name: &str, revision: Option<&str>) -> bool {
+        self.find_module_revision(name, revision).is_some()
+            || self.find_submodule_revision(name, revision).is_some()
+    }
+
     pub fn load_module(&mut self, module_name: &str) -> Result<Node, YangError> {
-        let path = self.find_file(module_name)?;
-        let input = fs::read_to_string(&path).map_err(|source| YangError::IoError {
-            path: path.clone(),
-            source,
-        })?;
-        let mut yang_grammar = YangGrammar::new();
-        match parse(&input, &path, &mut yang_grammar) {
-            Ok(_) => yang(yang_grammar),
-            // Hand the diagnostic back to the caller rather than
-            // printing it: a library has no business writing to stdout,
-            // and the position information is what makes the failure
-            // actionable.
-            Err(source) => Err(YangError::ParseError {
-                path,
-                source: Box::new(source),
-            }),
-        }
-    }
-
-    fn find_file(&self, module_name: &str) -> Result<PathBuf, YangError> {
-        for path in &self.paths {
-            if path.file_name() == Some(OsStr::new("...")) {
-                let mut dir = path.clone();
-                if dir.pop()
-                    && let Ok(file_name) = find_in_dir(&dir, module_name, true)
-                {
-                    return Ok(file_name);
+        let (name, revision) = split_revision(module_name);
+        load_text(self.find_text(name, revision)?)
+    }
+
+    fn find_text(&self, name: &str, revision: Option<&str>) -> Result<ModuleText, YangError> {
+        let current = DirectorySource::new(".");
+        let sources = std::iter::once(&self.memory as &dyn ModuleSource)
+            .chain(self.sources.iter().map(|s| s.as_ref()))
+            .chain(std::iter::once(&current as &dyn ModuleSource));
+        // A pinned revision filed as `name@revision` in any source wins
+        // over a plain `name` in an earlier one, which may not hold it.
+        let mut fallback = None;
+        for source in sources {
+            match source.find(name, revision)? {
+                Some(found) if revision.is_none() || found.revision.as_deref() == revision => {
+                    return Ok(found);
                 }
-            }
-            if let Ok(file_name) = find_in_dir(path, module_name, false) {
-                return Ok(file_name);
+                Some(found) => {
+                    fallback.get_or_insert(found);
+                }
+                None => {}
             }
         }
-        find_in_dir(&PathBuf::from("."), module_name, false)
+        if let Some(found) = fallback {
+            return Ok(found);
+        }
+        let name = match revision {
+            Some(revision) => format!("{name}@{revision}"),
+            None => name.to_string(),
+        };
+        Err(YangError::FileNotFound { name })
     }
 
+    /// The loaded module `name`: its latest loaded revision, or the one
+    /// `name@revision-date` pins.
     pub fn find_module(&self, name: &str) -> Option<&ModuleNode> {
-        self.modules.get(name)
+        let (name, revision) = split_revision(name);
+        self.find_module_revision(name, revision)
+    }
+
+    /// The loaded module `name` at `revision`, or at its latest loaded
+    /// revision when `revision` is `None`.
+    pub fn find_module_revision(&self, name: &str, revision: Option<&str>) -> Option<&ModuleNode> {
+        find_revision(&self.modules, name, revision, |m| m.latest_revision())
     }
 
+    /// The loaded submodule `name`, as [`find_module`](Self::find_module)
+    /// finds modules.
     pub fn find_submodule(&self, name: &str) -> Option<&SubmoduleNode> {
-        self.submodules.get(name)
+        let (name, revision) = split_revision(name);
+        self.find_submodule_revision(name, revision)
+    }
+
+    pub fn find_submodule_revision(
+        &self,
+        name: &str,
+        revision: Option<&str>,
+    ) -> Option<&SubmoduleNode> {
+        find_revision(&self.submodules, name, revision, |m| m.latest_revision())
+    }
+
+    /// The loaded submodules of `module`: those it includes and, per
+    /// YANG 1.1, those they include in turn, each once and in include
+    /// order. Together with the module they form one namespace for data
+    /// nodes, typedefs, groupings and identities. When the module
+    /// itself is not loaded, any loaded submodule that belongs to it is
+    /// returned instead.
+    pub fn submodules_of(&self, module: &str) -> Vec<&SubmoduleNode> {
+        let Some(m) = self.find_module(module) else {
+            return self
+                .submodules
+                .values()
+                .filter(|s| s.get_module_name() == module)
+                .collect();
+        };
+        self.submodules_of_module(m)
+    }
+
+    /// The loaded submodules of `m`, as [`submodules_of`](Self::submodules_of)
+    /// finds them, for a module already looked up at some revision.
+    pub(crate) fn submodules_of_module(&self, m: &ModuleNode) -> Vec<&SubmoduleNode> {
+        let mut found: Vec<&SubmoduleNode> = Vec::new();
+        let mut pending: Vec<&IncludeNode> = m.include.iter().collect();
+        let mut i = 0;
+        while i < pending.len() {
+            let include = pending[i];
+            if let Some(sub) =
+                self.find_submodule_revision(&include.name, include.revision_date.as_deref())
+                && !found.iter().any(|s| s.name == sub.name)
+            {
+                found.push(sub);
+                pending.extend(sub.include.iter());
+            }
+            i += 1;
+        }
+        found
     }
 
-    /// Problems found while building entry trees with
-    /// [`to_entry`](crate::to_entry), in the order they were found.
+    /// Problems found while resolving identities with
+    /// [`identity_resolve`](Self::identity_resolve) and building entry
+    /// trees with [`to_entry`](crate::to_entry), in the order they were
+    /// found.
     ///
     /// These are warnings: a tree is still produced, with the offending
-    /// augment skipped. They accumulate across calls, so a caller
+    /// augment skipped or reference left unresolved. The same problem
+    /// is recorded once, however many times a grouping that contains it
+    /// is expanded. They accumulate across calls, so a caller
     /// checking one module at a time should use
     /// [`take_diagnostics`](Self::take_diagnostics) instead.
     ///
@@ -159,62 +354,93 @@ impl YangStore {
         std::mem::take(&mut self.diagnostics.borrow_mut())
     }
 
-    /// Record a problem found while building an entry tree.
+    /// Record a problem found while building an entry tree, unless it
+    /// has already been recorded.
     pub(crate) fn diag(&self, diagnostic: Diagnostic) {
-        self.diagnostics.borrow_mut().push(diagnostic);
+        let mut diagnostics = self.diagnostics.borrow_mut();
+        if !diagnostics.contains(&diagnostic) {
+            diagnostics.push(diagnostic);
+        }
     }
 }
 
-fn find_in_dir(dir: &PathBuf, module_name: &str, recursive: bool) -> Result<PathBuf, YangError> {
-    let mut file_name = String::from(module_name);
-    if !file_name.ends_with(".yang") {
-        file_name.push_str(".yang");
-    }
-
-    let mut basename = String::from(module_name.trim_end_matches(".yang"));
-    basename.push('@');
-
-    let mut revisions = vec![];
-
-    let dirent = fs::read_dir(dir).map_err(|source| YangError::IoError {
-        path: dir.clone(),
-        source,
-    })?;
-    for entry in dirent.into_iter().flatten() {
-        if let Ok(file_type) = entry.file_type() {
-            if file_type.is_file() {
-                if let Some(os_str) = entry.path().file_name()
-                    && let Some(file_str) = os_str.to_str()
-                {
-                    if file_str == file_name {
-                        return Ok(entry.path());
-                    }
-                    // When module_name does not contain '@'.
-                    if module_name.find('@').is_none() {
-                        // Try revision match such as 'ietf-dhcp@2016-08-25.yang'.
-                        if file_str.starts_with(&basename) && file_str.ends_with(".yang") {
-                            revisions.push(entry.path());
-                        }
-                    }
-                }
-            } else if file_type.is_dir()
-                && recursive
-                && let Ok(path) = find_in_dir(&entry.path(), module_name, recursive)
-            {
-                return Ok(path);
-            }
-        }
+/// Split `name@revision-date` into the name and the revision.
+fn split_revision(name: &str) -> (&str, Option<&str>) {
+    let name = name.trim_end_matches(".yang");
+    match name.split_once('@') {
+        Some((name, revision)) => (name, Some(revision)),
+        None => (name, None),
     }
-    if revisions.is_empty() {
-        return Err(YangError::FileNotFound {
-            name: module_name.to_string(),
-        });
+}
+
+/// A file found for a pinned revision may still hold another one: the
+/// search falls back to the module's plain file name, which says
+/// nothing about what is inside.
+fn check_revision(name: &str, wanted: Option<&str>, found: Option<&str>) -> Result<(), YangError> {
+    match wanted {
+        Some(revision) if found != Some(revision) => Err(YangError::FileNotFound {
+            name: format!("{name}@{revision}"),
+        }),
+        _ => Ok(()),
     }
+}
 
-    // When the specified file is not found by exact match, directories are
-    // scanned for "name@revision-date.yang" files, the latest (sorted by
-    // YYYY-MM-DD revision-date) of candidates will be selected.
-    revisions.sort();
+/// Look up a (sub)module stored by [`insert_revision`].
+fn find_revision<'a, N>(
+    map: &'a BTreeMap<String, N>,
+    name: &str,
+    revision: Option<&str>,
+    revision_of: impl Fn(&N) -> Option<&str>,
+) -> Option<&'a N> {
+    let latest = map.get(name);
+    match revision {
+        None => latest,
+        Some(revision) => latest
+            .filter(|m| revision_of(m) == Some(revision))
+            .or_else(|| map.get(&format!("{name}@{revision}"))),
+    }
+}
 
-    Ok(revisions.pop().unwrap())
+/// Store a (sub)module under its name, keeping the latest revision
+/// there and any other under `name@revision`, so the revisions that
+/// different importers pin coexist and an unpinned lookup sees the
+/// latest.
+fn insert_revision<N>(
+    map: &mut BTreeMap<String, N>,
+    node: N,
+    key_of: impl Fn(&N) -> (&String, Option<&str>),
+) {
+    let (name, revision) = key_of(&node);
+    let (name, revision) = (name.clone(), revision.map(str::to_string));
+    let loaded = map.get(&name).and_then(|m| key_of(m).1.map(str::to_string));
+    // Without a revision on both sides there is nothing to tell them
+    // apart by; the one loaded last replaces the other.
+    let (Some(loaded), Some(revision)) = (loaded, revision) else {
+        map.insert(name, node);
+        return;
+    };
+    if loaded < revision {
+        let previous = map.insert(name.clone(), node).unwrap();
+        map.insert(format!("{name}@{loaded}"), previous);
+    } else if loaded > revision {
+        map.insert(format!("{name}@{revision}"), node);
+    } else {
+        map.insert(name, node);
+    }
+}
+
+fn load_text(found: ModuleText) -> Result<Node, YangError> {
+    let path = found.origin;
+    let mut yang_grammar = YangGrammar::new();
+    match parse(&found.text, &path, &mut yang_grammar) {
+        Ok(_) => yang(yang_grammar),
+        // Hand the diagnostic back to the caller rather than
+        // printing it: a library has no business writing to stdout,
+        // and the position information is what makes the failure
+        // actionable.
+        Err(source) => Err(YangError::ParseError {
+            path,
+            source: Box::new(source),
+        }),
+    }
 }
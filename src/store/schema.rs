@@ -0,0 +1,343 @@
+use crate::*;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::{Arc, Weak};
+
+/// A frozen copy of an [`Entry`], made by [`Entry::freeze`] once
+/// `to_entry` has built the tree and applied every augment.
+///
+/// An `Entry` tree is built from `Rc` and `RefCell` so it can be
+/// grown in place, which ties it to one thread. A `SchemaNode` tree is
+/// immutable and `Arc`-linked: it is `Send + Sync`, and cloning the
+/// root `Arc` shares the whole tree between threads. The fields and
+/// navigation methods mirror `Entry`'s.
+#[derive(Debug)]
+pub struct SchemaNode {
+    pub name: String,
+    pub kind: EntryKind,
+    pub presence: bool,
+    pub mandatory: bool,
+    pub dir: Vec<Arc<SchemaNode>>,
+    pub key: Vec<String>,
+    pub extension: HashMap<String, String>,
+    pub parent: Weak<SchemaNode>,
+    pub type_node: Option<TypeNode>,
+    pub list_attr: Option<ListAttr>,
+    pub choice: Option<String>,
+    pub case: Option<String>,
+    pub choice_defs: Vec<QualifiedName>,
+    pub choices: Vec<Arc<SchemaNode>>,
+    pub when: Option<String>,
+    pub default: Option<String>,
+    pub must: Vec<String>,
+    pub status: StatusNodeEnum,
+    pub module: String,
+    provenance: Provenance,
+    pub config: bool,
+    pub help: Option<Box<EntryHelp>>,
+}
+
+impl SchemaNode {
+    pub(crate) fn freeze(entry: &Entry, parent: Weak<SchemaNode>) -> Arc<SchemaNode> {
+        Arc::new_cyclic(|me| {
+            let data = entry.dir.borrow();
+            let dir: Vec<Arc<SchemaNode>> = data
+                .iter()
+                .map(|child| SchemaNode::freeze(child, me.clone()))
+                .collect();
+            let choices = entry
+                .choices
+                .borrow()
+                .iter()
+                .map(|choice| SchemaNode::freeze_choice(choice, me.clone(), &data, &dir))
+                .collect();
+            SchemaNode::copy(entry, parent, dir, choices)
+        })
+    }
+
+    /// Freeze a choice defined under the entry whose children are
+    /// `data`, already frozen as `frozen`. The cases share those nodes
+    /// rather than copying them.
+    fn freeze_choice(
+        choice: &Entry,
+        parent: Weak<SchemaNode>,
+        data: &[Rc<Entry>],
+        frozen: &[Arc<SchemaNode>],
+    ) -> Arc<SchemaNode> {
+        Arc::new_cyclic(|me| {
+            let cases = choice
+                .dir
+                .borrow()
+                .iter()
+                .map(|case| {
+                    Arc::new_cyclic(|case_me| {
+                        let dir = case
+                            .dir
+                            .borrow()
+                            .iter()
+                            .filter_map(|child| {
+                                if child.is_choice() {
+                                    let parent = case_me.clone();
+                                    Some(SchemaNode::freeze_choice(child, parent, data, frozen))
+                                } else {
+                                    let i = data.iter().position(|d| Rc::ptr_eq(d, child))?;
+                                    Some(frozen[i].clone())
+                                }
+                            })
+                            .collect();
+                        SchemaNode::copy(case, me.clone(), dir, Vec::new())
+                    })
+                })
+                .collect();
+            SchemaNode::copy(choice, parent, cases, Vec::new())
+        })
+    }
+
+    fn copy(
+        entry: &Entry,
+        parent: Weak<SchemaNode>,
+        dir: Vec<Arc<SchemaNode>>,
+        choices: Vec<Arc<SchemaNode>>,
+    ) -> SchemaNode {
+        SchemaNode {
+            name: entry.name.clone(),
+            kind: entry.kind,
+            presence: entry.presence,
+            mandatory: entry.mandatory,
+            dir,
+            key: entry.key.clone(),
+            extension: entry.extension.clone(),
+            parent,
+            type_node: entry.type_node.clone(),
+            list_attr: entry.list_attr.clone(),
+            choice: entry.choice.borrow().clone(),
+            case: entry.case.borrow().clone(),
+            choice_defs: entry.choice_defs.borrow().clone(),
+            choices,
+            when: entry.when.clone(),
+            default: entry.default.clone(),
+            must: entry.must.clone(),
+            status: entry.status,
+            module: entry.module.clone(),
+            provenance: entry.provenance.clone(),
+            config: entry.config,
+            help: entry.help.clone(),
+        }
+    }
+
+    /// Which module defines this node, and the groupings and augment it
+    /// came through.
+    pub fn provenance(&self) -> &Provenance {
+        &self.provenance
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.help.as_ref()?.description.as_deref()
+    }
+
+    pub fn reference(&self) -> Option<&str> {
+        self.help.as_ref()?.reference.as_deref()
+    }
+
+    pub fn units(&self) -> Option<&str> {
+        self.help.as_ref()?.units.as_deref()
+    }
+
+    /// The node this one is a child of, or `None` at the root.
+    pub fn parent(&self) -> Option<Arc<SchemaNode>> {
+        self.parent.upgrade()
+    }
+
+    pub fn has_key(&self) -> bool {
+        !self.key.is_empty()
+    }
+
+    pub fn is_directory_entry(&self) -> bool {
+        self.kind == EntryKind::DirectoryEntry
+    }
+
+    pub fn is_leaf_entry(&self) -> bool {
+        self.kind == EntryKind::LeafEntry
+    }
+
+    pub fn is_container(&self) -> bool {
+        self.kind == EntryKind::DirectoryEntry && self.list_attr.is_none()
+    }
+
+    pub fn is_list(&self) -> bool {
+        self.kind == EntryKind::DirectoryEntry && self.list_attr.is_some()
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        self.kind == EntryKind::LeafEntry && self.list_attr.is_none()
+    }
+
+    pub fn is_leaflist(&self) -> bool {
+        self.kind == EntryKind::LeafEntry && self.list_attr.is_some()
+    }
+
+    pub fn is_empty_leaf(&self) -> bool {
+        if self.kind == EntryKind::LeafEntry
+            && let Some(n) = self.type_node.as_ref()
+            && n.kind == YangType::Empty
+        {
+            return true;
+        }
+        false
+    }
+
+    pub fn is_choice(&self) -> bool {
+        self.kind == EntryKind::ChoiceEntry
+    }
+
+    pub fn is_case(&self) -> bool {
+        self.kind == EntryKind::CaseEntry
+    }
+
+    /// The choice named `name` defined directly under this node.
+    pub fn find_choice(&self, name: &str) -> Option<Arc<SchemaNode>> {
+        self.choices.iter().find(|c| c.name == name).cloned()
+    }
+
+    /// The names of the choices defined directly under this node,
+    /// without their modules.
+    pub fn choice_def_names(&self) -> Vec<String> {
+        self.choice_defs.iter().map(|q| q.name.clone()).collect()
+    }
+
+    /// The case of this choice named `name`.
+    pub fn find_case(&self, name: &str) -> Option<Arc<SchemaNode>> {
+        self.dir
+            .iter()
+            .filter(|c| c.is_case())
+            .find(|c| c.name == name)
+            .cloned()
+    }
+
+    pub fn is_action(&self) -> bool {
+        self.kind == EntryKind::ActionEntry
+    }
+
+    pub fn is_notification(&self) -> bool {
+        self.kind == EntryKind::NotificationEntry
+    }
+
+    pub fn is_anydata(&self) -> bool {
+        self.kind == EntryKind::AnydataEntry
+    }
+
+    pub fn is_anyxml(&self) -> bool {
+        self.kind == EntryKind::AnyxmlEntry
+    }
+
+    /// The absolute schema node identifier of this node, as
+    /// [`Entry::schema_path`] writes it.
+    pub fn schema_path(&self) -> String {
+        let mut segments = Vec::new();
+        self.path_segments(true, &mut segments);
+        segments
+            .iter()
+            .map(|(module, name)| format!("/{module}:{name}"))
+            .collect()
+    }
+
+    /// The data path of this node, as [`Entry::data_path`] writes it.
+    pub fn data_path(&self) -> String {
+        let mut segments = Vec::new();
+        self.path_segments(false, &mut segments);
+        let mut path = String::new();
+        let mut namespace = "";
+        for (module, name) in segments.iter() {
+            if module == namespace {
+                path.push_str(&format!("/{name}"));
+            } else {
+                path.push_str(&format!("/{module}:{name}"));
+                namespace = module;
+            }
+        }
+        path
+    }
+
+    fn path_segments(&self, schema: bool, segments: &mut Vec<(String, String)>) {
+        let Some(parent) = self.parent() else {
+            return;
+        };
+        parent.path_segments(schema, segments);
+        if schema {
+            segments.extend(parent.choice_path(self, &parent.choices));
+        } else if parent.is_action() {
+            return;
+        }
+        segments.push((self.module.clone(), self.name.clone()));
+    }
+
+    fn choice_path(&self, node: &SchemaNode, choices: &[Arc<SchemaNode>]) -> Vec<(String, String)> {
+        for choice in choices {
+            for case in choice.dir.iter() {
+                let mut path = vec![
+                    (choice.module.clone(), choice.name.clone()),
+                    (case.module.clone(), case.name.clone()),
+                ];
+                if case.dir.iter().any(|e| std::ptr::eq(Arc::as_ptr(e), node)) {
+                    return path;
+                }
+                let nested: Vec<Arc<SchemaNode>> =
+                    case.dir.iter().filter(|e| e.is_choice()).cloned().collect();
+                let inner = self.choice_path(node, &nested);
+                if !inner.is_empty() {
+                    path.extend(inner);
+                    return path;
+                }
+            }
+        }
+        Vec::new()
+    }
+
+    /// Find the node below this one at `path`, as
+    /// [`Entry::find_by_path`] does.
+    pub fn find_by_path(&self, path: &str) -> Option<Arc<SchemaNode>> {
+        fn find(nodes: &[Arc<SchemaNode>], name: &QualifiedName) -> Option<Arc<SchemaNode>> {
+            nodes
+                .iter()
+                .find(|e| e.name == name.name && e.module == name.module)
+                .cloned()
+        }
+        let mut current: Option<Arc<SchemaNode>> = None;
+        let mut namespace = self.module.clone();
+        // The choice or case a schema path is walking through.
+        let mut within: Option<Arc<SchemaNode>> = None;
+        for seg in path.split('/').filter(|s| !s.is_empty()) {
+            let (module, name) = match seg.split_once(':') {
+                Some((module, name)) => (module.to_string(), name),
+                None => (namespace.clone(), seg),
+            };
+            let name = QualifiedName::new(&module, name);
+            namespace = module;
+
+            if let Some(member) = within.take() {
+                let next = find(&member.dir, &name)?;
+                if next.is_choice() || next.is_case() {
+                    within = Some(next);
+                } else {
+                    current = Some(next);
+                }
+                continue;
+            }
+            let at = current.as_deref().unwrap_or(self);
+            let next = find(&at.dir, &name).or_else(|| {
+                at.dir
+                    .iter()
+                    .filter(|_| at.is_action())
+                    .find_map(|io| find(&io.dir, &name))
+            });
+            match next {
+                Some(next) => current = Some(next),
+                None => within = Some(find(&at.choices, &name)?),
+            }
+        }
+        if within.is_some() {
+            return None;
+        }
+        current
+    }
+}
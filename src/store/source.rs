@@ -0,0 +1,256 @@
+use crate::*;
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::fmt::Debug;
+use std::fs::{self};
+use std::path::{Path, PathBuf};
+
+/// The text of a module or submodule, as a [`ModuleSource`] found it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleText {
+    pub text: String,
+    /// Where the text came from: a file path, or a label shaped like
+    /// one. Parse errors and their diagnostics name it.
+    pub origin: PathBuf,
+    /// The revision the source filed the text under, as in
+    /// `name@revision.yang`, if it did. It lets the store tell a
+    /// revision it already holds without parsing the text again; what
+    /// the text itself says is checked once it is parsed.
+    pub revision: Option<String>,
+}
+
+/// Where a [`YangStore`] looks for modules and submodules.
+///
+/// A source files each text under `name` or `name@revision`. For an
+/// unpinned lookup it returns `name`, or else the latest
+/// `name@revision`; for a pinned one, `name@revision`, or else `name`,
+/// which may hold that revision too. [`select_revision`] applies these
+/// rules to a source's list of names.
+pub trait ModuleSource: Debug {
+    /// Find `name` at `revision`, or at its latest revision when
+    /// `revision` is `None`. `Ok(None)` means this source does not
+    /// have it.
+    fn find(&self, name: &str, revision: Option<&str>) -> Result<Option<ModuleText>, YangError>;
+}
+
+/// Pick, among the names a source files its texts under (each `name`
+/// or `name@revision`, with or without `.yang`), the one to return for
+/// `name` at `revision`.
+pub fn select_revision<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+    name: &str,
+    revision: Option<&str>,
+) -> Option<&'a str> {
+    let mut exact = None;
+    let mut pinned = None;
+    let mut latest: Option<(&str, &str)> = None;
+    for candidate in names {
+        let stem = candidate.trim_end_matches(".yang");
+        if stem == name {
+            exact = Some(candidate);
+        } else if let Some(r) = file_revision(stem, name) {
+            if Some(r) == revision {
+                pinned = Some(candidate);
+            }
+            if latest.is_none_or(|(l, _)| r > l) {
+                latest = Some((r, candidate));
+            }
+        }
+    }
+    match revision {
+        Some(_) => pinned.or(exact),
+        None => exact.or(latest.map(|(_, c)| c)),
+    }
+}
+
+/// The revision in a `name@revision` file name.
+pub(crate) fn file_revision<'a>(file_name: &'a str, name: &str) -> Option<&'a str> {
+    file_name
+        .trim_end_matches(".yang")
+        .strip_prefix(name)?
+        .strip_prefix('@')
+}
+
+/// Modules in `name.yang` and `name@revision.yang` files in a
+/// directory. A path whose last component is `...` stands for its
+/// parent and every directory below it, searched in turn when the
+/// directory itself does not have the module.
+#[derive(Debug, Clone)]
+pub struct DirectorySource {
+    path: PathBuf,
+}
+
+impl DirectorySource {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    fn search(&self, file_name: &str) -> Option<PathBuf> {
+        if self.path.file_name() == Some(OsStr::new("...")) {
+            let mut dir = self.path.clone();
+            if dir.pop()
+                && let Ok(found) = find_in_dir(&dir, file_name, true)
+            {
+                return Some(found);
+            }
+        }
+        find_in_dir(&self.path, file_name, false).ok()
+    }
+}
+
+impl ModuleSource for DirectorySource {
+    fn find(&self, name: &str, revision: Option<&str>) -> Result<Option<ModuleText>, YangError> {
+        let found = match revision {
+            Some(revision) => self
+                .search(&format!("{name}@{revision}"))
+                .or_else(|| self.search(name)),
+            None => self.search(name),
+        };
+        let Some(path) = found else {
+            return Ok(None);
+        };
+        let text = fs::read_to_string(&path).map_err(|source| YangError::IoError {
+            path: path.clone(),
+            source,
+        })?;
+        let revision = path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .and_then(|f| file_revision(f, name))
+            .map(str::to_string);
+        Ok(Some(ModuleText {
+            text,
+            origin: path,
+            revision,
+        }))
+    }
+}
+
+/// Module texts held in memory, each filed under `name` or
+/// `name@revision`. Their origin is reported as `<memory>/name.yang`.
+#[derive(Debug, Clone, Default)]
+pub struct MemorySource {
+    modules: BTreeMap<String, String>,
+}
+
+impl MemorySource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the text of `name`, which may carry a revision as
+    /// `name@revision`. Text already filed under the same name is
+    /// replaced.
+    pub fn add(&mut self, name: &str, text: impl Into<String>) {
+        let name = name.trim_end_matches(".yang");
+        self.modules.insert(name.to_string(), text.into());
+    }
+}
+
+impl ModuleSource for MemorySource {
+    fn find(&self, name: &str, revision: Option<&str>) -> Result<Option<ModuleText>, YangError> {
+        let keys = self.modules.keys().map(String::as_str);
+        Ok(select_revision(keys, name, revision).map(|key| ModuleText {
+            text: self.modules[key].clone(),
+            origin: Path::new("<memory>").join(format!("{key}.yang")),
+            revision: file_revision(key, name).map(str::to_string),
+        }))
+    }
+}
+
+/// Module texts compiled into the program, as `(file name, text)`
+/// pairs, typically with `include_str!`:
+///
+/// ```
+/// use libyang::{EmbeddedSource, YangStore};
+///
+/// static MODULES: &[(&str, &str)] = &[(
+///     "ietf-inet-types@2013-07-15.yang",
+///     include_str!("../../yang/ietf-inet-types@2013-07-15.yang"),
+/// )];
+///
+/// let mut store = YangStore::new();
+/// store.add_source(EmbeddedSource::new(MODULES));
+/// store.read_with_resolve("ietf-inet-types").unwrap();
+/// ```
+///
+/// Their origin is reported as `<embedded>/` followed by the file name.
+#[derive(Debug, Clone, Copy)]
+pub struct EmbeddedSource {
+    modules: &'static [(&'static str, &'static str)],
+}
+
+impl EmbeddedSource {
+    pub const fn new(modules: &'static [(&'static str, &'static str)]) -> Self {
+        Self { modules }
+    }
+}
+
+impl ModuleSource for EmbeddedSource {
+    fn find(&self, name: &str, revision: Option<&str>) -> Result<Option<ModuleText>, YangError> {
+        let names = self.modules.iter().map(|(file_name, _)| *file_name);
+        let Some(file_name) = select_revision(names, name, revision) else {
+            return Ok(None);
+        };
+        let (_, text) = self.modules.iter().find(|(f, _)| *f == file_name).unwrap();
+        Ok(Some(ModuleText {
+            text: text.to_string(),
+            origin: Path::new("<embedded>").join(file_name),
+            revision: file_revision(file_name, name).map(str::to_string),
+        }))
+    }
+}
+
+fn find_in_dir(dir: &PathBuf, module_name: &str, recursive: bool) -> Result<PathBuf, YangError> {
+    let mut file_name = String::from(module_name);
+    if !file_name.ends_with(".yang") {
+        file_name.push_str(".yang");
+    }
+
+    let mut basename = String::from(module_name.trim_end_matches(".yang"));
+    basename.push('@');
+
+    let mut revisions = vec![];
+
+    let dirent = fs::read_dir(dir).map_err(|source| YangError::IoError {
+        path: dir.clone(),
+        source,
+    })?;
+    for entry in dirent.into_iter().flatten() {
+        if let Ok(file_type) = entry.file_type() {
+            if file_type.is_file() {
+                if let Some(os_str) = entry.path().file_name()
+                    && let Some(file_str) = os_str.to_str()
+                {
+                    if file_str == file_name {
+                        return Ok(entry.path());
+                    }
+                    // When module_name does not contain '@'.
+                    if module_name.find('@').is_none() {
+                        // Try revision match such as 'ietf-dhcp@2016-08-25.yang'.
+                        if file_str.starts_with(&basename) && file_str.ends_with(".yang") {
+                            revisions.push(entry.path());
+                        }
+                    }
+                }
+            } else if file_type.is_dir()
+                && recursive
+                && let Ok(path) = find_in_dir(&entry.path(), module_name, recursive)
+            {
+                return Ok(path);
+            }
+        }
+    }
+    if revisions.is_empty() {
+        return Err(YangError::FileNotFound {
+            name: module_name.to_string(),
+        });
+    }
+
+    // When the specified file is not found by exact match, directories are
+    // scanned for "name@revision-date.yang" files, the latest (sorted by
+    // YYYY-MM-DD revision-date) of candidates will be selected.
+    revisions.sort();
+
+    Ok(revisions.pop().unwrap())
+}
@@ -20,10 +20,31 @@ pub enum YangError {
         source: std::io::Error,
     },
 
-    /// No file matching the module name was found on the search path.
+    /// No file matching the module name, or holding the revision an
+    /// import or include pins, was found on the search path. A pinned
+    /// revision is named as `name@revision`.
     #[error("YANG module `{name}` not found in the search path")]
     FileNotFound { name: String },
 
+    /// A module named as implemented has not been loaded into the store.
+    #[error("implemented YANG module `{name}` is not loaded")]
+    ModuleNotLoaded { name: String },
+
+    /// A module is named as implemented at two revisions; a module is
+    /// implemented at one revision only (RFC 7950 §5.6.5). Another
+    /// revision may still be loaded import-only.
+    #[error("implemented YANG module `{name}` is needed at revisions {revision} and {other}")]
+    RevisionConflict {
+        name: String,
+        revision: String,
+        other: String,
+    },
+
+    /// A yang-library instance (RFC 8525) could not be read, or the
+    /// modules it lists do not form a complete module set.
+    #[error("invalid YANG library: {reason}")]
+    InvalidLibrary { reason: String },
+
     /// The file was read but did not parse as YANG.
     #[error("{}: {source}", path.display())]
     ParseError {
@@ -184,6 +184,11 @@ pub trait YangGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'KwDeviate'
+    fn kw_deviate(&mut self, _arg: &KwDeviate<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'KwYangVersion'
     fn kw_yang_version(&mut self, _arg: &KwYangVersion<'t>) -> Result<()> {
         Ok(())
@@ -329,6 +334,21 @@ pub trait YangGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'DeviateStmt'
+    fn deviate_stmt(&mut self, _arg: &DeviateStmt<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'DeviateArgStr'
+    fn deviate_arg_str(&mut self, _arg: &DeviateArgStr<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'DeviateArg'
+    fn deviate_arg(&mut self, _arg: &DeviateArg<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'RpcStmt'
     fn rpc_stmt(&mut self, _arg: &RpcStmt<'t>) -> Result<()> {
         Ok(())
@@ -935,7 +955,7 @@ pub trait YangGrammarTrait<'t> {
 //
 
 ///
-/// Type derived for production 50
+/// Type derived for production 51
 ///
 /// `Yang: ModuleStmt;`
 ///
@@ -946,7 +966,7 @@ pub struct YangModuleStmt<'t> {
 }
 
 ///
-/// Type derived for production 51
+/// Type derived for production 52
 ///
 /// `Yang: SubmoduleStmt;`
 ///
@@ -957,7 +977,7 @@ pub struct YangSubmoduleStmt<'t> {
 }
 
 ///
-/// Type derived for production 74
+/// Type derived for production 75
 ///
 /// `ModuleHeaderStmts: YangVersionStmt;`
 ///
@@ -968,7 +988,7 @@ pub struct ModuleHeaderStmtsYangVersionStmt<'t> {
 }
 
 ///
-/// Type derived for production 75
+/// Type derived for production 76
 ///
 /// `ModuleHeaderStmts: NamespaceStmt;`
 ///
@@ -979,7 +999,7 @@ pub struct ModuleHeaderStmtsNamespaceStmt<'t> {
 }
 
 ///
-/// Type derived for production 76
+/// Type derived for production 77
 ///
 /// `ModuleHeaderStmts: PrefixStmt;`
 ///
@@ -990,7 +1010,7 @@ pub struct ModuleHeaderStmtsPrefixStmt<'t> {
 }
 
 ///
-/// Type derived for production 77
+/// Type derived for production 78
 ///
 /// `SubmoduleHeaderStmts: YangVersionStmt;`
 ///
@@ -1001,7 +1021,7 @@ pub struct SubmoduleHeaderStmtsYangVersionStmt<'t> {
 }
 
 ///
-/// Type derived for production 78
+/// Type derived for production 79
 ///
 /// `SubmoduleHeaderStmts: BelongsToStmt;`
 ///
@@ -1012,7 +1032,7 @@ pub struct SubmoduleHeaderStmtsBelongsToStmt<'t> {
 }
 
 ///
-/// Type derived for production 79
+/// Type derived for production 80
 ///
 /// `MetaStmts: OrganizationStmt;`
 ///
@@ -1023,7 +1043,7 @@ pub struct MetaStmtsOrganizationStmt<'t> {
 }
 
 ///
-/// Type derived for production 80
+/// Type derived for production 81
 ///
 /// `MetaStmts: ContactStmt;`
 ///
@@ -1034,7 +1054,7 @@ pub struct MetaStmtsContactStmt<'t> {
 }
 
 ///
-/// Type derived for production 81
+/// Type derived for production 82
 ///
 /// `MetaStmts: DescriptionStmt;`
 ///
@@ -1045,7 +1065,7 @@ pub struct MetaStmtsDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 82
+/// Type derived for production 83
 ///
 /// `MetaStmts: ReferenceStmt;`
 ///
@@ -1056,7 +1076,7 @@ pub struct MetaStmtsReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 83
+/// Type derived for production 84
 ///
 /// `LinkageStmts: ImportStmt;`
 ///
@@ -1067,7 +1087,7 @@ pub struct LinkageStmtsImportStmt<'t> {
 }
 
 ///
-/// Type derived for production 84
+/// Type derived for production 85
 ///
 /// `LinkageStmts: IncludeStmt;`
 ///
@@ -1078,7 +1098,7 @@ pub struct LinkageStmtsIncludeStmt<'t> {
 }
 
 ///
-/// Type derived for production 85
+/// Type derived for production 86
 ///
 /// `BodyStmts: ExtensionStmt;`
 ///
@@ -1089,7 +1109,7 @@ pub struct BodyStmtsExtensionStmt<'t> {
 }
 
 ///
-/// Type derived for production 86
+/// Type derived for production 87
 ///
 /// `BodyStmts: FeatureStmt;`
 ///
@@ -1100,7 +1120,7 @@ pub struct BodyStmtsFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 87
+/// Type derived for production 88
 ///
 /// `BodyStmts: IdentityStmt;`
 ///
@@ -1111,7 +1131,7 @@ pub struct BodyStmtsIdentityStmt<'t> {
 }
 
 ///
-/// Type derived for production 88
+/// Type derived for production 89
 ///
 /// `BodyStmts: TypedefStmt;`
 ///
@@ -1122,7 +1142,7 @@ pub struct BodyStmtsTypedefStmt<'t> {
 }
 
 ///
-/// Type derived for production 89
+/// Type derived for production 90
 ///
 /// `BodyStmts: GroupingStmt;`
 ///
@@ -1133,7 +1153,7 @@ pub struct BodyStmtsGroupingStmt<'t> {
 }
 
 ///
-/// Type derived for production 90
+/// Type derived for production 91
 ///
 /// `BodyStmts: DataDefStmt;`
 ///
@@ -1144,7 +1164,7 @@ pub struct BodyStmtsDataDefStmt<'t> {
 }
 
 ///
-/// Type derived for production 91
+/// Type derived for production 92
 ///
 /// `BodyStmts: AugmentStmt;`
 ///
@@ -1155,7 +1175,7 @@ pub struct BodyStmtsAugmentStmt<'t> {
 }
 
 ///
-/// Type derived for production 92
+/// Type derived for production 93
 ///
 /// `BodyStmts: RpcStmt;`
 ///
@@ -1166,7 +1186,7 @@ pub struct BodyStmtsRpcStmt<'t> {
 }
 
 ///
-/// Type derived for production 93
+/// Type derived for production 94
 ///
 /// `BodyStmts: NotificationStmt;`
 ///
@@ -1177,7 +1197,7 @@ pub struct BodyStmtsNotificationStmt<'t> {
 }
 
 ///
-/// Type derived for production 94
+/// Type derived for production 95
 ///
 /// `BodyStmts: DeviationStmt;`
 ///
@@ -1188,7 +1208,7 @@ pub struct BodyStmtsDeviationStmt<'t> {
 }
 
 ///
-/// Type derived for production 95
+/// Type derived for production 96
 ///
 /// `BodyStmts: UnknownStmt;`
 ///
@@ -1199,7 +1219,7 @@ pub struct BodyStmtsUnknownStmt<'t> {
 }
 
 ///
-/// Type derived for production 96
+/// Type derived for production 97
 ///
 /// `DataDefStmt: ContainerStmt;`
 ///
@@ -1210,7 +1230,7 @@ pub struct DataDefStmtContainerStmt<'t> {
 }
 
 ///
-/// Type derived for production 97
+/// Type derived for production 98
 ///
 /// `DataDefStmt: LeafStmt;`
 ///
@@ -1221,7 +1241,7 @@ pub struct DataDefStmtLeafStmt<'t> {
 }
 
 ///
-/// Type derived for production 98
+/// Type derived for production 99
 ///
 /// `DataDefStmt: LeafListStmt;`
 ///
@@ -1232,7 +1252,7 @@ pub struct DataDefStmtLeafListStmt<'t> {
 }
 
 ///
-/// Type derived for production 99
+/// Type derived for production 100
 ///
 /// `DataDefStmt: ListStmt;`
 ///
@@ -1243,7 +1263,7 @@ pub struct DataDefStmtListStmt<'t> {
 }
 
 ///
-/// Type derived for production 100
+/// Type derived for production 101
 ///
 /// `DataDefStmt: ChoiceStmt;`
 ///
@@ -1254,7 +1274,7 @@ pub struct DataDefStmtChoiceStmt<'t> {
 }
 
 ///
-/// Type derived for production 101
+/// Type derived for production 102
 ///
 /// `DataDefStmt: AnydataStmt;`
 ///
@@ -1265,7 +1285,7 @@ pub struct DataDefStmtAnydataStmt<'t> {
 }
 
 ///
-/// Type derived for production 102
+/// Type derived for production 103
 ///
 /// `DataDefStmt: AnyxmlStmt;`
 ///
@@ -1276,7 +1296,7 @@ pub struct DataDefStmtAnyxmlStmt<'t> {
 }
 
 ///
-/// Type derived for production 103
+/// Type derived for production 104
 ///
 /// `DataDefStmt: UsesStmt;`
 ///
@@ -1287,7 +1307,7 @@ pub struct DataDefStmtUsesStmt<'t> {
 }
 
 ///
-/// Type derived for production 105
+/// Type derived for production 106
 ///
 /// `YangVersionArgStr: YangVersionArg;`
 ///
@@ -1298,7 +1318,7 @@ pub struct YangVersionArgStrYangVersionArg<'t> {
 }
 
 ///
-/// Type derived for production 106
+/// Type derived for production 107
 ///
 /// `YangVersionArgStr: <YVersion>'"'^ /* Clipped */ YangVersionArg <YVersion>'"'^ /* Clipped */;`
 ///
@@ -1311,6 +1331,200 @@ pub struct YangVersionArgStrDoubleQuotationYangVersionArgDoubleQuotation<'t> {
 ///
 /// Type derived for production 109
 ///
+/// `DeviationStmtSuffix: Semicolon^ /* Clipped */;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviationStmtSuffixSemicolon {}
+
+///
+/// Type derived for production 110
+///
+/// `DeviationStmtSuffix: LBrace^ /* Clipped */ DeviationStmtList /* Vec */ '}'^ /* Clipped */;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviationStmtSuffixLBraceDeviationStmtListRBrace<'t> {
+    pub deviation_stmt_list: Vec<DeviationStmtList<'t>>,
+}
+
+///
+/// Type derived for production 112
+///
+/// `DeviationStmtListGroup: DescriptionStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviationStmtListGroupDescriptionStmt<'t> {
+    pub description_stmt: Box<DescriptionStmt<'t>>,
+}
+
+///
+/// Type derived for production 113
+///
+/// `DeviationStmtListGroup: ReferenceStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviationStmtListGroupReferenceStmt<'t> {
+    pub reference_stmt: Box<ReferenceStmt<'t>>,
+}
+
+///
+/// Type derived for production 114
+///
+/// `DeviationStmtListGroup: DeviateStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviationStmtListGroupDeviateStmt<'t> {
+    pub deviate_stmt: Box<DeviateStmt<'t>>,
+}
+
+///
+/// Type derived for production 117
+///
+/// `DeviateStmtSuffix: Semicolon^ /* Clipped */;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmtSuffixSemicolon {}
+
+///
+/// Type derived for production 118
+///
+/// `DeviateStmtSuffix: LBrace^ /* Clipped */ DeviateStmtList /* Vec */ '}'^ /* Clipped */;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmtSuffixLBraceDeviateStmtListRBrace<'t> {
+    pub deviate_stmt_list: Vec<DeviateStmtList<'t>>,
+}
+
+///
+/// Type derived for production 120
+///
+/// `DeviateStmtListGroup: UnitsStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmtListGroupUnitsStmt<'t> {
+    pub units_stmt: Box<UnitsStmt<'t>>,
+}
+
+///
+/// Type derived for production 121
+///
+/// `DeviateStmtListGroup: MustStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmtListGroupMustStmt<'t> {
+    pub must_stmt: Box<MustStmt<'t>>,
+}
+
+///
+/// Type derived for production 122
+///
+/// `DeviateStmtListGroup: UnknownStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmtListGroupUnknownStmt<'t> {
+    pub unknown_stmt: Box<UnknownStmt<'t>>,
+}
+
+///
+/// Type derived for production 123
+///
+/// `DeviateStmtListGroup: DefaultStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmtListGroupDefaultStmt<'t> {
+    pub default_stmt: Box<DefaultStmt<'t>>,
+}
+
+///
+/// Type derived for production 124
+///
+/// `DeviateStmtListGroup: ConfigStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmtListGroupConfigStmt<'t> {
+    pub config_stmt: Box<ConfigStmt<'t>>,
+}
+
+///
+/// Type derived for production 125
+///
+/// `DeviateStmtListGroup: MandatoryStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmtListGroupMandatoryStmt<'t> {
+    pub mandatory_stmt: Box<MandatoryStmt<'t>>,
+}
+
+///
+/// Type derived for production 126
+///
+/// `DeviateStmtListGroup: MinElementsStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmtListGroupMinElementsStmt<'t> {
+    pub min_elements_stmt: Box<MinElementsStmt<'t>>,
+}
+
+///
+/// Type derived for production 127
+///
+/// `DeviateStmtListGroup: MaxElementsStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmtListGroupMaxElementsStmt<'t> {
+    pub max_elements_stmt: Box<MaxElementsStmt<'t>>,
+}
+
+///
+/// Type derived for production 128
+///
+/// `DeviateStmtListGroup: TypeStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmtListGroupTypeStmt<'t> {
+    pub type_stmt: Box<TypeStmt<'t>>,
+}
+
+///
+/// Type derived for production 130
+///
+/// `DeviateArgStr: DeviateArg;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateArgStrDeviateArg<'t> {
+    pub deviate_arg: Box<DeviateArg<'t>>,
+}
+
+///
+/// Type derived for production 131
+///
+/// `DeviateArgStr: <Deviate>'"'^ /* Clipped */ DeviateArg <Deviate>'"'^ /* Clipped */;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateArgStrDoubleQuotationDeviateArgDoubleQuotation<'t> {
+    pub deviate_arg: Box<DeviateArg<'t>>,
+}
+
+///
+/// Type derived for production 134
+///
 /// `RpcStmtSuffix: Semicolon;`
 ///
 #[allow(dead_code)]
@@ -1320,7 +1534,7 @@ pub struct RpcStmtSuffixSemicolon<'t> {
 }
 
 ///
-/// Type derived for production 110
+/// Type derived for production 135
 ///
 /// `RpcStmtSuffix: LBrace^ /* Clipped */ RpcStmtList /* Vec */ '}';`
 ///
@@ -1332,7 +1546,7 @@ pub struct RpcStmtSuffixLBraceRpcStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 112
+/// Type derived for production 137
 ///
 /// `RpcStmtListGroup: IfFeatureStmt;`
 ///
@@ -1343,7 +1557,7 @@ pub struct RpcStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 113
+/// Type derived for production 138
 ///
 /// `RpcStmtListGroup: StatusStmt;`
 ///
@@ -1354,7 +1568,7 @@ pub struct RpcStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 114
+/// Type derived for production 139
 ///
 /// `RpcStmtListGroup: DescriptionStmt;`
 ///
@@ -1365,7 +1579,7 @@ pub struct RpcStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 115
+/// Type derived for production 140
 ///
 /// `RpcStmtListGroup: ReferenceStmt;`
 ///
@@ -1376,7 +1590,7 @@ pub struct RpcStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 116
+/// Type derived for production 141
 ///
 /// `RpcStmtListGroup: TypedefStmt;`
 ///
@@ -1387,7 +1601,7 @@ pub struct RpcStmtListGroupTypedefStmt<'t> {
 }
 
 ///
-/// Type derived for production 117
+/// Type derived for production 142
 ///
 /// `RpcStmtListGroup: GroupingStmt;`
 ///
@@ -1398,7 +1612,7 @@ pub struct RpcStmtListGroupGroupingStmt<'t> {
 }
 
 ///
-/// Type derived for production 118
+/// Type derived for production 143
 ///
 /// `RpcStmtListGroup: InputStmt;`
 ///
@@ -1409,7 +1623,7 @@ pub struct RpcStmtListGroupInputStmt<'t> {
 }
 
 ///
-/// Type derived for production 119
+/// Type derived for production 144
 ///
 /// `RpcStmtListGroup: OutputStmt;`
 ///
@@ -1420,7 +1634,7 @@ pub struct RpcStmtListGroupOutputStmt<'t> {
 }
 
 ///
-/// Type derived for production 122
+/// Type derived for production 147
 ///
 /// `ExtensionStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -1429,7 +1643,7 @@ pub struct RpcStmtListGroupOutputStmt<'t> {
 pub struct ExtensionStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 123
+/// Type derived for production 148
 ///
 /// `ExtensionStmtSuffix: LBrace^ /* Clipped */ ExtensionStmtList /* Vec */ '}'^ /* Clipped */;`
 ///
@@ -1440,7 +1654,7 @@ pub struct ExtensionStmtSuffixLBraceExtensionStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 125
+/// Type derived for production 150
 ///
 /// `ExtensionStmtListGroup: ArgumentStmt;`
 ///
@@ -1451,7 +1665,7 @@ pub struct ExtensionStmtListGroupArgumentStmt<'t> {
 }
 
 ///
-/// Type derived for production 126
+/// Type derived for production 151
 ///
 /// `ExtensionStmtListGroup: StatusStmt;`
 ///
@@ -1462,7 +1676,7 @@ pub struct ExtensionStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 127
+/// Type derived for production 152
 ///
 /// `ExtensionStmtListGroup: DescriptionStmt;`
 ///
@@ -1473,7 +1687,7 @@ pub struct ExtensionStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 128
+/// Type derived for production 153
 ///
 /// `ExtensionStmtListGroup: ReferenceStmt;`
 ///
@@ -1484,7 +1698,7 @@ pub struct ExtensionStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 132
+/// Type derived for production 157
 ///
 /// `FeatureStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -1493,7 +1707,7 @@ pub struct ExtensionStmtListGroupReferenceStmt<'t> {
 pub struct FeatureStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 133
+/// Type derived for production 158
 ///
 /// `FeatureStmtSuffix: LBrace^ /* Clipped */ FeatureStmtList /* Vec */ '}';`
 ///
@@ -1505,7 +1719,7 @@ pub struct FeatureStmtSuffixLBraceFeatureStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 135
+/// Type derived for production 160
 ///
 /// `FeatureStmtListGroup: IfFeatureStmt;`
 ///
@@ -1516,7 +1730,7 @@ pub struct FeatureStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 136
+/// Type derived for production 161
 ///
 /// `FeatureStmtListGroup: StatusStmt;`
 ///
@@ -1527,7 +1741,7 @@ pub struct FeatureStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 137
+/// Type derived for production 162
 ///
 /// `FeatureStmtListGroup: DescriptionStmt;`
 ///
@@ -1538,7 +1752,7 @@ pub struct FeatureStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 138
+/// Type derived for production 163
 ///
 /// `FeatureStmtListGroup: ReferenceStmt;`
 ///
@@ -1549,7 +1763,7 @@ pub struct FeatureStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 142
+/// Type derived for production 167
 ///
 /// `TypedefStmtListGroup: TypeStmt;`
 ///
@@ -1560,7 +1774,7 @@ pub struct TypedefStmtListGroupTypeStmt<'t> {
 }
 
 ///
-/// Type derived for production 143
+/// Type derived for production 168
 ///
 /// `TypedefStmtListGroup: UnitsStmt;`
 ///
@@ -1571,7 +1785,7 @@ pub struct TypedefStmtListGroupUnitsStmt<'t> {
 }
 
 ///
-/// Type derived for production 144
+/// Type derived for production 169
 ///
 /// `TypedefStmtListGroup: DefaultStmt;`
 ///
@@ -1582,7 +1796,7 @@ pub struct TypedefStmtListGroupDefaultStmt<'t> {
 }
 
 ///
-/// Type derived for production 145
+/// Type derived for production 170
 ///
 /// `TypedefStmtListGroup: StatusStmt;`
 ///
@@ -1593,7 +1807,7 @@ pub struct TypedefStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 146
+/// Type derived for production 171
 ///
 /// `TypedefStmtListGroup: DescriptionStmt;`
 ///
@@ -1604,7 +1818,7 @@ pub struct TypedefStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 147
+/// Type derived for production 172
 ///
 /// `TypedefStmtListGroup: ReferenceStmt;`
 ///
@@ -1615,7 +1829,7 @@ pub struct TypedefStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 151
+/// Type derived for production 176
 ///
 /// `AugmentStmtListGroup: WhenStmt;`
 ///
@@ -1626,7 +1840,7 @@ pub struct AugmentStmtListGroupWhenStmt<'t> {
 }
 
 ///
-/// Type derived for production 152
+/// Type derived for production 177
 ///
 /// `AugmentStmtListGroup: IfFeatureStmt;`
 ///
@@ -1637,7 +1851,7 @@ pub struct AugmentStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 153
+/// Type derived for production 178
 ///
 /// `AugmentStmtListGroup: StatusStmt;`
 ///
@@ -1648,7 +1862,7 @@ pub struct AugmentStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 154
+/// Type derived for production 179
 ///
 /// `AugmentStmtListGroup: DescriptionStmt;`
 ///
@@ -1659,7 +1873,7 @@ pub struct AugmentStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 155
+/// Type derived for production 180
 ///
 /// `AugmentStmtListGroup: ReferenceStmt;`
 ///
@@ -1670,7 +1884,7 @@ pub struct AugmentStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 156
+/// Type derived for production 181
 ///
 /// `AugmentStmtListGroup: DataDefStmt;`
 ///
@@ -1681,7 +1895,7 @@ pub struct AugmentStmtListGroupDataDefStmt<'t> {
 }
 
 ///
-/// Type derived for production 157
+/// Type derived for production 182
 ///
 /// `AugmentStmtListGroup: CaseStmt;`
 ///
@@ -1692,7 +1906,7 @@ pub struct AugmentStmtListGroupCaseStmt<'t> {
 }
 
 ///
-/// Type derived for production 158
+/// Type derived for production 183
 ///
 /// `AugmentStmtListGroup: ActionStmt;`
 ///
@@ -1703,7 +1917,7 @@ pub struct AugmentStmtListGroupActionStmt<'t> {
 }
 
 ///
-/// Type derived for production 159
+/// Type derived for production 184
 ///
 /// `AugmentStmtListGroup: NotificationStmt;`
 ///
@@ -1714,7 +1928,7 @@ pub struct AugmentStmtListGroupNotificationStmt<'t> {
 }
 
 ///
-/// Type derived for production 163
+/// Type derived for production 188
 ///
 /// `WhenStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -1723,7 +1937,7 @@ pub struct AugmentStmtListGroupNotificationStmt<'t> {
 pub struct WhenStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 164
+/// Type derived for production 189
 ///
 /// `WhenStmtSuffix: LBrace^ /* Clipped */ WhenStmtList /* Vec */ '}';`
 ///
@@ -1735,7 +1949,7 @@ pub struct WhenStmtSuffixLBraceWhenStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 166
+/// Type derived for production 191
 ///
 /// `WhenStmtListGroup: DescriptionStmt;`
 ///
@@ -1746,7 +1960,7 @@ pub struct WhenStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 167
+/// Type derived for production 192
 ///
 /// `WhenStmtListGroup: ReferenceStmt;`
 ///
@@ -1757,7 +1971,7 @@ pub struct WhenStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 171
+/// Type derived for production 196
 ///
 /// `GroupingStmtListGroup: StatusStmt;`
 ///
@@ -1768,7 +1982,7 @@ pub struct GroupingStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 172
+/// Type derived for production 197
 ///
 /// `GroupingStmtListGroup: DescriptionStmt;`
 ///
@@ -1779,7 +1993,7 @@ pub struct GroupingStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 173
+/// Type derived for production 198
 ///
 /// `GroupingStmtListGroup: ReferenceStmt;`
 ///
@@ -1790,7 +2004,7 @@ pub struct GroupingStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 174
+/// Type derived for production 199
 ///
 /// `GroupingStmtListGroup: TypedefStmt;`
 ///
@@ -1801,7 +2015,7 @@ pub struct GroupingStmtListGroupTypedefStmt<'t> {
 }
 
 ///
-/// Type derived for production 175
+/// Type derived for production 200
 ///
 /// `GroupingStmtListGroup: GroupingStmt;`
 ///
@@ -1812,7 +2026,7 @@ pub struct GroupingStmtListGroupGroupingStmt<'t> {
 }
 
 ///
-/// Type derived for production 176
+/// Type derived for production 201
 ///
 /// `GroupingStmtListGroup: DataDefStmt;`
 ///
@@ -1823,7 +2037,7 @@ pub struct GroupingStmtListGroupDataDefStmt<'t> {
 }
 
 ///
-/// Type derived for production 177
+/// Type derived for production 202
 ///
 /// `GroupingStmtListGroup: ActionStmt;`
 ///
@@ -1834,7 +2048,7 @@ pub struct GroupingStmtListGroupActionStmt<'t> {
 }
 
 ///
-/// Type derived for production 178
+/// Type derived for production 203
 ///
 /// `GroupingStmtListGroup: NotificationStmt;`
 ///
@@ -1845,7 +2059,7 @@ pub struct GroupingStmtListGroupNotificationStmt<'t> {
 }
 
 ///
-/// Type derived for production 179
+/// Type derived for production 204
 ///
 /// `GroupingStmtListGroup: UnknownStmt;`
 ///
@@ -1856,7 +2070,7 @@ pub struct GroupingStmtListGroupUnknownStmt<'t> {
 }
 
 ///
-/// Type derived for production 183
+/// Type derived for production 208
 ///
 /// `IdentityStmtListGroup: IfFeatureStmt;`
 ///
@@ -1867,7 +2081,7 @@ pub struct IdentityStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 184
+/// Type derived for production 209
 ///
 /// `IdentityStmtListGroup: BaseStmt;`
 ///
@@ -1878,7 +2092,18 @@ pub struct IdentityStmtListGroupBaseStmt<'t> {
 }
 
 ///
-/// Type derived for production 185
+/// Type derived for production 210
+///
+/// `IdentityStmtListGroup: StatusStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct IdentityStmtListGroupStatusStmt<'t> {
+    pub status_stmt: Box<StatusStmt<'t>>,
+}
+
+///
+/// Type derived for production 211
 ///
 /// `IdentityStmtListGroup: DescriptionStmt;`
 ///
@@ -1889,7 +2114,7 @@ pub struct IdentityStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 186
+/// Type derived for production 212
 ///
 /// `IdentityStmtListGroup: ReferenceStmt;`
 ///
@@ -1900,7 +2125,7 @@ pub struct IdentityStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 190
+/// Type derived for production 216
 ///
 /// `AnyxmlStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -1909,7 +2134,7 @@ pub struct IdentityStmtListGroupReferenceStmt<'t> {
 pub struct AnyxmlStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 191
+/// Type derived for production 217
 ///
 /// `AnyxmlStmtSuffix: LBrace^ /* Clipped */ AnyxmlStmtList /* Vec */ '}'^ /* Clipped */;`
 ///
@@ -1920,7 +2145,7 @@ pub struct AnyxmlStmtSuffixLBraceAnyxmlStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 193
+/// Type derived for production 219
 ///
 /// `AnyxmlStmtListGroup: WhenStmt;`
 ///
@@ -1931,7 +2156,7 @@ pub struct AnyxmlStmtListGroupWhenStmt<'t> {
 }
 
 ///
-/// Type derived for production 194
+/// Type derived for production 220
 ///
 /// `AnyxmlStmtListGroup: IfFeatureStmt;`
 ///
@@ -1942,7 +2167,7 @@ pub struct AnyxmlStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 195
+/// Type derived for production 221
 ///
 /// `AnyxmlStmtListGroup: MustStmt;`
 ///
@@ -1953,7 +2178,7 @@ pub struct AnyxmlStmtListGroupMustStmt<'t> {
 }
 
 ///
-/// Type derived for production 196
+/// Type derived for production 222
 ///
 /// `AnyxmlStmtListGroup: ConfigStmt;`
 ///
@@ -1964,7 +2189,7 @@ pub struct AnyxmlStmtListGroupConfigStmt<'t> {
 }
 
 ///
-/// Type derived for production 197
+/// Type derived for production 223
 ///
 /// `AnyxmlStmtListGroup: MandatoryStmt;`
 ///
@@ -1975,7 +2200,7 @@ pub struct AnyxmlStmtListGroupMandatoryStmt<'t> {
 }
 
 ///
-/// Type derived for production 198
+/// Type derived for production 224
 ///
 /// `AnyxmlStmtListGroup: StatusStmt;`
 ///
@@ -1986,7 +2211,7 @@ pub struct AnyxmlStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 199
+/// Type derived for production 225
 ///
 /// `AnyxmlStmtListGroup: DescriptionStmt;`
 ///
@@ -1997,7 +2222,7 @@ pub struct AnyxmlStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 200
+/// Type derived for production 226
 ///
 /// `AnyxmlStmtListGroup: ReferenceStmt;`
 ///
@@ -2008,7 +2233,7 @@ pub struct AnyxmlStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 203
+/// Type derived for production 229
 ///
 /// `ChoiceStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -2017,7 +2242,7 @@ pub struct AnyxmlStmtListGroupReferenceStmt<'t> {
 pub struct ChoiceStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 204
+/// Type derived for production 230
 ///
 /// `ChoiceStmtSuffix: LBrace^ /* Clipped */ ChoiceStmtList /* Vec */ '}';`
 ///
@@ -2029,7 +2254,7 @@ pub struct ChoiceStmtSuffixLBraceChoiceStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 206
+/// Type derived for production 232
 ///
 /// `ChoiceStmtListGroup: WhenStmt;`
 ///
@@ -2040,7 +2265,7 @@ pub struct ChoiceStmtListGroupWhenStmt<'t> {
 }
 
 ///
-/// Type derived for production 207
+/// Type derived for production 233
 ///
 /// `ChoiceStmtListGroup: IfFeatureStmt;`
 ///
@@ -2051,7 +2276,7 @@ pub struct ChoiceStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 208
+/// Type derived for production 234
 ///
 /// `ChoiceStmtListGroup: DefaultStmt;`
 ///
@@ -2062,7 +2287,7 @@ pub struct ChoiceStmtListGroupDefaultStmt<'t> {
 }
 
 ///
-/// Type derived for production 209
+/// Type derived for production 235
 ///
 /// `ChoiceStmtListGroup: ConfigStmt;`
 ///
@@ -2073,7 +2298,7 @@ pub struct ChoiceStmtListGroupConfigStmt<'t> {
 }
 
 ///
-/// Type derived for production 210
+/// Type derived for production 236
 ///
 /// `ChoiceStmtListGroup: MandatoryStmt;`
 ///
@@ -2084,7 +2309,7 @@ pub struct ChoiceStmtListGroupMandatoryStmt<'t> {
 }
 
 ///
-/// Type derived for production 211
+/// Type derived for production 237
 ///
 /// `ChoiceStmtListGroup: StatusStmt;`
 ///
@@ -2095,7 +2320,7 @@ pub struct ChoiceStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 212
+/// Type derived for production 238
 ///
 /// `ChoiceStmtListGroup: DescriptionStmt;`
 ///
@@ -2106,7 +2331,7 @@ pub struct ChoiceStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 213
+/// Type derived for production 239
 ///
 /// `ChoiceStmtListGroup: ReferenceStmt;`
 ///
@@ -2117,7 +2342,7 @@ pub struct ChoiceStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 214
+/// Type derived for production 240
 ///
 /// `ChoiceStmtListGroup: ShortCaseStmt;`
 ///
@@ -2128,7 +2353,7 @@ pub struct ChoiceStmtListGroupShortCaseStmt<'t> {
 }
 
 ///
-/// Type derived for production 215
+/// Type derived for production 241
 ///
 /// `ChoiceStmtListGroup: CaseStmt;`
 ///
@@ -2139,7 +2364,7 @@ pub struct ChoiceStmtListGroupCaseStmt<'t> {
 }
 
 ///
-/// Type derived for production 217
+/// Type derived for production 243
 ///
 /// `ShortCaseStmt: ChoiceStmt;`
 ///
@@ -2150,7 +2375,7 @@ pub struct ShortCaseStmtChoiceStmt<'t> {
 }
 
 ///
-/// Type derived for production 218
+/// Type derived for production 244
 ///
 /// `ShortCaseStmt: ContainerStmt;`
 ///
@@ -2161,7 +2386,7 @@ pub struct ShortCaseStmtContainerStmt<'t> {
 }
 
 ///
-/// Type derived for production 219
+/// Type derived for production 245
 ///
 /// `ShortCaseStmt: LeafStmt;`
 ///
@@ -2172,7 +2397,7 @@ pub struct ShortCaseStmtLeafStmt<'t> {
 }
 
 ///
-/// Type derived for production 220
+/// Type derived for production 246
 ///
 /// `ShortCaseStmt: LeafListStmt;`
 ///
@@ -2183,7 +2408,7 @@ pub struct ShortCaseStmtLeafListStmt<'t> {
 }
 
 ///
-/// Type derived for production 221
+/// Type derived for production 247
 ///
 /// `ShortCaseStmt: ListStmt;`
 ///
@@ -2194,7 +2419,7 @@ pub struct ShortCaseStmtListStmt<'t> {
 }
 
 ///
-/// Type derived for production 222
+/// Type derived for production 248
 ///
 /// `ShortCaseStmt: AnydataStmt;`
 ///
@@ -2205,7 +2430,7 @@ pub struct ShortCaseStmtAnydataStmt<'t> {
 }
 
 ///
-/// Type derived for production 223
+/// Type derived for production 249
 ///
 /// `ShortCaseStmt: AnyxmlStmt;`
 ///
@@ -2216,7 +2441,7 @@ pub struct ShortCaseStmtAnyxmlStmt<'t> {
 }
 
 ///
-/// Type derived for production 225
+/// Type derived for production 251
 ///
 /// `AnydataStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -2225,7 +2450,7 @@ pub struct ShortCaseStmtAnyxmlStmt<'t> {
 pub struct AnydataStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 226
+/// Type derived for production 252
 ///
 /// `AnydataStmtSuffix: LBrace^ /* Clipped */ AnydataStmtList /* Vec */ '}'^ /* Clipped */;`
 ///
@@ -2236,7 +2461,7 @@ pub struct AnydataStmtSuffixLBraceAnydataStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 228
+/// Type derived for production 254
 ///
 /// `AnydataStmtListGroup: WhenStmt;`
 ///
@@ -2247,7 +2472,7 @@ pub struct AnydataStmtListGroupWhenStmt<'t> {
 }
 
 ///
-/// Type derived for production 229
+/// Type derived for production 255
 ///
 /// `AnydataStmtListGroup: IfFeatureStmt;`
 ///
@@ -2258,7 +2483,7 @@ pub struct AnydataStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 230
+/// Type derived for production 256
 ///
 /// `AnydataStmtListGroup: MustStmt;`
 ///
@@ -2269,7 +2494,7 @@ pub struct AnydataStmtListGroupMustStmt<'t> {
 }
 
 ///
-/// Type derived for production 231
+/// Type derived for production 257
 ///
 /// `AnydataStmtListGroup: ConfigStmt;`
 ///
@@ -2280,7 +2505,7 @@ pub struct AnydataStmtListGroupConfigStmt<'t> {
 }
 
 ///
-/// Type derived for production 232
+/// Type derived for production 258
 ///
 /// `AnydataStmtListGroup: MandatoryStmt;`
 ///
@@ -2291,7 +2516,7 @@ pub struct AnydataStmtListGroupMandatoryStmt<'t> {
 }
 
 ///
-/// Type derived for production 233
+/// Type derived for production 259
 ///
 /// `AnydataStmtListGroup: StatusStmt;`
 ///
@@ -2302,7 +2527,7 @@ pub struct AnydataStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 234
+/// Type derived for production 260
 ///
 /// `AnydataStmtListGroup: DescriptionStmt;`
 ///
@@ -2313,7 +2538,7 @@ pub struct AnydataStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 235
+/// Type derived for production 261
 ///
 /// `AnydataStmtListGroup: ReferenceStmt;`
 ///
@@ -2324,7 +2549,7 @@ pub struct AnydataStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 238
+/// Type derived for production 264
 ///
 /// `CaseStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -2333,7 +2558,7 @@ pub struct AnydataStmtListGroupReferenceStmt<'t> {
 pub struct CaseStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 239
+/// Type derived for production 265
 ///
 /// `CaseStmtSuffix: LBrace^ /* Clipped */ CaseStmtList /* Vec */ '}'^ /* Clipped */;`
 ///
@@ -2344,7 +2569,7 @@ pub struct CaseStmtSuffixLBraceCaseStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 241
+/// Type derived for production 267
 ///
 /// `CaseStmtListGroup: WhenStmt;`
 ///
@@ -2355,7 +2580,7 @@ pub struct CaseStmtListGroupWhenStmt<'t> {
 }
 
 ///
-/// Type derived for production 242
+/// Type derived for production 268
 ///
 /// `CaseStmtListGroup: IfFeatureStmt;`
 ///
@@ -2366,7 +2591,18 @@ pub struct CaseStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 243
+/// Type derived for production 269
+///
+/// `CaseStmtListGroup: StatusStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct CaseStmtListGroupStatusStmt<'t> {
+    pub status_stmt: Box<StatusStmt<'t>>,
+}
+
+///
+/// Type derived for production 270
 ///
 /// `CaseStmtListGroup: DataDefStmt;`
 ///
@@ -2377,7 +2613,7 @@ pub struct CaseStmtListGroupDataDefStmt<'t> {
 }
 
 ///
-/// Type derived for production 244
+/// Type derived for production 271
 ///
 /// `CaseStmtListGroup: DescriptionStmt;`
 ///
@@ -2388,7 +2624,7 @@ pub struct CaseStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 245
+/// Type derived for production 272
 ///
 /// `CaseStmtListGroup: ReferenceStmt;`
 ///
@@ -2399,7 +2635,7 @@ pub struct CaseStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 248
+/// Type derived for production 275
 ///
 /// `StatusArgStr: StatusArg;`
 ///
@@ -2410,7 +2646,7 @@ pub struct StatusArgStrStatusArg<'t> {
 }
 
 ///
-/// Type derived for production 249
+/// Type derived for production 276
 ///
 /// `StatusArgStr: <Status>'"'^ /* Clipped */ StatusArg <Status>'"'^ /* Clipped */;`
 ///
@@ -2421,7 +2657,7 @@ pub struct StatusArgStrDoubleQuotationStatusArgDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 252
+/// Type derived for production 279
 ///
 /// `ContainerStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -2430,7 +2666,7 @@ pub struct StatusArgStrDoubleQuotationStatusArgDoubleQuotation<'t> {
 pub struct ContainerStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 253
+/// Type derived for production 280
 ///
 /// `ContainerStmtSuffix: LBrace^ /* Clipped */ ContainerStmtList /* Vec */ '}'^ /* Clipped */;`
 ///
@@ -2441,7 +2677,7 @@ pub struct ContainerStmtSuffixLBraceContainerStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 255
+/// Type derived for production 282
 ///
 /// `ContainerStmtListGroup: WhenStmt;`
 ///
@@ -2452,7 +2688,7 @@ pub struct ContainerStmtListGroupWhenStmt<'t> {
 }
 
 ///
-/// Type derived for production 256
+/// Type derived for production 283
 ///
 /// `ContainerStmtListGroup: IfFeatureStmt;`
 ///
@@ -2463,7 +2699,7 @@ pub struct ContainerStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 257
+/// Type derived for production 284
 ///
 /// `ContainerStmtListGroup: MustStmt;`
 ///
@@ -2474,7 +2710,7 @@ pub struct ContainerStmtListGroupMustStmt<'t> {
 }
 
 ///
-/// Type derived for production 258
+/// Type derived for production 285
 ///
 /// `ContainerStmtListGroup: PresenceStmt;`
 ///
@@ -2485,7 +2721,7 @@ pub struct ContainerStmtListGroupPresenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 259
+/// Type derived for production 286
 ///
 /// `ContainerStmtListGroup: ConfigStmt;`
 ///
@@ -2496,7 +2732,7 @@ pub struct ContainerStmtListGroupConfigStmt<'t> {
 }
 
 ///
-/// Type derived for production 260
+/// Type derived for production 287
 ///
 /// `ContainerStmtListGroup: StatusStmt;`
 ///
@@ -2507,7 +2743,7 @@ pub struct ContainerStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 261
+/// Type derived for production 288
 ///
 /// `ContainerStmtListGroup: DescriptionStmt;`
 ///
@@ -2518,7 +2754,7 @@ pub struct ContainerStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 262
+/// Type derived for production 289
 ///
 /// `ContainerStmtListGroup: ReferenceStmt;`
 ///
@@ -2529,7 +2765,29 @@ pub struct ContainerStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 263
+/// Type derived for production 290
+///
+/// `ContainerStmtListGroup: TypedefStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ContainerStmtListGroupTypedefStmt<'t> {
+    pub typedef_stmt: Box<TypedefStmt<'t>>,
+}
+
+///
+/// Type derived for production 291
+///
+/// `ContainerStmtListGroup: GroupingStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ContainerStmtListGroupGroupingStmt<'t> {
+    pub grouping_stmt: Box<GroupingStmt<'t>>,
+}
+
+///
+/// Type derived for production 292
 ///
 /// `ContainerStmtListGroup: DataDefStmt;`
 ///
@@ -2540,7 +2798,7 @@ pub struct ContainerStmtListGroupDataDefStmt<'t> {
 }
 
 ///
-/// Type derived for production 264
+/// Type derived for production 293
 ///
 /// `ContainerStmtListGroup: ActionStmt;`
 ///
@@ -2551,7 +2809,7 @@ pub struct ContainerStmtListGroupActionStmt<'t> {
 }
 
 ///
-/// Type derived for production 265
+/// Type derived for production 294
 ///
 /// `ContainerStmtListGroup: NotificationStmt;`
 ///
@@ -2562,7 +2820,7 @@ pub struct ContainerStmtListGroupNotificationStmt<'t> {
 }
 
 ///
-/// Type derived for production 266
+/// Type derived for production 295
 ///
 /// `ContainerStmtListGroup: UnknownStmt;`
 ///
@@ -2573,7 +2831,7 @@ pub struct ContainerStmtListGroupUnknownStmt<'t> {
 }
 
 ///
-/// Type derived for production 269
+/// Type derived for production 298
 ///
 /// `ActionStmtSuffix: Semicolon;`
 ///
@@ -2584,7 +2842,7 @@ pub struct ActionStmtSuffixSemicolon<'t> {
 }
 
 ///
-/// Type derived for production 270
+/// Type derived for production 299
 ///
 /// `ActionStmtSuffix: LBrace^ /* Clipped */ ActionStmtList /* Vec */ '}';`
 ///
@@ -2596,7 +2854,7 @@ pub struct ActionStmtSuffixLBraceActionStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 272
+/// Type derived for production 301
 ///
 /// `ActionStmtListGroup: IfFeatureStmt;`
 ///
@@ -2607,7 +2865,7 @@ pub struct ActionStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 273
+/// Type derived for production 302
 ///
 /// `ActionStmtListGroup: StatusStmt;`
 ///
@@ -2618,7 +2876,7 @@ pub struct ActionStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 274
+/// Type derived for production 303
 ///
 /// `ActionStmtListGroup: DescriptionStmt;`
 ///
@@ -2629,7 +2887,7 @@ pub struct ActionStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 275
+/// Type derived for production 304
 ///
 /// `ActionStmtListGroup: ReferenceStmt;`
 ///
@@ -2640,7 +2898,29 @@ pub struct ActionStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 276
+/// Type derived for production 305
+///
+/// `ActionStmtListGroup: TypedefStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ActionStmtListGroupTypedefStmt<'t> {
+    pub typedef_stmt: Box<TypedefStmt<'t>>,
+}
+
+///
+/// Type derived for production 306
+///
+/// `ActionStmtListGroup: GroupingStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ActionStmtListGroupGroupingStmt<'t> {
+    pub grouping_stmt: Box<GroupingStmt<'t>>,
+}
+
+///
+/// Type derived for production 307
 ///
 /// `ActionStmtListGroup: InputStmt;`
 ///
@@ -2651,7 +2931,7 @@ pub struct ActionStmtListGroupInputStmt<'t> {
 }
 
 ///
-/// Type derived for production 277
+/// Type derived for production 308
 ///
 /// `ActionStmtListGroup: OutputStmt;`
 ///
@@ -2662,7 +2942,73 @@ pub struct ActionStmtListGroupOutputStmt<'t> {
 }
 
 ///
-/// Type derived for production 287
+/// Type derived for production 312
+///
+/// `InputStmtListGroup: TypedefStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct InputStmtListGroupTypedefStmt<'t> {
+    pub typedef_stmt: Box<TypedefStmt<'t>>,
+}
+
+///
+/// Type derived for production 313
+///
+/// `InputStmtListGroup: GroupingStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct InputStmtListGroupGroupingStmt<'t> {
+    pub grouping_stmt: Box<GroupingStmt<'t>>,
+}
+
+///
+/// Type derived for production 314
+///
+/// `InputStmtListGroup: DataDefStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct InputStmtListGroupDataDefStmt<'t> {
+    pub data_def_stmt: Box<DataDefStmt<'t>>,
+}
+
+///
+/// Type derived for production 318
+///
+/// `OutputStmtListGroup: TypedefStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct OutputStmtListGroupTypedefStmt<'t> {
+    pub typedef_stmt: Box<TypedefStmt<'t>>,
+}
+
+///
+/// Type derived for production 319
+///
+/// `OutputStmtListGroup: GroupingStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct OutputStmtListGroupGroupingStmt<'t> {
+    pub grouping_stmt: Box<GroupingStmt<'t>>,
+}
+
+///
+/// Type derived for production 320
+///
+/// `OutputStmtListGroup: DataDefStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct OutputStmtListGroupDataDefStmt<'t> {
+    pub data_def_stmt: Box<DataDefStmt<'t>>,
+}
+
+///
+/// Type derived for production 324
 ///
 /// `NotificationStmtListGroup: IfFeatureStmt;`
 ///
@@ -2673,7 +3019,7 @@ pub struct NotificationStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 288
+/// Type derived for production 325
 ///
 /// `NotificationStmtListGroup: MustStmt;`
 ///
@@ -2684,7 +3030,7 @@ pub struct NotificationStmtListGroupMustStmt<'t> {
 }
 
 ///
-/// Type derived for production 289
+/// Type derived for production 326
 ///
 /// `NotificationStmtListGroup: StatusStmt;`
 ///
@@ -2695,7 +3041,7 @@ pub struct NotificationStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 290
+/// Type derived for production 327
 ///
 /// `NotificationStmtListGroup: DescriptionStmt;`
 ///
@@ -2706,7 +3052,7 @@ pub struct NotificationStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 291
+/// Type derived for production 328
 ///
 /// `NotificationStmtListGroup: ReferenceStmt;`
 ///
@@ -2717,7 +3063,7 @@ pub struct NotificationStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 292
+/// Type derived for production 329
 ///
 /// `NotificationStmtListGroup: TypedefStmt;`
 ///
@@ -2728,7 +3074,7 @@ pub struct NotificationStmtListGroupTypedefStmt<'t> {
 }
 
 ///
-/// Type derived for production 293
+/// Type derived for production 330
 ///
 /// `NotificationStmtListGroup: GroupingStmt;`
 ///
@@ -2739,7 +3085,7 @@ pub struct NotificationStmtListGroupGroupingStmt<'t> {
 }
 
 ///
-/// Type derived for production 294
+/// Type derived for production 331
 ///
 /// `NotificationStmtListGroup: DataDefStmt;`
 ///
@@ -2750,7 +3096,7 @@ pub struct NotificationStmtListGroupDataDefStmt<'t> {
 }
 
 ///
-/// Type derived for production 304
+/// Type derived for production 341
 ///
 /// `IfFeatureFactor: <IfFeature>'not' IfFeatureFactor;`
 ///
@@ -2762,7 +3108,7 @@ pub struct IfFeatureFactorNotIfFeatureFactor<'t> {
 }
 
 ///
-/// Type derived for production 305
+/// Type derived for production 342
 ///
 /// `IfFeatureFactor: <IfFeature>'(' IfFeatureExpr <IfFeature>')';`
 ///
@@ -2775,7 +3121,7 @@ pub struct IfFeatureFactorLParenIfFeatureExprRParen<'t> {
 }
 
 ///
-/// Type derived for production 306
+/// Type derived for production 343
 ///
 /// `IfFeatureFactor: Identifier;`
 ///
@@ -2786,7 +3132,7 @@ pub struct IfFeatureFactorIdentifier<'t> {
 }
 
 ///
-/// Type derived for production 307
+/// Type derived for production 344
 ///
 /// `IfFeatureFactor: <IfFeature>'"' Identifier <IfFeature>'"';`
 ///
@@ -2799,7 +3145,7 @@ pub struct IfFeatureFactorDoubleQuotationIdentifierDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 310
+/// Type derived for production 347
 ///
 /// `UsesStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -2808,7 +3154,7 @@ pub struct IfFeatureFactorDoubleQuotationIdentifierDoubleQuotation<'t> {
 pub struct UsesStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 311
+/// Type derived for production 348
 ///
 /// `UsesStmtSuffix: LBrace^ /* Clipped */ UsesStmtList /* Vec */ '}'^ /* Clipped */;`
 ///
@@ -2819,7 +3165,7 @@ pub struct UsesStmtSuffixLBraceUsesStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 313
+/// Type derived for production 350
 ///
 /// `UsesStmtListGroup: WhenStmt;`
 ///
@@ -2830,7 +3176,7 @@ pub struct UsesStmtListGroupWhenStmt<'t> {
 }
 
 ///
-/// Type derived for production 314
+/// Type derived for production 351
 ///
 /// `UsesStmtListGroup: IfFeatureStmt;`
 ///
@@ -2841,7 +3187,7 @@ pub struct UsesStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 315
+/// Type derived for production 352
 ///
 /// `UsesStmtListGroup: StatusStmt;`
 ///
@@ -2852,7 +3198,7 @@ pub struct UsesStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 316
+/// Type derived for production 353
 ///
 /// `UsesStmtListGroup: DescriptionStmt;`
 ///
@@ -2863,7 +3209,7 @@ pub struct UsesStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 317
+/// Type derived for production 354
 ///
 /// `UsesStmtListGroup: ReferenceStmt;`
 ///
@@ -2874,7 +3220,7 @@ pub struct UsesStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 318
+/// Type derived for production 355
 ///
 /// `UsesStmtListGroup: RefineStmt;`
 ///
@@ -2885,7 +3231,7 @@ pub struct UsesStmtListGroupRefineStmt<'t> {
 }
 
 ///
-/// Type derived for production 319
+/// Type derived for production 356
 ///
 /// `UsesStmtListGroup: AugmentStmt;`
 ///
@@ -2896,7 +3242,7 @@ pub struct UsesStmtListGroupAugmentStmt<'t> {
 }
 
 ///
-/// Type derived for production 322
+/// Type derived for production 359
 ///
 /// `RefineStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -2905,7 +3251,7 @@ pub struct UsesStmtListGroupAugmentStmt<'t> {
 pub struct RefineStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 323
+/// Type derived for production 360
 ///
 /// `RefineStmtSuffix: LBrace^ /* Clipped */ RefineStmtList /* Vec */ '}'^ /* Clipped */;`
 ///
@@ -2916,7 +3262,7 @@ pub struct RefineStmtSuffixLBraceRefineStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 325
+/// Type derived for production 362
 ///
 /// `RefineStmtListGroup: IfFeatureStmt;`
 ///
@@ -2927,7 +3273,7 @@ pub struct RefineStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 326
+/// Type derived for production 363
 ///
 /// `RefineStmtListGroup: MustStmt;`
 ///
@@ -2938,7 +3284,7 @@ pub struct RefineStmtListGroupMustStmt<'t> {
 }
 
 ///
-/// Type derived for production 327
+/// Type derived for production 364
 ///
 /// `RefineStmtListGroup: PresenceStmt;`
 ///
@@ -2949,7 +3295,7 @@ pub struct RefineStmtListGroupPresenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 328
+/// Type derived for production 365
 ///
 /// `RefineStmtListGroup: DefaultStmt;`
 ///
@@ -2960,7 +3306,7 @@ pub struct RefineStmtListGroupDefaultStmt<'t> {
 }
 
 ///
-/// Type derived for production 329
+/// Type derived for production 366
 ///
 /// `RefineStmtListGroup: ConfigStmt;`
 ///
@@ -2971,7 +3317,7 @@ pub struct RefineStmtListGroupConfigStmt<'t> {
 }
 
 ///
-/// Type derived for production 330
+/// Type derived for production 367
 ///
 /// `RefineStmtListGroup: MandatoryStmt;`
 ///
@@ -2982,7 +3328,7 @@ pub struct RefineStmtListGroupMandatoryStmt<'t> {
 }
 
 ///
-/// Type derived for production 331
+/// Type derived for production 368
 ///
 /// `RefineStmtListGroup: MinElementsStmt;`
 ///
@@ -2993,7 +3339,7 @@ pub struct RefineStmtListGroupMinElementsStmt<'t> {
 }
 
 ///
-/// Type derived for production 332
+/// Type derived for production 369
 ///
 /// `RefineStmtListGroup: MaxElementsStmt;`
 ///
@@ -3004,7 +3350,7 @@ pub struct RefineStmtListGroupMaxElementsStmt<'t> {
 }
 
 ///
-/// Type derived for production 333
+/// Type derived for production 370
 ///
 /// `RefineStmtListGroup: DescriptionStmt;`
 ///
@@ -3015,7 +3361,7 @@ pub struct RefineStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 334
+/// Type derived for production 371
 ///
 /// `RefineStmtListGroup: ReferenceStmt;`
 ///
@@ -3026,7 +3372,7 @@ pub struct RefineStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 336
+/// Type derived for production 373
 ///
 /// `RefineArgStr: RefineArg;`
 ///
@@ -3037,7 +3383,7 @@ pub struct RefineArgStrRefineArg<'t> {
 }
 
 ///
-/// Type derived for production 337
+/// Type derived for production 374
 ///
 /// `RefineArgStr: <Keyword>'"' RefineArg <Keyword>'"';`
 ///
@@ -3050,7 +3396,7 @@ pub struct RefineArgStrDoubleQuotationRefineArgDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 339
+/// Type derived for production 376
 ///
 /// `DescendantSchemaNodeid: IdentifierRef;`
 ///
@@ -3061,7 +3407,7 @@ pub struct DescendantSchemaNodeidIdentifierRef<'t> {
 }
 
 ///
-/// Type derived for production 340
+/// Type derived for production 377
 ///
 /// `DescendantSchemaNodeid: AbsoluteSchemaNodeid;`
 ///
@@ -3072,7 +3418,7 @@ pub struct DescendantSchemaNodeidAbsoluteSchemaNodeid<'t> {
 }
 
 ///
-/// Type derived for production 342
+/// Type derived for production 379
 ///
 /// `UnknownStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -3081,7 +3427,7 @@ pub struct DescendantSchemaNodeidAbsoluteSchemaNodeid<'t> {
 pub struct UnknownStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 343
+/// Type derived for production 380
 ///
 /// `UnknownStmtSuffix: LBrace^ /* Clipped */ UnknownStmtList /* Vec */ '}'^ /* Clipped */;`
 ///
@@ -3092,7 +3438,7 @@ pub struct UnknownStmtSuffixLBraceUnknownStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 345
+/// Type derived for production 382
 ///
 /// `UnknownStmtListGroup: TypeStmt;`
 ///
@@ -3103,7 +3449,7 @@ pub struct UnknownStmtListGroupTypeStmt<'t> {
 }
 
 ///
-/// Type derived for production 346
+/// Type derived for production 383
 ///
 /// `UnknownStmtListGroup: DescriptionStmt;`
 ///
@@ -3114,7 +3460,7 @@ pub struct UnknownStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 350
+/// Type derived for production 387
 ///
 /// `LeafListStmtListGroup: WhenStmt;`
 ///
@@ -3125,7 +3471,7 @@ pub struct LeafListStmtListGroupWhenStmt<'t> {
 }
 
 ///
-/// Type derived for production 351
+/// Type derived for production 388
 ///
 /// `LeafListStmtListGroup: IfFeatureStmt;`
 ///
@@ -3136,7 +3482,7 @@ pub struct LeafListStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 352
+/// Type derived for production 389
 ///
 /// `LeafListStmtListGroup: TypeStmt;`
 ///
@@ -3147,7 +3493,7 @@ pub struct LeafListStmtListGroupTypeStmt<'t> {
 }
 
 ///
-/// Type derived for production 353
+/// Type derived for production 390
 ///
 /// `LeafListStmtListGroup: UnitsStmt;`
 ///
@@ -3158,7 +3504,7 @@ pub struct LeafListStmtListGroupUnitsStmt<'t> {
 }
 
 ///
-/// Type derived for production 354
+/// Type derived for production 391
 ///
 /// `LeafListStmtListGroup: MustStmt;`
 ///
@@ -3169,7 +3515,7 @@ pub struct LeafListStmtListGroupMustStmt<'t> {
 }
 
 ///
-/// Type derived for production 355
+/// Type derived for production 392
 ///
 /// `LeafListStmtListGroup: DefaultStmt;`
 ///
@@ -3180,7 +3526,7 @@ pub struct LeafListStmtListGroupDefaultStmt<'t> {
 }
 
 ///
-/// Type derived for production 356
+/// Type derived for production 393
 ///
 /// `LeafListStmtListGroup: ConfigStmt;`
 ///
@@ -3191,7 +3537,7 @@ pub struct LeafListStmtListGroupConfigStmt<'t> {
 }
 
 ///
-/// Type derived for production 357
+/// Type derived for production 394
 ///
 /// `LeafListStmtListGroup: MinElementsStmt;`
 ///
@@ -3202,7 +3548,7 @@ pub struct LeafListStmtListGroupMinElementsStmt<'t> {
 }
 
 ///
-/// Type derived for production 358
+/// Type derived for production 395
 ///
 /// `LeafListStmtListGroup: MaxElementsStmt;`
 ///
@@ -3213,7 +3559,7 @@ pub struct LeafListStmtListGroupMaxElementsStmt<'t> {
 }
 
 ///
-/// Type derived for production 359
+/// Type derived for production 396
 ///
 /// `LeafListStmtListGroup: OrderedByStmt;`
 ///
@@ -3224,7 +3570,7 @@ pub struct LeafListStmtListGroupOrderedByStmt<'t> {
 }
 
 ///
-/// Type derived for production 360
+/// Type derived for production 397
 ///
 /// `LeafListStmtListGroup: StatusStmt;`
 ///
@@ -3235,7 +3581,7 @@ pub struct LeafListStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 361
+/// Type derived for production 398
 ///
 /// `LeafListStmtListGroup: DescriptionStmt;`
 ///
@@ -3246,7 +3592,7 @@ pub struct LeafListStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 362
+/// Type derived for production 399
 ///
 /// `LeafListStmtListGroup: ReferenceStmt;`
 ///
@@ -3257,7 +3603,7 @@ pub struct LeafListStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 363
+/// Type derived for production 400
 ///
 /// `LeafListStmtListGroup: UnknownStmt;`
 ///
@@ -3268,7 +3614,7 @@ pub struct LeafListStmtListGroupUnknownStmt<'t> {
 }
 
 ///
-/// Type derived for production 367
+/// Type derived for production 404
 ///
 /// `LeafStmtListGroup: WhenStmt;`
 ///
@@ -3279,7 +3625,7 @@ pub struct LeafStmtListGroupWhenStmt<'t> {
 }
 
 ///
-/// Type derived for production 368
+/// Type derived for production 405
 ///
 /// `LeafStmtListGroup: StatusStmt;`
 ///
@@ -3290,7 +3636,7 @@ pub struct LeafStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 369
+/// Type derived for production 406
 ///
 /// `LeafStmtListGroup: IfFeatureStmt;`
 ///
@@ -3301,7 +3647,7 @@ pub struct LeafStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 370
+/// Type derived for production 407
 ///
 /// `LeafStmtListGroup: TypeStmt;`
 ///
@@ -3312,7 +3658,7 @@ pub struct LeafStmtListGroupTypeStmt<'t> {
 }
 
 ///
-/// Type derived for production 371
+/// Type derived for production 408
 ///
 /// `LeafStmtListGroup: UnitsStmt;`
 ///
@@ -3323,7 +3669,7 @@ pub struct LeafStmtListGroupUnitsStmt<'t> {
 }
 
 ///
-/// Type derived for production 372
+/// Type derived for production 409
 ///
 /// `LeafStmtListGroup: MustStmt;`
 ///
@@ -3334,7 +3680,7 @@ pub struct LeafStmtListGroupMustStmt<'t> {
 }
 
 ///
-/// Type derived for production 373
+/// Type derived for production 410
 ///
 /// `LeafStmtListGroup: DefaultStmt;`
 ///
@@ -3345,7 +3691,7 @@ pub struct LeafStmtListGroupDefaultStmt<'t> {
 }
 
 ///
-/// Type derived for production 374
+/// Type derived for production 411
 ///
 /// `LeafStmtListGroup: ConfigStmt;`
 ///
@@ -3356,7 +3702,7 @@ pub struct LeafStmtListGroupConfigStmt<'t> {
 }
 
 ///
-/// Type derived for production 375
+/// Type derived for production 412
 ///
 /// `LeafStmtListGroup: MandatoryStmt;`
 ///
@@ -3367,7 +3713,7 @@ pub struct LeafStmtListGroupMandatoryStmt<'t> {
 }
 
 ///
-/// Type derived for production 376
+/// Type derived for production 413
 ///
 /// `LeafStmtListGroup: DescriptionStmt;`
 ///
@@ -3378,7 +3724,7 @@ pub struct LeafStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 377
+/// Type derived for production 414
 ///
 /// `LeafStmtListGroup: ReferenceStmt;`
 ///
@@ -3389,7 +3735,7 @@ pub struct LeafStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 378
+/// Type derived for production 415
 ///
 /// `LeafStmtListGroup: UnknownStmt;`
 ///
@@ -3400,7 +3746,7 @@ pub struct LeafStmtListGroupUnknownStmt<'t> {
 }
 
 ///
-/// Type derived for production 381
+/// Type derived for production 418
 ///
 /// `MustStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -3409,7 +3755,7 @@ pub struct LeafStmtListGroupUnknownStmt<'t> {
 pub struct MustStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 382
+/// Type derived for production 419
 ///
 /// `MustStmtSuffix: LBrace^ /* Clipped */ MustStmtList /* Vec */ '}'^ /* Clipped */;`
 ///
@@ -3420,7 +3766,7 @@ pub struct MustStmtSuffixLBraceMustStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 384
+/// Type derived for production 421
 ///
 /// `MustStmtListGroup: ErrorMessage;`
 ///
@@ -3431,7 +3777,7 @@ pub struct MustStmtListGroupErrorMessage<'t> {
 }
 
 ///
-/// Type derived for production 385
+/// Type derived for production 422
 ///
 /// `MustStmtListGroup: DescriptionStmt;`
 ///
@@ -3442,7 +3788,7 @@ pub struct MustStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 391
+/// Type derived for production 428
 ///
 /// `MandatoryArgStr: MandatoryArg;`
 ///
@@ -3453,7 +3799,7 @@ pub struct MandatoryArgStrMandatoryArg<'t> {
 }
 
 ///
-/// Type derived for production 392
+/// Type derived for production 429
 ///
 /// `MandatoryArgStr: <Mandatory>'"' MandatoryArg <Mandatory>'"';`
 ///
@@ -3466,7 +3812,7 @@ pub struct MandatoryArgStrDoubleQuotationMandatoryArgDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 396
+/// Type derived for production 433
 ///
 /// `ListStmtListGroup: WhenStmt;`
 ///
@@ -3477,7 +3823,7 @@ pub struct ListStmtListGroupWhenStmt<'t> {
 }
 
 ///
-/// Type derived for production 397
+/// Type derived for production 434
 ///
 /// `ListStmtListGroup: IfFeatureStmt;`
 ///
@@ -3488,7 +3834,7 @@ pub struct ListStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 398
+/// Type derived for production 435
 ///
 /// `ListStmtListGroup: MustStmt;`
 ///
@@ -3499,7 +3845,7 @@ pub struct ListStmtListGroupMustStmt<'t> {
 }
 
 ///
-/// Type derived for production 399
+/// Type derived for production 436
 ///
 /// `ListStmtListGroup: KeyStmt;`
 ///
@@ -3510,7 +3856,7 @@ pub struct ListStmtListGroupKeyStmt<'t> {
 }
 
 ///
-/// Type derived for production 400
+/// Type derived for production 437
 ///
 /// `ListStmtListGroup: ConfigStmt;`
 ///
@@ -3521,7 +3867,7 @@ pub struct ListStmtListGroupConfigStmt<'t> {
 }
 
 ///
-/// Type derived for production 401
+/// Type derived for production 438
 ///
 /// `ListStmtListGroup: MinElementsStmt;`
 ///
@@ -3532,7 +3878,7 @@ pub struct ListStmtListGroupMinElementsStmt<'t> {
 }
 
 ///
-/// Type derived for production 402
+/// Type derived for production 439
 ///
 /// `ListStmtListGroup: MaxElementsStmt;`
 ///
@@ -3543,7 +3889,7 @@ pub struct ListStmtListGroupMaxElementsStmt<'t> {
 }
 
 ///
-/// Type derived for production 403
+/// Type derived for production 440
 ///
 /// `ListStmtListGroup: OrderedByStmt;`
 ///
@@ -3554,7 +3900,7 @@ pub struct ListStmtListGroupOrderedByStmt<'t> {
 }
 
 ///
-/// Type derived for production 404
+/// Type derived for production 441
 ///
 /// `ListStmtListGroup: StatusStmt;`
 ///
@@ -3565,7 +3911,7 @@ pub struct ListStmtListGroupStatusStmt<'t> {
 }
 
 ///
-/// Type derived for production 405
+/// Type derived for production 442
 ///
 /// `ListStmtListGroup: DescriptionStmt;`
 ///
@@ -3576,7 +3922,7 @@ pub struct ListStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 406
+/// Type derived for production 443
 ///
 /// `ListStmtListGroup: ReferenceStmt;`
 ///
@@ -3587,7 +3933,29 @@ pub struct ListStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 407
+/// Type derived for production 444
+///
+/// `ListStmtListGroup: TypedefStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ListStmtListGroupTypedefStmt<'t> {
+    pub typedef_stmt: Box<TypedefStmt<'t>>,
+}
+
+///
+/// Type derived for production 445
+///
+/// `ListStmtListGroup: GroupingStmt;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ListStmtListGroupGroupingStmt<'t> {
+    pub grouping_stmt: Box<GroupingStmt<'t>>,
+}
+
+///
+/// Type derived for production 446
 ///
 /// `ListStmtListGroup: DataDefStmt;`
 ///
@@ -3598,7 +3966,7 @@ pub struct ListStmtListGroupDataDefStmt<'t> {
 }
 
 ///
-/// Type derived for production 408
+/// Type derived for production 447
 ///
 /// `ListStmtListGroup: ActionStmt;`
 ///
@@ -3609,7 +3977,7 @@ pub struct ListStmtListGroupActionStmt<'t> {
 }
 
 ///
-/// Type derived for production 409
+/// Type derived for production 448
 ///
 /// `ListStmtListGroup: NotificationStmt;`
 ///
@@ -3620,7 +3988,7 @@ pub struct ListStmtListGroupNotificationStmt<'t> {
 }
 
 ///
-/// Type derived for production 410
+/// Type derived for production 449
 ///
 /// `ListStmtListGroup: UnknownStmt;`
 ///
@@ -3631,7 +3999,7 @@ pub struct ListStmtListGroupUnknownStmt<'t> {
 }
 
 ///
-/// Type derived for production 413
+/// Type derived for production 452
 ///
 /// `OrderedByArgStr: OrderedByArg;`
 ///
@@ -3642,7 +4010,7 @@ pub struct OrderedByArgStrOrderedByArg<'t> {
 }
 
 ///
-/// Type derived for production 414
+/// Type derived for production 453
 ///
 /// `OrderedByArgStr: <Ordered>'"' OrderedByArg <Ordered>'"';`
 ///
@@ -3655,7 +4023,7 @@ pub struct OrderedByArgStrDoubleQuotationOrderedByArgDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 417
+/// Type derived for production 456
 ///
 /// `DefaultStmtSuffix: AsciiNoSemicolon Semicolon^ /* Clipped */;`
 ///
@@ -3666,7 +4034,7 @@ pub struct DefaultStmtSuffixAsciiNoSemicolonSemicolon<'t> {
 }
 
 ///
-/// Type derived for production 418
+/// Type derived for production 457
 ///
 /// `DefaultStmtSuffix: <Default>'"' AsciiNoSemicolon <Default>'"' Semicolon^ /* Clipped */;`
 ///
@@ -3679,7 +4047,7 @@ pub struct DefaultStmtSuffixDoubleQuotationAsciiNoSemicolonDoubleQuotationSemico
 }
 
 ///
-/// Type derived for production 422
+/// Type derived for production 461
 ///
 /// `TypeStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -3688,7 +4056,7 @@ pub struct DefaultStmtSuffixDoubleQuotationAsciiNoSemicolonDoubleQuotationSemico
 pub struct TypeStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 423
+/// Type derived for production 462
 ///
 /// `TypeStmtSuffix: LBrace^ /* Clipped */ TypeStmtList /* Vec */ <INITIAL, Keyword>'}'^ /* Clipped */;`
 ///
@@ -3699,7 +4067,7 @@ pub struct TypeStmtSuffixLBraceTypeStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 425
+/// Type derived for production 464
 ///
 /// `TypeStmtListGroup: FractionDigitsStmt;`
 ///
@@ -3710,7 +4078,7 @@ pub struct TypeStmtListGroupFractionDigitsStmt<'t> {
 }
 
 ///
-/// Type derived for production 426
+/// Type derived for production 465
 ///
 /// `TypeStmtListGroup: EnumStmt;`
 ///
@@ -3721,7 +4089,7 @@ pub struct TypeStmtListGroupEnumStmt<'t> {
 }
 
 ///
-/// Type derived for production 427
+/// Type derived for production 466
 ///
 /// `TypeStmtListGroup: BaseStmt;`
 ///
@@ -3732,7 +4100,7 @@ pub struct TypeStmtListGroupBaseStmt<'t> {
 }
 
 ///
-/// Type derived for production 428
+/// Type derived for production 467
 ///
 /// `TypeStmtListGroup: LeafrefSpecification;`
 ///
@@ -3743,7 +4111,7 @@ pub struct TypeStmtListGroupLeafrefSpecification<'t> {
 }
 
 ///
-/// Type derived for production 429
+/// Type derived for production 468
 ///
 /// `TypeStmtListGroup: StringRestrictions;`
 ///
@@ -3754,7 +4122,7 @@ pub struct TypeStmtListGroupStringRestrictions<'t> {
 }
 
 ///
-/// Type derived for production 430
+/// Type derived for production 469
 ///
 /// `TypeStmtListGroup: RangeStmt;`
 ///
@@ -3765,7 +4133,7 @@ pub struct TypeStmtListGroupRangeStmt<'t> {
 }
 
 ///
-/// Type derived for production 431
+/// Type derived for production 470
 ///
 /// `TypeStmtListGroup: BitStmt;`
 ///
@@ -3776,7 +4144,7 @@ pub struct TypeStmtListGroupBitStmt<'t> {
 }
 
 ///
-/// Type derived for production 432
+/// Type derived for production 471
 ///
 /// `TypeStmtListGroup: TypeStmt;`
 ///
@@ -3787,7 +4155,7 @@ pub struct TypeStmtListGroupTypeStmt<'t> {
 }
 
 ///
-/// Type derived for production 437
+/// Type derived for production 476
 ///
 /// `BitStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -3796,7 +4164,7 @@ pub struct TypeStmtListGroupTypeStmt<'t> {
 pub struct BitStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 438
+/// Type derived for production 477
 ///
 /// `BitStmtSuffix: LBrace^ /* Clipped */ BitStmtList /* Vec */ '}'^ /* Clipped */;`
 ///
@@ -3807,7 +4175,7 @@ pub struct BitStmtSuffixLBraceBitStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 440
+/// Type derived for production 479
 ///
 /// `BitStmtListGroup: PositionStmt;`
 ///
@@ -3818,7 +4186,7 @@ pub struct BitStmtListGroupPositionStmt<'t> {
 }
 
 ///
-/// Type derived for production 441
+/// Type derived for production 480
 ///
 /// `BitStmtListGroup: DescriptionStmt;`
 ///
@@ -3829,7 +4197,7 @@ pub struct BitStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 442
+/// Type derived for production 481
 ///
 /// `BitStmtListGroup: ReferenceStmt;`
 ///
@@ -3840,7 +4208,7 @@ pub struct BitStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 445
+/// Type derived for production 484
 ///
 /// `LeafrefSpecification: PathStmt;`
 ///
@@ -3851,7 +4219,7 @@ pub struct LeafrefSpecificationPathStmt<'t> {
 }
 
 ///
-/// Type derived for production 446
+/// Type derived for production 485
 ///
 /// `LeafrefSpecification: RequireInstanceStmt;`
 ///
@@ -3862,7 +4230,7 @@ pub struct LeafrefSpecificationRequireInstanceStmt<'t> {
 }
 
 ///
-/// Type derived for production 448
+/// Type derived for production 487
 ///
 /// `RequireInstanceArgStr: MandatoryArg;`
 ///
@@ -3873,7 +4241,7 @@ pub struct RequireInstanceArgStrMandatoryArg<'t> {
 }
 
 ///
-/// Type derived for production 449
+/// Type derived for production 488
 ///
 /// `RequireInstanceArgStr: <Mandatory>'"' MandatoryArg <Mandatory>'"';`
 ///
@@ -3886,7 +4254,7 @@ pub struct RequireInstanceArgStrDoubleQuotationMandatoryArgDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 451
+/// Type derived for production 490
 ///
 /// `StringRestrictions: LengthStmt;`
 ///
@@ -3897,7 +4265,7 @@ pub struct StringRestrictionsLengthStmt<'t> {
 }
 
 ///
-/// Type derived for production 452
+/// Type derived for production 491
 ///
 /// `StringRestrictions: PatternStmt;`
 ///
@@ -3908,7 +4276,7 @@ pub struct StringRestrictionsPatternStmt<'t> {
 }
 
 ///
-/// Type derived for production 456
+/// Type derived for production 495
 ///
 /// `EnumStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -3917,7 +4285,7 @@ pub struct StringRestrictionsPatternStmt<'t> {
 pub struct EnumStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 457
+/// Type derived for production 496
 ///
 /// `EnumStmtSuffix: LBrace^ /* Clipped */ EnumStmtList /* Vec */ '}'^ /* Clipped */;`
 ///
@@ -3928,7 +4296,7 @@ pub struct EnumStmtSuffixLBraceEnumStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 459
+/// Type derived for production 498
 ///
 /// `EnumStmtListGroup: IfFeatureStmt;`
 ///
@@ -3939,7 +4307,7 @@ pub struct EnumStmtListGroupIfFeatureStmt<'t> {
 }
 
 ///
-/// Type derived for production 460
+/// Type derived for production 499
 ///
 /// `EnumStmtListGroup: DescriptionStmt;`
 ///
@@ -3950,7 +4318,7 @@ pub struct EnumStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 461
+/// Type derived for production 500
 ///
 /// `EnumStmtListGroup: ValueStmt;`
 ///
@@ -3961,7 +4329,7 @@ pub struct EnumStmtListGroupValueStmt<'t> {
 }
 
 ///
-/// Type derived for production 462
+/// Type derived for production 501
 ///
 /// `EnumStmtListGroup: ReferenceStmt;`
 ///
@@ -3972,7 +4340,7 @@ pub struct EnumStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 465
+/// Type derived for production 504
 ///
 /// `IntegerValueStr: IntegerValue;`
 ///
@@ -3983,7 +4351,7 @@ pub struct IntegerValueStrIntegerValue<'t> {
 }
 
 ///
-/// Type derived for production 466
+/// Type derived for production 505
 ///
 /// `IntegerValueStr: <Value>'"' IntegerValue <Value>'"';`
 ///
@@ -3996,7 +4364,7 @@ pub struct IntegerValueStrDoubleQuotationIntegerValueDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 468
+/// Type derived for production 507
 ///
 /// `EnumArgStr: AsciiNoBrace;`
 ///
@@ -4007,7 +4375,7 @@ pub struct EnumArgStrAsciiNoBrace<'t> {
 }
 
 ///
-/// Type derived for production 469
+/// Type derived for production 508
 ///
 /// `EnumArgStr: <Enum>'"'^ /* Clipped */ AsciiNoBrace <Enum>'"'^ /* Clipped */;`
 ///
@@ -4018,7 +4386,7 @@ pub struct EnumArgStrDoubleQuotationAsciiNoBraceDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 471
+/// Type derived for production 510
 ///
 /// `RangeArgStr: RangeArg;`
 ///
@@ -4029,7 +4397,7 @@ pub struct RangeArgStrRangeArg<'t> {
 }
 
 ///
-/// Type derived for production 472
+/// Type derived for production 511
 ///
 /// `RangeArgStr: <Range>'"'^ /* Clipped */ RangeArg <Range>'"'^ /* Clipped */;`
 ///
@@ -4040,7 +4408,7 @@ pub struct RangeArgStrDoubleQuotationRangeArgDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 479
+/// Type derived for production 518
 ///
 /// `RangeBoundary: <Range>'min';`
 ///
@@ -4051,7 +4419,7 @@ pub struct RangeBoundaryMin<'t> {
 }
 
 ///
-/// Type derived for production 480
+/// Type derived for production 519
 ///
 /// `RangeBoundary: <Range>'max';`
 ///
@@ -4062,7 +4430,19 @@ pub struct RangeBoundaryMax<'t> {
 }
 
 ///
-/// Type derived for production 481
+/// Type derived for production 520
+///
+/// `RangeBoundary: <Range>"-?[0-9]+\.[0-9]+";`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct RangeBoundaryMinusQuestLBracket0Minus9RBracketPlusDotLBracket0Minus9RBracketPlus<'t> {
+    pub minus_quest_l_bracket0_minus9_r_bracket_plus_dot_l_bracket0_minus9_r_bracket_plus:
+        Token<'t>, /* -?[0-9]+\.[0-9]+ */
+}
+
+///
+/// Type derived for production 521
 ///
 /// `RangeBoundary: <Range>"-?[0-9]+";`
 ///
@@ -4073,7 +4453,7 @@ pub struct RangeBoundaryMinusQuestLBracket0Minus9RBracketPlus<'t> {
 }
 
 ///
-/// Type derived for production 482
+/// Type derived for production 522
 ///
 /// `IdentifierRefArgStr: IdentifierRef;`
 ///
@@ -4084,7 +4464,7 @@ pub struct IdentifierRefArgStrIdentifierRef<'t> {
 }
 
 ///
-/// Type derived for production 483
+/// Type derived for production 523
 ///
 /// `IdentifierRefArgStr: <Keyword, IfFeature>'"' IdentifierRef <Keyword, IfFeature>'"';`
 ///
@@ -4097,7 +4477,7 @@ pub struct IdentifierRefArgStrDoubleQuotationIdentifierRefDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 488
+/// Type derived for production 528
 ///
 /// `KeyArgStr: KeyArg;`
 ///
@@ -4108,7 +4488,7 @@ pub struct KeyArgStrKeyArg<'t> {
 }
 
 ///
-/// Type derived for production 489
+/// Type derived for production 529
 ///
 /// `KeyArgStr: <Keyword>'"'^ /* Clipped */ KeyArg <Keyword>'"'^ /* Clipped */;`
 ///
@@ -4119,7 +4499,7 @@ pub struct KeyArgStrDoubleQuotationKeyArgDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 497
+/// Type derived for production 537
 ///
 /// `ImportStmtListGroup: PrefixStmt;`
 ///
@@ -4130,7 +4510,7 @@ pub struct ImportStmtListGroupPrefixStmt<'t> {
 }
 
 ///
-/// Type derived for production 498
+/// Type derived for production 538
 ///
 /// `ImportStmtListGroup: RevisionDateStmt;`
 ///
@@ -4141,7 +4521,7 @@ pub struct ImportStmtListGroupRevisionDateStmt<'t> {
 }
 
 ///
-/// Type derived for production 499
+/// Type derived for production 539
 ///
 /// `ImportStmtListGroup: DescriptionStmt;`
 ///
@@ -4152,7 +4532,7 @@ pub struct ImportStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 500
+/// Type derived for production 540
 ///
 /// `ImportStmtListGroup: ReferenceStmt;`
 ///
@@ -4163,7 +4543,7 @@ pub struct ImportStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 503
+/// Type derived for production 543
 ///
 /// `IncludeStmtSuffix: Semicolon^ /* Clipped */;`
 ///
@@ -4172,7 +4552,7 @@ pub struct ImportStmtListGroupReferenceStmt<'t> {
 pub struct IncludeStmtSuffixSemicolon {}
 
 ///
-/// Type derived for production 504
+/// Type derived for production 544
 ///
 /// `IncludeStmtSuffix: LBrace^ /* Clipped */ IncludeStmtList /* Vec */ '}'^ /* Clipped */;`
 ///
@@ -4183,7 +4563,7 @@ pub struct IncludeStmtSuffixLBraceIncludeStmtListRBrace<'t> {
 }
 
 ///
-/// Type derived for production 506
+/// Type derived for production 546
 ///
 /// `IncludeStmtListGroup: RevisionDateStmt;`
 ///
@@ -4194,7 +4574,7 @@ pub struct IncludeStmtListGroupRevisionDateStmt<'t> {
 }
 
 ///
-/// Type derived for production 507
+/// Type derived for production 547
 ///
 /// `IncludeStmtListGroup: DescriptionStmt;`
 ///
@@ -4205,7 +4585,7 @@ pub struct IncludeStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 508
+/// Type derived for production 548
 ///
 /// `IncludeStmtListGroup: ReferenceStmt;`
 ///
@@ -4216,7 +4596,7 @@ pub struct IncludeStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 512
+/// Type derived for production 552
 ///
 /// `UriStr: UriArg;`
 ///
@@ -4227,7 +4607,7 @@ pub struct UriStrUriArg<'t> {
 }
 
 ///
-/// Type derived for production 513
+/// Type derived for production 553
 ///
 /// `UriStr: <Uri>'"'^ /* Clipped */ UriArg <Uri>'"'^ /* Clipped */;`
 ///
@@ -4238,7 +4618,7 @@ pub struct UriStrDoubleQuotationUriArgDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 523
+/// Type derived for production 563
 ///
 /// `RevisionStmtListGroup: DescriptionStmt;`
 ///
@@ -4249,7 +4629,7 @@ pub struct RevisionStmtListGroupDescriptionStmt<'t> {
 }
 
 ///
-/// Type derived for production 524
+/// Type derived for production 564
 ///
 /// `RevisionStmtListGroup: ReferenceStmt;`
 ///
@@ -4260,7 +4640,7 @@ pub struct RevisionStmtListGroupReferenceStmt<'t> {
 }
 
 ///
-/// Type derived for production 526
+/// Type derived for production 566
 ///
 /// `DateArgStr: DateArg;`
 ///
@@ -4271,7 +4651,7 @@ pub struct DateArgStrDateArg<'t> {
 }
 
 ///
-/// Type derived for production 527
+/// Type derived for production 567
 ///
 /// `DateArgStr: <Revision>'"'^ /* Clipped */ DateArg <Revision>'"'^ /* Clipped */;`
 ///
@@ -4282,7 +4662,7 @@ pub struct DateArgStrDoubleQuotationDateArgDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 529
+/// Type derived for production 569
 ///
 /// `Ystring: BasicString YstringOpt /* Option */;`
 ///
@@ -4294,7 +4674,7 @@ pub struct YstringBasicStringYstringOpt<'t> {
 }
 
 ///
-/// Type derived for production 530
+/// Type derived for production 570
 ///
 /// `Ystring: Identifier;`
 ///
@@ -4305,7 +4685,7 @@ pub struct YstringIdentifier<'t> {
 }
 
 ///
-/// Type derived for production 533
+/// Type derived for production 573
 ///
 /// `BasicString: DQString;`
 ///
@@ -4316,7 +4696,7 @@ pub struct BasicStringDQString<'t> {
 }
 
 ///
-/// Type derived for production 534
+/// Type derived for production 574
 ///
 /// `BasicString: SQString;`
 ///
@@ -4327,7 +4707,7 @@ pub struct BasicStringSQString<'t> {
 }
 
 ///
-/// Type derived for production 539
+/// Type derived for production 579
 ///
 /// `DQChar: DQUnescaped;`
 ///
@@ -4338,7 +4718,7 @@ pub struct DQCharDQUnescaped<'t> {
 }
 
 ///
-/// Type derived for production 540
+/// Type derived for production 580
 ///
 /// `DQChar: DQEscaped;`
 ///
@@ -4349,7 +4729,7 @@ pub struct DQCharDQEscaped<'t> {
 }
 
 ///
-/// Type derived for production 541
+/// Type derived for production 581
 ///
 /// `DQUnescaped: DQNoEscape;`
 ///
@@ -4360,7 +4740,7 @@ pub struct DQUnescapedDQNoEscape<'t> {
 }
 
 ///
-/// Type derived for production 542
+/// Type derived for production 582
 ///
 /// `DQUnescaped: NonAscii;`
 ///
@@ -4371,7 +4751,7 @@ pub struct DQUnescapedNonAscii<'t> {
 }
 
 ///
-/// Type derived for production 544
+/// Type derived for production 584
 ///
 /// `DQEscapeSeqChar: Escape;`
 ///
@@ -4382,7 +4762,7 @@ pub struct DQEscapeSeqCharEscape<'t> {
 }
 
 ///
-/// Type derived for production 545
+/// Type derived for production 585
 ///
 /// `DQEscapeSeqChar: DoubleQuotation;`
 ///
@@ -4393,7 +4773,7 @@ pub struct DQEscapeSeqCharDoubleQuotation<'t> {
 }
 
 ///
-/// Type derived for production 546
+/// Type derived for production 586
 ///
 /// `DQEscapeSeqChar: EscN;`
 ///
@@ -4404,7 +4784,7 @@ pub struct DQEscapeSeqCharEscN<'t> {
 }
 
 ///
-/// Type derived for production 547
+/// Type derived for production 587
 ///
 /// `DQEscapeSeqChar: EscT;`
 ///
@@ -4415,7 +4795,7 @@ pub struct DQEscapeSeqCharEscT<'t> {
 }
 
 ///
-/// Type derived for production 554
+/// Type derived for production 594
 ///
 /// `SQChar: SQUnescaped;`
 ///
@@ -4426,7 +4806,7 @@ pub struct SQCharSQUnescaped<'t> {
 }
 
 ///
-/// Type derived for production 555
+/// Type derived for production 595
 ///
 /// `SQChar: SQEscaped;`
 ///
@@ -4437,7 +4817,7 @@ pub struct SQCharSQEscaped<'t> {
 }
 
 ///
-/// Type derived for production 558
+/// Type derived for production 598
 ///
 /// `SQUnescaped: SQNoEscape;`
 ///
@@ -4448,7 +4828,7 @@ pub struct SQUnescapedSQNoEscape<'t> {
 }
 
 ///
-/// Type derived for production 559
+/// Type derived for production 599
 ///
 /// `SQUnescaped: NonAscii;`
 ///
@@ -4459,7 +4839,7 @@ pub struct SQUnescapedNonAscii<'t> {
 }
 
 ///
-/// Type derived for production 562
+/// Type derived for production 602
 ///
 /// `IdentifierArgStr: Identifier;`
 ///
@@ -4470,7 +4850,7 @@ pub struct IdentifierArgStrIdentifier<'t> {
 }
 
 ///
-/// Type derived for production 563
+/// Type derived for production 603
 ///
 /// `IdentifierArgStr: <Keyword>'"'^ /* Clipped */ Identifier <Keyword>'"'^ /* Clipped */;`
 ///
@@ -4524,6 +4904,8 @@ pub enum ActionStmtListGroup<'t> {
     StatusStmt(ActionStmtListGroupStatusStmt<'t>),
     DescriptionStmt(ActionStmtListGroupDescriptionStmt<'t>),
     ReferenceStmt(ActionStmtListGroupReferenceStmt<'t>),
+    TypedefStmt(ActionStmtListGroupTypedefStmt<'t>),
+    GroupingStmt(ActionStmtListGroupGroupingStmt<'t>),
     InputStmt(ActionStmtListGroupInputStmt<'t>),
     OutputStmt(ActionStmtListGroupOutputStmt<'t>),
 }
@@ -4815,6 +5197,7 @@ pub struct CaseStmtList<'t> {
 pub enum CaseStmtListGroup<'t> {
     WhenStmt(CaseStmtListGroupWhenStmt<'t>),
     IfFeatureStmt(CaseStmtListGroupIfFeatureStmt<'t>),
+    StatusStmt(CaseStmtListGroupStatusStmt<'t>),
     DataDefStmt(CaseStmtListGroupDataDefStmt<'t>),
     DescriptionStmt(CaseStmtListGroupDescriptionStmt<'t>),
     ReferenceStmt(CaseStmtListGroupReferenceStmt<'t>),
@@ -4930,6 +5313,8 @@ pub enum ContainerStmtListGroup<'t> {
     StatusStmt(ContainerStmtListGroupStatusStmt<'t>),
     DescriptionStmt(ContainerStmtListGroupDescriptionStmt<'t>),
     ReferenceStmt(ContainerStmtListGroupReferenceStmt<'t>),
+    TypedefStmt(ContainerStmtListGroupTypedefStmt<'t>),
+    GroupingStmt(ContainerStmtListGroupGroupingStmt<'t>),
     DataDefStmt(ContainerStmtListGroupDataDefStmt<'t>),
     ActionStmt(ContainerStmtListGroupActionStmt<'t>),
     NotificationStmt(ContainerStmtListGroupNotificationStmt<'t>),
@@ -5090,14 +5475,111 @@ pub struct DescriptionStmt<'t> {
     pub ystring: Box<Ystring<'t>>,
 }
 
+///
+/// Type derived for non-terminal DeviateArg
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateArg<'t> {
+    pub deviate_arg: Token<'t>, /* not-supported|add|replace|delete */
+}
+
+///
+/// Type derived for non-terminal DeviateArgStr
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum DeviateArgStr<'t> {
+    DeviateArg(DeviateArgStrDeviateArg<'t>),
+    DoubleQuotationDeviateArgDoubleQuotation(
+        DeviateArgStrDoubleQuotationDeviateArgDoubleQuotation<'t>,
+    ),
+}
+
+///
+/// Type derived for non-terminal DeviateStmt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmt<'t> {
+    pub deviate_arg_str: Box<DeviateArgStr<'t>>,
+    pub deviate_stmt_suffix: Box<DeviateStmtSuffix<'t>>,
+}
+
+///
+/// Type derived for non-terminal DeviateStmtList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviateStmtList<'t> {
+    pub deviate_stmt_list_group: Box<DeviateStmtListGroup<'t>>,
+}
+
+///
+/// Type derived for non-terminal DeviateStmtListGroup
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum DeviateStmtListGroup<'t> {
+    UnitsStmt(DeviateStmtListGroupUnitsStmt<'t>),
+    MustStmt(DeviateStmtListGroupMustStmt<'t>),
+    UnknownStmt(DeviateStmtListGroupUnknownStmt<'t>),
+    DefaultStmt(DeviateStmtListGroupDefaultStmt<'t>),
+    ConfigStmt(DeviateStmtListGroupConfigStmt<'t>),
+    MandatoryStmt(DeviateStmtListGroupMandatoryStmt<'t>),
+    MinElementsStmt(DeviateStmtListGroupMinElementsStmt<'t>),
+    MaxElementsStmt(DeviateStmtListGroupMaxElementsStmt<'t>),
+    TypeStmt(DeviateStmtListGroupTypeStmt<'t>),
+}
+
+///
+/// Type derived for non-terminal DeviateStmtSuffix
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum DeviateStmtSuffix<'t> {
+    Semicolon(DeviateStmtSuffixSemicolon),
+    LBraceDeviateStmtListRBrace(DeviateStmtSuffixLBraceDeviateStmtListRBrace<'t>),
+}
+
 ///
 /// Type derived for non-terminal DeviationStmt
 ///
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct DeviationStmt<'t> {
-    pub absolute_schema_nodeid: Box<AbsoluteSchemaNodeid<'t>>,
-    pub semicolon: Box<Semicolon<'t>>,
+    pub augment_arg_str: Box<AugmentArgStr<'t>>,
+    pub deviation_stmt_suffix: Box<DeviationStmtSuffix<'t>>,
+}
+
+///
+/// Type derived for non-terminal DeviationStmtList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeviationStmtList<'t> {
+    pub deviation_stmt_list_group: Box<DeviationStmtListGroup<'t>>,
+}
+
+///
+/// Type derived for non-terminal DeviationStmtListGroup
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum DeviationStmtListGroup<'t> {
+    DescriptionStmt(DeviationStmtListGroupDescriptionStmt<'t>),
+    ReferenceStmt(DeviationStmtListGroupReferenceStmt<'t>),
+    DeviateStmt(DeviationStmtListGroupDeviateStmt<'t>),
+}
+
+///
+/// Type derived for non-terminal DeviationStmtSuffix
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum DeviationStmtSuffix<'t> {
+    Semicolon(DeviationStmtSuffixSemicolon),
+    LBraceDeviationStmtListRBrace(DeviationStmtSuffixLBraceDeviationStmtListRBrace<'t>),
 }
 
 ///
@@ -5416,6 +5898,7 @@ pub struct IdentityStmtList<'t> {
 pub enum IdentityStmtListGroup<'t> {
     IfFeatureStmt(IdentityStmtListGroupIfFeatureStmt<'t>),
     BaseStmt(IdentityStmtListGroupBaseStmt<'t>),
+    StatusStmt(IdentityStmtListGroupStatusStmt<'t>),
     DescriptionStmt(IdentityStmtListGroupDescriptionStmt<'t>),
     ReferenceStmt(IdentityStmtListGroupReferenceStmt<'t>),
 }
@@ -5580,7 +6063,18 @@ pub struct InputStmt<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct InputStmtList<'t> {
-    pub data_def_stmt: Box<DataDefStmt<'t>>,
+    pub input_stmt_list_group: Box<InputStmtListGroup<'t>>,
+}
+
+///
+/// Type derived for non-terminal InputStmtListGroup
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum InputStmtListGroup<'t> {
+    TypedefStmt(InputStmtListGroupTypedefStmt<'t>),
+    GroupingStmt(InputStmtListGroupGroupingStmt<'t>),
+    DataDefStmt(InputStmtListGroupDataDefStmt<'t>),
 }
 
 ///
@@ -5751,7 +6245,16 @@ pub struct KwDefault<'t> {
 }
 
 ///
-/// Type derived for non-terminal KwDeviation
+/// Type derived for non-terminal KwDeviate
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct KwDeviate<'t> {
+    pub kw_deviate: Token<'t>, /* deviate */
+}
+
+///
+/// Type derived for non-terminal KwDeviation
 ///
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -6219,6 +6722,8 @@ pub enum ListStmtListGroup<'t> {
     StatusStmt(ListStmtListGroupStatusStmt<'t>),
     DescriptionStmt(ListStmtListGroupDescriptionStmt<'t>),
     ReferenceStmt(ListStmtListGroupReferenceStmt<'t>),
+    TypedefStmt(ListStmtListGroupTypedefStmt<'t>),
+    GroupingStmt(ListStmtListGroupGroupingStmt<'t>),
     DataDefStmt(ListStmtListGroupDataDefStmt<'t>),
     ActionStmt(ListStmtListGroupActionStmt<'t>),
     NotificationStmt(ListStmtListGroupNotificationStmt<'t>),
@@ -6506,7 +7011,18 @@ pub struct OutputStmt<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct OutputStmtList<'t> {
-    pub data_def_stmt: Box<DataDefStmt<'t>>,
+    pub output_stmt_list_group: Box<OutputStmtListGroup<'t>>,
+}
+
+///
+/// Type derived for non-terminal OutputStmtListGroup
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum OutputStmtListGroup<'t> {
+    TypedefStmt(OutputStmtListGroupTypedefStmt<'t>),
+    GroupingStmt(OutputStmtListGroupGroupingStmt<'t>),
+    DataDefStmt(OutputStmtListGroupDataDefStmt<'t>),
 }
 
 ///
@@ -6594,6 +7110,9 @@ pub enum RangeArgStr<'t> {
 pub enum RangeBoundary<'t> {
     Min(RangeBoundaryMin<'t>),
     Max(RangeBoundaryMax<'t>),
+    MinusQuestLBracket0Minus9RBracketPlusDotLBracket0Minus9RBracketPlus(
+        RangeBoundaryMinusQuestLBracket0Minus9RBracketPlusDotLBracket0Minus9RBracketPlus<'t>,
+    ),
     MinusQuestLBracket0Minus9RBracketPlus(RangeBoundaryMinusQuestLBracket0Minus9RBracketPlus<'t>),
 }
 
@@ -7382,7 +7901,16 @@ pub enum ASTType<'t> {
     DefaultStmtSuffix(DefaultStmtSuffix<'t>),
     DescendantSchemaNodeid(DescendantSchemaNodeid<'t>),
     DescriptionStmt(DescriptionStmt<'t>),
+    DeviateArg(DeviateArg<'t>),
+    DeviateArgStr(DeviateArgStr<'t>),
+    DeviateStmt(DeviateStmt<'t>),
+    DeviateStmtList(Vec<DeviateStmtList<'t>>),
+    DeviateStmtListGroup(DeviateStmtListGroup<'t>),
+    DeviateStmtSuffix(DeviateStmtSuffix<'t>),
     DeviationStmt(DeviationStmt<'t>),
+    DeviationStmtList(Vec<DeviationStmtList<'t>>),
+    DeviationStmtListGroup(DeviationStmtListGroup<'t>),
+    DeviationStmtSuffix(DeviationStmtSuffix<'t>),
     DoubleQuotation(DoubleQuotation<'t>),
     EnumArgStr(EnumArgStr<'t>),
     EnumStmt(EnumStmt<'t>),
@@ -7430,6 +7958,7 @@ pub enum ASTType<'t> {
     IncludeStmtSuffix(IncludeStmtSuffix<'t>),
     InputStmt(InputStmt<'t>),
     InputStmtList(Vec<InputStmtList<'t>>),
+    InputStmtListGroup(InputStmtListGroup<'t>),
     IntegerValue(IntegerValue<'t>),
     IntegerValueStr(IntegerValueStr<'t>),
     KeyArg(KeyArg<'t>),
@@ -7448,6 +7977,7 @@ pub enum ASTType<'t> {
     KwConfig(KwConfig<'t>),
     KwContainer(KwContainer<'t>),
     KwDefault(KwDefault<'t>),
+    KwDeviate(KwDeviate<'t>),
     KwDeviation(KwDeviation<'t>),
     KwEnum(KwEnum<'t>),
     KwExtension(KwExtension<'t>),
@@ -7524,6 +8054,7 @@ pub enum ASTType<'t> {
     OrganizationStmt(OrganizationStmt<'t>),
     OutputStmt(OutputStmt<'t>),
     OutputStmtList(Vec<OutputStmtList<'t>>),
+    OutputStmtListGroup(OutputStmtListGroup<'t>),
     PathStmt(PathStmt<'t>),
     PatternStmt(PatternStmt<'t>),
     PositionStmt(PositionStmt<'t>),
@@ -7668,7 +8199,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 0:
     ///
-    /// `LBrace: <INITIAL, Keyword, Enum, Revision>'{';`
+    /// `LBrace: <INITIAL, Keyword, Enum, Revision, Deviate>'{';`
     ///
     #[parol_runtime::function_name::named]
     fn l_brace(&mut self, l_brace: &ParseTreeType<'t>) -> Result<()> {
@@ -8196,6 +8727,22 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 33:
     ///
+    /// `KwDeviate: 'deviate';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn kw_deviate(&mut self, kw_deviate: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let kw_deviate = kw_deviate.token()?.clone();
+        let kw_deviate_built = KwDeviate { kw_deviate };
+        // Calling user action here
+        self.user_grammar.kw_deviate(&kw_deviate_built)?;
+        self.push(ASTType::KwDeviate(kw_deviate_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 34:
+    ///
     /// `KwYangVersion: 'yang-version';`
     ///
     #[parol_runtime::function_name::named]
@@ -8210,7 +8757,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 34:
+    /// Semantic action for production 35:
     ///
     /// `KwStatus: 'status';`
     ///
@@ -8226,7 +8773,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 35:
+    /// Semantic action for production 36:
     ///
     /// `KwIfFeature: 'if-feature';`
     ///
@@ -8242,7 +8789,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 36:
+    /// Semantic action for production 37:
     ///
     /// `KwMandatory: 'mandatory';`
     ///
@@ -8258,7 +8805,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 37:
+    /// Semantic action for production 38:
     ///
     /// `KwConfig: 'config';`
     ///
@@ -8274,7 +8821,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 38:
+    /// Semantic action for production 39:
     ///
     /// `KwRequireInstance: 'require-instance';`
     ///
@@ -8296,7 +8843,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 39:
+    /// Semantic action for production 40:
     ///
     /// `KwOrderedBy: 'ordered-by';`
     ///
@@ -8312,7 +8859,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 40:
+    /// Semantic action for production 41:
     ///
     /// `KwDefault: 'default';`
     ///
@@ -8328,7 +8875,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 41:
+    /// Semantic action for production 42:
     ///
     /// `KwFractionDigits: 'fraction-digits';`
     ///
@@ -8345,7 +8892,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 42:
+    /// Semantic action for production 43:
     ///
     /// `KwLength: 'length';`
     ///
@@ -8361,7 +8908,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 43:
+    /// Semantic action for production 44:
     ///
     /// `KwRange: 'range';`
     ///
@@ -8377,7 +8924,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 44:
+    /// Semantic action for production 45:
     ///
     /// `KwValue: 'value';`
     ///
@@ -8393,7 +8940,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 45:
+    /// Semantic action for production 46:
     ///
     /// `KwPosition: 'position';`
     ///
@@ -8409,7 +8956,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 46:
+    /// Semantic action for production 47:
     ///
     /// `KwEnum: 'enum';`
     ///
@@ -8425,7 +8972,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 47:
+    /// Semantic action for production 48:
     ///
     /// `KwRevision: 'revision';`
     ///
@@ -8441,7 +8988,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 48:
+    /// Semantic action for production 49:
     ///
     /// `KwRevisionDate: 'revision-date';`
     ///
@@ -8458,7 +9005,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 49:
+    /// Semantic action for production 50:
     ///
     /// `KwNamespace: 'namespace';`
     ///
@@ -8474,7 +9021,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 50:
+    /// Semantic action for production 51:
     ///
     /// `Yang: ModuleStmt;`
     ///
@@ -8493,7 +9040,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 51:
+    /// Semantic action for production 52:
     ///
     /// `Yang: SubmoduleStmt;`
     ///
@@ -8512,7 +9059,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 52:
+    /// Semantic action for production 53:
     ///
     /// `ModuleStmt: KwModule^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ ModuleStmtList /* Vec */ ModuleStmtList0 /* Vec */ ModuleStmtList1 /* Vec */ ModuleStmtList2 /* Vec */ ModuleStmtList3 /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -8558,7 +9105,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 53:
+    /// Semantic action for production 54:
     ///
     /// `ModuleStmtList3 /* Vec<T>::Push */: BodyStmts ModuleStmtList3;`
     ///
@@ -8581,7 +9128,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 54:
+    /// Semantic action for production 55:
     ///
     /// `ModuleStmtList3 /* Vec<T>::New */: ;`
     ///
@@ -8594,7 +9141,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 55:
+    /// Semantic action for production 56:
     ///
     /// `ModuleStmtList2 /* Vec<T>::Push */: RevisionStmt ModuleStmtList2;`
     ///
@@ -8617,7 +9164,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 56:
+    /// Semantic action for production 57:
     ///
     /// `ModuleStmtList2 /* Vec<T>::New */: ;`
     ///
@@ -8630,7 +9177,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 57:
+    /// Semantic action for production 58:
     ///
     /// `ModuleStmtList1 /* Vec<T>::Push */: MetaStmts ModuleStmtList1;`
     ///
@@ -8653,7 +9200,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 58:
+    /// Semantic action for production 59:
     ///
     /// `ModuleStmtList1 /* Vec<T>::New */: ;`
     ///
@@ -8666,7 +9213,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 59:
+    /// Semantic action for production 60:
     ///
     /// `ModuleStmtList0 /* Vec<T>::Push */: LinkageStmts ModuleStmtList0;`
     ///
@@ -8689,7 +9236,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 60:
+    /// Semantic action for production 61:
     ///
     /// `ModuleStmtList0 /* Vec<T>::New */: ;`
     ///
@@ -8702,7 +9249,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 61:
+    /// Semantic action for production 62:
     ///
     /// `ModuleStmtList /* Vec<T>::Push */: ModuleHeaderStmts ModuleStmtList;`
     ///
@@ -8725,7 +9272,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 62:
+    /// Semantic action for production 63:
     ///
     /// `ModuleStmtList /* Vec<T>::New */: ;`
     ///
@@ -8738,7 +9285,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 63:
+    /// Semantic action for production 64:
     ///
     /// `SubmoduleStmt: KwSubmodule^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ SubmoduleStmtList /* Vec */ SubmoduleStmtList0 /* Vec */ SubmoduleStmtList1 /* Vec */ SubmoduleStmtList2 /* Vec */ SubmoduleStmtList3 /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -8784,7 +9331,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 64:
+    /// Semantic action for production 65:
     ///
     /// `SubmoduleStmtList3 /* Vec<T>::Push */: BodyStmts SubmoduleStmtList3;`
     ///
@@ -8808,7 +9355,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 65:
+    /// Semantic action for production 66:
     ///
     /// `SubmoduleStmtList3 /* Vec<T>::New */: ;`
     ///
@@ -8824,7 +9371,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 66:
+    /// Semantic action for production 67:
     ///
     /// `SubmoduleStmtList2 /* Vec<T>::Push */: RevisionStmt SubmoduleStmtList2;`
     ///
@@ -8848,7 +9395,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 67:
+    /// Semantic action for production 68:
     ///
     /// `SubmoduleStmtList2 /* Vec<T>::New */: ;`
     ///
@@ -8864,7 +9411,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 68:
+    /// Semantic action for production 69:
     ///
     /// `SubmoduleStmtList1 /* Vec<T>::Push */: MetaStmts SubmoduleStmtList1;`
     ///
@@ -8888,7 +9435,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 69:
+    /// Semantic action for production 70:
     ///
     /// `SubmoduleStmtList1 /* Vec<T>::New */: ;`
     ///
@@ -8904,7 +9451,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 70:
+    /// Semantic action for production 71:
     ///
     /// `SubmoduleStmtList0 /* Vec<T>::Push */: LinkageStmts SubmoduleStmtList0;`
     ///
@@ -8928,7 +9475,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 71:
+    /// Semantic action for production 72:
     ///
     /// `SubmoduleStmtList0 /* Vec<T>::New */: ;`
     ///
@@ -8944,7 +9491,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 72:
+    /// Semantic action for production 73:
     ///
     /// `SubmoduleStmtList /* Vec<T>::Push */: SubmoduleHeaderStmts SubmoduleStmtList;`
     ///
@@ -8969,7 +9516,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 73:
+    /// Semantic action for production 74:
     ///
     /// `SubmoduleStmtList /* Vec<T>::New */: ;`
     ///
@@ -8985,7 +9532,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 74:
+    /// Semantic action for production 75:
     ///
     /// `ModuleHeaderStmts: YangVersionStmt;`
     ///
@@ -9009,7 +9556,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 75:
+    /// Semantic action for production 76:
     ///
     /// `ModuleHeaderStmts: NamespaceStmt;`
     ///
@@ -9033,7 +9580,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 76:
+    /// Semantic action for production 77:
     ///
     /// `ModuleHeaderStmts: PrefixStmt;`
     ///
@@ -9057,7 +9604,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 77:
+    /// Semantic action for production 78:
     ///
     /// `SubmoduleHeaderStmts: YangVersionStmt;`
     ///
@@ -9081,7 +9628,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 78:
+    /// Semantic action for production 79:
     ///
     /// `SubmoduleHeaderStmts: BelongsToStmt;`
     ///
@@ -9105,7 +9652,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 79:
+    /// Semantic action for production 80:
     ///
     /// `MetaStmts: OrganizationStmt;`
     ///
@@ -9124,7 +9671,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 80:
+    /// Semantic action for production 81:
     ///
     /// `MetaStmts: ContactStmt;`
     ///
@@ -9143,7 +9690,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 81:
+    /// Semantic action for production 82:
     ///
     /// `MetaStmts: DescriptionStmt;`
     ///
@@ -9162,7 +9709,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 82:
+    /// Semantic action for production 83:
     ///
     /// `MetaStmts: ReferenceStmt;`
     ///
@@ -9181,7 +9728,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 83:
+    /// Semantic action for production 84:
     ///
     /// `LinkageStmts: ImportStmt;`
     ///
@@ -9200,7 +9747,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 84:
+    /// Semantic action for production 85:
     ///
     /// `LinkageStmts: IncludeStmt;`
     ///
@@ -9219,7 +9766,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 85:
+    /// Semantic action for production 86:
     ///
     /// `BodyStmts: ExtensionStmt;`
     ///
@@ -9238,7 +9785,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 86:
+    /// Semantic action for production 87:
     ///
     /// `BodyStmts: FeatureStmt;`
     ///
@@ -9257,7 +9804,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 87:
+    /// Semantic action for production 88:
     ///
     /// `BodyStmts: IdentityStmt;`
     ///
@@ -9276,7 +9823,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 88:
+    /// Semantic action for production 89:
     ///
     /// `BodyStmts: TypedefStmt;`
     ///
@@ -9295,7 +9842,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 89:
+    /// Semantic action for production 90:
     ///
     /// `BodyStmts: GroupingStmt;`
     ///
@@ -9314,7 +9861,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 90:
+    /// Semantic action for production 91:
     ///
     /// `BodyStmts: DataDefStmt;`
     ///
@@ -9333,7 +9880,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 91:
+    /// Semantic action for production 92:
     ///
     /// `BodyStmts: AugmentStmt;`
     ///
@@ -9352,7 +9899,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 92:
+    /// Semantic action for production 93:
     ///
     /// `BodyStmts: RpcStmt;`
     ///
@@ -9371,7 +9918,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 93:
+    /// Semantic action for production 94:
     ///
     /// `BodyStmts: NotificationStmt;`
     ///
@@ -9390,7 +9937,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 94:
+    /// Semantic action for production 95:
     ///
     /// `BodyStmts: DeviationStmt;`
     ///
@@ -9409,7 +9956,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 95:
+    /// Semantic action for production 96:
     ///
     /// `BodyStmts: UnknownStmt;`
     ///
@@ -9428,7 +9975,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 96:
+    /// Semantic action for production 97:
     ///
     /// `DataDefStmt: ContainerStmt;`
     ///
@@ -9447,7 +9994,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 97:
+    /// Semantic action for production 98:
     ///
     /// `DataDefStmt: LeafStmt;`
     ///
@@ -9466,7 +10013,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 98:
+    /// Semantic action for production 99:
     ///
     /// `DataDefStmt: LeafListStmt;`
     ///
@@ -9485,7 +10032,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 99:
+    /// Semantic action for production 100:
     ///
     /// `DataDefStmt: ListStmt;`
     ///
@@ -9504,7 +10051,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 100:
+    /// Semantic action for production 101:
     ///
     /// `DataDefStmt: ChoiceStmt;`
     ///
@@ -9523,7 +10070,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 101:
+    /// Semantic action for production 102:
     ///
     /// `DataDefStmt: AnydataStmt;`
     ///
@@ -9542,7 +10089,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 102:
+    /// Semantic action for production 103:
     ///
     /// `DataDefStmt: AnyxmlStmt;`
     ///
@@ -9561,7 +10108,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 103:
+    /// Semantic action for production 104:
     ///
     /// `DataDefStmt: UsesStmt;`
     ///
@@ -9573,124 +10120,638 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         let data_def_stmt_7_built = DataDefStmtUsesStmt {
             uses_stmt: Box::new(uses_stmt),
         };
-        let data_def_stmt_7_built = DataDefStmt::UsesStmt(data_def_stmt_7_built);
-        // Calling user action here
-        self.user_grammar.data_def_stmt(&data_def_stmt_7_built)?;
-        self.push(ASTType::DataDefStmt(data_def_stmt_7_built), context);
+        let data_def_stmt_7_built = DataDefStmt::UsesStmt(data_def_stmt_7_built);
+        // Calling user action here
+        self.user_grammar.data_def_stmt(&data_def_stmt_7_built)?;
+        self.push(ASTType::DataDefStmt(data_def_stmt_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 105:
+    ///
+    /// `YangVersionStmt: KwYangVersion^ /* Clipped */ YangVersionArgStr Semicolon^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn yang_version_stmt(
+        &mut self,
+        _kw_yang_version: &ParseTreeType<'t>,
+        _yang_version_arg_str: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.pop(context);
+        let yang_version_arg_str =
+            pop_item!(self, yang_version_arg_str, YangVersionArgStr, context);
+        self.pop(context);
+        let yang_version_stmt_built = YangVersionStmt {
+            yang_version_arg_str: Box::new(yang_version_arg_str),
+        };
+        // Calling user action here
+        self.user_grammar
+            .yang_version_stmt(&yang_version_stmt_built)?;
+        self.push(ASTType::YangVersionStmt(yang_version_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 106:
+    ///
+    /// `YangVersionArgStr: YangVersionArg;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn yang_version_arg_str_0(&mut self, _yang_version_arg: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let yang_version_arg = pop_item!(self, yang_version_arg, YangVersionArg, context);
+        let yang_version_arg_str_0_built = YangVersionArgStrYangVersionArg {
+            yang_version_arg: Box::new(yang_version_arg),
+        };
+        let yang_version_arg_str_0_built =
+            YangVersionArgStr::YangVersionArg(yang_version_arg_str_0_built);
+        // Calling user action here
+        self.user_grammar
+            .yang_version_arg_str(&yang_version_arg_str_0_built)?;
+        self.push(
+            ASTType::YangVersionArgStr(yang_version_arg_str_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 107:
+    ///
+    /// `YangVersionArgStr: <YVersion>'"'^ /* Clipped */ YangVersionArg <YVersion>'"'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn yang_version_arg_str_1(
+        &mut self,
+        _double_quotation: &ParseTreeType<'t>,
+        _yang_version_arg: &ParseTreeType<'t>,
+        _double_quotation0: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let yang_version_arg = pop_item!(self, yang_version_arg, YangVersionArg, context);
+        let yang_version_arg_str_1_built =
+            YangVersionArgStrDoubleQuotationYangVersionArgDoubleQuotation {
+                yang_version_arg: Box::new(yang_version_arg),
+            };
+        let yang_version_arg_str_1_built =
+            YangVersionArgStr::DoubleQuotationYangVersionArgDoubleQuotation(
+                yang_version_arg_str_1_built,
+            );
+        // Calling user action here
+        self.user_grammar
+            .yang_version_arg_str(&yang_version_arg_str_1_built)?;
+        self.push(
+            ASTType::YangVersionArgStr(yang_version_arg_str_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 108:
+    ///
+    /// `DeviationStmt: KwDeviation^ /* Clipped */ AugmentArgStr DeviationStmtSuffix;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviation_stmt(
+        &mut self,
+        _kw_deviation: &ParseTreeType<'t>,
+        _augment_arg_str: &ParseTreeType<'t>,
+        _deviation_stmt_suffix: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let deviation_stmt_suffix =
+            pop_item!(self, deviation_stmt_suffix, DeviationStmtSuffix, context);
+        let augment_arg_str = pop_item!(self, augment_arg_str, AugmentArgStr, context);
+        self.pop(context);
+        let deviation_stmt_built = DeviationStmt {
+            augment_arg_str: Box::new(augment_arg_str),
+            deviation_stmt_suffix: Box::new(deviation_stmt_suffix),
+        };
+        // Calling user action here
+        self.user_grammar.deviation_stmt(&deviation_stmt_built)?;
+        self.push(ASTType::DeviationStmt(deviation_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 109:
+    ///
+    /// `DeviationStmtSuffix: Semicolon^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviation_stmt_suffix_0(&mut self, _semicolon: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.pop(context);
+        let deviation_stmt_suffix_0_built = DeviationStmtSuffixSemicolon {};
+        let deviation_stmt_suffix_0_built =
+            DeviationStmtSuffix::Semicolon(deviation_stmt_suffix_0_built);
+        self.push(
+            ASTType::DeviationStmtSuffix(deviation_stmt_suffix_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 110:
+    ///
+    /// `DeviationStmtSuffix: LBrace^ /* Clipped */ DeviationStmtList /* Vec */ '}'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviation_stmt_suffix_1(
+        &mut self,
+        _l_brace: &ParseTreeType<'t>,
+        _deviation_stmt_list: &ParseTreeType<'t>,
+        _r_brace: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let deviation_stmt_list =
+            pop_and_reverse_item!(self, deviation_stmt_list, DeviationStmtList, context);
+        self.pop(context);
+        let deviation_stmt_suffix_1_built = DeviationStmtSuffixLBraceDeviationStmtListRBrace {
+            deviation_stmt_list,
+        };
+        let deviation_stmt_suffix_1_built =
+            DeviationStmtSuffix::LBraceDeviationStmtListRBrace(deviation_stmt_suffix_1_built);
+        self.push(
+            ASTType::DeviationStmtSuffix(deviation_stmt_suffix_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 111:
+    ///
+    /// `DeviationStmtList /* Vec<T>::Push */: DeviationStmtListGroup DeviationStmtList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviation_stmt_list_0(
+        &mut self,
+        _deviation_stmt_list_group: &ParseTreeType<'t>,
+        _deviation_stmt_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut deviation_stmt_list =
+            pop_item!(self, deviation_stmt_list, DeviationStmtList, context);
+        let deviation_stmt_list_group = pop_item!(
+            self,
+            deviation_stmt_list_group,
+            DeviationStmtListGroup,
+            context
+        );
+        let deviation_stmt_list_0_built = DeviationStmtList {
+            deviation_stmt_list_group: Box::new(deviation_stmt_list_group),
+        };
+        // Add an element to the vector
+        deviation_stmt_list.push(deviation_stmt_list_0_built);
+        self.push(ASTType::DeviationStmtList(deviation_stmt_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 112:
+    ///
+    /// `DeviationStmtListGroup: DescriptionStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviation_stmt_list_group_0(&mut self, _description_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let description_stmt = pop_item!(self, description_stmt, DescriptionStmt, context);
+        let deviation_stmt_list_group_0_built = DeviationStmtListGroupDescriptionStmt {
+            description_stmt: Box::new(description_stmt),
+        };
+        let deviation_stmt_list_group_0_built =
+            DeviationStmtListGroup::DescriptionStmt(deviation_stmt_list_group_0_built);
+        self.push(
+            ASTType::DeviationStmtListGroup(deviation_stmt_list_group_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 113:
+    ///
+    /// `DeviationStmtListGroup: ReferenceStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviation_stmt_list_group_1(&mut self, _reference_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let reference_stmt = pop_item!(self, reference_stmt, ReferenceStmt, context);
+        let deviation_stmt_list_group_1_built = DeviationStmtListGroupReferenceStmt {
+            reference_stmt: Box::new(reference_stmt),
+        };
+        let deviation_stmt_list_group_1_built =
+            DeviationStmtListGroup::ReferenceStmt(deviation_stmt_list_group_1_built);
+        self.push(
+            ASTType::DeviationStmtListGroup(deviation_stmt_list_group_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 114:
+    ///
+    /// `DeviationStmtListGroup: DeviateStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviation_stmt_list_group_2(&mut self, _deviate_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let deviate_stmt = pop_item!(self, deviate_stmt, DeviateStmt, context);
+        let deviation_stmt_list_group_2_built = DeviationStmtListGroupDeviateStmt {
+            deviate_stmt: Box::new(deviate_stmt),
+        };
+        let deviation_stmt_list_group_2_built =
+            DeviationStmtListGroup::DeviateStmt(deviation_stmt_list_group_2_built);
+        self.push(
+            ASTType::DeviationStmtListGroup(deviation_stmt_list_group_2_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 115:
+    ///
+    /// `DeviationStmtList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviation_stmt_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let deviation_stmt_list_1_built = Vec::new();
+        self.push(
+            ASTType::DeviationStmtList(deviation_stmt_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 116:
+    ///
+    /// `DeviateStmt: KwDeviate^ /* Clipped */ DeviateArgStr DeviateStmtSuffix;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt(
+        &mut self,
+        _kw_deviate: &ParseTreeType<'t>,
+        _deviate_arg_str: &ParseTreeType<'t>,
+        _deviate_stmt_suffix: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let deviate_stmt_suffix = pop_item!(self, deviate_stmt_suffix, DeviateStmtSuffix, context);
+        let deviate_arg_str = pop_item!(self, deviate_arg_str, DeviateArgStr, context);
+        self.pop(context);
+        let deviate_stmt_built = DeviateStmt {
+            deviate_arg_str: Box::new(deviate_arg_str),
+            deviate_stmt_suffix: Box::new(deviate_stmt_suffix),
+        };
+        // Calling user action here
+        self.user_grammar.deviate_stmt(&deviate_stmt_built)?;
+        self.push(ASTType::DeviateStmt(deviate_stmt_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 117:
+    ///
+    /// `DeviateStmtSuffix: Semicolon^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt_suffix_0(&mut self, _semicolon: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.pop(context);
+        let deviate_stmt_suffix_0_built = DeviateStmtSuffixSemicolon {};
+        let deviate_stmt_suffix_0_built = DeviateStmtSuffix::Semicolon(deviate_stmt_suffix_0_built);
+        self.push(
+            ASTType::DeviateStmtSuffix(deviate_stmt_suffix_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 118:
+    ///
+    /// `DeviateStmtSuffix: LBrace^ /* Clipped */ DeviateStmtList /* Vec */ '}'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt_suffix_1(
+        &mut self,
+        _l_brace: &ParseTreeType<'t>,
+        _deviate_stmt_list: &ParseTreeType<'t>,
+        _r_brace: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let deviate_stmt_list =
+            pop_and_reverse_item!(self, deviate_stmt_list, DeviateStmtList, context);
+        self.pop(context);
+        let deviate_stmt_suffix_1_built =
+            DeviateStmtSuffixLBraceDeviateStmtListRBrace { deviate_stmt_list };
+        let deviate_stmt_suffix_1_built =
+            DeviateStmtSuffix::LBraceDeviateStmtListRBrace(deviate_stmt_suffix_1_built);
+        self.push(
+            ASTType::DeviateStmtSuffix(deviate_stmt_suffix_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 119:
+    ///
+    /// `DeviateStmtList /* Vec<T>::Push */: DeviateStmtListGroup DeviateStmtList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt_list_0(
+        &mut self,
+        _deviate_stmt_list_group: &ParseTreeType<'t>,
+        _deviate_stmt_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut deviate_stmt_list = pop_item!(self, deviate_stmt_list, DeviateStmtList, context);
+        let deviate_stmt_list_group =
+            pop_item!(self, deviate_stmt_list_group, DeviateStmtListGroup, context);
+        let deviate_stmt_list_0_built = DeviateStmtList {
+            deviate_stmt_list_group: Box::new(deviate_stmt_list_group),
+        };
+        // Add an element to the vector
+        deviate_stmt_list.push(deviate_stmt_list_0_built);
+        self.push(ASTType::DeviateStmtList(deviate_stmt_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 120:
+    ///
+    /// `DeviateStmtListGroup: UnitsStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt_list_group_0(&mut self, _units_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let units_stmt = pop_item!(self, units_stmt, UnitsStmt, context);
+        let deviate_stmt_list_group_0_built = DeviateStmtListGroupUnitsStmt {
+            units_stmt: Box::new(units_stmt),
+        };
+        let deviate_stmt_list_group_0_built =
+            DeviateStmtListGroup::UnitsStmt(deviate_stmt_list_group_0_built);
+        self.push(
+            ASTType::DeviateStmtListGroup(deviate_stmt_list_group_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 121:
+    ///
+    /// `DeviateStmtListGroup: MustStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt_list_group_1(&mut self, _must_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let must_stmt = pop_item!(self, must_stmt, MustStmt, context);
+        let deviate_stmt_list_group_1_built = DeviateStmtListGroupMustStmt {
+            must_stmt: Box::new(must_stmt),
+        };
+        let deviate_stmt_list_group_1_built =
+            DeviateStmtListGroup::MustStmt(deviate_stmt_list_group_1_built);
+        self.push(
+            ASTType::DeviateStmtListGroup(deviate_stmt_list_group_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 122:
+    ///
+    /// `DeviateStmtListGroup: UnknownStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt_list_group_2(&mut self, _unknown_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let unknown_stmt = pop_item!(self, unknown_stmt, UnknownStmt, context);
+        let deviate_stmt_list_group_2_built = DeviateStmtListGroupUnknownStmt {
+            unknown_stmt: Box::new(unknown_stmt),
+        };
+        let deviate_stmt_list_group_2_built =
+            DeviateStmtListGroup::UnknownStmt(deviate_stmt_list_group_2_built);
+        self.push(
+            ASTType::DeviateStmtListGroup(deviate_stmt_list_group_2_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 123:
+    ///
+    /// `DeviateStmtListGroup: DefaultStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt_list_group_3(&mut self, _default_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let default_stmt = pop_item!(self, default_stmt, DefaultStmt, context);
+        let deviate_stmt_list_group_3_built = DeviateStmtListGroupDefaultStmt {
+            default_stmt: Box::new(default_stmt),
+        };
+        let deviate_stmt_list_group_3_built =
+            DeviateStmtListGroup::DefaultStmt(deviate_stmt_list_group_3_built);
+        self.push(
+            ASTType::DeviateStmtListGroup(deviate_stmt_list_group_3_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 124:
+    ///
+    /// `DeviateStmtListGroup: ConfigStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt_list_group_4(&mut self, _config_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let config_stmt = pop_item!(self, config_stmt, ConfigStmt, context);
+        let deviate_stmt_list_group_4_built = DeviateStmtListGroupConfigStmt {
+            config_stmt: Box::new(config_stmt),
+        };
+        let deviate_stmt_list_group_4_built =
+            DeviateStmtListGroup::ConfigStmt(deviate_stmt_list_group_4_built);
+        self.push(
+            ASTType::DeviateStmtListGroup(deviate_stmt_list_group_4_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 125:
+    ///
+    /// `DeviateStmtListGroup: MandatoryStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt_list_group_5(&mut self, _mandatory_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mandatory_stmt = pop_item!(self, mandatory_stmt, MandatoryStmt, context);
+        let deviate_stmt_list_group_5_built = DeviateStmtListGroupMandatoryStmt {
+            mandatory_stmt: Box::new(mandatory_stmt),
+        };
+        let deviate_stmt_list_group_5_built =
+            DeviateStmtListGroup::MandatoryStmt(deviate_stmt_list_group_5_built);
+        self.push(
+            ASTType::DeviateStmtListGroup(deviate_stmt_list_group_5_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 126:
+    ///
+    /// `DeviateStmtListGroup: MinElementsStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt_list_group_6(&mut self, _min_elements_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let min_elements_stmt = pop_item!(self, min_elements_stmt, MinElementsStmt, context);
+        let deviate_stmt_list_group_6_built = DeviateStmtListGroupMinElementsStmt {
+            min_elements_stmt: Box::new(min_elements_stmt),
+        };
+        let deviate_stmt_list_group_6_built =
+            DeviateStmtListGroup::MinElementsStmt(deviate_stmt_list_group_6_built);
+        self.push(
+            ASTType::DeviateStmtListGroup(deviate_stmt_list_group_6_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 127:
+    ///
+    /// `DeviateStmtListGroup: MaxElementsStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt_list_group_7(&mut self, _max_elements_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let max_elements_stmt = pop_item!(self, max_elements_stmt, MaxElementsStmt, context);
+        let deviate_stmt_list_group_7_built = DeviateStmtListGroupMaxElementsStmt {
+            max_elements_stmt: Box::new(max_elements_stmt),
+        };
+        let deviate_stmt_list_group_7_built =
+            DeviateStmtListGroup::MaxElementsStmt(deviate_stmt_list_group_7_built);
+        self.push(
+            ASTType::DeviateStmtListGroup(deviate_stmt_list_group_7_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 128:
+    ///
+    /// `DeviateStmtListGroup: TypeStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn deviate_stmt_list_group_8(&mut self, _type_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let type_stmt = pop_item!(self, type_stmt, TypeStmt, context);
+        let deviate_stmt_list_group_8_built = DeviateStmtListGroupTypeStmt {
+            type_stmt: Box::new(type_stmt),
+        };
+        let deviate_stmt_list_group_8_built =
+            DeviateStmtListGroup::TypeStmt(deviate_stmt_list_group_8_built);
+        self.push(
+            ASTType::DeviateStmtListGroup(deviate_stmt_list_group_8_built),
+            context,
+        );
         Ok(())
     }
 
-    /// Semantic action for production 104:
+    /// Semantic action for production 129:
     ///
-    /// `YangVersionStmt: KwYangVersion^ /* Clipped */ YangVersionArgStr Semicolon^ /* Clipped */;`
+    /// `DeviateStmtList /* Vec<T>::New */: ;`
     ///
     #[parol_runtime::function_name::named]
-    fn yang_version_stmt(
-        &mut self,
-        _kw_yang_version: &ParseTreeType<'t>,
-        _yang_version_arg_str: &ParseTreeType<'t>,
-        _semicolon: &ParseTreeType<'t>,
-    ) -> Result<()> {
+    fn deviate_stmt_list_1(&mut self) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        self.pop(context);
-        let yang_version_arg_str =
-            pop_item!(self, yang_version_arg_str, YangVersionArgStr, context);
-        self.pop(context);
-        let yang_version_stmt_built = YangVersionStmt {
-            yang_version_arg_str: Box::new(yang_version_arg_str),
-        };
-        // Calling user action here
-        self.user_grammar
-            .yang_version_stmt(&yang_version_stmt_built)?;
-        self.push(ASTType::YangVersionStmt(yang_version_stmt_built), context);
+        let deviate_stmt_list_1_built = Vec::new();
+        self.push(ASTType::DeviateStmtList(deviate_stmt_list_1_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 105:
+    /// Semantic action for production 130:
     ///
-    /// `YangVersionArgStr: YangVersionArg;`
+    /// `DeviateArgStr: DeviateArg;`
     ///
     #[parol_runtime::function_name::named]
-    fn yang_version_arg_str_0(&mut self, _yang_version_arg: &ParseTreeType<'t>) -> Result<()> {
+    fn deviate_arg_str_0(&mut self, _deviate_arg: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let yang_version_arg = pop_item!(self, yang_version_arg, YangVersionArg, context);
-        let yang_version_arg_str_0_built = YangVersionArgStrYangVersionArg {
-            yang_version_arg: Box::new(yang_version_arg),
+        let deviate_arg = pop_item!(self, deviate_arg, DeviateArg, context);
+        let deviate_arg_str_0_built = DeviateArgStrDeviateArg {
+            deviate_arg: Box::new(deviate_arg),
         };
-        let yang_version_arg_str_0_built =
-            YangVersionArgStr::YangVersionArg(yang_version_arg_str_0_built);
+        let deviate_arg_str_0_built = DeviateArgStr::DeviateArg(deviate_arg_str_0_built);
         // Calling user action here
         self.user_grammar
-            .yang_version_arg_str(&yang_version_arg_str_0_built)?;
-        self.push(
-            ASTType::YangVersionArgStr(yang_version_arg_str_0_built),
-            context,
-        );
+            .deviate_arg_str(&deviate_arg_str_0_built)?;
+        self.push(ASTType::DeviateArgStr(deviate_arg_str_0_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 106:
+    /// Semantic action for production 131:
     ///
-    /// `YangVersionArgStr: <YVersion>'"'^ /* Clipped */ YangVersionArg <YVersion>'"'^ /* Clipped */;`
+    /// `DeviateArgStr: <Deviate>'"'^ /* Clipped */ DeviateArg <Deviate>'"'^ /* Clipped */;`
     ///
     #[parol_runtime::function_name::named]
-    fn yang_version_arg_str_1(
+    fn deviate_arg_str_1(
         &mut self,
         _double_quotation: &ParseTreeType<'t>,
-        _yang_version_arg: &ParseTreeType<'t>,
+        _deviate_arg: &ParseTreeType<'t>,
         _double_quotation0: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let yang_version_arg = pop_item!(self, yang_version_arg, YangVersionArg, context);
-        let yang_version_arg_str_1_built =
-            YangVersionArgStrDoubleQuotationYangVersionArgDoubleQuotation {
-                yang_version_arg: Box::new(yang_version_arg),
-            };
-        let yang_version_arg_str_1_built =
-            YangVersionArgStr::DoubleQuotationYangVersionArgDoubleQuotation(
-                yang_version_arg_str_1_built,
-            );
+        let deviate_arg = pop_item!(self, deviate_arg, DeviateArg, context);
+        let deviate_arg_str_1_built = DeviateArgStrDoubleQuotationDeviateArgDoubleQuotation {
+            deviate_arg: Box::new(deviate_arg),
+        };
+        let deviate_arg_str_1_built =
+            DeviateArgStr::DoubleQuotationDeviateArgDoubleQuotation(deviate_arg_str_1_built);
         // Calling user action here
         self.user_grammar
-            .yang_version_arg_str(&yang_version_arg_str_1_built)?;
-        self.push(
-            ASTType::YangVersionArgStr(yang_version_arg_str_1_built),
-            context,
-        );
+            .deviate_arg_str(&deviate_arg_str_1_built)?;
+        self.push(ASTType::DeviateArgStr(deviate_arg_str_1_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 107:
+    /// Semantic action for production 132:
     ///
-    /// `DeviationStmt: KwDeviation^ /* Clipped */ AbsoluteSchemaNodeid Semicolon;`
+    /// `DeviateArg: <Deviate>/not-supported|add|replace|delete/;`
     ///
     #[parol_runtime::function_name::named]
-    fn deviation_stmt(
-        &mut self,
-        _kw_deviation: &ParseTreeType<'t>,
-        _absolute_schema_nodeid: &ParseTreeType<'t>,
-        _semicolon: &ParseTreeType<'t>,
-    ) -> Result<()> {
+    fn deviate_arg(&mut self, deviate_arg: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let semicolon = pop_item!(self, semicolon, Semicolon, context);
-        let absolute_schema_nodeid =
-            pop_item!(self, absolute_schema_nodeid, AbsoluteSchemaNodeid, context);
-        self.pop(context);
-        let deviation_stmt_built = DeviationStmt {
-            absolute_schema_nodeid: Box::new(absolute_schema_nodeid),
-            semicolon: Box::new(semicolon),
-        };
+        let deviate_arg = deviate_arg.token()?.clone();
+        let deviate_arg_built = DeviateArg { deviate_arg };
         // Calling user action here
-        self.user_grammar.deviation_stmt(&deviation_stmt_built)?;
-        self.push(ASTType::DeviationStmt(deviation_stmt_built), context);
+        self.user_grammar.deviate_arg(&deviate_arg_built)?;
+        self.push(ASTType::DeviateArg(deviate_arg_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 108:
+    /// Semantic action for production 133:
     ///
     /// `RpcStmt: KwRpc^ /* Clipped */ IdentifierArgStr RpcStmtSuffix;`
     ///
@@ -9716,7 +10777,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 109:
+    /// Semantic action for production 134:
     ///
     /// `RpcStmtSuffix: Semicolon;`
     ///
@@ -9733,7 +10794,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 110:
+    /// Semantic action for production 135:
     ///
     /// `RpcStmtSuffix: LBrace^ /* Clipped */ RpcStmtList /* Vec */ '}';`
     ///
@@ -9759,7 +10820,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 111:
+    /// Semantic action for production 136:
     ///
     /// `RpcStmtList /* Vec<T>::Push */: RpcStmtListGroup RpcStmtList;`
     ///
@@ -9782,7 +10843,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 112:
+    /// Semantic action for production 137:
     ///
     /// `RpcStmtListGroup: IfFeatureStmt;`
     ///
@@ -9803,7 +10864,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 113:
+    /// Semantic action for production 138:
     ///
     /// `RpcStmtListGroup: StatusStmt;`
     ///
@@ -9823,7 +10884,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 114:
+    /// Semantic action for production 139:
     ///
     /// `RpcStmtListGroup: DescriptionStmt;`
     ///
@@ -9844,7 +10905,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 115:
+    /// Semantic action for production 140:
     ///
     /// `RpcStmtListGroup: ReferenceStmt;`
     ///
@@ -9865,7 +10926,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 116:
+    /// Semantic action for production 141:
     ///
     /// `RpcStmtListGroup: TypedefStmt;`
     ///
@@ -9886,7 +10947,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 117:
+    /// Semantic action for production 142:
     ///
     /// `RpcStmtListGroup: GroupingStmt;`
     ///
@@ -9907,7 +10968,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 118:
+    /// Semantic action for production 143:
     ///
     /// `RpcStmtListGroup: InputStmt;`
     ///
@@ -9927,7 +10988,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 119:
+    /// Semantic action for production 144:
     ///
     /// `RpcStmtListGroup: OutputStmt;`
     ///
@@ -9947,7 +11008,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 120:
+    /// Semantic action for production 145:
     ///
     /// `RpcStmtList /* Vec<T>::New */: ;`
     ///
@@ -9960,7 +11021,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 121:
+    /// Semantic action for production 146:
     ///
     /// `ExtensionStmt: KwExtension^ /* Clipped */ IdentifierArgStr ExtensionStmtSuffix;`
     ///
@@ -9987,7 +11048,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 122:
+    /// Semantic action for production 147:
     ///
     /// `ExtensionStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -10006,7 +11067,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 123:
+    /// Semantic action for production 148:
     ///
     /// `ExtensionStmtSuffix: LBrace^ /* Clipped */ ExtensionStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -10034,7 +11095,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 124:
+    /// Semantic action for production 149:
     ///
     /// `ExtensionStmtList /* Vec<T>::Push */: ExtensionStmtListGroup ExtensionStmtList;`
     ///
@@ -10063,7 +11124,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 125:
+    /// Semantic action for production 150:
     ///
     /// `ExtensionStmtListGroup: ArgumentStmt;`
     ///
@@ -10084,7 +11145,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 126:
+    /// Semantic action for production 151:
     ///
     /// `ExtensionStmtListGroup: StatusStmt;`
     ///
@@ -10105,7 +11166,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 127:
+    /// Semantic action for production 152:
     ///
     /// `ExtensionStmtListGroup: DescriptionStmt;`
     ///
@@ -10126,7 +11187,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 128:
+    /// Semantic action for production 153:
     ///
     /// `ExtensionStmtListGroup: ReferenceStmt;`
     ///
@@ -10147,7 +11208,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 129:
+    /// Semantic action for production 154:
     ///
     /// `ExtensionStmtList /* Vec<T>::New */: ;`
     ///
@@ -10163,7 +11224,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 130:
+    /// Semantic action for production 155:
     ///
     /// `ArgumentStmt: KwArgument^ /* Clipped */ IdentifierArgStr Semicolon^ /* Clipped */;`
     ///
@@ -10188,7 +11249,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 131:
+    /// Semantic action for production 156:
     ///
     /// `FeatureStmt: KwFeature^ /* Clipped */ IdentifierArgStr FeatureStmtSuffix;`
     ///
@@ -10214,7 +11275,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 132:
+    /// Semantic action for production 157:
     ///
     /// `FeatureStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -10232,7 +11293,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 133:
+    /// Semantic action for production 158:
     ///
     /// `FeatureStmtSuffix: LBrace^ /* Clipped */ FeatureStmtList /* Vec */ '}';`
     ///
@@ -10262,7 +11323,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 134:
+    /// Semantic action for production 159:
     ///
     /// `FeatureStmtList /* Vec<T>::Push */: FeatureStmtListGroup FeatureStmtList;`
     ///
@@ -10286,7 +11347,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 135:
+    /// Semantic action for production 160:
     ///
     /// `FeatureStmtListGroup: IfFeatureStmt;`
     ///
@@ -10307,7 +11368,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 136:
+    /// Semantic action for production 161:
     ///
     /// `FeatureStmtListGroup: StatusStmt;`
     ///
@@ -10328,7 +11389,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 137:
+    /// Semantic action for production 162:
     ///
     /// `FeatureStmtListGroup: DescriptionStmt;`
     ///
@@ -10349,7 +11410,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 138:
+    /// Semantic action for production 163:
     ///
     /// `FeatureStmtListGroup: ReferenceStmt;`
     ///
@@ -10370,7 +11431,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 139:
+    /// Semantic action for production 164:
     ///
     /// `FeatureStmtList /* Vec<T>::New */: ;`
     ///
@@ -10383,7 +11444,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 140:
+    /// Semantic action for production 165:
     ///
     /// `TypedefStmt: KwTypedef^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ TypedefStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -10413,7 +11474,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 141:
+    /// Semantic action for production 166:
     ///
     /// `TypedefStmtList /* Vec<T>::Push */: TypedefStmtListGroup TypedefStmtList;`
     ///
@@ -10437,7 +11498,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 142:
+    /// Semantic action for production 167:
     ///
     /// `TypedefStmtListGroup: TypeStmt;`
     ///
@@ -10458,7 +11519,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 143:
+    /// Semantic action for production 168:
     ///
     /// `TypedefStmtListGroup: UnitsStmt;`
     ///
@@ -10479,7 +11540,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 144:
+    /// Semantic action for production 169:
     ///
     /// `TypedefStmtListGroup: DefaultStmt;`
     ///
@@ -10500,7 +11561,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 145:
+    /// Semantic action for production 170:
     ///
     /// `TypedefStmtListGroup: StatusStmt;`
     ///
@@ -10521,7 +11582,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 146:
+    /// Semantic action for production 171:
     ///
     /// `TypedefStmtListGroup: DescriptionStmt;`
     ///
@@ -10542,7 +11603,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 147:
+    /// Semantic action for production 172:
     ///
     /// `TypedefStmtListGroup: ReferenceStmt;`
     ///
@@ -10563,7 +11624,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 148:
+    /// Semantic action for production 173:
     ///
     /// `TypedefStmtList /* Vec<T>::New */: ;`
     ///
@@ -10576,7 +11637,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 149:
+    /// Semantic action for production 174:
     ///
     /// `AugmentStmt: 'augment'^ /* Clipped */ AugmentArgStr LBrace^ /* Clipped */ AugmentStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -10605,7 +11666,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 150:
+    /// Semantic action for production 175:
     ///
     /// `AugmentStmtList /* Vec<T>::Push */: AugmentStmtListGroup AugmentStmtList;`
     ///
@@ -10629,7 +11690,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 151:
+    /// Semantic action for production 176:
     ///
     /// `AugmentStmtListGroup: WhenStmt;`
     ///
@@ -10650,7 +11711,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 152:
+    /// Semantic action for production 177:
     ///
     /// `AugmentStmtListGroup: IfFeatureStmt;`
     ///
@@ -10671,7 +11732,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 153:
+    /// Semantic action for production 178:
     ///
     /// `AugmentStmtListGroup: StatusStmt;`
     ///
@@ -10692,7 +11753,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 154:
+    /// Semantic action for production 179:
     ///
     /// `AugmentStmtListGroup: DescriptionStmt;`
     ///
@@ -10713,7 +11774,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 155:
+    /// Semantic action for production 180:
     ///
     /// `AugmentStmtListGroup: ReferenceStmt;`
     ///
@@ -10734,7 +11795,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 156:
+    /// Semantic action for production 181:
     ///
     /// `AugmentStmtListGroup: DataDefStmt;`
     ///
@@ -10755,7 +11816,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 157:
+    /// Semantic action for production 182:
     ///
     /// `AugmentStmtListGroup: CaseStmt;`
     ///
@@ -10776,7 +11837,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 158:
+    /// Semantic action for production 183:
     ///
     /// `AugmentStmtListGroup: ActionStmt;`
     ///
@@ -10797,7 +11858,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 159:
+    /// Semantic action for production 184:
     ///
     /// `AugmentStmtListGroup: NotificationStmt;`
     ///
@@ -10818,7 +11879,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 160:
+    /// Semantic action for production 185:
     ///
     /// `AugmentStmtList /* Vec<T>::New */: ;`
     ///
@@ -10831,7 +11892,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 161:
+    /// Semantic action for production 186:
     ///
     /// `AugmentArgStr: Ystring;`
     ///
@@ -10849,7 +11910,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 162:
+    /// Semantic action for production 187:
     ///
     /// `WhenStmt: 'when'^ /* Clipped */ Ystring WhenStmtSuffix;`
     ///
@@ -10874,7 +11935,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 163:
+    /// Semantic action for production 188:
     ///
     /// `WhenStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -10889,7 +11950,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 164:
+    /// Semantic action for production 189:
     ///
     /// `WhenStmtSuffix: LBrace^ /* Clipped */ WhenStmtList /* Vec */ '}';`
     ///
@@ -10915,7 +11976,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 165:
+    /// Semantic action for production 190:
     ///
     /// `WhenStmtList /* Vec<T>::Push */: WhenStmtListGroup WhenStmtList;`
     ///
@@ -10939,7 +12000,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 166:
+    /// Semantic action for production 191:
     ///
     /// `WhenStmtListGroup: DescriptionStmt;`
     ///
@@ -10960,7 +12021,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 167:
+    /// Semantic action for production 192:
     ///
     /// `WhenStmtListGroup: ReferenceStmt;`
     ///
@@ -10981,7 +12042,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 168:
+    /// Semantic action for production 193:
     ///
     /// `WhenStmtList /* Vec<T>::New */: ;`
     ///
@@ -10994,7 +12055,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 169:
+    /// Semantic action for production 194:
     ///
     /// `GroupingStmt: KwGrouping^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ GroupingStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -11024,7 +12085,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 170:
+    /// Semantic action for production 195:
     ///
     /// `GroupingStmtList /* Vec<T>::Push */: GroupingStmtListGroup GroupingStmtList;`
     ///
@@ -11052,7 +12113,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 171:
+    /// Semantic action for production 196:
     ///
     /// `GroupingStmtListGroup: StatusStmt;`
     ///
@@ -11073,7 +12134,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 172:
+    /// Semantic action for production 197:
     ///
     /// `GroupingStmtListGroup: DescriptionStmt;`
     ///
@@ -11094,7 +12155,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 173:
+    /// Semantic action for production 198:
     ///
     /// `GroupingStmtListGroup: ReferenceStmt;`
     ///
@@ -11115,7 +12176,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 174:
+    /// Semantic action for production 199:
     ///
     /// `GroupingStmtListGroup: TypedefStmt;`
     ///
@@ -11136,7 +12197,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 175:
+    /// Semantic action for production 200:
     ///
     /// `GroupingStmtListGroup: GroupingStmt;`
     ///
@@ -11157,7 +12218,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 176:
+    /// Semantic action for production 201:
     ///
     /// `GroupingStmtListGroup: DataDefStmt;`
     ///
@@ -11178,7 +12239,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 177:
+    /// Semantic action for production 202:
     ///
     /// `GroupingStmtListGroup: ActionStmt;`
     ///
@@ -11199,7 +12260,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 178:
+    /// Semantic action for production 203:
     ///
     /// `GroupingStmtListGroup: NotificationStmt;`
     ///
@@ -11220,7 +12281,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 179:
+    /// Semantic action for production 204:
     ///
     /// `GroupingStmtListGroup: UnknownStmt;`
     ///
@@ -11241,7 +12302,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 180:
+    /// Semantic action for production 205:
     ///
     /// `GroupingStmtList /* Vec<T>::New */: ;`
     ///
@@ -11257,7 +12318,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 181:
+    /// Semantic action for production 206:
     ///
     /// `IdentityStmt: KwIdentity^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ IdentityStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -11287,7 +12348,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 182:
+    /// Semantic action for production 207:
     ///
     /// `IdentityStmtList /* Vec<T>::Push */: IdentityStmtListGroup IdentityStmtList;`
     ///
@@ -11315,7 +12376,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 183:
+    /// Semantic action for production 208:
     ///
     /// `IdentityStmtListGroup: IfFeatureStmt;`
     ///
@@ -11330,76 +12391,97 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         let identity_stmt_list_group_0_built =
             IdentityStmtListGroup::IfFeatureStmt(identity_stmt_list_group_0_built);
         self.push(
-            ASTType::IdentityStmtListGroup(identity_stmt_list_group_0_built),
+            ASTType::IdentityStmtListGroup(identity_stmt_list_group_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 209:
+    ///
+    /// `IdentityStmtListGroup: BaseStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn identity_stmt_list_group_1(&mut self, _base_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let base_stmt = pop_item!(self, base_stmt, BaseStmt, context);
+        let identity_stmt_list_group_1_built = IdentityStmtListGroupBaseStmt {
+            base_stmt: Box::new(base_stmt),
+        };
+        let identity_stmt_list_group_1_built =
+            IdentityStmtListGroup::BaseStmt(identity_stmt_list_group_1_built);
+        self.push(
+            ASTType::IdentityStmtListGroup(identity_stmt_list_group_1_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 184:
+    /// Semantic action for production 210:
     ///
-    /// `IdentityStmtListGroup: BaseStmt;`
+    /// `IdentityStmtListGroup: StatusStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn identity_stmt_list_group_1(&mut self, _base_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn identity_stmt_list_group_2(&mut self, _status_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let base_stmt = pop_item!(self, base_stmt, BaseStmt, context);
-        let identity_stmt_list_group_1_built = IdentityStmtListGroupBaseStmt {
-            base_stmt: Box::new(base_stmt),
+        let status_stmt = pop_item!(self, status_stmt, StatusStmt, context);
+        let identity_stmt_list_group_2_built = IdentityStmtListGroupStatusStmt {
+            status_stmt: Box::new(status_stmt),
         };
-        let identity_stmt_list_group_1_built =
-            IdentityStmtListGroup::BaseStmt(identity_stmt_list_group_1_built);
+        let identity_stmt_list_group_2_built =
+            IdentityStmtListGroup::StatusStmt(identity_stmt_list_group_2_built);
         self.push(
-            ASTType::IdentityStmtListGroup(identity_stmt_list_group_1_built),
+            ASTType::IdentityStmtListGroup(identity_stmt_list_group_2_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 185:
+    /// Semantic action for production 211:
     ///
     /// `IdentityStmtListGroup: DescriptionStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn identity_stmt_list_group_2(&mut self, _description_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn identity_stmt_list_group_3(&mut self, _description_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let description_stmt = pop_item!(self, description_stmt, DescriptionStmt, context);
-        let identity_stmt_list_group_2_built = IdentityStmtListGroupDescriptionStmt {
+        let identity_stmt_list_group_3_built = IdentityStmtListGroupDescriptionStmt {
             description_stmt: Box::new(description_stmt),
         };
-        let identity_stmt_list_group_2_built =
-            IdentityStmtListGroup::DescriptionStmt(identity_stmt_list_group_2_built);
+        let identity_stmt_list_group_3_built =
+            IdentityStmtListGroup::DescriptionStmt(identity_stmt_list_group_3_built);
         self.push(
-            ASTType::IdentityStmtListGroup(identity_stmt_list_group_2_built),
+            ASTType::IdentityStmtListGroup(identity_stmt_list_group_3_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 186:
+    /// Semantic action for production 212:
     ///
     /// `IdentityStmtListGroup: ReferenceStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn identity_stmt_list_group_3(&mut self, _reference_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn identity_stmt_list_group_4(&mut self, _reference_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let reference_stmt = pop_item!(self, reference_stmt, ReferenceStmt, context);
-        let identity_stmt_list_group_3_built = IdentityStmtListGroupReferenceStmt {
+        let identity_stmt_list_group_4_built = IdentityStmtListGroupReferenceStmt {
             reference_stmt: Box::new(reference_stmt),
         };
-        let identity_stmt_list_group_3_built =
-            IdentityStmtListGroup::ReferenceStmt(identity_stmt_list_group_3_built);
+        let identity_stmt_list_group_4_built =
+            IdentityStmtListGroup::ReferenceStmt(identity_stmt_list_group_4_built);
         self.push(
-            ASTType::IdentityStmtListGroup(identity_stmt_list_group_3_built),
+            ASTType::IdentityStmtListGroup(identity_stmt_list_group_4_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 187:
+    /// Semantic action for production 213:
     ///
     /// `IdentityStmtList /* Vec<T>::New */: ;`
     ///
@@ -11415,7 +12497,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 188:
+    /// Semantic action for production 214:
     ///
     /// `BaseStmt: KwBase^ /* Clipped */ IdentifierRefArgStr Semicolon^ /* Clipped */;`
     ///
@@ -11441,7 +12523,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 189:
+    /// Semantic action for production 215:
     ///
     /// `AnyxmlStmt: KwAnyxml^ /* Clipped */ IdentifierArgStr AnyxmlStmtSuffix;`
     ///
@@ -11467,7 +12549,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 190:
+    /// Semantic action for production 216:
     ///
     /// `AnyxmlStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -11485,7 +12567,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 191:
+    /// Semantic action for production 217:
     ///
     /// `AnyxmlStmtSuffix: LBrace^ /* Clipped */ AnyxmlStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -11512,7 +12594,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 192:
+    /// Semantic action for production 218:
     ///
     /// `AnyxmlStmtList /* Vec<T>::Push */: AnyxmlStmtListGroup AnyxmlStmtList;`
     ///
@@ -11536,7 +12618,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 193:
+    /// Semantic action for production 219:
     ///
     /// `AnyxmlStmtListGroup: WhenStmt;`
     ///
@@ -11557,7 +12639,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 194:
+    /// Semantic action for production 220:
     ///
     /// `AnyxmlStmtListGroup: IfFeatureStmt;`
     ///
@@ -11578,7 +12660,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 195:
+    /// Semantic action for production 221:
     ///
     /// `AnyxmlStmtListGroup: MustStmt;`
     ///
@@ -11599,7 +12681,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 196:
+    /// Semantic action for production 222:
     ///
     /// `AnyxmlStmtListGroup: ConfigStmt;`
     ///
@@ -11620,7 +12702,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 197:
+    /// Semantic action for production 223:
     ///
     /// `AnyxmlStmtListGroup: MandatoryStmt;`
     ///
@@ -11641,7 +12723,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 198:
+    /// Semantic action for production 224:
     ///
     /// `AnyxmlStmtListGroup: StatusStmt;`
     ///
@@ -11662,7 +12744,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 199:
+    /// Semantic action for production 225:
     ///
     /// `AnyxmlStmtListGroup: DescriptionStmt;`
     ///
@@ -11683,7 +12765,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 200:
+    /// Semantic action for production 226:
     ///
     /// `AnyxmlStmtListGroup: ReferenceStmt;`
     ///
@@ -11704,7 +12786,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 201:
+    /// Semantic action for production 227:
     ///
     /// `AnyxmlStmtList /* Vec<T>::New */: ;`
     ///
@@ -11717,7 +12799,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 202:
+    /// Semantic action for production 228:
     ///
     /// `ChoiceStmt: KwChoice^ /* Clipped */ IdentifierRefArgStr ChoiceStmtSuffix;`
     ///
@@ -11744,7 +12826,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 203:
+    /// Semantic action for production 229:
     ///
     /// `ChoiceStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -11762,7 +12844,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 204:
+    /// Semantic action for production 230:
     ///
     /// `ChoiceStmtSuffix: LBrace^ /* Clipped */ ChoiceStmtList /* Vec */ '}';`
     ///
@@ -11792,7 +12874,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 205:
+    /// Semantic action for production 231:
     ///
     /// `ChoiceStmtList /* Vec<T>::Push */: ChoiceStmtListGroup ChoiceStmtList;`
     ///
@@ -11816,7 +12898,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 206:
+    /// Semantic action for production 232:
     ///
     /// `ChoiceStmtListGroup: WhenStmt;`
     ///
@@ -11837,7 +12919,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 207:
+    /// Semantic action for production 233:
     ///
     /// `ChoiceStmtListGroup: IfFeatureStmt;`
     ///
@@ -11858,7 +12940,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 208:
+    /// Semantic action for production 234:
     ///
     /// `ChoiceStmtListGroup: DefaultStmt;`
     ///
@@ -11879,7 +12961,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 209:
+    /// Semantic action for production 235:
     ///
     /// `ChoiceStmtListGroup: ConfigStmt;`
     ///
@@ -11900,7 +12982,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 210:
+    /// Semantic action for production 236:
     ///
     /// `ChoiceStmtListGroup: MandatoryStmt;`
     ///
@@ -11921,7 +13003,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 211:
+    /// Semantic action for production 237:
     ///
     /// `ChoiceStmtListGroup: StatusStmt;`
     ///
@@ -11942,7 +13024,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 212:
+    /// Semantic action for production 238:
     ///
     /// `ChoiceStmtListGroup: DescriptionStmt;`
     ///
@@ -11963,7 +13045,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 213:
+    /// Semantic action for production 239:
     ///
     /// `ChoiceStmtListGroup: ReferenceStmt;`
     ///
@@ -11984,7 +13066,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 214:
+    /// Semantic action for production 240:
     ///
     /// `ChoiceStmtListGroup: ShortCaseStmt;`
     ///
@@ -12005,7 +13087,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 215:
+    /// Semantic action for production 241:
     ///
     /// `ChoiceStmtListGroup: CaseStmt;`
     ///
@@ -12026,7 +13108,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 216:
+    /// Semantic action for production 242:
     ///
     /// `ChoiceStmtList /* Vec<T>::New */: ;`
     ///
@@ -12039,7 +13121,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 217:
+    /// Semantic action for production 243:
     ///
     /// `ShortCaseStmt: ChoiceStmt;`
     ///
@@ -12059,7 +13141,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 218:
+    /// Semantic action for production 244:
     ///
     /// `ShortCaseStmt: ContainerStmt;`
     ///
@@ -12079,7 +13161,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 219:
+    /// Semantic action for production 245:
     ///
     /// `ShortCaseStmt: LeafStmt;`
     ///
@@ -12099,7 +13181,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 220:
+    /// Semantic action for production 246:
     ///
     /// `ShortCaseStmt: LeafListStmt;`
     ///
@@ -12119,7 +13201,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 221:
+    /// Semantic action for production 247:
     ///
     /// `ShortCaseStmt: ListStmt;`
     ///
@@ -12139,7 +13221,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 222:
+    /// Semantic action for production 248:
     ///
     /// `ShortCaseStmt: AnydataStmt;`
     ///
@@ -12159,7 +13241,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 223:
+    /// Semantic action for production 249:
     ///
     /// `ShortCaseStmt: AnyxmlStmt;`
     ///
@@ -12179,7 +13261,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 224:
+    /// Semantic action for production 250:
     ///
     /// `AnydataStmt: KwAnydata^ /* Clipped */ IdentifierArgStr AnydataStmtSuffix;`
     ///
@@ -12205,7 +13287,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 225:
+    /// Semantic action for production 251:
     ///
     /// `AnydataStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -12223,7 +13305,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 226:
+    /// Semantic action for production 252:
     ///
     /// `AnydataStmtSuffix: LBrace^ /* Clipped */ AnydataStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -12250,7 +13332,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 227:
+    /// Semantic action for production 253:
     ///
     /// `AnydataStmtList /* Vec<T>::Push */: AnydataStmtListGroup AnydataStmtList;`
     ///
@@ -12274,7 +13356,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 228:
+    /// Semantic action for production 254:
     ///
     /// `AnydataStmtListGroup: WhenStmt;`
     ///
@@ -12295,7 +13377,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 229:
+    /// Semantic action for production 255:
     ///
     /// `AnydataStmtListGroup: IfFeatureStmt;`
     ///
@@ -12316,7 +13398,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 230:
+    /// Semantic action for production 256:
     ///
     /// `AnydataStmtListGroup: MustStmt;`
     ///
@@ -12337,7 +13419,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 231:
+    /// Semantic action for production 257:
     ///
     /// `AnydataStmtListGroup: ConfigStmt;`
     ///
@@ -12358,7 +13440,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 232:
+    /// Semantic action for production 258:
     ///
     /// `AnydataStmtListGroup: MandatoryStmt;`
     ///
@@ -12379,7 +13461,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 233:
+    /// Semantic action for production 259:
     ///
     /// `AnydataStmtListGroup: StatusStmt;`
     ///
@@ -12400,7 +13482,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 234:
+    /// Semantic action for production 260:
     ///
     /// `AnydataStmtListGroup: DescriptionStmt;`
     ///
@@ -12421,7 +13503,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 235:
+    /// Semantic action for production 261:
     ///
     /// `AnydataStmtListGroup: ReferenceStmt;`
     ///
@@ -12442,7 +13524,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 236:
+    /// Semantic action for production 262:
     ///
     /// `AnydataStmtList /* Vec<T>::New */: ;`
     ///
@@ -12455,7 +13537,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 237:
+    /// Semantic action for production 263:
     ///
     /// `CaseStmt: KwCase^ /* Clipped */ IdentifierArgStr CaseStmtSuffix;`
     ///
@@ -12481,7 +13563,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 238:
+    /// Semantic action for production 264:
     ///
     /// `CaseStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -12496,7 +13578,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 239:
+    /// Semantic action for production 265:
     ///
     /// `CaseStmtSuffix: LBrace^ /* Clipped */ CaseStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -12518,7 +13600,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 240:
+    /// Semantic action for production 266:
     ///
     /// `CaseStmtList /* Vec<T>::Push */: CaseStmtListGroup CaseStmtList;`
     ///
@@ -12542,7 +13624,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 241:
+    /// Semantic action for production 267:
     ///
     /// `CaseStmtListGroup: WhenStmt;`
     ///
@@ -12563,7 +13645,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 242:
+    /// Semantic action for production 268:
     ///
     /// `CaseStmtListGroup: IfFeatureStmt;`
     ///
@@ -12584,70 +13666,91 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 243:
+    /// Semantic action for production 269:
+    ///
+    /// `CaseStmtListGroup: StatusStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn case_stmt_list_group_2(&mut self, _status_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let status_stmt = pop_item!(self, status_stmt, StatusStmt, context);
+        let case_stmt_list_group_2_built = CaseStmtListGroupStatusStmt {
+            status_stmt: Box::new(status_stmt),
+        };
+        let case_stmt_list_group_2_built =
+            CaseStmtListGroup::StatusStmt(case_stmt_list_group_2_built);
+        self.push(
+            ASTType::CaseStmtListGroup(case_stmt_list_group_2_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 270:
     ///
     /// `CaseStmtListGroup: DataDefStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn case_stmt_list_group_2(&mut self, _data_def_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn case_stmt_list_group_3(&mut self, _data_def_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let data_def_stmt = pop_item!(self, data_def_stmt, DataDefStmt, context);
-        let case_stmt_list_group_2_built = CaseStmtListGroupDataDefStmt {
+        let case_stmt_list_group_3_built = CaseStmtListGroupDataDefStmt {
             data_def_stmt: Box::new(data_def_stmt),
         };
-        let case_stmt_list_group_2_built =
-            CaseStmtListGroup::DataDefStmt(case_stmt_list_group_2_built);
+        let case_stmt_list_group_3_built =
+            CaseStmtListGroup::DataDefStmt(case_stmt_list_group_3_built);
         self.push(
-            ASTType::CaseStmtListGroup(case_stmt_list_group_2_built),
+            ASTType::CaseStmtListGroup(case_stmt_list_group_3_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 244:
+    /// Semantic action for production 271:
     ///
     /// `CaseStmtListGroup: DescriptionStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn case_stmt_list_group_3(&mut self, _description_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn case_stmt_list_group_4(&mut self, _description_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let description_stmt = pop_item!(self, description_stmt, DescriptionStmt, context);
-        let case_stmt_list_group_3_built = CaseStmtListGroupDescriptionStmt {
+        let case_stmt_list_group_4_built = CaseStmtListGroupDescriptionStmt {
             description_stmt: Box::new(description_stmt),
         };
-        let case_stmt_list_group_3_built =
-            CaseStmtListGroup::DescriptionStmt(case_stmt_list_group_3_built);
+        let case_stmt_list_group_4_built =
+            CaseStmtListGroup::DescriptionStmt(case_stmt_list_group_4_built);
         self.push(
-            ASTType::CaseStmtListGroup(case_stmt_list_group_3_built),
+            ASTType::CaseStmtListGroup(case_stmt_list_group_4_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 245:
+    /// Semantic action for production 272:
     ///
     /// `CaseStmtListGroup: ReferenceStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn case_stmt_list_group_4(&mut self, _reference_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn case_stmt_list_group_5(&mut self, _reference_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let reference_stmt = pop_item!(self, reference_stmt, ReferenceStmt, context);
-        let case_stmt_list_group_4_built = CaseStmtListGroupReferenceStmt {
+        let case_stmt_list_group_5_built = CaseStmtListGroupReferenceStmt {
             reference_stmt: Box::new(reference_stmt),
         };
-        let case_stmt_list_group_4_built =
-            CaseStmtListGroup::ReferenceStmt(case_stmt_list_group_4_built);
+        let case_stmt_list_group_5_built =
+            CaseStmtListGroup::ReferenceStmt(case_stmt_list_group_5_built);
         self.push(
-            ASTType::CaseStmtListGroup(case_stmt_list_group_4_built),
+            ASTType::CaseStmtListGroup(case_stmt_list_group_5_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 246:
+    /// Semantic action for production 273:
     ///
     /// `CaseStmtList /* Vec<T>::New */: ;`
     ///
@@ -12660,7 +13763,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 247:
+    /// Semantic action for production 274:
     ///
     /// `StatusStmt: KwStatus^ /* Clipped */ StatusArgStr Semicolon^ /* Clipped */;`
     ///
@@ -12685,7 +13788,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 248:
+    /// Semantic action for production 275:
     ///
     /// `StatusArgStr: StatusArg;`
     ///
@@ -12704,7 +13807,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 249:
+    /// Semantic action for production 276:
     ///
     /// `StatusArgStr: <Status>'"'^ /* Clipped */ StatusArg <Status>'"'^ /* Clipped */;`
     ///
@@ -12729,7 +13832,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 250:
+    /// Semantic action for production 277:
     ///
     /// `StatusArg: <Status>/current|obsolete|deprecated/;`
     ///
@@ -12745,7 +13848,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 251:
+    /// Semantic action for production 278:
     ///
     /// `ContainerStmt: KwContainer^ /* Clipped */ IdentifierArgStr ContainerStmtSuffix;`
     ///
@@ -12772,7 +13875,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 252:
+    /// Semantic action for production 279:
     ///
     /// `ContainerStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -12791,7 +13894,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 253:
+    /// Semantic action for production 280:
     ///
     /// `ContainerStmtSuffix: LBrace^ /* Clipped */ ContainerStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -12819,7 +13922,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 254:
+    /// Semantic action for production 281:
     ///
     /// `ContainerStmtList /* Vec<T>::Push */: ContainerStmtListGroup ContainerStmtList;`
     ///
@@ -12848,7 +13951,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 255:
+    /// Semantic action for production 282:
     ///
     /// `ContainerStmtListGroup: WhenStmt;`
     ///
@@ -12869,7 +13972,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 256:
+    /// Semantic action for production 283:
     ///
     /// `ContainerStmtListGroup: IfFeatureStmt;`
     ///
@@ -12890,7 +13993,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 257:
+    /// Semantic action for production 284:
     ///
     /// `ContainerStmtListGroup: MustStmt;`
     ///
@@ -12911,7 +14014,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 258:
+    /// Semantic action for production 285:
     ///
     /// `ContainerStmtListGroup: PresenceStmt;`
     ///
@@ -12932,7 +14035,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 259:
+    /// Semantic action for production 286:
     ///
     /// `ContainerStmtListGroup: ConfigStmt;`
     ///
@@ -12953,7 +14056,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 260:
+    /// Semantic action for production 287:
     ///
     /// `ContainerStmtListGroup: StatusStmt;`
     ///
@@ -12974,7 +14077,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 261:
+    /// Semantic action for production 288:
     ///
     /// `ContainerStmtListGroup: DescriptionStmt;`
     ///
@@ -12995,7 +14098,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 262:
+    /// Semantic action for production 289:
     ///
     /// `ContainerStmtListGroup: ReferenceStmt;`
     ///
@@ -13016,94 +14119,136 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 263:
+    /// Semantic action for production 290:
+    ///
+    /// `ContainerStmtListGroup: TypedefStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn container_stmt_list_group_8(&mut self, _typedef_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let typedef_stmt = pop_item!(self, typedef_stmt, TypedefStmt, context);
+        let container_stmt_list_group_8_built = ContainerStmtListGroupTypedefStmt {
+            typedef_stmt: Box::new(typedef_stmt),
+        };
+        let container_stmt_list_group_8_built =
+            ContainerStmtListGroup::TypedefStmt(container_stmt_list_group_8_built);
+        self.push(
+            ASTType::ContainerStmtListGroup(container_stmt_list_group_8_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 291:
+    ///
+    /// `ContainerStmtListGroup: GroupingStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn container_stmt_list_group_9(&mut self, _grouping_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let grouping_stmt = pop_item!(self, grouping_stmt, GroupingStmt, context);
+        let container_stmt_list_group_9_built = ContainerStmtListGroupGroupingStmt {
+            grouping_stmt: Box::new(grouping_stmt),
+        };
+        let container_stmt_list_group_9_built =
+            ContainerStmtListGroup::GroupingStmt(container_stmt_list_group_9_built);
+        self.push(
+            ASTType::ContainerStmtListGroup(container_stmt_list_group_9_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 292:
     ///
     /// `ContainerStmtListGroup: DataDefStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn container_stmt_list_group_8(&mut self, _data_def_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn container_stmt_list_group_10(&mut self, _data_def_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let data_def_stmt = pop_item!(self, data_def_stmt, DataDefStmt, context);
-        let container_stmt_list_group_8_built = ContainerStmtListGroupDataDefStmt {
+        let container_stmt_list_group_10_built = ContainerStmtListGroupDataDefStmt {
             data_def_stmt: Box::new(data_def_stmt),
         };
-        let container_stmt_list_group_8_built =
-            ContainerStmtListGroup::DataDefStmt(container_stmt_list_group_8_built);
+        let container_stmt_list_group_10_built =
+            ContainerStmtListGroup::DataDefStmt(container_stmt_list_group_10_built);
         self.push(
-            ASTType::ContainerStmtListGroup(container_stmt_list_group_8_built),
+            ASTType::ContainerStmtListGroup(container_stmt_list_group_10_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 264:
+    /// Semantic action for production 293:
     ///
     /// `ContainerStmtListGroup: ActionStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn container_stmt_list_group_9(&mut self, _action_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn container_stmt_list_group_11(&mut self, _action_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let action_stmt = pop_item!(self, action_stmt, ActionStmt, context);
-        let container_stmt_list_group_9_built = ContainerStmtListGroupActionStmt {
+        let container_stmt_list_group_11_built = ContainerStmtListGroupActionStmt {
             action_stmt: Box::new(action_stmt),
         };
-        let container_stmt_list_group_9_built =
-            ContainerStmtListGroup::ActionStmt(container_stmt_list_group_9_built);
+        let container_stmt_list_group_11_built =
+            ContainerStmtListGroup::ActionStmt(container_stmt_list_group_11_built);
         self.push(
-            ASTType::ContainerStmtListGroup(container_stmt_list_group_9_built),
+            ASTType::ContainerStmtListGroup(container_stmt_list_group_11_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 265:
+    /// Semantic action for production 294:
     ///
     /// `ContainerStmtListGroup: NotificationStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn container_stmt_list_group_10(
+    fn container_stmt_list_group_12(
         &mut self,
         _notification_stmt: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let notification_stmt = pop_item!(self, notification_stmt, NotificationStmt, context);
-        let container_stmt_list_group_10_built = ContainerStmtListGroupNotificationStmt {
+        let container_stmt_list_group_12_built = ContainerStmtListGroupNotificationStmt {
             notification_stmt: Box::new(notification_stmt),
         };
-        let container_stmt_list_group_10_built =
-            ContainerStmtListGroup::NotificationStmt(container_stmt_list_group_10_built);
+        let container_stmt_list_group_12_built =
+            ContainerStmtListGroup::NotificationStmt(container_stmt_list_group_12_built);
         self.push(
-            ASTType::ContainerStmtListGroup(container_stmt_list_group_10_built),
+            ASTType::ContainerStmtListGroup(container_stmt_list_group_12_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 266:
+    /// Semantic action for production 295:
     ///
     /// `ContainerStmtListGroup: UnknownStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn container_stmt_list_group_11(&mut self, _unknown_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn container_stmt_list_group_13(&mut self, _unknown_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let unknown_stmt = pop_item!(self, unknown_stmt, UnknownStmt, context);
-        let container_stmt_list_group_11_built = ContainerStmtListGroupUnknownStmt {
+        let container_stmt_list_group_13_built = ContainerStmtListGroupUnknownStmt {
             unknown_stmt: Box::new(unknown_stmt),
         };
-        let container_stmt_list_group_11_built =
-            ContainerStmtListGroup::UnknownStmt(container_stmt_list_group_11_built);
+        let container_stmt_list_group_13_built =
+            ContainerStmtListGroup::UnknownStmt(container_stmt_list_group_13_built);
         self.push(
-            ASTType::ContainerStmtListGroup(container_stmt_list_group_11_built),
+            ASTType::ContainerStmtListGroup(container_stmt_list_group_13_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 267:
+    /// Semantic action for production 296:
     ///
     /// `ContainerStmtList /* Vec<T>::New */: ;`
     ///
@@ -13119,7 +14264,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 268:
+    /// Semantic action for production 297:
     ///
     /// `ActionStmt: KwAction^ /* Clipped */ IdentifierArgStr ActionStmtSuffix;`
     ///
@@ -13145,7 +14290,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 269:
+    /// Semantic action for production 298:
     ///
     /// `ActionStmtSuffix: Semicolon;`
     ///
@@ -13165,7 +14310,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 270:
+    /// Semantic action for production 299:
     ///
     /// `ActionStmtSuffix: LBrace^ /* Clipped */ ActionStmtList /* Vec */ '}';`
     ///
@@ -13195,7 +14340,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 271:
+    /// Semantic action for production 300:
     ///
     /// `ActionStmtList /* Vec<T>::Push */: ActionStmtListGroup ActionStmtList;`
     ///
@@ -13219,7 +14364,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 272:
+    /// Semantic action for production 301:
     ///
     /// `ActionStmtListGroup: IfFeatureStmt;`
     ///
@@ -13240,7 +14385,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 273:
+    /// Semantic action for production 302:
     ///
     /// `ActionStmtListGroup: StatusStmt;`
     ///
@@ -13261,7 +14406,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 274:
+    /// Semantic action for production 303:
     ///
     /// `ActionStmtListGroup: DescriptionStmt;`
     ///
@@ -13282,7 +14427,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 275:
+    /// Semantic action for production 304:
     ///
     /// `ActionStmtListGroup: ReferenceStmt;`
     ///
@@ -13303,49 +14448,91 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 276:
+    /// Semantic action for production 305:
+    ///
+    /// `ActionStmtListGroup: TypedefStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn action_stmt_list_group_4(&mut self, _typedef_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let typedef_stmt = pop_item!(self, typedef_stmt, TypedefStmt, context);
+        let action_stmt_list_group_4_built = ActionStmtListGroupTypedefStmt {
+            typedef_stmt: Box::new(typedef_stmt),
+        };
+        let action_stmt_list_group_4_built =
+            ActionStmtListGroup::TypedefStmt(action_stmt_list_group_4_built);
+        self.push(
+            ASTType::ActionStmtListGroup(action_stmt_list_group_4_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 306:
+    ///
+    /// `ActionStmtListGroup: GroupingStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn action_stmt_list_group_5(&mut self, _grouping_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let grouping_stmt = pop_item!(self, grouping_stmt, GroupingStmt, context);
+        let action_stmt_list_group_5_built = ActionStmtListGroupGroupingStmt {
+            grouping_stmt: Box::new(grouping_stmt),
+        };
+        let action_stmt_list_group_5_built =
+            ActionStmtListGroup::GroupingStmt(action_stmt_list_group_5_built);
+        self.push(
+            ASTType::ActionStmtListGroup(action_stmt_list_group_5_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 307:
     ///
     /// `ActionStmtListGroup: InputStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn action_stmt_list_group_4(&mut self, _input_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn action_stmt_list_group_6(&mut self, _input_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let input_stmt = pop_item!(self, input_stmt, InputStmt, context);
-        let action_stmt_list_group_4_built = ActionStmtListGroupInputStmt {
+        let action_stmt_list_group_6_built = ActionStmtListGroupInputStmt {
             input_stmt: Box::new(input_stmt),
         };
-        let action_stmt_list_group_4_built =
-            ActionStmtListGroup::InputStmt(action_stmt_list_group_4_built);
+        let action_stmt_list_group_6_built =
+            ActionStmtListGroup::InputStmt(action_stmt_list_group_6_built);
         self.push(
-            ASTType::ActionStmtListGroup(action_stmt_list_group_4_built),
+            ASTType::ActionStmtListGroup(action_stmt_list_group_6_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 277:
+    /// Semantic action for production 308:
     ///
     /// `ActionStmtListGroup: OutputStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn action_stmt_list_group_5(&mut self, _output_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn action_stmt_list_group_7(&mut self, _output_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let output_stmt = pop_item!(self, output_stmt, OutputStmt, context);
-        let action_stmt_list_group_5_built = ActionStmtListGroupOutputStmt {
+        let action_stmt_list_group_7_built = ActionStmtListGroupOutputStmt {
             output_stmt: Box::new(output_stmt),
         };
-        let action_stmt_list_group_5_built =
-            ActionStmtListGroup::OutputStmt(action_stmt_list_group_5_built);
+        let action_stmt_list_group_7_built =
+            ActionStmtListGroup::OutputStmt(action_stmt_list_group_7_built);
         self.push(
-            ASTType::ActionStmtListGroup(action_stmt_list_group_5_built),
+            ASTType::ActionStmtListGroup(action_stmt_list_group_7_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 278:
+    /// Semantic action for production 309:
     ///
     /// `ActionStmtList /* Vec<T>::New */: ;`
     ///
@@ -13358,7 +14545,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 279:
+    /// Semantic action for production 310:
     ///
     /// `InputStmt: 'input' LBrace^ /* Clipped */ InputStmtList /* Vec */ '}';`
     ///
@@ -13387,22 +14574,23 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 280:
+    /// Semantic action for production 311:
     ///
-    /// `InputStmtList /* Vec<T>::Push */: DataDefStmt InputStmtList;`
+    /// `InputStmtList /* Vec<T>::Push */: InputStmtListGroup InputStmtList;`
     ///
     #[parol_runtime::function_name::named]
     fn input_stmt_list_0(
         &mut self,
-        _data_def_stmt: &ParseTreeType<'t>,
+        _input_stmt_list_group: &ParseTreeType<'t>,
         _input_stmt_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let mut input_stmt_list = pop_item!(self, input_stmt_list, InputStmtList, context);
-        let data_def_stmt = pop_item!(self, data_def_stmt, DataDefStmt, context);
+        let input_stmt_list_group =
+            pop_item!(self, input_stmt_list_group, InputStmtListGroup, context);
         let input_stmt_list_0_built = InputStmtList {
-            data_def_stmt: Box::new(data_def_stmt),
+            input_stmt_list_group: Box::new(input_stmt_list_group),
         };
         // Add an element to the vector
         input_stmt_list.push(input_stmt_list_0_built);
@@ -13410,7 +14598,70 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 281:
+    /// Semantic action for production 312:
+    ///
+    /// `InputStmtListGroup: TypedefStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn input_stmt_list_group_0(&mut self, _typedef_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let typedef_stmt = pop_item!(self, typedef_stmt, TypedefStmt, context);
+        let input_stmt_list_group_0_built = InputStmtListGroupTypedefStmt {
+            typedef_stmt: Box::new(typedef_stmt),
+        };
+        let input_stmt_list_group_0_built =
+            InputStmtListGroup::TypedefStmt(input_stmt_list_group_0_built);
+        self.push(
+            ASTType::InputStmtListGroup(input_stmt_list_group_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 313:
+    ///
+    /// `InputStmtListGroup: GroupingStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn input_stmt_list_group_1(&mut self, _grouping_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let grouping_stmt = pop_item!(self, grouping_stmt, GroupingStmt, context);
+        let input_stmt_list_group_1_built = InputStmtListGroupGroupingStmt {
+            grouping_stmt: Box::new(grouping_stmt),
+        };
+        let input_stmt_list_group_1_built =
+            InputStmtListGroup::GroupingStmt(input_stmt_list_group_1_built);
+        self.push(
+            ASTType::InputStmtListGroup(input_stmt_list_group_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 314:
+    ///
+    /// `InputStmtListGroup: DataDefStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn input_stmt_list_group_2(&mut self, _data_def_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let data_def_stmt = pop_item!(self, data_def_stmt, DataDefStmt, context);
+        let input_stmt_list_group_2_built = InputStmtListGroupDataDefStmt {
+            data_def_stmt: Box::new(data_def_stmt),
+        };
+        let input_stmt_list_group_2_built =
+            InputStmtListGroup::DataDefStmt(input_stmt_list_group_2_built);
+        self.push(
+            ASTType::InputStmtListGroup(input_stmt_list_group_2_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 315:
     ///
     /// `InputStmtList /* Vec<T>::New */: ;`
     ///
@@ -13423,7 +14674,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 282:
+    /// Semantic action for production 316:
     ///
     /// `OutputStmt: 'output' LBrace^ /* Clipped */ OutputStmtList /* Vec */ '}';`
     ///
@@ -13453,22 +14704,23 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 283:
+    /// Semantic action for production 317:
     ///
-    /// `OutputStmtList /* Vec<T>::Push */: DataDefStmt OutputStmtList;`
+    /// `OutputStmtList /* Vec<T>::Push */: OutputStmtListGroup OutputStmtList;`
     ///
     #[parol_runtime::function_name::named]
     fn output_stmt_list_0(
         &mut self,
-        _data_def_stmt: &ParseTreeType<'t>,
+        _output_stmt_list_group: &ParseTreeType<'t>,
         _output_stmt_list: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let mut output_stmt_list = pop_item!(self, output_stmt_list, OutputStmtList, context);
-        let data_def_stmt = pop_item!(self, data_def_stmt, DataDefStmt, context);
+        let output_stmt_list_group =
+            pop_item!(self, output_stmt_list_group, OutputStmtListGroup, context);
         let output_stmt_list_0_built = OutputStmtList {
-            data_def_stmt: Box::new(data_def_stmt),
+            output_stmt_list_group: Box::new(output_stmt_list_group),
         };
         // Add an element to the vector
         output_stmt_list.push(output_stmt_list_0_built);
@@ -13476,7 +14728,70 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 284:
+    /// Semantic action for production 318:
+    ///
+    /// `OutputStmtListGroup: TypedefStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn output_stmt_list_group_0(&mut self, _typedef_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let typedef_stmt = pop_item!(self, typedef_stmt, TypedefStmt, context);
+        let output_stmt_list_group_0_built = OutputStmtListGroupTypedefStmt {
+            typedef_stmt: Box::new(typedef_stmt),
+        };
+        let output_stmt_list_group_0_built =
+            OutputStmtListGroup::TypedefStmt(output_stmt_list_group_0_built);
+        self.push(
+            ASTType::OutputStmtListGroup(output_stmt_list_group_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 319:
+    ///
+    /// `OutputStmtListGroup: GroupingStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn output_stmt_list_group_1(&mut self, _grouping_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let grouping_stmt = pop_item!(self, grouping_stmt, GroupingStmt, context);
+        let output_stmt_list_group_1_built = OutputStmtListGroupGroupingStmt {
+            grouping_stmt: Box::new(grouping_stmt),
+        };
+        let output_stmt_list_group_1_built =
+            OutputStmtListGroup::GroupingStmt(output_stmt_list_group_1_built);
+        self.push(
+            ASTType::OutputStmtListGroup(output_stmt_list_group_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 320:
+    ///
+    /// `OutputStmtListGroup: DataDefStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn output_stmt_list_group_2(&mut self, _data_def_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let data_def_stmt = pop_item!(self, data_def_stmt, DataDefStmt, context);
+        let output_stmt_list_group_2_built = OutputStmtListGroupDataDefStmt {
+            data_def_stmt: Box::new(data_def_stmt),
+        };
+        let output_stmt_list_group_2_built =
+            OutputStmtListGroup::DataDefStmt(output_stmt_list_group_2_built);
+        self.push(
+            ASTType::OutputStmtListGroup(output_stmt_list_group_2_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 321:
     ///
     /// `OutputStmtList /* Vec<T>::New */: ;`
     ///
@@ -13489,7 +14804,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 285:
+    /// Semantic action for production 322:
     ///
     /// `NotificationStmt: KwNotification^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ NotificationStmtList /* Vec */ '}';`
     ///
@@ -13522,7 +14837,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 286:
+    /// Semantic action for production 323:
     ///
     /// `NotificationStmtList /* Vec<T>::Push */: NotificationStmtListGroup NotificationStmtList;`
     ///
@@ -13554,7 +14869,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 287:
+    /// Semantic action for production 324:
     ///
     /// `NotificationStmtListGroup: IfFeatureStmt;`
     ///
@@ -13578,7 +14893,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 288:
+    /// Semantic action for production 325:
     ///
     /// `NotificationStmtListGroup: MustStmt;`
     ///
@@ -13599,7 +14914,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 289:
+    /// Semantic action for production 326:
     ///
     /// `NotificationStmtListGroup: StatusStmt;`
     ///
@@ -13620,7 +14935,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 290:
+    /// Semantic action for production 327:
     ///
     /// `NotificationStmtListGroup: DescriptionStmt;`
     ///
@@ -13644,7 +14959,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 291:
+    /// Semantic action for production 328:
     ///
     /// `NotificationStmtListGroup: ReferenceStmt;`
     ///
@@ -13668,7 +14983,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 292:
+    /// Semantic action for production 329:
     ///
     /// `NotificationStmtListGroup: TypedefStmt;`
     ///
@@ -13689,7 +15004,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 293:
+    /// Semantic action for production 330:
     ///
     /// `NotificationStmtListGroup: GroupingStmt;`
     ///
@@ -13710,7 +15025,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 294:
+    /// Semantic action for production 331:
     ///
     /// `NotificationStmtListGroup: DataDefStmt;`
     ///
@@ -13731,7 +15046,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 295:
+    /// Semantic action for production 332:
     ///
     /// `NotificationStmtList /* Vec<T>::New */: ;`
     ///
@@ -13747,7 +15062,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 296:
+    /// Semantic action for production 333:
     ///
     /// `IfFeatureStmt: KwIfFeature^ /* Clipped */ IfFeatureExprStr Semicolon^ /* Clipped */;`
     ///
@@ -13772,7 +15087,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 297:
+    /// Semantic action for production 334:
     ///
     /// `IfFeatureExprStr: IfFeatureExpr;`
     ///
@@ -13794,7 +15109,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 298:
+    /// Semantic action for production 335:
     ///
     /// `IfFeatureExpr: IfFeatureTerm IfFeatureExprOpt /* Option */;`
     ///
@@ -13818,7 +15133,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 299:
+    /// Semantic action for production 336:
     ///
     /// `IfFeatureExprOpt /* Option<T>::Some */: <IfFeature>'or' IfFeatureExpr;`
     ///
@@ -13843,7 +15158,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 300:
+    /// Semantic action for production 337:
     ///
     /// `IfFeatureExprOpt /* Option<T>::None */: ;`
     ///
@@ -13855,7 +15170,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 301:
+    /// Semantic action for production 338:
     ///
     /// `IfFeatureTerm: IfFeatureFactor IfFeatureTermOpt /* Option */;`
     ///
@@ -13879,7 +15194,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 302:
+    /// Semantic action for production 339:
     ///
     /// `IfFeatureTermOpt /* Option<T>::Some */: <IfFeature>'and' IfFeatureTerm;`
     ///
@@ -13904,7 +15219,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 303:
+    /// Semantic action for production 340:
     ///
     /// `IfFeatureTermOpt /* Option<T>::None */: ;`
     ///
@@ -13916,7 +15231,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 304:
+    /// Semantic action for production 341:
     ///
     /// `IfFeatureFactor: <IfFeature>'not' IfFeatureFactor;`
     ///
@@ -13943,7 +15258,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 305:
+    /// Semantic action for production 342:
     ///
     /// `IfFeatureFactor: <IfFeature>'(' IfFeatureExpr <IfFeature>')';`
     ///
@@ -13973,7 +15288,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 306:
+    /// Semantic action for production 343:
     ///
     /// `IfFeatureFactor: Identifier;`
     ///
@@ -13993,7 +15308,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 307:
+    /// Semantic action for production 344:
     ///
     /// `IfFeatureFactor: <IfFeature>'"' Identifier <IfFeature>'"';`
     ///
@@ -14023,7 +15338,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 308:
+    /// Semantic action for production 345:
     ///
     /// `PresenceStmt: 'presence'^ /* Clipped */ Ystring Semicolon^ /* Clipped */;`
     ///
@@ -14047,7 +15362,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 309:
+    /// Semantic action for production 346:
     ///
     /// `UsesStmt: KwUses^ /* Clipped */ IdentifierRefArgStr UsesStmtSuffix;`
     ///
@@ -14074,7 +15389,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 310:
+    /// Semantic action for production 347:
     ///
     /// `UsesStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -14089,7 +15404,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 311:
+    /// Semantic action for production 348:
     ///
     /// `UsesStmtSuffix: LBrace^ /* Clipped */ UsesStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -14111,7 +15426,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 312:
+    /// Semantic action for production 349:
     ///
     /// `UsesStmtList /* Vec<T>::Push */: UsesStmtListGroup UsesStmtList;`
     ///
@@ -14135,7 +15450,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 313:
+    /// Semantic action for production 350:
     ///
     /// `UsesStmtListGroup: WhenStmt;`
     ///
@@ -14156,7 +15471,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 314:
+    /// Semantic action for production 351:
     ///
     /// `UsesStmtListGroup: IfFeatureStmt;`
     ///
@@ -14177,7 +15492,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 315:
+    /// Semantic action for production 352:
     ///
     /// `UsesStmtListGroup: StatusStmt;`
     ///
@@ -14198,7 +15513,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 316:
+    /// Semantic action for production 353:
     ///
     /// `UsesStmtListGroup: DescriptionStmt;`
     ///
@@ -14219,7 +15534,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 317:
+    /// Semantic action for production 354:
     ///
     /// `UsesStmtListGroup: ReferenceStmt;`
     ///
@@ -14240,7 +15555,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 318:
+    /// Semantic action for production 355:
     ///
     /// `UsesStmtListGroup: RefineStmt;`
     ///
@@ -14261,7 +15576,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 319:
+    /// Semantic action for production 356:
     ///
     /// `UsesStmtListGroup: AugmentStmt;`
     ///
@@ -14282,7 +15597,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 320:
+    /// Semantic action for production 357:
     ///
     /// `UsesStmtList /* Vec<T>::New */: ;`
     ///
@@ -14295,7 +15610,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 321:
+    /// Semantic action for production 358:
     ///
     /// `RefineStmt: KwRefine^ /* Clipped */ RefineArgStr RefineStmtSuffix;`
     ///
@@ -14321,7 +15636,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 322:
+    /// Semantic action for production 359:
     ///
     /// `RefineStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -14339,7 +15654,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 323:
+    /// Semantic action for production 360:
     ///
     /// `RefineStmtSuffix: LBrace^ /* Clipped */ RefineStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -14366,7 +15681,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 324:
+    /// Semantic action for production 361:
     ///
     /// `RefineStmtList /* Vec<T>::Push */: RefineStmtListGroup RefineStmtList;`
     ///
@@ -14390,7 +15705,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 325:
+    /// Semantic action for production 362:
     ///
     /// `RefineStmtListGroup: IfFeatureStmt;`
     ///
@@ -14411,7 +15726,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 326:
+    /// Semantic action for production 363:
     ///
     /// `RefineStmtListGroup: MustStmt;`
     ///
@@ -14432,7 +15747,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 327:
+    /// Semantic action for production 364:
     ///
     /// `RefineStmtListGroup: PresenceStmt;`
     ///
@@ -14453,7 +15768,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 328:
+    /// Semantic action for production 365:
     ///
     /// `RefineStmtListGroup: DefaultStmt;`
     ///
@@ -14474,7 +15789,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 329:
+    /// Semantic action for production 366:
     ///
     /// `RefineStmtListGroup: ConfigStmt;`
     ///
@@ -14495,7 +15810,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 330:
+    /// Semantic action for production 367:
     ///
     /// `RefineStmtListGroup: MandatoryStmt;`
     ///
@@ -14516,7 +15831,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 331:
+    /// Semantic action for production 368:
     ///
     /// `RefineStmtListGroup: MinElementsStmt;`
     ///
@@ -14537,7 +15852,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 332:
+    /// Semantic action for production 369:
     ///
     /// `RefineStmtListGroup: MaxElementsStmt;`
     ///
@@ -14558,7 +15873,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 333:
+    /// Semantic action for production 370:
     ///
     /// `RefineStmtListGroup: DescriptionStmt;`
     ///
@@ -14579,7 +15894,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 334:
+    /// Semantic action for production 371:
     ///
     /// `RefineStmtListGroup: ReferenceStmt;`
     ///
@@ -14600,7 +15915,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 335:
+    /// Semantic action for production 372:
     ///
     /// `RefineStmtList /* Vec<T>::New */: ;`
     ///
@@ -14613,7 +15928,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 336:
+    /// Semantic action for production 373:
     ///
     /// `RefineArgStr: RefineArg;`
     ///
@@ -14632,7 +15947,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 337:
+    /// Semantic action for production 374:
     ///
     /// `RefineArgStr: <Keyword>'"' RefineArg <Keyword>'"';`
     ///
@@ -14661,7 +15976,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 338:
+    /// Semantic action for production 375:
     ///
     /// `RefineArg: DescendantSchemaNodeid;`
     ///
@@ -14684,7 +15999,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 339:
+    /// Semantic action for production 376:
     ///
     /// `DescendantSchemaNodeid: IdentifierRef;`
     ///
@@ -14708,7 +16023,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 340:
+    /// Semantic action for production 377:
     ///
     /// `DescendantSchemaNodeid: AbsoluteSchemaNodeid;`
     ///
@@ -14736,7 +16051,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 341:
+    /// Semantic action for production 378:
     ///
     /// `UnknownStmt: IdentifierRef Ystring UnknownStmtSuffix;`
     ///
@@ -14763,7 +16078,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 342:
+    /// Semantic action for production 379:
     ///
     /// `UnknownStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -14781,7 +16096,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 343:
+    /// Semantic action for production 380:
     ///
     /// `UnknownStmtSuffix: LBrace^ /* Clipped */ UnknownStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -14808,7 +16123,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 344:
+    /// Semantic action for production 381:
     ///
     /// `UnknownStmtList /* Vec<T>::Push */: UnknownStmtListGroup UnknownStmtList;`
     ///
@@ -14832,7 +16147,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 345:
+    /// Semantic action for production 382:
     ///
     /// `UnknownStmtListGroup: TypeStmt;`
     ///
@@ -14853,7 +16168,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 346:
+    /// Semantic action for production 383:
     ///
     /// `UnknownStmtListGroup: DescriptionStmt;`
     ///
@@ -14874,7 +16189,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 347:
+    /// Semantic action for production 384:
     ///
     /// `UnknownStmtList /* Vec<T>::New */: ;`
     ///
@@ -14887,7 +16202,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 348:
+    /// Semantic action for production 385:
     ///
     /// `LeafListStmt: KwLeafList^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ LeafListStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -14917,7 +16232,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 349:
+    /// Semantic action for production 386:
     ///
     /// `LeafListStmtList /* Vec<T>::Push */: LeafListStmtListGroup LeafListStmtList;`
     ///
@@ -14946,7 +16261,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 350:
+    /// Semantic action for production 387:
     ///
     /// `LeafListStmtListGroup: WhenStmt;`
     ///
@@ -14967,7 +16282,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 351:
+    /// Semantic action for production 388:
     ///
     /// `LeafListStmtListGroup: IfFeatureStmt;`
     ///
@@ -14988,7 +16303,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 352:
+    /// Semantic action for production 389:
     ///
     /// `LeafListStmtListGroup: TypeStmt;`
     ///
@@ -15009,7 +16324,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 353:
+    /// Semantic action for production 390:
     ///
     /// `LeafListStmtListGroup: UnitsStmt;`
     ///
@@ -15030,7 +16345,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 354:
+    /// Semantic action for production 391:
     ///
     /// `LeafListStmtListGroup: MustStmt;`
     ///
@@ -15051,7 +16366,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 355:
+    /// Semantic action for production 392:
     ///
     /// `LeafListStmtListGroup: DefaultStmt;`
     ///
@@ -15072,7 +16387,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 356:
+    /// Semantic action for production 393:
     ///
     /// `LeafListStmtListGroup: ConfigStmt;`
     ///
@@ -15093,7 +16408,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 357:
+    /// Semantic action for production 394:
     ///
     /// `LeafListStmtListGroup: MinElementsStmt;`
     ///
@@ -15117,7 +16432,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 358:
+    /// Semantic action for production 395:
     ///
     /// `LeafListStmtListGroup: MaxElementsStmt;`
     ///
@@ -15141,7 +16456,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 359:
+    /// Semantic action for production 396:
     ///
     /// `LeafListStmtListGroup: OrderedByStmt;`
     ///
@@ -15162,7 +16477,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 360:
+    /// Semantic action for production 397:
     ///
     /// `LeafListStmtListGroup: StatusStmt;`
     ///
@@ -15183,7 +16498,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 361:
+    /// Semantic action for production 398:
     ///
     /// `LeafListStmtListGroup: DescriptionStmt;`
     ///
@@ -15207,7 +16522,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 362:
+    /// Semantic action for production 399:
     ///
     /// `LeafListStmtListGroup: ReferenceStmt;`
     ///
@@ -15228,7 +16543,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 363:
+    /// Semantic action for production 400:
     ///
     /// `LeafListStmtListGroup: UnknownStmt;`
     ///
@@ -15249,7 +16564,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 364:
+    /// Semantic action for production 401:
     ///
     /// `LeafListStmtList /* Vec<T>::New */: ;`
     ///
@@ -15265,7 +16580,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 365:
+    /// Semantic action for production 402:
     ///
     /// `LeafStmt: KwLeaf^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ LeafStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -15294,7 +16609,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 366:
+    /// Semantic action for production 403:
     ///
     /// `LeafStmtList /* Vec<T>::Push */: LeafStmtListGroup LeafStmtList;`
     ///
@@ -15318,7 +16633,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 367:
+    /// Semantic action for production 404:
     ///
     /// `LeafStmtListGroup: WhenStmt;`
     ///
@@ -15339,7 +16654,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 368:
+    /// Semantic action for production 405:
     ///
     /// `LeafStmtListGroup: StatusStmt;`
     ///
@@ -15360,7 +16675,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 369:
+    /// Semantic action for production 406:
     ///
     /// `LeafStmtListGroup: IfFeatureStmt;`
     ///
@@ -15381,7 +16696,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 370:
+    /// Semantic action for production 407:
     ///
     /// `LeafStmtListGroup: TypeStmt;`
     ///
@@ -15402,7 +16717,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 371:
+    /// Semantic action for production 408:
     ///
     /// `LeafStmtListGroup: UnitsStmt;`
     ///
@@ -15423,7 +16738,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 372:
+    /// Semantic action for production 409:
     ///
     /// `LeafStmtListGroup: MustStmt;`
     ///
@@ -15444,7 +16759,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 373:
+    /// Semantic action for production 410:
     ///
     /// `LeafStmtListGroup: DefaultStmt;`
     ///
@@ -15465,7 +16780,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 374:
+    /// Semantic action for production 411:
     ///
     /// `LeafStmtListGroup: ConfigStmt;`
     ///
@@ -15486,7 +16801,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 375:
+    /// Semantic action for production 412:
     ///
     /// `LeafStmtListGroup: MandatoryStmt;`
     ///
@@ -15507,7 +16822,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 376:
+    /// Semantic action for production 413:
     ///
     /// `LeafStmtListGroup: DescriptionStmt;`
     ///
@@ -15528,7 +16843,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 377:
+    /// Semantic action for production 414:
     ///
     /// `LeafStmtListGroup: ReferenceStmt;`
     ///
@@ -15549,7 +16864,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 378:
+    /// Semantic action for production 415:
     ///
     /// `LeafStmtListGroup: UnknownStmt;`
     ///
@@ -15570,7 +16885,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 379:
+    /// Semantic action for production 416:
     ///
     /// `LeafStmtList /* Vec<T>::New */: ;`
     ///
@@ -15583,7 +16898,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 380:
+    /// Semantic action for production 417:
     ///
     /// `MustStmt: 'must'^ /* Clipped */ Ystring MustStmtSuffix;`
     ///
@@ -15608,7 +16923,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 381:
+    /// Semantic action for production 418:
     ///
     /// `MustStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -15623,7 +16938,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 382:
+    /// Semantic action for production 419:
     ///
     /// `MustStmtSuffix: LBrace^ /* Clipped */ MustStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -15645,7 +16960,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 383:
+    /// Semantic action for production 420:
     ///
     /// `MustStmtList /* Vec<T>::Push */: MustStmtListGroup MustStmtList;`
     ///
@@ -15669,7 +16984,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 384:
+    /// Semantic action for production 421:
     ///
     /// `MustStmtListGroup: ErrorMessage;`
     ///
@@ -15690,7 +17005,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 385:
+    /// Semantic action for production 422:
     ///
     /// `MustStmtListGroup: DescriptionStmt;`
     ///
@@ -15711,7 +17026,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 386:
+    /// Semantic action for production 423:
     ///
     /// `MustStmtList /* Vec<T>::New */: ;`
     ///
@@ -15724,7 +17039,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 387:
+    /// Semantic action for production 424:
     ///
     /// `ErrorMessage: 'error-message' Ystring Semicolon;`
     ///
@@ -15751,7 +17066,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 388:
+    /// Semantic action for production 425:
     ///
     /// `UnitsStmt: 'units' Ystring Semicolon;`
     ///
@@ -15778,7 +17093,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 389:
+    /// Semantic action for production 426:
     ///
     /// `ConfigStmt: KwConfig^ /* Clipped */ MandatoryArgStr Semicolon;`
     ///
@@ -15804,7 +17119,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 390:
+    /// Semantic action for production 427:
     ///
     /// `MandatoryStmt: KwMandatory^ /* Clipped */ MandatoryArgStr Semicolon;`
     ///
@@ -15830,7 +17145,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 391:
+    /// Semantic action for production 428:
     ///
     /// `MandatoryArgStr: MandatoryArg;`
     ///
@@ -15850,7 +17165,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 392:
+    /// Semantic action for production 429:
     ///
     /// `MandatoryArgStr: <Mandatory>'"' MandatoryArg <Mandatory>'"';`
     ///
@@ -15880,7 +17195,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 393:
+    /// Semantic action for production 430:
     ///
     /// `MandatoryArg: <Mandatory>/true|false/;`
     ///
@@ -15896,7 +17211,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 394:
+    /// Semantic action for production 431:
     ///
     /// `ListStmt: KwList^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ ListStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -15925,7 +17240,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 395:
+    /// Semantic action for production 432:
     ///
     /// `ListStmtList /* Vec<T>::Push */: ListStmtListGroup ListStmtList;`
     ///
@@ -15949,7 +17264,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 396:
+    /// Semantic action for production 433:
     ///
     /// `ListStmtListGroup: WhenStmt;`
     ///
@@ -15970,7 +17285,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 397:
+    /// Semantic action for production 434:
     ///
     /// `ListStmtListGroup: IfFeatureStmt;`
     ///
@@ -15991,7 +17306,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 398:
+    /// Semantic action for production 435:
     ///
     /// `ListStmtListGroup: MustStmt;`
     ///
@@ -16012,7 +17327,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 399:
+    /// Semantic action for production 436:
     ///
     /// `ListStmtListGroup: KeyStmt;`
     ///
@@ -16032,7 +17347,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 400:
+    /// Semantic action for production 437:
     ///
     /// `ListStmtListGroup: ConfigStmt;`
     ///
@@ -16053,7 +17368,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 401:
+    /// Semantic action for production 438:
     ///
     /// `ListStmtListGroup: MinElementsStmt;`
     ///
@@ -16074,7 +17389,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 402:
+    /// Semantic action for production 439:
     ///
     /// `ListStmtListGroup: MaxElementsStmt;`
     ///
@@ -16095,7 +17410,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 403:
+    /// Semantic action for production 440:
     ///
     /// `ListStmtListGroup: OrderedByStmt;`
     ///
@@ -16116,7 +17431,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 404:
+    /// Semantic action for production 441:
     ///
     /// `ListStmtListGroup: StatusStmt;`
     ///
@@ -16137,7 +17452,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 405:
+    /// Semantic action for production 442:
     ///
     /// `ListStmtListGroup: DescriptionStmt;`
     ///
@@ -16158,7 +17473,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 406:
+    /// Semantic action for production 443:
     ///
     /// `ListStmtListGroup: ReferenceStmt;`
     ///
@@ -16179,91 +17494,133 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 407:
+    /// Semantic action for production 444:
+    ///
+    /// `ListStmtListGroup: TypedefStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn list_stmt_list_group_11(&mut self, _typedef_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let typedef_stmt = pop_item!(self, typedef_stmt, TypedefStmt, context);
+        let list_stmt_list_group_11_built = ListStmtListGroupTypedefStmt {
+            typedef_stmt: Box::new(typedef_stmt),
+        };
+        let list_stmt_list_group_11_built =
+            ListStmtListGroup::TypedefStmt(list_stmt_list_group_11_built);
+        self.push(
+            ASTType::ListStmtListGroup(list_stmt_list_group_11_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 445:
+    ///
+    /// `ListStmtListGroup: GroupingStmt;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn list_stmt_list_group_12(&mut self, _grouping_stmt: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let grouping_stmt = pop_item!(self, grouping_stmt, GroupingStmt, context);
+        let list_stmt_list_group_12_built = ListStmtListGroupGroupingStmt {
+            grouping_stmt: Box::new(grouping_stmt),
+        };
+        let list_stmt_list_group_12_built =
+            ListStmtListGroup::GroupingStmt(list_stmt_list_group_12_built);
+        self.push(
+            ASTType::ListStmtListGroup(list_stmt_list_group_12_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 446:
     ///
     /// `ListStmtListGroup: DataDefStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn list_stmt_list_group_11(&mut self, _data_def_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn list_stmt_list_group_13(&mut self, _data_def_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let data_def_stmt = pop_item!(self, data_def_stmt, DataDefStmt, context);
-        let list_stmt_list_group_11_built = ListStmtListGroupDataDefStmt {
+        let list_stmt_list_group_13_built = ListStmtListGroupDataDefStmt {
             data_def_stmt: Box::new(data_def_stmt),
         };
-        let list_stmt_list_group_11_built =
-            ListStmtListGroup::DataDefStmt(list_stmt_list_group_11_built);
+        let list_stmt_list_group_13_built =
+            ListStmtListGroup::DataDefStmt(list_stmt_list_group_13_built);
         self.push(
-            ASTType::ListStmtListGroup(list_stmt_list_group_11_built),
+            ASTType::ListStmtListGroup(list_stmt_list_group_13_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 408:
+    /// Semantic action for production 447:
     ///
     /// `ListStmtListGroup: ActionStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn list_stmt_list_group_12(&mut self, _action_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn list_stmt_list_group_14(&mut self, _action_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let action_stmt = pop_item!(self, action_stmt, ActionStmt, context);
-        let list_stmt_list_group_12_built = ListStmtListGroupActionStmt {
+        let list_stmt_list_group_14_built = ListStmtListGroupActionStmt {
             action_stmt: Box::new(action_stmt),
         };
-        let list_stmt_list_group_12_built =
-            ListStmtListGroup::ActionStmt(list_stmt_list_group_12_built);
+        let list_stmt_list_group_14_built =
+            ListStmtListGroup::ActionStmt(list_stmt_list_group_14_built);
         self.push(
-            ASTType::ListStmtListGroup(list_stmt_list_group_12_built),
+            ASTType::ListStmtListGroup(list_stmt_list_group_14_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 409:
+    /// Semantic action for production 448:
     ///
     /// `ListStmtListGroup: NotificationStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn list_stmt_list_group_13(&mut self, _notification_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn list_stmt_list_group_15(&mut self, _notification_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let notification_stmt = pop_item!(self, notification_stmt, NotificationStmt, context);
-        let list_stmt_list_group_13_built = ListStmtListGroupNotificationStmt {
+        let list_stmt_list_group_15_built = ListStmtListGroupNotificationStmt {
             notification_stmt: Box::new(notification_stmt),
         };
-        let list_stmt_list_group_13_built =
-            ListStmtListGroup::NotificationStmt(list_stmt_list_group_13_built);
+        let list_stmt_list_group_15_built =
+            ListStmtListGroup::NotificationStmt(list_stmt_list_group_15_built);
         self.push(
-            ASTType::ListStmtListGroup(list_stmt_list_group_13_built),
+            ASTType::ListStmtListGroup(list_stmt_list_group_15_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 410:
+    /// Semantic action for production 449:
     ///
     /// `ListStmtListGroup: UnknownStmt;`
     ///
     #[parol_runtime::function_name::named]
-    fn list_stmt_list_group_14(&mut self, _unknown_stmt: &ParseTreeType<'t>) -> Result<()> {
+    fn list_stmt_list_group_16(&mut self, _unknown_stmt: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let unknown_stmt = pop_item!(self, unknown_stmt, UnknownStmt, context);
-        let list_stmt_list_group_14_built = ListStmtListGroupUnknownStmt {
+        let list_stmt_list_group_16_built = ListStmtListGroupUnknownStmt {
             unknown_stmt: Box::new(unknown_stmt),
         };
-        let list_stmt_list_group_14_built =
-            ListStmtListGroup::UnknownStmt(list_stmt_list_group_14_built);
+        let list_stmt_list_group_16_built =
+            ListStmtListGroup::UnknownStmt(list_stmt_list_group_16_built);
         self.push(
-            ASTType::ListStmtListGroup(list_stmt_list_group_14_built),
+            ASTType::ListStmtListGroup(list_stmt_list_group_16_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 411:
+    /// Semantic action for production 450:
     ///
     /// `ListStmtList /* Vec<T>::New */: ;`
     ///
@@ -16276,7 +17633,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 412:
+    /// Semantic action for production 451:
     ///
     /// `OrderedByStmt: KwOrderedBy^ /* Clipped */ OrderedByArgStr Semicolon^ /* Clipped */;`
     ///
@@ -16301,7 +17658,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 413:
+    /// Semantic action for production 452:
     ///
     /// `OrderedByArgStr: OrderedByArg;`
     ///
@@ -16324,7 +17681,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 414:
+    /// Semantic action for production 453:
     ///
     /// `OrderedByArgStr: <Ordered>'"' OrderedByArg <Ordered>'"';`
     ///
@@ -16358,7 +17715,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 415:
+    /// Semantic action for production 454:
     ///
     /// `OrderedByArg: <Ordered>/user|system/;`
     ///
@@ -16374,7 +17731,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 416:
+    /// Semantic action for production 455:
     ///
     /// `DefaultStmt: KwDefault^ /* Clipped */ DefaultStmtSuffix;`
     ///
@@ -16397,7 +17754,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 417:
+    /// Semantic action for production 456:
     ///
     /// `DefaultStmtSuffix: AsciiNoSemicolon Semicolon^ /* Clipped */;`
     ///
@@ -16423,7 +17780,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 418:
+    /// Semantic action for production 457:
     ///
     /// `DefaultStmtSuffix: <Default>'"' AsciiNoSemicolon <Default>'"' Semicolon^ /* Clipped */;`
     ///
@@ -16458,7 +17815,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 419:
+    /// Semantic action for production 458:
     ///
     /// `MaxElementsStmt: 'max-elements'^ /* Clipped */ /[1-9][0-9]*/ Semicolon^ /* Clipped */;`
     ///
@@ -16486,7 +17843,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 420:
+    /// Semantic action for production 459:
     ///
     /// `MinElementsStmt: 'min-elements'^ /* Clipped */ /[1-9][0-9]*/ Semicolon^ /* Clipped */;`
     ///
@@ -16514,7 +17871,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 421:
+    /// Semantic action for production 460:
     ///
     /// `TypeStmt: KwType^ /* Clipped */ IdentifierRefArgStr TypeStmtSuffix;`
     ///
@@ -16541,7 +17898,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 422:
+    /// Semantic action for production 461:
     ///
     /// `TypeStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -16556,7 +17913,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 423:
+    /// Semantic action for production 462:
     ///
     /// `TypeStmtSuffix: LBrace^ /* Clipped */ TypeStmtList /* Vec */ <INITIAL, Keyword>'}'^ /* Clipped */;`
     ///
@@ -16578,7 +17935,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 424:
+    /// Semantic action for production 463:
     ///
     /// `TypeStmtList /* Vec<T>::Push */: TypeStmtListGroup TypeStmtList;`
     ///
@@ -16602,7 +17959,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 425:
+    /// Semantic action for production 464:
     ///
     /// `TypeStmtListGroup: FractionDigitsStmt;`
     ///
@@ -16624,7 +17981,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 426:
+    /// Semantic action for production 465:
     ///
     /// `TypeStmtListGroup: EnumStmt;`
     ///
@@ -16645,7 +18002,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 427:
+    /// Semantic action for production 466:
     ///
     /// `TypeStmtListGroup: BaseStmt;`
     ///
@@ -16666,7 +18023,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 428:
+    /// Semantic action for production 467:
     ///
     /// `TypeStmtListGroup: LeafrefSpecification;`
     ///
@@ -16688,7 +18045,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 429:
+    /// Semantic action for production 468:
     ///
     /// `TypeStmtListGroup: StringRestrictions;`
     ///
@@ -16709,7 +18066,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 430:
+    /// Semantic action for production 469:
     ///
     /// `TypeStmtListGroup: RangeStmt;`
     ///
@@ -16730,7 +18087,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 431:
+    /// Semantic action for production 470:
     ///
     /// `TypeStmtListGroup: BitStmt;`
     ///
@@ -16750,7 +18107,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 432:
+    /// Semantic action for production 471:
     ///
     /// `TypeStmtListGroup: TypeStmt;`
     ///
@@ -16771,7 +18128,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 433:
+    /// Semantic action for production 472:
     ///
     /// `TypeStmtList /* Vec<T>::New */: ;`
     ///
@@ -16784,7 +18141,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 434:
+    /// Semantic action for production 473:
     ///
     /// `FractionDigitsStmt: KwFractionDigits^ /* Clipped */ FractionDigitsArg Semicolon^ /* Clipped */;`
     ///
@@ -16813,7 +18170,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 435:
+    /// Semantic action for production 474:
     ///
     /// `FractionDigitsArg: <Fraction>/1|2|3|4|5|6|7|8|9|10|11|12|13|14|15|16|17|18|/;`
     ///
@@ -16835,7 +18192,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 436:
+    /// Semantic action for production 475:
     ///
     /// `BitStmt: KwBit^ /* Clipped */ IdentifierArgStr BitStmtSuffix;`
     ///
@@ -16861,7 +18218,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 437:
+    /// Semantic action for production 476:
     ///
     /// `BitStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -16876,7 +18233,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 438:
+    /// Semantic action for production 477:
     ///
     /// `BitStmtSuffix: LBrace^ /* Clipped */ BitStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -16898,7 +18255,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 439:
+    /// Semantic action for production 478:
     ///
     /// `BitStmtList /* Vec<T>::Push */: BitStmtListGroup BitStmtList;`
     ///
@@ -16921,7 +18278,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 440:
+    /// Semantic action for production 479:
     ///
     /// `BitStmtListGroup: PositionStmt;`
     ///
@@ -16942,7 +18299,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 441:
+    /// Semantic action for production 480:
     ///
     /// `BitStmtListGroup: DescriptionStmt;`
     ///
@@ -16963,7 +18320,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 442:
+    /// Semantic action for production 481:
     ///
     /// `BitStmtListGroup: ReferenceStmt;`
     ///
@@ -16984,7 +18341,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 443:
+    /// Semantic action for production 482:
     ///
     /// `BitStmtList /* Vec<T>::New */: ;`
     ///
@@ -16997,7 +18354,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 444:
+    /// Semantic action for production 483:
     ///
     /// `PositionStmt: KwPosition^ /* Clipped */ IntegerValueStr Semicolon^ /* Clipped */;`
     ///
@@ -17022,7 +18379,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 445:
+    /// Semantic action for production 484:
     ///
     /// `LeafrefSpecification: PathStmt;`
     ///
@@ -17046,7 +18403,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 446:
+    /// Semantic action for production 485:
     ///
     /// `LeafrefSpecification: RequireInstanceStmt;`
     ///
@@ -17074,7 +18431,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 447:
+    /// Semantic action for production 486:
     ///
     /// `RequireInstanceStmt: KwRequireInstance^ /* Clipped */ RequireInstanceArgStr Semicolon;`
     ///
@@ -17109,7 +18466,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 448:
+    /// Semantic action for production 487:
     ///
     /// `RequireInstanceArgStr: MandatoryArg;`
     ///
@@ -17133,7 +18490,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 449:
+    /// Semantic action for production 488:
     ///
     /// `RequireInstanceArgStr: <Mandatory>'"' MandatoryArg <Mandatory>'"';`
     ///
@@ -17169,7 +18526,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 450:
+    /// Semantic action for production 489:
     ///
     /// `PathStmt: 'path'^ /* Clipped */ Ystring Semicolon^ /* Clipped */;`
     ///
@@ -17193,7 +18550,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 451:
+    /// Semantic action for production 490:
     ///
     /// `StringRestrictions: LengthStmt;`
     ///
@@ -17217,7 +18574,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 452:
+    /// Semantic action for production 491:
     ///
     /// `StringRestrictions: PatternStmt;`
     ///
@@ -17241,7 +18598,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 453:
+    /// Semantic action for production 492:
     ///
     /// `PatternStmt: 'pattern' Ystring Semicolon;`
     ///
@@ -17268,7 +18625,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 454:
+    /// Semantic action for production 493:
     ///
     /// `LengthStmt: KwLength^ /* Clipped */ RangeArgStr Semicolon^ /* Clipped */;`
     ///
@@ -17293,7 +18650,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 455:
+    /// Semantic action for production 494:
     ///
     /// `EnumStmt: KwEnum^ /* Clipped */ EnumArgStr EnumStmtSuffix;`
     ///
@@ -17319,7 +18676,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 456:
+    /// Semantic action for production 495:
     ///
     /// `EnumStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -17334,7 +18691,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 457:
+    /// Semantic action for production 496:
     ///
     /// `EnumStmtSuffix: LBrace^ /* Clipped */ EnumStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -17356,7 +18713,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 458:
+    /// Semantic action for production 497:
     ///
     /// `EnumStmtList /* Vec<T>::Push */: EnumStmtListGroup EnumStmtList;`
     ///
@@ -17380,7 +18737,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 459:
+    /// Semantic action for production 498:
     ///
     /// `EnumStmtListGroup: IfFeatureStmt;`
     ///
@@ -17401,7 +18758,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 460:
+    /// Semantic action for production 499:
     ///
     /// `EnumStmtListGroup: DescriptionStmt;`
     ///
@@ -17422,7 +18779,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 461:
+    /// Semantic action for production 500:
     ///
     /// `EnumStmtListGroup: ValueStmt;`
     ///
@@ -17443,7 +18800,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 462:
+    /// Semantic action for production 501:
     ///
     /// `EnumStmtListGroup: ReferenceStmt;`
     ///
@@ -17464,7 +18821,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 463:
+    /// Semantic action for production 502:
     ///
     /// `EnumStmtList /* Vec<T>::New */: ;`
     ///
@@ -17477,7 +18834,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 464:
+    /// Semantic action for production 503:
     ///
     /// `ValueStmt: KwValue^ /* Clipped */ IntegerValueStr Semicolon^ /* Clipped */;`
     ///
@@ -17502,7 +18859,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 465:
+    /// Semantic action for production 504:
     ///
     /// `IntegerValueStr: IntegerValue;`
     ///
@@ -17522,7 +18879,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 466:
+    /// Semantic action for production 505:
     ///
     /// `IntegerValueStr: <Value>'"' IntegerValue <Value>'"';`
     ///
@@ -17552,7 +18909,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 467:
+    /// Semantic action for production 506:
     ///
     /// `IntegerValue: <Value>/[0-9]+/;`
     ///
@@ -17568,7 +18925,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 468:
+    /// Semantic action for production 507:
     ///
     /// `EnumArgStr: AsciiNoBrace;`
     ///
@@ -17587,7 +18944,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 469:
+    /// Semantic action for production 508:
     ///
     /// `EnumArgStr: <Enum>'"'^ /* Clipped */ AsciiNoBrace <Enum>'"'^ /* Clipped */;`
     ///
@@ -17612,7 +18969,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 470:
+    /// Semantic action for production 509:
     ///
     /// `RangeStmt: KwRange^ /* Clipped */ RangeArgStr Semicolon^ /* Clipped */;`
     ///
@@ -17637,7 +18994,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 471:
+    /// Semantic action for production 510:
     ///
     /// `RangeArgStr: RangeArg;`
     ///
@@ -17656,7 +19013,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 472:
+    /// Semantic action for production 511:
     ///
     /// `RangeArgStr: <Range>'"'^ /* Clipped */ RangeArg <Range>'"'^ /* Clipped */;`
     ///
@@ -17681,7 +19038,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 473:
+    /// Semantic action for production 512:
     ///
     /// `RangeArg: RangePart RangeArgOpt /* Option */;`
     ///
@@ -17705,7 +19062,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 474:
+    /// Semantic action for production 513:
     ///
     /// `RangeArgOpt /* Option<T>::Some */: <Range>'|' RangeArg;`
     ///
@@ -17727,7 +19084,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 475:
+    /// Semantic action for production 514:
     ///
     /// `RangeArgOpt /* Option<T>::None */: ;`
     ///
@@ -17739,7 +19096,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 476:
+    /// Semantic action for production 515:
     ///
     /// `RangePart: RangeBoundary RangePartOpt /* Option */;`
     ///
@@ -17763,7 +19120,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 477:
+    /// Semantic action for production 516:
     ///
     /// `RangePartOpt /* Option<T>::Some */: <Range>'..' RangeBoundary;`
     ///
@@ -17785,7 +19142,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 478:
+    /// Semantic action for production 517:
     ///
     /// `RangePartOpt /* Option<T>::None */: ;`
     ///
@@ -17797,7 +19154,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 479:
+    /// Semantic action for production 518:
     ///
     /// `RangeBoundary: <Range>'min';`
     ///
@@ -17814,7 +19171,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 480:
+    /// Semantic action for production 519:
     ///
     /// `RangeBoundary: <Range>'max';`
     ///
@@ -17831,12 +19188,41 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 481:
+    /// Semantic action for production 520:
     ///
-    /// `RangeBoundary: <Range>"-?[0-9]+";`
+    /// `RangeBoundary: <Range>"-?[0-9]+\.[0-9]+";`
     ///
     #[parol_runtime::function_name::named]
     fn range_boundary_2(
+        &mut self,
+        minus_quest_l_bracket0_minus9_r_bracket_plus_dot_l_bracket0_minus9_r_bracket_plus: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let minus_quest_l_bracket0_minus9_r_bracket_plus_dot_l_bracket0_minus9_r_bracket_plus =
+            minus_quest_l_bracket0_minus9_r_bracket_plus_dot_l_bracket0_minus9_r_bracket_plus
+                .token()?
+                .clone();
+        let range_boundary_2_built =
+            RangeBoundaryMinusQuestLBracket0Minus9RBracketPlusDotLBracket0Minus9RBracketPlus {
+                minus_quest_l_bracket0_minus9_r_bracket_plus_dot_l_bracket0_minus9_r_bracket_plus,
+            };
+        let range_boundary_2_built =
+            RangeBoundary::MinusQuestLBracket0Minus9RBracketPlusDotLBracket0Minus9RBracketPlus(
+                range_boundary_2_built,
+            );
+        // Calling user action here
+        self.user_grammar.range_boundary(&range_boundary_2_built)?;
+        self.push(ASTType::RangeBoundary(range_boundary_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 521:
+    ///
+    /// `RangeBoundary: <Range>"-?[0-9]+";`
+    ///
+    #[parol_runtime::function_name::named]
+    fn range_boundary_3(
         &mut self,
         minus_quest_l_bracket0_minus9_r_bracket_plus: &ParseTreeType<'t>,
     ) -> Result<()> {
@@ -17846,18 +19232,18 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
             minus_quest_l_bracket0_minus9_r_bracket_plus
                 .token()?
                 .clone();
-        let range_boundary_2_built = RangeBoundaryMinusQuestLBracket0Minus9RBracketPlus {
+        let range_boundary_3_built = RangeBoundaryMinusQuestLBracket0Minus9RBracketPlus {
             minus_quest_l_bracket0_minus9_r_bracket_plus,
         };
-        let range_boundary_2_built =
-            RangeBoundary::MinusQuestLBracket0Minus9RBracketPlus(range_boundary_2_built);
+        let range_boundary_3_built =
+            RangeBoundary::MinusQuestLBracket0Minus9RBracketPlus(range_boundary_3_built);
         // Calling user action here
-        self.user_grammar.range_boundary(&range_boundary_2_built)?;
-        self.push(ASTType::RangeBoundary(range_boundary_2_built), context);
+        self.user_grammar.range_boundary(&range_boundary_3_built)?;
+        self.push(ASTType::RangeBoundary(range_boundary_3_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 482:
+    /// Semantic action for production 522:
     ///
     /// `IdentifierRefArgStr: IdentifierRef;`
     ///
@@ -17881,7 +19267,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 483:
+    /// Semantic action for production 523:
     ///
     /// `IdentifierRefArgStr: <Keyword, IfFeature>'"' IdentifierRef <Keyword, IfFeature>'"';`
     ///
@@ -17917,7 +19303,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 484:
+    /// Semantic action for production 524:
     ///
     /// `IdentifierRef: IdentifierRefOpt /* Option */ Identifier;`
     ///
@@ -17941,7 +19327,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 485:
+    /// Semantic action for production 525:
     ///
     /// `IdentifierRefOpt /* Option<T>::Some */: Identifier <Keyword, IfFeature>':';`
     ///
@@ -17966,7 +19352,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 486:
+    /// Semantic action for production 526:
     ///
     /// `IdentifierRefOpt /* Option<T>::None */: ;`
     ///
@@ -17978,7 +19364,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 487:
+    /// Semantic action for production 527:
     ///
     /// `KeyStmt: KwKey^ /* Clipped */ KeyArgStr Semicolon^ /* Clipped */;`
     ///
@@ -18003,7 +19389,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 488:
+    /// Semantic action for production 528:
     ///
     /// `KeyArgStr: KeyArg;`
     ///
@@ -18022,7 +19408,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 489:
+    /// Semantic action for production 529:
     ///
     /// `KeyArgStr: <Keyword>'"'^ /* Clipped */ KeyArg <Keyword>'"'^ /* Clipped */;`
     ///
@@ -18047,9 +19433,9 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 490:
+    /// Semantic action for production 530:
     ///
-    /// `Semicolon: <INITIAL, Keyword, YVersion, Range, Enum, Default, Revision, Mandatory, IfFeature, Status, Value, Fraction, Ordered, Uri>';';`
+    /// `Semicolon: <INITIAL, Keyword, YVersion, Range, Enum, Default, Revision, Mandatory, IfFeature, Status, Deviate, Value, Fraction, Ordered, Uri>';';`
     ///
     #[parol_runtime::function_name::named]
     fn semicolon(&mut self, semicolon: &ParseTreeType<'t>) -> Result<()> {
@@ -18063,7 +19449,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 491:
+    /// Semantic action for production 531:
     ///
     /// `KeyArg: IdentifierRef KeyArgOpt /* Option */;`
     ///
@@ -18087,7 +19473,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 492:
+    /// Semantic action for production 532:
     ///
     /// `KeyArgOpt /* Option<T>::Some */: KeyArg;`
     ///
@@ -18103,7 +19489,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 493:
+    /// Semantic action for production 533:
     ///
     /// `KeyArgOpt /* Option<T>::None */: ;`
     ///
@@ -18115,7 +19501,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 494:
+    /// Semantic action for production 534:
     ///
     /// `AbsoluteSchemaNodeid: <Keyword, IfFeature>'/' IdentifierRef;`
     ///
@@ -18143,7 +19529,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 495:
+    /// Semantic action for production 535:
     ///
     /// `ImportStmt: KwImport^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ ImportStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -18173,7 +19559,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 496:
+    /// Semantic action for production 536:
     ///
     /// `ImportStmtList /* Vec<T>::Push */: ImportStmtListGroup ImportStmtList;`
     ///
@@ -18197,7 +19583,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 497:
+    /// Semantic action for production 537:
     ///
     /// `ImportStmtListGroup: PrefixStmt;`
     ///
@@ -18218,7 +19604,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 498:
+    /// Semantic action for production 538:
     ///
     /// `ImportStmtListGroup: RevisionDateStmt;`
     ///
@@ -18239,7 +19625,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 499:
+    /// Semantic action for production 539:
     ///
     /// `ImportStmtListGroup: DescriptionStmt;`
     ///
@@ -18260,7 +19646,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 500:
+    /// Semantic action for production 540:
     ///
     /// `ImportStmtListGroup: ReferenceStmt;`
     ///
@@ -18281,7 +19667,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 501:
+    /// Semantic action for production 541:
     ///
     /// `ImportStmtList /* Vec<T>::New */: ;`
     ///
@@ -18294,7 +19680,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 502:
+    /// Semantic action for production 542:
     ///
     /// `IncludeStmt: KwInclude^ /* Clipped */ IdentifierArgStr IncludeStmtSuffix;`
     ///
@@ -18320,7 +19706,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 503:
+    /// Semantic action for production 543:
     ///
     /// `IncludeStmtSuffix: Semicolon^ /* Clipped */;`
     ///
@@ -18338,7 +19724,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 504:
+    /// Semantic action for production 544:
     ///
     /// `IncludeStmtSuffix: LBrace^ /* Clipped */ IncludeStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -18365,7 +19751,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 505:
+    /// Semantic action for production 545:
     ///
     /// `IncludeStmtList /* Vec<T>::Push */: IncludeStmtListGroup IncludeStmtList;`
     ///
@@ -18389,7 +19775,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 506:
+    /// Semantic action for production 546:
     ///
     /// `IncludeStmtListGroup: RevisionDateStmt;`
     ///
@@ -18410,7 +19796,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 507:
+    /// Semantic action for production 547:
     ///
     /// `IncludeStmtListGroup: DescriptionStmt;`
     ///
@@ -18431,7 +19817,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 508:
+    /// Semantic action for production 548:
     ///
     /// `IncludeStmtListGroup: ReferenceStmt;`
     ///
@@ -18452,7 +19838,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 509:
+    /// Semantic action for production 549:
     ///
     /// `IncludeStmtList /* Vec<T>::New */: ;`
     ///
@@ -18465,7 +19851,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 510:
+    /// Semantic action for production 550:
     ///
     /// `RevisionDateStmt: KwRevisionDate^ /* Clipped */ DateArgStr Semicolon^ /* Clipped */;`
     ///
@@ -18491,7 +19877,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 511:
+    /// Semantic action for production 551:
     ///
     /// `NamespaceStmt: KwNamespace^ /* Clipped */ UriStr Semicolon^ /* Clipped */;`
     ///
@@ -18516,7 +19902,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 512:
+    /// Semantic action for production 552:
     ///
     /// `UriStr: UriArg;`
     ///
@@ -18535,7 +19921,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 513:
+    /// Semantic action for production 553:
     ///
     /// `UriStr: <Uri>'"'^ /* Clipped */ UriArg <Uri>'"'^ /* Clipped */;`
     ///
@@ -18559,7 +19945,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 514:
+    /// Semantic action for production 554:
     ///
     /// `UriArg: <Uri>"urn:[a-zA-Z0-9\-\.:]+";`
     ///
@@ -18575,7 +19961,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 515:
+    /// Semantic action for production 555:
     ///
     /// `PrefixStmt: KwPrefix^ /* Clipped */ IdentifierArgStr Semicolon^ /* Clipped */;`
     ///
@@ -18600,7 +19986,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 516:
+    /// Semantic action for production 556:
     ///
     /// `BelongsToStmt: KwBelongsTo^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ PrefixStmt '}'^ /* Clipped */;`
     ///
@@ -18629,7 +20015,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 517:
+    /// Semantic action for production 557:
     ///
     /// `OrganizationStmt: 'organization'^ /* Clipped */ Ystring Semicolon;`
     ///
@@ -18655,7 +20041,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 518:
+    /// Semantic action for production 558:
     ///
     /// `ContactStmt: 'contact'^ /* Clipped */ Ystring Semicolon;`
     ///
@@ -18680,7 +20066,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 519:
+    /// Semantic action for production 559:
     ///
     /// `DescriptionStmt: 'description'^ /* Clipped */ Ystring Semicolon^ /* Clipped */;`
     ///
@@ -18705,7 +20091,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 520:
+    /// Semantic action for production 560:
     ///
     /// `ReferenceStmt: 'reference'^ /* Clipped */ Ystring Semicolon^ /* Clipped */;`
     ///
@@ -18729,7 +20115,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 521:
+    /// Semantic action for production 561:
     ///
     /// `RevisionStmt: KwRevision^ /* Clipped */ DateArgStr LBrace^ /* Clipped */ RevisionStmtList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -18759,7 +20145,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 522:
+    /// Semantic action for production 562:
     ///
     /// `RevisionStmtList /* Vec<T>::Push */: RevisionStmtListGroup RevisionStmtList;`
     ///
@@ -18787,7 +20173,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 523:
+    /// Semantic action for production 563:
     ///
     /// `RevisionStmtListGroup: DescriptionStmt;`
     ///
@@ -18808,7 +20194,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 524:
+    /// Semantic action for production 564:
     ///
     /// `RevisionStmtListGroup: ReferenceStmt;`
     ///
@@ -18829,7 +20215,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 525:
+    /// Semantic action for production 565:
     ///
     /// `RevisionStmtList /* Vec<T>::New */: ;`
     ///
@@ -18845,7 +20231,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 526:
+    /// Semantic action for production 566:
     ///
     /// `DateArgStr: DateArg;`
     ///
@@ -18864,7 +20250,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 527:
+    /// Semantic action for production 567:
     ///
     /// `DateArgStr: <Revision>'"'^ /* Clipped */ DateArg <Revision>'"'^ /* Clipped */;`
     ///
@@ -18889,7 +20275,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 528:
+    /// Semantic action for production 568:
     ///
     /// `DateArg: <Revision>/\d{4}-\d{2}-\d{2}/;`
     ///
@@ -18905,7 +20291,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 529:
+    /// Semantic action for production 569:
     ///
     /// `Ystring: BasicString YstringOpt /* Option */;`
     ///
@@ -18930,7 +20316,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 530:
+    /// Semantic action for production 570:
     ///
     /// `Ystring: Identifier;`
     ///
@@ -18949,7 +20335,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 531:
+    /// Semantic action for production 571:
     ///
     /// `YstringOpt /* Option<T>::Some */: '+' Ystring;`
     ///
@@ -18971,7 +20357,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 532:
+    /// Semantic action for production 572:
     ///
     /// `YstringOpt /* Option<T>::None */: ;`
     ///
@@ -18983,7 +20369,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 533:
+    /// Semantic action for production 573:
     ///
     /// `BasicString: DQString;`
     ///
@@ -19002,7 +20388,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 534:
+    /// Semantic action for production 574:
     ///
     /// `BasicString: SQString;`
     ///
@@ -19021,7 +20407,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 535:
+    /// Semantic action for production 575:
     ///
     /// `DoubleQuotation: <INITIAL, DQString, Esc>'"';`
     ///
@@ -19038,7 +20424,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 536:
+    /// Semantic action for production 576:
     ///
     /// `DQString: DoubleQuotation^ /* Clipped */ DQStringList /* Vec */ DoubleQuotation^ /* Clipped */;`
     ///
@@ -19061,7 +20447,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 537:
+    /// Semantic action for production 577:
     ///
     /// `DQStringList /* Vec<T>::Push */: DQChar DQStringList;`
     ///
@@ -19084,7 +20470,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 538:
+    /// Semantic action for production 578:
     ///
     /// `DQStringList /* Vec<T>::New */: ;`
     ///
@@ -19097,7 +20483,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 539:
+    /// Semantic action for production 579:
     ///
     /// `DQChar: DQUnescaped;`
     ///
@@ -19116,7 +20502,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 540:
+    /// Semantic action for production 580:
     ///
     /// `DQChar: DQEscaped;`
     ///
@@ -19135,7 +20521,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 541:
+    /// Semantic action for production 581:
     ///
     /// `DQUnescaped: DQNoEscape;`
     ///
@@ -19154,7 +20540,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 542:
+    /// Semantic action for production 582:
     ///
     /// `DQUnescaped: NonAscii;`
     ///
@@ -19173,7 +20559,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 543:
+    /// Semantic action for production 583:
     ///
     /// `DQEscaped: Escape DQEscapeSeqChar;`
     ///
@@ -19197,7 +20583,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 544:
+    /// Semantic action for production 584:
     ///
     /// `DQEscapeSeqChar: Escape;`
     ///
@@ -19220,7 +20606,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 545:
+    /// Semantic action for production 585:
     ///
     /// `DQEscapeSeqChar: DoubleQuotation;`
     ///
@@ -19244,7 +20630,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 546:
+    /// Semantic action for production 586:
     ///
     /// `DQEscapeSeqChar: EscN;`
     ///
@@ -19267,7 +20653,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 547:
+    /// Semantic action for production 587:
     ///
     /// `DQEscapeSeqChar: EscT;`
     ///
@@ -19290,7 +20676,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 548:
+    /// Semantic action for production 588:
     ///
     /// `Escape: <DQString, SQString, Esc>"\u{5C}";`
     ///
@@ -19306,7 +20692,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 549:
+    /// Semantic action for production 589:
     ///
     /// `NonAscii: <DQString, SQString>"[\n\r\u{80}-\u{D7FF}\u{E000}-\u{10FFFF}]";`
     ///
@@ -19322,7 +20708,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 550:
+    /// Semantic action for production 590:
     ///
     /// `DQNoEscape: <DQString>"[ \n\r\t\u{21}\u{23}-\u{5B}\u{5D}-\u{7E}]+";`
     ///
@@ -19338,7 +20724,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 551:
+    /// Semantic action for production 591:
     ///
     /// `SQString: SingleQuotation SQStringList /* Vec */ SingleQuotation;`
     ///
@@ -19365,7 +20751,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 552:
+    /// Semantic action for production 592:
     ///
     /// `SQStringList /* Vec<T>::Push */: SQChar SQStringList;`
     ///
@@ -19388,7 +20774,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 553:
+    /// Semantic action for production 593:
     ///
     /// `SQStringList /* Vec<T>::New */: ;`
     ///
@@ -19401,7 +20787,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 554:
+    /// Semantic action for production 594:
     ///
     /// `SQChar: SQUnescaped;`
     ///
@@ -19420,7 +20806,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 555:
+    /// Semantic action for production 595:
     ///
     /// `SQChar: SQEscaped;`
     ///
@@ -19439,7 +20825,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 556:
+    /// Semantic action for production 596:
     ///
     /// `SQEscaped: Escape SQEscapeSeqChar;`
     ///
@@ -19463,7 +20849,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 557:
+    /// Semantic action for production 597:
     ///
     /// `SQEscapeSeqChar: <Esc>".";`
     ///
@@ -19482,7 +20868,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 558:
+    /// Semantic action for production 598:
     ///
     /// `SQUnescaped: SQNoEscape;`
     ///
@@ -19501,7 +20887,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 559:
+    /// Semantic action for production 599:
     ///
     /// `SQUnescaped: NonAscii;`
     ///
@@ -19520,7 +20906,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 560:
+    /// Semantic action for production 600:
     ///
     /// `SQNoEscape: <SQString>"[ \t\u{21}-\u{26}\u{28}-\u{5B}\u{5D}-\u{7E}]+";`
     ///
@@ -19536,7 +20922,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 561:
+    /// Semantic action for production 601:
     ///
     /// `SingleQuotation: <INITIAL, SQString>"\u{27}";`
     ///
@@ -19553,7 +20939,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 562:
+    /// Semantic action for production 602:
     ///
     /// `IdentifierArgStr: Identifier;`
     ///
@@ -19576,7 +20962,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 563:
+    /// Semantic action for production 603:
     ///
     /// `IdentifierArgStr: <Keyword>'"'^ /* Clipped */ Identifier <Keyword>'"'^ /* Clipped */;`
     ///
@@ -19605,7 +20991,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 564:
+    /// Semantic action for production 604:
     ///
     /// `Identifier: <INITIAL, Keyword, IfFeature>/[a-zA-Z_][a-zA-Z0-9_\-\/\.:]*/;`
     ///
@@ -19621,7 +21007,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 565:
+    /// Semantic action for production 605:
     ///
     /// `AsciiNoSemicolon: <Default>/[ \t\u{21}\u{23}-\u{3a}\u{3c}-\u{5b}\u{5d}-\u{7e}]+/;`
     ///
@@ -19638,7 +21024,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 566:
+    /// Semantic action for production 606:
     ///
     /// `AsciiNoBrace: <Enum>/[\t\u{21}\u{23}-\u{3a}\u{3c}-\u{5b}\u{5d}-\u{7a}\u{7c}\u{7e}]+/;`
     ///
@@ -19654,7 +21040,7 @@ impl<'t, 'u> YangGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 567:
+    /// Semantic action for production 607:
     ///
     /// `YangVersionArg: <YVersion>"1.1|1";`
     ///
@@ -19715,26 +21101,27 @@ impl<'t> UserActionsTrait<'t> for YangGrammarAuto<'t, '_> {
             30 => self.kw_refine(&children[0]),
             31 => self.kw_key(&children[0]),
             32 => self.kw_deviation(&children[0]),
-            33 => self.kw_yang_version(&children[0]),
-            34 => self.kw_status(&children[0]),
-            35 => self.kw_if_feature(&children[0]),
-            36 => self.kw_mandatory(&children[0]),
-            37 => self.kw_config(&children[0]),
-            38 => self.kw_require_instance(&children[0]),
-            39 => self.kw_ordered_by(&children[0]),
-            40 => self.kw_default(&children[0]),
-            41 => self.kw_fraction_digits(&children[0]),
-            42 => self.kw_length(&children[0]),
-            43 => self.kw_range(&children[0]),
-            44 => self.kw_value(&children[0]),
-            45 => self.kw_position(&children[0]),
-            46 => self.kw_enum(&children[0]),
-            47 => self.kw_revision(&children[0]),
-            48 => self.kw_revision_date(&children[0]),
-            49 => self.kw_namespace(&children[0]),
-            50 => self.yang_0(&children[0]),
-            51 => self.yang_1(&children[0]),
-            52 => self.module_stmt(
+            33 => self.kw_deviate(&children[0]),
+            34 => self.kw_yang_version(&children[0]),
+            35 => self.kw_status(&children[0]),
+            36 => self.kw_if_feature(&children[0]),
+            37 => self.kw_mandatory(&children[0]),
+            38 => self.kw_config(&children[0]),
+            39 => self.kw_require_instance(&children[0]),
+            40 => self.kw_ordered_by(&children[0]),
+            41 => self.kw_default(&children[0]),
+            42 => self.kw_fraction_digits(&children[0]),
+            43 => self.kw_length(&children[0]),
+            44 => self.kw_range(&children[0]),
+            45 => self.kw_value(&children[0]),
+            46 => self.kw_position(&children[0]),
+            47 => self.kw_enum(&children[0]),
+            48 => self.kw_revision(&children[0]),
+            49 => self.kw_revision_date(&children[0]),
+            50 => self.kw_namespace(&children[0]),
+            51 => self.yang_0(&children[0]),
+            52 => self.yang_1(&children[0]),
+            53 => self.module_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -19745,17 +21132,17 @@ impl<'t> UserActionsTrait<'t> for YangGrammarAuto<'t, '_> {
                 &children[7],
                 &children[8],
             ),
-            53 => self.module_stmt_list3_0(&children[0], &children[1]),
-            54 => self.module_stmt_list3_1(),
-            55 => self.module_stmt_list2_0(&children[0], &children[1]),
-            56 => self.module_stmt_list2_1(),
-            57 => self.module_stmt_list1_0(&children[0], &children[1]),
-            58 => self.module_stmt_list1_1(),
-            59 => self.module_stmt_list0_0(&children[0], &children[1]),
-            60 => self.module_stmt_list0_1(),
-            61 => self.module_stmt_list_0(&children[0], &children[1]),
-            62 => self.module_stmt_list_1(),
-            63 => self.submodule_stmt(
+            54 => self.module_stmt_list3_0(&children[0], &children[1]),
+            55 => self.module_stmt_list3_1(),
+            56 => self.module_stmt_list2_0(&children[0], &children[1]),
+            57 => self.module_stmt_list2_1(),
+            58 => self.module_stmt_list1_0(&children[0], &children[1]),
+            59 => self.module_stmt_list1_1(),
+            60 => self.module_stmt_list0_0(&children[0], &children[1]),
+            61 => self.module_stmt_list0_1(),
+            62 => self.module_stmt_list_0(&children[0], &children[1]),
+            63 => self.module_stmt_list_1(),
+            64 => self.submodule_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -19766,578 +21153,617 @@ impl<'t> UserActionsTrait<'t> for YangGrammarAuto<'t, '_> {
                 &children[7],
                 &children[8],
             ),
-            64 => self.submodule_stmt_list3_0(&children[0], &children[1]),
-            65 => self.submodule_stmt_list3_1(),
-            66 => self.submodule_stmt_list2_0(&children[0], &children[1]),
-            67 => self.submodule_stmt_list2_1(),
-            68 => self.submodule_stmt_list1_0(&children[0], &children[1]),
-            69 => self.submodule_stmt_list1_1(),
-            70 => self.submodule_stmt_list0_0(&children[0], &children[1]),
-            71 => self.submodule_stmt_list0_1(),
-            72 => self.submodule_stmt_list_0(&children[0], &children[1]),
-            73 => self.submodule_stmt_list_1(),
-            74 => self.module_header_stmts_0(&children[0]),
-            75 => self.module_header_stmts_1(&children[0]),
-            76 => self.module_header_stmts_2(&children[0]),
-            77 => self.submodule_header_stmts_0(&children[0]),
-            78 => self.submodule_header_stmts_1(&children[0]),
-            79 => self.meta_stmts_0(&children[0]),
-            80 => self.meta_stmts_1(&children[0]),
-            81 => self.meta_stmts_2(&children[0]),
-            82 => self.meta_stmts_3(&children[0]),
-            83 => self.linkage_stmts_0(&children[0]),
-            84 => self.linkage_stmts_1(&children[0]),
-            85 => self.body_stmts_0(&children[0]),
-            86 => self.body_stmts_1(&children[0]),
-            87 => self.body_stmts_2(&children[0]),
-            88 => self.body_stmts_3(&children[0]),
-            89 => self.body_stmts_4(&children[0]),
-            90 => self.body_stmts_5(&children[0]),
-            91 => self.body_stmts_6(&children[0]),
-            92 => self.body_stmts_7(&children[0]),
-            93 => self.body_stmts_8(&children[0]),
-            94 => self.body_stmts_9(&children[0]),
-            95 => self.body_stmts_10(&children[0]),
-            96 => self.data_def_stmt_0(&children[0]),
-            97 => self.data_def_stmt_1(&children[0]),
-            98 => self.data_def_stmt_2(&children[0]),
-            99 => self.data_def_stmt_3(&children[0]),
-            100 => self.data_def_stmt_4(&children[0]),
-            101 => self.data_def_stmt_5(&children[0]),
-            102 => self.data_def_stmt_6(&children[0]),
-            103 => self.data_def_stmt_7(&children[0]),
-            104 => self.yang_version_stmt(&children[0], &children[1], &children[2]),
-            105 => self.yang_version_arg_str_0(&children[0]),
-            106 => self.yang_version_arg_str_1(&children[0], &children[1], &children[2]),
-            107 => self.deviation_stmt(&children[0], &children[1], &children[2]),
-            108 => self.rpc_stmt(&children[0], &children[1], &children[2]),
-            109 => self.rpc_stmt_suffix_0(&children[0]),
-            110 => self.rpc_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            111 => self.rpc_stmt_list_0(&children[0], &children[1]),
-            112 => self.rpc_stmt_list_group_0(&children[0]),
-            113 => self.rpc_stmt_list_group_1(&children[0]),
-            114 => self.rpc_stmt_list_group_2(&children[0]),
-            115 => self.rpc_stmt_list_group_3(&children[0]),
-            116 => self.rpc_stmt_list_group_4(&children[0]),
-            117 => self.rpc_stmt_list_group_5(&children[0]),
-            118 => self.rpc_stmt_list_group_6(&children[0]),
-            119 => self.rpc_stmt_list_group_7(&children[0]),
-            120 => self.rpc_stmt_list_1(),
-            121 => self.extension_stmt(&children[0], &children[1], &children[2]),
-            122 => self.extension_stmt_suffix_0(&children[0]),
-            123 => self.extension_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            124 => self.extension_stmt_list_0(&children[0], &children[1]),
-            125 => self.extension_stmt_list_group_0(&children[0]),
-            126 => self.extension_stmt_list_group_1(&children[0]),
-            127 => self.extension_stmt_list_group_2(&children[0]),
-            128 => self.extension_stmt_list_group_3(&children[0]),
-            129 => self.extension_stmt_list_1(),
-            130 => self.argument_stmt(&children[0], &children[1], &children[2]),
-            131 => self.feature_stmt(&children[0], &children[1], &children[2]),
-            132 => self.feature_stmt_suffix_0(&children[0]),
-            133 => self.feature_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            134 => self.feature_stmt_list_0(&children[0], &children[1]),
-            135 => self.feature_stmt_list_group_0(&children[0]),
-            136 => self.feature_stmt_list_group_1(&children[0]),
-            137 => self.feature_stmt_list_group_2(&children[0]),
-            138 => self.feature_stmt_list_group_3(&children[0]),
-            139 => self.feature_stmt_list_1(),
-            140 => self.typedef_stmt(
+            65 => self.submodule_stmt_list3_0(&children[0], &children[1]),
+            66 => self.submodule_stmt_list3_1(),
+            67 => self.submodule_stmt_list2_0(&children[0], &children[1]),
+            68 => self.submodule_stmt_list2_1(),
+            69 => self.submodule_stmt_list1_0(&children[0], &children[1]),
+            70 => self.submodule_stmt_list1_1(),
+            71 => self.submodule_stmt_list0_0(&children[0], &children[1]),
+            72 => self.submodule_stmt_list0_1(),
+            73 => self.submodule_stmt_list_0(&children[0], &children[1]),
+            74 => self.submodule_stmt_list_1(),
+            75 => self.module_header_stmts_0(&children[0]),
+            76 => self.module_header_stmts_1(&children[0]),
+            77 => self.module_header_stmts_2(&children[0]),
+            78 => self.submodule_header_stmts_0(&children[0]),
+            79 => self.submodule_header_stmts_1(&children[0]),
+            80 => self.meta_stmts_0(&children[0]),
+            81 => self.meta_stmts_1(&children[0]),
+            82 => self.meta_stmts_2(&children[0]),
+            83 => self.meta_stmts_3(&children[0]),
+            84 => self.linkage_stmts_0(&children[0]),
+            85 => self.linkage_stmts_1(&children[0]),
+            86 => self.body_stmts_0(&children[0]),
+            87 => self.body_stmts_1(&children[0]),
+            88 => self.body_stmts_2(&children[0]),
+            89 => self.body_stmts_3(&children[0]),
+            90 => self.body_stmts_4(&children[0]),
+            91 => self.body_stmts_5(&children[0]),
+            92 => self.body_stmts_6(&children[0]),
+            93 => self.body_stmts_7(&children[0]),
+            94 => self.body_stmts_8(&children[0]),
+            95 => self.body_stmts_9(&children[0]),
+            96 => self.body_stmts_10(&children[0]),
+            97 => self.data_def_stmt_0(&children[0]),
+            98 => self.data_def_stmt_1(&children[0]),
+            99 => self.data_def_stmt_2(&children[0]),
+            100 => self.data_def_stmt_3(&children[0]),
+            101 => self.data_def_stmt_4(&children[0]),
+            102 => self.data_def_stmt_5(&children[0]),
+            103 => self.data_def_stmt_6(&children[0]),
+            104 => self.data_def_stmt_7(&children[0]),
+            105 => self.yang_version_stmt(&children[0], &children[1], &children[2]),
+            106 => self.yang_version_arg_str_0(&children[0]),
+            107 => self.yang_version_arg_str_1(&children[0], &children[1], &children[2]),
+            108 => self.deviation_stmt(&children[0], &children[1], &children[2]),
+            109 => self.deviation_stmt_suffix_0(&children[0]),
+            110 => self.deviation_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            111 => self.deviation_stmt_list_0(&children[0], &children[1]),
+            112 => self.deviation_stmt_list_group_0(&children[0]),
+            113 => self.deviation_stmt_list_group_1(&children[0]),
+            114 => self.deviation_stmt_list_group_2(&children[0]),
+            115 => self.deviation_stmt_list_1(),
+            116 => self.deviate_stmt(&children[0], &children[1], &children[2]),
+            117 => self.deviate_stmt_suffix_0(&children[0]),
+            118 => self.deviate_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            119 => self.deviate_stmt_list_0(&children[0], &children[1]),
+            120 => self.deviate_stmt_list_group_0(&children[0]),
+            121 => self.deviate_stmt_list_group_1(&children[0]),
+            122 => self.deviate_stmt_list_group_2(&children[0]),
+            123 => self.deviate_stmt_list_group_3(&children[0]),
+            124 => self.deviate_stmt_list_group_4(&children[0]),
+            125 => self.deviate_stmt_list_group_5(&children[0]),
+            126 => self.deviate_stmt_list_group_6(&children[0]),
+            127 => self.deviate_stmt_list_group_7(&children[0]),
+            128 => self.deviate_stmt_list_group_8(&children[0]),
+            129 => self.deviate_stmt_list_1(),
+            130 => self.deviate_arg_str_0(&children[0]),
+            131 => self.deviate_arg_str_1(&children[0], &children[1], &children[2]),
+            132 => self.deviate_arg(&children[0]),
+            133 => self.rpc_stmt(&children[0], &children[1], &children[2]),
+            134 => self.rpc_stmt_suffix_0(&children[0]),
+            135 => self.rpc_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            136 => self.rpc_stmt_list_0(&children[0], &children[1]),
+            137 => self.rpc_stmt_list_group_0(&children[0]),
+            138 => self.rpc_stmt_list_group_1(&children[0]),
+            139 => self.rpc_stmt_list_group_2(&children[0]),
+            140 => self.rpc_stmt_list_group_3(&children[0]),
+            141 => self.rpc_stmt_list_group_4(&children[0]),
+            142 => self.rpc_stmt_list_group_5(&children[0]),
+            143 => self.rpc_stmt_list_group_6(&children[0]),
+            144 => self.rpc_stmt_list_group_7(&children[0]),
+            145 => self.rpc_stmt_list_1(),
+            146 => self.extension_stmt(&children[0], &children[1], &children[2]),
+            147 => self.extension_stmt_suffix_0(&children[0]),
+            148 => self.extension_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            149 => self.extension_stmt_list_0(&children[0], &children[1]),
+            150 => self.extension_stmt_list_group_0(&children[0]),
+            151 => self.extension_stmt_list_group_1(&children[0]),
+            152 => self.extension_stmt_list_group_2(&children[0]),
+            153 => self.extension_stmt_list_group_3(&children[0]),
+            154 => self.extension_stmt_list_1(),
+            155 => self.argument_stmt(&children[0], &children[1], &children[2]),
+            156 => self.feature_stmt(&children[0], &children[1], &children[2]),
+            157 => self.feature_stmt_suffix_0(&children[0]),
+            158 => self.feature_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            159 => self.feature_stmt_list_0(&children[0], &children[1]),
+            160 => self.feature_stmt_list_group_0(&children[0]),
+            161 => self.feature_stmt_list_group_1(&children[0]),
+            162 => self.feature_stmt_list_group_2(&children[0]),
+            163 => self.feature_stmt_list_group_3(&children[0]),
+            164 => self.feature_stmt_list_1(),
+            165 => self.typedef_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            141 => self.typedef_stmt_list_0(&children[0], &children[1]),
-            142 => self.typedef_stmt_list_group_0(&children[0]),
-            143 => self.typedef_stmt_list_group_1(&children[0]),
-            144 => self.typedef_stmt_list_group_2(&children[0]),
-            145 => self.typedef_stmt_list_group_3(&children[0]),
-            146 => self.typedef_stmt_list_group_4(&children[0]),
-            147 => self.typedef_stmt_list_group_5(&children[0]),
-            148 => self.typedef_stmt_list_1(),
-            149 => self.augment_stmt(
+            166 => self.typedef_stmt_list_0(&children[0], &children[1]),
+            167 => self.typedef_stmt_list_group_0(&children[0]),
+            168 => self.typedef_stmt_list_group_1(&children[0]),
+            169 => self.typedef_stmt_list_group_2(&children[0]),
+            170 => self.typedef_stmt_list_group_3(&children[0]),
+            171 => self.typedef_stmt_list_group_4(&children[0]),
+            172 => self.typedef_stmt_list_group_5(&children[0]),
+            173 => self.typedef_stmt_list_1(),
+            174 => self.augment_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            150 => self.augment_stmt_list_0(&children[0], &children[1]),
-            151 => self.augment_stmt_list_group_0(&children[0]),
-            152 => self.augment_stmt_list_group_1(&children[0]),
-            153 => self.augment_stmt_list_group_2(&children[0]),
-            154 => self.augment_stmt_list_group_3(&children[0]),
-            155 => self.augment_stmt_list_group_4(&children[0]),
-            156 => self.augment_stmt_list_group_5(&children[0]),
-            157 => self.augment_stmt_list_group_6(&children[0]),
-            158 => self.augment_stmt_list_group_7(&children[0]),
-            159 => self.augment_stmt_list_group_8(&children[0]),
-            160 => self.augment_stmt_list_1(),
-            161 => self.augment_arg_str(&children[0]),
-            162 => self.when_stmt(&children[0], &children[1], &children[2]),
-            163 => self.when_stmt_suffix_0(&children[0]),
-            164 => self.when_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            165 => self.when_stmt_list_0(&children[0], &children[1]),
-            166 => self.when_stmt_list_group_0(&children[0]),
-            167 => self.when_stmt_list_group_1(&children[0]),
-            168 => self.when_stmt_list_1(),
-            169 => self.grouping_stmt(
+            175 => self.augment_stmt_list_0(&children[0], &children[1]),
+            176 => self.augment_stmt_list_group_0(&children[0]),
+            177 => self.augment_stmt_list_group_1(&children[0]),
+            178 => self.augment_stmt_list_group_2(&children[0]),
+            179 => self.augment_stmt_list_group_3(&children[0]),
+            180 => self.augment_stmt_list_group_4(&children[0]),
+            181 => self.augment_stmt_list_group_5(&children[0]),
+            182 => self.augment_stmt_list_group_6(&children[0]),
+            183 => self.augment_stmt_list_group_7(&children[0]),
+            184 => self.augment_stmt_list_group_8(&children[0]),
+            185 => self.augment_stmt_list_1(),
+            186 => self.augment_arg_str(&children[0]),
+            187 => self.when_stmt(&children[0], &children[1], &children[2]),
+            188 => self.when_stmt_suffix_0(&children[0]),
+            189 => self.when_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            190 => self.when_stmt_list_0(&children[0], &children[1]),
+            191 => self.when_stmt_list_group_0(&children[0]),
+            192 => self.when_stmt_list_group_1(&children[0]),
+            193 => self.when_stmt_list_1(),
+            194 => self.grouping_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            170 => self.grouping_stmt_list_0(&children[0], &children[1]),
-            171 => self.grouping_stmt_list_group_0(&children[0]),
-            172 => self.grouping_stmt_list_group_1(&children[0]),
-            173 => self.grouping_stmt_list_group_2(&children[0]),
-            174 => self.grouping_stmt_list_group_3(&children[0]),
-            175 => self.grouping_stmt_list_group_4(&children[0]),
-            176 => self.grouping_stmt_list_group_5(&children[0]),
-            177 => self.grouping_stmt_list_group_6(&children[0]),
-            178 => self.grouping_stmt_list_group_7(&children[0]),
-            179 => self.grouping_stmt_list_group_8(&children[0]),
-            180 => self.grouping_stmt_list_1(),
-            181 => self.identity_stmt(
+            195 => self.grouping_stmt_list_0(&children[0], &children[1]),
+            196 => self.grouping_stmt_list_group_0(&children[0]),
+            197 => self.grouping_stmt_list_group_1(&children[0]),
+            198 => self.grouping_stmt_list_group_2(&children[0]),
+            199 => self.grouping_stmt_list_group_3(&children[0]),
+            200 => self.grouping_stmt_list_group_4(&children[0]),
+            201 => self.grouping_stmt_list_group_5(&children[0]),
+            202 => self.grouping_stmt_list_group_6(&children[0]),
+            203 => self.grouping_stmt_list_group_7(&children[0]),
+            204 => self.grouping_stmt_list_group_8(&children[0]),
+            205 => self.grouping_stmt_list_1(),
+            206 => self.identity_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            182 => self.identity_stmt_list_0(&children[0], &children[1]),
-            183 => self.identity_stmt_list_group_0(&children[0]),
-            184 => self.identity_stmt_list_group_1(&children[0]),
-            185 => self.identity_stmt_list_group_2(&children[0]),
-            186 => self.identity_stmt_list_group_3(&children[0]),
-            187 => self.identity_stmt_list_1(),
-            188 => self.base_stmt(&children[0], &children[1], &children[2]),
-            189 => self.anyxml_stmt(&children[0], &children[1], &children[2]),
-            190 => self.anyxml_stmt_suffix_0(&children[0]),
-            191 => self.anyxml_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            192 => self.anyxml_stmt_list_0(&children[0], &children[1]),
-            193 => self.anyxml_stmt_list_group_0(&children[0]),
-            194 => self.anyxml_stmt_list_group_1(&children[0]),
-            195 => self.anyxml_stmt_list_group_2(&children[0]),
-            196 => self.anyxml_stmt_list_group_3(&children[0]),
-            197 => self.anyxml_stmt_list_group_4(&children[0]),
-            198 => self.anyxml_stmt_list_group_5(&children[0]),
-            199 => self.anyxml_stmt_list_group_6(&children[0]),
-            200 => self.anyxml_stmt_list_group_7(&children[0]),
-            201 => self.anyxml_stmt_list_1(),
-            202 => self.choice_stmt(&children[0], &children[1], &children[2]),
-            203 => self.choice_stmt_suffix_0(&children[0]),
-            204 => self.choice_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            205 => self.choice_stmt_list_0(&children[0], &children[1]),
-            206 => self.choice_stmt_list_group_0(&children[0]),
-            207 => self.choice_stmt_list_group_1(&children[0]),
-            208 => self.choice_stmt_list_group_2(&children[0]),
-            209 => self.choice_stmt_list_group_3(&children[0]),
-            210 => self.choice_stmt_list_group_4(&children[0]),
-            211 => self.choice_stmt_list_group_5(&children[0]),
-            212 => self.choice_stmt_list_group_6(&children[0]),
-            213 => self.choice_stmt_list_group_7(&children[0]),
-            214 => self.choice_stmt_list_group_8(&children[0]),
-            215 => self.choice_stmt_list_group_9(&children[0]),
-            216 => self.choice_stmt_list_1(),
-            217 => self.short_case_stmt_0(&children[0]),
-            218 => self.short_case_stmt_1(&children[0]),
-            219 => self.short_case_stmt_2(&children[0]),
-            220 => self.short_case_stmt_3(&children[0]),
-            221 => self.short_case_stmt_4(&children[0]),
-            222 => self.short_case_stmt_5(&children[0]),
-            223 => self.short_case_stmt_6(&children[0]),
-            224 => self.anydata_stmt(&children[0], &children[1], &children[2]),
-            225 => self.anydata_stmt_suffix_0(&children[0]),
-            226 => self.anydata_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            227 => self.anydata_stmt_list_0(&children[0], &children[1]),
-            228 => self.anydata_stmt_list_group_0(&children[0]),
-            229 => self.anydata_stmt_list_group_1(&children[0]),
-            230 => self.anydata_stmt_list_group_2(&children[0]),
-            231 => self.anydata_stmt_list_group_3(&children[0]),
-            232 => self.anydata_stmt_list_group_4(&children[0]),
-            233 => self.anydata_stmt_list_group_5(&children[0]),
-            234 => self.anydata_stmt_list_group_6(&children[0]),
-            235 => self.anydata_stmt_list_group_7(&children[0]),
-            236 => self.anydata_stmt_list_1(),
-            237 => self.case_stmt(&children[0], &children[1], &children[2]),
-            238 => self.case_stmt_suffix_0(&children[0]),
-            239 => self.case_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            240 => self.case_stmt_list_0(&children[0], &children[1]),
-            241 => self.case_stmt_list_group_0(&children[0]),
-            242 => self.case_stmt_list_group_1(&children[0]),
-            243 => self.case_stmt_list_group_2(&children[0]),
-            244 => self.case_stmt_list_group_3(&children[0]),
-            245 => self.case_stmt_list_group_4(&children[0]),
-            246 => self.case_stmt_list_1(),
-            247 => self.status_stmt(&children[0], &children[1], &children[2]),
-            248 => self.status_arg_str_0(&children[0]),
-            249 => self.status_arg_str_1(&children[0], &children[1], &children[2]),
-            250 => self.status_arg(&children[0]),
-            251 => self.container_stmt(&children[0], &children[1], &children[2]),
-            252 => self.container_stmt_suffix_0(&children[0]),
-            253 => self.container_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            254 => self.container_stmt_list_0(&children[0], &children[1]),
-            255 => self.container_stmt_list_group_0(&children[0]),
-            256 => self.container_stmt_list_group_1(&children[0]),
-            257 => self.container_stmt_list_group_2(&children[0]),
-            258 => self.container_stmt_list_group_3(&children[0]),
-            259 => self.container_stmt_list_group_4(&children[0]),
-            260 => self.container_stmt_list_group_5(&children[0]),
-            261 => self.container_stmt_list_group_6(&children[0]),
-            262 => self.container_stmt_list_group_7(&children[0]),
-            263 => self.container_stmt_list_group_8(&children[0]),
-            264 => self.container_stmt_list_group_9(&children[0]),
-            265 => self.container_stmt_list_group_10(&children[0]),
-            266 => self.container_stmt_list_group_11(&children[0]),
-            267 => self.container_stmt_list_1(),
-            268 => self.action_stmt(&children[0], &children[1], &children[2]),
-            269 => self.action_stmt_suffix_0(&children[0]),
-            270 => self.action_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            271 => self.action_stmt_list_0(&children[0], &children[1]),
-            272 => self.action_stmt_list_group_0(&children[0]),
-            273 => self.action_stmt_list_group_1(&children[0]),
-            274 => self.action_stmt_list_group_2(&children[0]),
-            275 => self.action_stmt_list_group_3(&children[0]),
-            276 => self.action_stmt_list_group_4(&children[0]),
-            277 => self.action_stmt_list_group_5(&children[0]),
-            278 => self.action_stmt_list_1(),
-            279 => self.input_stmt(&children[0], &children[1], &children[2], &children[3]),
-            280 => self.input_stmt_list_0(&children[0], &children[1]),
-            281 => self.input_stmt_list_1(),
-            282 => self.output_stmt(&children[0], &children[1], &children[2], &children[3]),
-            283 => self.output_stmt_list_0(&children[0], &children[1]),
-            284 => self.output_stmt_list_1(),
-            285 => self.notification_stmt(
+            207 => self.identity_stmt_list_0(&children[0], &children[1]),
+            208 => self.identity_stmt_list_group_0(&children[0]),
+            209 => self.identity_stmt_list_group_1(&children[0]),
+            210 => self.identity_stmt_list_group_2(&children[0]),
+            211 => self.identity_stmt_list_group_3(&children[0]),
+            212 => self.identity_stmt_list_group_4(&children[0]),
+            213 => self.identity_stmt_list_1(),
+            214 => self.base_stmt(&children[0], &children[1], &children[2]),
+            215 => self.anyxml_stmt(&children[0], &children[1], &children[2]),
+            216 => self.anyxml_stmt_suffix_0(&children[0]),
+            217 => self.anyxml_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            218 => self.anyxml_stmt_list_0(&children[0], &children[1]),
+            219 => self.anyxml_stmt_list_group_0(&children[0]),
+            220 => self.anyxml_stmt_list_group_1(&children[0]),
+            221 => self.anyxml_stmt_list_group_2(&children[0]),
+            222 => self.anyxml_stmt_list_group_3(&children[0]),
+            223 => self.anyxml_stmt_list_group_4(&children[0]),
+            224 => self.anyxml_stmt_list_group_5(&children[0]),
+            225 => self.anyxml_stmt_list_group_6(&children[0]),
+            226 => self.anyxml_stmt_list_group_7(&children[0]),
+            227 => self.anyxml_stmt_list_1(),
+            228 => self.choice_stmt(&children[0], &children[1], &children[2]),
+            229 => self.choice_stmt_suffix_0(&children[0]),
+            230 => self.choice_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            231 => self.choice_stmt_list_0(&children[0], &children[1]),
+            232 => self.choice_stmt_list_group_0(&children[0]),
+            233 => self.choice_stmt_list_group_1(&children[0]),
+            234 => self.choice_stmt_list_group_2(&children[0]),
+            235 => self.choice_stmt_list_group_3(&children[0]),
+            236 => self.choice_stmt_list_group_4(&children[0]),
+            237 => self.choice_stmt_list_group_5(&children[0]),
+            238 => self.choice_stmt_list_group_6(&children[0]),
+            239 => self.choice_stmt_list_group_7(&children[0]),
+            240 => self.choice_stmt_list_group_8(&children[0]),
+            241 => self.choice_stmt_list_group_9(&children[0]),
+            242 => self.choice_stmt_list_1(),
+            243 => self.short_case_stmt_0(&children[0]),
+            244 => self.short_case_stmt_1(&children[0]),
+            245 => self.short_case_stmt_2(&children[0]),
+            246 => self.short_case_stmt_3(&children[0]),
+            247 => self.short_case_stmt_4(&children[0]),
+            248 => self.short_case_stmt_5(&children[0]),
+            249 => self.short_case_stmt_6(&children[0]),
+            250 => self.anydata_stmt(&children[0], &children[1], &children[2]),
+            251 => self.anydata_stmt_suffix_0(&children[0]),
+            252 => self.anydata_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            253 => self.anydata_stmt_list_0(&children[0], &children[1]),
+            254 => self.anydata_stmt_list_group_0(&children[0]),
+            255 => self.anydata_stmt_list_group_1(&children[0]),
+            256 => self.anydata_stmt_list_group_2(&children[0]),
+            257 => self.anydata_stmt_list_group_3(&children[0]),
+            258 => self.anydata_stmt_list_group_4(&children[0]),
+            259 => self.anydata_stmt_list_group_5(&children[0]),
+            260 => self.anydata_stmt_list_group_6(&children[0]),
+            261 => self.anydata_stmt_list_group_7(&children[0]),
+            262 => self.anydata_stmt_list_1(),
+            263 => self.case_stmt(&children[0], &children[1], &children[2]),
+            264 => self.case_stmt_suffix_0(&children[0]),
+            265 => self.case_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            266 => self.case_stmt_list_0(&children[0], &children[1]),
+            267 => self.case_stmt_list_group_0(&children[0]),
+            268 => self.case_stmt_list_group_1(&children[0]),
+            269 => self.case_stmt_list_group_2(&children[0]),
+            270 => self.case_stmt_list_group_3(&children[0]),
+            271 => self.case_stmt_list_group_4(&children[0]),
+            272 => self.case_stmt_list_group_5(&children[0]),
+            273 => self.case_stmt_list_1(),
+            274 => self.status_stmt(&children[0], &children[1], &children[2]),
+            275 => self.status_arg_str_0(&children[0]),
+            276 => self.status_arg_str_1(&children[0], &children[1], &children[2]),
+            277 => self.status_arg(&children[0]),
+            278 => self.container_stmt(&children[0], &children[1], &children[2]),
+            279 => self.container_stmt_suffix_0(&children[0]),
+            280 => self.container_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            281 => self.container_stmt_list_0(&children[0], &children[1]),
+            282 => self.container_stmt_list_group_0(&children[0]),
+            283 => self.container_stmt_list_group_1(&children[0]),
+            284 => self.container_stmt_list_group_2(&children[0]),
+            285 => self.container_stmt_list_group_3(&children[0]),
+            286 => self.container_stmt_list_group_4(&children[0]),
+            287 => self.container_stmt_list_group_5(&children[0]),
+            288 => self.container_stmt_list_group_6(&children[0]),
+            289 => self.container_stmt_list_group_7(&children[0]),
+            290 => self.container_stmt_list_group_8(&children[0]),
+            291 => self.container_stmt_list_group_9(&children[0]),
+            292 => self.container_stmt_list_group_10(&children[0]),
+            293 => self.container_stmt_list_group_11(&children[0]),
+            294 => self.container_stmt_list_group_12(&children[0]),
+            295 => self.container_stmt_list_group_13(&children[0]),
+            296 => self.container_stmt_list_1(),
+            297 => self.action_stmt(&children[0], &children[1], &children[2]),
+            298 => self.action_stmt_suffix_0(&children[0]),
+            299 => self.action_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            300 => self.action_stmt_list_0(&children[0], &children[1]),
+            301 => self.action_stmt_list_group_0(&children[0]),
+            302 => self.action_stmt_list_group_1(&children[0]),
+            303 => self.action_stmt_list_group_2(&children[0]),
+            304 => self.action_stmt_list_group_3(&children[0]),
+            305 => self.action_stmt_list_group_4(&children[0]),
+            306 => self.action_stmt_list_group_5(&children[0]),
+            307 => self.action_stmt_list_group_6(&children[0]),
+            308 => self.action_stmt_list_group_7(&children[0]),
+            309 => self.action_stmt_list_1(),
+            310 => self.input_stmt(&children[0], &children[1], &children[2], &children[3]),
+            311 => self.input_stmt_list_0(&children[0], &children[1]),
+            312 => self.input_stmt_list_group_0(&children[0]),
+            313 => self.input_stmt_list_group_1(&children[0]),
+            314 => self.input_stmt_list_group_2(&children[0]),
+            315 => self.input_stmt_list_1(),
+            316 => self.output_stmt(&children[0], &children[1], &children[2], &children[3]),
+            317 => self.output_stmt_list_0(&children[0], &children[1]),
+            318 => self.output_stmt_list_group_0(&children[0]),
+            319 => self.output_stmt_list_group_1(&children[0]),
+            320 => self.output_stmt_list_group_2(&children[0]),
+            321 => self.output_stmt_list_1(),
+            322 => self.notification_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            286 => self.notification_stmt_list_0(&children[0], &children[1]),
-            287 => self.notification_stmt_list_group_0(&children[0]),
-            288 => self.notification_stmt_list_group_1(&children[0]),
-            289 => self.notification_stmt_list_group_2(&children[0]),
-            290 => self.notification_stmt_list_group_3(&children[0]),
-            291 => self.notification_stmt_list_group_4(&children[0]),
-            292 => self.notification_stmt_list_group_5(&children[0]),
-            293 => self.notification_stmt_list_group_6(&children[0]),
-            294 => self.notification_stmt_list_group_7(&children[0]),
-            295 => self.notification_stmt_list_1(),
-            296 => self.if_feature_stmt(&children[0], &children[1], &children[2]),
-            297 => self.if_feature_expr_str(&children[0]),
-            298 => self.if_feature_expr(&children[0], &children[1]),
-            299 => self.if_feature_expr_opt_0(&children[0], &children[1]),
-            300 => self.if_feature_expr_opt_1(),
-            301 => self.if_feature_term(&children[0], &children[1]),
-            302 => self.if_feature_term_opt_0(&children[0], &children[1]),
-            303 => self.if_feature_term_opt_1(),
-            304 => self.if_feature_factor_0(&children[0], &children[1]),
-            305 => self.if_feature_factor_1(&children[0], &children[1], &children[2]),
-            306 => self.if_feature_factor_2(&children[0]),
-            307 => self.if_feature_factor_3(&children[0], &children[1], &children[2]),
-            308 => self.presence_stmt(&children[0], &children[1], &children[2]),
-            309 => self.uses_stmt(&children[0], &children[1], &children[2]),
-            310 => self.uses_stmt_suffix_0(&children[0]),
-            311 => self.uses_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            312 => self.uses_stmt_list_0(&children[0], &children[1]),
-            313 => self.uses_stmt_list_group_0(&children[0]),
-            314 => self.uses_stmt_list_group_1(&children[0]),
-            315 => self.uses_stmt_list_group_2(&children[0]),
-            316 => self.uses_stmt_list_group_3(&children[0]),
-            317 => self.uses_stmt_list_group_4(&children[0]),
-            318 => self.uses_stmt_list_group_5(&children[0]),
-            319 => self.uses_stmt_list_group_6(&children[0]),
-            320 => self.uses_stmt_list_1(),
-            321 => self.refine_stmt(&children[0], &children[1], &children[2]),
-            322 => self.refine_stmt_suffix_0(&children[0]),
-            323 => self.refine_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            324 => self.refine_stmt_list_0(&children[0], &children[1]),
-            325 => self.refine_stmt_list_group_0(&children[0]),
-            326 => self.refine_stmt_list_group_1(&children[0]),
-            327 => self.refine_stmt_list_group_2(&children[0]),
-            328 => self.refine_stmt_list_group_3(&children[0]),
-            329 => self.refine_stmt_list_group_4(&children[0]),
-            330 => self.refine_stmt_list_group_5(&children[0]),
-            331 => self.refine_stmt_list_group_6(&children[0]),
-            332 => self.refine_stmt_list_group_7(&children[0]),
-            333 => self.refine_stmt_list_group_8(&children[0]),
-            334 => self.refine_stmt_list_group_9(&children[0]),
-            335 => self.refine_stmt_list_1(),
-            336 => self.refine_arg_str_0(&children[0]),
-            337 => self.refine_arg_str_1(&children[0], &children[1], &children[2]),
-            338 => self.refine_arg(&children[0]),
-            339 => self.descendant_schema_nodeid_0(&children[0]),
-            340 => self.descendant_schema_nodeid_1(&children[0]),
-            341 => self.unknown_stmt(&children[0], &children[1], &children[2]),
-            342 => self.unknown_stmt_suffix_0(&children[0]),
-            343 => self.unknown_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            344 => self.unknown_stmt_list_0(&children[0], &children[1]),
-            345 => self.unknown_stmt_list_group_0(&children[0]),
-            346 => self.unknown_stmt_list_group_1(&children[0]),
-            347 => self.unknown_stmt_list_1(),
-            348 => self.leaf_list_stmt(
+            323 => self.notification_stmt_list_0(&children[0], &children[1]),
+            324 => self.notification_stmt_list_group_0(&children[0]),
+            325 => self.notification_stmt_list_group_1(&children[0]),
+            326 => self.notification_stmt_list_group_2(&children[0]),
+            327 => self.notification_stmt_list_group_3(&children[0]),
+            328 => self.notification_stmt_list_group_4(&children[0]),
+            329 => self.notification_stmt_list_group_5(&children[0]),
+            330 => self.notification_stmt_list_group_6(&children[0]),
+            331 => self.notification_stmt_list_group_7(&children[0]),
+            332 => self.notification_stmt_list_1(),
+            333 => self.if_feature_stmt(&children[0], &children[1], &children[2]),
+            334 => self.if_feature_expr_str(&children[0]),
+            335 => self.if_feature_expr(&children[0], &children[1]),
+            336 => self.if_feature_expr_opt_0(&children[0], &children[1]),
+            337 => self.if_feature_expr_opt_1(),
+            338 => self.if_feature_term(&children[0], &children[1]),
+            339 => self.if_feature_term_opt_0(&children[0], &children[1]),
+            340 => self.if_feature_term_opt_1(),
+            341 => self.if_feature_factor_0(&children[0], &children[1]),
+            342 => self.if_feature_factor_1(&children[0], &children[1], &children[2]),
+            343 => self.if_feature_factor_2(&children[0]),
+            344 => self.if_feature_factor_3(&children[0], &children[1], &children[2]),
+            345 => self.presence_stmt(&children[0], &children[1], &children[2]),
+            346 => self.uses_stmt(&children[0], &children[1], &children[2]),
+            347 => self.uses_stmt_suffix_0(&children[0]),
+            348 => self.uses_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            349 => self.uses_stmt_list_0(&children[0], &children[1]),
+            350 => self.uses_stmt_list_group_0(&children[0]),
+            351 => self.uses_stmt_list_group_1(&children[0]),
+            352 => self.uses_stmt_list_group_2(&children[0]),
+            353 => self.uses_stmt_list_group_3(&children[0]),
+            354 => self.uses_stmt_list_group_4(&children[0]),
+            355 => self.uses_stmt_list_group_5(&children[0]),
+            356 => self.uses_stmt_list_group_6(&children[0]),
+            357 => self.uses_stmt_list_1(),
+            358 => self.refine_stmt(&children[0], &children[1], &children[2]),
+            359 => self.refine_stmt_suffix_0(&children[0]),
+            360 => self.refine_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            361 => self.refine_stmt_list_0(&children[0], &children[1]),
+            362 => self.refine_stmt_list_group_0(&children[0]),
+            363 => self.refine_stmt_list_group_1(&children[0]),
+            364 => self.refine_stmt_list_group_2(&children[0]),
+            365 => self.refine_stmt_list_group_3(&children[0]),
+            366 => self.refine_stmt_list_group_4(&children[0]),
+            367 => self.refine_stmt_list_group_5(&children[0]),
+            368 => self.refine_stmt_list_group_6(&children[0]),
+            369 => self.refine_stmt_list_group_7(&children[0]),
+            370 => self.refine_stmt_list_group_8(&children[0]),
+            371 => self.refine_stmt_list_group_9(&children[0]),
+            372 => self.refine_stmt_list_1(),
+            373 => self.refine_arg_str_0(&children[0]),
+            374 => self.refine_arg_str_1(&children[0], &children[1], &children[2]),
+            375 => self.refine_arg(&children[0]),
+            376 => self.descendant_schema_nodeid_0(&children[0]),
+            377 => self.descendant_schema_nodeid_1(&children[0]),
+            378 => self.unknown_stmt(&children[0], &children[1], &children[2]),
+            379 => self.unknown_stmt_suffix_0(&children[0]),
+            380 => self.unknown_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            381 => self.unknown_stmt_list_0(&children[0], &children[1]),
+            382 => self.unknown_stmt_list_group_0(&children[0]),
+            383 => self.unknown_stmt_list_group_1(&children[0]),
+            384 => self.unknown_stmt_list_1(),
+            385 => self.leaf_list_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            349 => self.leaf_list_stmt_list_0(&children[0], &children[1]),
-            350 => self.leaf_list_stmt_list_group_0(&children[0]),
-            351 => self.leaf_list_stmt_list_group_1(&children[0]),
-            352 => self.leaf_list_stmt_list_group_2(&children[0]),
-            353 => self.leaf_list_stmt_list_group_3(&children[0]),
-            354 => self.leaf_list_stmt_list_group_4(&children[0]),
-            355 => self.leaf_list_stmt_list_group_5(&children[0]),
-            356 => self.leaf_list_stmt_list_group_6(&children[0]),
-            357 => self.leaf_list_stmt_list_group_7(&children[0]),
-            358 => self.leaf_list_stmt_list_group_8(&children[0]),
-            359 => self.leaf_list_stmt_list_group_9(&children[0]),
-            360 => self.leaf_list_stmt_list_group_10(&children[0]),
-            361 => self.leaf_list_stmt_list_group_11(&children[0]),
-            362 => self.leaf_list_stmt_list_group_12(&children[0]),
-            363 => self.leaf_list_stmt_list_group_13(&children[0]),
-            364 => self.leaf_list_stmt_list_1(),
-            365 => self.leaf_stmt(
+            386 => self.leaf_list_stmt_list_0(&children[0], &children[1]),
+            387 => self.leaf_list_stmt_list_group_0(&children[0]),
+            388 => self.leaf_list_stmt_list_group_1(&children[0]),
+            389 => self.leaf_list_stmt_list_group_2(&children[0]),
+            390 => self.leaf_list_stmt_list_group_3(&children[0]),
+            391 => self.leaf_list_stmt_list_group_4(&children[0]),
+            392 => self.leaf_list_stmt_list_group_5(&children[0]),
+            393 => self.leaf_list_stmt_list_group_6(&children[0]),
+            394 => self.leaf_list_stmt_list_group_7(&children[0]),
+            395 => self.leaf_list_stmt_list_group_8(&children[0]),
+            396 => self.leaf_list_stmt_list_group_9(&children[0]),
+            397 => self.leaf_list_stmt_list_group_10(&children[0]),
+            398 => self.leaf_list_stmt_list_group_11(&children[0]),
+            399 => self.leaf_list_stmt_list_group_12(&children[0]),
+            400 => self.leaf_list_stmt_list_group_13(&children[0]),
+            401 => self.leaf_list_stmt_list_1(),
+            402 => self.leaf_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            366 => self.leaf_stmt_list_0(&children[0], &children[1]),
-            367 => self.leaf_stmt_list_group_0(&children[0]),
-            368 => self.leaf_stmt_list_group_1(&children[0]),
-            369 => self.leaf_stmt_list_group_2(&children[0]),
-            370 => self.leaf_stmt_list_group_3(&children[0]),
-            371 => self.leaf_stmt_list_group_4(&children[0]),
-            372 => self.leaf_stmt_list_group_5(&children[0]),
-            373 => self.leaf_stmt_list_group_6(&children[0]),
-            374 => self.leaf_stmt_list_group_7(&children[0]),
-            375 => self.leaf_stmt_list_group_8(&children[0]),
-            376 => self.leaf_stmt_list_group_9(&children[0]),
-            377 => self.leaf_stmt_list_group_10(&children[0]),
-            378 => self.leaf_stmt_list_group_11(&children[0]),
-            379 => self.leaf_stmt_list_1(),
-            380 => self.must_stmt(&children[0], &children[1], &children[2]),
-            381 => self.must_stmt_suffix_0(&children[0]),
-            382 => self.must_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            383 => self.must_stmt_list_0(&children[0], &children[1]),
-            384 => self.must_stmt_list_group_0(&children[0]),
-            385 => self.must_stmt_list_group_1(&children[0]),
-            386 => self.must_stmt_list_1(),
-            387 => self.error_message(&children[0], &children[1], &children[2]),
-            388 => self.units_stmt(&children[0], &children[1], &children[2]),
-            389 => self.config_stmt(&children[0], &children[1], &children[2]),
-            390 => self.mandatory_stmt(&children[0], &children[1], &children[2]),
-            391 => self.mandatory_arg_str_0(&children[0]),
-            392 => self.mandatory_arg_str_1(&children[0], &children[1], &children[2]),
-            393 => self.mandatory_arg(&children[0]),
-            394 => self.list_stmt(
+            403 => self.leaf_stmt_list_0(&children[0], &children[1]),
+            404 => self.leaf_stmt_list_group_0(&children[0]),
+            405 => self.leaf_stmt_list_group_1(&children[0]),
+            406 => self.leaf_stmt_list_group_2(&children[0]),
+            407 => self.leaf_stmt_list_group_3(&children[0]),
+            408 => self.leaf_stmt_list_group_4(&children[0]),
+            409 => self.leaf_stmt_list_group_5(&children[0]),
+            410 => self.leaf_stmt_list_group_6(&children[0]),
+            411 => self.leaf_stmt_list_group_7(&children[0]),
+            412 => self.leaf_stmt_list_group_8(&children[0]),
+            413 => self.leaf_stmt_list_group_9(&children[0]),
+            414 => self.leaf_stmt_list_group_10(&children[0]),
+            415 => self.leaf_stmt_list_group_11(&children[0]),
+            416 => self.leaf_stmt_list_1(),
+            417 => self.must_stmt(&children[0], &children[1], &children[2]),
+            418 => self.must_stmt_suffix_0(&children[0]),
+            419 => self.must_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            420 => self.must_stmt_list_0(&children[0], &children[1]),
+            421 => self.must_stmt_list_group_0(&children[0]),
+            422 => self.must_stmt_list_group_1(&children[0]),
+            423 => self.must_stmt_list_1(),
+            424 => self.error_message(&children[0], &children[1], &children[2]),
+            425 => self.units_stmt(&children[0], &children[1], &children[2]),
+            426 => self.config_stmt(&children[0], &children[1], &children[2]),
+            427 => self.mandatory_stmt(&children[0], &children[1], &children[2]),
+            428 => self.mandatory_arg_str_0(&children[0]),
+            429 => self.mandatory_arg_str_1(&children[0], &children[1], &children[2]),
+            430 => self.mandatory_arg(&children[0]),
+            431 => self.list_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            395 => self.list_stmt_list_0(&children[0], &children[1]),
-            396 => self.list_stmt_list_group_0(&children[0]),
-            397 => self.list_stmt_list_group_1(&children[0]),
-            398 => self.list_stmt_list_group_2(&children[0]),
-            399 => self.list_stmt_list_group_3(&children[0]),
-            400 => self.list_stmt_list_group_4(&children[0]),
-            401 => self.list_stmt_list_group_5(&children[0]),
-            402 => self.list_stmt_list_group_6(&children[0]),
-            403 => self.list_stmt_list_group_7(&children[0]),
-            404 => self.list_stmt_list_group_8(&children[0]),
-            405 => self.list_stmt_list_group_9(&children[0]),
-            406 => self.list_stmt_list_group_10(&children[0]),
-            407 => self.list_stmt_list_group_11(&children[0]),
-            408 => self.list_stmt_list_group_12(&children[0]),
-            409 => self.list_stmt_list_group_13(&children[0]),
-            410 => self.list_stmt_list_group_14(&children[0]),
-            411 => self.list_stmt_list_1(),
-            412 => self.ordered_by_stmt(&children[0], &children[1], &children[2]),
-            413 => self.ordered_by_arg_str_0(&children[0]),
-            414 => self.ordered_by_arg_str_1(&children[0], &children[1], &children[2]),
-            415 => self.ordered_by_arg(&children[0]),
-            416 => self.default_stmt(&children[0], &children[1]),
-            417 => self.default_stmt_suffix_0(&children[0], &children[1]),
-            418 => {
+            432 => self.list_stmt_list_0(&children[0], &children[1]),
+            433 => self.list_stmt_list_group_0(&children[0]),
+            434 => self.list_stmt_list_group_1(&children[0]),
+            435 => self.list_stmt_list_group_2(&children[0]),
+            436 => self.list_stmt_list_group_3(&children[0]),
+            437 => self.list_stmt_list_group_4(&children[0]),
+            438 => self.list_stmt_list_group_5(&children[0]),
+            439 => self.list_stmt_list_group_6(&children[0]),
+            440 => self.list_stmt_list_group_7(&children[0]),
+            441 => self.list_stmt_list_group_8(&children[0]),
+            442 => self.list_stmt_list_group_9(&children[0]),
+            443 => self.list_stmt_list_group_10(&children[0]),
+            444 => self.list_stmt_list_group_11(&children[0]),
+            445 => self.list_stmt_list_group_12(&children[0]),
+            446 => self.list_stmt_list_group_13(&children[0]),
+            447 => self.list_stmt_list_group_14(&children[0]),
+            448 => self.list_stmt_list_group_15(&children[0]),
+            449 => self.list_stmt_list_group_16(&children[0]),
+            450 => self.list_stmt_list_1(),
+            451 => self.ordered_by_stmt(&children[0], &children[1], &children[2]),
+            452 => self.ordered_by_arg_str_0(&children[0]),
+            453 => self.ordered_by_arg_str_1(&children[0], &children[1], &children[2]),
+            454 => self.ordered_by_arg(&children[0]),
+            455 => self.default_stmt(&children[0], &children[1]),
+            456 => self.default_stmt_suffix_0(&children[0], &children[1]),
+            457 => {
                 self.default_stmt_suffix_1(&children[0], &children[1], &children[2], &children[3])
             }
-            419 => self.max_elements_stmt(&children[0], &children[1], &children[2]),
-            420 => self.min_elements_stmt(&children[0], &children[1], &children[2]),
-            421 => self.type_stmt(&children[0], &children[1], &children[2]),
-            422 => self.type_stmt_suffix_0(&children[0]),
-            423 => self.type_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            424 => self.type_stmt_list_0(&children[0], &children[1]),
-            425 => self.type_stmt_list_group_0(&children[0]),
-            426 => self.type_stmt_list_group_1(&children[0]),
-            427 => self.type_stmt_list_group_2(&children[0]),
-            428 => self.type_stmt_list_group_3(&children[0]),
-            429 => self.type_stmt_list_group_4(&children[0]),
-            430 => self.type_stmt_list_group_5(&children[0]),
-            431 => self.type_stmt_list_group_6(&children[0]),
-            432 => self.type_stmt_list_group_7(&children[0]),
-            433 => self.type_stmt_list_1(),
-            434 => self.fraction_digits_stmt(&children[0], &children[1], &children[2]),
-            435 => self.fraction_digits_arg(&children[0]),
-            436 => self.bit_stmt(&children[0], &children[1], &children[2]),
-            437 => self.bit_stmt_suffix_0(&children[0]),
-            438 => self.bit_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            439 => self.bit_stmt_list_0(&children[0], &children[1]),
-            440 => self.bit_stmt_list_group_0(&children[0]),
-            441 => self.bit_stmt_list_group_1(&children[0]),
-            442 => self.bit_stmt_list_group_2(&children[0]),
-            443 => self.bit_stmt_list_1(),
-            444 => self.position_stmt(&children[0], &children[1], &children[2]),
-            445 => self.leafref_specification_0(&children[0]),
-            446 => self.leafref_specification_1(&children[0]),
-            447 => self.require_instance_stmt(&children[0], &children[1], &children[2]),
-            448 => self.require_instance_arg_str_0(&children[0]),
-            449 => self.require_instance_arg_str_1(&children[0], &children[1], &children[2]),
-            450 => self.path_stmt(&children[0], &children[1], &children[2]),
-            451 => self.string_restrictions_0(&children[0]),
-            452 => self.string_restrictions_1(&children[0]),
-            453 => self.pattern_stmt(&children[0], &children[1], &children[2]),
-            454 => self.length_stmt(&children[0], &children[1], &children[2]),
-            455 => self.enum_stmt(&children[0], &children[1], &children[2]),
-            456 => self.enum_stmt_suffix_0(&children[0]),
-            457 => self.enum_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            458 => self.enum_stmt_list_0(&children[0], &children[1]),
-            459 => self.enum_stmt_list_group_0(&children[0]),
-            460 => self.enum_stmt_list_group_1(&children[0]),
-            461 => self.enum_stmt_list_group_2(&children[0]),
-            462 => self.enum_stmt_list_group_3(&children[0]),
-            463 => self.enum_stmt_list_1(),
-            464 => self.value_stmt(&children[0], &children[1], &children[2]),
-            465 => self.integer_value_str_0(&children[0]),
-            466 => self.integer_value_str_1(&children[0], &children[1], &children[2]),
-            467 => self.integer_value(&children[0]),
-            468 => self.enum_arg_str_0(&children[0]),
-            469 => self.enum_arg_str_1(&children[0], &children[1], &children[2]),
-            470 => self.range_stmt(&children[0], &children[1], &children[2]),
-            471 => self.range_arg_str_0(&children[0]),
-            472 => self.range_arg_str_1(&children[0], &children[1], &children[2]),
-            473 => self.range_arg(&children[0], &children[1]),
-            474 => self.range_arg_opt_0(&children[0], &children[1]),
-            475 => self.range_arg_opt_1(),
-            476 => self.range_part(&children[0], &children[1]),
-            477 => self.range_part_opt_0(&children[0], &children[1]),
-            478 => self.range_part_opt_1(),
-            479 => self.range_boundary_0(&children[0]),
-            480 => self.range_boundary_1(&children[0]),
-            481 => self.range_boundary_2(&children[0]),
-            482 => self.identifier_ref_arg_str_0(&children[0]),
-            483 => self.identifier_ref_arg_str_1(&children[0], &children[1], &children[2]),
-            484 => self.identifier_ref(&children[0], &children[1]),
-            485 => self.identifier_ref_opt_0(&children[0], &children[1]),
-            486 => self.identifier_ref_opt_1(),
-            487 => self.key_stmt(&children[0], &children[1], &children[2]),
-            488 => self.key_arg_str_0(&children[0]),
-            489 => self.key_arg_str_1(&children[0], &children[1], &children[2]),
-            490 => self.semicolon(&children[0]),
-            491 => self.key_arg(&children[0], &children[1]),
-            492 => self.key_arg_opt_0(&children[0]),
-            493 => self.key_arg_opt_1(),
-            494 => self.absolute_schema_nodeid(&children[0], &children[1]),
-            495 => self.import_stmt(
+            458 => self.max_elements_stmt(&children[0], &children[1], &children[2]),
+            459 => self.min_elements_stmt(&children[0], &children[1], &children[2]),
+            460 => self.type_stmt(&children[0], &children[1], &children[2]),
+            461 => self.type_stmt_suffix_0(&children[0]),
+            462 => self.type_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            463 => self.type_stmt_list_0(&children[0], &children[1]),
+            464 => self.type_stmt_list_group_0(&children[0]),
+            465 => self.type_stmt_list_group_1(&children[0]),
+            466 => self.type_stmt_list_group_2(&children[0]),
+            467 => self.type_stmt_list_group_3(&children[0]),
+            468 => self.type_stmt_list_group_4(&children[0]),
+            469 => self.type_stmt_list_group_5(&children[0]),
+            470 => self.type_stmt_list_group_6(&children[0]),
+            471 => self.type_stmt_list_group_7(&children[0]),
+            472 => self.type_stmt_list_1(),
+            473 => self.fraction_digits_stmt(&children[0], &children[1], &children[2]),
+            474 => self.fraction_digits_arg(&children[0]),
+            475 => self.bit_stmt(&children[0], &children[1], &children[2]),
+            476 => self.bit_stmt_suffix_0(&children[0]),
+            477 => self.bit_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            478 => self.bit_stmt_list_0(&children[0], &children[1]),
+            479 => self.bit_stmt_list_group_0(&children[0]),
+            480 => self.bit_stmt_list_group_1(&children[0]),
+            481 => self.bit_stmt_list_group_2(&children[0]),
+            482 => self.bit_stmt_list_1(),
+            483 => self.position_stmt(&children[0], &children[1], &children[2]),
+            484 => self.leafref_specification_0(&children[0]),
+            485 => self.leafref_specification_1(&children[0]),
+            486 => self.require_instance_stmt(&children[0], &children[1], &children[2]),
+            487 => self.require_instance_arg_str_0(&children[0]),
+            488 => self.require_instance_arg_str_1(&children[0], &children[1], &children[2]),
+            489 => self.path_stmt(&children[0], &children[1], &children[2]),
+            490 => self.string_restrictions_0(&children[0]),
+            491 => self.string_restrictions_1(&children[0]),
+            492 => self.pattern_stmt(&children[0], &children[1], &children[2]),
+            493 => self.length_stmt(&children[0], &children[1], &children[2]),
+            494 => self.enum_stmt(&children[0], &children[1], &children[2]),
+            495 => self.enum_stmt_suffix_0(&children[0]),
+            496 => self.enum_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            497 => self.enum_stmt_list_0(&children[0], &children[1]),
+            498 => self.enum_stmt_list_group_0(&children[0]),
+            499 => self.enum_stmt_list_group_1(&children[0]),
+            500 => self.enum_stmt_list_group_2(&children[0]),
+            501 => self.enum_stmt_list_group_3(&children[0]),
+            502 => self.enum_stmt_list_1(),
+            503 => self.value_stmt(&children[0], &children[1], &children[2]),
+            504 => self.integer_value_str_0(&children[0]),
+            505 => self.integer_value_str_1(&children[0], &children[1], &children[2]),
+            506 => self.integer_value(&children[0]),
+            507 => self.enum_arg_str_0(&children[0]),
+            508 => self.enum_arg_str_1(&children[0], &children[1], &children[2]),
+            509 => self.range_stmt(&children[0], &children[1], &children[2]),
+            510 => self.range_arg_str_0(&children[0]),
+            511 => self.range_arg_str_1(&children[0], &children[1], &children[2]),
+            512 => self.range_arg(&children[0], &children[1]),
+            513 => self.range_arg_opt_0(&children[0], &children[1]),
+            514 => self.range_arg_opt_1(),
+            515 => self.range_part(&children[0], &children[1]),
+            516 => self.range_part_opt_0(&children[0], &children[1]),
+            517 => self.range_part_opt_1(),
+            518 => self.range_boundary_0(&children[0]),
+            519 => self.range_boundary_1(&children[0]),
+            520 => self.range_boundary_2(&children[0]),
+            521 => self.range_boundary_3(&children[0]),
+            522 => self.identifier_ref_arg_str_0(&children[0]),
+            523 => self.identifier_ref_arg_str_1(&children[0], &children[1], &children[2]),
+            524 => self.identifier_ref(&children[0], &children[1]),
+            525 => self.identifier_ref_opt_0(&children[0], &children[1]),
+            526 => self.identifier_ref_opt_1(),
+            527 => self.key_stmt(&children[0], &children[1], &children[2]),
+            528 => self.key_arg_str_0(&children[0]),
+            529 => self.key_arg_str_1(&children[0], &children[1], &children[2]),
+            530 => self.semicolon(&children[0]),
+            531 => self.key_arg(&children[0], &children[1]),
+            532 => self.key_arg_opt_0(&children[0]),
+            533 => self.key_arg_opt_1(),
+            534 => self.absolute_schema_nodeid(&children[0], &children[1]),
+            535 => self.import_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            496 => self.import_stmt_list_0(&children[0], &children[1]),
-            497 => self.import_stmt_list_group_0(&children[0]),
-            498 => self.import_stmt_list_group_1(&children[0]),
-            499 => self.import_stmt_list_group_2(&children[0]),
-            500 => self.import_stmt_list_group_3(&children[0]),
-            501 => self.import_stmt_list_1(),
-            502 => self.include_stmt(&children[0], &children[1], &children[2]),
-            503 => self.include_stmt_suffix_0(&children[0]),
-            504 => self.include_stmt_suffix_1(&children[0], &children[1], &children[2]),
-            505 => self.include_stmt_list_0(&children[0], &children[1]),
-            506 => self.include_stmt_list_group_0(&children[0]),
-            507 => self.include_stmt_list_group_1(&children[0]),
-            508 => self.include_stmt_list_group_2(&children[0]),
-            509 => self.include_stmt_list_1(),
-            510 => self.revision_date_stmt(&children[0], &children[1], &children[2]),
-            511 => self.namespace_stmt(&children[0], &children[1], &children[2]),
-            512 => self.uri_str_0(&children[0]),
-            513 => self.uri_str_1(&children[0], &children[1], &children[2]),
-            514 => self.uri_arg(&children[0]),
-            515 => self.prefix_stmt(&children[0], &children[1], &children[2]),
-            516 => self.belongs_to_stmt(
+            536 => self.import_stmt_list_0(&children[0], &children[1]),
+            537 => self.import_stmt_list_group_0(&children[0]),
+            538 => self.import_stmt_list_group_1(&children[0]),
+            539 => self.import_stmt_list_group_2(&children[0]),
+            540 => self.import_stmt_list_group_3(&children[0]),
+            541 => self.import_stmt_list_1(),
+            542 => self.include_stmt(&children[0], &children[1], &children[2]),
+            543 => self.include_stmt_suffix_0(&children[0]),
+            544 => self.include_stmt_suffix_1(&children[0], &children[1], &children[2]),
+            545 => self.include_stmt_list_0(&children[0], &children[1]),
+            546 => self.include_stmt_list_group_0(&children[0]),
+            547 => self.include_stmt_list_group_1(&children[0]),
+            548 => self.include_stmt_list_group_2(&children[0]),
+            549 => self.include_stmt_list_1(),
+            550 => self.revision_date_stmt(&children[0], &children[1], &children[2]),
+            551 => self.namespace_stmt(&children[0], &children[1], &children[2]),
+            552 => self.uri_str_0(&children[0]),
+            553 => self.uri_str_1(&children[0], &children[1], &children[2]),
+            554 => self.uri_arg(&children[0]),
+            555 => self.prefix_stmt(&children[0], &children[1], &children[2]),
+            556 => self.belongs_to_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            517 => self.organization_stmt(&children[0], &children[1], &children[2]),
-            518 => self.contact_stmt(&children[0], &children[1], &children[2]),
-            519 => self.description_stmt(&children[0], &children[1], &children[2]),
-            520 => self.reference_stmt(&children[0], &children[1], &children[2]),
-            521 => self.revision_stmt(
+            557 => self.organization_stmt(&children[0], &children[1], &children[2]),
+            558 => self.contact_stmt(&children[0], &children[1], &children[2]),
+            559 => self.description_stmt(&children[0], &children[1], &children[2]),
+            560 => self.reference_stmt(&children[0], &children[1], &children[2]),
+            561 => self.revision_stmt(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            522 => self.revision_stmt_list_0(&children[0], &children[1]),
-            523 => self.revision_stmt_list_group_0(&children[0]),
-            524 => self.revision_stmt_list_group_1(&children[0]),
-            525 => self.revision_stmt_list_1(),
-            526 => self.date_arg_str_0(&children[0]),
-            527 => self.date_arg_str_1(&children[0], &children[1], &children[2]),
-            528 => self.date_arg(&children[0]),
-            529 => self.ystring_0(&children[0], &children[1]),
-            530 => self.ystring_1(&children[0]),
-            531 => self.ystring_opt_0(&children[0], &children[1]),
-            532 => self.ystring_opt_1(),
-            533 => self.basic_string_0(&children[0]),
-            534 => self.basic_string_1(&children[0]),
-            535 => self.double_quotation(&children[0]),
-            536 => self.d_q_string(&children[0], &children[1], &children[2]),
-            537 => self.d_q_string_list_0(&children[0], &children[1]),
-            538 => self.d_q_string_list_1(),
-            539 => self.d_q_char_0(&children[0]),
-            540 => self.d_q_char_1(&children[0]),
-            541 => self.d_q_unescaped_0(&children[0]),
-            542 => self.d_q_unescaped_1(&children[0]),
-            543 => self.d_q_escaped(&children[0], &children[1]),
-            544 => self.d_q_escape_seq_char_0(&children[0]),
-            545 => self.d_q_escape_seq_char_1(&children[0]),
-            546 => self.d_q_escape_seq_char_2(&children[0]),
-            547 => self.d_q_escape_seq_char_3(&children[0]),
-            548 => self.escape(&children[0]),
-            549 => self.non_ascii(&children[0]),
-            550 => self.d_q_no_escape(&children[0]),
-            551 => self.s_q_string(&children[0], &children[1], &children[2]),
-            552 => self.s_q_string_list_0(&children[0], &children[1]),
-            553 => self.s_q_string_list_1(),
-            554 => self.s_q_char_0(&children[0]),
-            555 => self.s_q_char_1(&children[0]),
-            556 => self.s_q_escaped(&children[0], &children[1]),
-            557 => self.s_q_escape_seq_char(&children[0]),
-            558 => self.s_q_unescaped_0(&children[0]),
-            559 => self.s_q_unescaped_1(&children[0]),
-            560 => self.s_q_no_escape(&children[0]),
-            561 => self.single_quotation(&children[0]),
-            562 => self.identifier_arg_str_0(&children[0]),
-            563 => self.identifier_arg_str_1(&children[0], &children[1], &children[2]),
-            564 => self.identifier(&children[0]),
-            565 => self.ascii_no_semicolon(&children[0]),
-            566 => self.ascii_no_brace(&children[0]),
-            567 => self.yang_version_arg(&children[0]),
+            562 => self.revision_stmt_list_0(&children[0], &children[1]),
+            563 => self.revision_stmt_list_group_0(&children[0]),
+            564 => self.revision_stmt_list_group_1(&children[0]),
+            565 => self.revision_stmt_list_1(),
+            566 => self.date_arg_str_0(&children[0]),
+            567 => self.date_arg_str_1(&children[0], &children[1], &children[2]),
+            568 => self.date_arg(&children[0]),
+            569 => self.ystring_0(&children[0], &children[1]),
+            570 => self.ystring_1(&children[0]),
+            571 => self.ystring_opt_0(&children[0], &children[1]),
+            572 => self.ystring_opt_1(),
+            573 => self.basic_string_0(&children[0]),
+            574 => self.basic_string_1(&children[0]),
+            575 => self.double_quotation(&children[0]),
+            576 => self.d_q_string(&children[0], &children[1], &children[2]),
+            577 => self.d_q_string_list_0(&children[0], &children[1]),
+            578 => self.d_q_string_list_1(),
+            579 => self.d_q_char_0(&children[0]),
+            580 => self.d_q_char_1(&children[0]),
+            581 => self.d_q_unescaped_0(&children[0]),
+            582 => self.d_q_unescaped_1(&children[0]),
+            583 => self.d_q_escaped(&children[0], &children[1]),
+            584 => self.d_q_escape_seq_char_0(&children[0]),
+            585 => self.d_q_escape_seq_char_1(&children[0]),
+            586 => self.d_q_escape_seq_char_2(&children[0]),
+            587 => self.d_q_escape_seq_char_3(&children[0]),
+            588 => self.escape(&children[0]),
+            589 => self.non_ascii(&children[0]),
+            590 => self.d_q_no_escape(&children[0]),
+            591 => self.s_q_string(&children[0], &children[1], &children[2]),
+            592 => self.s_q_string_list_0(&children[0], &children[1]),
+            593 => self.s_q_string_list_1(),
+            594 => self.s_q_char_0(&children[0]),
+            595 => self.s_q_char_1(&children[0]),
+            596 => self.s_q_escaped(&children[0], &children[1]),
+            597 => self.s_q_escape_seq_char(&children[0]),
+            598 => self.s_q_unescaped_0(&children[0]),
+            599 => self.s_q_unescaped_1(&children[0]),
+            600 => self.s_q_no_escape(&children[0]),
+            601 => self.single_quotation(&children[0]),
+            602 => self.identifier_arg_str_0(&children[0]),
+            603 => self.identifier_arg_str_1(&children[0], &children[1], &children[2]),
+            604 => self.identifier(&children[0]),
+            605 => self.ascii_no_semicolon(&children[0]),
+            606 => self.ascii_no_brace(&children[0]),
+            607 => self.yang_version_arg(&children[0]),
             _ => Err(ParserError::InternalError(format!(
                 "Unhandled production number: {prod_num}"
             ))
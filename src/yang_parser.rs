@@ -16,7 +16,7 @@ use std::path::Path;
 use crate::yang_grammar::YangGrammar;
 use crate::yang_grammar_trait::YangGrammarAuto;
 
-pub const TERMINAL_NAMES: &[&str; 106] = &[
+pub const TERMINAL_NAMES: &[&str; 109] = &[
     /*   0 */ "EndOfInput",
     /*   1 */ "Newline",
     /*   2 */ "Whitespace",
@@ -55,74 +55,77 @@ pub const TERMINAL_NAMES: &[&str; 106] = &[
     /*  35 */ "KwRefine",
     /*  36 */ "KwKey",
     /*  37 */ "KwDeviation",
-    /*  38 */ "KwYangVersion",
-    /*  39 */ "KwStatus",
-    /*  40 */ "KwIfFeature",
-    /*  41 */ "KwMandatory",
-    /*  42 */ "KwConfig",
-    /*  43 */ "KwRequireInstance",
-    /*  44 */ "KwOrderedBy",
-    /*  45 */ "KwDefault",
-    /*  46 */ "KwFractionDigits",
-    /*  47 */ "KwLength",
-    /*  48 */ "KwRange",
-    /*  49 */ "KwValue",
-    /*  50 */ "KwPosition",
-    /*  51 */ "KwEnum",
-    /*  52 */ "KwRevision",
-    /*  53 */ "KwRevisionDate",
-    /*  54 */ "KwNamespace",
-    /*  55 */ "RBrace",
-    /*  56 */ "DoubleQuotation",
-    /*  57 */ "Augment",
-    /*  58 */ "When",
-    /*  59 */ "StatusArg",
-    /*  60 */ "Input",
-    /*  61 */ "Output",
-    /*  62 */ "Or",
-    /*  63 */ "And",
-    /*  64 */ "Not",
-    /*  65 */ "LParen",
-    /*  66 */ "RParen",
-    /*  67 */ "Presence",
-    /*  68 */ "Must",
-    /*  69 */ "ErrorMinusMessage",
-    /*  70 */ "Units",
-    /*  71 */ "MandatoryArg",
-    /*  72 */ "OrderedByArg",
-    /*  73 */ "MaxMinusElements",
-    /*  74 */ "LBracket1Minus9RBracketLBracket0Minus9RBracketStar",
-    /*  75 */ "MinMinusElements",
-    /*  76 */ "FractionDigitsArg",
-    /*  77 */ "Path",
-    /*  78 */ "Pattern",
-    /*  79 */ "IntegerValue",
-    /*  80 */ "Or0",
-    /*  81 */ "DotDot",
-    /*  82 */ "Min",
-    /*  83 */ "Max",
-    /*  84 */ "MinusQuestLBracket0Minus9RBracketPlus",
-    /*  85 */ "Colon",
-    /*  86 */ "Semicolon",
-    /*  87 */ "Slash",
-    /*  88 */ "UriArg",
-    /*  89 */ "Organization",
-    /*  90 */ "Contact",
-    /*  91 */ "Description",
-    /*  92 */ "Reference",
-    /*  93 */ "DateArg",
-    /*  94 */ "Plus",
-    /*  95 */ "Escape",
-    /*  96 */ "NonAscii",
-    /*  97 */ "DQNoEscape",
-    /*  98 */ "SQEscapeSeqChar",
-    /*  99 */ "SQNoEscape",
-    /* 100 */ "SingleQuotation",
-    /* 101 */ "Identifier",
-    /* 102 */ "AsciiNoSemicolon",
-    /* 103 */ "AsciiNoBrace",
-    /* 104 */ "YangVersionArg",
-    /* 105 */ "Error",
+    /*  38 */ "KwDeviate",
+    /*  39 */ "KwYangVersion",
+    /*  40 */ "KwStatus",
+    /*  41 */ "KwIfFeature",
+    /*  42 */ "KwMandatory",
+    /*  43 */ "KwConfig",
+    /*  44 */ "KwRequireInstance",
+    /*  45 */ "KwOrderedBy",
+    /*  46 */ "KwDefault",
+    /*  47 */ "KwFractionDigits",
+    /*  48 */ "KwLength",
+    /*  49 */ "KwRange",
+    /*  50 */ "KwValue",
+    /*  51 */ "KwPosition",
+    /*  52 */ "KwEnum",
+    /*  53 */ "KwRevision",
+    /*  54 */ "KwRevisionDate",
+    /*  55 */ "KwNamespace",
+    /*  56 */ "RBrace",
+    /*  57 */ "DoubleQuotation",
+    /*  58 */ "DeviateArg",
+    /*  59 */ "Augment",
+    /*  60 */ "When",
+    /*  61 */ "StatusArg",
+    /*  62 */ "Input",
+    /*  63 */ "Output",
+    /*  64 */ "Or",
+    /*  65 */ "And",
+    /*  66 */ "Not",
+    /*  67 */ "LParen",
+    /*  68 */ "RParen",
+    /*  69 */ "Presence",
+    /*  70 */ "Must",
+    /*  71 */ "ErrorMinusMessage",
+    /*  72 */ "Units",
+    /*  73 */ "MandatoryArg",
+    /*  74 */ "OrderedByArg",
+    /*  75 */ "MaxMinusElements",
+    /*  76 */ "LBracket1Minus9RBracketLBracket0Minus9RBracketStar",
+    /*  77 */ "MinMinusElements",
+    /*  78 */ "FractionDigitsArg",
+    /*  79 */ "Path",
+    /*  80 */ "Pattern",
+    /*  81 */ "IntegerValue",
+    /*  82 */ "Or0",
+    /*  83 */ "DotDot",
+    /*  84 */ "Min",
+    /*  85 */ "Max",
+    /*  86 */ "MinusQuestLBracket0Minus9RBracketPlusDotLBracket0Minus9RBracketPlus",
+    /*  87 */ "MinusQuestLBracket0Minus9RBracketPlus",
+    /*  88 */ "Colon",
+    /*  89 */ "Semicolon",
+    /*  90 */ "Slash",
+    /*  91 */ "UriArg",
+    /*  92 */ "Organization",
+    /*  93 */ "Contact",
+    /*  94 */ "Description",
+    /*  95 */ "Reference",
+    /*  96 */ "DateArg",
+    /*  97 */ "Plus",
+    /*  98 */ "Escape",
+    /*  99 */ "NonAscii",
+    /* 100 */ "DQNoEscape",
+    /* 101 */ "SQEscapeSeqChar",
+    /* 102 */ "SQNoEscape",
+    /* 103 */ "SingleQuotation",
+    /* 104 */ "Identifier",
+    /* 105 */ "AsciiNoSemicolon",
+    /* 106 */ "AsciiNoBrace",
+    /* 107 */ "YangVersionArg",
+    /* 108 */ "Error",
 ];
 
 scanner! {
@@ -163,47 +166,48 @@ scanner! {
             token r"refine" => 35; // "KwRefine"
             token r"key" => 36; // "KwKey"
             token r"deviation" => 37; // "KwDeviation"
-            token r"yang\-version" => 38; // "KwYangVersion"
-            token r"status" => 39; // "KwStatus"
-            token r"if\-feature" => 40; // "KwIfFeature"
-            token r"mandatory" => 41; // "KwMandatory"
-            token r"config" => 42; // "KwConfig"
-            token r"require\-instance" => 43; // "KwRequireInstance"
-            token r"ordered\-by" => 44; // "KwOrderedBy"
-            token r"default" => 45; // "KwDefault"
-            token r"fraction\-digits" => 46; // "KwFractionDigits"
-            token r"length" => 47; // "KwLength"
-            token r"range" => 48; // "KwRange"
-            token r"value" => 49; // "KwValue"
-            token r"position" => 50; // "KwPosition"
-            token r"enum" => 51; // "KwEnum"
-            token r"revision" => 52; // "KwRevision"
-            token r"revision\-date" => 53; // "KwRevisionDate"
-            token r"namespace" => 54; // "KwNamespace"
-            token r"\}" => 55; // "RBrace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r"augment" => 57; // "Augment"
-            token r"when" => 58; // "When"
-            token r"input" => 60; // "Input"
-            token r"output" => 61; // "Output"
-            token r"presence" => 67; // "Presence"
-            token r"must" => 68; // "Must"
-            token r"error\-message" => 69; // "ErrorMinusMessage"
-            token r"units" => 70; // "Units"
-            token r"max\-elements" => 73; // "MaxMinusElements"
-            token r"[1-9][0-9]*" => 74; // "LBracket1Minus9RBracketLBracket0Minus9RBracketStar"
-            token r"min\-elements" => 75; // "MinMinusElements"
-            token r"path" => 77; // "Path"
-            token r"pattern" => 78; // "Pattern"
-            token r";" => 86; // "Semicolon"
-            token r"organization" => 89; // "Organization"
-            token r"contact" => 90; // "Contact"
-            token r"description" => 91; // "Description"
-            token r"reference" => 92; // "Reference"
-            token r"\+" => 94; // "Plus"
-            token r"\u{27}" => 100; // "SingleQuotation"
-            token r"[a-zA-Z_][a-zA-Z0-9_\-\/\.:]*" => 101; // "Identifier"
-            token r"." => 105; // "Error"
+            token r"deviate" => 38; // "KwDeviate"
+            token r"yang\-version" => 39; // "KwYangVersion"
+            token r"status" => 40; // "KwStatus"
+            token r"if\-feature" => 41; // "KwIfFeature"
+            token r"mandatory" => 42; // "KwMandatory"
+            token r"config" => 43; // "KwConfig"
+            token r"require\-instance" => 44; // "KwRequireInstance"
+            token r"ordered\-by" => 45; // "KwOrderedBy"
+            token r"default" => 46; // "KwDefault"
+            token r"fraction\-digits" => 47; // "KwFractionDigits"
+            token r"length" => 48; // "KwLength"
+            token r"range" => 49; // "KwRange"
+            token r"value" => 50; // "KwValue"
+            token r"position" => 51; // "KwPosition"
+            token r"enum" => 52; // "KwEnum"
+            token r"revision" => 53; // "KwRevision"
+            token r"revision\-date" => 54; // "KwRevisionDate"
+            token r"namespace" => 55; // "KwNamespace"
+            token r"\}" => 56; // "RBrace"
+            token r#"""# => 57; // "DoubleQuotation"
+            token r"augment" => 59; // "Augment"
+            token r"when" => 60; // "When"
+            token r"input" => 62; // "Input"
+            token r"output" => 63; // "Output"
+            token r"presence" => 69; // "Presence"
+            token r"must" => 70; // "Must"
+            token r"error\-message" => 71; // "ErrorMinusMessage"
+            token r"units" => 72; // "Units"
+            token r"max\-elements" => 75; // "MaxMinusElements"
+            token r"[1-9][0-9]*" => 76; // "LBracket1Minus9RBracketLBracket0Minus9RBracketStar"
+            token r"min\-elements" => 77; // "MinMinusElements"
+            token r"path" => 79; // "Path"
+            token r"pattern" => 80; // "Pattern"
+            token r";" => 89; // "Semicolon"
+            token r"organization" => 92; // "Organization"
+            token r"contact" => 93; // "Contact"
+            token r"description" => 94; // "Description"
+            token r"reference" => 95; // "Reference"
+            token r"\+" => 97; // "Plus"
+            token r"\u{27}" => 103; // "SingleQuotation"
+            token r"[a-zA-Z_][a-zA-Z0-9_\-\/\.:]*" => 104; // "Identifier"
+            token r"." => 108; // "Error"
             on 8 enter Keyword;
             on 9 enter Keyword;
             on 10 enter Keyword;
@@ -233,199 +237,212 @@ scanner! {
             on 34 enter Keyword;
             on 35 enter Keyword;
             on 36 enter Keyword;
-            on 37 enter Keyword;
-            on 38 enter YVersion;
-            on 39 enter Status;
-            on 40 enter IfFeature;
-            on 41 enter Mandatory;
+            on 38 enter Deviate;
+            on 39 enter YVersion;
+            on 40 enter Status;
+            on 41 enter IfFeature;
             on 42 enter Mandatory;
             on 43 enter Mandatory;
-            on 44 enter Ordered;
-            on 45 enter Default;
-            on 46 enter Fraction;
-            on 47 enter Range;
+            on 44 enter Mandatory;
+            on 45 enter Ordered;
+            on 46 enter Default;
+            on 47 enter Fraction;
             on 48 enter Range;
-            on 49 enter Value;
+            on 49 enter Range;
             on 50 enter Value;
-            on 51 enter Enum;
-            on 52 enter Revision;
+            on 51 enter Value;
+            on 52 enter Enum;
             on 53 enter Revision;
-            on 54 enter Uri;
-            on 56 push DQString;
-            on 100 push SQString;
+            on 54 enter Revision;
+            on 55 enter Uri;
+            on 57 push DQString;
+            on 103 push SQString;
         }
         mode DQString {
-            token r#"""# => 56; // "DoubleQuotation"
-            token r"\u{5C}" => 95; // "Escape"
-            token r"[\n\r\u{80}-\u{D7FF}\u{E000}-\u{10FFFF}]" => 96; // "NonAscii"
-            token r"[ \n\r\t\u{21}\u{23}-\u{5B}\u{5D}-\u{7E}]+" => 97; // "DQNoEscape"
-            token r"." => 105; // "Error"
-            on 56 pop;
-            on 95 push Esc;
+            token r#"""# => 57; // "DoubleQuotation"
+            token r"\u{5C}" => 98; // "Escape"
+            token r"[\n\r\u{80}-\u{D7FF}\u{E000}-\u{10FFFF}]" => 99; // "NonAscii"
+            token r"[ \n\r\t\u{21}\u{23}-\u{5B}\u{5D}-\u{7E}]+" => 100; // "DQNoEscape"
+            token r"." => 108; // "Error"
+            on 57 pop;
+            on 98 push Esc;
         }
         mode SQString {
-            token r"\u{5C}" => 95; // "Escape"
-            token r"[\n\r\u{80}-\u{D7FF}\u{E000}-\u{10FFFF}]" => 96; // "NonAscii"
-            token r"[ \t\u{21}-\u{26}\u{28}-\u{5B}\u{5D}-\u{7E}]+" => 99; // "SQNoEscape"
-            token r"\u{27}" => 100; // "SingleQuotation"
-            token r"." => 105; // "Error"
-            on 95 push Esc;
-            on 100 pop;
+            token r"\u{5C}" => 98; // "Escape"
+            token r"[\n\r\u{80}-\u{D7FF}\u{E000}-\u{10FFFF}]" => 99; // "NonAscii"
+            token r"[ \t\u{21}-\u{26}\u{28}-\u{5B}\u{5D}-\u{7E}]+" => 102; // "SQNoEscape"
+            token r"\u{27}" => 103; // "SingleQuotation"
+            token r"." => 108; // "Error"
+            on 98 push Esc;
+            on 103 pop;
         }
         mode Esc {
             token r"n" => 6; // "EscN"
             token r"t" => 7; // "EscT"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r"\u{5C}" => 95; // "Escape"
-            token r"." => 98; // "SQEscapeSeqChar"
-            token r"." => 105; // "Error"
+            token r#"""# => 57; // "DoubleQuotation"
+            token r"\u{5C}" => 98; // "Escape"
+            token r"." => 101; // "SQEscapeSeqChar"
+            token r"." => 108; // "Error"
             on 6 pop;
             on 7 pop;
-            on 56 pop;
-            on 95 pop;
+            on 57 pop;
             on 98 pop;
+            on 101 pop;
         }
         mode Keyword {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
             token r"\{" => 5; // "LBrace"
-            token r"\}" => 55; // "RBrace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r":" => 85; // "Colon"
-            token r";" => 86; // "Semicolon"
-            token r"/" => 87; // "Slash"
-            token r"[a-zA-Z_][a-zA-Z0-9_\-\/\.:]*" => 101; // "Identifier"
-            token r"." => 105; // "Error"
+            token r"\}" => 56; // "RBrace"
+            token r#"""# => 57; // "DoubleQuotation"
+            token r":" => 88; // "Colon"
+            token r";" => 89; // "Semicolon"
+            token r"/" => 90; // "Slash"
+            token r"[a-zA-Z_][a-zA-Z0-9_\-\/\.:]*" => 104; // "Identifier"
+            token r"." => 108; // "Error"
             on 5 enter INITIAL;
-            on 86 enter INITIAL;
+            on 89 enter INITIAL;
         }
         mode YVersion {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r";" => 86; // "Semicolon"
-            token r"1.1|1" => 104; // "YangVersionArg"
-            token r"." => 105; // "Error"
-            on 86 enter INITIAL;
+            token r#"""# => 57; // "DoubleQuotation"
+            token r";" => 89; // "Semicolon"
+            token r"1.1|1" => 107; // "YangVersionArg"
+            token r"." => 108; // "Error"
+            on 89 enter INITIAL;
         }
         mode Range {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r"\|" => 80; // "Or0"
-            token r"\.\." => 81; // "DotDot"
-            token r"min" => 82; // "Min"
-            token r"max" => 83; // "Max"
-            token r"-?[0-9]+" => 84; // "MinusQuestLBracket0Minus9RBracketPlus"
-            token r";" => 86; // "Semicolon"
-            token r"." => 105; // "Error"
-            on 86 enter INITIAL;
+            token r#"""# => 57; // "DoubleQuotation"
+            token r"\|" => 82; // "Or0"
+            token r"\.\." => 83; // "DotDot"
+            token r"min" => 84; // "Min"
+            token r"max" => 85; // "Max"
+            token r"-?[0-9]+\.[0-9]+" => 86; // "MinusQuestLBracket0Minus9RBracketPlusDotLBracket0Minus9RBracketPlus"
+            token r"-?[0-9]+" => 87; // "MinusQuestLBracket0Minus9RBracketPlus"
+            token r";" => 89; // "Semicolon"
+            token r"." => 108; // "Error"
+            on 89 enter INITIAL;
         }
         mode Enum {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
             token r"\{" => 5; // "LBrace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r";" => 86; // "Semicolon"
-            token r"[\t\u{21}\u{23}-\u{3a}\u{3c}-\u{5b}\u{5d}-\u{7a}\u{7c}\u{7e}]+" => 103; // "AsciiNoBrace"
-            token r"." => 105; // "Error"
+            token r#"""# => 57; // "DoubleQuotation"
+            token r";" => 89; // "Semicolon"
+            token r"[\t\u{21}\u{23}-\u{3a}\u{3c}-\u{5b}\u{5d}-\u{7a}\u{7c}\u{7e}]+" => 106; // "AsciiNoBrace"
+            token r"." => 108; // "Error"
             on 5 enter INITIAL;
-            on 86 enter INITIAL;
+            on 89 enter INITIAL;
         }
         mode Default {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r";" => 86; // "Semicolon"
-            token r"[ \t\u{21}\u{23}-\u{3a}\u{3c}-\u{5b}\u{5d}-\u{7e}]+" => 102; // "AsciiNoSemicolon"
-            token r"." => 105; // "Error"
-            on 86 enter INITIAL;
+            token r#"""# => 57; // "DoubleQuotation"
+            token r";" => 89; // "Semicolon"
+            token r"[ \t\u{21}\u{23}-\u{3a}\u{3c}-\u{5b}\u{5d}-\u{7e}]+" => 105; // "AsciiNoSemicolon"
+            token r"." => 108; // "Error"
+            on 89 enter INITIAL;
         }
         mode Revision {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
             token r"\{" => 5; // "LBrace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r";" => 86; // "Semicolon"
-            token r"\d{4}-\d{2}-\d{2}" => 93; // "DateArg"
-            token r"." => 105; // "Error"
+            token r#"""# => 57; // "DoubleQuotation"
+            token r";" => 89; // "Semicolon"
+            token r"\d{4}-\d{2}-\d{2}" => 96; // "DateArg"
+            token r"." => 108; // "Error"
             on 5 enter INITIAL;
-            on 86 enter INITIAL;
+            on 89 enter INITIAL;
         }
         mode Mandatory {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r"true|false" => 71; // "MandatoryArg"
-            token r";" => 86; // "Semicolon"
-            token r"." => 105; // "Error"
-            on 86 enter INITIAL;
+            token r#"""# => 57; // "DoubleQuotation"
+            token r"true|false" => 73; // "MandatoryArg"
+            token r";" => 89; // "Semicolon"
+            token r"." => 108; // "Error"
+            on 89 enter INITIAL;
         }
         mode IfFeature {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r"or" => 62; // "Or"
-            token r"and" => 63; // "And"
-            token r"not" => 64; // "Not"
-            token r"\(" => 65; // "LParen"
-            token r"\)" => 66; // "RParen"
-            token r":" => 85; // "Colon"
-            token r";" => 86; // "Semicolon"
-            token r"/" => 87; // "Slash"
-            token r"[a-zA-Z_][a-zA-Z0-9_\-\/\.:]*" => 101; // "Identifier"
-            token r"." => 105; // "Error"
-            on 86 enter INITIAL;
+            token r#"""# => 57; // "DoubleQuotation"
+            token r"or" => 64; // "Or"
+            token r"and" => 65; // "And"
+            token r"not" => 66; // "Not"
+            token r"\(" => 67; // "LParen"
+            token r"\)" => 68; // "RParen"
+            token r":" => 88; // "Colon"
+            token r";" => 89; // "Semicolon"
+            token r"/" => 90; // "Slash"
+            token r"[a-zA-Z_][a-zA-Z0-9_\-\/\.:]*" => 104; // "Identifier"
+            token r"." => 108; // "Error"
+            on 89 enter INITIAL;
         }
         mode Status {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r"current|obsolete|deprecated" => 59; // "StatusArg"
-            token r";" => 86; // "Semicolon"
-            token r"." => 105; // "Error"
-            on 86 enter INITIAL;
+            token r#"""# => 57; // "DoubleQuotation"
+            token r"current|obsolete|deprecated" => 61; // "StatusArg"
+            token r";" => 89; // "Semicolon"
+            token r"." => 108; // "Error"
+            on 89 enter INITIAL;
+        }
+        mode Deviate {
+            token r"\r\n|\r|\n" => 1; // "Newline"
+            token r"[\s--\r\n]+" => 2; // "Whitespace"
+            token r"\{" => 5; // "LBrace"
+            token r#"""# => 57; // "DoubleQuotation"
+            token r"not-supported|add|replace|delete" => 58; // "DeviateArg"
+            token r";" => 89; // "Semicolon"
+            token r"." => 108; // "Error"
+            on 5 enter INITIAL;
+            on 89 enter INITIAL;
         }
         mode Value {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r"[0-9]+" => 79; // "IntegerValue"
-            token r";" => 86; // "Semicolon"
-            token r"." => 105; // "Error"
-            on 86 enter INITIAL;
+            token r#"""# => 57; // "DoubleQuotation"
+            token r"[0-9]+" => 81; // "IntegerValue"
+            token r";" => 89; // "Semicolon"
+            token r"." => 108; // "Error"
+            on 89 enter INITIAL;
         }
         mode Fraction {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
-            token r"1|2|3|4|5|6|7|8|9|10|11|12|13|14|15|16|17|18|" => 76; // "FractionDigitsArg"
-            token r";" => 86; // "Semicolon"
-            token r"." => 105; // "Error"
-            on 86 enter INITIAL;
+            token r"1|2|3|4|5|6|7|8|9|10|11|12|13|14|15|16|17|18|" => 78; // "FractionDigitsArg"
+            token r";" => 89; // "Semicolon"
+            token r"." => 108; // "Error"
+            on 89 enter INITIAL;
         }
         mode Ordered {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r"user|system" => 72; // "OrderedByArg"
-            token r";" => 86; // "Semicolon"
-            token r"." => 105; // "Error"
-            on 86 enter INITIAL;
+            token r#"""# => 57; // "DoubleQuotation"
+            token r"user|system" => 74; // "OrderedByArg"
+            token r";" => 89; // "Semicolon"
+            token r"." => 108; // "Error"
+            on 89 enter INITIAL;
         }
         mode Uri {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
-            token r#"""# => 56; // "DoubleQuotation"
-            token r";" => 86; // "Semicolon"
-            token r"urn:[a-zA-Z0-9\-\.:]+" => 88; // "UriArg"
-            token r"." => 105; // "Error"
-            on 86 enter INITIAL;
+            token r#"""# => 57; // "DoubleQuotation"
+            token r";" => 89; // "Semicolon"
+            token r"urn:[a-zA-Z0-9\-\.:]+" => 91; // "UriArg"
+            token r"." => 108; // "Error"
+            on 89 enter INITIAL;
         }
     }
 }
 
 const MAX_K: usize = 2;
-pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 17] = &[
+pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 18] = &[
+    &[],
     &[],
     &[],
     &[],
@@ -445,7 +462,7 @@ pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 17] =
     &[],
 ];
 
-pub const NON_TERMINALS: &[&str; 277] = &[
+pub const NON_TERMINALS: &[&str; 289] = &[
     /*   0 */ "AbsoluteSchemaNodeid",
     /*   1 */ "ActionStmt",
     /*   2 */ "ActionStmtList",
@@ -502,239 +519,251 @@ pub const NON_TERMINALS: &[&str; 277] = &[
     /*  53 */ "DefaultStmtSuffix",
     /*  54 */ "DescendantSchemaNodeid",
     /*  55 */ "DescriptionStmt",
-    /*  56 */ "DeviationStmt",
-    /*  57 */ "DoubleQuotation",
-    /*  58 */ "EnumArgStr",
-    /*  59 */ "EnumStmt",
-    /*  60 */ "EnumStmtList",
-    /*  61 */ "EnumStmtListGroup",
-    /*  62 */ "EnumStmtSuffix",
-    /*  63 */ "ErrorMessage",
-    /*  64 */ "EscN",
-    /*  65 */ "EscT",
-    /*  66 */ "Escape",
-    /*  67 */ "ExtensionStmt",
-    /*  68 */ "ExtensionStmtList",
-    /*  69 */ "ExtensionStmtListGroup",
-    /*  70 */ "ExtensionStmtSuffix",
-    /*  71 */ "FeatureStmt",
-    /*  72 */ "FeatureStmtList",
-    /*  73 */ "FeatureStmtListGroup",
-    /*  74 */ "FeatureStmtSuffix",
-    /*  75 */ "FractionDigitsArg",
-    /*  76 */ "FractionDigitsStmt",
-    /*  77 */ "GroupingStmt",
-    /*  78 */ "GroupingStmtList",
-    /*  79 */ "GroupingStmtListGroup",
-    /*  80 */ "Identifier",
-    /*  81 */ "IdentifierArgStr",
-    /*  82 */ "IdentifierRef",
-    /*  83 */ "IdentifierRefArgStr",
-    /*  84 */ "IdentifierRefOpt",
-    /*  85 */ "IdentityStmt",
-    /*  86 */ "IdentityStmtList",
-    /*  87 */ "IdentityStmtListGroup",
-    /*  88 */ "IfFeatureExpr",
-    /*  89 */ "IfFeatureExprOpt",
-    /*  90 */ "IfFeatureExprStr",
-    /*  91 */ "IfFeatureFactor",
-    /*  92 */ "IfFeatureStmt",
-    /*  93 */ "IfFeatureTerm",
-    /*  94 */ "IfFeatureTermOpt",
-    /*  95 */ "ImportStmt",
-    /*  96 */ "ImportStmtList",
-    /*  97 */ "ImportStmtListGroup",
-    /*  98 */ "IncludeStmt",
-    /*  99 */ "IncludeStmtList",
-    /* 100 */ "IncludeStmtListGroup",
-    /* 101 */ "IncludeStmtSuffix",
-    /* 102 */ "InputStmt",
-    /* 103 */ "InputStmtList",
-    /* 104 */ "IntegerValue",
-    /* 105 */ "IntegerValueStr",
-    /* 106 */ "KeyArg",
-    /* 107 */ "KeyArgOpt",
-    /* 108 */ "KeyArgStr",
-    /* 109 */ "KeyStmt",
-    /* 110 */ "KwAction",
-    /* 111 */ "KwAnydata",
-    /* 112 */ "KwAnyxml",
-    /* 113 */ "KwArgument",
-    /* 114 */ "KwBase",
-    /* 115 */ "KwBelongsTo",
-    /* 116 */ "KwBit",
-    /* 117 */ "KwCase",
-    /* 118 */ "KwChoice",
-    /* 119 */ "KwConfig",
-    /* 120 */ "KwContainer",
-    /* 121 */ "KwDefault",
-    /* 122 */ "KwDeviation",
-    /* 123 */ "KwEnum",
-    /* 124 */ "KwExtension",
-    /* 125 */ "KwFeature",
-    /* 126 */ "KwFractionDigits",
-    /* 127 */ "KwGrouping",
-    /* 128 */ "KwIdentity",
-    /* 129 */ "KwIfFeature",
-    /* 130 */ "KwImport",
-    /* 131 */ "KwInclude",
-    /* 132 */ "KwKey",
-    /* 133 */ "KwLeaf",
-    /* 134 */ "KwLeafList",
-    /* 135 */ "KwLength",
-    /* 136 */ "KwList",
-    /* 137 */ "KwMandatory",
-    /* 138 */ "KwModule",
-    /* 139 */ "KwNamespace",
-    /* 140 */ "KwNotification",
-    /* 141 */ "KwOrderedBy",
-    /* 142 */ "KwPosition",
-    /* 143 */ "KwPrefix",
-    /* 144 */ "KwRange",
-    /* 145 */ "KwRefine",
-    /* 146 */ "KwRequireInstance",
-    /* 147 */ "KwRevision",
-    /* 148 */ "KwRevisionDate",
-    /* 149 */ "KwRpc",
-    /* 150 */ "KwStatus",
-    /* 151 */ "KwSubmodule",
-    /* 152 */ "KwType",
-    /* 153 */ "KwTypedef",
-    /* 154 */ "KwUses",
-    /* 155 */ "KwValue",
-    /* 156 */ "KwYangVersion",
-    /* 157 */ "LBrace",
-    /* 158 */ "LeafListStmt",
-    /* 159 */ "LeafListStmtList",
-    /* 160 */ "LeafListStmtListGroup",
-    /* 161 */ "LeafStmt",
-    /* 162 */ "LeafStmtList",
-    /* 163 */ "LeafStmtListGroup",
-    /* 164 */ "LeafrefSpecification",
-    /* 165 */ "LengthStmt",
-    /* 166 */ "LinkageStmts",
-    /* 167 */ "ListStmt",
-    /* 168 */ "ListStmtList",
-    /* 169 */ "ListStmtListGroup",
-    /* 170 */ "MandatoryArg",
-    /* 171 */ "MandatoryArgStr",
-    /* 172 */ "MandatoryStmt",
-    /* 173 */ "MaxElementsStmt",
-    /* 174 */ "MetaStmts",
-    /* 175 */ "MinElementsStmt",
-    /* 176 */ "ModuleHeaderStmts",
-    /* 177 */ "ModuleStmt",
-    /* 178 */ "ModuleStmtList",
-    /* 179 */ "ModuleStmtList0",
-    /* 180 */ "ModuleStmtList1",
-    /* 181 */ "ModuleStmtList2",
-    /* 182 */ "ModuleStmtList3",
-    /* 183 */ "MustStmt",
-    /* 184 */ "MustStmtList",
-    /* 185 */ "MustStmtListGroup",
-    /* 186 */ "MustStmtSuffix",
-    /* 187 */ "NamespaceStmt",
-    /* 188 */ "NonAscii",
-    /* 189 */ "NotificationStmt",
-    /* 190 */ "NotificationStmtList",
-    /* 191 */ "NotificationStmtListGroup",
-    /* 192 */ "OrderedByArg",
-    /* 193 */ "OrderedByArgStr",
-    /* 194 */ "OrderedByStmt",
-    /* 195 */ "OrganizationStmt",
-    /* 196 */ "OutputStmt",
-    /* 197 */ "OutputStmtList",
-    /* 198 */ "PathStmt",
-    /* 199 */ "PatternStmt",
-    /* 200 */ "PositionStmt",
-    /* 201 */ "PrefixStmt",
-    /* 202 */ "PresenceStmt",
-    /* 203 */ "RangeArg",
-    /* 204 */ "RangeArgOpt",
-    /* 205 */ "RangeArgStr",
-    /* 206 */ "RangeBoundary",
-    /* 207 */ "RangePart",
-    /* 208 */ "RangePartOpt",
-    /* 209 */ "RangeStmt",
-    /* 210 */ "ReferenceStmt",
-    /* 211 */ "RefineArg",
-    /* 212 */ "RefineArgStr",
-    /* 213 */ "RefineStmt",
-    /* 214 */ "RefineStmtList",
-    /* 215 */ "RefineStmtListGroup",
-    /* 216 */ "RefineStmtSuffix",
-    /* 217 */ "RequireInstanceArgStr",
-    /* 218 */ "RequireInstanceStmt",
-    /* 219 */ "RevisionDateStmt",
-    /* 220 */ "RevisionStmt",
-    /* 221 */ "RevisionStmtList",
-    /* 222 */ "RevisionStmtListGroup",
-    /* 223 */ "RpcStmt",
-    /* 224 */ "RpcStmtList",
-    /* 225 */ "RpcStmtListGroup",
-    /* 226 */ "RpcStmtSuffix",
-    /* 227 */ "SQChar",
-    /* 228 */ "SQEscapeSeqChar",
-    /* 229 */ "SQEscaped",
-    /* 230 */ "SQNoEscape",
-    /* 231 */ "SQString",
-    /* 232 */ "SQStringList",
-    /* 233 */ "SQUnescaped",
-    /* 234 */ "Semicolon",
-    /* 235 */ "ShortCaseStmt",
-    /* 236 */ "SingleQuotation",
-    /* 237 */ "StatusArg",
-    /* 238 */ "StatusArgStr",
-    /* 239 */ "StatusStmt",
-    /* 240 */ "StringRestrictions",
-    /* 241 */ "SubmoduleHeaderStmts",
-    /* 242 */ "SubmoduleStmt",
-    /* 243 */ "SubmoduleStmtList",
-    /* 244 */ "SubmoduleStmtList0",
-    /* 245 */ "SubmoduleStmtList1",
-    /* 246 */ "SubmoduleStmtList2",
-    /* 247 */ "SubmoduleStmtList3",
-    /* 248 */ "TypeStmt",
-    /* 249 */ "TypeStmtList",
-    /* 250 */ "TypeStmtListGroup",
-    /* 251 */ "TypeStmtSuffix",
-    /* 252 */ "TypedefStmt",
-    /* 253 */ "TypedefStmtList",
-    /* 254 */ "TypedefStmtListGroup",
-    /* 255 */ "UnitsStmt",
-    /* 256 */ "UnknownStmt",
-    /* 257 */ "UnknownStmtList",
-    /* 258 */ "UnknownStmtListGroup",
-    /* 259 */ "UnknownStmtSuffix",
-    /* 260 */ "UriArg",
-    /* 261 */ "UriStr",
-    /* 262 */ "UsesStmt",
-    /* 263 */ "UsesStmtList",
-    /* 264 */ "UsesStmtListGroup",
-    /* 265 */ "UsesStmtSuffix",
-    /* 266 */ "ValueStmt",
-    /* 267 */ "WhenStmt",
-    /* 268 */ "WhenStmtList",
-    /* 269 */ "WhenStmtListGroup",
-    /* 270 */ "WhenStmtSuffix",
-    /* 271 */ "Yang",
-    /* 272 */ "YangVersionArg",
-    /* 273 */ "YangVersionArgStr",
-    /* 274 */ "YangVersionStmt",
-    /* 275 */ "Ystring",
-    /* 276 */ "YstringOpt",
+    /*  56 */ "DeviateArg",
+    /*  57 */ "DeviateArgStr",
+    /*  58 */ "DeviateStmt",
+    /*  59 */ "DeviateStmtList",
+    /*  60 */ "DeviateStmtListGroup",
+    /*  61 */ "DeviateStmtSuffix",
+    /*  62 */ "DeviationStmt",
+    /*  63 */ "DeviationStmtList",
+    /*  64 */ "DeviationStmtListGroup",
+    /*  65 */ "DeviationStmtSuffix",
+    /*  66 */ "DoubleQuotation",
+    /*  67 */ "EnumArgStr",
+    /*  68 */ "EnumStmt",
+    /*  69 */ "EnumStmtList",
+    /*  70 */ "EnumStmtListGroup",
+    /*  71 */ "EnumStmtSuffix",
+    /*  72 */ "ErrorMessage",
+    /*  73 */ "EscN",
+    /*  74 */ "EscT",
+    /*  75 */ "Escape",
+    /*  76 */ "ExtensionStmt",
+    /*  77 */ "ExtensionStmtList",
+    /*  78 */ "ExtensionStmtListGroup",
+    /*  79 */ "ExtensionStmtSuffix",
+    /*  80 */ "FeatureStmt",
+    /*  81 */ "FeatureStmtList",
+    /*  82 */ "FeatureStmtListGroup",
+    /*  83 */ "FeatureStmtSuffix",
+    /*  84 */ "FractionDigitsArg",
+    /*  85 */ "FractionDigitsStmt",
+    /*  86 */ "GroupingStmt",
+    /*  87 */ "GroupingStmtList",
+    /*  88 */ "GroupingStmtListGroup",
+    /*  89 */ "Identifier",
+    /*  90 */ "IdentifierArgStr",
+    /*  91 */ "IdentifierRef",
+    /*  92 */ "IdentifierRefArgStr",
+    /*  93 */ "IdentifierRefOpt",
+    /*  94 */ "IdentityStmt",
+    /*  95 */ "IdentityStmtList",
+    /*  96 */ "IdentityStmtListGroup",
+    /*  97 */ "IfFeatureExpr",
+    /*  98 */ "IfFeatureExprOpt",
+    /*  99 */ "IfFeatureExprStr",
+    /* 100 */ "IfFeatureFactor",
+    /* 101 */ "IfFeatureStmt",
+    /* 102 */ "IfFeatureTerm",
+    /* 103 */ "IfFeatureTermOpt",
+    /* 104 */ "ImportStmt",
+    /* 105 */ "ImportStmtList",
+    /* 106 */ "ImportStmtListGroup",
+    /* 107 */ "IncludeStmt",
+    /* 108 */ "IncludeStmtList",
+    /* 109 */ "IncludeStmtListGroup",
+    /* 110 */ "IncludeStmtSuffix",
+    /* 111 */ "InputStmt",
+    /* 112 */ "InputStmtList",
+    /* 113 */ "InputStmtListGroup",
+    /* 114 */ "IntegerValue",
+    /* 115 */ "IntegerValueStr",
+    /* 116 */ "KeyArg",
+    /* 117 */ "KeyArgOpt",
+    /* 118 */ "KeyArgStr",
+    /* 119 */ "KeyStmt",
+    /* 120 */ "KwAction",
+    /* 121 */ "KwAnydata",
+    /* 122 */ "KwAnyxml",
+    /* 123 */ "KwArgument",
+    /* 124 */ "KwBase",
+    /* 125 */ "KwBelongsTo",
+    /* 126 */ "KwBit",
+    /* 127 */ "KwCase",
+    /* 128 */ "KwChoice",
+    /* 129 */ "KwConfig",
+    /* 130 */ "KwContainer",
+    /* 131 */ "KwDefault",
+    /* 132 */ "KwDeviate",
+    /* 133 */ "KwDeviation",
+    /* 134 */ "KwEnum",
+    /* 135 */ "KwExtension",
+    /* 136 */ "KwFeature",
+    /* 137 */ "KwFractionDigits",
+    /* 138 */ "KwGrouping",
+    /* 139 */ "KwIdentity",
+    /* 140 */ "KwIfFeature",
+    /* 141 */ "KwImport",
+    /* 142 */ "KwInclude",
+    /* 143 */ "KwKey",
+    /* 144 */ "KwLeaf",
+    /* 145 */ "KwLeafList",
+    /* 146 */ "KwLength",
+    /* 147 */ "KwList",
+    /* 148 */ "KwMandatory",
+    /* 149 */ "KwModule",
+    /* 150 */ "KwNamespace",
+    /* 151 */ "KwNotification",
+    /* 152 */ "KwOrderedBy",
+    /* 153 */ "KwPosition",
+    /* 154 */ "KwPrefix",
+    /* 155 */ "KwRange",
+    /* 156 */ "KwRefine",
+    /* 157 */ "KwRequireInstance",
+    /* 158 */ "KwRevision",
+    /* 159 */ "KwRevisionDate",
+    /* 160 */ "KwRpc",
+    /* 161 */ "KwStatus",
+    /* 162 */ "KwSubmodule",
+    /* 163 */ "KwType",
+    /* 164 */ "KwTypedef",
+    /* 165 */ "KwUses",
+    /* 166 */ "KwValue",
+    /* 167 */ "KwYangVersion",
+    /* 168 */ "LBrace",
+    /* 169 */ "LeafListStmt",
+    /* 170 */ "LeafListStmtList",
+    /* 171 */ "LeafListStmtListGroup",
+    /* 172 */ "LeafStmt",
+    /* 173 */ "LeafStmtList",
+    /* 174 */ "LeafStmtListGroup",
+    /* 175 */ "LeafrefSpecification",
+    /* 176 */ "LengthStmt",
+    /* 177 */ "LinkageStmts",
+    /* 178 */ "ListStmt",
+    /* 179 */ "ListStmtList",
+    /* 180 */ "ListStmtListGroup",
+    /* 181 */ "MandatoryArg",
+    /* 182 */ "MandatoryArgStr",
+    /* 183 */ "MandatoryStmt",
+    /* 184 */ "MaxElementsStmt",
+    /* 185 */ "MetaStmts",
+    /* 186 */ "MinElementsStmt",
+    /* 187 */ "ModuleHeaderStmts",
+    /* 188 */ "ModuleStmt",
+    /* 189 */ "ModuleStmtList",
+    /* 190 */ "ModuleStmtList0",
+    /* 191 */ "ModuleStmtList1",
+    /* 192 */ "ModuleStmtList2",
+    /* 193 */ "ModuleStmtList3",
+    /* 194 */ "MustStmt",
+    /* 195 */ "MustStmtList",
+    /* 196 */ "MustStmtListGroup",
+    /* 197 */ "MustStmtSuffix",
+    /* 198 */ "NamespaceStmt",
+    /* 199 */ "NonAscii",
+    /* 200 */ "NotificationStmt",
+    /* 201 */ "NotificationStmtList",
+    /* 202 */ "NotificationStmtListGroup",
+    /* 203 */ "OrderedByArg",
+    /* 204 */ "OrderedByArgStr",
+    /* 205 */ "OrderedByStmt",
+    /* 206 */ "OrganizationStmt",
+    /* 207 */ "OutputStmt",
+    /* 208 */ "OutputStmtList",
+    /* 209 */ "OutputStmtListGroup",
+    /* 210 */ "PathStmt",
+    /* 211 */ "PatternStmt",
+    /* 212 */ "PositionStmt",
+    /* 213 */ "PrefixStmt",
+    /* 214 */ "PresenceStmt",
+    /* 215 */ "RangeArg",
+    /* 216 */ "RangeArgOpt",
+    /* 217 */ "RangeArgStr",
+    /* 218 */ "RangeBoundary",
+    /* 219 */ "RangePart",
+    /* 220 */ "RangePartOpt",
+    /* 221 */ "RangeStmt",
+    /* 222 */ "ReferenceStmt",
+    /* 223 */ "RefineArg",
+    /* 224 */ "RefineArgStr",
+    /* 225 */ "RefineStmt",
+    /* 226 */ "RefineStmtList",
+    /* 227 */ "RefineStmtListGroup",
+    /* 228 */ "RefineStmtSuffix",
+    /* 229 */ "RequireInstanceArgStr",
+    /* 230 */ "RequireInstanceStmt",
+    /* 231 */ "RevisionDateStmt",
+    /* 232 */ "RevisionStmt",
+    /* 233 */ "RevisionStmtList",
+    /* 234 */ "RevisionStmtListGroup",
+    /* 235 */ "RpcStmt",
+    /* 236 */ "RpcStmtList",
+    /* 237 */ "RpcStmtListGroup",
+    /* 238 */ "RpcStmtSuffix",
+    /* 239 */ "SQChar",
+    /* 240 */ "SQEscapeSeqChar",
+    /* 241 */ "SQEscaped",
+    /* 242 */ "SQNoEscape",
+    /* 243 */ "SQString",
+    /* 244 */ "SQStringList",
+    /* 245 */ "SQUnescaped",
+    /* 246 */ "Semicolon",
+    /* 247 */ "ShortCaseStmt",
+    /* 248 */ "SingleQuotation",
+    /* 249 */ "StatusArg",
+    /* 250 */ "StatusArgStr",
+    /* 251 */ "StatusStmt",
+    /* 252 */ "StringRestrictions",
+    /* 253 */ "SubmoduleHeaderStmts",
+    /* 254 */ "SubmoduleStmt",
+    /* 255 */ "SubmoduleStmtList",
+    /* 256 */ "SubmoduleStmtList0",
+    /* 257 */ "SubmoduleStmtList1",
+    /* 258 */ "SubmoduleStmtList2",
+    /* 259 */ "SubmoduleStmtList3",
+    /* 260 */ "TypeStmt",
+    /* 261 */ "TypeStmtList",
+    /* 262 */ "TypeStmtListGroup",
+    /* 263 */ "TypeStmtSuffix",
+    /* 264 */ "TypedefStmt",
+    /* 265 */ "TypedefStmtList",
+    /* 266 */ "TypedefStmtListGroup",
+    /* 267 */ "UnitsStmt",
+    /* 268 */ "UnknownStmt",
+    /* 269 */ "UnknownStmtList",
+    /* 270 */ "UnknownStmtListGroup",
+    /* 271 */ "UnknownStmtSuffix",
+    /* 272 */ "UriArg",
+    /* 273 */ "UriStr",
+    /* 274 */ "UsesStmt",
+    /* 275 */ "UsesStmtList",
+    /* 276 */ "UsesStmtListGroup",
+    /* 277 */ "UsesStmtSuffix",
+    /* 278 */ "ValueStmt",
+    /* 279 */ "WhenStmt",
+    /* 280 */ "WhenStmtList",
+    /* 281 */ "WhenStmtListGroup",
+    /* 282 */ "WhenStmtSuffix",
+    /* 283 */ "Yang",
+    /* 284 */ "YangVersionArg",
+    /* 285 */ "YangVersionArgStr",
+    /* 286 */ "YangVersionStmt",
+    /* 287 */ "Ystring",
+    /* 288 */ "YstringOpt",
 ];
 
-pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 289] = &[
     /* 0 - "AbsoluteSchemaNodeid" */
     LookaheadDFA {
-        prod0: 494,
+        prod0: 534,
         transitions: &[],
         k: 0,
     },
     /* 1 - "ActionStmt" */
     LookaheadDFA {
-        prod0: 268,
+        prod0: 297,
         transitions: &[],
         k: 0,
     },
@@ -742,13 +771,15 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 39, 1, 271),
-            Trans(0, 40, 1, 271),
-            Trans(0, 55, 2, 278),
-            Trans(0, 60, 1, 271),
-            Trans(0, 61, 1, 271),
-            Trans(0, 91, 1, 271),
-            Trans(0, 92, 1, 271),
+            Trans(0, 14, 1, 300),
+            Trans(0, 15, 1, 300),
+            Trans(0, 40, 1, 300),
+            Trans(0, 41, 1, 300),
+            Trans(0, 56, 2, 309),
+            Trans(0, 62, 1, 300),
+            Trans(0, 63, 1, 300),
+            Trans(0, 94, 1, 300),
+            Trans(0, 95, 1, 300),
         ],
         k: 1,
     },
@@ -756,24 +787,26 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 39, 2, 273),
-            Trans(0, 40, 1, 272),
-            Trans(0, 60, 5, 276),
-            Trans(0, 61, 6, 277),
-            Trans(0, 91, 3, 274),
-            Trans(0, 92, 4, 275),
+            Trans(0, 14, 5, 305),
+            Trans(0, 15, 6, 306),
+            Trans(0, 40, 2, 302),
+            Trans(0, 41, 1, 301),
+            Trans(0, 62, 7, 307),
+            Trans(0, 63, 8, 308),
+            Trans(0, 94, 3, 303),
+            Trans(0, 95, 4, 304),
         ],
         k: 1,
     },
     /* 4 - "ActionStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 270), Trans(0, 86, 1, 269)],
+        transitions: &[Trans(0, 5, 2, 299), Trans(0, 89, 1, 298)],
         k: 1,
     },
     /* 5 - "AnydataStmt" */
     LookaheadDFA {
-        prod0: 224,
+        prod0: 250,
         transitions: &[],
         k: 0,
     },
@@ -781,15 +814,15 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 39, 1, 227),
-            Trans(0, 40, 1, 227),
-            Trans(0, 41, 1, 227),
-            Trans(0, 42, 1, 227),
-            Trans(0, 55, 2, 236),
-            Trans(0, 58, 1, 227),
-            Trans(0, 68, 1, 227),
-            Trans(0, 91, 1, 227),
-            Trans(0, 92, 1, 227),
+            Trans(0, 40, 1, 253),
+            Trans(0, 41, 1, 253),
+            Trans(0, 42, 1, 253),
+            Trans(0, 43, 1, 253),
+            Trans(0, 56, 2, 262),
+            Trans(0, 60, 1, 253),
+            Trans(0, 70, 1, 253),
+            Trans(0, 94, 1, 253),
+            Trans(0, 95, 1, 253),
         ],
         k: 1,
     },
@@ -797,26 +830,26 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 39, 6, 233),
-            Trans(0, 40, 2, 229),
-            Trans(0, 41, 5, 232),
-            Trans(0, 42, 4, 231),
-            Trans(0, 58, 1, 228),
-            Trans(0, 68, 3, 230),
-            Trans(0, 91, 7, 234),
-            Trans(0, 92, 8, 235),
+            Trans(0, 40, 6, 259),
+            Trans(0, 41, 2, 255),
+            Trans(0, 42, 5, 258),
+            Trans(0, 43, 4, 257),
+            Trans(0, 60, 1, 254),
+            Trans(0, 70, 3, 256),
+            Trans(0, 94, 7, 260),
+            Trans(0, 95, 8, 261),
         ],
         k: 1,
     },
     /* 8 - "AnydataStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 226), Trans(0, 86, 1, 225)],
+        transitions: &[Trans(0, 5, 2, 252), Trans(0, 89, 1, 251)],
         k: 1,
     },
     /* 9 - "AnyxmlStmt" */
     LookaheadDFA {
-        prod0: 189,
+        prod0: 215,
         transitions: &[],
         k: 0,
     },
@@ -824,15 +857,15 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 39, 1, 192),
-            Trans(0, 40, 1, 192),
-            Trans(0, 41, 1, 192),
-            Trans(0, 42, 1, 192),
-            Trans(0, 55, 2, 201),
-            Trans(0, 58, 1, 192),
-            Trans(0, 68, 1, 192),
-            Trans(0, 91, 1, 192),
-            Trans(0, 92, 1, 192),
+            Trans(0, 40, 1, 218),
+            Trans(0, 41, 1, 218),
+            Trans(0, 42, 1, 218),
+            Trans(0, 43, 1, 218),
+            Trans(0, 56, 2, 227),
+            Trans(0, 60, 1, 218),
+            Trans(0, 70, 1, 218),
+            Trans(0, 94, 1, 218),
+            Trans(0, 95, 1, 218),
         ],
         k: 1,
     },
@@ -840,50 +873,50 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 39, 6, 198),
-            Trans(0, 40, 2, 194),
-            Trans(0, 41, 5, 197),
-            Trans(0, 42, 4, 196),
-            Trans(0, 58, 1, 193),
-            Trans(0, 68, 3, 195),
-            Trans(0, 91, 7, 199),
-            Trans(0, 92, 8, 200),
+            Trans(0, 40, 6, 224),
+            Trans(0, 41, 2, 220),
+            Trans(0, 42, 5, 223),
+            Trans(0, 43, 4, 222),
+            Trans(0, 60, 1, 219),
+            Trans(0, 70, 3, 221),
+            Trans(0, 94, 7, 225),
+            Trans(0, 95, 8, 226),
         ],
         k: 1,
     },
     /* 12 - "AnyxmlStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 191), Trans(0, 86, 1, 190)],
+        transitions: &[Trans(0, 5, 2, 217), Trans(0, 89, 1, 216)],
         k: 1,
     },
     /* 13 - "ArgumentStmt" */
     LookaheadDFA {
-        prod0: 130,
+        prod0: 155,
         transitions: &[],
         k: 0,
     },
     /* 14 - "AsciiNoBrace" */
     LookaheadDFA {
-        prod0: 566,
+        prod0: 606,
         transitions: &[],
         k: 0,
     },
     /* 15 - "AsciiNoSemicolon" */
     LookaheadDFA {
-        prod0: 565,
+        prod0: 605,
         transitions: &[],
         k: 0,
     },
     /* 16 - "AugmentArgStr" */
     LookaheadDFA {
-        prod0: 161,
+        prod0: 186,
         transitions: &[],
         k: 0,
     },
     /* 17 - "AugmentStmt" */
     LookaheadDFA {
-        prod0: 149,
+        prod0: 174,
         transitions: &[],
         k: 0,
     },
@@ -891,23 +924,23 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 1, 150),
-            Trans(0, 18, 1, 150),
-            Trans(0, 19, 1, 150),
-            Trans(0, 20, 1, 150),
-            Trans(0, 21, 1, 150),
-            Trans(0, 22, 1, 150),
-            Trans(0, 23, 1, 150),
-            Trans(0, 24, 1, 150),
-            Trans(0, 25, 1, 150),
-            Trans(0, 32, 1, 150),
-            Trans(0, 33, 1, 150),
-            Trans(0, 39, 1, 150),
-            Trans(0, 40, 1, 150),
-            Trans(0, 55, 2, 160),
-            Trans(0, 58, 1, 150),
-            Trans(0, 91, 1, 150),
-            Trans(0, 92, 1, 150),
+            Trans(0, 17, 1, 175),
+            Trans(0, 18, 1, 175),
+            Trans(0, 19, 1, 175),
+            Trans(0, 20, 1, 175),
+            Trans(0, 21, 1, 175),
+            Trans(0, 22, 1, 175),
+            Trans(0, 23, 1, 175),
+            Trans(0, 24, 1, 175),
+            Trans(0, 25, 1, 175),
+            Trans(0, 32, 1, 175),
+            Trans(0, 33, 1, 175),
+            Trans(0, 40, 1, 175),
+            Trans(0, 41, 1, 175),
+            Trans(0, 56, 2, 185),
+            Trans(0, 60, 1, 175),
+            Trans(0, 94, 1, 175),
+            Trans(0, 95, 1, 175),
         ],
         k: 1,
     },
@@ -915,46 +948,46 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 6, 156),
-            Trans(0, 18, 6, 156),
-            Trans(0, 19, 7, 157),
-            Trans(0, 20, 6, 156),
-            Trans(0, 21, 8, 158),
-            Trans(0, 22, 9, 159),
-            Trans(0, 23, 6, 156),
-            Trans(0, 24, 6, 156),
-            Trans(0, 25, 6, 156),
-            Trans(0, 32, 6, 156),
-            Trans(0, 33, 6, 156),
-            Trans(0, 39, 3, 153),
-            Trans(0, 40, 2, 152),
-            Trans(0, 58, 1, 151),
-            Trans(0, 91, 4, 154),
-            Trans(0, 92, 5, 155),
+            Trans(0, 17, 6, 181),
+            Trans(0, 18, 6, 181),
+            Trans(0, 19, 7, 182),
+            Trans(0, 20, 6, 181),
+            Trans(0, 21, 8, 183),
+            Trans(0, 22, 9, 184),
+            Trans(0, 23, 6, 181),
+            Trans(0, 24, 6, 181),
+            Trans(0, 25, 6, 181),
+            Trans(0, 32, 6, 181),
+            Trans(0, 33, 6, 181),
+            Trans(0, 40, 3, 178),
+            Trans(0, 41, 2, 177),
+            Trans(0, 60, 1, 176),
+            Trans(0, 94, 4, 179),
+            Trans(0, 95, 5, 180),
         ],
         k: 1,
     },
     /* 20 - "BaseStmt" */
     LookaheadDFA {
-        prod0: 188,
+        prod0: 214,
         transitions: &[],
         k: 0,
     },
     /* 21 - "BasicString" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 1, 533), Trans(0, 100, 2, 534)],
+        transitions: &[Trans(0, 57, 1, 573), Trans(0, 103, 2, 574)],
         k: 1,
     },
     /* 22 - "BelongsToStmt" */
     LookaheadDFA {
-        prod0: 516,
+        prod0: 556,
         transitions: &[],
         k: 0,
     },
     /* 23 - "BitStmt" */
     LookaheadDFA {
-        prod0: 436,
+        prod0: 475,
         transitions: &[],
         k: 0,
     },
@@ -962,10 +995,10 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 50, 1, 439),
-            Trans(0, 55, 2, 443),
-            Trans(0, 91, 1, 439),
-            Trans(0, 92, 1, 439),
+            Trans(0, 51, 1, 478),
+            Trans(0, 56, 2, 482),
+            Trans(0, 94, 1, 478),
+            Trans(0, 95, 1, 478),
         ],
         k: 1,
     },
@@ -973,46 +1006,46 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 50, 1, 440),
-            Trans(0, 91, 2, 441),
-            Trans(0, 92, 3, 442),
+            Trans(0, 51, 1, 479),
+            Trans(0, 94, 2, 480),
+            Trans(0, 95, 3, 481),
         ],
         k: 1,
     },
     /* 26 - "BitStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 438), Trans(0, 86, 1, 437)],
+        transitions: &[Trans(0, 5, 2, 477), Trans(0, 89, 1, 476)],
         k: 1,
     },
     /* 27 - "BodyStmts" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 10, 8, 92),
-            Trans(0, 11, 1, 85),
-            Trans(0, 13, 2, 86),
-            Trans(0, 14, 4, 88),
-            Trans(0, 15, 5, 89),
-            Trans(0, 16, 3, 87),
-            Trans(0, 17, 6, 90),
-            Trans(0, 18, 6, 90),
-            Trans(0, 20, 6, 90),
-            Trans(0, 22, 9, 93),
-            Trans(0, 23, 6, 90),
-            Trans(0, 24, 6, 90),
-            Trans(0, 25, 6, 90),
-            Trans(0, 32, 6, 90),
-            Trans(0, 33, 6, 90),
-            Trans(0, 37, 10, 94),
-            Trans(0, 57, 7, 91),
-            Trans(0, 101, 11, 95),
+            Trans(0, 10, 8, 93),
+            Trans(0, 11, 1, 86),
+            Trans(0, 13, 2, 87),
+            Trans(0, 14, 4, 89),
+            Trans(0, 15, 5, 90),
+            Trans(0, 16, 3, 88),
+            Trans(0, 17, 6, 91),
+            Trans(0, 18, 6, 91),
+            Trans(0, 20, 6, 91),
+            Trans(0, 22, 9, 94),
+            Trans(0, 23, 6, 91),
+            Trans(0, 24, 6, 91),
+            Trans(0, 25, 6, 91),
+            Trans(0, 32, 6, 91),
+            Trans(0, 33, 6, 91),
+            Trans(0, 37, 10, 95),
+            Trans(0, 59, 7, 92),
+            Trans(0, 104, 11, 96),
         ],
         k: 1,
     },
     /* 28 - "CaseStmt" */
     LookaheadDFA {
-        prod0: 237,
+        prod0: 263,
         transitions: &[],
         k: 0,
     },
@@ -1020,19 +1053,20 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 1, 240),
-            Trans(0, 18, 1, 240),
-            Trans(0, 20, 1, 240),
-            Trans(0, 23, 1, 240),
-            Trans(0, 24, 1, 240),
-            Trans(0, 25, 1, 240),
-            Trans(0, 32, 1, 240),
-            Trans(0, 33, 1, 240),
-            Trans(0, 40, 1, 240),
-            Trans(0, 55, 2, 246),
-            Trans(0, 58, 1, 240),
-            Trans(0, 91, 1, 240),
-            Trans(0, 92, 1, 240),
+            Trans(0, 17, 1, 266),
+            Trans(0, 18, 1, 266),
+            Trans(0, 20, 1, 266),
+            Trans(0, 23, 1, 266),
+            Trans(0, 24, 1, 266),
+            Trans(0, 25, 1, 266),
+            Trans(0, 32, 1, 266),
+            Trans(0, 33, 1, 266),
+            Trans(0, 40, 1, 266),
+            Trans(0, 41, 1, 266),
+            Trans(0, 56, 2, 273),
+            Trans(0, 60, 1, 266),
+            Trans(0, 94, 1, 266),
+            Trans(0, 95, 1, 266),
         ],
         k: 1,
     },
@@ -1040,30 +1074,31 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 3, 243),
-            Trans(0, 18, 3, 243),
-            Trans(0, 20, 3, 243),
-            Trans(0, 23, 3, 243),
-            Trans(0, 24, 3, 243),
-            Trans(0, 25, 3, 243),
-            Trans(0, 32, 3, 243),
-            Trans(0, 33, 3, 243),
-            Trans(0, 40, 2, 242),
-            Trans(0, 58, 1, 241),
-            Trans(0, 91, 4, 244),
-            Trans(0, 92, 5, 245),
+            Trans(0, 17, 4, 270),
+            Trans(0, 18, 4, 270),
+            Trans(0, 20, 4, 270),
+            Trans(0, 23, 4, 270),
+            Trans(0, 24, 4, 270),
+            Trans(0, 25, 4, 270),
+            Trans(0, 32, 4, 270),
+            Trans(0, 33, 4, 270),
+            Trans(0, 40, 3, 269),
+            Trans(0, 41, 2, 268),
+            Trans(0, 60, 1, 267),
+            Trans(0, 94, 5, 271),
+            Trans(0, 95, 6, 272),
         ],
         k: 1,
     },
     /* 31 - "CaseStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 239), Trans(0, 86, 1, 238)],
+        transitions: &[Trans(0, 5, 2, 265), Trans(0, 89, 1, 264)],
         k: 1,
     },
     /* 32 - "ChoiceStmt" */
     LookaheadDFA {
-        prod0: 202,
+        prod0: 228,
         transitions: &[],
         k: 0,
     },
@@ -1071,23 +1106,23 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 1, 205),
-            Trans(0, 18, 1, 205),
-            Trans(0, 19, 1, 205),
-            Trans(0, 20, 1, 205),
-            Trans(0, 23, 1, 205),
-            Trans(0, 24, 1, 205),
-            Trans(0, 25, 1, 205),
-            Trans(0, 32, 1, 205),
-            Trans(0, 39, 1, 205),
-            Trans(0, 40, 1, 205),
-            Trans(0, 41, 1, 205),
-            Trans(0, 42, 1, 205),
-            Trans(0, 45, 1, 205),
-            Trans(0, 55, 2, 216),
-            Trans(0, 58, 1, 205),
-            Trans(0, 91, 1, 205),
-            Trans(0, 92, 1, 205),
+            Trans(0, 17, 1, 231),
+            Trans(0, 18, 1, 231),
+            Trans(0, 19, 1, 231),
+            Trans(0, 20, 1, 231),
+            Trans(0, 23, 1, 231),
+            Trans(0, 24, 1, 231),
+            Trans(0, 25, 1, 231),
+            Trans(0, 32, 1, 231),
+            Trans(0, 40, 1, 231),
+            Trans(0, 41, 1, 231),
+            Trans(0, 42, 1, 231),
+            Trans(0, 43, 1, 231),
+            Trans(0, 46, 1, 231),
+            Trans(0, 56, 2, 242),
+            Trans(0, 60, 1, 231),
+            Trans(0, 94, 1, 231),
+            Trans(0, 95, 1, 231),
         ],
         k: 1,
     },
@@ -1095,46 +1130,46 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 9, 214),
-            Trans(0, 18, 9, 214),
-            Trans(0, 19, 10, 215),
-            Trans(0, 20, 9, 214),
-            Trans(0, 23, 9, 214),
-            Trans(0, 24, 9, 214),
-            Trans(0, 25, 9, 214),
-            Trans(0, 32, 9, 214),
-            Trans(0, 39, 6, 211),
-            Trans(0, 40, 2, 207),
-            Trans(0, 41, 5, 210),
-            Trans(0, 42, 4, 209),
-            Trans(0, 45, 3, 208),
-            Trans(0, 58, 1, 206),
-            Trans(0, 91, 7, 212),
-            Trans(0, 92, 8, 213),
+            Trans(0, 17, 9, 240),
+            Trans(0, 18, 9, 240),
+            Trans(0, 19, 10, 241),
+            Trans(0, 20, 9, 240),
+            Trans(0, 23, 9, 240),
+            Trans(0, 24, 9, 240),
+            Trans(0, 25, 9, 240),
+            Trans(0, 32, 9, 240),
+            Trans(0, 40, 6, 237),
+            Trans(0, 41, 2, 233),
+            Trans(0, 42, 5, 236),
+            Trans(0, 43, 4, 235),
+            Trans(0, 46, 3, 234),
+            Trans(0, 60, 1, 232),
+            Trans(0, 94, 7, 238),
+            Trans(0, 95, 8, 239),
         ],
         k: 1,
     },
     /* 35 - "ChoiceStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 204), Trans(0, 86, 1, 203)],
+        transitions: &[Trans(0, 5, 2, 230), Trans(0, 89, 1, 229)],
         k: 1,
     },
     /* 36 - "ConfigStmt" */
     LookaheadDFA {
-        prod0: 389,
+        prod0: 426,
         transitions: &[],
         k: 0,
     },
     /* 37 - "ContactStmt" */
     LookaheadDFA {
-        prod0: 518,
+        prod0: 558,
         transitions: &[],
         k: 0,
     },
     /* 38 - "ContainerStmt" */
     LookaheadDFA {
-        prod0: 251,
+        prod0: 278,
         transitions: &[],
         k: 0,
     },
@@ -1142,26 +1177,28 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 1, 254),
-            Trans(0, 18, 1, 254),
-            Trans(0, 20, 1, 254),
-            Trans(0, 21, 1, 254),
-            Trans(0, 22, 1, 254),
-            Trans(0, 23, 1, 254),
-            Trans(0, 24, 1, 254),
-            Trans(0, 25, 1, 254),
-            Trans(0, 32, 1, 254),
-            Trans(0, 33, 1, 254),
-            Trans(0, 39, 1, 254),
-            Trans(0, 40, 1, 254),
-            Trans(0, 42, 1, 254),
-            Trans(0, 55, 2, 267),
-            Trans(0, 58, 1, 254),
-            Trans(0, 67, 1, 254),
-            Trans(0, 68, 1, 254),
-            Trans(0, 91, 1, 254),
-            Trans(0, 92, 1, 254),
-            Trans(0, 101, 1, 254),
+            Trans(0, 14, 1, 281),
+            Trans(0, 15, 1, 281),
+            Trans(0, 17, 1, 281),
+            Trans(0, 18, 1, 281),
+            Trans(0, 20, 1, 281),
+            Trans(0, 21, 1, 281),
+            Trans(0, 22, 1, 281),
+            Trans(0, 23, 1, 281),
+            Trans(0, 24, 1, 281),
+            Trans(0, 25, 1, 281),
+            Trans(0, 32, 1, 281),
+            Trans(0, 33, 1, 281),
+            Trans(0, 40, 1, 281),
+            Trans(0, 41, 1, 281),
+            Trans(0, 43, 1, 281),
+            Trans(0, 56, 2, 296),
+            Trans(0, 60, 1, 281),
+            Trans(0, 69, 1, 281),
+            Trans(0, 70, 1, 281),
+            Trans(0, 94, 1, 281),
+            Trans(0, 95, 1, 281),
+            Trans(0, 104, 1, 281),
         ],
         k: 1,
     },
@@ -1169,41 +1206,43 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 9, 263),
-            Trans(0, 18, 9, 263),
-            Trans(0, 20, 9, 263),
-            Trans(0, 21, 10, 264),
-            Trans(0, 22, 11, 265),
-            Trans(0, 23, 9, 263),
-            Trans(0, 24, 9, 263),
-            Trans(0, 25, 9, 263),
-            Trans(0, 32, 9, 263),
-            Trans(0, 33, 9, 263),
-            Trans(0, 39, 6, 260),
-            Trans(0, 40, 2, 256),
-            Trans(0, 42, 5, 259),
-            Trans(0, 58, 1, 255),
-            Trans(0, 67, 4, 258),
-            Trans(0, 68, 3, 257),
-            Trans(0, 91, 7, 261),
-            Trans(0, 92, 8, 262),
-            Trans(0, 101, 12, 266),
+            Trans(0, 14, 9, 290),
+            Trans(0, 15, 10, 291),
+            Trans(0, 17, 11, 292),
+            Trans(0, 18, 11, 292),
+            Trans(0, 20, 11, 292),
+            Trans(0, 21, 12, 293),
+            Trans(0, 22, 13, 294),
+            Trans(0, 23, 11, 292),
+            Trans(0, 24, 11, 292),
+            Trans(0, 25, 11, 292),
+            Trans(0, 32, 11, 292),
+            Trans(0, 33, 11, 292),
+            Trans(0, 40, 6, 287),
+            Trans(0, 41, 2, 283),
+            Trans(0, 43, 5, 286),
+            Trans(0, 60, 1, 282),
+            Trans(0, 69, 4, 285),
+            Trans(0, 70, 3, 284),
+            Trans(0, 94, 7, 288),
+            Trans(0, 95, 8, 289),
+            Trans(0, 104, 14, 295),
         ],
         k: 1,
     },
     /* 41 - "ContainerStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 253), Trans(0, 86, 1, 252)],
+        transitions: &[Trans(0, 5, 2, 280), Trans(0, 89, 1, 279)],
         k: 1,
     },
     /* 42 - "DQChar" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 95, 2, 540),
-            Trans(0, 96, 1, 539),
-            Trans(0, 97, 1, 539),
+            Trans(0, 98, 2, 580),
+            Trans(0, 99, 1, 579),
+            Trans(0, 100, 1, 579),
         ],
         k: 1,
     },
@@ -1211,28 +1250,28 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 6, 3, 546),
-            Trans(0, 7, 4, 547),
-            Trans(0, 56, 2, 545),
-            Trans(0, 95, 1, 544),
+            Trans(0, 6, 3, 586),
+            Trans(0, 7, 4, 587),
+            Trans(0, 57, 2, 585),
+            Trans(0, 98, 1, 584),
         ],
         k: 1,
     },
     /* 44 - "DQEscaped" */
     LookaheadDFA {
-        prod0: 543,
+        prod0: 583,
         transitions: &[],
         k: 0,
     },
     /* 45 - "DQNoEscape" */
     LookaheadDFA {
-        prod0: 550,
+        prod0: 590,
         transitions: &[],
         k: 0,
     },
     /* 46 - "DQString" */
     LookaheadDFA {
-        prod0: 536,
+        prod0: 576,
         transitions: &[],
         k: 0,
     },
@@ -1240,5036 +1279,5372 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 277] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 56, 2, 538),
-            Trans(0, 95, 1, 537),
-            Trans(0, 96, 1, 537),
-            Trans(0, 97, 1, 537),
+            Trans(0, 57, 2, 578),
+            Trans(0, 98, 1, 577),
+            Trans(0, 99, 1, 577),
+            Trans(0, 100, 1, 577),
         ],
         k: 1,
     },
     /* 48 - "DQUnescaped" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 96, 2, 542), Trans(0, 97, 1, 541)],
+        transitions: &[Trans(0, 99, 2, 582), Trans(0, 100, 1, 581)],
         k: 1,
     },
     /* 49 - "DataDefStmt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 7, 102),
-            Trans(0, 18, 6, 101),
-            Trans(0, 20, 1, 96),
-            Trans(0, 23, 3, 98),
-            Trans(0, 24, 2, 97),
-            Trans(0, 25, 4, 99),
-            Trans(0, 32, 5, 100),
-            Trans(0, 33, 8, 103),
+            Trans(0, 17, 7, 103),
+            Trans(0, 18, 6, 102),
+            Trans(0, 20, 1, 97),
+            Trans(0, 23, 3, 99),
+            Trans(0, 24, 2, 98),
+            Trans(0, 25, 4, 100),
+            Trans(0, 32, 5, 101),
+            Trans(0, 33, 8, 104),
         ],
         k: 1,
     },
     /* 50 - "DateArg" */
     LookaheadDFA {
-        prod0: 528,
+        prod0: 568,
         transitions: &[],
         k: 0,
     },
     /* 51 - "DateArgStr" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 527), Trans(0, 93, 1, 526)],
+        transitions: &[Trans(0, 57, 2, 567), Trans(0, 96, 1, 566)],
         k: 1,
     },
     /* 52 - "DefaultStmt" */
     LookaheadDFA {
-        prod0: 416,
+        prod0: 455,
         transitions: &[],
         k: 0,
     },
     /* 53 - "DefaultStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 418), Trans(0, 102, 1, 417)],
+        transitions: &[Trans(0, 57, 2, 457), Trans(0, 105, 1, 456)],
         k: 1,
     },
     /* 54 - "DescendantSchemaNodeid" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 87, 2, 340), Trans(0, 101, 1, 339)],
+        transitions: &[Trans(0, 90, 2, 377), Trans(0, 104, 1, 376)],
         k: 1,
     },
     /* 55 - "DescriptionStmt" */
     LookaheadDFA {
-        prod0: 519,
+        prod0: 559,
         transitions: &[],
         k: 0,
     },
-    /* 56 - "DeviationStmt" */
+    /* 56 - "DeviateArg" */
     LookaheadDFA {
-        prod0: 107,
+        prod0: 132,
         transitions: &[],
         k: 0,
     },
-    /* 57 - "DoubleQuotation" */
+    /* 57 - "DeviateArgStr" */
     LookaheadDFA {
-        prod0: 535,
+        prod0: -1,
+        transitions: &[Trans(0, 57, 2, 131), Trans(0, 58, 1, 130)],
+        k: 1,
+    },
+    /* 58 - "DeviateStmt" */
+    LookaheadDFA {
+        prod0: 116,
         transitions: &[],
         k: 0,
     },
-    /* 58 - "EnumArgStr" */
+    /* 59 - "DeviateStmtList" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 469), Trans(0, 103, 1, 468)],
+        transitions: &[
+            Trans(0, 34, 1, 119),
+            Trans(0, 42, 1, 119),
+            Trans(0, 43, 1, 119),
+            Trans(0, 46, 1, 119),
+            Trans(0, 56, 2, 129),
+            Trans(0, 70, 1, 119),
+            Trans(0, 72, 1, 119),
+            Trans(0, 75, 1, 119),
+            Trans(0, 77, 1, 119),
+            Trans(0, 104, 1, 119),
+        ],
         k: 1,
     },
-    /* 59 - "EnumStmt" */
+    /* 60 - "DeviateStmtListGroup" */
     LookaheadDFA {
-        prod0: 455,
+        prod0: -1,
+        transitions: &[
+            Trans(0, 34, 9, 128),
+            Trans(0, 42, 6, 125),
+            Trans(0, 43, 5, 124),
+            Trans(0, 46, 4, 123),
+            Trans(0, 70, 2, 121),
+            Trans(0, 72, 1, 120),
+            Trans(0, 75, 8, 127),
+            Trans(0, 77, 7, 126),
+            Trans(0, 104, 3, 122),
+        ],
+        k: 1,
+    },
+    /* 61 - "DeviateStmtSuffix" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 5, 2, 118), Trans(0, 89, 1, 117)],
+        k: 1,
+    },
+    /* 62 - "DeviationStmt" */
+    LookaheadDFA {
+        prod0: 108,
+        transitions: &[],
+        k: 0,
+    },
+    /* 63 - "DeviationStmtList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 38, 1, 111),
+            Trans(0, 56, 2, 115),
+            Trans(0, 94, 1, 111),
+            Trans(0, 95, 1, 111),
+        ],
+        k: 1,
+    },
+    /* 64 - "DeviationStmtListGroup" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 38, 3, 114),
+            Trans(0, 94, 1, 112),
+            Trans(0, 95, 2, 113),
+        ],
+        k: 1,
+    },
+    /* 65 - "DeviationStmtSuffix" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 5, 2, 110), Trans(0, 89, 1, 109)],
+        k: 1,
+    },
+    /* 66 - "DoubleQuotation" */
+    LookaheadDFA {
+        prod0: 575,
+        transitions: &[],
+        k: 0,
+    },
+    /* 67 - "EnumArgStr" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 57, 2, 508), Trans(0, 106, 1, 507)],
+        k: 1,
+    },
+    /* 68 - "EnumStmt" */
+    LookaheadDFA {
+        prod0: 494,
         transitions: &[],
         k: 0,
     },
-    /* 60 - "EnumStmtList" */
+    /* 69 - "EnumStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 40, 1, 458),
-            Trans(0, 49, 1, 458),
-            Trans(0, 55, 2, 463),
-            Trans(0, 91, 1, 458),
-            Trans(0, 92, 1, 458),
+            Trans(0, 41, 1, 497),
+            Trans(0, 50, 1, 497),
+            Trans(0, 56, 2, 502),
+            Trans(0, 94, 1, 497),
+            Trans(0, 95, 1, 497),
         ],
         k: 1,
     },
-    /* 61 - "EnumStmtListGroup" */
+    /* 70 - "EnumStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 40, 1, 459),
-            Trans(0, 49, 3, 461),
-            Trans(0, 91, 2, 460),
-            Trans(0, 92, 4, 462),
+            Trans(0, 41, 1, 498),
+            Trans(0, 50, 3, 500),
+            Trans(0, 94, 2, 499),
+            Trans(0, 95, 4, 501),
         ],
         k: 1,
     },
-    /* 62 - "EnumStmtSuffix" */
+    /* 71 - "EnumStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 457), Trans(0, 86, 1, 456)],
+        transitions: &[Trans(0, 5, 2, 496), Trans(0, 89, 1, 495)],
         k: 1,
     },
-    /* 63 - "ErrorMessage" */
+    /* 72 - "ErrorMessage" */
     LookaheadDFA {
-        prod0: 387,
+        prod0: 424,
         transitions: &[],
         k: 0,
     },
-    /* 64 - "EscN" */
+    /* 73 - "EscN" */
     LookaheadDFA {
         prod0: 1,
         transitions: &[],
         k: 0,
     },
-    /* 65 - "EscT" */
+    /* 74 - "EscT" */
     LookaheadDFA {
         prod0: 2,
         transitions: &[],
         k: 0,
     },
-    /* 66 - "Escape" */
+    /* 75 - "Escape" */
     LookaheadDFA {
-        prod0: 548,
+        prod0: 588,
         transitions: &[],
         k: 0,
     },
-    /* 67 - "ExtensionStmt" */
+    /* 76 - "ExtensionStmt" */
     LookaheadDFA {
-        prod0: 121,
+        prod0: 146,
         transitions: &[],
         k: 0,
     },
-    /* 68 - "ExtensionStmtList" */
+    /* 77 - "ExtensionStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 12, 1, 124),
-            Trans(0, 39, 1, 124),
-            Trans(0, 55, 2, 129),
-            Trans(0, 91, 1, 124),
-            Trans(0, 92, 1, 124),
+            Trans(0, 12, 1, 149),
+            Trans(0, 40, 1, 149),
+            Trans(0, 56, 2, 154),
+            Trans(0, 94, 1, 149),
+            Trans(0, 95, 1, 149),
         ],
         k: 1,
     },
-    /* 69 - "ExtensionStmtListGroup" */
+    /* 78 - "ExtensionStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 12, 1, 125),
-            Trans(0, 39, 2, 126),
-            Trans(0, 91, 3, 127),
-            Trans(0, 92, 4, 128),
+            Trans(0, 12, 1, 150),
+            Trans(0, 40, 2, 151),
+            Trans(0, 94, 3, 152),
+            Trans(0, 95, 4, 153),
         ],
         k: 1,
     },
-    /* 70 - "ExtensionStmtSuffix" */
+    /* 79 - "ExtensionStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 123), Trans(0, 86, 1, 122)],
+        transitions: &[Trans(0, 5, 2, 148), Trans(0, 89, 1, 147)],
         k: 1,
     },
-    /* 71 - "FeatureStmt" */
+    /* 80 - "FeatureStmt" */
     LookaheadDFA {
-        prod0: 131,
+        prod0: 156,
         transitions: &[],
         k: 0,
     },
-    /* 72 - "FeatureStmtList" */
+    /* 81 - "FeatureStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 39, 1, 134),
-            Trans(0, 40, 1, 134),
-            Trans(0, 55, 2, 139),
-            Trans(0, 91, 1, 134),
-            Trans(0, 92, 1, 134),
+            Trans(0, 40, 1, 159),
+            Trans(0, 41, 1, 159),
+            Trans(0, 56, 2, 164),
+            Trans(0, 94, 1, 159),
+            Trans(0, 95, 1, 159),
         ],
         k: 1,
     },
-    /* 73 - "FeatureStmtListGroup" */
+    /* 82 - "FeatureStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 39, 2, 136),
-            Trans(0, 40, 1, 135),
-            Trans(0, 91, 3, 137),
-            Trans(0, 92, 4, 138),
+            Trans(0, 40, 2, 161),
+            Trans(0, 41, 1, 160),
+            Trans(0, 94, 3, 162),
+            Trans(0, 95, 4, 163),
         ],
         k: 1,
     },
-    /* 74 - "FeatureStmtSuffix" */
+    /* 83 - "FeatureStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 133), Trans(0, 86, 1, 132)],
+        transitions: &[Trans(0, 5, 2, 158), Trans(0, 89, 1, 157)],
         k: 1,
     },
-    /* 75 - "FractionDigitsArg" */
+    /* 84 - "FractionDigitsArg" */
     LookaheadDFA {
-        prod0: 435,
+        prod0: 474,
         transitions: &[],
         k: 0,
     },
-    /* 76 - "FractionDigitsStmt" */
+    /* 85 - "FractionDigitsStmt" */
     LookaheadDFA {
-        prod0: 434,
+        prod0: 473,
         transitions: &[],
         k: 0,
     },
-    /* 77 - "GroupingStmt" */
+    /* 86 - "GroupingStmt" */
     LookaheadDFA {
-        prod0: 169,
+        prod0: 194,
         transitions: &[],
         k: 0,
     },
-    /* 78 - "GroupingStmtList" */
+    /* 87 - "GroupingStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 14, 1, 170),
-            Trans(0, 15, 1, 170),
-            Trans(0, 17, 1, 170),
-            Trans(0, 18, 1, 170),
-            Trans(0, 20, 1, 170),
-            Trans(0, 21, 1, 170),
-            Trans(0, 22, 1, 170),
-            Trans(0, 23, 1, 170),
-            Trans(0, 24, 1, 170),
-            Trans(0, 25, 1, 170),
-            Trans(0, 32, 1, 170),
-            Trans(0, 33, 1, 170),
-            Trans(0, 39, 1, 170),
-            Trans(0, 55, 2, 180),
-            Trans(0, 91, 1, 170),
-            Trans(0, 92, 1, 170),
-            Trans(0, 101, 1, 170),
+            Trans(0, 14, 1, 195),
+            Trans(0, 15, 1, 195),
+            Trans(0, 17, 1, 195),
+            Trans(0, 18, 1, 195),
+            Trans(0, 20, 1, 195),
+            Trans(0, 21, 1, 195),
+            Trans(0, 22, 1, 195),
+            Trans(0, 23, 1, 195),
+            Trans(0, 24, 1, 195),
+            Trans(0, 25, 1, 195),
+            Trans(0, 32, 1, 195),
+            Trans(0, 33, 1, 195),
+            Trans(0, 40, 1, 195),
+            Trans(0, 56, 2, 205),
+            Trans(0, 94, 1, 195),
+            Trans(0, 95, 1, 195),
+            Trans(0, 104, 1, 195),
         ],
         k: 1,
     },
-    /* 79 - "GroupingStmtListGroup" */
+    /* 88 - "GroupingStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 14, 4, 174),
-            Trans(0, 15, 5, 175),
-            Trans(0, 17, 6, 176),
-            Trans(0, 18, 6, 176),
-            Trans(0, 20, 6, 176),
-            Trans(0, 21, 7, 177),
-            Trans(0, 22, 8, 178),
-            Trans(0, 23, 6, 176),
-            Trans(0, 24, 6, 176),
-            Trans(0, 25, 6, 176),
-            Trans(0, 32, 6, 176),
-            Trans(0, 33, 6, 176),
-            Trans(0, 39, 1, 171),
-            Trans(0, 91, 2, 172),
-            Trans(0, 92, 3, 173),
-            Trans(0, 101, 9, 179),
+            Trans(0, 14, 4, 199),
+            Trans(0, 15, 5, 200),
+            Trans(0, 17, 6, 201),
+            Trans(0, 18, 6, 201),
+            Trans(0, 20, 6, 201),
+            Trans(0, 21, 7, 202),
+            Trans(0, 22, 8, 203),
+            Trans(0, 23, 6, 201),
+            Trans(0, 24, 6, 201),
+            Trans(0, 25, 6, 201),
+            Trans(0, 32, 6, 201),
+            Trans(0, 33, 6, 201),
+            Trans(0, 40, 1, 196),
+            Trans(0, 94, 2, 197),
+            Trans(0, 95, 3, 198),
+            Trans(0, 104, 9, 204),
         ],
         k: 1,
     },
-    /* 80 - "Identifier" */
+    /* 89 - "Identifier" */
     LookaheadDFA {
-        prod0: 564,
+        prod0: 604,
         transitions: &[],
         k: 0,
     },
-    /* 81 - "IdentifierArgStr" */
+    /* 90 - "IdentifierArgStr" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 563), Trans(0, 101, 1, 562)],
+        transitions: &[Trans(0, 57, 2, 603), Trans(0, 104, 1, 602)],
         k: 1,
     },
-    /* 82 - "IdentifierRef" */
+    /* 91 - "IdentifierRef" */
     LookaheadDFA {
-        prod0: 484,
+        prod0: 524,
         transitions: &[],
         k: 0,
     },
-    /* 83 - "IdentifierRefArgStr" */
+    /* 92 - "IdentifierRefArgStr" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 483), Trans(0, 101, 1, 482)],
+        transitions: &[Trans(0, 57, 2, 523), Trans(0, 104, 1, 522)],
         k: 1,
     },
-    /* 84 - "IdentifierRefOpt" */
+    /* 93 - "IdentifierRefOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 101, 1, -1),
-            Trans(1, 5, 3, 486),
-            Trans(1, 56, 3, 486),
-            Trans(1, 85, 2, 485),
-            Trans(1, 86, 3, 486),
-            Trans(1, 100, 3, 486),
-            Trans(1, 101, 3, 486),
+            Trans(0, 104, 1, -1),
+            Trans(1, 5, 3, 526),
+            Trans(1, 57, 3, 526),
+            Trans(1, 88, 2, 525),
+            Trans(1, 89, 3, 526),
+            Trans(1, 103, 3, 526),
+            Trans(1, 104, 3, 526),
         ],
         k: 2,
     },
-    /* 85 - "IdentityStmt" */
+    /* 94 - "IdentityStmt" */
     LookaheadDFA {
-        prod0: 181,
+        prod0: 206,
         transitions: &[],
         k: 0,
     },
-    /* 86 - "IdentityStmtList" */
+    /* 95 - "IdentityStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 31, 1, 182),
-            Trans(0, 40, 1, 182),
-            Trans(0, 55, 2, 187),
-            Trans(0, 91, 1, 182),
-            Trans(0, 92, 1, 182),
+            Trans(0, 31, 1, 207),
+            Trans(0, 40, 1, 207),
+            Trans(0, 41, 1, 207),
+            Trans(0, 56, 2, 213),
+            Trans(0, 94, 1, 207),
+            Trans(0, 95, 1, 207),
         ],
         k: 1,
     },
-    /* 87 - "IdentityStmtListGroup" */
+    /* 96 - "IdentityStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 31, 2, 184),
-            Trans(0, 40, 1, 183),
-            Trans(0, 91, 3, 185),
-            Trans(0, 92, 4, 186),
+            Trans(0, 31, 2, 209),
+            Trans(0, 40, 3, 210),
+            Trans(0, 41, 1, 208),
+            Trans(0, 94, 4, 211),
+            Trans(0, 95, 5, 212),
         ],
         k: 1,
     },
-    /* 88 - "IfFeatureExpr" */
+    /* 97 - "IfFeatureExpr" */
     LookaheadDFA {
-        prod0: 298,
+        prod0: 335,
         transitions: &[],
         k: 0,
     },
-    /* 89 - "IfFeatureExprOpt" */
+    /* 98 - "IfFeatureExprOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 62, 1, 299),
-            Trans(0, 66, 2, 300),
-            Trans(0, 86, 2, 300),
+            Trans(0, 64, 1, 336),
+            Trans(0, 68, 2, 337),
+            Trans(0, 89, 2, 337),
         ],
         k: 1,
     },
-    /* 90 - "IfFeatureExprStr" */
+    /* 99 - "IfFeatureExprStr" */
     LookaheadDFA {
-        prod0: 297,
+        prod0: 334,
         transitions: &[],
         k: 0,
     },
-    /* 91 - "IfFeatureFactor" */
+    /* 100 - "IfFeatureFactor" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 56, 4, 307),
-            Trans(0, 64, 1, 304),
-            Trans(0, 65, 2, 305),
-            Trans(0, 101, 3, 306),
+            Trans(0, 57, 4, 344),
+            Trans(0, 66, 1, 341),
+            Trans(0, 67, 2, 342),
+            Trans(0, 104, 3, 343),
         ],
         k: 1,
     },
-    /* 92 - "IfFeatureStmt" */
+    /* 101 - "IfFeatureStmt" */
     LookaheadDFA {
-        prod0: 296,
+        prod0: 333,
         transitions: &[],
         k: 0,
     },
-    /* 93 - "IfFeatureTerm" */
+    /* 102 - "IfFeatureTerm" */
     LookaheadDFA {
-        prod0: 301,
+        prod0: 338,
         transitions: &[],
         k: 0,
     },
-    /* 94 - "IfFeatureTermOpt" */
+    /* 103 - "IfFeatureTermOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 62, 2, 303),
-            Trans(0, 63, 1, 302),
-            Trans(0, 66, 2, 303),
-            Trans(0, 86, 2, 303),
+            Trans(0, 64, 2, 340),
+            Trans(0, 65, 1, 339),
+            Trans(0, 68, 2, 340),
+            Trans(0, 89, 2, 340),
         ],
         k: 1,
     },
-    /* 95 - "ImportStmt" */
+    /* 104 - "ImportStmt" */
     LookaheadDFA {
-        prod0: 495,
+        prod0: 535,
         transitions: &[],
         k: 0,
     },
-    /* 96 - "ImportStmtList" */
+    /* 105 - "ImportStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 29, 1, 496),
-            Trans(0, 53, 1, 496),
-            Trans(0, 55, 2, 501),
-            Trans(0, 91, 1, 496),
-            Trans(0, 92, 1, 496),
+            Trans(0, 29, 1, 536),
+            Trans(0, 54, 1, 536),
+            Trans(0, 56, 2, 541),
+            Trans(0, 94, 1, 536),
+            Trans(0, 95, 1, 536),
         ],
         k: 1,
     },
-    /* 97 - "ImportStmtListGroup" */
+    /* 106 - "ImportStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 29, 1, 497),
-            Trans(0, 53, 2, 498),
-            Trans(0, 91, 3, 499),
-            Trans(0, 92, 4, 500),
+            Trans(0, 29, 1, 537),
+            Trans(0, 54, 2, 538),
+            Trans(0, 94, 3, 539),
+            Trans(0, 95, 4, 540),
         ],
         k: 1,
     },
-    /* 98 - "IncludeStmt" */
+    /* 107 - "IncludeStmt" */
     LookaheadDFA {
-        prod0: 502,
+        prod0: 542,
         transitions: &[],
         k: 0,
     },
-    /* 99 - "IncludeStmtList" */
+    /* 108 - "IncludeStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 53, 1, 505),
-            Trans(0, 55, 2, 509),
-            Trans(0, 91, 1, 505),
-            Trans(0, 92, 1, 505),
+            Trans(0, 54, 1, 545),
+            Trans(0, 56, 2, 549),
+            Trans(0, 94, 1, 545),
+            Trans(0, 95, 1, 545),
         ],
         k: 1,
     },
-    /* 100 - "IncludeStmtListGroup" */
+    /* 109 - "IncludeStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 53, 1, 506),
-            Trans(0, 91, 2, 507),
-            Trans(0, 92, 3, 508),
+            Trans(0, 54, 1, 546),
+            Trans(0, 94, 2, 547),
+            Trans(0, 95, 3, 548),
         ],
         k: 1,
     },
-    /* 101 - "IncludeStmtSuffix" */
+    /* 110 - "IncludeStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 504), Trans(0, 86, 1, 503)],
+        transitions: &[Trans(0, 5, 2, 544), Trans(0, 89, 1, 543)],
         k: 1,
     },
-    /* 102 - "InputStmt" */
+    /* 111 - "InputStmt" */
     LookaheadDFA {
-        prod0: 279,
+        prod0: 310,
         transitions: &[],
         k: 0,
     },
-    /* 103 - "InputStmtList" */
+    /* 112 - "InputStmtList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 14, 1, 311),
+            Trans(0, 15, 1, 311),
+            Trans(0, 17, 1, 311),
+            Trans(0, 18, 1, 311),
+            Trans(0, 20, 1, 311),
+            Trans(0, 23, 1, 311),
+            Trans(0, 24, 1, 311),
+            Trans(0, 25, 1, 311),
+            Trans(0, 32, 1, 311),
+            Trans(0, 33, 1, 311),
+            Trans(0, 56, 2, 315),
+        ],
+        k: 1,
+    },
+    /* 113 - "InputStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 1, 280),
-            Trans(0, 18, 1, 280),
-            Trans(0, 20, 1, 280),
-            Trans(0, 23, 1, 280),
-            Trans(0, 24, 1, 280),
-            Trans(0, 25, 1, 280),
-            Trans(0, 32, 1, 280),
-            Trans(0, 33, 1, 280),
-            Trans(0, 55, 2, 281),
+            Trans(0, 14, 1, 312),
+            Trans(0, 15, 2, 313),
+            Trans(0, 17, 3, 314),
+            Trans(0, 18, 3, 314),
+            Trans(0, 20, 3, 314),
+            Trans(0, 23, 3, 314),
+            Trans(0, 24, 3, 314),
+            Trans(0, 25, 3, 314),
+            Trans(0, 32, 3, 314),
+            Trans(0, 33, 3, 314),
         ],
         k: 1,
     },
-    /* 104 - "IntegerValue" */
+    /* 114 - "IntegerValue" */
     LookaheadDFA {
-        prod0: 467,
+        prod0: 506,
         transitions: &[],
         k: 0,
     },
-    /* 105 - "IntegerValueStr" */
+    /* 115 - "IntegerValueStr" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 466), Trans(0, 79, 1, 465)],
+        transitions: &[Trans(0, 57, 2, 505), Trans(0, 81, 1, 504)],
         k: 1,
     },
-    /* 106 - "KeyArg" */
+    /* 116 - "KeyArg" */
     LookaheadDFA {
-        prod0: 491,
+        prod0: 531,
         transitions: &[],
         k: 0,
     },
-    /* 107 - "KeyArgOpt" */
+    /* 117 - "KeyArgOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 56, 2, 493),
-            Trans(0, 86, 2, 493),
-            Trans(0, 101, 1, 492),
+            Trans(0, 57, 2, 533),
+            Trans(0, 89, 2, 533),
+            Trans(0, 104, 1, 532),
         ],
         k: 1,
     },
-    /* 108 - "KeyArgStr" */
+    /* 118 - "KeyArgStr" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 489), Trans(0, 101, 1, 488)],
+        transitions: &[Trans(0, 57, 2, 529), Trans(0, 104, 1, 528)],
         k: 1,
     },
-    /* 109 - "KeyStmt" */
+    /* 119 - "KeyStmt" */
     LookaheadDFA {
-        prod0: 487,
+        prod0: 527,
         transitions: &[],
         k: 0,
     },
-    /* 110 - "KwAction" */
+    /* 120 - "KwAction" */
     LookaheadDFA {
         prod0: 16,
         transitions: &[],
         k: 0,
     },
-    /* 111 - "KwAnydata" */
+    /* 121 - "KwAnydata" */
     LookaheadDFA {
         prod0: 13,
         transitions: &[],
         k: 0,
     },
-    /* 112 - "KwAnyxml" */
+    /* 122 - "KwAnyxml" */
     LookaheadDFA {
         prod0: 12,
         transitions: &[],
         k: 0,
     },
-    /* 113 - "KwArgument" */
+    /* 123 - "KwArgument" */
     LookaheadDFA {
         prod0: 7,
         transitions: &[],
         k: 0,
     },
-    /* 114 - "KwBase" */
+    /* 124 - "KwBase" */
     LookaheadDFA {
         prod0: 26,
         transitions: &[],
         k: 0,
     },
-    /* 115 - "KwBelongsTo" */
+    /* 125 - "KwBelongsTo" */
     LookaheadDFA {
         prod0: 25,
         transitions: &[],
         k: 0,
     },
-    /* 116 - "KwBit" */
+    /* 126 - "KwBit" */
     LookaheadDFA {
         prod0: 21,
         transitions: &[],
         k: 0,
     },
-    /* 117 - "KwCase" */
+    /* 127 - "KwCase" */
     LookaheadDFA {
         prod0: 14,
         transitions: &[],
         k: 0,
     },
-    /* 118 - "KwChoice" */
+    /* 128 - "KwChoice" */
     LookaheadDFA {
         prod0: 27,
         transitions: &[],
         k: 0,
     },
-    /* 119 - "KwConfig" */
+    /* 129 - "KwConfig" */
     LookaheadDFA {
-        prod0: 37,
+        prod0: 38,
         transitions: &[],
         k: 0,
     },
-    /* 120 - "KwContainer" */
+    /* 130 - "KwContainer" */
     LookaheadDFA {
         prod0: 15,
         transitions: &[],
         k: 0,
     },
-    /* 121 - "KwDefault" */
+    /* 131 - "KwDefault" */
     LookaheadDFA {
-        prod0: 40,
+        prod0: 41,
+        transitions: &[],
+        k: 0,
+    },
+    /* 132 - "KwDeviate" */
+    LookaheadDFA {
+        prod0: 33,
         transitions: &[],
         k: 0,
     },
-    /* 122 - "KwDeviation" */
+    /* 133 - "KwDeviation" */
     LookaheadDFA {
         prod0: 32,
         transitions: &[],
         k: 0,
     },
-    /* 123 - "KwEnum" */
+    /* 134 - "KwEnum" */
     LookaheadDFA {
-        prod0: 46,
+        prod0: 47,
         transitions: &[],
         k: 0,
     },
-    /* 124 - "KwExtension" */
+    /* 135 - "KwExtension" */
     LookaheadDFA {
         prod0: 6,
         transitions: &[],
         k: 0,
     },
-    /* 125 - "KwFeature" */
+    /* 136 - "KwFeature" */
     LookaheadDFA {
         prod0: 8,
         transitions: &[],
         k: 0,
     },
-    /* 126 - "KwFractionDigits" */
+    /* 137 - "KwFractionDigits" */
     LookaheadDFA {
-        prod0: 41,
+        prod0: 42,
         transitions: &[],
         k: 0,
     },
-    /* 127 - "KwGrouping" */
+    /* 138 - "KwGrouping" */
     LookaheadDFA {
         prod0: 10,
         transitions: &[],
         k: 0,
     },
-    /* 128 - "KwIdentity" */
+    /* 139 - "KwIdentity" */
     LookaheadDFA {
         prod0: 11,
         transitions: &[],
         k: 0,
     },
-    /* 129 - "KwIfFeature" */
+    /* 140 - "KwIfFeature" */
     LookaheadDFA {
-        prod0: 35,
+        prod0: 36,
         transitions: &[],
         k: 0,
     },
-    /* 130 - "KwImport" */
+    /* 141 - "KwImport" */
     LookaheadDFA {
         prod0: 22,
         transitions: &[],
         k: 0,
     },
-    /* 131 - "KwInclude" */
+    /* 142 - "KwInclude" */
     LookaheadDFA {
         prod0: 23,
         transitions: &[],
         k: 0,
     },
-    /* 132 - "KwKey" */
+    /* 143 - "KwKey" */
     LookaheadDFA {
         prod0: 31,
         transitions: &[],
         k: 0,
     },
-    /* 133 - "KwLeaf" */
+    /* 144 - "KwLeaf" */
     LookaheadDFA {
         prod0: 19,
         transitions: &[],
         k: 0,
     },
-    /* 134 - "KwLeafList" */
+    /* 145 - "KwLeafList" */
     LookaheadDFA {
         prod0: 18,
         transitions: &[],
         k: 0,
     },
-    /* 135 - "KwLength" */
+    /* 146 - "KwLength" */
     LookaheadDFA {
-        prod0: 42,
+        prod0: 43,
         transitions: &[],
         k: 0,
     },
-    /* 136 - "KwList" */
+    /* 147 - "KwList" */
     LookaheadDFA {
         prod0: 20,
         transitions: &[],
         k: 0,
     },
-    /* 137 - "KwMandatory" */
+    /* 148 - "KwMandatory" */
     LookaheadDFA {
-        prod0: 36,
+        prod0: 37,
         transitions: &[],
         k: 0,
     },
-    /* 138 - "KwModule" */
+    /* 149 - "KwModule" */
     LookaheadDFA {
         prod0: 3,
         transitions: &[],
         k: 0,
     },
-    /* 139 - "KwNamespace" */
+    /* 150 - "KwNamespace" */
     LookaheadDFA {
-        prod0: 49,
+        prod0: 50,
         transitions: &[],
         k: 0,
     },
-    /* 140 - "KwNotification" */
+    /* 151 - "KwNotification" */
     LookaheadDFA {
         prod0: 17,
         transitions: &[],
         k: 0,
     },
-    /* 141 - "KwOrderedBy" */
+    /* 152 - "KwOrderedBy" */
     LookaheadDFA {
-        prod0: 39,
+        prod0: 40,
         transitions: &[],
         k: 0,
     },
-    /* 142 - "KwPosition" */
+    /* 153 - "KwPosition" */
     LookaheadDFA {
-        prod0: 45,
+        prod0: 46,
         transitions: &[],
         k: 0,
     },
-    /* 143 - "KwPrefix" */
+    /* 154 - "KwPrefix" */
     LookaheadDFA {
         prod0: 24,
         transitions: &[],
         k: 0,
     },
-    /* 144 - "KwRange" */
+    /* 155 - "KwRange" */
     LookaheadDFA {
-        prod0: 43,
+        prod0: 44,
         transitions: &[],
         k: 0,
     },
-    /* 145 - "KwRefine" */
+    /* 156 - "KwRefine" */
     LookaheadDFA {
         prod0: 30,
         transitions: &[],
         k: 0,
     },
-    /* 146 - "KwRequireInstance" */
+    /* 157 - "KwRequireInstance" */
     LookaheadDFA {
-        prod0: 38,
+        prod0: 39,
         transitions: &[],
         k: 0,
     },
-    /* 147 - "KwRevision" */
+    /* 158 - "KwRevision" */
     LookaheadDFA {
-        prod0: 47,
+        prod0: 48,
         transitions: &[],
         k: 0,
     },
-    /* 148 - "KwRevisionDate" */
+    /* 159 - "KwRevisionDate" */
     LookaheadDFA {
-        prod0: 48,
+        prod0: 49,
         transitions: &[],
         k: 0,
     },
-    /* 149 - "KwRpc" */
+    /* 160 - "KwRpc" */
     LookaheadDFA {
         prod0: 5,
         transitions: &[],
         k: 0,
     },
-    /* 150 - "KwStatus" */
+    /* 161 - "KwStatus" */
     LookaheadDFA {
-        prod0: 34,
+        prod0: 35,
         transitions: &[],
         k: 0,
     },
-    /* 151 - "KwSubmodule" */
+    /* 162 - "KwSubmodule" */
     LookaheadDFA {
         prod0: 4,
         transitions: &[],
         k: 0,
     },
-    /* 152 - "KwType" */
+    /* 163 - "KwType" */
     LookaheadDFA {
         prod0: 29,
         transitions: &[],
         k: 0,
     },
-    /* 153 - "KwTypedef" */
+    /* 164 - "KwTypedef" */
     LookaheadDFA {
         prod0: 9,
         transitions: &[],
         k: 0,
     },
-    /* 154 - "KwUses" */
+    /* 165 - "KwUses" */
     LookaheadDFA {
         prod0: 28,
         transitions: &[],
         k: 0,
     },
-    /* 155 - "KwValue" */
+    /* 166 - "KwValue" */
     LookaheadDFA {
-        prod0: 44,
+        prod0: 45,
         transitions: &[],
         k: 0,
     },
-    /* 156 - "KwYangVersion" */
+    /* 167 - "KwYangVersion" */
     LookaheadDFA {
-        prod0: 33,
+        prod0: 34,
         transitions: &[],
         k: 0,
     },
-    /* 157 - "LBrace" */
+    /* 168 - "LBrace" */
     LookaheadDFA {
         prod0: 0,
         transitions: &[],
         k: 0,
     },
-    /* 158 - "LeafListStmt" */
+    /* 169 - "LeafListStmt" */
     LookaheadDFA {
-        prod0: 348,
+        prod0: 385,
         transitions: &[],
         k: 0,
     },
-    /* 159 - "LeafListStmtList" */
+    /* 170 - "LeafListStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 34, 1, 349),
-            Trans(0, 39, 1, 349),
-            Trans(0, 40, 1, 349),
-            Trans(0, 42, 1, 349),
-            Trans(0, 44, 1, 349),
-            Trans(0, 45, 1, 349),
-            Trans(0, 55, 2, 364),
-            Trans(0, 58, 1, 349),
-            Trans(0, 68, 1, 349),
-            Trans(0, 70, 1, 349),
-            Trans(0, 73, 1, 349),
-            Trans(0, 75, 1, 349),
-            Trans(0, 91, 1, 349),
-            Trans(0, 92, 1, 349),
-            Trans(0, 101, 1, 349),
+            Trans(0, 34, 1, 386),
+            Trans(0, 40, 1, 386),
+            Trans(0, 41, 1, 386),
+            Trans(0, 43, 1, 386),
+            Trans(0, 45, 1, 386),
+            Trans(0, 46, 1, 386),
+            Trans(0, 56, 2, 401),
+            Trans(0, 60, 1, 386),
+            Trans(0, 70, 1, 386),
+            Trans(0, 72, 1, 386),
+            Trans(0, 75, 1, 386),
+            Trans(0, 77, 1, 386),
+            Trans(0, 94, 1, 386),
+            Trans(0, 95, 1, 386),
+            Trans(0, 104, 1, 386),
         ],
         k: 1,
     },
-    /* 160 - "LeafListStmtListGroup" */
+    /* 171 - "LeafListStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 34, 3, 352),
-            Trans(0, 39, 11, 360),
-            Trans(0, 40, 2, 351),
-            Trans(0, 42, 7, 356),
-            Trans(0, 44, 10, 359),
-            Trans(0, 45, 6, 355),
-            Trans(0, 58, 1, 350),
-            Trans(0, 68, 5, 354),
-            Trans(0, 70, 4, 353),
-            Trans(0, 73, 9, 358),
-            Trans(0, 75, 8, 357),
-            Trans(0, 91, 12, 361),
-            Trans(0, 92, 13, 362),
-            Trans(0, 101, 14, 363),
+            Trans(0, 34, 3, 389),
+            Trans(0, 40, 11, 397),
+            Trans(0, 41, 2, 388),
+            Trans(0, 43, 7, 393),
+            Trans(0, 45, 10, 396),
+            Trans(0, 46, 6, 392),
+            Trans(0, 60, 1, 387),
+            Trans(0, 70, 5, 391),
+            Trans(0, 72, 4, 390),
+            Trans(0, 75, 9, 395),
+            Trans(0, 77, 8, 394),
+            Trans(0, 94, 12, 398),
+            Trans(0, 95, 13, 399),
+            Trans(0, 104, 14, 400),
         ],
         k: 1,
     },
-    /* 161 - "LeafStmt" */
+    /* 172 - "LeafStmt" */
     LookaheadDFA {
-        prod0: 365,
+        prod0: 402,
         transitions: &[],
         k: 0,
     },
-    /* 162 - "LeafStmtList" */
+    /* 173 - "LeafStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 34, 1, 366),
-            Trans(0, 39, 1, 366),
-            Trans(0, 40, 1, 366),
-            Trans(0, 41, 1, 366),
-            Trans(0, 42, 1, 366),
-            Trans(0, 45, 1, 366),
-            Trans(0, 55, 2, 379),
-            Trans(0, 58, 1, 366),
-            Trans(0, 68, 1, 366),
-            Trans(0, 70, 1, 366),
-            Trans(0, 91, 1, 366),
-            Trans(0, 92, 1, 366),
-            Trans(0, 101, 1, 366),
+            Trans(0, 34, 1, 403),
+            Trans(0, 40, 1, 403),
+            Trans(0, 41, 1, 403),
+            Trans(0, 42, 1, 403),
+            Trans(0, 43, 1, 403),
+            Trans(0, 46, 1, 403),
+            Trans(0, 56, 2, 416),
+            Trans(0, 60, 1, 403),
+            Trans(0, 70, 1, 403),
+            Trans(0, 72, 1, 403),
+            Trans(0, 94, 1, 403),
+            Trans(0, 95, 1, 403),
+            Trans(0, 104, 1, 403),
         ],
         k: 1,
     },
-    /* 163 - "LeafStmtListGroup" */
+    /* 174 - "LeafStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 34, 4, 370),
-            Trans(0, 39, 2, 368),
-            Trans(0, 40, 3, 369),
-            Trans(0, 41, 9, 375),
-            Trans(0, 42, 8, 374),
-            Trans(0, 45, 7, 373),
-            Trans(0, 58, 1, 367),
-            Trans(0, 68, 6, 372),
-            Trans(0, 70, 5, 371),
-            Trans(0, 91, 10, 376),
-            Trans(0, 92, 11, 377),
-            Trans(0, 101, 12, 378),
+            Trans(0, 34, 4, 407),
+            Trans(0, 40, 2, 405),
+            Trans(0, 41, 3, 406),
+            Trans(0, 42, 9, 412),
+            Trans(0, 43, 8, 411),
+            Trans(0, 46, 7, 410),
+            Trans(0, 60, 1, 404),
+            Trans(0, 70, 6, 409),
+            Trans(0, 72, 5, 408),
+            Trans(0, 94, 10, 413),
+            Trans(0, 95, 11, 414),
+            Trans(0, 104, 12, 415),
         ],
         k: 1,
     },
-    /* 164 - "LeafrefSpecification" */
+    /* 175 - "LeafrefSpecification" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 43, 2, 446), Trans(0, 77, 1, 445)],
+        transitions: &[Trans(0, 44, 2, 485), Trans(0, 79, 1, 484)],
         k: 1,
     },
-    /* 165 - "LengthStmt" */
+    /* 176 - "LengthStmt" */
     LookaheadDFA {
-        prod0: 454,
+        prod0: 493,
         transitions: &[],
         k: 0,
     },
-    /* 166 - "LinkageStmts" */
+    /* 177 - "LinkageStmts" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 27, 1, 83), Trans(0, 28, 2, 84)],
+        transitions: &[Trans(0, 27, 1, 84), Trans(0, 28, 2, 85)],
         k: 1,
     },
-    /* 167 - "ListStmt" */
+    /* 178 - "ListStmt" */
     LookaheadDFA {
-        prod0: 394,
+        prod0: 431,
         transitions: &[],
         k: 0,
     },
-    /* 168 - "ListStmtList" */
+    /* 179 - "ListStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 1, 395),
-            Trans(0, 18, 1, 395),
-            Trans(0, 20, 1, 395),
-            Trans(0, 21, 1, 395),
-            Trans(0, 22, 1, 395),
-            Trans(0, 23, 1, 395),
-            Trans(0, 24, 1, 395),
-            Trans(0, 25, 1, 395),
-            Trans(0, 32, 1, 395),
-            Trans(0, 33, 1, 395),
-            Trans(0, 36, 1, 395),
-            Trans(0, 39, 1, 395),
-            Trans(0, 40, 1, 395),
-            Trans(0, 42, 1, 395),
-            Trans(0, 44, 1, 395),
-            Trans(0, 55, 2, 411),
-            Trans(0, 58, 1, 395),
-            Trans(0, 68, 1, 395),
-            Trans(0, 73, 1, 395),
-            Trans(0, 75, 1, 395),
-            Trans(0, 91, 1, 395),
-            Trans(0, 92, 1, 395),
-            Trans(0, 101, 1, 395),
+            Trans(0, 14, 1, 432),
+            Trans(0, 15, 1, 432),
+            Trans(0, 17, 1, 432),
+            Trans(0, 18, 1, 432),
+            Trans(0, 20, 1, 432),
+            Trans(0, 21, 1, 432),
+            Trans(0, 22, 1, 432),
+            Trans(0, 23, 1, 432),
+            Trans(0, 24, 1, 432),
+            Trans(0, 25, 1, 432),
+            Trans(0, 32, 1, 432),
+            Trans(0, 33, 1, 432),
+            Trans(0, 36, 1, 432),
+            Trans(0, 40, 1, 432),
+            Trans(0, 41, 1, 432),
+            Trans(0, 43, 1, 432),
+            Trans(0, 45, 1, 432),
+            Trans(0, 56, 2, 450),
+            Trans(0, 60, 1, 432),
+            Trans(0, 70, 1, 432),
+            Trans(0, 75, 1, 432),
+            Trans(0, 77, 1, 432),
+            Trans(0, 94, 1, 432),
+            Trans(0, 95, 1, 432),
+            Trans(0, 104, 1, 432),
         ],
         k: 1,
     },
-    /* 169 - "ListStmtListGroup" */
+    /* 180 - "ListStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 12, 407),
-            Trans(0, 18, 12, 407),
-            Trans(0, 20, 12, 407),
-            Trans(0, 21, 13, 408),
-            Trans(0, 22, 14, 409),
-            Trans(0, 23, 12, 407),
-            Trans(0, 24, 12, 407),
-            Trans(0, 25, 12, 407),
-            Trans(0, 32, 12, 407),
-            Trans(0, 33, 12, 407),
-            Trans(0, 36, 4, 399),
-            Trans(0, 39, 9, 404),
-            Trans(0, 40, 2, 397),
-            Trans(0, 42, 5, 400),
-            Trans(0, 44, 8, 403),
-            Trans(0, 58, 1, 396),
-            Trans(0, 68, 3, 398),
-            Trans(0, 73, 7, 402),
-            Trans(0, 75, 6, 401),
-            Trans(0, 91, 10, 405),
-            Trans(0, 92, 11, 406),
-            Trans(0, 101, 15, 410),
+            Trans(0, 14, 12, 444),
+            Trans(0, 15, 13, 445),
+            Trans(0, 17, 14, 446),
+            Trans(0, 18, 14, 446),
+            Trans(0, 20, 14, 446),
+            Trans(0, 21, 15, 447),
+            Trans(0, 22, 16, 448),
+            Trans(0, 23, 14, 446),
+            Trans(0, 24, 14, 446),
+            Trans(0, 25, 14, 446),
+            Trans(0, 32, 14, 446),
+            Trans(0, 33, 14, 446),
+            Trans(0, 36, 4, 436),
+            Trans(0, 40, 9, 441),
+            Trans(0, 41, 2, 434),
+            Trans(0, 43, 5, 437),
+            Trans(0, 45, 8, 440),
+            Trans(0, 60, 1, 433),
+            Trans(0, 70, 3, 435),
+            Trans(0, 75, 7, 439),
+            Trans(0, 77, 6, 438),
+            Trans(0, 94, 10, 442),
+            Trans(0, 95, 11, 443),
+            Trans(0, 104, 17, 449),
         ],
         k: 1,
     },
-    /* 170 - "MandatoryArg" */
+    /* 181 - "MandatoryArg" */
     LookaheadDFA {
-        prod0: 393,
+        prod0: 430,
         transitions: &[],
         k: 0,
     },
-    /* 171 - "MandatoryArgStr" */
+    /* 182 - "MandatoryArgStr" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 392), Trans(0, 71, 1, 391)],
+        transitions: &[Trans(0, 57, 2, 429), Trans(0, 73, 1, 428)],
         k: 1,
     },
-    /* 172 - "MandatoryStmt" */
+    /* 183 - "MandatoryStmt" */
     LookaheadDFA {
-        prod0: 390,
+        prod0: 427,
         transitions: &[],
         k: 0,
     },
-    /* 173 - "MaxElementsStmt" */
+    /* 184 - "MaxElementsStmt" */
     LookaheadDFA {
-        prod0: 419,
+        prod0: 458,
         transitions: &[],
         k: 0,
     },
-    /* 174 - "MetaStmts" */
+    /* 185 - "MetaStmts" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 89, 1, 79),
-            Trans(0, 90, 2, 80),
-            Trans(0, 91, 3, 81),
-            Trans(0, 92, 4, 82),
+            Trans(0, 92, 1, 80),
+            Trans(0, 93, 2, 81),
+            Trans(0, 94, 3, 82),
+            Trans(0, 95, 4, 83),
         ],
         k: 1,
     },
-    /* 175 - "MinElementsStmt" */
+    /* 186 - "MinElementsStmt" */
     LookaheadDFA {
-        prod0: 420,
+        prod0: 459,
         transitions: &[],
         k: 0,
     },
-    /* 176 - "ModuleHeaderStmts" */
+    /* 187 - "ModuleHeaderStmts" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 29, 3, 76),
-            Trans(0, 38, 1, 74),
-            Trans(0, 54, 2, 75),
+            Trans(0, 29, 3, 77),
+            Trans(0, 39, 1, 75),
+            Trans(0, 55, 2, 76),
         ],
         k: 1,
     },
-    /* 177 - "ModuleStmt" */
+    /* 188 - "ModuleStmt" */
     LookaheadDFA {
-        prod0: 52,
+        prod0: 53,
         transitions: &[],
         k: 0,
     },
-    /* 178 - "ModuleStmtList" */
+    /* 189 - "ModuleStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 10, 2, 62),
-            Trans(0, 11, 2, 62),
-            Trans(0, 13, 2, 62),
-            Trans(0, 14, 2, 62),
-            Trans(0, 15, 2, 62),
-            Trans(0, 16, 2, 62),
-            Trans(0, 17, 2, 62),
-            Trans(0, 18, 2, 62),
-            Trans(0, 20, 2, 62),
-            Trans(0, 22, 2, 62),
-            Trans(0, 23, 2, 62),
-            Trans(0, 24, 2, 62),
-            Trans(0, 25, 2, 62),
-            Trans(0, 27, 2, 62),
-            Trans(0, 28, 2, 62),
-            Trans(0, 29, 1, 61),
-            Trans(0, 32, 2, 62),
-            Trans(0, 33, 2, 62),
-            Trans(0, 37, 2, 62),
-            Trans(0, 38, 1, 61),
-            Trans(0, 52, 2, 62),
-            Trans(0, 54, 1, 61),
-            Trans(0, 55, 2, 62),
-            Trans(0, 57, 2, 62),
-            Trans(0, 89, 2, 62),
-            Trans(0, 90, 2, 62),
-            Trans(0, 91, 2, 62),
-            Trans(0, 92, 2, 62),
-            Trans(0, 101, 2, 62),
+            Trans(0, 10, 2, 63),
+            Trans(0, 11, 2, 63),
+            Trans(0, 13, 2, 63),
+            Trans(0, 14, 2, 63),
+            Trans(0, 15, 2, 63),
+            Trans(0, 16, 2, 63),
+            Trans(0, 17, 2, 63),
+            Trans(0, 18, 2, 63),
+            Trans(0, 20, 2, 63),
+            Trans(0, 22, 2, 63),
+            Trans(0, 23, 2, 63),
+            Trans(0, 24, 2, 63),
+            Trans(0, 25, 2, 63),
+            Trans(0, 27, 2, 63),
+            Trans(0, 28, 2, 63),
+            Trans(0, 29, 1, 62),
+            Trans(0, 32, 2, 63),
+            Trans(0, 33, 2, 63),
+            Trans(0, 37, 2, 63),
+            Trans(0, 39, 1, 62),
+            Trans(0, 53, 2, 63),
+            Trans(0, 55, 1, 62),
+            Trans(0, 56, 2, 63),
+            Trans(0, 59, 2, 63),
+            Trans(0, 92, 2, 63),
+            Trans(0, 93, 2, 63),
+            Trans(0, 94, 2, 63),
+            Trans(0, 95, 2, 63),
+            Trans(0, 104, 2, 63),
         ],
         k: 1,
     },
-    /* 179 - "ModuleStmtList0" */
+    /* 190 - "ModuleStmtList0" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 10, 2, 60),
-            Trans(0, 11, 2, 60),
-            Trans(0, 13, 2, 60),
-            Trans(0, 14, 2, 60),
-            Trans(0, 15, 2, 60),
-            Trans(0, 16, 2, 60),
-            Trans(0, 17, 2, 60),
-            Trans(0, 18, 2, 60),
-            Trans(0, 20, 2, 60),
-            Trans(0, 22, 2, 60),
-            Trans(0, 23, 2, 60),
-            Trans(0, 24, 2, 60),
-            Trans(0, 25, 2, 60),
-            Trans(0, 27, 1, 59),
-            Trans(0, 28, 1, 59),
-            Trans(0, 32, 2, 60),
-            Trans(0, 33, 2, 60),
-            Trans(0, 37, 2, 60),
-            Trans(0, 52, 2, 60),
-            Trans(0, 55, 2, 60),
-            Trans(0, 57, 2, 60),
-            Trans(0, 89, 2, 60),
-            Trans(0, 90, 2, 60),
-            Trans(0, 91, 2, 60),
-            Trans(0, 92, 2, 60),
-            Trans(0, 101, 2, 60),
+            Trans(0, 10, 2, 61),
+            Trans(0, 11, 2, 61),
+            Trans(0, 13, 2, 61),
+            Trans(0, 14, 2, 61),
+            Trans(0, 15, 2, 61),
+            Trans(0, 16, 2, 61),
+            Trans(0, 17, 2, 61),
+            Trans(0, 18, 2, 61),
+            Trans(0, 20, 2, 61),
+            Trans(0, 22, 2, 61),
+            Trans(0, 23, 2, 61),
+            Trans(0, 24, 2, 61),
+            Trans(0, 25, 2, 61),
+            Trans(0, 27, 1, 60),
+            Trans(0, 28, 1, 60),
+            Trans(0, 32, 2, 61),
+            Trans(0, 33, 2, 61),
+            Trans(0, 37, 2, 61),
+            Trans(0, 53, 2, 61),
+            Trans(0, 56, 2, 61),
+            Trans(0, 59, 2, 61),
+            Trans(0, 92, 2, 61),
+            Trans(0, 93, 2, 61),
+            Trans(0, 94, 2, 61),
+            Trans(0, 95, 2, 61),
+            Trans(0, 104, 2, 61),
         ],
         k: 1,
     },
-    /* 180 - "ModuleStmtList1" */
+    /* 191 - "ModuleStmtList1" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 10, 2, 58),
-            Trans(0, 11, 2, 58),
-            Trans(0, 13, 2, 58),
-            Trans(0, 14, 2, 58),
-            Trans(0, 15, 2, 58),
-            Trans(0, 16, 2, 58),
-            Trans(0, 17, 2, 58),
-            Trans(0, 18, 2, 58),
-            Trans(0, 20, 2, 58),
-            Trans(0, 22, 2, 58),
-            Trans(0, 23, 2, 58),
-            Trans(0, 24, 2, 58),
-            Trans(0, 25, 2, 58),
-            Trans(0, 32, 2, 58),
-            Trans(0, 33, 2, 58),
-            Trans(0, 37, 2, 58),
-            Trans(0, 52, 2, 58),
-            Trans(0, 55, 2, 58),
-            Trans(0, 57, 2, 58),
-            Trans(0, 89, 1, 57),
-            Trans(0, 90, 1, 57),
-            Trans(0, 91, 1, 57),
-            Trans(0, 92, 1, 57),
-            Trans(0, 101, 2, 58),
+            Trans(0, 10, 2, 59),
+            Trans(0, 11, 2, 59),
+            Trans(0, 13, 2, 59),
+            Trans(0, 14, 2, 59),
+            Trans(0, 15, 2, 59),
+            Trans(0, 16, 2, 59),
+            Trans(0, 17, 2, 59),
+            Trans(0, 18, 2, 59),
+            Trans(0, 20, 2, 59),
+            Trans(0, 22, 2, 59),
+            Trans(0, 23, 2, 59),
+            Trans(0, 24, 2, 59),
+            Trans(0, 25, 2, 59),
+            Trans(0, 32, 2, 59),
+            Trans(0, 33, 2, 59),
+            Trans(0, 37, 2, 59),
+            Trans(0, 53, 2, 59),
+            Trans(0, 56, 2, 59),
+            Trans(0, 59, 2, 59),
+            Trans(0, 92, 1, 58),
+            Trans(0, 93, 1, 58),
+            Trans(0, 94, 1, 58),
+            Trans(0, 95, 1, 58),
+            Trans(0, 104, 2, 59),
         ],
         k: 1,
     },
-    /* 181 - "ModuleStmtList2" */
+    /* 192 - "ModuleStmtList2" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 10, 2, 56),
-            Trans(0, 11, 2, 56),
-            Trans(0, 13, 2, 56),
-            Trans(0, 14, 2, 56),
-            Trans(0, 15, 2, 56),
-            Trans(0, 16, 2, 56),
-            Trans(0, 17, 2, 56),
-            Trans(0, 18, 2, 56),
-            Trans(0, 20, 2, 56),
-            Trans(0, 22, 2, 56),
-            Trans(0, 23, 2, 56),
-            Trans(0, 24, 2, 56),
-            Trans(0, 25, 2, 56),
-            Trans(0, 32, 2, 56),
-            Trans(0, 33, 2, 56),
-            Trans(0, 37, 2, 56),
-            Trans(0, 52, 1, 55),
-            Trans(0, 55, 2, 56),
-            Trans(0, 57, 2, 56),
-            Trans(0, 101, 2, 56),
+            Trans(0, 10, 2, 57),
+            Trans(0, 11, 2, 57),
+            Trans(0, 13, 2, 57),
+            Trans(0, 14, 2, 57),
+            Trans(0, 15, 2, 57),
+            Trans(0, 16, 2, 57),
+            Trans(0, 17, 2, 57),
+            Trans(0, 18, 2, 57),
+            Trans(0, 20, 2, 57),
+            Trans(0, 22, 2, 57),
+            Trans(0, 23, 2, 57),
+            Trans(0, 24, 2, 57),
+            Trans(0, 25, 2, 57),
+            Trans(0, 32, 2, 57),
+            Trans(0, 33, 2, 57),
+            Trans(0, 37, 2, 57),
+            Trans(0, 53, 1, 56),
+            Trans(0, 56, 2, 57),
+            Trans(0, 59, 2, 57),
+            Trans(0, 104, 2, 57),
         ],
         k: 1,
     },
-    /* 182 - "ModuleStmtList3" */
+    /* 193 - "ModuleStmtList3" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 10, 1, 53),
-            Trans(0, 11, 1, 53),
-            Trans(0, 13, 1, 53),
-            Trans(0, 14, 1, 53),
-            Trans(0, 15, 1, 53),
-            Trans(0, 16, 1, 53),
-            Trans(0, 17, 1, 53),
-            Trans(0, 18, 1, 53),
-            Trans(0, 20, 1, 53),
-            Trans(0, 22, 1, 53),
-            Trans(0, 23, 1, 53),
-            Trans(0, 24, 1, 53),
-            Trans(0, 25, 1, 53),
-            Trans(0, 32, 1, 53),
-            Trans(0, 33, 1, 53),
-            Trans(0, 37, 1, 53),
-            Trans(0, 55, 2, 54),
-            Trans(0, 57, 1, 53),
-            Trans(0, 101, 1, 53),
+            Trans(0, 10, 1, 54),
+            Trans(0, 11, 1, 54),
+            Trans(0, 13, 1, 54),
+            Trans(0, 14, 1, 54),
+            Trans(0, 15, 1, 54),
+            Trans(0, 16, 1, 54),
+            Trans(0, 17, 1, 54),
+            Trans(0, 18, 1, 54),
+            Trans(0, 20, 1, 54),
+            Trans(0, 22, 1, 54),
+            Trans(0, 23, 1, 54),
+            Trans(0, 24, 1, 54),
+            Trans(0, 25, 1, 54),
+            Trans(0, 32, 1, 54),
+            Trans(0, 33, 1, 54),
+            Trans(0, 37, 1, 54),
+            Trans(0, 56, 2, 55),
+            Trans(0, 59, 1, 54),
+            Trans(0, 104, 1, 54),
         ],
         k: 1,
     },
-    /* 183 - "MustStmt" */
+    /* 194 - "MustStmt" */
     LookaheadDFA {
-        prod0: 380,
+        prod0: 417,
         transitions: &[],
         k: 0,
     },
-    /* 184 - "MustStmtList" */
+    /* 195 - "MustStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 55, 2, 386),
-            Trans(0, 69, 1, 383),
-            Trans(0, 91, 1, 383),
+            Trans(0, 56, 2, 423),
+            Trans(0, 71, 1, 420),
+            Trans(0, 94, 1, 420),
         ],
         k: 1,
     },
-    /* 185 - "MustStmtListGroup" */
+    /* 196 - "MustStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 69, 1, 384), Trans(0, 91, 2, 385)],
+        transitions: &[Trans(0, 71, 1, 421), Trans(0, 94, 2, 422)],
         k: 1,
     },
-    /* 186 - "MustStmtSuffix" */
+    /* 197 - "MustStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 382), Trans(0, 86, 1, 381)],
+        transitions: &[Trans(0, 5, 2, 419), Trans(0, 89, 1, 418)],
         k: 1,
     },
-    /* 187 - "NamespaceStmt" */
+    /* 198 - "NamespaceStmt" */
     LookaheadDFA {
-        prod0: 511,
+        prod0: 551,
         transitions: &[],
         k: 0,
     },
-    /* 188 - "NonAscii" */
+    /* 199 - "NonAscii" */
     LookaheadDFA {
-        prod0: 549,
+        prod0: 589,
         transitions: &[],
         k: 0,
     },
-    /* 189 - "NotificationStmt" */
+    /* 200 - "NotificationStmt" */
     LookaheadDFA {
-        prod0: 285,
+        prod0: 322,
         transitions: &[],
         k: 0,
     },
-    /* 190 - "NotificationStmtList" */
+    /* 201 - "NotificationStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 14, 1, 286),
-            Trans(0, 15, 1, 286),
-            Trans(0, 17, 1, 286),
-            Trans(0, 18, 1, 286),
-            Trans(0, 20, 1, 286),
-            Trans(0, 23, 1, 286),
-            Trans(0, 24, 1, 286),
-            Trans(0, 25, 1, 286),
-            Trans(0, 32, 1, 286),
-            Trans(0, 33, 1, 286),
-            Trans(0, 39, 1, 286),
-            Trans(0, 40, 1, 286),
-            Trans(0, 55, 2, 295),
-            Trans(0, 68, 1, 286),
-            Trans(0, 91, 1, 286),
-            Trans(0, 92, 1, 286),
+            Trans(0, 14, 1, 323),
+            Trans(0, 15, 1, 323),
+            Trans(0, 17, 1, 323),
+            Trans(0, 18, 1, 323),
+            Trans(0, 20, 1, 323),
+            Trans(0, 23, 1, 323),
+            Trans(0, 24, 1, 323),
+            Trans(0, 25, 1, 323),
+            Trans(0, 32, 1, 323),
+            Trans(0, 33, 1, 323),
+            Trans(0, 40, 1, 323),
+            Trans(0, 41, 1, 323),
+            Trans(0, 56, 2, 332),
+            Trans(0, 70, 1, 323),
+            Trans(0, 94, 1, 323),
+            Trans(0, 95, 1, 323),
         ],
         k: 1,
     },
-    /* 191 - "NotificationStmtListGroup" */
+    /* 202 - "NotificationStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 14, 6, 292),
-            Trans(0, 15, 7, 293),
-            Trans(0, 17, 8, 294),
-            Trans(0, 18, 8, 294),
-            Trans(0, 20, 8, 294),
-            Trans(0, 23, 8, 294),
-            Trans(0, 24, 8, 294),
-            Trans(0, 25, 8, 294),
-            Trans(0, 32, 8, 294),
-            Trans(0, 33, 8, 294),
-            Trans(0, 39, 3, 289),
-            Trans(0, 40, 1, 287),
-            Trans(0, 68, 2, 288),
-            Trans(0, 91, 4, 290),
-            Trans(0, 92, 5, 291),
+            Trans(0, 14, 6, 329),
+            Trans(0, 15, 7, 330),
+            Trans(0, 17, 8, 331),
+            Trans(0, 18, 8, 331),
+            Trans(0, 20, 8, 331),
+            Trans(0, 23, 8, 331),
+            Trans(0, 24, 8, 331),
+            Trans(0, 25, 8, 331),
+            Trans(0, 32, 8, 331),
+            Trans(0, 33, 8, 331),
+            Trans(0, 40, 3, 326),
+            Trans(0, 41, 1, 324),
+            Trans(0, 70, 2, 325),
+            Trans(0, 94, 4, 327),
+            Trans(0, 95, 5, 328),
         ],
         k: 1,
     },
-    /* 192 - "OrderedByArg" */
+    /* 203 - "OrderedByArg" */
     LookaheadDFA {
-        prod0: 415,
+        prod0: 454,
         transitions: &[],
         k: 0,
     },
-    /* 193 - "OrderedByArgStr" */
+    /* 204 - "OrderedByArgStr" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 414), Trans(0, 72, 1, 413)],
+        transitions: &[Trans(0, 57, 2, 453), Trans(0, 74, 1, 452)],
         k: 1,
     },
-    /* 194 - "OrderedByStmt" */
+    /* 205 - "OrderedByStmt" */
     LookaheadDFA {
-        prod0: 412,
+        prod0: 451,
         transitions: &[],
         k: 0,
     },
-    /* 195 - "OrganizationStmt" */
+    /* 206 - "OrganizationStmt" */
     LookaheadDFA {
-        prod0: 517,
+        prod0: 557,
         transitions: &[],
         k: 0,
     },
-    /* 196 - "OutputStmt" */
+    /* 207 - "OutputStmt" */
     LookaheadDFA {
-        prod0: 282,
+        prod0: 316,
         transitions: &[],
         k: 0,
     },
-    /* 197 - "OutputStmtList" */
+    /* 208 - "OutputStmtList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 14, 1, 317),
+            Trans(0, 15, 1, 317),
+            Trans(0, 17, 1, 317),
+            Trans(0, 18, 1, 317),
+            Trans(0, 20, 1, 317),
+            Trans(0, 23, 1, 317),
+            Trans(0, 24, 1, 317),
+            Trans(0, 25, 1, 317),
+            Trans(0, 32, 1, 317),
+            Trans(0, 33, 1, 317),
+            Trans(0, 56, 2, 321),
+        ],
+        k: 1,
+    },
+    /* 209 - "OutputStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 1, 283),
-            Trans(0, 18, 1, 283),
-            Trans(0, 20, 1, 283),
-            Trans(0, 23, 1, 283),
-            Trans(0, 24, 1, 283),
-            Trans(0, 25, 1, 283),
-            Trans(0, 32, 1, 283),
-            Trans(0, 33, 1, 283),
-            Trans(0, 55, 2, 284),
+            Trans(0, 14, 1, 318),
+            Trans(0, 15, 2, 319),
+            Trans(0, 17, 3, 320),
+            Trans(0, 18, 3, 320),
+            Trans(0, 20, 3, 320),
+            Trans(0, 23, 3, 320),
+            Trans(0, 24, 3, 320),
+            Trans(0, 25, 3, 320),
+            Trans(0, 32, 3, 320),
+            Trans(0, 33, 3, 320),
         ],
         k: 1,
     },
-    /* 198 - "PathStmt" */
+    /* 210 - "PathStmt" */
     LookaheadDFA {
-        prod0: 450,
+        prod0: 489,
         transitions: &[],
         k: 0,
     },
-    /* 199 - "PatternStmt" */
+    /* 211 - "PatternStmt" */
     LookaheadDFA {
-        prod0: 453,
+        prod0: 492,
         transitions: &[],
         k: 0,
     },
-    /* 200 - "PositionStmt" */
+    /* 212 - "PositionStmt" */
     LookaheadDFA {
-        prod0: 444,
+        prod0: 483,
         transitions: &[],
         k: 0,
     },
-    /* 201 - "PrefixStmt" */
+    /* 213 - "PrefixStmt" */
     LookaheadDFA {
-        prod0: 515,
+        prod0: 555,
         transitions: &[],
         k: 0,
     },
-    /* 202 - "PresenceStmt" */
+    /* 214 - "PresenceStmt" */
     LookaheadDFA {
-        prod0: 308,
+        prod0: 345,
         transitions: &[],
         k: 0,
     },
-    /* 203 - "RangeArg" */
+    /* 215 - "RangeArg" */
     LookaheadDFA {
-        prod0: 473,
+        prod0: 512,
         transitions: &[],
         k: 0,
     },
-    /* 204 - "RangeArgOpt" */
+    /* 216 - "RangeArgOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 56, 2, 475),
-            Trans(0, 80, 1, 474),
-            Trans(0, 86, 2, 475),
+            Trans(0, 57, 2, 514),
+            Trans(0, 82, 1, 513),
+            Trans(0, 89, 2, 514),
         ],
         k: 1,
     },
-    /* 205 - "RangeArgStr" */
+    /* 217 - "RangeArgStr" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 56, 2, 472),
-            Trans(0, 82, 1, 471),
-            Trans(0, 83, 1, 471),
-            Trans(0, 84, 1, 471),
+            Trans(0, 57, 2, 511),
+            Trans(0, 84, 1, 510),
+            Trans(0, 85, 1, 510),
+            Trans(0, 86, 1, 510),
+            Trans(0, 87, 1, 510),
         ],
         k: 1,
     },
-    /* 206 - "RangeBoundary" */
+    /* 218 - "RangeBoundary" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 82, 1, 479),
-            Trans(0, 83, 2, 480),
-            Trans(0, 84, 3, 481),
+            Trans(0, 84, 1, 518),
+            Trans(0, 85, 2, 519),
+            Trans(0, 86, 3, 520),
+            Trans(0, 87, 4, 521),
         ],
         k: 1,
     },
-    /* 207 - "RangePart" */
+    /* 219 - "RangePart" */
     LookaheadDFA {
-        prod0: 476,
+        prod0: 515,
         transitions: &[],
         k: 0,
     },
-    /* 208 - "RangePartOpt" */
+    /* 220 - "RangePartOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 56, 2, 478),
-            Trans(0, 80, 2, 478),
-            Trans(0, 81, 1, 477),
-            Trans(0, 86, 2, 478),
+            Trans(0, 57, 2, 517),
+            Trans(0, 82, 2, 517),
+            Trans(0, 83, 1, 516),
+            Trans(0, 89, 2, 517),
         ],
         k: 1,
     },
-    /* 209 - "RangeStmt" */
+    /* 221 - "RangeStmt" */
     LookaheadDFA {
-        prod0: 470,
+        prod0: 509,
         transitions: &[],
         k: 0,
     },
-    /* 210 - "ReferenceStmt" */
+    /* 222 - "ReferenceStmt" */
     LookaheadDFA {
-        prod0: 520,
+        prod0: 560,
         transitions: &[],
         k: 0,
     },
-    /* 211 - "RefineArg" */
+    /* 223 - "RefineArg" */
     LookaheadDFA {
-        prod0: 338,
+        prod0: 375,
         transitions: &[],
         k: 0,
     },
-    /* 212 - "RefineArgStr" */
+    /* 224 - "RefineArgStr" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 56, 2, 337),
-            Trans(0, 87, 1, 336),
-            Trans(0, 101, 1, 336),
+            Trans(0, 57, 2, 374),
+            Trans(0, 90, 1, 373),
+            Trans(0, 104, 1, 373),
         ],
         k: 1,
     },
-    /* 213 - "RefineStmt" */
+    /* 225 - "RefineStmt" */
     LookaheadDFA {
-        prod0: 321,
+        prod0: 358,
         transitions: &[],
         k: 0,
     },
-    /* 214 - "RefineStmtList" */
+    /* 226 - "RefineStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 40, 1, 324),
-            Trans(0, 41, 1, 324),
-            Trans(0, 42, 1, 324),
-            Trans(0, 45, 1, 324),
-            Trans(0, 55, 2, 335),
-            Trans(0, 67, 1, 324),
-            Trans(0, 68, 1, 324),
-            Trans(0, 73, 1, 324),
-            Trans(0, 75, 1, 324),
-            Trans(0, 91, 1, 324),
-            Trans(0, 92, 1, 324),
+            Trans(0, 41, 1, 361),
+            Trans(0, 42, 1, 361),
+            Trans(0, 43, 1, 361),
+            Trans(0, 46, 1, 361),
+            Trans(0, 56, 2, 372),
+            Trans(0, 69, 1, 361),
+            Trans(0, 70, 1, 361),
+            Trans(0, 75, 1, 361),
+            Trans(0, 77, 1, 361),
+            Trans(0, 94, 1, 361),
+            Trans(0, 95, 1, 361),
         ],
         k: 1,
     },
-    /* 215 - "RefineStmtListGroup" */
+    /* 227 - "RefineStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 40, 1, 325),
-            Trans(0, 41, 6, 330),
-            Trans(0, 42, 5, 329),
-            Trans(0, 45, 4, 328),
-            Trans(0, 67, 3, 327),
-            Trans(0, 68, 2, 326),
-            Trans(0, 73, 8, 332),
-            Trans(0, 75, 7, 331),
-            Trans(0, 91, 9, 333),
-            Trans(0, 92, 10, 334),
+            Trans(0, 41, 1, 362),
+            Trans(0, 42, 6, 367),
+            Trans(0, 43, 5, 366),
+            Trans(0, 46, 4, 365),
+            Trans(0, 69, 3, 364),
+            Trans(0, 70, 2, 363),
+            Trans(0, 75, 8, 369),
+            Trans(0, 77, 7, 368),
+            Trans(0, 94, 9, 370),
+            Trans(0, 95, 10, 371),
         ],
         k: 1,
     },
-    /* 216 - "RefineStmtSuffix" */
+    /* 228 - "RefineStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 323), Trans(0, 86, 1, 322)],
+        transitions: &[Trans(0, 5, 2, 360), Trans(0, 89, 1, 359)],
         k: 1,
     },
-    /* 217 - "RequireInstanceArgStr" */
+    /* 229 - "RequireInstanceArgStr" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 449), Trans(0, 71, 1, 448)],
+        transitions: &[Trans(0, 57, 2, 488), Trans(0, 73, 1, 487)],
         k: 1,
     },
-    /* 218 - "RequireInstanceStmt" */
+    /* 230 - "RequireInstanceStmt" */
     LookaheadDFA {
-        prod0: 447,
+        prod0: 486,
         transitions: &[],
         k: 0,
     },
-    /* 219 - "RevisionDateStmt" */
+    /* 231 - "RevisionDateStmt" */
     LookaheadDFA {
-        prod0: 510,
+        prod0: 550,
         transitions: &[],
         k: 0,
     },
-    /* 220 - "RevisionStmt" */
+    /* 232 - "RevisionStmt" */
     LookaheadDFA {
-        prod0: 521,
+        prod0: 561,
         transitions: &[],
         k: 0,
     },
-    /* 221 - "RevisionStmtList" */
+    /* 233 - "RevisionStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 55, 2, 525),
-            Trans(0, 91, 1, 522),
-            Trans(0, 92, 1, 522),
+            Trans(0, 56, 2, 565),
+            Trans(0, 94, 1, 562),
+            Trans(0, 95, 1, 562),
         ],
         k: 1,
     },
-    /* 222 - "RevisionStmtListGroup" */
+    /* 234 - "RevisionStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 91, 1, 523), Trans(0, 92, 2, 524)],
+        transitions: &[Trans(0, 94, 1, 563), Trans(0, 95, 2, 564)],
         k: 1,
     },
-    /* 223 - "RpcStmt" */
+    /* 235 - "RpcStmt" */
     LookaheadDFA {
-        prod0: 108,
+        prod0: 133,
         transitions: &[],
         k: 0,
     },
-    /* 224 - "RpcStmtList" */
+    /* 236 - "RpcStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 14, 1, 111),
-            Trans(0, 15, 1, 111),
-            Trans(0, 39, 1, 111),
-            Trans(0, 40, 1, 111),
-            Trans(0, 55, 2, 120),
-            Trans(0, 60, 1, 111),
-            Trans(0, 61, 1, 111),
-            Trans(0, 91, 1, 111),
-            Trans(0, 92, 1, 111),
+            Trans(0, 14, 1, 136),
+            Trans(0, 15, 1, 136),
+            Trans(0, 40, 1, 136),
+            Trans(0, 41, 1, 136),
+            Trans(0, 56, 2, 145),
+            Trans(0, 62, 1, 136),
+            Trans(0, 63, 1, 136),
+            Trans(0, 94, 1, 136),
+            Trans(0, 95, 1, 136),
         ],
         k: 1,
     },
-    /* 225 - "RpcStmtListGroup" */
+    /* 237 - "RpcStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 14, 5, 116),
-            Trans(0, 15, 6, 117),
-            Trans(0, 39, 2, 113),
-            Trans(0, 40, 1, 112),
-            Trans(0, 60, 7, 118),
-            Trans(0, 61, 8, 119),
-            Trans(0, 91, 3, 114),
-            Trans(0, 92, 4, 115),
+            Trans(0, 14, 5, 141),
+            Trans(0, 15, 6, 142),
+            Trans(0, 40, 2, 138),
+            Trans(0, 41, 1, 137),
+            Trans(0, 62, 7, 143),
+            Trans(0, 63, 8, 144),
+            Trans(0, 94, 3, 139),
+            Trans(0, 95, 4, 140),
         ],
         k: 1,
     },
-    /* 226 - "RpcStmtSuffix" */
+    /* 238 - "RpcStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 110), Trans(0, 86, 1, 109)],
+        transitions: &[Trans(0, 5, 2, 135), Trans(0, 89, 1, 134)],
         k: 1,
     },
-    /* 227 - "SQChar" */
+    /* 239 - "SQChar" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 95, 2, 555),
-            Trans(0, 96, 1, 554),
-            Trans(0, 99, 1, 554),
+            Trans(0, 98, 2, 595),
+            Trans(0, 99, 1, 594),
+            Trans(0, 102, 1, 594),
         ],
         k: 1,
     },
-    /* 228 - "SQEscapeSeqChar" */
+    /* 240 - "SQEscapeSeqChar" */
     LookaheadDFA {
-        prod0: 557,
+        prod0: 597,
         transitions: &[],
         k: 0,
     },
-    /* 229 - "SQEscaped" */
+    /* 241 - "SQEscaped" */
     LookaheadDFA {
-        prod0: 556,
+        prod0: 596,
         transitions: &[],
         k: 0,
     },
-    /* 230 - "SQNoEscape" */
+    /* 242 - "SQNoEscape" */
     LookaheadDFA {
-        prod0: 560,
+        prod0: 600,
         transitions: &[],
         k: 0,
     },
-    /* 231 - "SQString" */
+    /* 243 - "SQString" */
     LookaheadDFA {
-        prod0: 551,
+        prod0: 591,
         transitions: &[],
         k: 0,
     },
-    /* 232 - "SQStringList" */
+    /* 244 - "SQStringList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 95, 1, 552),
-            Trans(0, 96, 1, 552),
-            Trans(0, 99, 1, 552),
-            Trans(0, 100, 2, 553),
+            Trans(0, 98, 1, 592),
+            Trans(0, 99, 1, 592),
+            Trans(0, 102, 1, 592),
+            Trans(0, 103, 2, 593),
         ],
         k: 1,
     },
-    /* 233 - "SQUnescaped" */
+    /* 245 - "SQUnescaped" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 96, 2, 559), Trans(0, 99, 1, 558)],
+        transitions: &[Trans(0, 99, 2, 599), Trans(0, 102, 1, 598)],
         k: 1,
     },
-    /* 234 - "Semicolon" */
+    /* 246 - "Semicolon" */
     LookaheadDFA {
-        prod0: 490,
+        prod0: 530,
         transitions: &[],
         k: 0,
     },
-    /* 235 - "ShortCaseStmt" */
+    /* 247 - "ShortCaseStmt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 17, 7, 223),
-            Trans(0, 18, 6, 222),
-            Trans(0, 20, 2, 218),
-            Trans(0, 23, 4, 220),
-            Trans(0, 24, 3, 219),
-            Trans(0, 25, 5, 221),
-            Trans(0, 32, 1, 217),
+            Trans(0, 17, 7, 249),
+            Trans(0, 18, 6, 248),
+            Trans(0, 20, 2, 244),
+            Trans(0, 23, 4, 246),
+            Trans(0, 24, 3, 245),
+            Trans(0, 25, 5, 247),
+            Trans(0, 32, 1, 243),
         ],
         k: 1,
     },
-    /* 236 - "SingleQuotation" */
+    /* 248 - "SingleQuotation" */
     LookaheadDFA {
-        prod0: 561,
+        prod0: 601,
         transitions: &[],
         k: 0,
     },
-    /* 237 - "StatusArg" */
+    /* 249 - "StatusArg" */
     LookaheadDFA {
-        prod0: 250,
+        prod0: 277,
         transitions: &[],
         k: 0,
     },
-    /* 238 - "StatusArgStr" */
+    /* 250 - "StatusArgStr" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 249), Trans(0, 59, 1, 248)],
+        transitions: &[Trans(0, 57, 2, 276), Trans(0, 61, 1, 275)],
         k: 1,
     },
-    /* 239 - "StatusStmt" */
+    /* 251 - "StatusStmt" */
     LookaheadDFA {
-        prod0: 247,
+        prod0: 274,
         transitions: &[],
         k: 0,
     },
-    /* 240 - "StringRestrictions" */
+    /* 252 - "StringRestrictions" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 47, 1, 451), Trans(0, 78, 2, 452)],
+        transitions: &[Trans(0, 48, 1, 490), Trans(0, 80, 2, 491)],
         k: 1,
     },
-    /* 241 - "SubmoduleHeaderStmts" */
+    /* 253 - "SubmoduleHeaderStmts" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 30, 2, 78), Trans(0, 38, 1, 77)],
+        transitions: &[Trans(0, 30, 2, 79), Trans(0, 39, 1, 78)],
         k: 1,
     },
-    /* 242 - "SubmoduleStmt" */
+    /* 254 - "SubmoduleStmt" */
     LookaheadDFA {
-        prod0: 63,
+        prod0: 64,
         transitions: &[],
         k: 0,
     },
-    /* 243 - "SubmoduleStmtList" */
+    /* 255 - "SubmoduleStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 10, 2, 73),
-            Trans(0, 11, 2, 73),
-            Trans(0, 13, 2, 73),
-            Trans(0, 14, 2, 73),
-            Trans(0, 15, 2, 73),
-            Trans(0, 16, 2, 73),
-            Trans(0, 17, 2, 73),
-            Trans(0, 18, 2, 73),
-            Trans(0, 20, 2, 73),
-            Trans(0, 22, 2, 73),
-            Trans(0, 23, 2, 73),
-            Trans(0, 24, 2, 73),
-            Trans(0, 25, 2, 73),
-            Trans(0, 27, 2, 73),
-            Trans(0, 28, 2, 73),
-            Trans(0, 30, 1, 72),
-            Trans(0, 32, 2, 73),
-            Trans(0, 33, 2, 73),
-            Trans(0, 37, 2, 73),
-            Trans(0, 38, 1, 72),
-            Trans(0, 52, 2, 73),
-            Trans(0, 55, 2, 73),
-            Trans(0, 57, 2, 73),
-            Trans(0, 89, 2, 73),
-            Trans(0, 90, 2, 73),
-            Trans(0, 91, 2, 73),
-            Trans(0, 92, 2, 73),
-            Trans(0, 101, 2, 73),
+            Trans(0, 10, 2, 74),
+            Trans(0, 11, 2, 74),
+            Trans(0, 13, 2, 74),
+            Trans(0, 14, 2, 74),
+            Trans(0, 15, 2, 74),
+            Trans(0, 16, 2, 74),
+            Trans(0, 17, 2, 74),
+            Trans(0, 18, 2, 74),
+            Trans(0, 20, 2, 74),
+            Trans(0, 22, 2, 74),
+            Trans(0, 23, 2, 74),
+            Trans(0, 24, 2, 74),
+            Trans(0, 25, 2, 74),
+            Trans(0, 27, 2, 74),
+            Trans(0, 28, 2, 74),
+            Trans(0, 30, 1, 73),
+            Trans(0, 32, 2, 74),
+            Trans(0, 33, 2, 74),
+            Trans(0, 37, 2, 74),
+            Trans(0, 39, 1, 73),
+            Trans(0, 53, 2, 74),
+            Trans(0, 56, 2, 74),
+            Trans(0, 59, 2, 74),
+            Trans(0, 92, 2, 74),
+            Trans(0, 93, 2, 74),
+            Trans(0, 94, 2, 74),
+            Trans(0, 95, 2, 74),
+            Trans(0, 104, 2, 74),
         ],
         k: 1,
     },
-    /* 244 - "SubmoduleStmtList0" */
+    /* 256 - "SubmoduleStmtList0" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 10, 2, 71),
-            Trans(0, 11, 2, 71),
-            Trans(0, 13, 2, 71),
-            Trans(0, 14, 2, 71),
-            Trans(0, 15, 2, 71),
-            Trans(0, 16, 2, 71),
-            Trans(0, 17, 2, 71),
-            Trans(0, 18, 2, 71),
-            Trans(0, 20, 2, 71),
-            Trans(0, 22, 2, 71),
-            Trans(0, 23, 2, 71),
-            Trans(0, 24, 2, 71),
-            Trans(0, 25, 2, 71),
-            Trans(0, 27, 1, 70),
-            Trans(0, 28, 1, 70),
-            Trans(0, 32, 2, 71),
-            Trans(0, 33, 2, 71),
-            Trans(0, 37, 2, 71),
-            Trans(0, 52, 2, 71),
-            Trans(0, 55, 2, 71),
-            Trans(0, 57, 2, 71),
-            Trans(0, 89, 2, 71),
-            Trans(0, 90, 2, 71),
-            Trans(0, 91, 2, 71),
-            Trans(0, 92, 2, 71),
-            Trans(0, 101, 2, 71),
+            Trans(0, 10, 2, 72),
+            Trans(0, 11, 2, 72),
+            Trans(0, 13, 2, 72),
+            Trans(0, 14, 2, 72),
+            Trans(0, 15, 2, 72),
+            Trans(0, 16, 2, 72),
+            Trans(0, 17, 2, 72),
+            Trans(0, 18, 2, 72),
+            Trans(0, 20, 2, 72),
+            Trans(0, 22, 2, 72),
+            Trans(0, 23, 2, 72),
+            Trans(0, 24, 2, 72),
+            Trans(0, 25, 2, 72),
+            Trans(0, 27, 1, 71),
+            Trans(0, 28, 1, 71),
+            Trans(0, 32, 2, 72),
+            Trans(0, 33, 2, 72),
+            Trans(0, 37, 2, 72),
+            Trans(0, 53, 2, 72),
+            Trans(0, 56, 2, 72),
+            Trans(0, 59, 2, 72),
+            Trans(0, 92, 2, 72),
+            Trans(0, 93, 2, 72),
+            Trans(0, 94, 2, 72),
+            Trans(0, 95, 2, 72),
+            Trans(0, 104, 2, 72),
         ],
         k: 1,
     },
-    /* 245 - "SubmoduleStmtList1" */
+    /* 257 - "SubmoduleStmtList1" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 10, 2, 69),
-            Trans(0, 11, 2, 69),
-            Trans(0, 13, 2, 69),
-            Trans(0, 14, 2, 69),
-            Trans(0, 15, 2, 69),
-            Trans(0, 16, 2, 69),
-            Trans(0, 17, 2, 69),
-            Trans(0, 18, 2, 69),
-            Trans(0, 20, 2, 69),
-            Trans(0, 22, 2, 69),
-            Trans(0, 23, 2, 69),
-            Trans(0, 24, 2, 69),
-            Trans(0, 25, 2, 69),
-            Trans(0, 32, 2, 69),
-            Trans(0, 33, 2, 69),
-            Trans(0, 37, 2, 69),
-            Trans(0, 52, 2, 69),
-            Trans(0, 55, 2, 69),
-            Trans(0, 57, 2, 69),
-            Trans(0, 89, 1, 68),
-            Trans(0, 90, 1, 68),
-            Trans(0, 91, 1, 68),
-            Trans(0, 92, 1, 68),
-            Trans(0, 101, 2, 69),
+            Trans(0, 10, 2, 70),
+            Trans(0, 11, 2, 70),
+            Trans(0, 13, 2, 70),
+            Trans(0, 14, 2, 70),
+            Trans(0, 15, 2, 70),
+            Trans(0, 16, 2, 70),
+            Trans(0, 17, 2, 70),
+            Trans(0, 18, 2, 70),
+            Trans(0, 20, 2, 70),
+            Trans(0, 22, 2, 70),
+            Trans(0, 23, 2, 70),
+            Trans(0, 24, 2, 70),
+            Trans(0, 25, 2, 70),
+            Trans(0, 32, 2, 70),
+            Trans(0, 33, 2, 70),
+            Trans(0, 37, 2, 70),
+            Trans(0, 53, 2, 70),
+            Trans(0, 56, 2, 70),
+            Trans(0, 59, 2, 70),
+            Trans(0, 92, 1, 69),
+            Trans(0, 93, 1, 69),
+            Trans(0, 94, 1, 69),
+            Trans(0, 95, 1, 69),
+            Trans(0, 104, 2, 70),
         ],
         k: 1,
     },
-    /* 246 - "SubmoduleStmtList2" */
+    /* 258 - "SubmoduleStmtList2" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 10, 2, 67),
-            Trans(0, 11, 2, 67),
-            Trans(0, 13, 2, 67),
-            Trans(0, 14, 2, 67),
-            Trans(0, 15, 2, 67),
-            Trans(0, 16, 2, 67),
-            Trans(0, 17, 2, 67),
-            Trans(0, 18, 2, 67),
-            Trans(0, 20, 2, 67),
-            Trans(0, 22, 2, 67),
-            Trans(0, 23, 2, 67),
-            Trans(0, 24, 2, 67),
-            Trans(0, 25, 2, 67),
-            Trans(0, 32, 2, 67),
-            Trans(0, 33, 2, 67),
-            Trans(0, 37, 2, 67),
-            Trans(0, 52, 1, 66),
-            Trans(0, 55, 2, 67),
-            Trans(0, 57, 2, 67),
-            Trans(0, 101, 2, 67),
+            Trans(0, 10, 2, 68),
+            Trans(0, 11, 2, 68),
+            Trans(0, 13, 2, 68),
+            Trans(0, 14, 2, 68),
+            Trans(0, 15, 2, 68),
+            Trans(0, 16, 2, 68),
+            Trans(0, 17, 2, 68),
+            Trans(0, 18, 2, 68),
+            Trans(0, 20, 2, 68),
+            Trans(0, 22, 2, 68),
+            Trans(0, 23, 2, 68),
+            Trans(0, 24, 2, 68),
+            Trans(0, 25, 2, 68),
+            Trans(0, 32, 2, 68),
+            Trans(0, 33, 2, 68),
+            Trans(0, 37, 2, 68),
+            Trans(0, 53, 1, 67),
+            Trans(0, 56, 2, 68),
+            Trans(0, 59, 2, 68),
+            Trans(0, 104, 2, 68),
         ],
         k: 1,
     },
-    /* 247 - "SubmoduleStmtList3" */
+    /* 259 - "SubmoduleStmtList3" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 10, 1, 64),
-            Trans(0, 11, 1, 64),
-            Trans(0, 13, 1, 64),
-            Trans(0, 14, 1, 64),
-            Trans(0, 15, 1, 64),
-            Trans(0, 16, 1, 64),
-            Trans(0, 17, 1, 64),
-            Trans(0, 18, 1, 64),
-            Trans(0, 20, 1, 64),
-            Trans(0, 22, 1, 64),
-            Trans(0, 23, 1, 64),
-            Trans(0, 24, 1, 64),
-            Trans(0, 25, 1, 64),
-            Trans(0, 32, 1, 64),
-            Trans(0, 33, 1, 64),
-            Trans(0, 37, 1, 64),
-            Trans(0, 55, 2, 65),
-            Trans(0, 57, 1, 64),
-            Trans(0, 101, 1, 64),
+            Trans(0, 10, 1, 65),
+            Trans(0, 11, 1, 65),
+            Trans(0, 13, 1, 65),
+            Trans(0, 14, 1, 65),
+            Trans(0, 15, 1, 65),
+            Trans(0, 16, 1, 65),
+            Trans(0, 17, 1, 65),
+            Trans(0, 18, 1, 65),
+            Trans(0, 20, 1, 65),
+            Trans(0, 22, 1, 65),
+            Trans(0, 23, 1, 65),
+            Trans(0, 24, 1, 65),
+            Trans(0, 25, 1, 65),
+            Trans(0, 32, 1, 65),
+            Trans(0, 33, 1, 65),
+            Trans(0, 37, 1, 65),
+            Trans(0, 56, 2, 66),
+            Trans(0, 59, 1, 65),
+            Trans(0, 104, 1, 65),
         ],
         k: 1,
     },
-    /* 248 - "TypeStmt" */
+    /* 260 - "TypeStmt" */
     LookaheadDFA {
-        prod0: 421,
+        prod0: 460,
         transitions: &[],
         k: 0,
     },
-    /* 249 - "TypeStmtList" */
+    /* 261 - "TypeStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 26, 1, 424),
-            Trans(0, 31, 1, 424),
-            Trans(0, 34, 1, 424),
-            Trans(0, 43, 1, 424),
-            Trans(0, 46, 1, 424),
-            Trans(0, 47, 1, 424),
-            Trans(0, 48, 1, 424),
-            Trans(0, 51, 1, 424),
-            Trans(0, 55, 2, 433),
-            Trans(0, 77, 1, 424),
-            Trans(0, 78, 1, 424),
+            Trans(0, 26, 1, 463),
+            Trans(0, 31, 1, 463),
+            Trans(0, 34, 1, 463),
+            Trans(0, 44, 1, 463),
+            Trans(0, 47, 1, 463),
+            Trans(0, 48, 1, 463),
+            Trans(0, 49, 1, 463),
+            Trans(0, 52, 1, 463),
+            Trans(0, 56, 2, 472),
+            Trans(0, 79, 1, 463),
+            Trans(0, 80, 1, 463),
         ],
         k: 1,
     },
-    /* 250 - "TypeStmtListGroup" */
+    /* 262 - "TypeStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 26, 7, 431),
-            Trans(0, 31, 3, 427),
-            Trans(0, 34, 8, 432),
-            Trans(0, 43, 4, 428),
-            Trans(0, 46, 1, 425),
-            Trans(0, 47, 5, 429),
-            Trans(0, 48, 6, 430),
-            Trans(0, 51, 2, 426),
-            Trans(0, 77, 4, 428),
-            Trans(0, 78, 5, 429),
+            Trans(0, 26, 7, 470),
+            Trans(0, 31, 3, 466),
+            Trans(0, 34, 8, 471),
+            Trans(0, 44, 4, 467),
+            Trans(0, 47, 1, 464),
+            Trans(0, 48, 5, 468),
+            Trans(0, 49, 6, 469),
+            Trans(0, 52, 2, 465),
+            Trans(0, 79, 4, 467),
+            Trans(0, 80, 5, 468),
         ],
         k: 1,
     },
-    /* 251 - "TypeStmtSuffix" */
+    /* 263 - "TypeStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 423), Trans(0, 86, 1, 422)],
+        transitions: &[Trans(0, 5, 2, 462), Trans(0, 89, 1, 461)],
         k: 1,
     },
-    /* 252 - "TypedefStmt" */
+    /* 264 - "TypedefStmt" */
     LookaheadDFA {
-        prod0: 140,
+        prod0: 165,
         transitions: &[],
         k: 0,
     },
-    /* 253 - "TypedefStmtList" */
+    /* 265 - "TypedefStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 34, 1, 141),
-            Trans(0, 39, 1, 141),
-            Trans(0, 45, 1, 141),
-            Trans(0, 55, 2, 148),
-            Trans(0, 70, 1, 141),
-            Trans(0, 91, 1, 141),
-            Trans(0, 92, 1, 141),
+            Trans(0, 34, 1, 166),
+            Trans(0, 40, 1, 166),
+            Trans(0, 46, 1, 166),
+            Trans(0, 56, 2, 173),
+            Trans(0, 72, 1, 166),
+            Trans(0, 94, 1, 166),
+            Trans(0, 95, 1, 166),
         ],
         k: 1,
     },
-    /* 254 - "TypedefStmtListGroup" */
+    /* 266 - "TypedefStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 34, 1, 142),
-            Trans(0, 39, 4, 145),
-            Trans(0, 45, 3, 144),
-            Trans(0, 70, 2, 143),
-            Trans(0, 91, 5, 146),
-            Trans(0, 92, 6, 147),
+            Trans(0, 34, 1, 167),
+            Trans(0, 40, 4, 170),
+            Trans(0, 46, 3, 169),
+            Trans(0, 72, 2, 168),
+            Trans(0, 94, 5, 171),
+            Trans(0, 95, 6, 172),
         ],
         k: 1,
     },
-    /* 255 - "UnitsStmt" */
+    /* 267 - "UnitsStmt" */
     LookaheadDFA {
-        prod0: 388,
+        prod0: 425,
         transitions: &[],
         k: 0,
     },
-    /* 256 - "UnknownStmt" */
+    /* 268 - "UnknownStmt" */
     LookaheadDFA {
-        prod0: 341,
+        prod0: 378,
         transitions: &[],
         k: 0,
     },
-    /* 257 - "UnknownStmtList" */
+    /* 269 - "UnknownStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 34, 1, 344),
-            Trans(0, 55, 2, 347),
-            Trans(0, 91, 1, 344),
+            Trans(0, 34, 1, 381),
+            Trans(0, 56, 2, 384),
+            Trans(0, 94, 1, 381),
         ],
         k: 1,
     },
-    /* 258 - "UnknownStmtListGroup" */
+    /* 270 - "UnknownStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 34, 1, 345), Trans(0, 91, 2, 346)],
+        transitions: &[Trans(0, 34, 1, 382), Trans(0, 94, 2, 383)],
         k: 1,
     },
-    /* 259 - "UnknownStmtSuffix" */
+    /* 271 - "UnknownStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 343), Trans(0, 86, 1, 342)],
+        transitions: &[Trans(0, 5, 2, 380), Trans(0, 89, 1, 379)],
         k: 1,
     },
-    /* 260 - "UriArg" */
+    /* 272 - "UriArg" */
     LookaheadDFA {
-        prod0: 514,
+        prod0: 554,
         transitions: &[],
         k: 0,
     },
-    /* 261 - "UriStr" */
+    /* 273 - "UriStr" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 513), Trans(0, 88, 1, 512)],
+        transitions: &[Trans(0, 57, 2, 553), Trans(0, 91, 1, 552)],
         k: 1,
     },
-    /* 262 - "UsesStmt" */
+    /* 274 - "UsesStmt" */
     LookaheadDFA {
-        prod0: 309,
+        prod0: 346,
         transitions: &[],
         k: 0,
     },
-    /* 263 - "UsesStmtList" */
+    /* 275 - "UsesStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 35, 1, 312),
-            Trans(0, 39, 1, 312),
-            Trans(0, 40, 1, 312),
-            Trans(0, 55, 2, 320),
-            Trans(0, 57, 1, 312),
-            Trans(0, 58, 1, 312),
-            Trans(0, 91, 1, 312),
-            Trans(0, 92, 1, 312),
+            Trans(0, 35, 1, 349),
+            Trans(0, 40, 1, 349),
+            Trans(0, 41, 1, 349),
+            Trans(0, 56, 2, 357),
+            Trans(0, 59, 1, 349),
+            Trans(0, 60, 1, 349),
+            Trans(0, 94, 1, 349),
+            Trans(0, 95, 1, 349),
         ],
         k: 1,
     },
-    /* 264 - "UsesStmtListGroup" */
+    /* 276 - "UsesStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 35, 6, 318),
-            Trans(0, 39, 3, 315),
-            Trans(0, 40, 2, 314),
-            Trans(0, 57, 7, 319),
-            Trans(0, 58, 1, 313),
-            Trans(0, 91, 4, 316),
-            Trans(0, 92, 5, 317),
+            Trans(0, 35, 6, 355),
+            Trans(0, 40, 3, 352),
+            Trans(0, 41, 2, 351),
+            Trans(0, 59, 7, 356),
+            Trans(0, 60, 1, 350),
+            Trans(0, 94, 4, 353),
+            Trans(0, 95, 5, 354),
         ],
         k: 1,
     },
-    /* 265 - "UsesStmtSuffix" */
+    /* 277 - "UsesStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 311), Trans(0, 86, 1, 310)],
+        transitions: &[Trans(0, 5, 2, 348), Trans(0, 89, 1, 347)],
         k: 1,
     },
-    /* 266 - "ValueStmt" */
+    /* 278 - "ValueStmt" */
     LookaheadDFA {
-        prod0: 464,
+        prod0: 503,
         transitions: &[],
         k: 0,
     },
-    /* 267 - "WhenStmt" */
+    /* 279 - "WhenStmt" */
     LookaheadDFA {
-        prod0: 162,
+        prod0: 187,
         transitions: &[],
         k: 0,
     },
-    /* 268 - "WhenStmtList" */
+    /* 280 - "WhenStmtList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 55, 2, 168),
-            Trans(0, 91, 1, 165),
-            Trans(0, 92, 1, 165),
+            Trans(0, 56, 2, 193),
+            Trans(0, 94, 1, 190),
+            Trans(0, 95, 1, 190),
         ],
         k: 1,
     },
-    /* 269 - "WhenStmtListGroup" */
+    /* 281 - "WhenStmtListGroup" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 91, 1, 166), Trans(0, 92, 2, 167)],
+        transitions: &[Trans(0, 94, 1, 191), Trans(0, 95, 2, 192)],
         k: 1,
     },
-    /* 270 - "WhenStmtSuffix" */
+    /* 282 - "WhenStmtSuffix" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 5, 2, 164), Trans(0, 86, 1, 163)],
+        transitions: &[Trans(0, 5, 2, 189), Trans(0, 89, 1, 188)],
         k: 1,
     },
-    /* 271 - "Yang" */
+    /* 283 - "Yang" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 8, 1, 50), Trans(0, 9, 2, 51)],
+        transitions: &[Trans(0, 8, 1, 51), Trans(0, 9, 2, 52)],
         k: 1,
     },
-    /* 272 - "YangVersionArg" */
+    /* 284 - "YangVersionArg" */
     LookaheadDFA {
-        prod0: 567,
+        prod0: 607,
         transitions: &[],
         k: 0,
     },
-    /* 273 - "YangVersionArgStr" */
+    /* 285 - "YangVersionArgStr" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 56, 2, 106), Trans(0, 104, 1, 105)],
+        transitions: &[Trans(0, 57, 2, 107), Trans(0, 107, 1, 106)],
         k: 1,
     },
-    /* 274 - "YangVersionStmt" */
+    /* 286 - "YangVersionStmt" */
     LookaheadDFA {
-        prod0: 104,
+        prod0: 105,
         transitions: &[],
         k: 0,
     },
-    /* 275 - "Ystring" */
+    /* 287 - "Ystring" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 56, 1, 529),
-            Trans(0, 100, 1, 529),
-            Trans(0, 101, 2, 530),
+            Trans(0, 57, 1, 569),
+            Trans(0, 103, 1, 569),
+            Trans(0, 104, 2, 570),
         ],
         k: 1,
     },
-    /* 276 - "YstringOpt" */
+    /* 288 - "YstringOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 5, 2, 532),
-            Trans(0, 86, 2, 532),
-            Trans(0, 94, 1, 531),
+            Trans(0, 5, 2, 572),
+            Trans(0, 89, 2, 572),
+            Trans(0, 97, 1, 571),
         ],
         k: 1,
     },
 ];
 
-pub const PRODUCTIONS: &[Production; 568] = &[
+pub const PRODUCTIONS: &[Production; 608] = &[
     // 0 - LBrace: '{';
     Production {
-        lhs: 157,
+        lhs: 168,
         production: &[ParseType::T(5)],
     },
     // 1 - EscN: "n";
     Production {
-        lhs: 64,
+        lhs: 73,
         production: &[ParseType::T(6)],
     },
     // 2 - EscT: "t";
     Production {
-        lhs: 65,
+        lhs: 74,
         production: &[ParseType::T(7)],
     },
     // 3 - KwModule: 'module';
     Production {
-        lhs: 138,
+        lhs: 149,
         production: &[ParseType::T(8)],
     },
     // 4 - KwSubmodule: 'submodule';
     Production {
-        lhs: 151,
+        lhs: 162,
         production: &[ParseType::T(9)],
     },
     // 5 - KwRpc: 'rpc';
     Production {
-        lhs: 149,
+        lhs: 160,
         production: &[ParseType::T(10)],
     },
     // 6 - KwExtension: 'extension';
     Production {
-        lhs: 124,
+        lhs: 135,
         production: &[ParseType::T(11)],
     },
     // 7 - KwArgument: 'argument';
     Production {
-        lhs: 113,
+        lhs: 123,
         production: &[ParseType::T(12)],
     },
     // 8 - KwFeature: 'feature';
     Production {
-        lhs: 125,
+        lhs: 136,
         production: &[ParseType::T(13)],
     },
     // 9 - KwTypedef: 'typedef';
     Production {
-        lhs: 153,
+        lhs: 164,
         production: &[ParseType::T(14)],
     },
     // 10 - KwGrouping: 'grouping';
     Production {
-        lhs: 127,
+        lhs: 138,
         production: &[ParseType::T(15)],
     },
     // 11 - KwIdentity: 'identity';
     Production {
-        lhs: 128,
+        lhs: 139,
         production: &[ParseType::T(16)],
     },
     // 12 - KwAnyxml: 'anyxml';
     Production {
-        lhs: 112,
+        lhs: 122,
         production: &[ParseType::T(17)],
     },
     // 13 - KwAnydata: 'anydata';
     Production {
-        lhs: 111,
+        lhs: 121,
         production: &[ParseType::T(18)],
     },
     // 14 - KwCase: 'case';
     Production {
-        lhs: 117,
+        lhs: 127,
         production: &[ParseType::T(19)],
     },
     // 15 - KwContainer: 'container';
     Production {
-        lhs: 120,
+        lhs: 130,
         production: &[ParseType::T(20)],
     },
     // 16 - KwAction: 'action';
     Production {
-        lhs: 110,
+        lhs: 120,
         production: &[ParseType::T(21)],
     },
     // 17 - KwNotification: 'notification';
     Production {
-        lhs: 140,
+        lhs: 151,
         production: &[ParseType::T(22)],
     },
     // 18 - KwLeafList: 'leaf-list';
     Production {
-        lhs: 134,
+        lhs: 145,
         production: &[ParseType::T(23)],
     },
     // 19 - KwLeaf: 'leaf';
     Production {
-        lhs: 133,
+        lhs: 144,
         production: &[ParseType::T(24)],
     },
     // 20 - KwList: 'list';
     Production {
-        lhs: 136,
+        lhs: 147,
         production: &[ParseType::T(25)],
     },
     // 21 - KwBit: 'bit';
     Production {
-        lhs: 116,
+        lhs: 126,
         production: &[ParseType::T(26)],
     },
     // 22 - KwImport: 'import';
     Production {
-        lhs: 130,
+        lhs: 141,
         production: &[ParseType::T(27)],
     },
     // 23 - KwInclude: 'include';
     Production {
-        lhs: 131,
+        lhs: 142,
         production: &[ParseType::T(28)],
     },
     // 24 - KwPrefix: 'prefix';
     Production {
-        lhs: 143,
+        lhs: 154,
         production: &[ParseType::T(29)],
     },
     // 25 - KwBelongsTo: 'belongs-to';
     Production {
-        lhs: 115,
+        lhs: 125,
         production: &[ParseType::T(30)],
     },
     // 26 - KwBase: 'base';
     Production {
-        lhs: 114,
+        lhs: 124,
         production: &[ParseType::T(31)],
     },
     // 27 - KwChoice: 'choice';
     Production {
-        lhs: 118,
+        lhs: 128,
         production: &[ParseType::T(32)],
     },
     // 28 - KwUses: 'uses';
     Production {
-        lhs: 154,
+        lhs: 165,
         production: &[ParseType::T(33)],
     },
     // 29 - KwType: 'type';
     Production {
-        lhs: 152,
+        lhs: 163,
         production: &[ParseType::T(34)],
     },
     // 30 - KwRefine: 'refine';
     Production {
-        lhs: 145,
+        lhs: 156,
         production: &[ParseType::T(35)],
     },
     // 31 - KwKey: 'key';
     Production {
-        lhs: 132,
+        lhs: 143,
         production: &[ParseType::T(36)],
     },
     // 32 - KwDeviation: 'deviation';
     Production {
-        lhs: 122,
+        lhs: 133,
         production: &[ParseType::T(37)],
     },
-    // 33 - KwYangVersion: 'yang-version';
+    // 33 - KwDeviate: 'deviate';
     Production {
-        lhs: 156,
+        lhs: 132,
         production: &[ParseType::T(38)],
     },
-    // 34 - KwStatus: 'status';
+    // 34 - KwYangVersion: 'yang-version';
     Production {
-        lhs: 150,
+        lhs: 167,
         production: &[ParseType::T(39)],
     },
-    // 35 - KwIfFeature: 'if-feature';
+    // 35 - KwStatus: 'status';
     Production {
-        lhs: 129,
+        lhs: 161,
         production: &[ParseType::T(40)],
     },
-    // 36 - KwMandatory: 'mandatory';
+    // 36 - KwIfFeature: 'if-feature';
     Production {
-        lhs: 137,
+        lhs: 140,
         production: &[ParseType::T(41)],
     },
-    // 37 - KwConfig: 'config';
+    // 37 - KwMandatory: 'mandatory';
     Production {
-        lhs: 119,
+        lhs: 148,
         production: &[ParseType::T(42)],
     },
-    // 38 - KwRequireInstance: 'require-instance';
+    // 38 - KwConfig: 'config';
     Production {
-        lhs: 146,
+        lhs: 129,
         production: &[ParseType::T(43)],
     },
-    // 39 - KwOrderedBy: 'ordered-by';
+    // 39 - KwRequireInstance: 'require-instance';
     Production {
-        lhs: 141,
+        lhs: 157,
         production: &[ParseType::T(44)],
     },
-    // 40 - KwDefault: 'default';
+    // 40 - KwOrderedBy: 'ordered-by';
     Production {
-        lhs: 121,
+        lhs: 152,
         production: &[ParseType::T(45)],
     },
-    // 41 - KwFractionDigits: 'fraction-digits';
+    // 41 - KwDefault: 'default';
     Production {
-        lhs: 126,
+        lhs: 131,
         production: &[ParseType::T(46)],
     },
-    // 42 - KwLength: 'length';
+    // 42 - KwFractionDigits: 'fraction-digits';
     Production {
-        lhs: 135,
+        lhs: 137,
         production: &[ParseType::T(47)],
     },
-    // 43 - KwRange: 'range';
+    // 43 - KwLength: 'length';
     Production {
-        lhs: 144,
+        lhs: 146,
         production: &[ParseType::T(48)],
     },
-    // 44 - KwValue: 'value';
+    // 44 - KwRange: 'range';
     Production {
         lhs: 155,
         production: &[ParseType::T(49)],
     },
-    // 45 - KwPosition: 'position';
+    // 45 - KwValue: 'value';
     Production {
-        lhs: 142,
+        lhs: 166,
         production: &[ParseType::T(50)],
     },
-    // 46 - KwEnum: 'enum';
+    // 46 - KwPosition: 'position';
     Production {
-        lhs: 123,
+        lhs: 153,
         production: &[ParseType::T(51)],
     },
-    // 47 - KwRevision: 'revision';
+    // 47 - KwEnum: 'enum';
     Production {
-        lhs: 147,
+        lhs: 134,
         production: &[ParseType::T(52)],
     },
-    // 48 - KwRevisionDate: 'revision-date';
+    // 48 - KwRevision: 'revision';
     Production {
-        lhs: 148,
+        lhs: 158,
         production: &[ParseType::T(53)],
     },
-    // 49 - KwNamespace: 'namespace';
+    // 49 - KwRevisionDate: 'revision-date';
     Production {
-        lhs: 139,
+        lhs: 159,
         production: &[ParseType::T(54)],
     },
-    // 50 - Yang: ModuleStmt;
+    // 50 - KwNamespace: 'namespace';
     Production {
-        lhs: 271,
-        production: &[ParseType::N(177)],
+        lhs: 150,
+        production: &[ParseType::T(55)],
     },
-    // 51 - Yang: SubmoduleStmt;
+    // 51 - Yang: ModuleStmt;
     Production {
-        lhs: 271,
-        production: &[ParseType::N(242)],
+        lhs: 283,
+        production: &[ParseType::N(188)],
     },
-    // 52 - ModuleStmt: KwModule^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ ModuleStmtList /* Vec */ ModuleStmtList0 /* Vec */ ModuleStmtList1 /* Vec */ ModuleStmtList2 /* Vec */ ModuleStmtList3 /* Vec */ '}'^ /* Clipped */;
+    // 52 - Yang: SubmoduleStmt;
     Production {
-        lhs: 177,
+        lhs: 283,
+        production: &[ParseType::N(254)],
+    },
+    // 53 - ModuleStmt: KwModule^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ ModuleStmtList /* Vec */ ModuleStmtList0 /* Vec */ ModuleStmtList1 /* Vec */ ModuleStmtList2 /* Vec */ ModuleStmtList3 /* Vec */ '}'^ /* Clipped */;
+    Production {
+        lhs: 188,
         production: &[
-            ParseType::T(55),
-            ParseType::N(182),
-            ParseType::N(181),
-            ParseType::N(180),
-            ParseType::N(179),
-            ParseType::N(178),
-            ParseType::N(157),
-            ParseType::N(81),
-            ParseType::N(138),
+            ParseType::T(56),
+            ParseType::N(193),
+            ParseType::N(192),
+            ParseType::N(191),
+            ParseType::N(190),
+            ParseType::N(189),
+            ParseType::N(168),
+            ParseType::N(90),
+            ParseType::N(149),
         ],
     },
-    // 53 - ModuleStmtList3: BodyStmts ModuleStmtList3;
+    // 54 - ModuleStmtList3: BodyStmts ModuleStmtList3;
     Production {
-        lhs: 182,
-        production: &[ParseType::N(182), ParseType::N(27)],
+        lhs: 193,
+        production: &[ParseType::N(193), ParseType::N(27)],
     },
-    // 54 - ModuleStmtList3: ;
+    // 55 - ModuleStmtList3: ;
     Production {
-        lhs: 182,
+        lhs: 193,
         production: &[],
     },
-    // 55 - ModuleStmtList2: RevisionStmt ModuleStmtList2;
+    // 56 - ModuleStmtList2: RevisionStmt ModuleStmtList2;
     Production {
-        lhs: 181,
-        production: &[ParseType::N(181), ParseType::N(220)],
+        lhs: 192,
+        production: &[ParseType::N(192), ParseType::N(232)],
     },
-    // 56 - ModuleStmtList2: ;
+    // 57 - ModuleStmtList2: ;
     Production {
-        lhs: 181,
+        lhs: 192,
         production: &[],
     },
-    // 57 - ModuleStmtList1: MetaStmts ModuleStmtList1;
+    // 58 - ModuleStmtList1: MetaStmts ModuleStmtList1;
     Production {
-        lhs: 180,
-        production: &[ParseType::N(180), ParseType::N(174)],
+        lhs: 191,
+        production: &[ParseType::N(191), ParseType::N(185)],
     },
-    // 58 - ModuleStmtList1: ;
+    // 59 - ModuleStmtList1: ;
     Production {
-        lhs: 180,
+        lhs: 191,
         production: &[],
     },
-    // 59 - ModuleStmtList0: LinkageStmts ModuleStmtList0;
+    // 60 - ModuleStmtList0: LinkageStmts ModuleStmtList0;
     Production {
-        lhs: 179,
-        production: &[ParseType::N(179), ParseType::N(166)],
+        lhs: 190,
+        production: &[ParseType::N(190), ParseType::N(177)],
     },
-    // 60 - ModuleStmtList0: ;
+    // 61 - ModuleStmtList0: ;
     Production {
-        lhs: 179,
+        lhs: 190,
         production: &[],
     },
-    // 61 - ModuleStmtList: ModuleHeaderStmts ModuleStmtList;
+    // 62 - ModuleStmtList: ModuleHeaderStmts ModuleStmtList;
     Production {
-        lhs: 178,
-        production: &[ParseType::N(178), ParseType::N(176)],
+        lhs: 189,
+        production: &[ParseType::N(189), ParseType::N(187)],
     },
-    // 62 - ModuleStmtList: ;
+    // 63 - ModuleStmtList: ;
     Production {
-        lhs: 178,
+        lhs: 189,
         production: &[],
     },
-    // 63 - SubmoduleStmt: KwSubmodule^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ SubmoduleStmtList /* Vec */ SubmoduleStmtList0 /* Vec */ SubmoduleStmtList1 /* Vec */ SubmoduleStmtList2 /* Vec */ SubmoduleStmtList3 /* Vec */ '}'^ /* Clipped */;
+    // 64 - SubmoduleStmt: KwSubmodule^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ SubmoduleStmtList /* Vec */ SubmoduleStmtList0 /* Vec */ SubmoduleStmtList1 /* Vec */ SubmoduleStmtList2 /* Vec */ SubmoduleStmtList3 /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 242,
+        lhs: 254,
         production: &[
-            ParseType::T(55),
-            ParseType::N(247),
-            ParseType::N(246),
-            ParseType::N(245),
-            ParseType::N(244),
-            ParseType::N(243),
-            ParseType::N(157),
-            ParseType::N(81),
-            ParseType::N(151),
+            ParseType::T(56),
+            ParseType::N(259),
+            ParseType::N(258),
+            ParseType::N(257),
+            ParseType::N(256),
+            ParseType::N(255),
+            ParseType::N(168),
+            ParseType::N(90),
+            ParseType::N(162),
         ],
     },
-    // 64 - SubmoduleStmtList3: BodyStmts SubmoduleStmtList3;
+    // 65 - SubmoduleStmtList3: BodyStmts SubmoduleStmtList3;
     Production {
-        lhs: 247,
-        production: &[ParseType::N(247), ParseType::N(27)],
+        lhs: 259,
+        production: &[ParseType::N(259), ParseType::N(27)],
     },
-    // 65 - SubmoduleStmtList3: ;
+    // 66 - SubmoduleStmtList3: ;
     Production {
-        lhs: 247,
+        lhs: 259,
         production: &[],
     },
-    // 66 - SubmoduleStmtList2: RevisionStmt SubmoduleStmtList2;
+    // 67 - SubmoduleStmtList2: RevisionStmt SubmoduleStmtList2;
     Production {
-        lhs: 246,
-        production: &[ParseType::N(246), ParseType::N(220)],
+        lhs: 258,
+        production: &[ParseType::N(258), ParseType::N(232)],
     },
-    // 67 - SubmoduleStmtList2: ;
+    // 68 - SubmoduleStmtList2: ;
     Production {
-        lhs: 246,
+        lhs: 258,
         production: &[],
     },
-    // 68 - SubmoduleStmtList1: MetaStmts SubmoduleStmtList1;
+    // 69 - SubmoduleStmtList1: MetaStmts SubmoduleStmtList1;
     Production {
-        lhs: 245,
-        production: &[ParseType::N(245), ParseType::N(174)],
+        lhs: 257,
+        production: &[ParseType::N(257), ParseType::N(185)],
     },
-    // 69 - SubmoduleStmtList1: ;
+    // 70 - SubmoduleStmtList1: ;
     Production {
-        lhs: 245,
+        lhs: 257,
         production: &[],
     },
-    // 70 - SubmoduleStmtList0: LinkageStmts SubmoduleStmtList0;
+    // 71 - SubmoduleStmtList0: LinkageStmts SubmoduleStmtList0;
     Production {
-        lhs: 244,
-        production: &[ParseType::N(244), ParseType::N(166)],
+        lhs: 256,
+        production: &[ParseType::N(256), ParseType::N(177)],
     },
-    // 71 - SubmoduleStmtList0: ;
+    // 72 - SubmoduleStmtList0: ;
     Production {
-        lhs: 244,
+        lhs: 256,
         production: &[],
     },
-    // 72 - SubmoduleStmtList: SubmoduleHeaderStmts SubmoduleStmtList;
+    // 73 - SubmoduleStmtList: SubmoduleHeaderStmts SubmoduleStmtList;
     Production {
-        lhs: 243,
-        production: &[ParseType::N(243), ParseType::N(241)],
+        lhs: 255,
+        production: &[ParseType::N(255), ParseType::N(253)],
     },
-    // 73 - SubmoduleStmtList: ;
+    // 74 - SubmoduleStmtList: ;
     Production {
-        lhs: 243,
+        lhs: 255,
         production: &[],
     },
-    // 74 - ModuleHeaderStmts: YangVersionStmt;
+    // 75 - ModuleHeaderStmts: YangVersionStmt;
     Production {
-        lhs: 176,
-        production: &[ParseType::N(274)],
+        lhs: 187,
+        production: &[ParseType::N(286)],
     },
-    // 75 - ModuleHeaderStmts: NamespaceStmt;
+    // 76 - ModuleHeaderStmts: NamespaceStmt;
     Production {
-        lhs: 176,
-        production: &[ParseType::N(187)],
+        lhs: 187,
+        production: &[ParseType::N(198)],
     },
-    // 76 - ModuleHeaderStmts: PrefixStmt;
+    // 77 - ModuleHeaderStmts: PrefixStmt;
     Production {
-        lhs: 176,
-        production: &[ParseType::N(201)],
+        lhs: 187,
+        production: &[ParseType::N(213)],
     },
-    // 77 - SubmoduleHeaderStmts: YangVersionStmt;
+    // 78 - SubmoduleHeaderStmts: YangVersionStmt;
     Production {
-        lhs: 241,
-        production: &[ParseType::N(274)],
+        lhs: 253,
+        production: &[ParseType::N(286)],
     },
-    // 78 - SubmoduleHeaderStmts: BelongsToStmt;
+    // 79 - SubmoduleHeaderStmts: BelongsToStmt;
     Production {
-        lhs: 241,
+        lhs: 253,
         production: &[ParseType::N(22)],
     },
-    // 79 - MetaStmts: OrganizationStmt;
+    // 80 - MetaStmts: OrganizationStmt;
     Production {
-        lhs: 174,
-        production: &[ParseType::N(195)],
+        lhs: 185,
+        production: &[ParseType::N(206)],
     },
-    // 80 - MetaStmts: ContactStmt;
+    // 81 - MetaStmts: ContactStmt;
     Production {
-        lhs: 174,
+        lhs: 185,
         production: &[ParseType::N(37)],
     },
-    // 81 - MetaStmts: DescriptionStmt;
+    // 82 - MetaStmts: DescriptionStmt;
     Production {
-        lhs: 174,
+        lhs: 185,
         production: &[ParseType::N(55)],
     },
-    // 82 - MetaStmts: ReferenceStmt;
+    // 83 - MetaStmts: ReferenceStmt;
     Production {
-        lhs: 174,
-        production: &[ParseType::N(210)],
+        lhs: 185,
+        production: &[ParseType::N(222)],
     },
-    // 83 - LinkageStmts: ImportStmt;
+    // 84 - LinkageStmts: ImportStmt;
     Production {
-        lhs: 166,
-        production: &[ParseType::N(95)],
+        lhs: 177,
+        production: &[ParseType::N(104)],
     },
-    // 84 - LinkageStmts: IncludeStmt;
+    // 85 - LinkageStmts: IncludeStmt;
     Production {
-        lhs: 166,
-        production: &[ParseType::N(98)],
+        lhs: 177,
+        production: &[ParseType::N(107)],
     },
-    // 85 - BodyStmts: ExtensionStmt;
+    // 86 - BodyStmts: ExtensionStmt;
     Production {
         lhs: 27,
-        production: &[ParseType::N(67)],
+        production: &[ParseType::N(76)],
     },
-    // 86 - BodyStmts: FeatureStmt;
+    // 87 - BodyStmts: FeatureStmt;
     Production {
         lhs: 27,
-        production: &[ParseType::N(71)],
+        production: &[ParseType::N(80)],
     },
-    // 87 - BodyStmts: IdentityStmt;
+    // 88 - BodyStmts: IdentityStmt;
     Production {
         lhs: 27,
-        production: &[ParseType::N(85)],
+        production: &[ParseType::N(94)],
     },
-    // 88 - BodyStmts: TypedefStmt;
+    // 89 - BodyStmts: TypedefStmt;
     Production {
         lhs: 27,
-        production: &[ParseType::N(252)],
+        production: &[ParseType::N(264)],
     },
-    // 89 - BodyStmts: GroupingStmt;
+    // 90 - BodyStmts: GroupingStmt;
     Production {
         lhs: 27,
-        production: &[ParseType::N(77)],
+        production: &[ParseType::N(86)],
     },
-    // 90 - BodyStmts: DataDefStmt;
+    // 91 - BodyStmts: DataDefStmt;
     Production {
         lhs: 27,
         production: &[ParseType::N(49)],
     },
-    // 91 - BodyStmts: AugmentStmt;
+    // 92 - BodyStmts: AugmentStmt;
     Production {
         lhs: 27,
         production: &[ParseType::N(17)],
     },
-    // 92 - BodyStmts: RpcStmt;
+    // 93 - BodyStmts: RpcStmt;
     Production {
         lhs: 27,
-        production: &[ParseType::N(223)],
+        production: &[ParseType::N(235)],
     },
-    // 93 - BodyStmts: NotificationStmt;
+    // 94 - BodyStmts: NotificationStmt;
     Production {
         lhs: 27,
-        production: &[ParseType::N(189)],
+        production: &[ParseType::N(200)],
     },
-    // 94 - BodyStmts: DeviationStmt;
+    // 95 - BodyStmts: DeviationStmt;
     Production {
         lhs: 27,
-        production: &[ParseType::N(56)],
+        production: &[ParseType::N(62)],
     },
-    // 95 - BodyStmts: UnknownStmt;
+    // 96 - BodyStmts: UnknownStmt;
     Production {
         lhs: 27,
-        production: &[ParseType::N(256)],
+        production: &[ParseType::N(268)],
     },
-    // 96 - DataDefStmt: ContainerStmt;
+    // 97 - DataDefStmt: ContainerStmt;
     Production {
         lhs: 49,
         production: &[ParseType::N(38)],
     },
-    // 97 - DataDefStmt: LeafStmt;
+    // 98 - DataDefStmt: LeafStmt;
     Production {
         lhs: 49,
-        production: &[ParseType::N(161)],
+        production: &[ParseType::N(172)],
     },
-    // 98 - DataDefStmt: LeafListStmt;
+    // 99 - DataDefStmt: LeafListStmt;
     Production {
         lhs: 49,
-        production: &[ParseType::N(158)],
+        production: &[ParseType::N(169)],
     },
-    // 99 - DataDefStmt: ListStmt;
+    // 100 - DataDefStmt: ListStmt;
     Production {
         lhs: 49,
-        production: &[ParseType::N(167)],
+        production: &[ParseType::N(178)],
     },
-    // 100 - DataDefStmt: ChoiceStmt;
+    // 101 - DataDefStmt: ChoiceStmt;
     Production {
         lhs: 49,
         production: &[ParseType::N(32)],
     },
-    // 101 - DataDefStmt: AnydataStmt;
+    // 102 - DataDefStmt: AnydataStmt;
     Production {
         lhs: 49,
         production: &[ParseType::N(5)],
     },
-    // 102 - DataDefStmt: AnyxmlStmt;
+    // 103 - DataDefStmt: AnyxmlStmt;
     Production {
         lhs: 49,
         production: &[ParseType::N(9)],
     },
-    // 103 - DataDefStmt: UsesStmt;
+    // 104 - DataDefStmt: UsesStmt;
     Production {
         lhs: 49,
-        production: &[ParseType::N(262)],
-    },
-    // 104 - YangVersionStmt: KwYangVersion^ /* Clipped */ YangVersionArgStr Semicolon^ /* Clipped */;
-    Production {
-        lhs: 274,
-        production: &[ParseType::N(234), ParseType::N(273), ParseType::N(156)],
+        production: &[ParseType::N(274)],
     },
-    // 105 - YangVersionArgStr: YangVersionArg;
+    // 105 - YangVersionStmt: KwYangVersion^ /* Clipped */ YangVersionArgStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 273,
-        production: &[ParseType::N(272)],
+        lhs: 286,
+        production: &[ParseType::N(246), ParseType::N(285), ParseType::N(167)],
     },
-    // 106 - YangVersionArgStr: '"'^ /* Clipped */ YangVersionArg '"'^ /* Clipped */;
+    // 106 - YangVersionArgStr: YangVersionArg;
     Production {
-        lhs: 273,
-        production: &[ParseType::T(56), ParseType::N(272), ParseType::T(56)],
+        lhs: 285,
+        production: &[ParseType::N(284)],
     },
-    // 107 - DeviationStmt: KwDeviation^ /* Clipped */ AbsoluteSchemaNodeid Semicolon;
+    // 107 - YangVersionArgStr: '"'^ /* Clipped */ YangVersionArg '"'^ /* Clipped */;
     Production {
-        lhs: 56,
-        production: &[ParseType::N(234), ParseType::N(0), ParseType::N(122)],
+        lhs: 285,
+        production: &[ParseType::T(57), ParseType::N(284), ParseType::T(57)],
     },
-    // 108 - RpcStmt: KwRpc^ /* Clipped */ IdentifierArgStr RpcStmtSuffix;
+    // 108 - DeviationStmt: KwDeviation^ /* Clipped */ AugmentArgStr DeviationStmtSuffix;
     Production {
-        lhs: 223,
-        production: &[ParseType::N(226), ParseType::N(81), ParseType::N(149)],
+        lhs: 62,
+        production: &[ParseType::N(65), ParseType::N(16), ParseType::N(133)],
     },
-    // 109 - RpcStmtSuffix: Semicolon;
+    // 109 - DeviationStmtSuffix: Semicolon^ /* Clipped */;
     Production {
-        lhs: 226,
-        production: &[ParseType::N(234)],
+        lhs: 65,
+        production: &[ParseType::N(246)],
     },
-    // 110 - RpcStmtSuffix: LBrace^ /* Clipped */ RpcStmtList /* Vec */ '}';
+    // 110 - DeviationStmtSuffix: LBrace^ /* Clipped */ DeviationStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 226,
-        production: &[ParseType::T(55), ParseType::N(224), ParseType::N(157)],
+        lhs: 65,
+        production: &[ParseType::T(56), ParseType::N(63), ParseType::N(168)],
     },
-    // 111 - RpcStmtList: RpcStmtListGroup RpcStmtList;
+    // 111 - DeviationStmtList: DeviationStmtListGroup DeviationStmtList;
     Production {
-        lhs: 224,
-        production: &[ParseType::N(224), ParseType::N(225)],
+        lhs: 63,
+        production: &[ParseType::N(63), ParseType::N(64)],
     },
-    // 112 - RpcStmtListGroup: IfFeatureStmt;
+    // 112 - DeviationStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 225,
-        production: &[ParseType::N(92)],
+        lhs: 64,
+        production: &[ParseType::N(55)],
     },
-    // 113 - RpcStmtListGroup: StatusStmt;
+    // 113 - DeviationStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 225,
-        production: &[ParseType::N(239)],
+        lhs: 64,
+        production: &[ParseType::N(222)],
     },
-    // 114 - RpcStmtListGroup: DescriptionStmt;
+    // 114 - DeviationStmtListGroup: DeviateStmt;
     Production {
-        lhs: 225,
-        production: &[ParseType::N(55)],
+        lhs: 64,
+        production: &[ParseType::N(58)],
     },
-    // 115 - RpcStmtListGroup: ReferenceStmt;
+    // 115 - DeviationStmtList: ;
     Production {
-        lhs: 225,
-        production: &[ParseType::N(210)],
+        lhs: 63,
+        production: &[],
     },
-    // 116 - RpcStmtListGroup: TypedefStmt;
+    // 116 - DeviateStmt: KwDeviate^ /* Clipped */ DeviateArgStr DeviateStmtSuffix;
     Production {
-        lhs: 225,
-        production: &[ParseType::N(252)],
+        lhs: 58,
+        production: &[ParseType::N(61), ParseType::N(57), ParseType::N(132)],
     },
-    // 117 - RpcStmtListGroup: GroupingStmt;
+    // 117 - DeviateStmtSuffix: Semicolon^ /* Clipped */;
     Production {
-        lhs: 225,
-        production: &[ParseType::N(77)],
+        lhs: 61,
+        production: &[ParseType::N(246)],
     },
-    // 118 - RpcStmtListGroup: InputStmt;
+    // 118 - DeviateStmtSuffix: LBrace^ /* Clipped */ DeviateStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 225,
-        production: &[ParseType::N(102)],
+        lhs: 61,
+        production: &[ParseType::T(56), ParseType::N(59), ParseType::N(168)],
     },
-    // 119 - RpcStmtListGroup: OutputStmt;
+    // 119 - DeviateStmtList: DeviateStmtListGroup DeviateStmtList;
     Production {
-        lhs: 225,
-        production: &[ParseType::N(196)],
+        lhs: 59,
+        production: &[ParseType::N(59), ParseType::N(60)],
     },
-    // 120 - RpcStmtList: ;
+    // 120 - DeviateStmtListGroup: UnitsStmt;
     Production {
-        lhs: 224,
-        production: &[],
+        lhs: 60,
+        production: &[ParseType::N(267)],
     },
-    // 121 - ExtensionStmt: KwExtension^ /* Clipped */ IdentifierArgStr ExtensionStmtSuffix;
+    // 121 - DeviateStmtListGroup: MustStmt;
     Production {
-        lhs: 67,
-        production: &[ParseType::N(70), ParseType::N(81), ParseType::N(124)],
+        lhs: 60,
+        production: &[ParseType::N(194)],
     },
-    // 122 - ExtensionStmtSuffix: Semicolon^ /* Clipped */;
+    // 122 - DeviateStmtListGroup: UnknownStmt;
     Production {
-        lhs: 70,
-        production: &[ParseType::N(234)],
+        lhs: 60,
+        production: &[ParseType::N(268)],
     },
-    // 123 - ExtensionStmtSuffix: LBrace^ /* Clipped */ ExtensionStmtList /* Vec */ '}'^ /* Clipped */;
+    // 123 - DeviateStmtListGroup: DefaultStmt;
     Production {
-        lhs: 70,
-        production: &[ParseType::T(55), ParseType::N(68), ParseType::N(157)],
+        lhs: 60,
+        production: &[ParseType::N(52)],
     },
-    // 124 - ExtensionStmtList: ExtensionStmtListGroup ExtensionStmtList;
+    // 124 - DeviateStmtListGroup: ConfigStmt;
     Production {
-        lhs: 68,
-        production: &[ParseType::N(68), ParseType::N(69)],
+        lhs: 60,
+        production: &[ParseType::N(36)],
     },
-    // 125 - ExtensionStmtListGroup: ArgumentStmt;
+    // 125 - DeviateStmtListGroup: MandatoryStmt;
     Production {
-        lhs: 69,
-        production: &[ParseType::N(13)],
+        lhs: 60,
+        production: &[ParseType::N(183)],
     },
-    // 126 - ExtensionStmtListGroup: StatusStmt;
+    // 126 - DeviateStmtListGroup: MinElementsStmt;
     Production {
-        lhs: 69,
-        production: &[ParseType::N(239)],
+        lhs: 60,
+        production: &[ParseType::N(186)],
     },
-    // 127 - ExtensionStmtListGroup: DescriptionStmt;
+    // 127 - DeviateStmtListGroup: MaxElementsStmt;
     Production {
-        lhs: 69,
-        production: &[ParseType::N(55)],
+        lhs: 60,
+        production: &[ParseType::N(184)],
     },
-    // 128 - ExtensionStmtListGroup: ReferenceStmt;
+    // 128 - DeviateStmtListGroup: TypeStmt;
     Production {
-        lhs: 69,
-        production: &[ParseType::N(210)],
+        lhs: 60,
+        production: &[ParseType::N(260)],
     },
-    // 129 - ExtensionStmtList: ;
+    // 129 - DeviateStmtList: ;
     Production {
-        lhs: 68,
+        lhs: 59,
         production: &[],
     },
-    // 130 - ArgumentStmt: KwArgument^ /* Clipped */ IdentifierArgStr Semicolon^ /* Clipped */;
+    // 130 - DeviateArgStr: DeviateArg;
     Production {
-        lhs: 13,
-        production: &[ParseType::N(234), ParseType::N(81), ParseType::N(113)],
+        lhs: 57,
+        production: &[ParseType::N(56)],
     },
-    // 131 - FeatureStmt: KwFeature^ /* Clipped */ IdentifierArgStr FeatureStmtSuffix;
+    // 131 - DeviateArgStr: '"'^ /* Clipped */ DeviateArg '"'^ /* Clipped */;
     Production {
-        lhs: 71,
-        production: &[ParseType::N(74), ParseType::N(81), ParseType::N(125)],
+        lhs: 57,
+        production: &[ParseType::T(57), ParseType::N(56), ParseType::T(57)],
     },
-    // 132 - FeatureStmtSuffix: Semicolon^ /* Clipped */;
+    // 132 - DeviateArg: /not-supported|add|replace|delete/;
     Production {
-        lhs: 74,
-        production: &[ParseType::N(234)],
+        lhs: 56,
+        production: &[ParseType::T(58)],
     },
-    // 133 - FeatureStmtSuffix: LBrace^ /* Clipped */ FeatureStmtList /* Vec */ '}';
+    // 133 - RpcStmt: KwRpc^ /* Clipped */ IdentifierArgStr RpcStmtSuffix;
     Production {
-        lhs: 74,
-        production: &[ParseType::T(55), ParseType::N(72), ParseType::N(157)],
+        lhs: 235,
+        production: &[ParseType::N(238), ParseType::N(90), ParseType::N(160)],
     },
-    // 134 - FeatureStmtList: FeatureStmtListGroup FeatureStmtList;
+    // 134 - RpcStmtSuffix: Semicolon;
     Production {
-        lhs: 72,
-        production: &[ParseType::N(72), ParseType::N(73)],
+        lhs: 238,
+        production: &[ParseType::N(246)],
     },
-    // 135 - FeatureStmtListGroup: IfFeatureStmt;
+    // 135 - RpcStmtSuffix: LBrace^ /* Clipped */ RpcStmtList /* Vec */ '}';
     Production {
-        lhs: 73,
-        production: &[ParseType::N(92)],
+        lhs: 238,
+        production: &[ParseType::T(56), ParseType::N(236), ParseType::N(168)],
     },
-    // 136 - FeatureStmtListGroup: StatusStmt;
+    // 136 - RpcStmtList: RpcStmtListGroup RpcStmtList;
     Production {
-        lhs: 73,
-        production: &[ParseType::N(239)],
+        lhs: 236,
+        production: &[ParseType::N(236), ParseType::N(237)],
     },
-    // 137 - FeatureStmtListGroup: DescriptionStmt;
+    // 137 - RpcStmtListGroup: IfFeatureStmt;
     Production {
-        lhs: 73,
-        production: &[ParseType::N(55)],
+        lhs: 237,
+        production: &[ParseType::N(101)],
     },
-    // 138 - FeatureStmtListGroup: ReferenceStmt;
+    // 138 - RpcStmtListGroup: StatusStmt;
     Production {
-        lhs: 73,
-        production: &[ParseType::N(210)],
+        lhs: 237,
+        production: &[ParseType::N(251)],
     },
-    // 139 - FeatureStmtList: ;
+    // 139 - RpcStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 72,
-        production: &[],
+        lhs: 237,
+        production: &[ParseType::N(55)],
     },
-    // 140 - TypedefStmt: KwTypedef^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ TypedefStmtList /* Vec */ '}'^ /* Clipped */;
+    // 140 - RpcStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 252,
-        production: &[
-            ParseType::T(55),
-            ParseType::N(253),
-            ParseType::N(157),
-            ParseType::N(81),
-            ParseType::N(153),
-        ],
+        lhs: 237,
+        production: &[ParseType::N(222)],
     },
-    // 141 - TypedefStmtList: TypedefStmtListGroup TypedefStmtList;
+    // 141 - RpcStmtListGroup: TypedefStmt;
     Production {
-        lhs: 253,
-        production: &[ParseType::N(253), ParseType::N(254)],
+        lhs: 237,
+        production: &[ParseType::N(264)],
     },
-    // 142 - TypedefStmtListGroup: TypeStmt;
+    // 142 - RpcStmtListGroup: GroupingStmt;
     Production {
-        lhs: 254,
-        production: &[ParseType::N(248)],
+        lhs: 237,
+        production: &[ParseType::N(86)],
     },
-    // 143 - TypedefStmtListGroup: UnitsStmt;
+    // 143 - RpcStmtListGroup: InputStmt;
     Production {
-        lhs: 254,
-        production: &[ParseType::N(255)],
+        lhs: 237,
+        production: &[ParseType::N(111)],
     },
-    // 144 - TypedefStmtListGroup: DefaultStmt;
+    // 144 - RpcStmtListGroup: OutputStmt;
     Production {
-        lhs: 254,
-        production: &[ParseType::N(52)],
+        lhs: 237,
+        production: &[ParseType::N(207)],
     },
-    // 145 - TypedefStmtListGroup: StatusStmt;
+    // 145 - RpcStmtList: ;
     Production {
-        lhs: 254,
-        production: &[ParseType::N(239)],
+        lhs: 236,
+        production: &[],
     },
-    // 146 - TypedefStmtListGroup: DescriptionStmt;
+    // 146 - ExtensionStmt: KwExtension^ /* Clipped */ IdentifierArgStr ExtensionStmtSuffix;
     Production {
-        lhs: 254,
-        production: &[ParseType::N(55)],
+        lhs: 76,
+        production: &[ParseType::N(79), ParseType::N(90), ParseType::N(135)],
     },
-    // 147 - TypedefStmtListGroup: ReferenceStmt;
+    // 147 - ExtensionStmtSuffix: Semicolon^ /* Clipped */;
     Production {
-        lhs: 254,
-        production: &[ParseType::N(210)],
+        lhs: 79,
+        production: &[ParseType::N(246)],
     },
-    // 148 - TypedefStmtList: ;
+    // 148 - ExtensionStmtSuffix: LBrace^ /* Clipped */ ExtensionStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 253,
-        production: &[],
+        lhs: 79,
+        production: &[ParseType::T(56), ParseType::N(77), ParseType::N(168)],
     },
-    // 149 - AugmentStmt: 'augment'^ /* Clipped */ AugmentArgStr LBrace^ /* Clipped */ AugmentStmtList /* Vec */ '}'^ /* Clipped */;
+    // 149 - ExtensionStmtList: ExtensionStmtListGroup ExtensionStmtList;
     Production {
-        lhs: 17,
-        production: &[
-            ParseType::T(55),
-            ParseType::N(18),
-            ParseType::N(157),
-            ParseType::N(16),
-            ParseType::T(57),
-        ],
+        lhs: 77,
+        production: &[ParseType::N(77), ParseType::N(78)],
     },
-    // 150 - AugmentStmtList: AugmentStmtListGroup AugmentStmtList;
+    // 150 - ExtensionStmtListGroup: ArgumentStmt;
     Production {
-        lhs: 18,
-        production: &[ParseType::N(18), ParseType::N(19)],
+        lhs: 78,
+        production: &[ParseType::N(13)],
     },
-    // 151 - AugmentStmtListGroup: WhenStmt;
+    // 151 - ExtensionStmtListGroup: StatusStmt;
     Production {
-        lhs: 19,
-        production: &[ParseType::N(267)],
+        lhs: 78,
+        production: &[ParseType::N(251)],
     },
-    // 152 - AugmentStmtListGroup: IfFeatureStmt;
+    // 152 - ExtensionStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 19,
-        production: &[ParseType::N(92)],
+        lhs: 78,
+        production: &[ParseType::N(55)],
     },
-    // 153 - AugmentStmtListGroup: StatusStmt;
+    // 153 - ExtensionStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 19,
-        production: &[ParseType::N(239)],
+        lhs: 78,
+        production: &[ParseType::N(222)],
     },
-    // 154 - AugmentStmtListGroup: DescriptionStmt;
+    // 154 - ExtensionStmtList: ;
     Production {
-        lhs: 19,
-        production: &[ParseType::N(55)],
+        lhs: 77,
+        production: &[],
     },
-    // 155 - AugmentStmtListGroup: ReferenceStmt;
+    // 155 - ArgumentStmt: KwArgument^ /* Clipped */ IdentifierArgStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 19,
-        production: &[ParseType::N(210)],
+        lhs: 13,
+        production: &[ParseType::N(246), ParseType::N(90), ParseType::N(123)],
     },
-    // 156 - AugmentStmtListGroup: DataDefStmt;
+    // 156 - FeatureStmt: KwFeature^ /* Clipped */ IdentifierArgStr FeatureStmtSuffix;
     Production {
-        lhs: 19,
-        production: &[ParseType::N(49)],
+        lhs: 80,
+        production: &[ParseType::N(83), ParseType::N(90), ParseType::N(136)],
     },
-    // 157 - AugmentStmtListGroup: CaseStmt;
+    // 157 - FeatureStmtSuffix: Semicolon^ /* Clipped */;
     Production {
-        lhs: 19,
-        production: &[ParseType::N(28)],
+        lhs: 83,
+        production: &[ParseType::N(246)],
     },
-    // 158 - AugmentStmtListGroup: ActionStmt;
+    // 158 - FeatureStmtSuffix: LBrace^ /* Clipped */ FeatureStmtList /* Vec */ '}';
     Production {
-        lhs: 19,
-        production: &[ParseType::N(1)],
+        lhs: 83,
+        production: &[ParseType::T(56), ParseType::N(81), ParseType::N(168)],
     },
-    // 159 - AugmentStmtListGroup: NotificationStmt;
+    // 159 - FeatureStmtList: FeatureStmtListGroup FeatureStmtList;
     Production {
-        lhs: 19,
-        production: &[ParseType::N(189)],
+        lhs: 81,
+        production: &[ParseType::N(81), ParseType::N(82)],
     },
-    // 160 - AugmentStmtList: ;
+    // 160 - FeatureStmtListGroup: IfFeatureStmt;
     Production {
-        lhs: 18,
-        production: &[],
+        lhs: 82,
+        production: &[ParseType::N(101)],
     },
-    // 161 - AugmentArgStr: Ystring;
+    // 161 - FeatureStmtListGroup: StatusStmt;
     Production {
-        lhs: 16,
-        production: &[ParseType::N(275)],
+        lhs: 82,
+        production: &[ParseType::N(251)],
     },
-    // 162 - WhenStmt: 'when'^ /* Clipped */ Ystring WhenStmtSuffix;
+    // 162 - FeatureStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 267,
-        production: &[ParseType::N(270), ParseType::N(275), ParseType::T(58)],
+        lhs: 82,
+        production: &[ParseType::N(55)],
     },
-    // 163 - WhenStmtSuffix: Semicolon^ /* Clipped */;
+    // 163 - FeatureStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 270,
-        production: &[ParseType::N(234)],
+        lhs: 82,
+        production: &[ParseType::N(222)],
     },
-    // 164 - WhenStmtSuffix: LBrace^ /* Clipped */ WhenStmtList /* Vec */ '}';
+    // 164 - FeatureStmtList: ;
     Production {
-        lhs: 270,
-        production: &[ParseType::T(55), ParseType::N(268), ParseType::N(157)],
+        lhs: 81,
+        production: &[],
     },
-    // 165 - WhenStmtList: WhenStmtListGroup WhenStmtList;
+    // 165 - TypedefStmt: KwTypedef^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ TypedefStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 268,
-        production: &[ParseType::N(268), ParseType::N(269)],
+        lhs: 264,
+        production: &[
+            ParseType::T(56),
+            ParseType::N(265),
+            ParseType::N(168),
+            ParseType::N(90),
+            ParseType::N(164),
+        ],
     },
-    // 166 - WhenStmtListGroup: DescriptionStmt;
+    // 166 - TypedefStmtList: TypedefStmtListGroup TypedefStmtList;
     Production {
-        lhs: 269,
-        production: &[ParseType::N(55)],
+        lhs: 265,
+        production: &[ParseType::N(265), ParseType::N(266)],
     },
-    // 167 - WhenStmtListGroup: ReferenceStmt;
+    // 167 - TypedefStmtListGroup: TypeStmt;
     Production {
-        lhs: 269,
-        production: &[ParseType::N(210)],
+        lhs: 266,
+        production: &[ParseType::N(260)],
     },
-    // 168 - WhenStmtList: ;
+    // 168 - TypedefStmtListGroup: UnitsStmt;
     Production {
-        lhs: 268,
+        lhs: 266,
+        production: &[ParseType::N(267)],
+    },
+    // 169 - TypedefStmtListGroup: DefaultStmt;
+    Production {
+        lhs: 266,
+        production: &[ParseType::N(52)],
+    },
+    // 170 - TypedefStmtListGroup: StatusStmt;
+    Production {
+        lhs: 266,
+        production: &[ParseType::N(251)],
+    },
+    // 171 - TypedefStmtListGroup: DescriptionStmt;
+    Production {
+        lhs: 266,
+        production: &[ParseType::N(55)],
+    },
+    // 172 - TypedefStmtListGroup: ReferenceStmt;
+    Production {
+        lhs: 266,
+        production: &[ParseType::N(222)],
+    },
+    // 173 - TypedefStmtList: ;
+    Production {
+        lhs: 265,
         production: &[],
     },
-    // 169 - GroupingStmt: KwGrouping^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ GroupingStmtList /* Vec */ '}'^ /* Clipped */;
+    // 174 - AugmentStmt: 'augment'^ /* Clipped */ AugmentArgStr LBrace^ /* Clipped */ AugmentStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 77,
+        lhs: 17,
         production: &[
-            ParseType::T(55),
-            ParseType::N(78),
-            ParseType::N(157),
-            ParseType::N(81),
-            ParseType::N(127),
+            ParseType::T(56),
+            ParseType::N(18),
+            ParseType::N(168),
+            ParseType::N(16),
+            ParseType::T(59),
         ],
     },
-    // 170 - GroupingStmtList: GroupingStmtListGroup GroupingStmtList;
+    // 175 - AugmentStmtList: AugmentStmtListGroup AugmentStmtList;
     Production {
-        lhs: 78,
-        production: &[ParseType::N(78), ParseType::N(79)],
+        lhs: 18,
+        production: &[ParseType::N(18), ParseType::N(19)],
     },
-    // 171 - GroupingStmtListGroup: StatusStmt;
+    // 176 - AugmentStmtListGroup: WhenStmt;
     Production {
-        lhs: 79,
-        production: &[ParseType::N(239)],
+        lhs: 19,
+        production: &[ParseType::N(279)],
     },
-    // 172 - GroupingStmtListGroup: DescriptionStmt;
+    // 177 - AugmentStmtListGroup: IfFeatureStmt;
     Production {
-        lhs: 79,
-        production: &[ParseType::N(55)],
+        lhs: 19,
+        production: &[ParseType::N(101)],
     },
-    // 173 - GroupingStmtListGroup: ReferenceStmt;
+    // 178 - AugmentStmtListGroup: StatusStmt;
     Production {
-        lhs: 79,
-        production: &[ParseType::N(210)],
+        lhs: 19,
+        production: &[ParseType::N(251)],
     },
-    // 174 - GroupingStmtListGroup: TypedefStmt;
+    // 179 - AugmentStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 79,
-        production: &[ParseType::N(252)],
+        lhs: 19,
+        production: &[ParseType::N(55)],
     },
-    // 175 - GroupingStmtListGroup: GroupingStmt;
+    // 180 - AugmentStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 79,
-        production: &[ParseType::N(77)],
+        lhs: 19,
+        production: &[ParseType::N(222)],
     },
-    // 176 - GroupingStmtListGroup: DataDefStmt;
+    // 181 - AugmentStmtListGroup: DataDefStmt;
     Production {
-        lhs: 79,
+        lhs: 19,
         production: &[ParseType::N(49)],
     },
-    // 177 - GroupingStmtListGroup: ActionStmt;
+    // 182 - AugmentStmtListGroup: CaseStmt;
     Production {
-        lhs: 79,
+        lhs: 19,
+        production: &[ParseType::N(28)],
+    },
+    // 183 - AugmentStmtListGroup: ActionStmt;
+    Production {
+        lhs: 19,
         production: &[ParseType::N(1)],
     },
-    // 178 - GroupingStmtListGroup: NotificationStmt;
+    // 184 - AugmentStmtListGroup: NotificationStmt;
     Production {
-        lhs: 79,
-        production: &[ParseType::N(189)],
+        lhs: 19,
+        production: &[ParseType::N(200)],
     },
-    // 179 - GroupingStmtListGroup: UnknownStmt;
+    // 185 - AugmentStmtList: ;
     Production {
-        lhs: 79,
-        production: &[ParseType::N(256)],
+        lhs: 18,
+        production: &[],
     },
-    // 180 - GroupingStmtList: ;
+    // 186 - AugmentArgStr: Ystring;
     Production {
-        lhs: 78,
+        lhs: 16,
+        production: &[ParseType::N(287)],
+    },
+    // 187 - WhenStmt: 'when'^ /* Clipped */ Ystring WhenStmtSuffix;
+    Production {
+        lhs: 279,
+        production: &[ParseType::N(282), ParseType::N(287), ParseType::T(60)],
+    },
+    // 188 - WhenStmtSuffix: Semicolon^ /* Clipped */;
+    Production {
+        lhs: 282,
+        production: &[ParseType::N(246)],
+    },
+    // 189 - WhenStmtSuffix: LBrace^ /* Clipped */ WhenStmtList /* Vec */ '}';
+    Production {
+        lhs: 282,
+        production: &[ParseType::T(56), ParseType::N(280), ParseType::N(168)],
+    },
+    // 190 - WhenStmtList: WhenStmtListGroup WhenStmtList;
+    Production {
+        lhs: 280,
+        production: &[ParseType::N(280), ParseType::N(281)],
+    },
+    // 191 - WhenStmtListGroup: DescriptionStmt;
+    Production {
+        lhs: 281,
+        production: &[ParseType::N(55)],
+    },
+    // 192 - WhenStmtListGroup: ReferenceStmt;
+    Production {
+        lhs: 281,
+        production: &[ParseType::N(222)],
+    },
+    // 193 - WhenStmtList: ;
+    Production {
+        lhs: 280,
         production: &[],
     },
-    // 181 - IdentityStmt: KwIdentity^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ IdentityStmtList /* Vec */ '}'^ /* Clipped */;
+    // 194 - GroupingStmt: KwGrouping^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ GroupingStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 85,
+        lhs: 86,
         production: &[
-            ParseType::T(55),
-            ParseType::N(86),
-            ParseType::N(157),
-            ParseType::N(81),
-            ParseType::N(128),
+            ParseType::T(56),
+            ParseType::N(87),
+            ParseType::N(168),
+            ParseType::N(90),
+            ParseType::N(138),
         ],
     },
-    // 182 - IdentityStmtList: IdentityStmtListGroup IdentityStmtList;
+    // 195 - GroupingStmtList: GroupingStmtListGroup GroupingStmtList;
     Production {
-        lhs: 86,
-        production: &[ParseType::N(86), ParseType::N(87)],
+        lhs: 87,
+        production: &[ParseType::N(87), ParseType::N(88)],
     },
-    // 183 - IdentityStmtListGroup: IfFeatureStmt;
+    // 196 - GroupingStmtListGroup: StatusStmt;
     Production {
-        lhs: 87,
-        production: &[ParseType::N(92)],
+        lhs: 88,
+        production: &[ParseType::N(251)],
+    },
+    // 197 - GroupingStmtListGroup: DescriptionStmt;
+    Production {
+        lhs: 88,
+        production: &[ParseType::N(55)],
+    },
+    // 198 - GroupingStmtListGroup: ReferenceStmt;
+    Production {
+        lhs: 88,
+        production: &[ParseType::N(222)],
+    },
+    // 199 - GroupingStmtListGroup: TypedefStmt;
+    Production {
+        lhs: 88,
+        production: &[ParseType::N(264)],
+    },
+    // 200 - GroupingStmtListGroup: GroupingStmt;
+    Production {
+        lhs: 88,
+        production: &[ParseType::N(86)],
+    },
+    // 201 - GroupingStmtListGroup: DataDefStmt;
+    Production {
+        lhs: 88,
+        production: &[ParseType::N(49)],
+    },
+    // 202 - GroupingStmtListGroup: ActionStmt;
+    Production {
+        lhs: 88,
+        production: &[ParseType::N(1)],
+    },
+    // 203 - GroupingStmtListGroup: NotificationStmt;
+    Production {
+        lhs: 88,
+        production: &[ParseType::N(200)],
     },
-    // 184 - IdentityStmtListGroup: BaseStmt;
+    // 204 - GroupingStmtListGroup: UnknownStmt;
+    Production {
+        lhs: 88,
+        production: &[ParseType::N(268)],
+    },
+    // 205 - GroupingStmtList: ;
     Production {
         lhs: 87,
+        production: &[],
+    },
+    // 206 - IdentityStmt: KwIdentity^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ IdentityStmtList /* Vec */ '}'^ /* Clipped */;
+    Production {
+        lhs: 94,
+        production: &[
+            ParseType::T(56),
+            ParseType::N(95),
+            ParseType::N(168),
+            ParseType::N(90),
+            ParseType::N(139),
+        ],
+    },
+    // 207 - IdentityStmtList: IdentityStmtListGroup IdentityStmtList;
+    Production {
+        lhs: 95,
+        production: &[ParseType::N(95), ParseType::N(96)],
+    },
+    // 208 - IdentityStmtListGroup: IfFeatureStmt;
+    Production {
+        lhs: 96,
+        production: &[ParseType::N(101)],
+    },
+    // 209 - IdentityStmtListGroup: BaseStmt;
+    Production {
+        lhs: 96,
         production: &[ParseType::N(20)],
     },
-    // 185 - IdentityStmtListGroup: DescriptionStmt;
+    // 210 - IdentityStmtListGroup: StatusStmt;
     Production {
-        lhs: 87,
+        lhs: 96,
+        production: &[ParseType::N(251)],
+    },
+    // 211 - IdentityStmtListGroup: DescriptionStmt;
+    Production {
+        lhs: 96,
         production: &[ParseType::N(55)],
     },
-    // 186 - IdentityStmtListGroup: ReferenceStmt;
+    // 212 - IdentityStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 87,
-        production: &[ParseType::N(210)],
+        lhs: 96,
+        production: &[ParseType::N(222)],
     },
-    // 187 - IdentityStmtList: ;
+    // 213 - IdentityStmtList: ;
     Production {
-        lhs: 86,
+        lhs: 95,
         production: &[],
     },
-    // 188 - BaseStmt: KwBase^ /* Clipped */ IdentifierRefArgStr Semicolon^ /* Clipped */;
+    // 214 - BaseStmt: KwBase^ /* Clipped */ IdentifierRefArgStr Semicolon^ /* Clipped */;
     Production {
         lhs: 20,
-        production: &[ParseType::N(234), ParseType::N(83), ParseType::N(114)],
+        production: &[ParseType::N(246), ParseType::N(92), ParseType::N(124)],
     },
-    // 189 - AnyxmlStmt: KwAnyxml^ /* Clipped */ IdentifierArgStr AnyxmlStmtSuffix;
+    // 215 - AnyxmlStmt: KwAnyxml^ /* Clipped */ IdentifierArgStr AnyxmlStmtSuffix;
     Production {
         lhs: 9,
-        production: &[ParseType::N(12), ParseType::N(81), ParseType::N(112)],
+        production: &[ParseType::N(12), ParseType::N(90), ParseType::N(122)],
     },
-    // 190 - AnyxmlStmtSuffix: Semicolon^ /* Clipped */;
+    // 216 - AnyxmlStmtSuffix: Semicolon^ /* Clipped */;
     Production {
         lhs: 12,
-        production: &[ParseType::N(234)],
+        production: &[ParseType::N(246)],
     },
-    // 191 - AnyxmlStmtSuffix: LBrace^ /* Clipped */ AnyxmlStmtList /* Vec */ '}'^ /* Clipped */;
+    // 217 - AnyxmlStmtSuffix: LBrace^ /* Clipped */ AnyxmlStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
         lhs: 12,
-        production: &[ParseType::T(55), ParseType::N(10), ParseType::N(157)],
+        production: &[ParseType::T(56), ParseType::N(10), ParseType::N(168)],
     },
-    // 192 - AnyxmlStmtList: AnyxmlStmtListGroup AnyxmlStmtList;
+    // 218 - AnyxmlStmtList: AnyxmlStmtListGroup AnyxmlStmtList;
     Production {
         lhs: 10,
         production: &[ParseType::N(10), ParseType::N(11)],
     },
-    // 193 - AnyxmlStmtListGroup: WhenStmt;
+    // 219 - AnyxmlStmtListGroup: WhenStmt;
     Production {
         lhs: 11,
-        production: &[ParseType::N(267)],
+        production: &[ParseType::N(279)],
     },
-    // 194 - AnyxmlStmtListGroup: IfFeatureStmt;
+    // 220 - AnyxmlStmtListGroup: IfFeatureStmt;
     Production {
         lhs: 11,
-        production: &[ParseType::N(92)],
+        production: &[ParseType::N(101)],
     },
-    // 195 - AnyxmlStmtListGroup: MustStmt;
+    // 221 - AnyxmlStmtListGroup: MustStmt;
     Production {
         lhs: 11,
-        production: &[ParseType::N(183)],
+        production: &[ParseType::N(194)],
     },
-    // 196 - AnyxmlStmtListGroup: ConfigStmt;
+    // 222 - AnyxmlStmtListGroup: ConfigStmt;
     Production {
         lhs: 11,
         production: &[ParseType::N(36)],
     },
-    // 197 - AnyxmlStmtListGroup: MandatoryStmt;
+    // 223 - AnyxmlStmtListGroup: MandatoryStmt;
     Production {
         lhs: 11,
-        production: &[ParseType::N(172)],
+        production: &[ParseType::N(183)],
     },
-    // 198 - AnyxmlStmtListGroup: StatusStmt;
+    // 224 - AnyxmlStmtListGroup: StatusStmt;
     Production {
         lhs: 11,
-        production: &[ParseType::N(239)],
+        production: &[ParseType::N(251)],
     },
-    // 199 - AnyxmlStmtListGroup: DescriptionStmt;
+    // 225 - AnyxmlStmtListGroup: DescriptionStmt;
     Production {
         lhs: 11,
         production: &[ParseType::N(55)],
     },
-    // 200 - AnyxmlStmtListGroup: ReferenceStmt;
+    // 226 - AnyxmlStmtListGroup: ReferenceStmt;
     Production {
         lhs: 11,
-        production: &[ParseType::N(210)],
+        production: &[ParseType::N(222)],
     },
-    // 201 - AnyxmlStmtList: ;
+    // 227 - AnyxmlStmtList: ;
     Production {
         lhs: 10,
         production: &[],
     },
-    // 202 - ChoiceStmt: KwChoice^ /* Clipped */ IdentifierRefArgStr ChoiceStmtSuffix;
+    // 228 - ChoiceStmt: KwChoice^ /* Clipped */ IdentifierRefArgStr ChoiceStmtSuffix;
     Production {
         lhs: 32,
-        production: &[ParseType::N(35), ParseType::N(83), ParseType::N(118)],
+        production: &[ParseType::N(35), ParseType::N(92), ParseType::N(128)],
     },
-    // 203 - ChoiceStmtSuffix: Semicolon^ /* Clipped */;
+    // 229 - ChoiceStmtSuffix: Semicolon^ /* Clipped */;
     Production {
         lhs: 35,
-        production: &[ParseType::N(234)],
+        production: &[ParseType::N(246)],
     },
-    // 204 - ChoiceStmtSuffix: LBrace^ /* Clipped */ ChoiceStmtList /* Vec */ '}';
+    // 230 - ChoiceStmtSuffix: LBrace^ /* Clipped */ ChoiceStmtList /* Vec */ '}';
     Production {
         lhs: 35,
-        production: &[ParseType::T(55), ParseType::N(33), ParseType::N(157)],
+        production: &[ParseType::T(56), ParseType::N(33), ParseType::N(168)],
     },
-    // 205 - ChoiceStmtList: ChoiceStmtListGroup ChoiceStmtList;
+    // 231 - ChoiceStmtList: ChoiceStmtListGroup ChoiceStmtList;
     Production {
         lhs: 33,
         production: &[ParseType::N(33), ParseType::N(34)],
     },
-    // 206 - ChoiceStmtListGroup: WhenStmt;
+    // 232 - ChoiceStmtListGroup: WhenStmt;
     Production {
         lhs: 34,
-        production: &[ParseType::N(267)],
+        production: &[ParseType::N(279)],
     },
-    // 207 - ChoiceStmtListGroup: IfFeatureStmt;
+    // 233 - ChoiceStmtListGroup: IfFeatureStmt;
     Production {
         lhs: 34,
-        production: &[ParseType::N(92)],
+        production: &[ParseType::N(101)],
     },
-    // 208 - ChoiceStmtListGroup: DefaultStmt;
+    // 234 - ChoiceStmtListGroup: DefaultStmt;
     Production {
         lhs: 34,
         production: &[ParseType::N(52)],
     },
-    // 209 - ChoiceStmtListGroup: ConfigStmt;
+    // 235 - ChoiceStmtListGroup: ConfigStmt;
     Production {
         lhs: 34,
         production: &[ParseType::N(36)],
     },
-    // 210 - ChoiceStmtListGroup: MandatoryStmt;
+    // 236 - ChoiceStmtListGroup: MandatoryStmt;
     Production {
         lhs: 34,
-        production: &[ParseType::N(172)],
+        production: &[ParseType::N(183)],
     },
-    // 211 - ChoiceStmtListGroup: StatusStmt;
+    // 237 - ChoiceStmtListGroup: StatusStmt;
     Production {
         lhs: 34,
-        production: &[ParseType::N(239)],
+        production: &[ParseType::N(251)],
     },
-    // 212 - ChoiceStmtListGroup: DescriptionStmt;
+    // 238 - ChoiceStmtListGroup: DescriptionStmt;
     Production {
         lhs: 34,
         production: &[ParseType::N(55)],
     },
-    // 213 - ChoiceStmtListGroup: ReferenceStmt;
+    // 239 - ChoiceStmtListGroup: ReferenceStmt;
     Production {
         lhs: 34,
-        production: &[ParseType::N(210)],
+        production: &[ParseType::N(222)],
     },
-    // 214 - ChoiceStmtListGroup: ShortCaseStmt;
+    // 240 - ChoiceStmtListGroup: ShortCaseStmt;
     Production {
         lhs: 34,
-        production: &[ParseType::N(235)],
+        production: &[ParseType::N(247)],
     },
-    // 215 - ChoiceStmtListGroup: CaseStmt;
+    // 241 - ChoiceStmtListGroup: CaseStmt;
     Production {
         lhs: 34,
         production: &[ParseType::N(28)],
     },
-    // 216 - ChoiceStmtList: ;
+    // 242 - ChoiceStmtList: ;
     Production {
         lhs: 33,
         production: &[],
     },
-    // 217 - ShortCaseStmt: ChoiceStmt;
+    // 243 - ShortCaseStmt: ChoiceStmt;
     Production {
-        lhs: 235,
+        lhs: 247,
         production: &[ParseType::N(32)],
     },
-    // 218 - ShortCaseStmt: ContainerStmt;
+    // 244 - ShortCaseStmt: ContainerStmt;
     Production {
-        lhs: 235,
+        lhs: 247,
         production: &[ParseType::N(38)],
     },
-    // 219 - ShortCaseStmt: LeafStmt;
+    // 245 - ShortCaseStmt: LeafStmt;
     Production {
-        lhs: 235,
-        production: &[ParseType::N(161)],
+        lhs: 247,
+        production: &[ParseType::N(172)],
     },
-    // 220 - ShortCaseStmt: LeafListStmt;
+    // 246 - ShortCaseStmt: LeafListStmt;
     Production {
-        lhs: 235,
-        production: &[ParseType::N(158)],
+        lhs: 247,
+        production: &[ParseType::N(169)],
     },
-    // 221 - ShortCaseStmt: ListStmt;
+    // 247 - ShortCaseStmt: ListStmt;
     Production {
-        lhs: 235,
-        production: &[ParseType::N(167)],
+        lhs: 247,
+        production: &[ParseType::N(178)],
     },
-    // 222 - ShortCaseStmt: AnydataStmt;
+    // 248 - ShortCaseStmt: AnydataStmt;
     Production {
-        lhs: 235,
+        lhs: 247,
         production: &[ParseType::N(5)],
     },
-    // 223 - ShortCaseStmt: AnyxmlStmt;
+    // 249 - ShortCaseStmt: AnyxmlStmt;
     Production {
-        lhs: 235,
+        lhs: 247,
         production: &[ParseType::N(9)],
     },
-    // 224 - AnydataStmt: KwAnydata^ /* Clipped */ IdentifierArgStr AnydataStmtSuffix;
+    // 250 - AnydataStmt: KwAnydata^ /* Clipped */ IdentifierArgStr AnydataStmtSuffix;
     Production {
         lhs: 5,
-        production: &[ParseType::N(8), ParseType::N(81), ParseType::N(111)],
+        production: &[ParseType::N(8), ParseType::N(90), ParseType::N(121)],
     },
-    // 225 - AnydataStmtSuffix: Semicolon^ /* Clipped */;
+    // 251 - AnydataStmtSuffix: Semicolon^ /* Clipped */;
     Production {
         lhs: 8,
-        production: &[ParseType::N(234)],
+        production: &[ParseType::N(246)],
     },
-    // 226 - AnydataStmtSuffix: LBrace^ /* Clipped */ AnydataStmtList /* Vec */ '}'^ /* Clipped */;
+    // 252 - AnydataStmtSuffix: LBrace^ /* Clipped */ AnydataStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
         lhs: 8,
-        production: &[ParseType::T(55), ParseType::N(6), ParseType::N(157)],
+        production: &[ParseType::T(56), ParseType::N(6), ParseType::N(168)],
     },
-    // 227 - AnydataStmtList: AnydataStmtListGroup AnydataStmtList;
+    // 253 - AnydataStmtList: AnydataStmtListGroup AnydataStmtList;
     Production {
         lhs: 6,
         production: &[ParseType::N(6), ParseType::N(7)],
     },
-    // 228 - AnydataStmtListGroup: WhenStmt;
+    // 254 - AnydataStmtListGroup: WhenStmt;
     Production {
         lhs: 7,
-        production: &[ParseType::N(267)],
+        production: &[ParseType::N(279)],
     },
-    // 229 - AnydataStmtListGroup: IfFeatureStmt;
+    // 255 - AnydataStmtListGroup: IfFeatureStmt;
     Production {
         lhs: 7,
-        production: &[ParseType::N(92)],
+        production: &[ParseType::N(101)],
     },
-    // 230 - AnydataStmtListGroup: MustStmt;
+    // 256 - AnydataStmtListGroup: MustStmt;
     Production {
         lhs: 7,
-        production: &[ParseType::N(183)],
+        production: &[ParseType::N(194)],
     },
-    // 231 - AnydataStmtListGroup: ConfigStmt;
+    // 257 - AnydataStmtListGroup: ConfigStmt;
     Production {
         lhs: 7,
         production: &[ParseType::N(36)],
     },
-    // 232 - AnydataStmtListGroup: MandatoryStmt;
+    // 258 - AnydataStmtListGroup: MandatoryStmt;
     Production {
         lhs: 7,
-        production: &[ParseType::N(172)],
+        production: &[ParseType::N(183)],
     },
-    // 233 - AnydataStmtListGroup: StatusStmt;
+    // 259 - AnydataStmtListGroup: StatusStmt;
     Production {
         lhs: 7,
-        production: &[ParseType::N(239)],
+        production: &[ParseType::N(251)],
     },
-    // 234 - AnydataStmtListGroup: DescriptionStmt;
+    // 260 - AnydataStmtListGroup: DescriptionStmt;
     Production {
         lhs: 7,
         production: &[ParseType::N(55)],
     },
-    // 235 - AnydataStmtListGroup: ReferenceStmt;
+    // 261 - AnydataStmtListGroup: ReferenceStmt;
     Production {
         lhs: 7,
-        production: &[ParseType::N(210)],
+        production: &[ParseType::N(222)],
     },
-    // 236 - AnydataStmtList: ;
+    // 262 - AnydataStmtList: ;
     Production {
         lhs: 6,
         production: &[],
     },
-    // 237 - CaseStmt: KwCase^ /* Clipped */ IdentifierArgStr CaseStmtSuffix;
+    // 263 - CaseStmt: KwCase^ /* Clipped */ IdentifierArgStr CaseStmtSuffix;
     Production {
         lhs: 28,
-        production: &[ParseType::N(31), ParseType::N(81), ParseType::N(117)],
+        production: &[ParseType::N(31), ParseType::N(90), ParseType::N(127)],
     },
-    // 238 - CaseStmtSuffix: Semicolon^ /* Clipped */;
+    // 264 - CaseStmtSuffix: Semicolon^ /* Clipped */;
     Production {
         lhs: 31,
-        production: &[ParseType::N(234)],
+        production: &[ParseType::N(246)],
     },
-    // 239 - CaseStmtSuffix: LBrace^ /* Clipped */ CaseStmtList /* Vec */ '}'^ /* Clipped */;
+    // 265 - CaseStmtSuffix: LBrace^ /* Clipped */ CaseStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
         lhs: 31,
-        production: &[ParseType::T(55), ParseType::N(29), ParseType::N(157)],
+        production: &[ParseType::T(56), ParseType::N(29), ParseType::N(168)],
     },
-    // 240 - CaseStmtList: CaseStmtListGroup CaseStmtList;
+    // 266 - CaseStmtList: CaseStmtListGroup CaseStmtList;
     Production {
         lhs: 29,
         production: &[ParseType::N(29), ParseType::N(30)],
     },
-    // 241 - CaseStmtListGroup: WhenStmt;
+    // 267 - CaseStmtListGroup: WhenStmt;
     Production {
         lhs: 30,
-        production: &[ParseType::N(267)],
+        production: &[ParseType::N(279)],
+    },
+    // 268 - CaseStmtListGroup: IfFeatureStmt;
+    Production {
+        lhs: 30,
+        production: &[ParseType::N(101)],
     },
-    // 242 - CaseStmtListGroup: IfFeatureStmt;
+    // 269 - CaseStmtListGroup: StatusStmt;
     Production {
         lhs: 30,
-        production: &[ParseType::N(92)],
+        production: &[ParseType::N(251)],
     },
-    // 243 - CaseStmtListGroup: DataDefStmt;
+    // 270 - CaseStmtListGroup: DataDefStmt;
     Production {
         lhs: 30,
         production: &[ParseType::N(49)],
     },
-    // 244 - CaseStmtListGroup: DescriptionStmt;
+    // 271 - CaseStmtListGroup: DescriptionStmt;
     Production {
         lhs: 30,
         production: &[ParseType::N(55)],
     },
-    // 245 - CaseStmtListGroup: ReferenceStmt;
+    // 272 - CaseStmtListGroup: ReferenceStmt;
     Production {
         lhs: 30,
-        production: &[ParseType::N(210)],
+        production: &[ParseType::N(222)],
     },
-    // 246 - CaseStmtList: ;
+    // 273 - CaseStmtList: ;
     Production {
         lhs: 29,
         production: &[],
     },
-    // 247 - StatusStmt: KwStatus^ /* Clipped */ StatusArgStr Semicolon^ /* Clipped */;
+    // 274 - StatusStmt: KwStatus^ /* Clipped */ StatusArgStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 239,
-        production: &[ParseType::N(234), ParseType::N(238), ParseType::N(150)],
+        lhs: 251,
+        production: &[ParseType::N(246), ParseType::N(250), ParseType::N(161)],
     },
-    // 248 - StatusArgStr: StatusArg;
+    // 275 - StatusArgStr: StatusArg;
     Production {
-        lhs: 238,
-        production: &[ParseType::N(237)],
+        lhs: 250,
+        production: &[ParseType::N(249)],
     },
-    // 249 - StatusArgStr: '"'^ /* Clipped */ StatusArg '"'^ /* Clipped */;
+    // 276 - StatusArgStr: '"'^ /* Clipped */ StatusArg '"'^ /* Clipped */;
     Production {
-        lhs: 238,
-        production: &[ParseType::T(56), ParseType::N(237), ParseType::T(56)],
+        lhs: 250,
+        production: &[ParseType::T(57), ParseType::N(249), ParseType::T(57)],
     },
-    // 250 - StatusArg: /current|obsolete|deprecated/;
+    // 277 - StatusArg: /current|obsolete|deprecated/;
     Production {
-        lhs: 237,
-        production: &[ParseType::T(59)],
+        lhs: 249,
+        production: &[ParseType::T(61)],
     },
-    // 251 - ContainerStmt: KwContainer^ /* Clipped */ IdentifierArgStr ContainerStmtSuffix;
+    // 278 - ContainerStmt: KwContainer^ /* Clipped */ IdentifierArgStr ContainerStmtSuffix;
     Production {
         lhs: 38,
-        production: &[ParseType::N(41), ParseType::N(81), ParseType::N(120)],
+        production: &[ParseType::N(41), ParseType::N(90), ParseType::N(130)],
     },
-    // 252 - ContainerStmtSuffix: Semicolon^ /* Clipped */;
+    // 279 - ContainerStmtSuffix: Semicolon^ /* Clipped */;
     Production {
         lhs: 41,
-        production: &[ParseType::N(234)],
+        production: &[ParseType::N(246)],
     },
-    // 253 - ContainerStmtSuffix: LBrace^ /* Clipped */ ContainerStmtList /* Vec */ '}'^ /* Clipped */;
+    // 280 - ContainerStmtSuffix: LBrace^ /* Clipped */ ContainerStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
         lhs: 41,
-        production: &[ParseType::T(55), ParseType::N(39), ParseType::N(157)],
+        production: &[ParseType::T(56), ParseType::N(39), ParseType::N(168)],
     },
-    // 254 - ContainerStmtList: ContainerStmtListGroup ContainerStmtList;
+    // 281 - ContainerStmtList: ContainerStmtListGroup ContainerStmtList;
     Production {
         lhs: 39,
         production: &[ParseType::N(39), ParseType::N(40)],
     },
-    // 255 - ContainerStmtListGroup: WhenStmt;
+    // 282 - ContainerStmtListGroup: WhenStmt;
     Production {
         lhs: 40,
-        production: &[ParseType::N(267)],
+        production: &[ParseType::N(279)],
     },
-    // 256 - ContainerStmtListGroup: IfFeatureStmt;
+    // 283 - ContainerStmtListGroup: IfFeatureStmt;
     Production {
         lhs: 40,
-        production: &[ParseType::N(92)],
+        production: &[ParseType::N(101)],
     },
-    // 257 - ContainerStmtListGroup: MustStmt;
+    // 284 - ContainerStmtListGroup: MustStmt;
     Production {
         lhs: 40,
-        production: &[ParseType::N(183)],
+        production: &[ParseType::N(194)],
     },
-    // 258 - ContainerStmtListGroup: PresenceStmt;
+    // 285 - ContainerStmtListGroup: PresenceStmt;
     Production {
         lhs: 40,
-        production: &[ParseType::N(202)],
+        production: &[ParseType::N(214)],
     },
-    // 259 - ContainerStmtListGroup: ConfigStmt;
+    // 286 - ContainerStmtListGroup: ConfigStmt;
     Production {
         lhs: 40,
         production: &[ParseType::N(36)],
     },
-    // 260 - ContainerStmtListGroup: StatusStmt;
+    // 287 - ContainerStmtListGroup: StatusStmt;
     Production {
         lhs: 40,
-        production: &[ParseType::N(239)],
+        production: &[ParseType::N(251)],
     },
-    // 261 - ContainerStmtListGroup: DescriptionStmt;
+    // 288 - ContainerStmtListGroup: DescriptionStmt;
     Production {
         lhs: 40,
         production: &[ParseType::N(55)],
     },
-    // 262 - ContainerStmtListGroup: ReferenceStmt;
+    // 289 - ContainerStmtListGroup: ReferenceStmt;
     Production {
         lhs: 40,
-        production: &[ParseType::N(210)],
+        production: &[ParseType::N(222)],
+    },
+    // 290 - ContainerStmtListGroup: TypedefStmt;
+    Production {
+        lhs: 40,
+        production: &[ParseType::N(264)],
+    },
+    // 291 - ContainerStmtListGroup: GroupingStmt;
+    Production {
+        lhs: 40,
+        production: &[ParseType::N(86)],
     },
-    // 263 - ContainerStmtListGroup: DataDefStmt;
+    // 292 - ContainerStmtListGroup: DataDefStmt;
     Production {
         lhs: 40,
         production: &[ParseType::N(49)],
     },
-    // 264 - ContainerStmtListGroup: ActionStmt;
+    // 293 - ContainerStmtListGroup: ActionStmt;
     Production {
         lhs: 40,
         production: &[ParseType::N(1)],
     },
-    // 265 - ContainerStmtListGroup: NotificationStmt;
+    // 294 - ContainerStmtListGroup: NotificationStmt;
     Production {
         lhs: 40,
-        production: &[ParseType::N(189)],
+        production: &[ParseType::N(200)],
     },
-    // 266 - ContainerStmtListGroup: UnknownStmt;
+    // 295 - ContainerStmtListGroup: UnknownStmt;
     Production {
         lhs: 40,
-        production: &[ParseType::N(256)],
+        production: &[ParseType::N(268)],
     },
-    // 267 - ContainerStmtList: ;
+    // 296 - ContainerStmtList: ;
     Production {
         lhs: 39,
         production: &[],
     },
-    // 268 - ActionStmt: KwAction^ /* Clipped */ IdentifierArgStr ActionStmtSuffix;
+    // 297 - ActionStmt: KwAction^ /* Clipped */ IdentifierArgStr ActionStmtSuffix;
     Production {
         lhs: 1,
-        production: &[ParseType::N(4), ParseType::N(81), ParseType::N(110)],
+        production: &[ParseType::N(4), ParseType::N(90), ParseType::N(120)],
     },
-    // 269 - ActionStmtSuffix: Semicolon;
+    // 298 - ActionStmtSuffix: Semicolon;
     Production {
         lhs: 4,
-        production: &[ParseType::N(234)],
+        production: &[ParseType::N(246)],
     },
-    // 270 - ActionStmtSuffix: LBrace^ /* Clipped */ ActionStmtList /* Vec */ '}';
+    // 299 - ActionStmtSuffix: LBrace^ /* Clipped */ ActionStmtList /* Vec */ '}';
     Production {
         lhs: 4,
-        production: &[ParseType::T(55), ParseType::N(2), ParseType::N(157)],
+        production: &[ParseType::T(56), ParseType::N(2), ParseType::N(168)],
     },
-    // 271 - ActionStmtList: ActionStmtListGroup ActionStmtList;
+    // 300 - ActionStmtList: ActionStmtListGroup ActionStmtList;
     Production {
         lhs: 2,
         production: &[ParseType::N(2), ParseType::N(3)],
     },
-    // 272 - ActionStmtListGroup: IfFeatureStmt;
+    // 301 - ActionStmtListGroup: IfFeatureStmt;
     Production {
         lhs: 3,
-        production: &[ParseType::N(92)],
+        production: &[ParseType::N(101)],
     },
-    // 273 - ActionStmtListGroup: StatusStmt;
+    // 302 - ActionStmtListGroup: StatusStmt;
     Production {
         lhs: 3,
-        production: &[ParseType::N(239)],
+        production: &[ParseType::N(251)],
     },
-    // 274 - ActionStmtListGroup: DescriptionStmt;
+    // 303 - ActionStmtListGroup: DescriptionStmt;
     Production {
         lhs: 3,
         production: &[ParseType::N(55)],
     },
-    // 275 - ActionStmtListGroup: ReferenceStmt;
+    // 304 - ActionStmtListGroup: ReferenceStmt;
     Production {
         lhs: 3,
-        production: &[ParseType::N(210)],
+        production: &[ParseType::N(222)],
+    },
+    // 305 - ActionStmtListGroup: TypedefStmt;
+    Production {
+        lhs: 3,
+        production: &[ParseType::N(264)],
+    },
+    // 306 - ActionStmtListGroup: GroupingStmt;
+    Production {
+        lhs: 3,
+        production: &[ParseType::N(86)],
     },
-    // 276 - ActionStmtListGroup: InputStmt;
+    // 307 - ActionStmtListGroup: InputStmt;
     Production {
         lhs: 3,
-        production: &[ParseType::N(102)],
+        production: &[ParseType::N(111)],
     },
-    // 277 - ActionStmtListGroup: OutputStmt;
+    // 308 - ActionStmtListGroup: OutputStmt;
     Production {
         lhs: 3,
-        production: &[ParseType::N(196)],
+        production: &[ParseType::N(207)],
     },
-    // 278 - ActionStmtList: ;
+    // 309 - ActionStmtList: ;
     Production {
         lhs: 2,
         production: &[],
     },
-    // 279 - InputStmt: 'input' LBrace^ /* Clipped */ InputStmtList /* Vec */ '}';
+    // 310 - InputStmt: 'input' LBrace^ /* Clipped */ InputStmtList /* Vec */ '}';
     Production {
-        lhs: 102,
+        lhs: 111,
         production: &[
-            ParseType::T(55),
-            ParseType::N(103),
-            ParseType::N(157),
-            ParseType::T(60),
+            ParseType::T(56),
+            ParseType::N(112),
+            ParseType::N(168),
+            ParseType::T(62),
         ],
     },
-    // 280 - InputStmtList: DataDefStmt InputStmtList;
+    // 311 - InputStmtList: InputStmtListGroup InputStmtList;
     Production {
-        lhs: 103,
-        production: &[ParseType::N(103), ParseType::N(49)],
+        lhs: 112,
+        production: &[ParseType::N(112), ParseType::N(113)],
     },
-    // 281 - InputStmtList: ;
+    // 312 - InputStmtListGroup: TypedefStmt;
     Production {
-        lhs: 103,
-        production: &[],
+        lhs: 113,
+        production: &[ParseType::N(264)],
     },
-    // 282 - OutputStmt: 'output' LBrace^ /* Clipped */ OutputStmtList /* Vec */ '}';
+    // 313 - InputStmtListGroup: GroupingStmt;
     Production {
-        lhs: 196,
-        production: &[
-            ParseType::T(55),
-            ParseType::N(197),
-            ParseType::N(157),
-            ParseType::T(61),
-        ],
+        lhs: 113,
+        production: &[ParseType::N(86)],
     },
-    // 283 - OutputStmtList: DataDefStmt OutputStmtList;
+    // 314 - InputStmtListGroup: DataDefStmt;
     Production {
-        lhs: 197,
-        production: &[ParseType::N(197), ParseType::N(49)],
+        lhs: 113,
+        production: &[ParseType::N(49)],
     },
-    // 284 - OutputStmtList: ;
+    // 315 - InputStmtList: ;
     Production {
-        lhs: 197,
+        lhs: 112,
         production: &[],
     },
-    // 285 - NotificationStmt: KwNotification^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ NotificationStmtList /* Vec */ '}';
+    // 316 - OutputStmt: 'output' LBrace^ /* Clipped */ OutputStmtList /* Vec */ '}';
     Production {
-        lhs: 189,
+        lhs: 207,
         production: &[
-            ParseType::T(55),
-            ParseType::N(190),
-            ParseType::N(157),
-            ParseType::N(81),
-            ParseType::N(140),
+            ParseType::T(56),
+            ParseType::N(208),
+            ParseType::N(168),
+            ParseType::T(63),
         ],
     },
-    // 286 - NotificationStmtList: NotificationStmtListGroup NotificationStmtList;
+    // 317 - OutputStmtList: OutputStmtListGroup OutputStmtList;
     Production {
-        lhs: 190,
-        production: &[ParseType::N(190), ParseType::N(191)],
+        lhs: 208,
+        production: &[ParseType::N(208), ParseType::N(209)],
     },
-    // 287 - NotificationStmtListGroup: IfFeatureStmt;
+    // 318 - OutputStmtListGroup: TypedefStmt;
     Production {
-        lhs: 191,
-        production: &[ParseType::N(92)],
+        lhs: 209,
+        production: &[ParseType::N(264)],
     },
-    // 288 - NotificationStmtListGroup: MustStmt;
+    // 319 - OutputStmtListGroup: GroupingStmt;
     Production {
-        lhs: 191,
-        production: &[ParseType::N(183)],
+        lhs: 209,
+        production: &[ParseType::N(86)],
     },
-    // 289 - NotificationStmtListGroup: StatusStmt;
+    // 320 - OutputStmtListGroup: DataDefStmt;
     Production {
-        lhs: 191,
-        production: &[ParseType::N(239)],
+        lhs: 209,
+        production: &[ParseType::N(49)],
     },
-    // 290 - NotificationStmtListGroup: DescriptionStmt;
+    // 321 - OutputStmtList: ;
     Production {
-        lhs: 191,
-        production: &[ParseType::N(55)],
+        lhs: 208,
+        production: &[],
     },
-    // 291 - NotificationStmtListGroup: ReferenceStmt;
+    // 322 - NotificationStmt: KwNotification^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ NotificationStmtList /* Vec */ '}';
     Production {
-        lhs: 191,
-        production: &[ParseType::N(210)],
+        lhs: 200,
+        production: &[
+            ParseType::T(56),
+            ParseType::N(201),
+            ParseType::N(168),
+            ParseType::N(90),
+            ParseType::N(151),
+        ],
     },
-    // 292 - NotificationStmtListGroup: TypedefStmt;
+    // 323 - NotificationStmtList: NotificationStmtListGroup NotificationStmtList;
     Production {
-        lhs: 191,
-        production: &[ParseType::N(252)],
+        lhs: 201,
+        production: &[ParseType::N(201), ParseType::N(202)],
     },
-    // 293 - NotificationStmtListGroup: GroupingStmt;
+    // 324 - NotificationStmtListGroup: IfFeatureStmt;
     Production {
-        lhs: 191,
-        production: &[ParseType::N(77)],
+        lhs: 202,
+        production: &[ParseType::N(101)],
     },
-    // 294 - NotificationStmtListGroup: DataDefStmt;
+    // 325 - NotificationStmtListGroup: MustStmt;
     Production {
-        lhs: 191,
+        lhs: 202,
+        production: &[ParseType::N(194)],
+    },
+    // 326 - NotificationStmtListGroup: StatusStmt;
+    Production {
+        lhs: 202,
+        production: &[ParseType::N(251)],
+    },
+    // 327 - NotificationStmtListGroup: DescriptionStmt;
+    Production {
+        lhs: 202,
+        production: &[ParseType::N(55)],
+    },
+    // 328 - NotificationStmtListGroup: ReferenceStmt;
+    Production {
+        lhs: 202,
+        production: &[ParseType::N(222)],
+    },
+    // 329 - NotificationStmtListGroup: TypedefStmt;
+    Production {
+        lhs: 202,
+        production: &[ParseType::N(264)],
+    },
+    // 330 - NotificationStmtListGroup: GroupingStmt;
+    Production {
+        lhs: 202,
+        production: &[ParseType::N(86)],
+    },
+    // 331 - NotificationStmtListGroup: DataDefStmt;
+    Production {
+        lhs: 202,
         production: &[ParseType::N(49)],
     },
-    // 295 - NotificationStmtList: ;
+    // 332 - NotificationStmtList: ;
     Production {
-        lhs: 190,
+        lhs: 201,
         production: &[],
     },
-    // 296 - IfFeatureStmt: KwIfFeature^ /* Clipped */ IfFeatureExprStr Semicolon^ /* Clipped */;
+    // 333 - IfFeatureStmt: KwIfFeature^ /* Clipped */ IfFeatureExprStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 92,
-        production: &[ParseType::N(234), ParseType::N(90), ParseType::N(129)],
+        lhs: 101,
+        production: &[ParseType::N(246), ParseType::N(99), ParseType::N(140)],
     },
-    // 297 - IfFeatureExprStr: IfFeatureExpr;
+    // 334 - IfFeatureExprStr: IfFeatureExpr;
     Production {
-        lhs: 90,
-        production: &[ParseType::N(88)],
+        lhs: 99,
+        production: &[ParseType::N(97)],
     },
-    // 298 - IfFeatureExpr: IfFeatureTerm IfFeatureExprOpt /* Option */;
+    // 335 - IfFeatureExpr: IfFeatureTerm IfFeatureExprOpt /* Option */;
     Production {
-        lhs: 88,
-        production: &[ParseType::N(89), ParseType::N(93)],
+        lhs: 97,
+        production: &[ParseType::N(98), ParseType::N(102)],
     },
-    // 299 - IfFeatureExprOpt: 'or' IfFeatureExpr;
+    // 336 - IfFeatureExprOpt: 'or' IfFeatureExpr;
     Production {
-        lhs: 89,
-        production: &[ParseType::N(88), ParseType::T(62)],
+        lhs: 98,
+        production: &[ParseType::N(97), ParseType::T(64)],
     },
-    // 300 - IfFeatureExprOpt: ;
+    // 337 - IfFeatureExprOpt: ;
     Production {
-        lhs: 89,
+        lhs: 98,
         production: &[],
     },
-    // 301 - IfFeatureTerm: IfFeatureFactor IfFeatureTermOpt /* Option */;
+    // 338 - IfFeatureTerm: IfFeatureFactor IfFeatureTermOpt /* Option */;
     Production {
-        lhs: 93,
-        production: &[ParseType::N(94), ParseType::N(91)],
+        lhs: 102,
+        production: &[ParseType::N(103), ParseType::N(100)],
     },
-    // 302 - IfFeatureTermOpt: 'and' IfFeatureTerm;
+    // 339 - IfFeatureTermOpt: 'and' IfFeatureTerm;
     Production {
-        lhs: 94,
-        production: &[ParseType::N(93), ParseType::T(63)],
+        lhs: 103,
+        production: &[ParseType::N(102), ParseType::T(65)],
     },
-    // 303 - IfFeatureTermOpt: ;
+    // 340 - IfFeatureTermOpt: ;
     Production {
-        lhs: 94,
+        lhs: 103,
         production: &[],
     },
-    // 304 - IfFeatureFactor: 'not' IfFeatureFactor;
+    // 341 - IfFeatureFactor: 'not' IfFeatureFactor;
     Production {
-        lhs: 91,
-        production: &[ParseType::N(91), ParseType::T(64)],
+        lhs: 100,
+        production: &[ParseType::N(100), ParseType::T(66)],
     },
-    // 305 - IfFeatureFactor: '(' IfFeatureExpr ')';
+    // 342 - IfFeatureFactor: '(' IfFeatureExpr ')';
     Production {
-        lhs: 91,
-        production: &[ParseType::T(66), ParseType::N(88), ParseType::T(65)],
+        lhs: 100,
+        production: &[ParseType::T(68), ParseType::N(97), ParseType::T(67)],
     },
-    // 306 - IfFeatureFactor: Identifier;
+    // 343 - IfFeatureFactor: Identifier;
     Production {
-        lhs: 91,
-        production: &[ParseType::N(80)],
+        lhs: 100,
+        production: &[ParseType::N(89)],
     },
-    // 307 - IfFeatureFactor: '"' Identifier '"';
+    // 344 - IfFeatureFactor: '"' Identifier '"';
     Production {
-        lhs: 91,
-        production: &[ParseType::T(56), ParseType::N(80), ParseType::T(56)],
+        lhs: 100,
+        production: &[ParseType::T(57), ParseType::N(89), ParseType::T(57)],
     },
-    // 308 - PresenceStmt: 'presence'^ /* Clipped */ Ystring Semicolon^ /* Clipped */;
+    // 345 - PresenceStmt: 'presence'^ /* Clipped */ Ystring Semicolon^ /* Clipped */;
     Production {
-        lhs: 202,
-        production: &[ParseType::N(234), ParseType::N(275), ParseType::T(67)],
+        lhs: 214,
+        production: &[ParseType::N(246), ParseType::N(287), ParseType::T(69)],
     },
-    // 309 - UsesStmt: KwUses^ /* Clipped */ IdentifierRefArgStr UsesStmtSuffix;
+    // 346 - UsesStmt: KwUses^ /* Clipped */ IdentifierRefArgStr UsesStmtSuffix;
     Production {
-        lhs: 262,
-        production: &[ParseType::N(265), ParseType::N(83), ParseType::N(154)],
+        lhs: 274,
+        production: &[ParseType::N(277), ParseType::N(92), ParseType::N(165)],
     },
-    // 310 - UsesStmtSuffix: Semicolon^ /* Clipped */;
+    // 347 - UsesStmtSuffix: Semicolon^ /* Clipped */;
     Production {
-        lhs: 265,
-        production: &[ParseType::N(234)],
+        lhs: 277,
+        production: &[ParseType::N(246)],
     },
-    // 311 - UsesStmtSuffix: LBrace^ /* Clipped */ UsesStmtList /* Vec */ '}'^ /* Clipped */;
+    // 348 - UsesStmtSuffix: LBrace^ /* Clipped */ UsesStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 265,
-        production: &[ParseType::T(55), ParseType::N(263), ParseType::N(157)],
+        lhs: 277,
+        production: &[ParseType::T(56), ParseType::N(275), ParseType::N(168)],
     },
-    // 312 - UsesStmtList: UsesStmtListGroup UsesStmtList;
+    // 349 - UsesStmtList: UsesStmtListGroup UsesStmtList;
     Production {
-        lhs: 263,
-        production: &[ParseType::N(263), ParseType::N(264)],
+        lhs: 275,
+        production: &[ParseType::N(275), ParseType::N(276)],
     },
-    // 313 - UsesStmtListGroup: WhenStmt;
+    // 350 - UsesStmtListGroup: WhenStmt;
     Production {
-        lhs: 264,
-        production: &[ParseType::N(267)],
+        lhs: 276,
+        production: &[ParseType::N(279)],
     },
-    // 314 - UsesStmtListGroup: IfFeatureStmt;
+    // 351 - UsesStmtListGroup: IfFeatureStmt;
     Production {
-        lhs: 264,
-        production: &[ParseType::N(92)],
+        lhs: 276,
+        production: &[ParseType::N(101)],
     },
-    // 315 - UsesStmtListGroup: StatusStmt;
+    // 352 - UsesStmtListGroup: StatusStmt;
     Production {
-        lhs: 264,
-        production: &[ParseType::N(239)],
+        lhs: 276,
+        production: &[ParseType::N(251)],
     },
-    // 316 - UsesStmtListGroup: DescriptionStmt;
+    // 353 - UsesStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 264,
+        lhs: 276,
         production: &[ParseType::N(55)],
     },
-    // 317 - UsesStmtListGroup: ReferenceStmt;
+    // 354 - UsesStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 264,
-        production: &[ParseType::N(210)],
+        lhs: 276,
+        production: &[ParseType::N(222)],
     },
-    // 318 - UsesStmtListGroup: RefineStmt;
+    // 355 - UsesStmtListGroup: RefineStmt;
     Production {
-        lhs: 264,
-        production: &[ParseType::N(213)],
+        lhs: 276,
+        production: &[ParseType::N(225)],
     },
-    // 319 - UsesStmtListGroup: AugmentStmt;
+    // 356 - UsesStmtListGroup: AugmentStmt;
     Production {
-        lhs: 264,
+        lhs: 276,
         production: &[ParseType::N(17)],
     },
-    // 320 - UsesStmtList: ;
+    // 357 - UsesStmtList: ;
     Production {
-        lhs: 263,
+        lhs: 275,
         production: &[],
     },
-    // 321 - RefineStmt: KwRefine^ /* Clipped */ RefineArgStr RefineStmtSuffix;
+    // 358 - RefineStmt: KwRefine^ /* Clipped */ RefineArgStr RefineStmtSuffix;
     Production {
-        lhs: 213,
-        production: &[ParseType::N(216), ParseType::N(212), ParseType::N(145)],
+        lhs: 225,
+        production: &[ParseType::N(228), ParseType::N(224), ParseType::N(156)],
     },
-    // 322 - RefineStmtSuffix: Semicolon^ /* Clipped */;
+    // 359 - RefineStmtSuffix: Semicolon^ /* Clipped */;
     Production {
-        lhs: 216,
-        production: &[ParseType::N(234)],
+        lhs: 228,
+        production: &[ParseType::N(246)],
     },
-    // 323 - RefineStmtSuffix: LBrace^ /* Clipped */ RefineStmtList /* Vec */ '}'^ /* Clipped */;
+    // 360 - RefineStmtSuffix: LBrace^ /* Clipped */ RefineStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 216,
-        production: &[ParseType::T(55), ParseType::N(214), ParseType::N(157)],
+        lhs: 228,
+        production: &[ParseType::T(56), ParseType::N(226), ParseType::N(168)],
     },
-    // 324 - RefineStmtList: RefineStmtListGroup RefineStmtList;
+    // 361 - RefineStmtList: RefineStmtListGroup RefineStmtList;
     Production {
-        lhs: 214,
-        production: &[ParseType::N(214), ParseType::N(215)],
+        lhs: 226,
+        production: &[ParseType::N(226), ParseType::N(227)],
     },
-    // 325 - RefineStmtListGroup: IfFeatureStmt;
+    // 362 - RefineStmtListGroup: IfFeatureStmt;
     Production {
-        lhs: 215,
-        production: &[ParseType::N(92)],
+        lhs: 227,
+        production: &[ParseType::N(101)],
     },
-    // 326 - RefineStmtListGroup: MustStmt;
+    // 363 - RefineStmtListGroup: MustStmt;
     Production {
-        lhs: 215,
-        production: &[ParseType::N(183)],
+        lhs: 227,
+        production: &[ParseType::N(194)],
     },
-    // 327 - RefineStmtListGroup: PresenceStmt;
+    // 364 - RefineStmtListGroup: PresenceStmt;
     Production {
-        lhs: 215,
-        production: &[ParseType::N(202)],
+        lhs: 227,
+        production: &[ParseType::N(214)],
     },
-    // 328 - RefineStmtListGroup: DefaultStmt;
+    // 365 - RefineStmtListGroup: DefaultStmt;
     Production {
-        lhs: 215,
+        lhs: 227,
         production: &[ParseType::N(52)],
     },
-    // 329 - RefineStmtListGroup: ConfigStmt;
+    // 366 - RefineStmtListGroup: ConfigStmt;
     Production {
-        lhs: 215,
+        lhs: 227,
         production: &[ParseType::N(36)],
     },
-    // 330 - RefineStmtListGroup: MandatoryStmt;
+    // 367 - RefineStmtListGroup: MandatoryStmt;
     Production {
-        lhs: 215,
-        production: &[ParseType::N(172)],
+        lhs: 227,
+        production: &[ParseType::N(183)],
     },
-    // 331 - RefineStmtListGroup: MinElementsStmt;
+    // 368 - RefineStmtListGroup: MinElementsStmt;
     Production {
-        lhs: 215,
-        production: &[ParseType::N(175)],
+        lhs: 227,
+        production: &[ParseType::N(186)],
     },
-    // 332 - RefineStmtListGroup: MaxElementsStmt;
+    // 369 - RefineStmtListGroup: MaxElementsStmt;
     Production {
-        lhs: 215,
-        production: &[ParseType::N(173)],
+        lhs: 227,
+        production: &[ParseType::N(184)],
     },
-    // 333 - RefineStmtListGroup: DescriptionStmt;
+    // 370 - RefineStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 215,
+        lhs: 227,
         production: &[ParseType::N(55)],
     },
-    // 334 - RefineStmtListGroup: ReferenceStmt;
+    // 371 - RefineStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 215,
-        production: &[ParseType::N(210)],
+        lhs: 227,
+        production: &[ParseType::N(222)],
     },
-    // 335 - RefineStmtList: ;
+    // 372 - RefineStmtList: ;
     Production {
-        lhs: 214,
+        lhs: 226,
         production: &[],
     },
-    // 336 - RefineArgStr: RefineArg;
+    // 373 - RefineArgStr: RefineArg;
     Production {
-        lhs: 212,
-        production: &[ParseType::N(211)],
+        lhs: 224,
+        production: &[ParseType::N(223)],
     },
-    // 337 - RefineArgStr: '"' RefineArg '"';
+    // 374 - RefineArgStr: '"' RefineArg '"';
     Production {
-        lhs: 212,
-        production: &[ParseType::T(56), ParseType::N(211), ParseType::T(56)],
+        lhs: 224,
+        production: &[ParseType::T(57), ParseType::N(223), ParseType::T(57)],
     },
-    // 338 - RefineArg: DescendantSchemaNodeid;
+    // 375 - RefineArg: DescendantSchemaNodeid;
     Production {
-        lhs: 211,
+        lhs: 223,
         production: &[ParseType::N(54)],
     },
-    // 339 - DescendantSchemaNodeid: IdentifierRef;
+    // 376 - DescendantSchemaNodeid: IdentifierRef;
     Production {
         lhs: 54,
-        production: &[ParseType::N(82)],
+        production: &[ParseType::N(91)],
     },
-    // 340 - DescendantSchemaNodeid: AbsoluteSchemaNodeid;
+    // 377 - DescendantSchemaNodeid: AbsoluteSchemaNodeid;
     Production {
         lhs: 54,
         production: &[ParseType::N(0)],
     },
-    // 341 - UnknownStmt: IdentifierRef Ystring UnknownStmtSuffix;
+    // 378 - UnknownStmt: IdentifierRef Ystring UnknownStmtSuffix;
     Production {
-        lhs: 256,
-        production: &[ParseType::N(259), ParseType::N(275), ParseType::N(82)],
+        lhs: 268,
+        production: &[ParseType::N(271), ParseType::N(287), ParseType::N(91)],
     },
-    // 342 - UnknownStmtSuffix: Semicolon^ /* Clipped */;
+    // 379 - UnknownStmtSuffix: Semicolon^ /* Clipped */;
     Production {
-        lhs: 259,
-        production: &[ParseType::N(234)],
+        lhs: 271,
+        production: &[ParseType::N(246)],
     },
-    // 343 - UnknownStmtSuffix: LBrace^ /* Clipped */ UnknownStmtList /* Vec */ '}'^ /* Clipped */;
+    // 380 - UnknownStmtSuffix: LBrace^ /* Clipped */ UnknownStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 259,
-        production: &[ParseType::T(55), ParseType::N(257), ParseType::N(157)],
+        lhs: 271,
+        production: &[ParseType::T(56), ParseType::N(269), ParseType::N(168)],
     },
-    // 344 - UnknownStmtList: UnknownStmtListGroup UnknownStmtList;
+    // 381 - UnknownStmtList: UnknownStmtListGroup UnknownStmtList;
     Production {
-        lhs: 257,
-        production: &[ParseType::N(257), ParseType::N(258)],
+        lhs: 269,
+        production: &[ParseType::N(269), ParseType::N(270)],
     },
-    // 345 - UnknownStmtListGroup: TypeStmt;
+    // 382 - UnknownStmtListGroup: TypeStmt;
     Production {
-        lhs: 258,
-        production: &[ParseType::N(248)],
+        lhs: 270,
+        production: &[ParseType::N(260)],
     },
-    // 346 - UnknownStmtListGroup: DescriptionStmt;
+    // 383 - UnknownStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 258,
+        lhs: 270,
         production: &[ParseType::N(55)],
     },
-    // 347 - UnknownStmtList: ;
+    // 384 - UnknownStmtList: ;
     Production {
-        lhs: 257,
+        lhs: 269,
         production: &[],
     },
-    // 348 - LeafListStmt: KwLeafList^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ LeafListStmtList /* Vec */ '}'^ /* Clipped */;
+    // 385 - LeafListStmt: KwLeafList^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ LeafListStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 158,
+        lhs: 169,
         production: &[
-            ParseType::T(55),
-            ParseType::N(159),
-            ParseType::N(157),
-            ParseType::N(81),
-            ParseType::N(134),
+            ParseType::T(56),
+            ParseType::N(170),
+            ParseType::N(168),
+            ParseType::N(90),
+            ParseType::N(145),
         ],
     },
-    // 349 - LeafListStmtList: LeafListStmtListGroup LeafListStmtList;
+    // 386 - LeafListStmtList: LeafListStmtListGroup LeafListStmtList;
     Production {
-        lhs: 159,
-        production: &[ParseType::N(159), ParseType::N(160)],
+        lhs: 170,
+        production: &[ParseType::N(170), ParseType::N(171)],
     },
-    // 350 - LeafListStmtListGroup: WhenStmt;
+    // 387 - LeafListStmtListGroup: WhenStmt;
     Production {
-        lhs: 160,
-        production: &[ParseType::N(267)],
+        lhs: 171,
+        production: &[ParseType::N(279)],
     },
-    // 351 - LeafListStmtListGroup: IfFeatureStmt;
+    // 388 - LeafListStmtListGroup: IfFeatureStmt;
     Production {
-        lhs: 160,
-        production: &[ParseType::N(92)],
+        lhs: 171,
+        production: &[ParseType::N(101)],
     },
-    // 352 - LeafListStmtListGroup: TypeStmt;
+    // 389 - LeafListStmtListGroup: TypeStmt;
     Production {
-        lhs: 160,
-        production: &[ParseType::N(248)],
+        lhs: 171,
+        production: &[ParseType::N(260)],
     },
-    // 353 - LeafListStmtListGroup: UnitsStmt;
+    // 390 - LeafListStmtListGroup: UnitsStmt;
     Production {
-        lhs: 160,
-        production: &[ParseType::N(255)],
+        lhs: 171,
+        production: &[ParseType::N(267)],
     },
-    // 354 - LeafListStmtListGroup: MustStmt;
+    // 391 - LeafListStmtListGroup: MustStmt;
     Production {
-        lhs: 160,
-        production: &[ParseType::N(183)],
+        lhs: 171,
+        production: &[ParseType::N(194)],
     },
-    // 355 - LeafListStmtListGroup: DefaultStmt;
+    // 392 - LeafListStmtListGroup: DefaultStmt;
     Production {
-        lhs: 160,
+        lhs: 171,
         production: &[ParseType::N(52)],
     },
-    // 356 - LeafListStmtListGroup: ConfigStmt;
+    // 393 - LeafListStmtListGroup: ConfigStmt;
     Production {
-        lhs: 160,
+        lhs: 171,
         production: &[ParseType::N(36)],
     },
-    // 357 - LeafListStmtListGroup: MinElementsStmt;
+    // 394 - LeafListStmtListGroup: MinElementsStmt;
     Production {
-        lhs: 160,
-        production: &[ParseType::N(175)],
+        lhs: 171,
+        production: &[ParseType::N(186)],
     },
-    // 358 - LeafListStmtListGroup: MaxElementsStmt;
+    // 395 - LeafListStmtListGroup: MaxElementsStmt;
     Production {
-        lhs: 160,
-        production: &[ParseType::N(173)],
+        lhs: 171,
+        production: &[ParseType::N(184)],
     },
-    // 359 - LeafListStmtListGroup: OrderedByStmt;
+    // 396 - LeafListStmtListGroup: OrderedByStmt;
     Production {
-        lhs: 160,
-        production: &[ParseType::N(194)],
+        lhs: 171,
+        production: &[ParseType::N(205)],
     },
-    // 360 - LeafListStmtListGroup: StatusStmt;
+    // 397 - LeafListStmtListGroup: StatusStmt;
     Production {
-        lhs: 160,
-        production: &[ParseType::N(239)],
+        lhs: 171,
+        production: &[ParseType::N(251)],
     },
-    // 361 - LeafListStmtListGroup: DescriptionStmt;
+    // 398 - LeafListStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 160,
+        lhs: 171,
         production: &[ParseType::N(55)],
     },
-    // 362 - LeafListStmtListGroup: ReferenceStmt;
+    // 399 - LeafListStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 160,
-        production: &[ParseType::N(210)],
+        lhs: 171,
+        production: &[ParseType::N(222)],
     },
-    // 363 - LeafListStmtListGroup: UnknownStmt;
+    // 400 - LeafListStmtListGroup: UnknownStmt;
     Production {
-        lhs: 160,
-        production: &[ParseType::N(256)],
+        lhs: 171,
+        production: &[ParseType::N(268)],
     },
-    // 364 - LeafListStmtList: ;
+    // 401 - LeafListStmtList: ;
     Production {
-        lhs: 159,
+        lhs: 170,
         production: &[],
     },
-    // 365 - LeafStmt: KwLeaf^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ LeafStmtList /* Vec */ '}'^ /* Clipped */;
+    // 402 - LeafStmt: KwLeaf^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ LeafStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 161,
+        lhs: 172,
         production: &[
-            ParseType::T(55),
-            ParseType::N(162),
-            ParseType::N(157),
-            ParseType::N(81),
-            ParseType::N(133),
+            ParseType::T(56),
+            ParseType::N(173),
+            ParseType::N(168),
+            ParseType::N(90),
+            ParseType::N(144),
         ],
     },
-    // 366 - LeafStmtList: LeafStmtListGroup LeafStmtList;
+    // 403 - LeafStmtList: LeafStmtListGroup LeafStmtList;
     Production {
-        lhs: 162,
-        production: &[ParseType::N(162), ParseType::N(163)],
+        lhs: 173,
+        production: &[ParseType::N(173), ParseType::N(174)],
     },
-    // 367 - LeafStmtListGroup: WhenStmt;
+    // 404 - LeafStmtListGroup: WhenStmt;
     Production {
-        lhs: 163,
-        production: &[ParseType::N(267)],
+        lhs: 174,
+        production: &[ParseType::N(279)],
     },
-    // 368 - LeafStmtListGroup: StatusStmt;
+    // 405 - LeafStmtListGroup: StatusStmt;
     Production {
-        lhs: 163,
-        production: &[ParseType::N(239)],
+        lhs: 174,
+        production: &[ParseType::N(251)],
     },
-    // 369 - LeafStmtListGroup: IfFeatureStmt;
+    // 406 - LeafStmtListGroup: IfFeatureStmt;
     Production {
-        lhs: 163,
-        production: &[ParseType::N(92)],
+        lhs: 174,
+        production: &[ParseType::N(101)],
     },
-    // 370 - LeafStmtListGroup: TypeStmt;
+    // 407 - LeafStmtListGroup: TypeStmt;
     Production {
-        lhs: 163,
-        production: &[ParseType::N(248)],
+        lhs: 174,
+        production: &[ParseType::N(260)],
     },
-    // 371 - LeafStmtListGroup: UnitsStmt;
+    // 408 - LeafStmtListGroup: UnitsStmt;
     Production {
-        lhs: 163,
-        production: &[ParseType::N(255)],
+        lhs: 174,
+        production: &[ParseType::N(267)],
     },
-    // 372 - LeafStmtListGroup: MustStmt;
+    // 409 - LeafStmtListGroup: MustStmt;
     Production {
-        lhs: 163,
-        production: &[ParseType::N(183)],
+        lhs: 174,
+        production: &[ParseType::N(194)],
     },
-    // 373 - LeafStmtListGroup: DefaultStmt;
+    // 410 - LeafStmtListGroup: DefaultStmt;
     Production {
-        lhs: 163,
+        lhs: 174,
         production: &[ParseType::N(52)],
     },
-    // 374 - LeafStmtListGroup: ConfigStmt;
+    // 411 - LeafStmtListGroup: ConfigStmt;
     Production {
-        lhs: 163,
+        lhs: 174,
         production: &[ParseType::N(36)],
     },
-    // 375 - LeafStmtListGroup: MandatoryStmt;
+    // 412 - LeafStmtListGroup: MandatoryStmt;
     Production {
-        lhs: 163,
-        production: &[ParseType::N(172)],
+        lhs: 174,
+        production: &[ParseType::N(183)],
     },
-    // 376 - LeafStmtListGroup: DescriptionStmt;
+    // 413 - LeafStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 163,
+        lhs: 174,
         production: &[ParseType::N(55)],
     },
-    // 377 - LeafStmtListGroup: ReferenceStmt;
+    // 414 - LeafStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 163,
-        production: &[ParseType::N(210)],
+        lhs: 174,
+        production: &[ParseType::N(222)],
     },
-    // 378 - LeafStmtListGroup: UnknownStmt;
+    // 415 - LeafStmtListGroup: UnknownStmt;
     Production {
-        lhs: 163,
-        production: &[ParseType::N(256)],
+        lhs: 174,
+        production: &[ParseType::N(268)],
     },
-    // 379 - LeafStmtList: ;
+    // 416 - LeafStmtList: ;
     Production {
-        lhs: 162,
+        lhs: 173,
         production: &[],
     },
-    // 380 - MustStmt: 'must'^ /* Clipped */ Ystring MustStmtSuffix;
+    // 417 - MustStmt: 'must'^ /* Clipped */ Ystring MustStmtSuffix;
     Production {
-        lhs: 183,
-        production: &[ParseType::N(186), ParseType::N(275), ParseType::T(68)],
+        lhs: 194,
+        production: &[ParseType::N(197), ParseType::N(287), ParseType::T(70)],
     },
-    // 381 - MustStmtSuffix: Semicolon^ /* Clipped */;
+    // 418 - MustStmtSuffix: Semicolon^ /* Clipped */;
     Production {
-        lhs: 186,
-        production: &[ParseType::N(234)],
+        lhs: 197,
+        production: &[ParseType::N(246)],
     },
-    // 382 - MustStmtSuffix: LBrace^ /* Clipped */ MustStmtList /* Vec */ '}'^ /* Clipped */;
+    // 419 - MustStmtSuffix: LBrace^ /* Clipped */ MustStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 186,
-        production: &[ParseType::T(55), ParseType::N(184), ParseType::N(157)],
+        lhs: 197,
+        production: &[ParseType::T(56), ParseType::N(195), ParseType::N(168)],
     },
-    // 383 - MustStmtList: MustStmtListGroup MustStmtList;
+    // 420 - MustStmtList: MustStmtListGroup MustStmtList;
     Production {
-        lhs: 184,
-        production: &[ParseType::N(184), ParseType::N(185)],
+        lhs: 195,
+        production: &[ParseType::N(195), ParseType::N(196)],
     },
-    // 384 - MustStmtListGroup: ErrorMessage;
+    // 421 - MustStmtListGroup: ErrorMessage;
     Production {
-        lhs: 185,
-        production: &[ParseType::N(63)],
+        lhs: 196,
+        production: &[ParseType::N(72)],
     },
-    // 385 - MustStmtListGroup: DescriptionStmt;
+    // 422 - MustStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 185,
+        lhs: 196,
         production: &[ParseType::N(55)],
     },
-    // 386 - MustStmtList: ;
+    // 423 - MustStmtList: ;
     Production {
-        lhs: 184,
+        lhs: 195,
         production: &[],
     },
-    // 387 - ErrorMessage: 'error-message' Ystring Semicolon;
+    // 424 - ErrorMessage: 'error-message' Ystring Semicolon;
     Production {
-        lhs: 63,
-        production: &[ParseType::N(234), ParseType::N(275), ParseType::T(69)],
+        lhs: 72,
+        production: &[ParseType::N(246), ParseType::N(287), ParseType::T(71)],
     },
-    // 388 - UnitsStmt: 'units' Ystring Semicolon;
+    // 425 - UnitsStmt: 'units' Ystring Semicolon;
     Production {
-        lhs: 255,
-        production: &[ParseType::N(234), ParseType::N(275), ParseType::T(70)],
+        lhs: 267,
+        production: &[ParseType::N(246), ParseType::N(287), ParseType::T(72)],
     },
-    // 389 - ConfigStmt: KwConfig^ /* Clipped */ MandatoryArgStr Semicolon;
+    // 426 - ConfigStmt: KwConfig^ /* Clipped */ MandatoryArgStr Semicolon;
     Production {
         lhs: 36,
-        production: &[ParseType::N(234), ParseType::N(171), ParseType::N(119)],
+        production: &[ParseType::N(246), ParseType::N(182), ParseType::N(129)],
     },
-    // 390 - MandatoryStmt: KwMandatory^ /* Clipped */ MandatoryArgStr Semicolon;
+    // 427 - MandatoryStmt: KwMandatory^ /* Clipped */ MandatoryArgStr Semicolon;
     Production {
-        lhs: 172,
-        production: &[ParseType::N(234), ParseType::N(171), ParseType::N(137)],
+        lhs: 183,
+        production: &[ParseType::N(246), ParseType::N(182), ParseType::N(148)],
     },
-    // 391 - MandatoryArgStr: MandatoryArg;
+    // 428 - MandatoryArgStr: MandatoryArg;
     Production {
-        lhs: 171,
-        production: &[ParseType::N(170)],
+        lhs: 182,
+        production: &[ParseType::N(181)],
     },
-    // 392 - MandatoryArgStr: '"' MandatoryArg '"';
+    // 429 - MandatoryArgStr: '"' MandatoryArg '"';
     Production {
-        lhs: 171,
-        production: &[ParseType::T(56), ParseType::N(170), ParseType::T(56)],
+        lhs: 182,
+        production: &[ParseType::T(57), ParseType::N(181), ParseType::T(57)],
     },
-    // 393 - MandatoryArg: /true|false/;
+    // 430 - MandatoryArg: /true|false/;
     Production {
-        lhs: 170,
-        production: &[ParseType::T(71)],
+        lhs: 181,
+        production: &[ParseType::T(73)],
     },
-    // 394 - ListStmt: KwList^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ ListStmtList /* Vec */ '}'^ /* Clipped */;
+    // 431 - ListStmt: KwList^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ ListStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 167,
+        lhs: 178,
         production: &[
-            ParseType::T(55),
+            ParseType::T(56),
+            ParseType::N(179),
             ParseType::N(168),
-            ParseType::N(157),
-            ParseType::N(81),
-            ParseType::N(136),
+            ParseType::N(90),
+            ParseType::N(147),
         ],
     },
-    // 395 - ListStmtList: ListStmtListGroup ListStmtList;
+    // 432 - ListStmtList: ListStmtListGroup ListStmtList;
     Production {
-        lhs: 168,
-        production: &[ParseType::N(168), ParseType::N(169)],
+        lhs: 179,
+        production: &[ParseType::N(179), ParseType::N(180)],
     },
-    // 396 - ListStmtListGroup: WhenStmt;
+    // 433 - ListStmtListGroup: WhenStmt;
     Production {
-        lhs: 169,
-        production: &[ParseType::N(267)],
+        lhs: 180,
+        production: &[ParseType::N(279)],
     },
-    // 397 - ListStmtListGroup: IfFeatureStmt;
+    // 434 - ListStmtListGroup: IfFeatureStmt;
     Production {
-        lhs: 169,
-        production: &[ParseType::N(92)],
+        lhs: 180,
+        production: &[ParseType::N(101)],
     },
-    // 398 - ListStmtListGroup: MustStmt;
+    // 435 - ListStmtListGroup: MustStmt;
     Production {
-        lhs: 169,
-        production: &[ParseType::N(183)],
+        lhs: 180,
+        production: &[ParseType::N(194)],
     },
-    // 399 - ListStmtListGroup: KeyStmt;
+    // 436 - ListStmtListGroup: KeyStmt;
     Production {
-        lhs: 169,
-        production: &[ParseType::N(109)],
+        lhs: 180,
+        production: &[ParseType::N(119)],
     },
-    // 400 - ListStmtListGroup: ConfigStmt;
+    // 437 - ListStmtListGroup: ConfigStmt;
     Production {
-        lhs: 169,
+        lhs: 180,
         production: &[ParseType::N(36)],
     },
-    // 401 - ListStmtListGroup: MinElementsStmt;
+    // 438 - ListStmtListGroup: MinElementsStmt;
     Production {
-        lhs: 169,
-        production: &[ParseType::N(175)],
+        lhs: 180,
+        production: &[ParseType::N(186)],
     },
-    // 402 - ListStmtListGroup: MaxElementsStmt;
+    // 439 - ListStmtListGroup: MaxElementsStmt;
     Production {
-        lhs: 169,
-        production: &[ParseType::N(173)],
+        lhs: 180,
+        production: &[ParseType::N(184)],
     },
-    // 403 - ListStmtListGroup: OrderedByStmt;
+    // 440 - ListStmtListGroup: OrderedByStmt;
     Production {
-        lhs: 169,
-        production: &[ParseType::N(194)],
+        lhs: 180,
+        production: &[ParseType::N(205)],
     },
-    // 404 - ListStmtListGroup: StatusStmt;
+    // 441 - ListStmtListGroup: StatusStmt;
     Production {
-        lhs: 169,
-        production: &[ParseType::N(239)],
+        lhs: 180,
+        production: &[ParseType::N(251)],
     },
-    // 405 - ListStmtListGroup: DescriptionStmt;
+    // 442 - ListStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 169,
+        lhs: 180,
         production: &[ParseType::N(55)],
     },
-    // 406 - ListStmtListGroup: ReferenceStmt;
+    // 443 - ListStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 169,
-        production: &[ParseType::N(210)],
+        lhs: 180,
+        production: &[ParseType::N(222)],
     },
-    // 407 - ListStmtListGroup: DataDefStmt;
+    // 444 - ListStmtListGroup: TypedefStmt;
     Production {
-        lhs: 169,
+        lhs: 180,
+        production: &[ParseType::N(264)],
+    },
+    // 445 - ListStmtListGroup: GroupingStmt;
+    Production {
+        lhs: 180,
+        production: &[ParseType::N(86)],
+    },
+    // 446 - ListStmtListGroup: DataDefStmt;
+    Production {
+        lhs: 180,
         production: &[ParseType::N(49)],
     },
-    // 408 - ListStmtListGroup: ActionStmt;
+    // 447 - ListStmtListGroup: ActionStmt;
     Production {
-        lhs: 169,
+        lhs: 180,
         production: &[ParseType::N(1)],
     },
-    // 409 - ListStmtListGroup: NotificationStmt;
+    // 448 - ListStmtListGroup: NotificationStmt;
     Production {
-        lhs: 169,
-        production: &[ParseType::N(189)],
+        lhs: 180,
+        production: &[ParseType::N(200)],
     },
-    // 410 - ListStmtListGroup: UnknownStmt;
+    // 449 - ListStmtListGroup: UnknownStmt;
     Production {
-        lhs: 169,
-        production: &[ParseType::N(256)],
+        lhs: 180,
+        production: &[ParseType::N(268)],
     },
-    // 411 - ListStmtList: ;
+    // 450 - ListStmtList: ;
     Production {
-        lhs: 168,
+        lhs: 179,
         production: &[],
     },
-    // 412 - OrderedByStmt: KwOrderedBy^ /* Clipped */ OrderedByArgStr Semicolon^ /* Clipped */;
+    // 451 - OrderedByStmt: KwOrderedBy^ /* Clipped */ OrderedByArgStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 194,
-        production: &[ParseType::N(234), ParseType::N(193), ParseType::N(141)],
+        lhs: 205,
+        production: &[ParseType::N(246), ParseType::N(204), ParseType::N(152)],
     },
-    // 413 - OrderedByArgStr: OrderedByArg;
+    // 452 - OrderedByArgStr: OrderedByArg;
     Production {
-        lhs: 193,
-        production: &[ParseType::N(192)],
+        lhs: 204,
+        production: &[ParseType::N(203)],
     },
-    // 414 - OrderedByArgStr: '"' OrderedByArg '"';
+    // 453 - OrderedByArgStr: '"' OrderedByArg '"';
     Production {
-        lhs: 193,
-        production: &[ParseType::T(56), ParseType::N(192), ParseType::T(56)],
+        lhs: 204,
+        production: &[ParseType::T(57), ParseType::N(203), ParseType::T(57)],
     },
-    // 415 - OrderedByArg: /user|system/;
+    // 454 - OrderedByArg: /user|system/;
     Production {
-        lhs: 192,
-        production: &[ParseType::T(72)],
+        lhs: 203,
+        production: &[ParseType::T(74)],
     },
-    // 416 - DefaultStmt: KwDefault^ /* Clipped */ DefaultStmtSuffix;
+    // 455 - DefaultStmt: KwDefault^ /* Clipped */ DefaultStmtSuffix;
     Production {
         lhs: 52,
-        production: &[ParseType::N(53), ParseType::N(121)],
+        production: &[ParseType::N(53), ParseType::N(131)],
     },
-    // 417 - DefaultStmtSuffix: AsciiNoSemicolon Semicolon^ /* Clipped */;
+    // 456 - DefaultStmtSuffix: AsciiNoSemicolon Semicolon^ /* Clipped */;
     Production {
         lhs: 53,
-        production: &[ParseType::N(234), ParseType::N(15)],
+        production: &[ParseType::N(246), ParseType::N(15)],
     },
-    // 418 - DefaultStmtSuffix: '"' AsciiNoSemicolon '"' Semicolon^ /* Clipped */;
+    // 457 - DefaultStmtSuffix: '"' AsciiNoSemicolon '"' Semicolon^ /* Clipped */;
     Production {
         lhs: 53,
         production: &[
-            ParseType::N(234),
-            ParseType::T(56),
+            ParseType::N(246),
+            ParseType::T(57),
             ParseType::N(15),
-            ParseType::T(56),
+            ParseType::T(57),
         ],
     },
-    // 419 - MaxElementsStmt: 'max-elements'^ /* Clipped */ /[1-9][0-9]*/ Semicolon^ /* Clipped */;
+    // 458 - MaxElementsStmt: 'max-elements'^ /* Clipped */ /[1-9][0-9]*/ Semicolon^ /* Clipped */;
     Production {
-        lhs: 173,
-        production: &[ParseType::N(234), ParseType::T(74), ParseType::T(73)],
+        lhs: 184,
+        production: &[ParseType::N(246), ParseType::T(76), ParseType::T(75)],
     },
-    // 420 - MinElementsStmt: 'min-elements'^ /* Clipped */ /[1-9][0-9]*/ Semicolon^ /* Clipped */;
+    // 459 - MinElementsStmt: 'min-elements'^ /* Clipped */ /[1-9][0-9]*/ Semicolon^ /* Clipped */;
     Production {
-        lhs: 175,
-        production: &[ParseType::N(234), ParseType::T(74), ParseType::T(75)],
+        lhs: 186,
+        production: &[ParseType::N(246), ParseType::T(76), ParseType::T(77)],
     },
-    // 421 - TypeStmt: KwType^ /* Clipped */ IdentifierRefArgStr TypeStmtSuffix;
+    // 460 - TypeStmt: KwType^ /* Clipped */ IdentifierRefArgStr TypeStmtSuffix;
     Production {
-        lhs: 248,
-        production: &[ParseType::N(251), ParseType::N(83), ParseType::N(152)],
+        lhs: 260,
+        production: &[ParseType::N(263), ParseType::N(92), ParseType::N(163)],
     },
-    // 422 - TypeStmtSuffix: Semicolon^ /* Clipped */;
+    // 461 - TypeStmtSuffix: Semicolon^ /* Clipped */;
     Production {
-        lhs: 251,
-        production: &[ParseType::N(234)],
+        lhs: 263,
+        production: &[ParseType::N(246)],
     },
-    // 423 - TypeStmtSuffix: LBrace^ /* Clipped */ TypeStmtList /* Vec */ '}'^ /* Clipped */;
+    // 462 - TypeStmtSuffix: LBrace^ /* Clipped */ TypeStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 251,
-        production: &[ParseType::T(55), ParseType::N(249), ParseType::N(157)],
+        lhs: 263,
+        production: &[ParseType::T(56), ParseType::N(261), ParseType::N(168)],
     },
-    // 424 - TypeStmtList: TypeStmtListGroup TypeStmtList;
+    // 463 - TypeStmtList: TypeStmtListGroup TypeStmtList;
     Production {
-        lhs: 249,
-        production: &[ParseType::N(249), ParseType::N(250)],
+        lhs: 261,
+        production: &[ParseType::N(261), ParseType::N(262)],
     },
-    // 425 - TypeStmtListGroup: FractionDigitsStmt;
+    // 464 - TypeStmtListGroup: FractionDigitsStmt;
     Production {
-        lhs: 250,
-        production: &[ParseType::N(76)],
+        lhs: 262,
+        production: &[ParseType::N(85)],
     },
-    // 426 - TypeStmtListGroup: EnumStmt;
+    // 465 - TypeStmtListGroup: EnumStmt;
     Production {
-        lhs: 250,
-        production: &[ParseType::N(59)],
+        lhs: 262,
+        production: &[ParseType::N(68)],
     },
-    // 427 - TypeStmtListGroup: BaseStmt;
+    // 466 - TypeStmtListGroup: BaseStmt;
     Production {
-        lhs: 250,
+        lhs: 262,
         production: &[ParseType::N(20)],
     },
-    // 428 - TypeStmtListGroup: LeafrefSpecification;
+    // 467 - TypeStmtListGroup: LeafrefSpecification;
     Production {
-        lhs: 250,
-        production: &[ParseType::N(164)],
+        lhs: 262,
+        production: &[ParseType::N(175)],
     },
-    // 429 - TypeStmtListGroup: StringRestrictions;
+    // 468 - TypeStmtListGroup: StringRestrictions;
     Production {
-        lhs: 250,
-        production: &[ParseType::N(240)],
+        lhs: 262,
+        production: &[ParseType::N(252)],
     },
-    // 430 - TypeStmtListGroup: RangeStmt;
+    // 469 - TypeStmtListGroup: RangeStmt;
     Production {
-        lhs: 250,
-        production: &[ParseType::N(209)],
+        lhs: 262,
+        production: &[ParseType::N(221)],
     },
-    // 431 - TypeStmtListGroup: BitStmt;
+    // 470 - TypeStmtListGroup: BitStmt;
     Production {
-        lhs: 250,
+        lhs: 262,
         production: &[ParseType::N(23)],
     },
-    // 432 - TypeStmtListGroup: TypeStmt;
+    // 471 - TypeStmtListGroup: TypeStmt;
     Production {
-        lhs: 250,
-        production: &[ParseType::N(248)],
+        lhs: 262,
+        production: &[ParseType::N(260)],
     },
-    // 433 - TypeStmtList: ;
+    // 472 - TypeStmtList: ;
     Production {
-        lhs: 249,
+        lhs: 261,
         production: &[],
     },
-    // 434 - FractionDigitsStmt: KwFractionDigits^ /* Clipped */ FractionDigitsArg Semicolon^ /* Clipped */;
+    // 473 - FractionDigitsStmt: KwFractionDigits^ /* Clipped */ FractionDigitsArg Semicolon^ /* Clipped */;
     Production {
-        lhs: 76,
-        production: &[ParseType::N(234), ParseType::N(75), ParseType::N(126)],
+        lhs: 85,
+        production: &[ParseType::N(246), ParseType::N(84), ParseType::N(137)],
     },
-    // 435 - FractionDigitsArg: /1|2|3|4|5|6|7|8|9|10|11|12|13|14|15|16|17|18|/;
+    // 474 - FractionDigitsArg: /1|2|3|4|5|6|7|8|9|10|11|12|13|14|15|16|17|18|/;
     Production {
-        lhs: 75,
-        production: &[ParseType::T(76)],
+        lhs: 84,
+        production: &[ParseType::T(78)],
     },
-    // 436 - BitStmt: KwBit^ /* Clipped */ IdentifierArgStr BitStmtSuffix;
+    // 475 - BitStmt: KwBit^ /* Clipped */ IdentifierArgStr BitStmtSuffix;
     Production {
         lhs: 23,
-        production: &[ParseType::N(26), ParseType::N(81), ParseType::N(116)],
+        production: &[ParseType::N(26), ParseType::N(90), ParseType::N(126)],
     },
-    // 437 - BitStmtSuffix: Semicolon^ /* Clipped */;
+    // 476 - BitStmtSuffix: Semicolon^ /* Clipped */;
     Production {
         lhs: 26,
-        production: &[ParseType::N(234)],
+        production: &[ParseType::N(246)],
     },
-    // 438 - BitStmtSuffix: LBrace^ /* Clipped */ BitStmtList /* Vec */ '}'^ /* Clipped */;
+    // 477 - BitStmtSuffix: LBrace^ /* Clipped */ BitStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
         lhs: 26,
-        production: &[ParseType::T(55), ParseType::N(24), ParseType::N(157)],
+        production: &[ParseType::T(56), ParseType::N(24), ParseType::N(168)],
     },
-    // 439 - BitStmtList: BitStmtListGroup BitStmtList;
+    // 478 - BitStmtList: BitStmtListGroup BitStmtList;
     Production {
         lhs: 24,
         production: &[ParseType::N(24), ParseType::N(25)],
     },
-    // 440 - BitStmtListGroup: PositionStmt;
+    // 479 - BitStmtListGroup: PositionStmt;
     Production {
         lhs: 25,
-        production: &[ParseType::N(200)],
+        production: &[ParseType::N(212)],
     },
-    // 441 - BitStmtListGroup: DescriptionStmt;
+    // 480 - BitStmtListGroup: DescriptionStmt;
     Production {
         lhs: 25,
         production: &[ParseType::N(55)],
     },
-    // 442 - BitStmtListGroup: ReferenceStmt;
+    // 481 - BitStmtListGroup: ReferenceStmt;
     Production {
         lhs: 25,
-        production: &[ParseType::N(210)],
+        production: &[ParseType::N(222)],
     },
-    // 443 - BitStmtList: ;
+    // 482 - BitStmtList: ;
     Production {
         lhs: 24,
         production: &[],
     },
-    // 444 - PositionStmt: KwPosition^ /* Clipped */ IntegerValueStr Semicolon^ /* Clipped */;
+    // 483 - PositionStmt: KwPosition^ /* Clipped */ IntegerValueStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 200,
-        production: &[ParseType::N(234), ParseType::N(105), ParseType::N(142)],
+        lhs: 212,
+        production: &[ParseType::N(246), ParseType::N(115), ParseType::N(153)],
     },
-    // 445 - LeafrefSpecification: PathStmt;
+    // 484 - LeafrefSpecification: PathStmt;
     Production {
-        lhs: 164,
-        production: &[ParseType::N(198)],
+        lhs: 175,
+        production: &[ParseType::N(210)],
     },
-    // 446 - LeafrefSpecification: RequireInstanceStmt;
+    // 485 - LeafrefSpecification: RequireInstanceStmt;
     Production {
-        lhs: 164,
-        production: &[ParseType::N(218)],
+        lhs: 175,
+        production: &[ParseType::N(230)],
     },
-    // 447 - RequireInstanceStmt: KwRequireInstance^ /* Clipped */ RequireInstanceArgStr Semicolon;
+    // 486 - RequireInstanceStmt: KwRequireInstance^ /* Clipped */ RequireInstanceArgStr Semicolon;
     Production {
-        lhs: 218,
-        production: &[ParseType::N(234), ParseType::N(217), ParseType::N(146)],
+        lhs: 230,
+        production: &[ParseType::N(246), ParseType::N(229), ParseType::N(157)],
     },
-    // 448 - RequireInstanceArgStr: MandatoryArg;
+    // 487 - RequireInstanceArgStr: MandatoryArg;
     Production {
-        lhs: 217,
-        production: &[ParseType::N(170)],
+        lhs: 229,
+        production: &[ParseType::N(181)],
     },
-    // 449 - RequireInstanceArgStr: '"' MandatoryArg '"';
+    // 488 - RequireInstanceArgStr: '"' MandatoryArg '"';
     Production {
-        lhs: 217,
-        production: &[ParseType::T(56), ParseType::N(170), ParseType::T(56)],
+        lhs: 229,
+        production: &[ParseType::T(57), ParseType::N(181), ParseType::T(57)],
     },
-    // 450 - PathStmt: 'path'^ /* Clipped */ Ystring Semicolon^ /* Clipped */;
+    // 489 - PathStmt: 'path'^ /* Clipped */ Ystring Semicolon^ /* Clipped */;
     Production {
-        lhs: 198,
-        production: &[ParseType::N(234), ParseType::N(275), ParseType::T(77)],
+        lhs: 210,
+        production: &[ParseType::N(246), ParseType::N(287), ParseType::T(79)],
     },
-    // 451 - StringRestrictions: LengthStmt;
+    // 490 - StringRestrictions: LengthStmt;
     Production {
-        lhs: 240,
-        production: &[ParseType::N(165)],
+        lhs: 252,
+        production: &[ParseType::N(176)],
     },
-    // 452 - StringRestrictions: PatternStmt;
+    // 491 - StringRestrictions: PatternStmt;
     Production {
-        lhs: 240,
-        production: &[ParseType::N(199)],
+        lhs: 252,
+        production: &[ParseType::N(211)],
     },
-    // 453 - PatternStmt: 'pattern' Ystring Semicolon;
+    // 492 - PatternStmt: 'pattern' Ystring Semicolon;
     Production {
-        lhs: 199,
-        production: &[ParseType::N(234), ParseType::N(275), ParseType::T(78)],
+        lhs: 211,
+        production: &[ParseType::N(246), ParseType::N(287), ParseType::T(80)],
     },
-    // 454 - LengthStmt: KwLength^ /* Clipped */ RangeArgStr Semicolon^ /* Clipped */;
+    // 493 - LengthStmt: KwLength^ /* Clipped */ RangeArgStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 165,
-        production: &[ParseType::N(234), ParseType::N(205), ParseType::N(135)],
+        lhs: 176,
+        production: &[ParseType::N(246), ParseType::N(217), ParseType::N(146)],
     },
-    // 455 - EnumStmt: KwEnum^ /* Clipped */ EnumArgStr EnumStmtSuffix;
+    // 494 - EnumStmt: KwEnum^ /* Clipped */ EnumArgStr EnumStmtSuffix;
     Production {
-        lhs: 59,
-        production: &[ParseType::N(62), ParseType::N(58), ParseType::N(123)],
+        lhs: 68,
+        production: &[ParseType::N(71), ParseType::N(67), ParseType::N(134)],
     },
-    // 456 - EnumStmtSuffix: Semicolon^ /* Clipped */;
+    // 495 - EnumStmtSuffix: Semicolon^ /* Clipped */;
     Production {
-        lhs: 62,
-        production: &[ParseType::N(234)],
+        lhs: 71,
+        production: &[ParseType::N(246)],
     },
-    // 457 - EnumStmtSuffix: LBrace^ /* Clipped */ EnumStmtList /* Vec */ '}'^ /* Clipped */;
+    // 496 - EnumStmtSuffix: LBrace^ /* Clipped */ EnumStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 62,
-        production: &[ParseType::T(55), ParseType::N(60), ParseType::N(157)],
+        lhs: 71,
+        production: &[ParseType::T(56), ParseType::N(69), ParseType::N(168)],
     },
-    // 458 - EnumStmtList: EnumStmtListGroup EnumStmtList;
+    // 497 - EnumStmtList: EnumStmtListGroup EnumStmtList;
     Production {
-        lhs: 60,
-        production: &[ParseType::N(60), ParseType::N(61)],
+        lhs: 69,
+        production: &[ParseType::N(69), ParseType::N(70)],
     },
-    // 459 - EnumStmtListGroup: IfFeatureStmt;
+    // 498 - EnumStmtListGroup: IfFeatureStmt;
     Production {
-        lhs: 61,
-        production: &[ParseType::N(92)],
+        lhs: 70,
+        production: &[ParseType::N(101)],
     },
-    // 460 - EnumStmtListGroup: DescriptionStmt;
+    // 499 - EnumStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 61,
+        lhs: 70,
         production: &[ParseType::N(55)],
     },
-    // 461 - EnumStmtListGroup: ValueStmt;
+    // 500 - EnumStmtListGroup: ValueStmt;
     Production {
-        lhs: 61,
-        production: &[ParseType::N(266)],
+        lhs: 70,
+        production: &[ParseType::N(278)],
     },
-    // 462 - EnumStmtListGroup: ReferenceStmt;
+    // 501 - EnumStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 61,
-        production: &[ParseType::N(210)],
+        lhs: 70,
+        production: &[ParseType::N(222)],
     },
-    // 463 - EnumStmtList: ;
+    // 502 - EnumStmtList: ;
     Production {
-        lhs: 60,
+        lhs: 69,
         production: &[],
     },
-    // 464 - ValueStmt: KwValue^ /* Clipped */ IntegerValueStr Semicolon^ /* Clipped */;
+    // 503 - ValueStmt: KwValue^ /* Clipped */ IntegerValueStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 266,
-        production: &[ParseType::N(234), ParseType::N(105), ParseType::N(155)],
+        lhs: 278,
+        production: &[ParseType::N(246), ParseType::N(115), ParseType::N(166)],
     },
-    // 465 - IntegerValueStr: IntegerValue;
+    // 504 - IntegerValueStr: IntegerValue;
     Production {
-        lhs: 105,
-        production: &[ParseType::N(104)],
+        lhs: 115,
+        production: &[ParseType::N(114)],
     },
-    // 466 - IntegerValueStr: '"' IntegerValue '"';
+    // 505 - IntegerValueStr: '"' IntegerValue '"';
     Production {
-        lhs: 105,
-        production: &[ParseType::T(56), ParseType::N(104), ParseType::T(56)],
+        lhs: 115,
+        production: &[ParseType::T(57), ParseType::N(114), ParseType::T(57)],
     },
-    // 467 - IntegerValue: /[0-9]+/;
+    // 506 - IntegerValue: /[0-9]+/;
     Production {
-        lhs: 104,
-        production: &[ParseType::T(79)],
+        lhs: 114,
+        production: &[ParseType::T(81)],
     },
-    // 468 - EnumArgStr: AsciiNoBrace;
+    // 507 - EnumArgStr: AsciiNoBrace;
     Production {
-        lhs: 58,
+        lhs: 67,
         production: &[ParseType::N(14)],
     },
-    // 469 - EnumArgStr: '"'^ /* Clipped */ AsciiNoBrace '"'^ /* Clipped */;
+    // 508 - EnumArgStr: '"'^ /* Clipped */ AsciiNoBrace '"'^ /* Clipped */;
     Production {
-        lhs: 58,
-        production: &[ParseType::T(56), ParseType::N(14), ParseType::T(56)],
+        lhs: 67,
+        production: &[ParseType::T(57), ParseType::N(14), ParseType::T(57)],
     },
-    // 470 - RangeStmt: KwRange^ /* Clipped */ RangeArgStr Semicolon^ /* Clipped */;
+    // 509 - RangeStmt: KwRange^ /* Clipped */ RangeArgStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 209,
-        production: &[ParseType::N(234), ParseType::N(205), ParseType::N(144)],
+        lhs: 221,
+        production: &[ParseType::N(246), ParseType::N(217), ParseType::N(155)],
     },
-    // 471 - RangeArgStr: RangeArg;
+    // 510 - RangeArgStr: RangeArg;
     Production {
-        lhs: 205,
-        production: &[ParseType::N(203)],
+        lhs: 217,
+        production: &[ParseType::N(215)],
     },
-    // 472 - RangeArgStr: '"'^ /* Clipped */ RangeArg '"'^ /* Clipped */;
+    // 511 - RangeArgStr: '"'^ /* Clipped */ RangeArg '"'^ /* Clipped */;
     Production {
-        lhs: 205,
-        production: &[ParseType::T(56), ParseType::N(203), ParseType::T(56)],
+        lhs: 217,
+        production: &[ParseType::T(57), ParseType::N(215), ParseType::T(57)],
     },
-    // 473 - RangeArg: RangePart RangeArgOpt /* Option */;
+    // 512 - RangeArg: RangePart RangeArgOpt /* Option */;
     Production {
-        lhs: 203,
-        production: &[ParseType::N(204), ParseType::N(207)],
+        lhs: 215,
+        production: &[ParseType::N(216), ParseType::N(219)],
     },
-    // 474 - RangeArgOpt: '|' RangeArg;
+    // 513 - RangeArgOpt: '|' RangeArg;
     Production {
-        lhs: 204,
-        production: &[ParseType::N(203), ParseType::T(80)],
+        lhs: 216,
+        production: &[ParseType::N(215), ParseType::T(82)],
     },
-    // 475 - RangeArgOpt: ;
+    // 514 - RangeArgOpt: ;
     Production {
-        lhs: 204,
+        lhs: 216,
         production: &[],
     },
-    // 476 - RangePart: RangeBoundary RangePartOpt /* Option */;
+    // 515 - RangePart: RangeBoundary RangePartOpt /* Option */;
     Production {
-        lhs: 207,
-        production: &[ParseType::N(208), ParseType::N(206)],
+        lhs: 219,
+        production: &[ParseType::N(220), ParseType::N(218)],
     },
-    // 477 - RangePartOpt: '..' RangeBoundary;
+    // 516 - RangePartOpt: '..' RangeBoundary;
     Production {
-        lhs: 208,
-        production: &[ParseType::N(206), ParseType::T(81)],
+        lhs: 220,
+        production: &[ParseType::N(218), ParseType::T(83)],
     },
-    // 478 - RangePartOpt: ;
+    // 517 - RangePartOpt: ;
     Production {
-        lhs: 208,
+        lhs: 220,
         production: &[],
     },
-    // 479 - RangeBoundary: 'min';
+    // 518 - RangeBoundary: 'min';
     Production {
-        lhs: 206,
-        production: &[ParseType::T(82)],
+        lhs: 218,
+        production: &[ParseType::T(84)],
     },
-    // 480 - RangeBoundary: 'max';
+    // 519 - RangeBoundary: 'max';
     Production {
-        lhs: 206,
-        production: &[ParseType::T(83)],
+        lhs: 218,
+        production: &[ParseType::T(85)],
     },
-    // 481 - RangeBoundary: "-?[0-9]+";
+    // 520 - RangeBoundary: "-?[0-9]+\.[0-9]+";
     Production {
-        lhs: 206,
-        production: &[ParseType::T(84)],
+        lhs: 218,
+        production: &[ParseType::T(86)],
     },
-    // 482 - IdentifierRefArgStr: IdentifierRef;
+    // 521 - RangeBoundary: "-?[0-9]+";
     Production {
-        lhs: 83,
-        production: &[ParseType::N(82)],
+        lhs: 218,
+        production: &[ParseType::T(87)],
     },
-    // 483 - IdentifierRefArgStr: '"' IdentifierRef '"';
+    // 522 - IdentifierRefArgStr: IdentifierRef;
     Production {
-        lhs: 83,
-        production: &[ParseType::T(56), ParseType::N(82), ParseType::T(56)],
+        lhs: 92,
+        production: &[ParseType::N(91)],
     },
-    // 484 - IdentifierRef: IdentifierRefOpt /* Option */ Identifier;
+    // 523 - IdentifierRefArgStr: '"' IdentifierRef '"';
     Production {
-        lhs: 82,
-        production: &[ParseType::N(80), ParseType::N(84)],
+        lhs: 92,
+        production: &[ParseType::T(57), ParseType::N(91), ParseType::T(57)],
     },
-    // 485 - IdentifierRefOpt: Identifier ':';
+    // 524 - IdentifierRef: IdentifierRefOpt /* Option */ Identifier;
     Production {
-        lhs: 84,
-        production: &[ParseType::T(85), ParseType::N(80)],
+        lhs: 91,
+        production: &[ParseType::N(89), ParseType::N(93)],
     },
-    // 486 - IdentifierRefOpt: ;
+    // 525 - IdentifierRefOpt: Identifier ':';
     Production {
-        lhs: 84,
+        lhs: 93,
+        production: &[ParseType::T(88), ParseType::N(89)],
+    },
+    // 526 - IdentifierRefOpt: ;
+    Production {
+        lhs: 93,
         production: &[],
     },
-    // 487 - KeyStmt: KwKey^ /* Clipped */ KeyArgStr Semicolon^ /* Clipped */;
+    // 527 - KeyStmt: KwKey^ /* Clipped */ KeyArgStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 109,
-        production: &[ParseType::N(234), ParseType::N(108), ParseType::N(132)],
+        lhs: 119,
+        production: &[ParseType::N(246), ParseType::N(118), ParseType::N(143)],
     },
-    // 488 - KeyArgStr: KeyArg;
+    // 528 - KeyArgStr: KeyArg;
     Production {
-        lhs: 108,
-        production: &[ParseType::N(106)],
+        lhs: 118,
+        production: &[ParseType::N(116)],
     },
-    // 489 - KeyArgStr: '"'^ /* Clipped */ KeyArg '"'^ /* Clipped */;
+    // 529 - KeyArgStr: '"'^ /* Clipped */ KeyArg '"'^ /* Clipped */;
     Production {
-        lhs: 108,
-        production: &[ParseType::T(56), ParseType::N(106), ParseType::T(56)],
+        lhs: 118,
+        production: &[ParseType::T(57), ParseType::N(116), ParseType::T(57)],
     },
-    // 490 - Semicolon: ';';
+    // 530 - Semicolon: ';';
     Production {
-        lhs: 234,
-        production: &[ParseType::T(86)],
+        lhs: 246,
+        production: &[ParseType::T(89)],
     },
-    // 491 - KeyArg: IdentifierRef KeyArgOpt /* Option */;
+    // 531 - KeyArg: IdentifierRef KeyArgOpt /* Option */;
     Production {
-        lhs: 106,
-        production: &[ParseType::N(107), ParseType::N(82)],
+        lhs: 116,
+        production: &[ParseType::N(117), ParseType::N(91)],
     },
-    // 492 - KeyArgOpt: KeyArg;
+    // 532 - KeyArgOpt: KeyArg;
     Production {
-        lhs: 107,
-        production: &[ParseType::N(106)],
+        lhs: 117,
+        production: &[ParseType::N(116)],
     },
-    // 493 - KeyArgOpt: ;
+    // 533 - KeyArgOpt: ;
     Production {
-        lhs: 107,
+        lhs: 117,
         production: &[],
     },
-    // 494 - AbsoluteSchemaNodeid: '/' IdentifierRef;
+    // 534 - AbsoluteSchemaNodeid: '/' IdentifierRef;
     Production {
         lhs: 0,
-        production: &[ParseType::N(82), ParseType::T(87)],
+        production: &[ParseType::N(91), ParseType::T(90)],
     },
-    // 495 - ImportStmt: KwImport^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ ImportStmtList /* Vec */ '}'^ /* Clipped */;
+    // 535 - ImportStmt: KwImport^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ ImportStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 95,
+        lhs: 104,
         production: &[
-            ParseType::T(55),
-            ParseType::N(96),
-            ParseType::N(157),
-            ParseType::N(81),
-            ParseType::N(130),
+            ParseType::T(56),
+            ParseType::N(105),
+            ParseType::N(168),
+            ParseType::N(90),
+            ParseType::N(141),
         ],
     },
-    // 496 - ImportStmtList: ImportStmtListGroup ImportStmtList;
+    // 536 - ImportStmtList: ImportStmtListGroup ImportStmtList;
     Production {
-        lhs: 96,
-        production: &[ParseType::N(96), ParseType::N(97)],
+        lhs: 105,
+        production: &[ParseType::N(105), ParseType::N(106)],
     },
-    // 497 - ImportStmtListGroup: PrefixStmt;
+    // 537 - ImportStmtListGroup: PrefixStmt;
     Production {
-        lhs: 97,
-        production: &[ParseType::N(201)],
+        lhs: 106,
+        production: &[ParseType::N(213)],
     },
-    // 498 - ImportStmtListGroup: RevisionDateStmt;
+    // 538 - ImportStmtListGroup: RevisionDateStmt;
     Production {
-        lhs: 97,
-        production: &[ParseType::N(219)],
+        lhs: 106,
+        production: &[ParseType::N(231)],
     },
-    // 499 - ImportStmtListGroup: DescriptionStmt;
+    // 539 - ImportStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 97,
+        lhs: 106,
         production: &[ParseType::N(55)],
     },
-    // 500 - ImportStmtListGroup: ReferenceStmt;
+    // 540 - ImportStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 97,
-        production: &[ParseType::N(210)],
+        lhs: 106,
+        production: &[ParseType::N(222)],
     },
-    // 501 - ImportStmtList: ;
+    // 541 - ImportStmtList: ;
     Production {
-        lhs: 96,
+        lhs: 105,
         production: &[],
     },
-    // 502 - IncludeStmt: KwInclude^ /* Clipped */ IdentifierArgStr IncludeStmtSuffix;
+    // 542 - IncludeStmt: KwInclude^ /* Clipped */ IdentifierArgStr IncludeStmtSuffix;
     Production {
-        lhs: 98,
-        production: &[ParseType::N(101), ParseType::N(81), ParseType::N(131)],
+        lhs: 107,
+        production: &[ParseType::N(110), ParseType::N(90), ParseType::N(142)],
     },
-    // 503 - IncludeStmtSuffix: Semicolon^ /* Clipped */;
+    // 543 - IncludeStmtSuffix: Semicolon^ /* Clipped */;
     Production {
-        lhs: 101,
-        production: &[ParseType::N(234)],
+        lhs: 110,
+        production: &[ParseType::N(246)],
     },
-    // 504 - IncludeStmtSuffix: LBrace^ /* Clipped */ IncludeStmtList /* Vec */ '}'^ /* Clipped */;
+    // 544 - IncludeStmtSuffix: LBrace^ /* Clipped */ IncludeStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 101,
-        production: &[ParseType::T(55), ParseType::N(99), ParseType::N(157)],
+        lhs: 110,
+        production: &[ParseType::T(56), ParseType::N(108), ParseType::N(168)],
     },
-    // 505 - IncludeStmtList: IncludeStmtListGroup IncludeStmtList;
+    // 545 - IncludeStmtList: IncludeStmtListGroup IncludeStmtList;
     Production {
-        lhs: 99,
-        production: &[ParseType::N(99), ParseType::N(100)],
+        lhs: 108,
+        production: &[ParseType::N(108), ParseType::N(109)],
     },
-    // 506 - IncludeStmtListGroup: RevisionDateStmt;
+    // 546 - IncludeStmtListGroup: RevisionDateStmt;
     Production {
-        lhs: 100,
-        production: &[ParseType::N(219)],
+        lhs: 109,
+        production: &[ParseType::N(231)],
     },
-    // 507 - IncludeStmtListGroup: DescriptionStmt;
+    // 547 - IncludeStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 100,
+        lhs: 109,
         production: &[ParseType::N(55)],
     },
-    // 508 - IncludeStmtListGroup: ReferenceStmt;
+    // 548 - IncludeStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 100,
-        production: &[ParseType::N(210)],
+        lhs: 109,
+        production: &[ParseType::N(222)],
     },
-    // 509 - IncludeStmtList: ;
+    // 549 - IncludeStmtList: ;
     Production {
-        lhs: 99,
+        lhs: 108,
         production: &[],
     },
-    // 510 - RevisionDateStmt: KwRevisionDate^ /* Clipped */ DateArgStr Semicolon^ /* Clipped */;
+    // 550 - RevisionDateStmt: KwRevisionDate^ /* Clipped */ DateArgStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 219,
-        production: &[ParseType::N(234), ParseType::N(51), ParseType::N(148)],
+        lhs: 231,
+        production: &[ParseType::N(246), ParseType::N(51), ParseType::N(159)],
     },
-    // 511 - NamespaceStmt: KwNamespace^ /* Clipped */ UriStr Semicolon^ /* Clipped */;
+    // 551 - NamespaceStmt: KwNamespace^ /* Clipped */ UriStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 187,
-        production: &[ParseType::N(234), ParseType::N(261), ParseType::N(139)],
+        lhs: 198,
+        production: &[ParseType::N(246), ParseType::N(273), ParseType::N(150)],
     },
-    // 512 - UriStr: UriArg;
+    // 552 - UriStr: UriArg;
     Production {
-        lhs: 261,
-        production: &[ParseType::N(260)],
+        lhs: 273,
+        production: &[ParseType::N(272)],
     },
-    // 513 - UriStr: '"'^ /* Clipped */ UriArg '"'^ /* Clipped */;
+    // 553 - UriStr: '"'^ /* Clipped */ UriArg '"'^ /* Clipped */;
     Production {
-        lhs: 261,
-        production: &[ParseType::T(56), ParseType::N(260), ParseType::T(56)],
+        lhs: 273,
+        production: &[ParseType::T(57), ParseType::N(272), ParseType::T(57)],
     },
-    // 514 - UriArg: "urn:[a-zA-Z0-9\-\.:]+";
+    // 554 - UriArg: "urn:[a-zA-Z0-9\-\.:]+";
     Production {
-        lhs: 260,
-        production: &[ParseType::T(88)],
+        lhs: 272,
+        production: &[ParseType::T(91)],
     },
-    // 515 - PrefixStmt: KwPrefix^ /* Clipped */ IdentifierArgStr Semicolon^ /* Clipped */;
+    // 555 - PrefixStmt: KwPrefix^ /* Clipped */ IdentifierArgStr Semicolon^ /* Clipped */;
     Production {
-        lhs: 201,
-        production: &[ParseType::N(234), ParseType::N(81), ParseType::N(143)],
+        lhs: 213,
+        production: &[ParseType::N(246), ParseType::N(90), ParseType::N(154)],
     },
-    // 516 - BelongsToStmt: KwBelongsTo^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ PrefixStmt '}'^ /* Clipped */;
+    // 556 - BelongsToStmt: KwBelongsTo^ /* Clipped */ IdentifierArgStr LBrace^ /* Clipped */ PrefixStmt '}'^ /* Clipped */;
     Production {
         lhs: 22,
         production: &[
-            ParseType::T(55),
-            ParseType::N(201),
-            ParseType::N(157),
-            ParseType::N(81),
-            ParseType::N(115),
+            ParseType::T(56),
+            ParseType::N(213),
+            ParseType::N(168),
+            ParseType::N(90),
+            ParseType::N(125),
         ],
     },
-    // 517 - OrganizationStmt: 'organization'^ /* Clipped */ Ystring Semicolon;
+    // 557 - OrganizationStmt: 'organization'^ /* Clipped */ Ystring Semicolon;
     Production {
-        lhs: 195,
-        production: &[ParseType::N(234), ParseType::N(275), ParseType::T(89)],
+        lhs: 206,
+        production: &[ParseType::N(246), ParseType::N(287), ParseType::T(92)],
     },
-    // 518 - ContactStmt: 'contact'^ /* Clipped */ Ystring Semicolon;
+    // 558 - ContactStmt: 'contact'^ /* Clipped */ Ystring Semicolon;
     Production {
         lhs: 37,
-        production: &[ParseType::N(234), ParseType::N(275), ParseType::T(90)],
+        production: &[ParseType::N(246), ParseType::N(287), ParseType::T(93)],
     },
-    // 519 - DescriptionStmt: 'description'^ /* Clipped */ Ystring Semicolon^ /* Clipped */;
+    // 559 - DescriptionStmt: 'description'^ /* Clipped */ Ystring Semicolon^ /* Clipped */;
     Production {
         lhs: 55,
-        production: &[ParseType::N(234), ParseType::N(275), ParseType::T(91)],
+        production: &[ParseType::N(246), ParseType::N(287), ParseType::T(94)],
     },
-    // 520 - ReferenceStmt: 'reference'^ /* Clipped */ Ystring Semicolon^ /* Clipped */;
+    // 560 - ReferenceStmt: 'reference'^ /* Clipped */ Ystring Semicolon^ /* Clipped */;
     Production {
-        lhs: 210,
-        production: &[ParseType::N(234), ParseType::N(275), ParseType::T(92)],
+        lhs: 222,
+        production: &[ParseType::N(246), ParseType::N(287), ParseType::T(95)],
     },
-    // 521 - RevisionStmt: KwRevision^ /* Clipped */ DateArgStr LBrace^ /* Clipped */ RevisionStmtList /* Vec */ '}'^ /* Clipped */;
+    // 561 - RevisionStmt: KwRevision^ /* Clipped */ DateArgStr LBrace^ /* Clipped */ RevisionStmtList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 220,
+        lhs: 232,
         production: &[
-            ParseType::T(55),
-            ParseType::N(221),
-            ParseType::N(157),
+            ParseType::T(56),
+            ParseType::N(233),
+            ParseType::N(168),
             ParseType::N(51),
-            ParseType::N(147),
+            ParseType::N(158),
         ],
     },
-    // 522 - RevisionStmtList: RevisionStmtListGroup RevisionStmtList;
+    // 562 - RevisionStmtList: RevisionStmtListGroup RevisionStmtList;
     Production {
-        lhs: 221,
-        production: &[ParseType::N(221), ParseType::N(222)],
+        lhs: 233,
+        production: &[ParseType::N(233), ParseType::N(234)],
     },
-    // 523 - RevisionStmtListGroup: DescriptionStmt;
+    // 563 - RevisionStmtListGroup: DescriptionStmt;
     Production {
-        lhs: 222,
+        lhs: 234,
         production: &[ParseType::N(55)],
     },
-    // 524 - RevisionStmtListGroup: ReferenceStmt;
+    // 564 - RevisionStmtListGroup: ReferenceStmt;
     Production {
-        lhs: 222,
-        production: &[ParseType::N(210)],
+        lhs: 234,
+        production: &[ParseType::N(222)],
     },
-    // 525 - RevisionStmtList: ;
+    // 565 - RevisionStmtList: ;
     Production {
-        lhs: 221,
+        lhs: 233,
         production: &[],
     },
-    // 526 - DateArgStr: DateArg;
+    // 566 - DateArgStr: DateArg;
     Production {
         lhs: 51,
         production: &[ParseType::N(50)],
     },
-    // 527 - DateArgStr: '"'^ /* Clipped */ DateArg '"'^ /* Clipped */;
+    // 567 - DateArgStr: '"'^ /* Clipped */ DateArg '"'^ /* Clipped */;
     Production {
         lhs: 51,
-        production: &[ParseType::T(56), ParseType::N(50), ParseType::T(56)],
+        production: &[ParseType::T(57), ParseType::N(50), ParseType::T(57)],
     },
-    // 528 - DateArg: /\d{4}-\d{2}-\d{2}/;
+    // 568 - DateArg: /\d{4}-\d{2}-\d{2}/;
     Production {
         lhs: 50,
-        production: &[ParseType::T(93)],
+        production: &[ParseType::T(96)],
     },
-    // 529 - Ystring: BasicString YstringOpt /* Option */;
+    // 569 - Ystring: BasicString YstringOpt /* Option */;
     Production {
-        lhs: 275,
-        production: &[ParseType::N(276), ParseType::N(21)],
+        lhs: 287,
+        production: &[ParseType::N(288), ParseType::N(21)],
     },
-    // 530 - Ystring: Identifier;
+    // 570 - Ystring: Identifier;
     Production {
-        lhs: 275,
-        production: &[ParseType::N(80)],
+        lhs: 287,
+        production: &[ParseType::N(89)],
     },
-    // 531 - YstringOpt: '+' Ystring;
+    // 571 - YstringOpt: '+' Ystring;
     Production {
-        lhs: 276,
-        production: &[ParseType::N(275), ParseType::T(94)],
+        lhs: 288,
+        production: &[ParseType::N(287), ParseType::T(97)],
     },
-    // 532 - YstringOpt: ;
+    // 572 - YstringOpt: ;
     Production {
-        lhs: 276,
+        lhs: 288,
         production: &[],
     },
-    // 533 - BasicString: DQString;
+    // 573 - BasicString: DQString;
     Production {
         lhs: 21,
         production: &[ParseType::N(46)],
     },
-    // 534 - BasicString: SQString;
+    // 574 - BasicString: SQString;
     Production {
         lhs: 21,
-        production: &[ParseType::N(231)],
+        production: &[ParseType::N(243)],
     },
-    // 535 - DoubleQuotation: '"';
+    // 575 - DoubleQuotation: '"';
     Production {
-        lhs: 57,
-        production: &[ParseType::T(56)],
+        lhs: 66,
+        production: &[ParseType::T(57)],
     },
-    // 536 - DQString: DoubleQuotation^ /* Clipped */ DQStringList /* Vec */ DoubleQuotation^ /* Clipped */;
+    // 576 - DQString: DoubleQuotation^ /* Clipped */ DQStringList /* Vec */ DoubleQuotation^ /* Clipped */;
     Production {
         lhs: 46,
-        production: &[ParseType::N(57), ParseType::N(47), ParseType::N(57)],
+        production: &[ParseType::N(66), ParseType::N(47), ParseType::N(66)],
     },
-    // 537 - DQStringList: DQChar DQStringList;
+    // 577 - DQStringList: DQChar DQStringList;
     Production {
         lhs: 47,
         production: &[ParseType::N(47), ParseType::N(42)],
     },
-    // 538 - DQStringList: ;
+    // 578 - DQStringList: ;
     Production {
         lhs: 47,
         production: &[],
     },
-    // 539 - DQChar: DQUnescaped;
+    // 579 - DQChar: DQUnescaped;
     Production {
         lhs: 42,
         production: &[ParseType::N(48)],
     },
-    // 540 - DQChar: DQEscaped;
+    // 580 - DQChar: DQEscaped;
     Production {
         lhs: 42,
         production: &[ParseType::N(44)],
     },
-    // 541 - DQUnescaped: DQNoEscape;
+    // 581 - DQUnescaped: DQNoEscape;
     Production {
         lhs: 48,
         production: &[ParseType::N(45)],
     },
-    // 542 - DQUnescaped: NonAscii;
+    // 582 - DQUnescaped: NonAscii;
     Production {
         lhs: 48,
-        production: &[ParseType::N(188)],
+        production: &[ParseType::N(199)],
     },
-    // 543 - DQEscaped: Escape DQEscapeSeqChar;
+    // 583 - DQEscaped: Escape DQEscapeSeqChar;
     Production {
         lhs: 44,
-        production: &[ParseType::N(43), ParseType::N(66)],
+        production: &[ParseType::N(43), ParseType::N(75)],
     },
-    // 544 - DQEscapeSeqChar: Escape;
+    // 584 - DQEscapeSeqChar: Escape;
     Production {
         lhs: 43,
-        production: &[ParseType::N(66)],
+        production: &[ParseType::N(75)],
     },
-    // 545 - DQEscapeSeqChar: DoubleQuotation;
+    // 585 - DQEscapeSeqChar: DoubleQuotation;
     Production {
         lhs: 43,
-        production: &[ParseType::N(57)],
+        production: &[ParseType::N(66)],
     },
-    // 546 - DQEscapeSeqChar: EscN;
+    // 586 - DQEscapeSeqChar: EscN;
     Production {
         lhs: 43,
-        production: &[ParseType::N(64)],
+        production: &[ParseType::N(73)],
     },
-    // 547 - DQEscapeSeqChar: EscT;
+    // 587 - DQEscapeSeqChar: EscT;
     Production {
         lhs: 43,
-        production: &[ParseType::N(65)],
+        production: &[ParseType::N(74)],
     },
-    // 548 - Escape: "\u{5C}";
+    // 588 - Escape: "\u{5C}";
     Production {
-        lhs: 66,
-        production: &[ParseType::T(95)],
+        lhs: 75,
+        production: &[ParseType::T(98)],
     },
-    // 549 - NonAscii: "[\n\r\u{80}-\u{D7FF}\u{E000}-\u{10FFFF}]";
+    // 589 - NonAscii: "[\n\r\u{80}-\u{D7FF}\u{E000}-\u{10FFFF}]";
     Production {
-        lhs: 188,
-        production: &[ParseType::T(96)],
+        lhs: 199,
+        production: &[ParseType::T(99)],
     },
-    // 550 - DQNoEscape: "[ \n\r\t\u{21}\u{23}-\u{5B}\u{5D}-\u{7E}]+";
+    // 590 - DQNoEscape: "[ \n\r\t\u{21}\u{23}-\u{5B}\u{5D}-\u{7E}]+";
     Production {
         lhs: 45,
-        production: &[ParseType::T(97)],
+        production: &[ParseType::T(100)],
     },
-    // 551 - SQString: SingleQuotation SQStringList /* Vec */ SingleQuotation;
+    // 591 - SQString: SingleQuotation SQStringList /* Vec */ SingleQuotation;
     Production {
-        lhs: 231,
-        production: &[ParseType::N(236), ParseType::N(232), ParseType::N(236)],
+        lhs: 243,
+        production: &[ParseType::N(248), ParseType::N(244), ParseType::N(248)],
     },
-    // 552 - SQStringList: SQChar SQStringList;
+    // 592 - SQStringList: SQChar SQStringList;
     Production {
-        lhs: 232,
-        production: &[ParseType::N(232), ParseType::N(227)],
+        lhs: 244,
+        production: &[ParseType::N(244), ParseType::N(239)],
     },
-    // 553 - SQStringList: ;
+    // 593 - SQStringList: ;
     Production {
-        lhs: 232,
+        lhs: 244,
         production: &[],
     },
-    // 554 - SQChar: SQUnescaped;
+    // 594 - SQChar: SQUnescaped;
     Production {
-        lhs: 227,
-        production: &[ParseType::N(233)],
+        lhs: 239,
+        production: &[ParseType::N(245)],
     },
-    // 555 - SQChar: SQEscaped;
+    // 595 - SQChar: SQEscaped;
     Production {
-        lhs: 227,
-        production: &[ParseType::N(229)],
+        lhs: 239,
+        production: &[ParseType::N(241)],
     },
-    // 556 - SQEscaped: Escape SQEscapeSeqChar;
+    // 596 - SQEscaped: Escape SQEscapeSeqChar;
     Production {
-        lhs: 229,
-        production: &[ParseType::N(228), ParseType::N(66)],
+        lhs: 241,
+        production: &[ParseType::N(240), ParseType::N(75)],
     },
-    // 557 - SQEscapeSeqChar: ".";
+    // 597 - SQEscapeSeqChar: ".";
     Production {
-        lhs: 228,
-        production: &[ParseType::T(98)],
+        lhs: 240,
+        production: &[ParseType::T(101)],
     },
-    // 558 - SQUnescaped: SQNoEscape;
+    // 598 - SQUnescaped: SQNoEscape;
     Production {
-        lhs: 233,
-        production: &[ParseType::N(230)],
+        lhs: 245,
+        production: &[ParseType::N(242)],
     },
-    // 559 - SQUnescaped: NonAscii;
+    // 599 - SQUnescaped: NonAscii;
     Production {
-        lhs: 233,
-        production: &[ParseType::N(188)],
+        lhs: 245,
+        production: &[ParseType::N(199)],
     },
-    // 560 - SQNoEscape: "[ \t\u{21}-\u{26}\u{28}-\u{5B}\u{5D}-\u{7E}]+";
+    // 600 - SQNoEscape: "[ \t\u{21}-\u{26}\u{28}-\u{5B}\u{5D}-\u{7E}]+";
     Production {
-        lhs: 230,
-        production: &[ParseType::T(99)],
+        lhs: 242,
+        production: &[ParseType::T(102)],
     },
-    // 561 - SingleQuotation: "\u{27}";
+    // 601 - SingleQuotation: "\u{27}";
     Production {
-        lhs: 236,
-        production: &[ParseType::T(100)],
+        lhs: 248,
+        production: &[ParseType::T(103)],
     },
-    // 562 - IdentifierArgStr: Identifier;
+    // 602 - IdentifierArgStr: Identifier;
     Production {
-        lhs: 81,
-        production: &[ParseType::N(80)],
+        lhs: 90,
+        production: &[ParseType::N(89)],
     },
-    // 563 - IdentifierArgStr: '"'^ /* Clipped */ Identifier '"'^ /* Clipped */;
+    // 603 - IdentifierArgStr: '"'^ /* Clipped */ Identifier '"'^ /* Clipped */;
     Production {
-        lhs: 81,
-        production: &[ParseType::T(56), ParseType::N(80), ParseType::T(56)],
+        lhs: 90,
+        production: &[ParseType::T(57), ParseType::N(89), ParseType::T(57)],
     },
-    // 564 - Identifier: /[a-zA-Z_][a-zA-Z0-9_\-\/\.:]*/;
+    // 604 - Identifier: /[a-zA-Z_][a-zA-Z0-9_\-\/\.:]*/;
     Production {
-        lhs: 80,
-        production: &[ParseType::T(101)],
+        lhs: 89,
+        production: &[ParseType::T(104)],
     },
-    // 565 - AsciiNoSemicolon: /[ \t\u{21}\u{23}-\u{3a}\u{3c}-\u{5b}\u{5d}-\u{7e}]+/;
+    // 605 - AsciiNoSemicolon: /[ \t\u{21}\u{23}-\u{3a}\u{3c}-\u{5b}\u{5d}-\u{7e}]+/;
     Production {
         lhs: 15,
-        production: &[ParseType::T(102)],
+        production: &[ParseType::T(105)],
     },
-    // 566 - AsciiNoBrace: /[\t\u{21}\u{23}-\u{3a}\u{3c}-\u{5b}\u{5d}-\u{7a}\u{7c}\u{7e}]+/;
+    // 606 - AsciiNoBrace: /[\t\u{21}\u{23}-\u{3a}\u{3c}-\u{5b}\u{5d}-\u{7a}\u{7c}\u{7e}]+/;
     Production {
         lhs: 14,
-        production: &[ParseType::T(103)],
+        production: &[ParseType::T(106)],
     },
-    // 567 - YangVersionArg: "1.1|1";
+    // 607 - YangVersionArg: "1.1|1";
     Production {
-        lhs: 272,
-        production: &[ParseType::T(104)],
+        lhs: 284,
+        production: &[ParseType::T(107)],
     },
 ];
 
@@ -6301,7 +6676,7 @@ where
 {
     use yang_grammar_scanner::YangGrammarScanner;
     let mut llk_parser = LLKParser::new(
-        271,
+        283,
         LOOKAHEAD_AUTOMATA,
         PRODUCTIONS,
         TERMINAL_NAMES,
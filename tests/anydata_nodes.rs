@@ -0,0 +1,104 @@
+// `anydata` and `anyxml` nodes (RFC 7950 §7.10, §7.11) appear in the
+// entry tree with their own kinds, carrying config, mandatory, when,
+// must and status. They hold unknown content, so an augment cannot add
+// children to one.
+//
+// tests/yang/anydata.yang puts both under a container, one in a
+// shorthand case, and an anyxml `config` in an rpc input, as
+// ietf-netconf's edit-config does.
+
+use libyang::{
+    Diagnostic, Entry, EntryOptions, EntryTree, SchemaContext, StatusNodeEnum, YangStore,
+    to_entry_with,
+};
+use std::rc::Rc;
+
+fn store() -> YangStore {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store.read_with_resolve("anydata").expect("parse / resolve");
+    store.identity_resolve();
+    store
+}
+
+fn load(tree: EntryTree) -> (Rc<Entry>, Vec<Diagnostic>) {
+    let store = store();
+    let module = store.find_module("anydata").expect("module found");
+    let entry = to_entry_with(&store, module, &EntryOptions::new().tree(tree));
+    (entry, store.take_diagnostics())
+}
+
+fn child(parent: &Rc<Entry>, name: &str) -> Option<Rc<Entry>> {
+    parent.dir.borrow().iter().find(|e| e.name == name).cloned()
+}
+
+fn names(entry: &Rc<Entry>) -> Vec<String> {
+    entry.dir.borrow().iter().map(|e| e.name.clone()).collect()
+}
+
+#[test]
+fn anydata_and_anyxml_are_entries() {
+    let (root, _) = load(EntryTree::Full);
+    let store = child(&root, "store").expect("store");
+    assert_eq!(
+        names(&store),
+        ["enabled", "blob", "snapshot", "inline", "url"]
+    );
+
+    let blob = child(&store, "blob").unwrap();
+    assert!(blob.is_anydata());
+    assert!(!blob.is_leaf_entry() && !blob.is_directory_entry());
+    assert!(blob.config);
+    assert!(blob.mandatory);
+    assert_eq!(blob.when.as_deref(), Some("../enabled = 'true'"));
+    assert_eq!(blob.must, ["count(*) > 0"]);
+    assert_eq!(blob.status, StatusNodeEnum::Deprecated);
+    assert!(names(&blob).is_empty());
+
+    let snapshot = child(&store, "snapshot").unwrap();
+    assert!(snapshot.is_anyxml());
+    assert!(!snapshot.config);
+    assert!(!snapshot.mandatory);
+    assert_eq!(snapshot.status, StatusNodeEnum::Current);
+
+    let inline = child(&store, "inline").unwrap();
+    assert!(inline.is_anydata());
+    assert_eq!(inline.case.borrow().as_deref(), Some("inline"));
+}
+
+#[test]
+fn augment_into_anydata_is_reported() {
+    let (_, diags) = load(EntryTree::Full);
+    assert_eq!(
+        diags,
+        vec![Diagnostic::AugmentIntoLeaf {
+            module: "anydata".to_string(),
+            target: "/ad:store/ad:blob".to_string(),
+            leaf: "blob".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn trees_split_anydata_by_config() {
+    let (root, _) = load(EntryTree::Config);
+    let store = child(&root, "store").unwrap();
+    assert_eq!(names(&store), ["enabled", "blob", "inline", "url"]);
+
+    let (root, _) = load(EntryTree::State);
+    let store = child(&root, "store").unwrap();
+    assert_eq!(names(&store), ["snapshot"]);
+}
+
+#[test]
+fn anyxml_rpc_parameter() {
+    let store = store();
+    let context = SchemaContext::new(&store, &["anydata"]).expect("context");
+    let edit = context.find_rpc("anydata:edit").expect("edit");
+    let input = child(&edit, "input").unwrap();
+    let config = child(&input, "config").unwrap();
+    assert!(config.is_anyxml());
+
+    let frozen = edit.freeze();
+    assert!(frozen.dir[0].dir[0].is_anyxml());
+}
@@ -0,0 +1,87 @@
+// An `ArchiveSource` reads modules from a .tar, .tar.gz or .zip bundle
+// without extracting it, from any directory in it, choosing revisions
+// by the same name@revision rules as a directory. Errors name the file
+// inside the archive.
+//
+// tests/yang/bundle.{tar,tar.gz,zip} hold the rev-* fixtures of
+// tests/revision_select.rs under vendor/types, vendor/models and
+// vendor/models/sub, and malformed.yang under vendor/broken.
+
+#![cfg(feature = "archive")]
+
+use libyang::{ArchiveSource, Entry, YangError, YangStore, to_entry};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+const BUNDLES: [&str; 3] = [
+    "tests/yang/bundle.tar",
+    "tests/yang/bundle.tar.gz",
+    "tests/yang/bundle.zip",
+];
+
+fn store(bundle: &str) -> YangStore {
+    let mut store = YangStore::new();
+    store.add_source(ArchiveSource::open(bundle).expect("archive opens"));
+    store
+}
+
+fn units(store: &YangStore, module: &str) -> Option<String> {
+    let root = to_entry(store, store.find_module(module).expect("module found"));
+    let timeout: Rc<Entry> = root
+        .dir
+        .borrow()
+        .iter()
+        .find(|e| e.name == "timeout")
+        .cloned()
+        .expect("timeout");
+    timeout.type_node.as_ref().and_then(|t| t.units.clone())
+}
+
+#[test]
+fn modules_load_from_each_format() {
+    for bundle in BUNDLES {
+        let mut store = store(bundle);
+        store
+            .read_with_resolve("rev-vendor")
+            .expect("parse / resolve");
+        store
+            .read_with_resolve("rev-main")
+            .expect("parse / resolve");
+
+        let latest = store.find_module("rev-types").expect("rev-types");
+        assert_eq!(latest.latest_revision(), Some("2020-06-01"), "{bundle}");
+        assert!(store.find_module("rev-types@2010-01-01").is_some());
+        let part = store.find_submodule("rev-main-part").expect("submodule");
+        assert_eq!(part.latest_revision(), Some("2018-01-01"));
+
+        assert_eq!(units(&store, "rev-vendor").as_deref(), Some("seconds"));
+        assert_eq!(units(&store, "rev-main").as_deref(), Some("milliseconds"));
+        assert!(store.take_diagnostics().is_empty());
+    }
+}
+
+#[test]
+fn errors_name_the_file_in_the_archive() {
+    for bundle in BUNDLES {
+        let mut store = store(bundle);
+        match store.read_with_resolve("malformed") {
+            Err(YangError::ParseError { path, .. }) => {
+                assert_eq!(
+                    path,
+                    PathBuf::from(bundle).join("vendor/broken/malformed.yang")
+                )
+            }
+            other => panic!("expected ParseError, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn unknown_archive_format_is_an_error() {
+    match ArchiveSource::open("tests/yang/rev-main.yang") {
+        Err(YangError::IoError { path, .. }) => {
+            assert_eq!(path, PathBuf::from("tests/yang/rev-main.yang"))
+        }
+        other => panic!("expected IoError, got {other:?}"),
+    }
+}
@@ -0,0 +1,116 @@
+// Choices and cases are entries of their own, reachable from the
+// parent they are defined under, alongside the flattened data view in
+// which only the cases' data nodes appear. A choice keeps `mandatory`,
+// `default` and `when`, a case its `when`, and a case holds its data
+// nodes and nested choices.
+//
+// tests/yang/choice-nodes.yang nests a choice in a case, and augments
+// cases into both the outer and the nested choice.
+
+use libyang::{Entry, YangStore, to_entry};
+use std::rc::Rc;
+
+fn load() -> Rc<Entry> {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("choice-nodes")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("choice-nodes").expect("module found");
+    let entry = to_entry(&store, module);
+    assert!(store.diagnostics().is_empty(), "{:?}", store.diagnostics());
+    entry
+}
+
+fn names(entries: &[Rc<Entry>]) -> Vec<String> {
+    entries.iter().map(|e| e.name.clone()).collect()
+}
+
+fn top(root: &Rc<Entry>) -> Rc<Entry> {
+    root.find_by_path("/choice-nodes:top").expect("top")
+}
+
+#[test]
+fn choice_keeps_its_statements() {
+    let top = top(&load());
+    let transport = top.find_choice("transport").expect("transport");
+    assert!(transport.is_choice());
+    assert!(transport.mandatory);
+    assert_eq!(transport.when.as_deref(), Some("../enabled = 'true'"));
+    assert!(Rc::ptr_eq(&transport.parent().unwrap(), &top));
+    assert_eq!(top.choice_def_names(), ["transport", "security"]);
+    assert_eq!(names(&transport.dir.borrow()), ["tcp", "udp", "quic"]);
+
+    let tcp = transport.find_case("tcp").expect("tcp");
+    assert!(tcp.is_case());
+    assert_eq!(tcp.when.as_deref(), Some("../enabled"));
+    assert!(Rc::ptr_eq(&tcp.parent().unwrap(), &transport));
+}
+
+#[test]
+fn cases_share_the_flattened_nodes() {
+    let top = top(&load());
+    assert_eq!(
+        names(&top.dir.borrow()),
+        [
+            "enabled",
+            "port",
+            "none",
+            "cert",
+            "udp-port",
+            "quic-port",
+            "psk"
+        ]
+    );
+
+    let tcp = top
+        .find_choice("transport")
+        .unwrap()
+        .find_case("tcp")
+        .unwrap();
+    assert_eq!(names(&tcp.dir.borrow()), ["port", "security"]);
+    let port = tcp.dir.borrow()[0].clone();
+    let flat = top.dir.borrow()[1].clone();
+    assert!(Rc::ptr_eq(&port, &flat));
+    assert!(Rc::ptr_eq(&port.parent().unwrap(), &top), "data parent");
+}
+
+#[test]
+fn nested_choice_sits_in_its_case() {
+    let root = load();
+    let top = top(&root);
+    assert_eq!(names(&top.choices.borrow()), ["transport"]);
+    let tcp = top
+        .find_choice("transport")
+        .unwrap()
+        .find_case("tcp")
+        .unwrap();
+    let security = tcp.dir.borrow()[1].clone();
+    assert!(security.is_choice());
+    assert_eq!(security.default.as_deref(), Some("none"));
+    assert_eq!(names(&security.dir.borrow()), ["none", "tls", "dtls"]);
+    assert!(Rc::ptr_eq(&security.parent().unwrap(), &tcp));
+
+    let cert = top.find_by_path("cert").expect("cert");
+    assert_eq!(cert.choice.borrow().as_deref(), Some("security"));
+    assert_eq!(
+        cert.schema_path(),
+        "/choice-nodes:top/choice-nodes:transport/choice-nodes:tcp\
+         /choice-nodes:security/choice-nodes:tls/choice-nodes:cert"
+    );
+    let again = root.find_by_path(&cert.schema_path()).expect("schema path");
+    assert_eq!(again.data_path(), "/choice-nodes:top/cert");
+}
+
+#[test]
+fn frozen_cases_share_the_frozen_nodes() {
+    let root = load();
+    let frozen = root.freeze();
+    let top = frozen.dir[0].clone();
+    let transport = top.find_choice("transport").expect("transport");
+    let udp = transport.find_case("udp").expect("udp");
+    let udp_port = top.dir.iter().find(|e| e.name == "udp-port").unwrap();
+    assert!(std::sync::Arc::ptr_eq(&udp.dir[0], udp_port));
+    assert!(std::sync::Arc::ptr_eq(&udp.parent().unwrap(), &transport));
+}
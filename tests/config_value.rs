@@ -5,6 +5,10 @@
 // fixed it, config() read the `config` keyword token instead of its
 // argument, so every explicit `config` parsed as false — which meant
 // `config true` nodes were wrongly dropped. This test guards that.
+//
+// A deviation that puts state data back to `config true` makes the
+// nodes below it configuration again, except those that set `config
+// false` themselves.
 
 use libyang::{Entry, YangStore, to_entry};
 use std::rc::Rc;
@@ -40,3 +44,23 @@ fn config_true_kept_false_dropped() {
         "`config false;` leaf is state data and must be excluded"
     );
 }
+
+#[test]
+fn deviation_to_config_true_restores_descendants() {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    for name in ["config-sample", "config-dev"] {
+        store.read_with_resolve(name).expect("parse / resolve");
+    }
+    store.identity_resolve();
+    let module = store.find_module("config-sample").expect("module found");
+    let root = to_entry(&store, module);
+
+    let counters = find_child(&root, "counters").expect("counters is configuration again");
+    assert!(counters.config);
+    assert!(find_child(&counters, "total").is_some_and(|e| e.config));
+    // `config false` of their own keeps these state.
+    assert!(find_child(&counters, "history").is_none());
+    assert!(find_child(&counters, "probe").is_none());
+    assert!(store.take_diagnostics().is_empty());
+}
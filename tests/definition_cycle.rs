@@ -0,0 +1,100 @@
+// A grouping that uses itself, or a typedef whose type leads back to
+// it, is reported as a cycle instead of recursing until the stack
+// overflows. The expansion stops where the cycle closes.
+//
+// tests/yang/definition-cycle.yang has a two-grouping cycle entered
+// from both ends, a grouping that uses itself, and typedef cycles
+// directly, in pairs and through a union.
+
+use libyang::{Diagnostic, Entry, YangStore, YangType, to_entry};
+use std::rc::Rc;
+
+fn load() -> (Rc<Entry>, Vec<Diagnostic>) {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("definition-cycle")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("definition-cycle").expect("module found");
+    let entry = to_entry(&store, module);
+    (entry, store.take_diagnostics())
+}
+
+fn child(ent: &Rc<Entry>, name: &str) -> Rc<Entry> {
+    ent.dir
+        .borrow()
+        .iter()
+        .find(|e| e.name == name)
+        .cloned()
+        .unwrap_or_else(|| panic!("{name} present"))
+}
+
+fn cycle(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+#[test]
+fn grouping_cycles_are_reported_once_each() {
+    let (_, diags) = load();
+    let mut cycles: Vec<Vec<String>> = diags
+        .iter()
+        .filter_map(|d| match d {
+            Diagnostic::GroupingCycle { cycle, .. } => Some(cycle.clone()),
+            _ => None,
+        })
+        .collect();
+    cycles.sort();
+    assert_eq!(
+        cycles,
+        vec![cycle(&["ga", "gb", "ga"]), cycle(&["itself", "itself"])]
+    );
+}
+
+#[test]
+fn typedef_cycles_are_reported() {
+    let (_, diags) = load();
+    let mut cycles: Vec<Vec<String>> = diags
+        .iter()
+        .filter_map(|d| match d {
+            Diagnostic::TypedefCycle { cycle, .. } => Some(cycle.clone()),
+            _ => None,
+        })
+        .collect();
+    cycles.sort();
+    assert_eq!(
+        cycles,
+        vec![
+            cycle(&["me", "me"]),
+            cycle(&["t1", "t2", "t1"]),
+            cycle(&["via-union", "via-union"]),
+        ]
+    );
+}
+
+#[test]
+fn expansion_stops_where_the_cycle_closes() {
+    let (root, _) = load();
+    let top = child(&root, "top");
+    assert_eq!(top.dir.borrow().len(), 2, "from-a and from-b once each");
+    let again = child(&child(&root, "self"), "again");
+    assert!(again.dir.borrow().is_empty());
+
+    let types = child(&root, "types");
+    assert!(child(&types, "pair").type_node.is_none());
+    // The union keeps its arm that does resolve.
+    let union = child(&types, "union");
+    let t = union.type_node.as_ref().expect("type_node");
+    assert_eq!(t.kind, YangType::Union);
+    assert_eq!(t.union.len(), 1);
+    assert_eq!(t.union[0].kind, YangType::String);
+}
+
+#[test]
+fn display_names_the_cycle() {
+    let d = Diagnostic::GroupingCycle {
+        module: "m".to_string(),
+        cycle: cycle(&["ga", "gb", "ga"]),
+    };
+    assert_eq!(d.to_string(), "m: grouping cycle ga -> gb -> ga");
+}
@@ -0,0 +1,48 @@
+// Entry trees hold their parents weakly, so dropping the root frees
+// every node instead of leaking a reference cycle.
+//
+// Uses tests/yang/state-tree.yang, whose full tree has containers,
+// lists, leaves, choice children and an augment.
+
+use libyang::{Entry, EntryOptions, EntryTree, YangStore, to_entry_with};
+use std::rc::{Rc, Weak};
+
+fn load() -> Rc<Entry> {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("state-tree")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("state-tree").expect("module found");
+    let options = EntryOptions::new().tree(EntryTree::Full);
+    to_entry_with(&store, module, &options)
+}
+
+fn collect(entry: &Rc<Entry>, out: &mut Vec<Weak<Entry>>) {
+    out.push(Rc::downgrade(entry));
+    for child in entry.dir.borrow().iter() {
+        collect(child, out);
+    }
+}
+
+#[test]
+fn parent_reaches_the_owning_entry() {
+    let root = load();
+    let system = root.dir.borrow()[0].clone();
+    let hostname = system.dir.borrow()[0].clone();
+    assert!(Rc::ptr_eq(&hostname.parent().unwrap(), &system));
+    assert!(Rc::ptr_eq(&system.parent().unwrap(), &root));
+    assert!(root.parent().is_none());
+}
+
+#[test]
+fn dropped_tree_frees_all_nodes() {
+    let root = load();
+    let mut nodes = Vec::new();
+    collect(&root, &mut nodes);
+    assert!(nodes.len() > 10);
+
+    drop(root);
+    assert!(nodes.iter().all(|n| n.upgrade().is_none()));
+}
@@ -0,0 +1,130 @@
+// Every entry can give its schema path (module-qualified, with choice
+// and case) and its data path (RFC 7951 style), and the root finds an
+// entry again from either. A frozen tree does the same.
+//
+// tests/yang/entry-path.yang has a choice with an explicit and a
+// shorthand case, a case holding only a nested choice, a list and an
+// action; the ns-*.yang modules add
+// nodes from other namespaces.
+
+use libyang::{Entry, YangStore, to_entry};
+use std::rc::Rc;
+use std::sync::Arc;
+
+fn load(path: &str, name: &str) -> Rc<Entry> {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store.read_with_resolve(path).expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module(name).expect("module found");
+    to_entry(&store, module)
+}
+
+#[test]
+fn paths_of_choice_members() {
+    let root = load("entry-path", "entry-path");
+    let speed = root.find_by_path("/entry-path:top/speed").expect("speed");
+    assert_eq!(speed.name, "speed");
+    assert_eq!(
+        speed.schema_path(),
+        "/entry-path:top/entry-path:mode/entry-path:fast/entry-path:speed"
+    );
+    assert_eq!(speed.data_path(), "/entry-path:top/speed");
+
+    let slow = root.find_by_path("/entry-path:top/slow").expect("slow");
+    assert_eq!(
+        slow.schema_path(),
+        "/entry-path:top/entry-path:mode/entry-path:slow/entry-path:slow"
+    );
+}
+
+#[test]
+fn paths_through_a_nested_choice() {
+    let root = load("entry-path", "entry-path");
+    let x = root.find_by_path("/entry-path:top/x").expect("x");
+    let path = "/entry-path:top/entry-path:outer/entry-path:nested\
+                /entry-path:inner/entry-path:x/entry-path:x";
+    assert_eq!(x.schema_path(), path);
+    assert!(Rc::ptr_eq(&root.find_by_path(path).expect("x"), &x));
+    // A choice or case is not a data node of its own.
+    assert!(
+        root.find_by_path("/entry-path:top/entry-path:outer/entry-path:nested")
+            .is_none()
+    );
+    assert!(
+        root.find_by_path("/entry-path:top/entry-path:outer/entry-path:x")
+            .is_none()
+    );
+}
+
+#[test]
+fn find_by_path_inverts_both_paths() {
+    let root = load("entry-path", "entry-path");
+    for path in [
+        "/entry-path:top/speed",
+        "/entry-path:top/slow",
+        "/entry-path:top/x",
+        "/entry-path:top/y1",
+        "/entry-path:top/item/id",
+        "/entry-path:ops/reset/delay",
+    ] {
+        let entry = root.find_by_path(path).expect(path);
+        assert_eq!(entry.data_path(), path);
+        let again = root
+            .find_by_path(&entry.schema_path())
+            .expect("schema path");
+        assert!(Rc::ptr_eq(&entry, &again), "{path}");
+    }
+    assert!(root.find_by_path("/entry-path:top/missing").is_none());
+    assert!(root.find_by_path("/other:top").is_none());
+}
+
+#[test]
+fn frozen_nodes_have_the_same_paths() {
+    let root = load("entry-path", "entry-path");
+    let frozen = root.freeze();
+    for path in [
+        "/entry-path:top/speed",
+        "/entry-path:top/slow",
+        "/entry-path:top/x",
+        "/entry-path:top/item/id",
+        "/entry-path:ops/reset/delay",
+    ] {
+        let entry = root.find_by_path(path).expect(path);
+        let node = frozen.find_by_path(path).expect(path);
+        assert_eq!(node.data_path(), path);
+        assert_eq!(node.schema_path(), entry.schema_path());
+        let again = frozen
+            .find_by_path(&node.schema_path())
+            .expect("schema path");
+        assert!(Arc::ptr_eq(&node, &again), "{path}");
+    }
+    assert!(frozen.find_by_path("/entry-path:top/missing").is_none());
+}
+
+#[test]
+fn operation_input_is_in_the_schema_path_only() {
+    let root = load("entry-path", "entry-path");
+    let delay = root
+        .find_by_path("/entry-path:ops/reset/delay")
+        .expect("delay");
+    assert_eq!(
+        delay.schema_path(),
+        "/entry-path:ops/entry-path:reset/entry-path:input/entry-path:delay"
+    );
+    assert_eq!(delay.data_path(), "/entry-path:ops/reset/delay");
+}
+
+#[test]
+fn data_path_qualifies_where_the_namespace_changes() {
+    let root = load("ns-two", "ns-base");
+    let more = root
+        .find_by_path("/ns-base:top/ns-one:extra/ns-two:more")
+        .expect("more");
+    assert_eq!(more.data_path(), "/ns-base:top/ns-one:extra/ns-two:more");
+    let flag = root
+        .find_by_path("/ns-base:top/ns-one:extra/flag")
+        .expect("flag");
+    assert_eq!(flag.data_path(), "/ns-base:top/ns-one:extra/flag");
+    assert_eq!(flag.schema_path(), "/ns-base:top/ns-one:extra/ns-one:flag");
+}
@@ -0,0 +1,64 @@
+// A finished entry tree can be frozen into a `SchemaNode` tree that is
+// `Send + Sync` and shared across threads by cloning an `Arc`, with the
+// same shape, attributes and parent links as the tree it came from.
+//
+// Uses tests/yang/state-tree.yang.
+
+use libyang::{EntryOptions, EntryTree, SchemaNode, YangStore, to_entry_with};
+use std::sync::Arc;
+use std::thread;
+
+fn load() -> Arc<SchemaNode> {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("state-tree")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("state-tree").expect("module found");
+    let options = EntryOptions::new().tree(EntryTree::Full);
+    to_entry_with(&store, module, &options).freeze()
+}
+
+fn child(parent: &Arc<SchemaNode>, name: &str) -> Arc<SchemaNode> {
+    parent
+        .dir
+        .iter()
+        .find(|e| e.name == name)
+        .cloned()
+        .unwrap_or_else(|| panic!("{name} not found"))
+}
+
+#[test]
+fn schema_node_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SchemaNode>();
+    assert_send_sync::<Arc<SchemaNode>>();
+}
+
+#[test]
+fn frozen_tree_keeps_shape_and_attributes() {
+    let root = load();
+    let system = child(&root, "system");
+    let user = child(&system, "user");
+    assert!(user.is_list());
+    assert_eq!(user.key, ["name"]);
+    assert!(!child(&user, "last-login").config);
+    assert_eq!(child(&system, "ntp").choice.as_deref(), Some("source"));
+    assert!(Arc::ptr_eq(&user.parent().unwrap(), &system));
+    assert!(root.parent().is_none());
+}
+
+#[test]
+fn frozen_tree_is_shared_across_threads() {
+    let root = load();
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let root = root.clone();
+            thread::spawn(move || child(&child(&root, "system"), "hostname").is_leaf())
+        })
+        .collect();
+    for handle in handles {
+        assert!(handle.join().unwrap());
+    }
+}
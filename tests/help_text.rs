@@ -0,0 +1,106 @@
+// With `EntryOptions::help`, each entry keeps its description,
+// reference and units; a leaf without its own description or units
+// takes them from its type's typedef chain. Without the option no
+// help text is kept.
+//
+// tests/yang/help-text.yang derives `load` from `percent`, which sets
+// units; leaves use both, override them, or have no help at all.
+
+use libyang::{Entry, EntryOptions, EntryTree, SchemaContext, YangStore, to_entry, to_entry_with};
+use std::rc::Rc;
+
+fn store() -> YangStore {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("help-text")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    store
+}
+
+fn load(help: bool) -> Rc<Entry> {
+    let store = store();
+    let module = store.find_module("help-text").expect("module found");
+    let options = EntryOptions::new().help(help);
+    to_entry_with(&store, module, &options)
+}
+
+fn child(parent: &Rc<Entry>, name: &str) -> Rc<Entry> {
+    parent
+        .dir
+        .borrow()
+        .iter()
+        .find(|e| e.name == name)
+        .cloned()
+        .expect(name)
+}
+
+#[test]
+fn help_text_is_kept_on_request() {
+    let root = load(true);
+    let system = child(&root, "system");
+    assert_eq!(system.description(), Some("System settings."));
+    assert_eq!(system.reference(), Some("RFC 7950"));
+    assert_eq!(system.units(), None);
+
+    let memory = child(&system, "memory");
+    assert_eq!(memory.description(), Some("Memory in use."));
+    assert_eq!(memory.units(), Some("%"));
+
+    let temperature = child(&system, "temperature");
+    assert_eq!(temperature.units(), Some("celsius"));
+    assert_eq!(temperature.description(), None);
+
+    let plain = child(&system, "plain");
+    assert!(plain.help.is_some());
+    assert_eq!(plain.description(), None);
+
+    let mode = system.find_choice("mode").unwrap();
+    assert_eq!(mode.description(), Some("How the system runs."));
+    assert_eq!(
+        mode.find_case("fast").unwrap().description(),
+        Some("Run fast.")
+    );
+}
+
+#[test]
+fn leaves_inherit_from_the_typedef_chain() {
+    let root = load(true);
+    let system = child(&root, "system");
+    let cpu = child(&system, "cpu");
+    // The nearest typedef's description, and units from further down.
+    assert_eq!(cpu.description(), Some("System load."));
+    assert_eq!(cpu.units(), Some("percent"));
+
+    let frozen = cpu.freeze();
+    assert_eq!(frozen.description(), Some("System load."));
+    assert_eq!(frozen.units(), Some("percent"));
+}
+
+#[test]
+fn no_help_text_by_default() {
+    let store = store();
+    let module = store.find_module("help-text").unwrap();
+    let root = to_entry(&store, module);
+    let system = child(&root, "system");
+    assert!(system.help.is_none());
+    assert!(child(&system, "cpu").help.is_none());
+    assert!(
+        child(&system, "cpu")
+            .type_node
+            .as_ref()
+            .unwrap()
+            .description
+            .is_none()
+    );
+}
+
+#[test]
+fn context_keeps_help_text_of_rpcs() {
+    let store = store();
+    let options = EntryOptions::new().tree(EntryTree::Full).help(true);
+    let context = SchemaContext::new_with(&store, &["help-text"], &options).expect("context");
+    let reboot = context.find_rpc("help-text:reboot").expect("reboot");
+    assert_eq!(reboot.description(), Some("Reboot the system."));
+}
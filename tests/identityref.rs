@@ -0,0 +1,94 @@
+// An identityref keeps its own kind on the entry, with the base and the
+// set of identities derived from it qualified by the module that
+// defines each one.
+//
+// tests/yang/identityref-base.yang defines `address-family` with
+// `ipv4` derived from it and `ipv4-unicast` derived from `ipv4`.
+// tests/yang/identityref-ext.yang imports it and defines its own
+// `ipv4` and `ipv6` on the same base. It is loaded as the entry point
+// so both modules are in the store; the tree is built for the base.
+
+use libyang::{Entry, QualifiedName, YangStore, YangType, to_entry};
+use std::rc::Rc;
+
+fn load() -> Rc<Entry> {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("identityref-ext")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("identityref-base").expect("module found");
+    to_entry(&store, module)
+}
+
+fn find_child(ent: &Rc<Entry>, name: &str) -> Option<Rc<Entry>> {
+    ent.dir.borrow().iter().find(|e| e.name == name).cloned()
+}
+
+fn leaf(name: &str) -> Rc<Entry> {
+    let root = load();
+    let top = find_child(&root, "top").expect("top container");
+    find_child(&top, name).expect("leaf present")
+}
+
+fn q(module: &str, name: &str) -> QualifiedName {
+    QualifiedName::new(module, name)
+}
+
+#[test]
+fn identityref_keeps_its_kind_and_qualified_identities() {
+    let family = leaf("family");
+    let t = family.type_node.as_ref().expect("type_node");
+    assert_eq!(t.kind, YangType::Identityref);
+    assert_eq!(
+        t.identity_base,
+        Some(q("identityref-base", "address-family"))
+    );
+    // Transitive (`ipv4-unicast`) and cross-module (identityref-ext)
+    // derivations are both included; the base itself is not.
+    assert_eq!(
+        t.identities,
+        vec![
+            q("identityref-base", "ipv4"),
+            q("identityref-base", "ipv4-unicast"),
+            q("identityref-ext", "ipv4"),
+            q("identityref-ext", "ipv6"),
+        ]
+    );
+    assert!(t.enum_stmt.is_empty(), "not rewritten to an enumeration");
+}
+
+#[test]
+fn identityref_values_match_prefixed_and_unprefixed() {
+    let family = leaf("family");
+    let t = family.type_node.as_ref().expect("type_node");
+
+    assert_eq!(
+        t.find_identity("identityref-ext:ipv4"),
+        Some(&q("identityref-ext", "ipv4"))
+    );
+    assert_eq!(t.find_identity("ipv6"), Some(&q("identityref-ext", "ipv6")));
+    // Two modules define `ipv4`, so the bare name is ambiguous.
+    assert_eq!(t.find_identity("ipv4"), None);
+    // The base is not a valid value, and neither is an unknown module.
+    assert_eq!(t.find_identity("identityref-base:address-family"), None);
+    assert_eq!(t.find_identity("other:ipv6"), None);
+}
+
+#[test]
+fn identityref_base_with_own_prefix() {
+    let unicast = leaf("unicast");
+    let t = unicast.type_node.as_ref().expect("type_node");
+    assert_eq!(t.identity_base, Some(q("identityref-base", "ipv4")));
+    assert_eq!(t.identities, vec![q("identityref-base", "ipv4-unicast")]);
+}
+
+#[test]
+fn identityref_through_typedef() {
+    let via = leaf("via-typedef");
+    let t = via.type_node.as_ref().expect("type_node");
+    assert_eq!(t.kind, YangType::Identityref);
+    assert_eq!(t.identities.len(), 4);
+    assert_eq!(t.typedef.as_deref(), Some("family-ref"));
+}
@@ -0,0 +1,174 @@
+// Modules are found through `ModuleSource`s: directories added with
+// `add_path`, texts added with `add_module_text`, texts compiled into
+// the program with `EmbeddedSource`, or any source a caller implements.
+// Each source applies the same name@revision rules, and a module that
+// fails to parse is reported with the origin its source gave it.
+
+use libyang::{EmbeddedSource, ModuleSource, ModuleText, YangError, YangStore, to_entry};
+use std::path::PathBuf;
+
+const TYPES_2010: &str = r#"
+module mem-types {
+  namespace "urn:example:mem-types";
+  prefix mt;
+  revision 2010-01-01 {
+    description "Initial.";
+  }
+  typedef name {
+    type string;
+  }
+}
+"#;
+
+const TYPES_2020: &str = r#"
+module mem-types {
+  namespace "urn:example:mem-types";
+  prefix mt;
+  revision 2020-01-01 {
+    description "Adds count.";
+  }
+  revision 2010-01-01 {
+    description "Initial.";
+  }
+  typedef name {
+    type string;
+  }
+  typedef count {
+    type uint32;
+  }
+}
+"#;
+
+const MAIN: &str = r#"
+module mem-main {
+  namespace "urn:example:mem-main";
+  prefix mm;
+  import mem-types {
+    prefix mt;
+  }
+  leaf host {
+    type mt:name;
+  }
+}
+"#;
+
+#[test]
+fn modules_load_from_memory() {
+    let mut store = YangStore::new();
+    store.add_module_text("mem-main", MAIN);
+    store.add_module_text("mem-types@2010-01-01", TYPES_2010);
+    store.add_module_text("mem-types@2020-01-01.yang", TYPES_2020);
+    store
+        .read_with_resolve("mem-main")
+        .expect("parse / resolve");
+
+    // The unpinned import takes the latest revision.
+    let types = store.find_module("mem-types").expect("mem-types");
+    assert_eq!(types.latest_revision(), Some("2020-01-01"));
+
+    let root = to_entry(&store, store.find_module("mem-main").unwrap());
+    let host = root.dir.borrow()[0].clone();
+    assert_eq!(host.name, "host");
+    assert!(store.take_diagnostics().is_empty());
+}
+
+#[test]
+fn memory_texts_come_before_paths() {
+    // tests/yang/rev-types@2020-06-01.yang is on the path too.
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store.add_module_text("rev-types", TYPES_2010.replace("mem-types", "rev-types"));
+    store
+        .read_with_resolve("rev-types")
+        .expect("parse / resolve");
+    let types = store.find_module("rev-types").unwrap();
+    assert_eq!(types.latest_revision(), Some("2010-01-01"));
+}
+
+#[test]
+fn pinned_revision_is_searched_in_every_source() {
+    // tests/yang/latest/rev-types.yang is the 2020-06-01 revision; the
+    // pinned one is only filed as tests/yang/rev-types@2010-01-01.yang.
+    let mut store = YangStore::new();
+    store.add_path("tests/yang/latest:tests/yang");
+    store
+        .read_with_resolve("rev-types@2010-01-01")
+        .expect("parse / resolve");
+    let types = store.find_module("rev-types").unwrap();
+    assert_eq!(types.latest_revision(), Some("2010-01-01"));
+
+    // The same when the plain text is held in memory.
+    let mut store = YangStore::new();
+    store.add_module_text("rev-types", TYPES_2020.replace("mem-types", "rev-types"));
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("rev-types@2010-01-01")
+        .expect("parse / resolve");
+    let types = store.find_module("rev-types").unwrap();
+    assert_eq!(types.latest_revision(), Some("2010-01-01"));
+}
+
+#[test]
+fn embedded_modules_load() {
+    static MODULES: &[(&str, &str)] = &[
+        (
+            "ietf-yang-types@2013-07-15.yang",
+            include_str!("../yang/ietf-yang-types@2013-07-15.yang"),
+        ),
+        (
+            "ietf-inet-types@2013-07-15.yang",
+            include_str!("../yang/ietf-inet-types@2013-07-15.yang"),
+        ),
+    ];
+    let mut store = YangStore::new();
+    store.add_source(EmbeddedSource::new(MODULES));
+    store
+        .read_with_resolve("ietf-inet-types@2013-07-15")
+        .expect("parse / resolve");
+    assert!(store.find_module("ietf-inet-types").is_some());
+    assert!(store.find_module("ietf-yang-types").is_none());
+}
+
+// A source serving one module under a made-up origin.
+#[derive(Debug)]
+struct Single;
+
+impl ModuleSource for Single {
+    fn find(&self, name: &str, _: Option<&str>) -> Result<Option<ModuleText>, YangError> {
+        Ok((name == "mem-types").then(|| ModuleText {
+            text: TYPES_2010.to_string(),
+            origin: PathBuf::from("single/mem-types.yang"),
+            revision: None,
+        }))
+    }
+}
+
+#[test]
+fn custom_source_is_searched() {
+    let mut store = YangStore::new();
+    store.add_module_text("mem-main", MAIN);
+    store.add_source(Single);
+    store
+        .read_with_resolve("mem-main")
+        .expect("parse / resolve");
+    let types = store.find_module("mem-types").unwrap();
+    assert_eq!(types.latest_revision(), Some("2010-01-01"));
+}
+
+#[test]
+fn errors_name_the_origin() {
+    let mut store = YangStore::new();
+    store.add_module_text("broken", "module broken {");
+    match store.read_with_resolve("broken") {
+        Err(YangError::ParseError { path, .. }) => {
+            assert_eq!(path, PathBuf::from("<memory>/broken.yang"))
+        }
+        other => panic!("expected ParseError, got {other:?}"),
+    }
+
+    // A pinned revision nobody has is named with its revision.
+    match store.read_with_resolve("mem-types@2030-01-01") {
+        Err(YangError::FileNotFound { name }) => assert_eq!(name, "mem-types@2030-01-01"),
+        other => panic!("expected FileNotFound, got {other:?}"),
+    }
+}
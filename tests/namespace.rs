@@ -0,0 +1,78 @@
+// Every entry records the module whose namespace it is in, and augment
+// targets are matched segment by segment on module and name. Two
+// modules may then add same-named nodes to one target, and a grouping's
+// nodes take the namespace of the module that uses it.
+//
+// tests/yang/ns-base.yang defines `top`; ns-one.yang and ns-two.yang
+// both augment it with a `name` leaf, ns-two reaches into ns-one's
+// augment, misqualifies another target, and uses ns-one's grouping.
+
+use libyang::{AugmentKind, Diagnostic, Entry, YangStore, to_entry};
+use std::rc::Rc;
+
+fn load(name: &str) -> (Rc<Entry>, Vec<Diagnostic>) {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store.read_with_resolve("ns-two").expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module(name).expect("module found");
+    let entry = to_entry(&store, module);
+    (entry, store.take_diagnostics())
+}
+
+fn child(parent: &Rc<Entry>, module: &str, name: &str) -> Rc<Entry> {
+    parent
+        .dir
+        .borrow()
+        .iter()
+        .find(|e| e.module == module && e.name == name)
+        .cloned()
+        .unwrap_or_else(|| panic!("{module}:{name} not found"))
+}
+
+fn qualified(entry: &Rc<Entry>) -> Vec<String> {
+    entry
+        .dir
+        .borrow()
+        .iter()
+        .map(|e| format!("{}:{}", e.module, e.name))
+        .collect()
+}
+
+#[test]
+fn same_named_augments_from_two_modules_coexist() {
+    let (root, diags) = load("ns-base");
+    let top = child(&root, "ns-base", "top");
+    assert_eq!(
+        qualified(&top),
+        ["ns-base:id", "ns-one:name", "ns-one:extra", "ns-two:name"]
+    );
+    assert!(
+        !diags
+            .iter()
+            .any(|d| matches!(d, Diagnostic::AugmentDuplicateNode { .. })),
+        "{diags:?}"
+    );
+}
+
+#[test]
+fn target_segments_are_matched_by_module() {
+    let (root, diags) = load("ns-base");
+    let extra = child(&child(&root, "ns-base", "top"), "ns-one", "extra");
+    assert_eq!(qualified(&extra), ["ns-one:flag", "ns-two:more"]);
+    assert!(diags.contains(&Diagnostic::AugmentTargetNotFound {
+        kind: AugmentKind::Augment,
+        module: "ns-two".to_string(),
+        target: "/base:top/two:extra".to_string(),
+        missing: "two:extra".to_string(),
+    }));
+}
+
+#[test]
+fn grouping_nodes_take_the_uses_namespace() {
+    let (root, diags) = load("ns-two");
+    assert!(diags.is_empty(), "{diags:?}");
+    let local = child(&root, "ns-two", "local");
+    let inner = child(&local, "ns-two", "inner");
+    assert_eq!(qualified(&inner), ["ns-two:x", "ns-two:added"]);
+}
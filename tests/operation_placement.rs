@@ -0,0 +1,158 @@
+// Actions and notifications may sit in lists, groupings and augments
+// as well as containers (RFC 7950 §7.15, §7.16), but never inside an
+// rpc, action or notification, below a list without a key, or (for
+// an action) at the top level. Misplaced ones are reported and left
+// out of the tree.
+//
+// tests/yang/operations.yang defines a keyed list with its own action
+// and notification, a grouping carrying both that is used in the list,
+// in a keyless list and at the top level, an augment adding a
+// notification, and a grouping with an action used inside an rpc and a
+// notification.
+
+use libyang::{
+    Diagnostic, Enclosing, Entry, EntryOptions, EntryTree, IfFeatureExprNode, SchemaContext,
+    YangStore, to_entry_with,
+};
+use std::rc::Rc;
+
+fn store() -> YangStore {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("operations")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    store
+}
+
+fn load() -> (Rc<Entry>, Vec<Diagnostic>) {
+    let store = store();
+    let module = store.find_module("operations").expect("module found");
+    let options = EntryOptions::new().tree(EntryTree::Full);
+    let entry = to_entry_with(&store, module, &options);
+    (entry, store.take_diagnostics())
+}
+
+fn child(parent: &Rc<Entry>, name: &str) -> Option<Rc<Entry>> {
+    parent.dir.borrow().iter().find(|e| e.name == name).cloned()
+}
+
+fn names(entry: &Rc<Entry>) -> Vec<String> {
+    entry.dir.borrow().iter().map(|e| e.name.clone()).collect()
+}
+
+fn misplaced(statement: &str, name: &str, within: Enclosing) -> Diagnostic {
+    Diagnostic::MisplacedOperation {
+        module: "operations".to_string(),
+        statement: statement.to_string(),
+        name: name.to_string(),
+        within,
+    }
+}
+
+#[test]
+fn list_holds_actions_and_notifications() {
+    let (root, _) = load();
+    let servers = child(&root, "servers").expect("servers");
+    let server = child(&servers, "server").expect("server");
+    assert_eq!(
+        names(&server),
+        [
+            "name",
+            "restart",
+            "restarted",
+            "reset",
+            "down",
+            "overheated"
+        ]
+    );
+
+    let reset = child(&server, "reset").unwrap();
+    assert!(reset.is_action());
+    assert_eq!(names(&reset), ["output"]);
+    assert!(Rc::ptr_eq(&reset.parent().unwrap(), &server));
+
+    let restart = child(&server, "restart").unwrap();
+    assert!(restart.is_action());
+    let input = child(&restart, "input").unwrap();
+    assert_eq!(names(&input), ["delay"]);
+
+    assert!(child(&server, "down").unwrap().is_notification());
+    assert!(child(&server, "restarted").unwrap().is_notification());
+    let overheated = child(&server, "overheated").unwrap();
+    assert!(overheated.is_notification());
+    assert_eq!(names(&overheated), ["temperature"]);
+}
+
+#[test]
+fn action_if_feature_is_captured() {
+    let store = store();
+    let module = store.find_module("operations").expect("module found");
+    let list = &module.d.container[0].d.list[0];
+    let reset = list.action.iter().find(|a| a.name == "reset").unwrap();
+    assert_eq!(reset.if_feature.len(), 1);
+    assert_eq!(
+        reset.if_feature[0].expr,
+        IfFeatureExprNode::Feature("fast-reset".to_string())
+    );
+}
+
+#[test]
+fn misplaced_operations_are_reported_and_dropped() {
+    let (root, diags) = load();
+    let servers = child(&root, "servers").unwrap();
+    let changed = child(&servers, "changed").unwrap();
+    assert!(names(&changed).is_empty());
+    let log = child(&root, "log").unwrap();
+    assert_eq!(names(&log), ["message"]);
+    // A module's own tree leaves its top-level operations out, the
+    // notification the top-level `uses` brings among them.
+    assert_eq!(names(&root), ["servers", "log"]);
+
+    assert_eq!(
+        diags,
+        vec![
+            misplaced(
+                "action",
+                "inner",
+                Enclosing::Notification("changed".to_string())
+            ),
+            misplaced(
+                "action",
+                "restart",
+                Enclosing::KeylessList("log".to_string())
+            ),
+            misplaced(
+                "notification",
+                "restarted",
+                Enclosing::KeylessList("log".to_string())
+            ),
+        ]
+    );
+    assert_eq!(
+        diags[1].to_string(),
+        "operations: action \"restart\" may not be defined below list \"log\", which has no key"
+    );
+}
+
+#[test]
+fn action_inside_rpc_is_reported() {
+    let store = store();
+    let context = SchemaContext::new(&store, &["operations"]).expect("context");
+    let ping = context.find_rpc("operations:ping").expect("ping");
+    let input = child(&ping, "input").unwrap();
+    assert!(names(&input).is_empty());
+    let diags = store.diagnostics();
+    assert!(diags.contains(&misplaced(
+        "action",
+        "inner",
+        Enclosing::Rpc("ping".to_string())
+    )));
+    assert!(diags.contains(&misplaced("action", "restart", Enclosing::TopLevel)));
+
+    // The notification the top-level `uses` brings is a notification
+    // of the module, not a data node.
+    assert!(context.find_notification("operations:restarted").is_some());
+    assert!(context.find("operations:restarted").is_none());
+}
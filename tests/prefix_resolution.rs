@@ -0,0 +1,135 @@
+// Every `prefix:name` reference — typedef, grouping, identity, leafref
+// path and augment target — resolves its prefix the same way: the
+// module's own prefix (or a submodule's `belongs-to` prefix) names the
+// module itself, an import prefix names the imported module, and any
+// other prefix is reported as `Diagnostic::UnknownPrefix`.
+//
+// tests/yang/prefix-main.yang and its submodule prefix-part.yang refer
+// to their own definitions through the `pm` prefix throughout.
+// tests/yang/prefix-unknown.yang uses a different undeclared prefix for
+// each kind of reference.
+
+use libyang::{Diagnostic, Entry, QualifiedName, YangStore, YangType, to_entry};
+use std::rc::Rc;
+
+fn load(name: &str) -> (Rc<Entry>, Vec<Diagnostic>) {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store.read_with_resolve(name).expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module(name).expect("module found");
+    let entry = to_entry(&store, module);
+    (entry, store.take_diagnostics())
+}
+
+fn find_child(ent: &Rc<Entry>, name: &str) -> Option<Rc<Entry>> {
+    ent.dir.borrow().iter().find(|e| e.name == name).cloned()
+}
+
+fn child(ent: &Rc<Entry>, name: &str) -> Rc<Entry> {
+    find_child(ent, name).unwrap_or_else(|| panic!("{name} present"))
+}
+
+fn unknown_prefixes(diags: &[Diagnostic]) -> Vec<(String, String)> {
+    diags
+        .iter()
+        .filter_map(|d| match d {
+            Diagnostic::UnknownPrefix {
+                prefix, reference, ..
+            } => Some((prefix.clone(), reference.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn own_prefix_resolves_everywhere() {
+    let (root, diags) = load("prefix-main");
+    assert!(diags.is_empty(), "expected no diagnostics, got {diags:?}");
+
+    let top = child(&root, "top");
+    assert!(find_child(&top, "name").is_some(), "pm:named expanded");
+    assert!(find_child(&top, "added").is_some(), "/pm:top augmented");
+
+    let count = child(&top, "count");
+    let t = count.type_node.as_ref().expect("type_node");
+    assert_eq!(t.kind, YangType::Uint32);
+    assert_eq!(t.typedef.as_deref(), Some("pm:counter"));
+
+    let kind = child(&top, "kind");
+    let t = kind.type_node.as_ref().expect("type_node");
+    assert_eq!(
+        t.identity_base,
+        Some(QualifiedName::new("prefix-main", "shape"))
+    );
+    // One identity from the module, one from its submodule, both
+    // deriving from the base through the own prefix.
+    assert_eq!(
+        t.identities,
+        vec![
+            QualifiedName::new("prefix-main", "circle"),
+            QualifiedName::new("prefix-main", "square"),
+        ]
+    );
+}
+
+#[test]
+fn import_prefix_resolves() {
+    let (root, _) = load("prefix-main");
+    let family = child(&child(&root, "top"), "family");
+    let t = family.type_node.as_ref().expect("type_node");
+    assert_eq!(
+        t.identity_base,
+        Some(QualifiedName::new("identityref-base", "address-family"))
+    );
+    assert!(!t.identities.is_empty());
+}
+
+#[test]
+fn belongs_to_prefix_resolves_in_submodule() {
+    let (root, _) = load("prefix-main");
+    let part = child(&root, "part-top");
+    assert!(find_child(&part, "name").is_some(), "pm:named expanded");
+    assert!(find_child(&part, "added-by-part").is_some());
+    let count = child(&part, "count");
+    assert_eq!(
+        count.type_node.as_ref().expect("type_node").kind,
+        YangType::Uint32
+    );
+}
+
+#[test]
+fn unknown_prefix_is_a_diagnostic() {
+    let (root, diags) = load("prefix-unknown");
+    let mut found = unknown_prefixes(&diags);
+    found.sort();
+    assert_eq!(
+        found,
+        vec![
+            ("xa".to_string(), "/xa:top".to_string()),
+            ("xg".to_string(), "xg:named".to_string()),
+            ("xi".to_string(), "xi:shape".to_string()),
+            ("xl".to_string(), "/xl:top/pu:count".to_string()),
+            ("xt".to_string(), "xt:counter".to_string()),
+            ("xx".to_string(), "xx:base".to_string()),
+        ]
+    );
+
+    // The leaf whose type could not be found is still in the tree.
+    let top = child(&root, "top");
+    assert!(find_child(&top, "count").is_some());
+    assert!(find_child(&top, "added").is_none());
+}
+
+#[test]
+fn unknown_prefix_display_names_module_and_reference() {
+    let (_, diags) = load("prefix-unknown");
+    let d = diags
+        .iter()
+        .find(|d| matches!(d, Diagnostic::UnknownPrefix { prefix, .. } if prefix == "xt"))
+        .expect("xt diagnostic");
+    assert_eq!(
+        d.to_string(),
+        "prefix-unknown: unknown prefix \"xt\" in \"xt:counter\""
+    );
+}
@@ -0,0 +1,124 @@
+// Each entry records where its statement is written, the `uses` it was
+// expanded through, the augment that added it and the modules whose
+// deviations changed it.
+//
+// Uses the ns-*.yang modules: ns-one augments ns-base's `top`, ns-two
+// augments ns-one's addition and instantiates ns-one's groupings (one
+// nested in the other) with a uses-augment. lib-dev deviates lib-base.
+
+use libyang::{AugmentKind, AugmentSite, Entry, QualifiedName, UsesSite, YangStore, to_entry};
+use std::rc::Rc;
+
+fn load(name: &str) -> Rc<Entry> {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store.read_with_resolve("ns-two").expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module(name).expect("module found");
+    to_entry(&store, module)
+}
+
+fn wrapper_site() -> UsesSite {
+    UsesSite {
+        module: "ns-two".to_string(),
+        grouping: QualifiedName::new("ns-one", "wrapper"),
+    }
+}
+
+#[test]
+fn plain_node_is_its_own_module() {
+    let root = load("ns-base");
+    let id = root.find_by_path("/ns-base:top/id").expect("id");
+    let p = id.provenance();
+    assert_eq!(p.module, "ns-base");
+    assert!(p.uses.is_empty());
+    assert_eq!(p.augment, None);
+}
+
+#[test]
+fn augmented_nodes_record_the_augment() {
+    let root = load("ns-base");
+    let flag = root
+        .find_by_path("/ns-base:top/ns-one:extra/flag")
+        .expect("flag");
+    assert_eq!(flag.provenance().module, "ns-one");
+    assert_eq!(
+        flag.provenance().augment,
+        Some(AugmentSite {
+            module: "ns-one".to_string(),
+            kind: AugmentKind::Augment,
+            target: "/base:top".to_string(),
+        })
+    );
+
+    let more = root
+        .find_by_path("/ns-base:top/ns-one:extra/ns-two:more")
+        .expect("more");
+    assert_eq!(more.provenance().module, "ns-two");
+    assert_eq!(
+        more.provenance()
+            .augment
+            .as_ref()
+            .map(|a| a.target.as_str()),
+        Some("/base:top/one:extra")
+    );
+}
+
+#[test]
+fn grouping_nodes_record_the_uses_chain() {
+    let root = load("ns-two");
+    let x = root.find_by_path("/ns-two:local/inner/x").expect("x");
+    assert_eq!(x.module, "ns-two");
+    assert_eq!(x.provenance().module, "ns-one");
+    assert_eq!(x.provenance().uses, [wrapper_site()]);
+
+    let enabled = root.find_by_path("/ns-two:local/enabled").expect("enabled");
+    assert_eq!(
+        enabled.provenance().uses,
+        [
+            wrapper_site(),
+            UsesSite {
+                module: "ns-one".to_string(),
+                grouping: QualifiedName::new("ns-one", "flags"),
+            }
+        ]
+    );
+}
+
+#[test]
+fn uses_augment_nodes_record_the_uses_augment() {
+    let root = load("ns-two");
+    let added = root
+        .find_by_path("/ns-two:local/inner/added")
+        .expect("added");
+    let p = added.provenance();
+    assert_eq!(p.module, "ns-two");
+    assert_eq!(
+        p.augment,
+        Some(AugmentSite {
+            module: "ns-two".to_string(),
+            kind: AugmentKind::UsesAugment,
+            target: "inner".to_string(),
+        })
+    );
+}
+
+#[test]
+fn deviated_nodes_record_the_deviation() {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store.read_with_resolve("lib-dev").expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("lib-base").expect("module found");
+    let root = to_entry(&store, module);
+
+    let mtu = root.find_by_path("/lib-base:system/mtu").expect("mtu");
+    let p = mtu.provenance();
+    assert_eq!(p.module, "lib-base");
+    assert_eq!(p.deviation, ["lib-dev"]);
+
+    let timeout = root
+        .find_by_path("/lib-base:system/timeout")
+        .expect("timeout");
+    assert!(timeout.provenance().deviation.is_empty());
+}
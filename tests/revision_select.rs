@@ -0,0 +1,153 @@
+// An import or include with a `revision-date` loads exactly that
+// revision (RFC 7950 §7.1.5, §7.1.6). Importers pinning different
+// revisions of one module each get theirs: the latest is kept under the
+// module's name and the others as `name@revision`. A module may be
+// implemented at one revision only, so naming two revisions of one
+// module as implemented is an error.
+//
+// tests/yang/rev-types@{2010-01-01,2020-06-01}.yang are two revisions
+// of a module whose `interval` typedef changed units. rev-vendor pins
+// the old one; rev-main imports it unpinned and pins the older of two
+// revisions of its submodule rev-main-part. rev-missing pins a revision
+// that is not on the search path. rev-aug@{2010-01-01,2020-01-01}.yang
+// both augment rev-target, and rev-aug-pin pins the older one.
+// rev-inc@{2010-01-01,2020-01-01}.yang include the submodules
+// rev-inc-old and rev-inc-new, and rev-inc-pin pins the older one.
+
+use libyang::{Entry, SchemaContext, YangError, YangStore, to_entry};
+use std::rc::Rc;
+
+fn store(modules: &[&str]) -> YangStore {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    for name in modules {
+        store.read_with_resolve(name).expect("parse / resolve");
+    }
+    store.identity_resolve();
+    store
+}
+
+fn child(parent: &Rc<Entry>, name: &str) -> Option<Rc<Entry>> {
+    parent.dir.borrow().iter().find(|e| e.name == name).cloned()
+}
+
+fn units(store: &YangStore, module: &str) -> Option<String> {
+    let root = to_entry(store, store.find_module(module).expect("module found"));
+    let timeout = child(&root, "timeout").expect("timeout");
+    timeout.type_node.as_ref().and_then(|t| t.units.clone())
+}
+
+#[test]
+fn pinned_import_loads_that_revision() {
+    let store = store(&["rev-vendor"]);
+    let types = store.find_module("rev-types").expect("rev-types");
+    assert_eq!(types.latest_revision(), Some("2010-01-01"));
+    assert_eq!(units(&store, "rev-vendor").as_deref(), Some("seconds"));
+    assert!(store.take_diagnostics().is_empty());
+}
+
+#[test]
+fn pinned_revisions_coexist() {
+    // Either load order keeps the latest revision under the bare name.
+    for order in [["rev-vendor", "rev-main"], ["rev-main", "rev-vendor"]] {
+        let store = store(&order);
+        let latest = store.find_module("rev-types").expect("rev-types");
+        assert_eq!(latest.latest_revision(), Some("2020-06-01"));
+        let old = store
+            .find_module("rev-types@2010-01-01")
+            .expect("old revision kept");
+        assert_eq!(old.latest_revision(), Some("2010-01-01"));
+        assert!(
+            store
+                .find_module_revision("rev-types", Some("2020-06-01"))
+                .is_some()
+        );
+
+        assert_eq!(units(&store, "rev-vendor").as_deref(), Some("seconds"));
+        assert_eq!(units(&store, "rev-main").as_deref(), Some("milliseconds"));
+        assert!(store.take_diagnostics().is_empty());
+    }
+}
+
+#[test]
+fn pinned_include_loads_that_revision() {
+    let store = store(&["rev-main"]);
+    let part = store.find_submodule("rev-main-part").expect("submodule");
+    assert_eq!(part.latest_revision(), Some("2018-01-01"));
+
+    let root = to_entry(&store, store.find_module("rev-main").unwrap());
+    assert!(child(&root, "retries").is_some());
+    assert!(child(&root, "attempts").is_none());
+}
+
+#[test]
+fn each_revision_has_its_own_submodules() {
+    let store = store(&["rev-inc", "rev-inc-pin"]);
+    let names = |module: &str| -> Vec<String> {
+        let root = to_entry(&store, store.find_module(module).expect("module found"));
+        root.dir.borrow().iter().map(|e| e.name.clone()).collect()
+    };
+    assert_eq!(names("rev-inc"), ["new-leaf"]);
+    assert_eq!(names("rev-inc@2010-01-01"), ["old-leaf"]);
+    assert!(store.take_diagnostics().is_empty());
+}
+
+#[test]
+fn older_revision_augments_are_not_applied() {
+    let store = store(&["rev-aug", "rev-aug-pin"]);
+    assert!(store.find_module("rev-aug@2010-01-01").is_some());
+    let root = to_entry(&store, store.find_module("rev-target").unwrap());
+    let top = child(&root, "top").expect("top");
+    let names: Vec<_> = top.dir.borrow().iter().map(|e| e.name.clone()).collect();
+    assert_eq!(names, ["name", "x"]);
+    assert!(store.take_diagnostics().is_empty());
+}
+
+#[test]
+fn missing_revision_is_an_error() {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    match store.read_with_resolve("rev-missing") {
+        Err(YangError::FileNotFound { name }) => assert_eq!(name, "rev-types@2015-01-01"),
+        other => panic!("expected FileNotFound, got {other:?}"),
+    }
+}
+
+#[test]
+fn implemented_module_at_two_revisions_conflicts() {
+    let store = store(&["rev-vendor", "rev-main"]);
+    let err = SchemaContext::new(&store, &["rev-types", "rev-types@2010-01-01"]).unwrap_err();
+    assert!(matches!(
+        &err,
+        YangError::RevisionConflict { name, revision, other }
+            if name == "rev-types" && revision == "2020-06-01" && other == "2010-01-01"
+    ));
+    assert_eq!(
+        err.to_string(),
+        "implemented YANG module `rev-types` is needed at revisions 2020-06-01 and 2010-01-01"
+    );
+
+    // Import-only, both revisions are fine.
+    let context = SchemaContext::new(&store, &["rev-main", "rev-vendor"]).expect("context");
+    assert_eq!(context.import_only(), ["rev-types", "rev-types@2010-01-01"]);
+
+    // So is implementing one revision while rev-vendor pins the other.
+    let context = SchemaContext::new(&store, &["rev-types", "rev-main"]).expect("context");
+    assert_eq!(context.implemented(), ["rev-types", "rev-main"]);
+    assert_eq!(
+        context.import_only(),
+        ["rev-types@2010-01-01", "rev-vendor"]
+    );
+}
+
+#[test]
+fn older_revision_can_be_implemented() {
+    let store = store(&["rev-aug", "rev-aug-pin"]);
+    let context =
+        SchemaContext::new(&store, &["rev-target", "rev-aug@2010-01-01"]).expect("context");
+    let top = context.find("rev-target:top").expect("top");
+    let names: Vec<_> = top.dir.borrow().iter().map(|e| e.name.clone()).collect();
+    assert_eq!(names, ["name", "x"]);
+    assert_eq!(context.import_only(), ["rev-aug", "rev-aug-pin"]);
+    assert!(store.take_diagnostics().is_empty());
+}
@@ -0,0 +1,109 @@
+// A SchemaContext gathers the implemented modules' top-level data
+// nodes, RPCs and notifications under one root, looked up by
+// `module:name`. Modules loaded only for their definitions are
+// import-only: they add no nodes and their augments are not applied.
+//
+// tests/yang/context-a.yang and context-b.yang both define a `top`
+// container; context-b augments context-a's, and context-a's RPC input
+// and notification. context-extra.yang also
+// augments it but is loaded without being implemented, and
+// context-types.yang is only imported for a typedef.
+
+use libyang::{Entry, SchemaContext, YangError, YangStore, to_entry};
+use std::rc::Rc;
+
+fn store() -> YangStore {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    for name in ["context-a", "context-b", "context-extra"] {
+        store.read_with_resolve(name).expect("parse / resolve");
+    }
+    store.identity_resolve();
+    store
+}
+
+fn names(entry: &Rc<Entry>) -> Vec<String> {
+    entry.dir.borrow().iter().map(|e| e.name.clone()).collect()
+}
+
+#[test]
+fn top_level_nodes_are_found_by_module() {
+    let store = store();
+    let context = SchemaContext::new(&store, &["context-a", "context-b"]).expect("context");
+    assert_eq!(names(context.root()), ["top", "top"]);
+
+    let a = context.find("context-a:top").expect("context-a:top");
+    let b = context.find("context-b:top").expect("context-b:top");
+    assert_eq!(names(&a), ["level", "from-b"]);
+    assert_eq!(names(&b), ["mode"]);
+    assert!(Rc::ptr_eq(&a.parent().unwrap(), context.root()));
+    assert!(context.find("context-extra:extra").is_none());
+    assert!(context.find("top").is_none());
+}
+
+#[test]
+fn rpcs_and_notifications_are_collected() {
+    let store = store();
+    let context = SchemaContext::new(&store, &["context-a", "context-b"]).expect("context");
+
+    let ping = context.find_rpc("context-a:ping").expect("ping");
+    assert!(ping.is_action());
+    assert_eq!(names(&ping), ["input", "output"]);
+    assert_eq!(context.rpcs().len(), 1);
+
+    let alarm = context.find_notification("context-a:alarm").expect("alarm");
+    assert!(alarm.is_notification());
+    assert_eq!(names(&alarm), ["severity", "source"]);
+    let severity = alarm.dir.borrow()[0].clone();
+    assert!(severity.type_node.is_some());
+    assert_eq!(names(context.root()), ["top", "top"]);
+}
+
+#[test]
+fn rpcs_and_notifications_are_augmented() {
+    let store = store();
+    let context = SchemaContext::new(&store, &["context-a", "context-b"]).expect("context");
+    let ping = context.find_rpc("context-a:ping").expect("ping");
+    let input = ping.dir.borrow()[0].clone();
+    assert_eq!(names(&input), ["count", "timeout"]);
+    let alarm = context.find_notification("context-a:alarm").expect("alarm");
+    assert_eq!(names(&alarm), ["severity", "source"]);
+    assert!(Rc::ptr_eq(&alarm.parent().unwrap(), context.root()));
+    assert!(store.diagnostics().is_empty(), "{:?}", store.diagnostics());
+
+    // Without context-b implemented, its augments are not applied.
+    let context = SchemaContext::new(&store, &["context-a"]).expect("context");
+    let alarm = context.find_notification("context-a:alarm").expect("alarm");
+    assert_eq!(names(&alarm), ["severity"]);
+}
+
+#[test]
+fn module_tree_leaves_operations_unbuilt() {
+    // A module's own tree has only its data nodes, and the augments of
+    // its RPC and notification are skipped rather than reported.
+    let store = store();
+    let module = store.find_module("context-a").expect("module found");
+    let entry = to_entry(&store, module);
+    assert_eq!(names(&entry), ["top"]);
+    assert!(store.diagnostics().is_empty(), "{:?}", store.diagnostics());
+}
+
+#[test]
+fn import_only_modules_contribute_nothing() {
+    let store = store();
+    let context = SchemaContext::new(&store, &["context-a", "context-b"]).expect("context");
+    assert_eq!(context.implemented(), ["context-a", "context-b"]);
+    assert_eq!(context.import_only(), ["context-extra", "context-types"]);
+    assert!(context.is_implemented("context-b"));
+    assert!(!context.is_implemented("context-types"));
+
+    let a = context.find("context-a:top").unwrap();
+    assert!(!names(&a).contains(&"from-extra".to_string()));
+}
+
+#[test]
+fn unloaded_implemented_module_is_an_error() {
+    let store = store();
+    let err = SchemaContext::new(&store, &["context-a", "context-missing"]).unwrap_err();
+    assert!(matches!(err, YangError::ModuleNotLoaded { name } if name == "context-missing"));
+}
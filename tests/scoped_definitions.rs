@@ -0,0 +1,109 @@
+// Typedefs and groupings follow the lexical scoping of RFC 7950 §5.5:
+// a definition made in a container, list, grouping, action, input or
+// output is visible to that node and its descendants, the nearest
+// enclosing scope is searched first, and a grouping's body resolves
+// names where the grouping is defined. Reusing a name already visible
+// in scope is reported.
+//
+// tests/yang/scoped-defs.yang defines helpers at each of those levels,
+// refers to them from inside and outside their scope, and shadows a
+// module-level typedef and a sibling grouping.
+
+use libyang::{Diagnostic, Entry, YangStore, YangType, to_entry};
+use std::rc::Rc;
+
+fn load() -> (Rc<Entry>, Vec<Diagnostic>) {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("scoped-defs")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("scoped-defs").expect("module found");
+    let entry = to_entry(&store, module);
+    (entry, store.take_diagnostics())
+}
+
+fn child(ent: &Rc<Entry>, name: &str) -> Rc<Entry> {
+    ent.dir
+        .borrow()
+        .iter()
+        .find(|e| e.name == name)
+        .cloned()
+        .unwrap_or_else(|| panic!("{name} present"))
+}
+
+fn kind_of(ent: &Rc<Entry>) -> YangType {
+    ent.type_node.as_ref().expect("type_node").kind
+}
+
+#[test]
+fn container_definitions_are_visible_to_descendants() {
+    let (root, _) = load();
+    let boxed = child(&root, "box");
+    assert_eq!(kind_of(&child(&boxed, "id")), YangType::Uint32);
+    assert_eq!(kind_of(&child(&boxed, "helper-id")), YangType::Uint32);
+
+    let inner = child(&boxed, "inner-box");
+    assert_eq!(kind_of(&child(&inner, "deep")), YangType::Uint32);
+    assert_eq!(kind_of(&child(&inner, "helper-id")), YangType::Uint32);
+}
+
+#[test]
+fn list_action_and_input_are_scopes() {
+    let (root, _) = load();
+    let boxed = child(&root, "box");
+    let items = child(&boxed, "items");
+    assert_eq!(kind_of(&child(&items, "name")), YangType::String);
+
+    let input = child(&child(&boxed, "reset"), "input");
+    assert_eq!(kind_of(&child(&input, "why")), YangType::String);
+}
+
+#[test]
+fn grouping_body_resolves_where_the_grouping_is_defined() {
+    let (root, _) = load();
+    let boxed = child(&root, "box");
+    assert_eq!(kind_of(&child(&boxed, "outer-leaf")), YangType::Uint8);
+    assert_eq!(kind_of(&child(&boxed, "from-inner")), YangType::Uint8);
+}
+
+#[test]
+fn definitions_are_not_visible_outside_their_scope() {
+    let (root, diags) = load();
+    let other = child(&root, "other");
+    assert!(child(&other, "id").type_node.is_none());
+    assert!(diags.contains(&Diagnostic::UnresolvedTypedef {
+        module: "scoped-defs".to_string(),
+        node: "id".to_string(),
+        name: "box-id".to_string(),
+    }));
+    assert!(diags.contains(&Diagnostic::UnresolvedGrouping {
+        module: "scoped-defs".to_string(),
+        node: "other".to_string(),
+        name: "helper".to_string(),
+    }));
+}
+
+#[test]
+fn shadowing_is_reported_and_nearest_wins() {
+    let (root, diags) = load();
+    assert!(diags.contains(&Diagnostic::ShadowedTypedef {
+        module: "scoped-defs".to_string(),
+        node: "shadow".to_string(),
+        name: "top-type".to_string(),
+    }));
+    assert!(diags.contains(&Diagnostic::ShadowedGrouping {
+        module: "scoped-defs".to_string(),
+        node: "shadow".to_string(),
+        name: "dup".to_string(),
+    }));
+    let shadow = child(&root, "shadow");
+    assert_eq!(kind_of(&child(&shadow, "s")), YangType::Int8);
+}
+
+#[test]
+fn only_the_intended_problems_are_reported() {
+    let (_, diags) = load();
+    assert_eq!(diags.len(), 4, "{diags:?}");
+}
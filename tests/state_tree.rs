@@ -0,0 +1,102 @@
+// `config false` nodes are kept in the entry tree and tagged with
+// `Entry::config`, which children inherit (RFC 7950 §7.21.1).
+// `to_entry_with` picks a configuration, state or full tree; `to_entry`
+// keeps building the configuration tree.
+//
+// tests/yang/state-tree.yang mixes config and state under one
+// container: a state container (with a misplaced `config true` inside
+// and an augment into it), a config list with a state leaf, a config
+// only container and a state choice.
+
+use libyang::{Diagnostic, Entry, EntryOptions, EntryTree, YangStore, to_entry, to_entry_with};
+use std::rc::Rc;
+
+fn load(tree: EntryTree) -> (Rc<Entry>, Vec<Diagnostic>) {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("state-tree")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("state-tree").expect("module found");
+    let entry = to_entry_with(&store, module, &EntryOptions::new().tree(tree));
+    (entry, store.take_diagnostics())
+}
+
+fn child(parent: &Rc<Entry>, name: &str) -> Option<Rc<Entry>> {
+    parent.dir.borrow().iter().find(|e| e.name == name).cloned()
+}
+
+fn names(entry: &Rc<Entry>) -> Vec<String> {
+    entry.dir.borrow().iter().map(|e| e.name.clone()).collect()
+}
+
+#[test]
+fn full_tree_tags_config_with_inheritance() {
+    let (root, _) = load(EntryTree::Full);
+    let system = child(&root, "system").expect("system");
+    assert!(system.config);
+    assert!(child(&system, "hostname").unwrap().config);
+
+    let status = child(&system, "status").expect("status");
+    assert!(!status.config);
+    assert!(!child(&status, "uptime").unwrap().config);
+    assert!(!child(&status, "load").unwrap().config, "augment inherits");
+
+    let user = child(&system, "user").expect("user");
+    assert!(child(&user, "password").unwrap().config);
+    assert!(!child(&user, "last-login").unwrap().config);
+
+    assert!(!child(&system, "ntp").unwrap().config, "choice inherits");
+    assert!(!child(&system, "manual").unwrap().config);
+}
+
+#[test]
+fn config_true_under_state_is_reported() {
+    let (root, diags) = load(EntryTree::Full);
+    let status = child(&child(&root, "system").unwrap(), "status").unwrap();
+    let detail = child(&status, "detail").expect("detail kept");
+    assert!(!detail.config, "stays state");
+    assert!(!child(&detail, "note").unwrap().config);
+    assert_eq!(
+        diags,
+        vec![Diagnostic::ConfigUnderState {
+            module: "state-tree".to_string(),
+            node: "detail".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn config_tree_drops_state() {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("state-tree")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("state-tree").expect("module found");
+    let root = to_entry(&store, module);
+
+    let system = child(&root, "system").expect("system");
+    assert_eq!(names(&system), ["hostname", "user", "clock"]);
+    assert_eq!(
+        names(&child(&system, "user").unwrap()),
+        ["name", "password"]
+    );
+}
+
+#[test]
+fn state_tree_keeps_paths_and_keys() {
+    let (root, _) = load(EntryTree::State);
+    let system = child(&root, "system").expect("system leads to state");
+    assert_eq!(names(&system), ["status", "user", "ntp", "manual"]);
+    assert_eq!(
+        names(&child(&system, "status").unwrap()),
+        ["uptime", "detail", "load"]
+    );
+    assert_eq!(
+        names(&child(&system, "user").unwrap()),
+        ["name", "last-login"]
+    );
+}
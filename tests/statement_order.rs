@@ -0,0 +1,57 @@
+// Data nodes appear in the entry tree in the order they were written,
+// whatever their kind. A `uses` contributes the grouping's nodes at its
+// own position, and augmented nodes follow the target's own children.
+//
+// tests/yang/statement-order.yang interleaves leaves, a list, a choice,
+// a leaf-list and a `uses` in a container, augments it, and mixes
+// kinds in an action input.
+
+use libyang::{Entry, YangStore, to_entry};
+use std::rc::Rc;
+
+fn load() -> Rc<Entry> {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("statement-order")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("statement-order").expect("module found");
+    to_entry(&store, module)
+}
+
+fn child(parent: &Rc<Entry>, name: &str) -> Rc<Entry> {
+    parent
+        .dir
+        .borrow()
+        .iter()
+        .find(|e| e.name == name)
+        .cloned()
+        .unwrap_or_else(|| panic!("{name} not found"))
+}
+
+fn names(entry: &Rc<Entry>) -> Vec<String> {
+    entry.dir.borrow().iter().map(|e| e.name.clone()).collect()
+}
+
+#[test]
+fn container_children_follow_source_order() {
+    let root = load();
+    let top = child(&root, "top");
+    let names = names(&top);
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    assert_eq!(
+        names,
+        [
+            "zulu", "second", "first", "bravo", "alpha", "fast", "mike", "added"
+        ]
+    );
+}
+
+#[test]
+fn action_input_follows_source_order() {
+    let root = load();
+    let ops = child(&root, "ops");
+    let input = child(&child(&ops, "reset"), "input");
+    assert_eq!(names(&input), ["when", "scope", "delay"]);
+}
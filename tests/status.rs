@@ -0,0 +1,114 @@
+// Each entry carries its effective status: its own `status`, or that
+// of the node, `uses`, augment or case it is defined in when that is
+// less current (RFC 7950 §7.21.2). The `omit_obsolete` option leaves
+// obsolete nodes out, and a current definition referencing a
+// deprecated or obsolete one of its own module is reported.
+//
+// tests/yang/status.yang has deprecated and obsolete typedefs, a
+// deprecated identity and grouping, a deprecated container with an
+// obsolete leaf, a choice with an obsolete case, and a deprecated
+// augment.
+
+use libyang::{
+    Diagnostic, Entry, EntryOptions, EntryTree, StatusNodeEnum, YangStore, to_entry_with,
+};
+use std::rc::Rc;
+
+fn load(omit_obsolete: bool) -> (Rc<Entry>, Vec<Diagnostic>) {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store.read_with_resolve("status").expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("status").expect("module found");
+    let options = EntryOptions::new()
+        .tree(EntryTree::Full)
+        .omit_obsolete(omit_obsolete);
+    let entry = to_entry_with(&store, module, &options);
+    (entry, store.take_diagnostics())
+}
+
+fn child(parent: &Rc<Entry>, name: &str) -> Option<Rc<Entry>> {
+    parent.dir.borrow().iter().find(|e| e.name == name).cloned()
+}
+
+fn names(entry: &Rc<Entry>) -> Vec<String> {
+    entry.dir.borrow().iter().map(|e| e.name.clone()).collect()
+}
+
+fn status(parent: &Rc<Entry>, name: &str) -> StatusNodeEnum {
+    child(parent, name).expect(name).status
+}
+
+#[test]
+fn status_is_inherited() {
+    use StatusNodeEnum::{Current, Deprecated, Obsolete};
+
+    let (root, _) = load(false);
+    let system = child(&root, "system").unwrap();
+    assert_eq!(system.status, Current);
+    assert_eq!(status(&system, "name"), Current);
+    assert_eq!(status(&system, "legacy"), Deprecated);
+    assert_eq!(status(&system, "removed"), Obsolete);
+    assert_eq!(status(&system, "from-group"), Deprecated);
+    assert_eq!(status(&system, "added"), Deprecated);
+
+    let old = child(&system, "old").unwrap();
+    assert_eq!(old.status, Deprecated);
+    assert_eq!(status(&old, "inner"), Deprecated);
+    assert_eq!(status(&old, "gone"), Obsolete);
+
+    assert_eq!(status(&system, "retired-leaf"), Obsolete);
+    assert_eq!(status(&system, "active-leaf"), Current);
+    let mode = system.find_choice("mode").unwrap();
+    assert_eq!(mode.find_case("retired").unwrap().status, Obsolete);
+    assert_eq!(mode.find_case("active").unwrap().status, Current);
+}
+
+#[test]
+fn obsolete_nodes_can_be_left_out() {
+    let (root, _) = load(true);
+    let system = child(&root, "system").unwrap();
+    assert_eq!(
+        names(&system),
+        [
+            "name",
+            "legacy",
+            "kind",
+            "from-group",
+            "old",
+            "active-leaf",
+            "added"
+        ]
+    );
+    let old = child(&system, "old").unwrap();
+    assert_eq!(names(&old), ["inner"]);
+
+    let mode = system.find_choice("mode").unwrap();
+    assert!(mode.find_case("retired").is_none());
+    assert_eq!(names(&mode), ["active"]);
+}
+
+#[test]
+fn current_references_to_deprecated_definitions_are_reported() {
+    let (_, diags) = load(false);
+    let reference = |node: &str, definition: &str, name: &str| Diagnostic::StatusReference {
+        module: "status".to_string(),
+        node: node.to_string(),
+        definition: definition.to_string(),
+        name: name.to_string(),
+        status: StatusNodeEnum::Deprecated,
+    };
+    assert_eq!(
+        diags,
+        vec![
+            reference("newer", "identity", "old-id"),
+            reference("wrap", "typedef", "old-type"),
+            reference("kind", "identity", "old-id"),
+            reference("system", "grouping", "old-group"),
+        ]
+    );
+    assert_eq!(
+        diags[1].to_string(),
+        "status: \"wrap\" is current but references deprecated typedef \"old-type\""
+    );
+}
@@ -0,0 +1,112 @@
+// A module and its included submodules form one namespace and one
+// schema tree.
+//
+// tests/yang/submodule-main.yang includes submodule-part, which in
+// turn includes submodule-deep. Data nodes, augments, typedefs,
+// groupings and identities are spread across the three and refer to
+// one another in every direction; all of it must reach the tree built
+// for the module.
+
+use libyang::{Entry, QualifiedName, YangStore, YangType, to_entry};
+use std::rc::Rc;
+
+fn load_store() -> YangStore {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("submodule-main")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    store
+}
+
+fn load() -> Rc<Entry> {
+    let store = load_store();
+    let module = store.find_module("submodule-main").expect("module found");
+    let entry = to_entry(&store, module);
+    let diags = store.take_diagnostics();
+    assert!(diags.is_empty(), "expected no diagnostics, got {diags:?}");
+    entry
+}
+
+fn find_child(ent: &Rc<Entry>, name: &str) -> Option<Rc<Entry>> {
+    ent.dir.borrow().iter().find(|e| e.name == name).cloned()
+}
+
+fn kind_of(ent: &Rc<Entry>) -> YangType {
+    ent.type_node.as_ref().expect("type_node").kind
+}
+
+#[test]
+fn submodule_data_nodes_reach_the_tree() {
+    let root = load();
+    let names: Vec<String> = root.dir.borrow().iter().map(|e| e.name.clone()).collect();
+    // The transitively included submodule counts too.
+    assert_eq!(names, ["main-top", "part-top", "deep-leaf"]);
+}
+
+#[test]
+fn submodule_augment_is_applied() {
+    let root = load();
+    let top = find_child(&root, "main-top").expect("main-top");
+    assert!(find_child(&top, "added-by-part").is_some());
+}
+
+#[test]
+fn typedefs_resolve_across_the_namespace() {
+    let root = load();
+    let top = find_child(&root, "main-top").expect("main-top");
+
+    // Module -> submodule typedef.
+    let size = find_child(&top, "size").expect("size");
+    assert_eq!(kind_of(&size), YangType::Uint16);
+
+    // Submodule grouping -> typedef in the transitively included one.
+    let from_part = find_child(&top, "from-part").expect("from-part");
+    assert_eq!(kind_of(&from_part), YangType::String);
+
+    // Submodule -> sibling submodule typedef.
+    let deep = find_child(&root, "deep-leaf").expect("deep-leaf");
+    assert_eq!(kind_of(&deep), YangType::Uint16);
+}
+
+#[test]
+fn submodule_uses_module_grouping() {
+    let root = load();
+    let part = find_child(&root, "part-top").expect("part-top");
+    assert!(find_child(&part, "shared-leaf").is_some());
+}
+
+#[test]
+fn identities_span_module_and_submodules() {
+    let root = load();
+    let part = find_child(&root, "part-top").expect("part-top");
+    let tint = find_child(&part, "tint").expect("tint");
+    let t = tint.type_node.as_ref().expect("type_node");
+    // Identities defined in submodules are in the module's namespace.
+    assert_eq!(
+        t.identities,
+        vec![
+            QualifiedName::new("submodule-main", "blue"),
+            QualifiedName::new("submodule-main", "red"),
+        ]
+    );
+
+    // The per-module map sees the submodules' identities as well.
+    let store = load_store();
+    let module = store.find_module("submodule-main").expect("module");
+    let mut derived = module.identities.get("colour").cloned().unwrap_or_default();
+    derived.sort();
+    assert_eq!(derived, ["blue", "red"]);
+}
+
+#[test]
+fn submodules_of_follows_nested_includes() {
+    let store = load_store();
+    let names: Vec<&str> = store
+        .submodules_of("submodule-main")
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(names, ["submodule-part", "submodule-deep"]);
+}
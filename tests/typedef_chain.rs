@@ -0,0 +1,174 @@
+// A type derived from a typedef keeps the typedef's restrictions and
+// narrows them with its own, all the way down a chain of typedefs to
+// the built-in type: ranges and lengths intersect, patterns accumulate,
+// and the most derived units and default win, a leaf's own default
+// above all. A derived range's `min` and `max` are its base's bounds.
+// Decimal64 ranges are narrowed too, and kept as text. The chain is
+// recorded on the resolved type.
+//
+// tests/yang/typedef-chain.yang derives ports from a uint16 typedef
+// and names from a string typedef, including restrictions that reach
+// outside their base.
+
+use libyang::{Diagnostic, Entry, RangeNode, TypeNode, YangStore, YangType, to_entry};
+use std::rc::Rc;
+
+fn load() -> (Rc<Entry>, Vec<Diagnostic>) {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("typedef-chain")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("typedef-chain").expect("module found");
+    let entry = to_entry(&store, module);
+    (entry, store.take_diagnostics())
+}
+
+fn leaf_type(name: &str) -> TypeNode {
+    let (root, _) = load();
+    let top = root
+        .dir
+        .borrow()
+        .iter()
+        .find(|e| e.name == "top")
+        .cloned()
+        .expect("top container");
+    let leaf = top
+        .dir
+        .borrow()
+        .iter()
+        .find(|e| e.name == name)
+        .cloned()
+        .unwrap_or_else(|| panic!("{name} present"));
+    leaf.type_node.clone().expect("type_node")
+}
+
+fn range(text: &str, kind: YangType) -> Option<RangeNode> {
+    RangeNode::parse(text, kind)
+}
+
+#[test]
+fn chain_resolves_to_builtin_with_merged_restrictions() {
+    let t = leaf_type("port");
+    assert_eq!(t.kind, YangType::Uint16);
+    assert_eq!(t.range, range("2000..3000", YangType::Uint16));
+    assert_eq!(t.typedef_chain, vec!["high-port", "base-port"]);
+    // units from the base, default overridden by the derived typedef.
+    assert_eq!(t.units.as_deref(), Some("port"));
+    assert_eq!(t.default.as_deref(), Some("8080"));
+}
+
+#[test]
+fn typedef_restrictions_survive_a_plain_reference() {
+    let t = leaf_type("plain-port");
+    assert_eq!(t.range, range("1..65535", YangType::Uint16));
+    assert_eq!(t.default.as_deref(), Some("80"));
+    assert_eq!(t.typedef_chain, vec!["base-port"]);
+}
+
+#[test]
+fn unquoted_typedef_default_is_kept() {
+    assert_eq!(leaf_type("admin-port").default.as_deref(), Some("22"));
+}
+
+#[test]
+fn leaf_default_overrides_the_type() {
+    let t = leaf_type("own-port");
+    assert_eq!(t.default.as_deref(), Some("9090"));
+    assert_eq!(t.typedef_chain, vec!["high-port", "base-port"]);
+}
+
+#[test]
+fn decimal64_ranges_narrow_as_text() {
+    let t = leaf_type("ratio");
+    assert_eq!(t.kind, YangType::Decimal64);
+    assert_eq!(t.range_arg.as_deref(), Some("0..1"));
+    assert_eq!(t.range, None);
+    assert_eq!(leaf_type("half-ratio").range_arg.as_deref(), Some("0..0.5"));
+    assert_eq!(
+        leaf_type("upper-ratio").range_arg.as_deref(),
+        Some("0.25..1")
+    );
+    assert_eq!(leaf_type("bad-ratio").range_arg.as_deref(), Some("0..1"));
+}
+
+#[test]
+fn min_and_max_are_the_base_bounds() {
+    let (_, diags) = load();
+    assert!(
+        !diags.iter().any(|d| matches!(
+            d,
+            Diagnostic::RestrictionNotSubset { node, .. } if node == "lower" || node == "upper"
+        )),
+        "{diags:?}"
+    );
+    assert_eq!(
+        leaf_type("lower").range,
+        range("1024..2000", YangType::Uint16)
+    );
+    assert_eq!(
+        leaf_type("upper").range,
+        range("2000..4096", YangType::Uint16)
+    );
+}
+
+#[test]
+fn lengths_intersect_and_patterns_accumulate() {
+    let t = leaf_type("name");
+    assert_eq!(t.kind, YangType::String);
+    assert_eq!(t.length, range("1..8", YangType::Uint64));
+    assert_eq!(t.patterns, vec!["[a-z]+", "[a-m]+"]);
+    assert_eq!(t.pattern.as_deref(), Some("[a-m]+"));
+    assert_eq!(t.units.as_deref(), Some("chars"));
+}
+
+#[test]
+fn widening_is_reported_and_cut_back() {
+    let (_, diags) = load();
+    let mut found: Vec<(String, String, String)> = diags
+        .iter()
+        .filter_map(|d| match d {
+            Diagnostic::RestrictionNotSubset {
+                node,
+                restriction,
+                value,
+                ..
+            } => Some((node.clone(), restriction.clone(), value.clone())),
+            _ => None,
+        })
+        .collect();
+    found.sort();
+    assert_eq!(
+        found,
+        vec![
+            ("bad-port".into(), "range".into(), "<0..10>".into()),
+            ("bad-ratio".into(), "range".into(), "<0..2>".into()),
+            ("long-name".into(), "length".into(), "<1..100>".into()),
+            ("out-of-type".into(), "range".into(), "1..70000".into()),
+        ]
+    );
+
+    assert_eq!(
+        leaf_type("bad").range,
+        range("1..10", YangType::Uint16),
+        "cut back to the base range"
+    );
+    assert_eq!(
+        leaf_type("long-name").length,
+        range("1..64", YangType::Uint64)
+    );
+}
+
+#[test]
+fn range_parse_subset_and_intersect() {
+    let base = range("1..10|20..max", YangType::Uint8).expect("parses");
+    assert!(range("2..5|30", YangType::Uint8).unwrap().is_subset(&base));
+    assert!(!range("5..25", YangType::Uint8).unwrap().is_subset(&base));
+    assert_eq!(
+        range("5..25", YangType::Uint8).unwrap().intersect(&base),
+        range("5..10|20..25", YangType::Uint8).unwrap()
+    );
+    assert_eq!(range("300", YangType::Uint8), None);
+    assert_eq!(range("1..2", YangType::String), None);
+}
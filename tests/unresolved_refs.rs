@@ -0,0 +1,125 @@
+// A reference that names nothing — a typedef, a grouping, an
+// identityref base or an identity's own base — is reported as a
+// diagnostic naming the module, the referring node and the missing
+// name, rather than leaving a silent hole in the tree.
+//
+// A feature that names nothing does not hold: what it guards is left
+// out, and the `if-feature` naming it is reported.
+//
+// tests/yang/unresolved-refs.yang has one of each, plus a typedef whose
+// own type is missing, used by a leaf, and a feature whose own
+// `if-feature` names a missing feature.
+
+use libyang::{Diagnostic, Entry, YangStore, to_entry};
+use std::rc::Rc;
+
+fn load() -> (Rc<Entry>, Vec<Diagnostic>) {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store
+        .read_with_resolve("unresolved-refs")
+        .expect("parse / resolve");
+    store.identity_resolve();
+    let module = store.find_module("unresolved-refs").expect("module found");
+    let entry = to_entry(&store, module);
+    (entry, store.take_diagnostics())
+}
+
+fn find_child(ent: &Rc<Entry>, name: &str) -> Option<Rc<Entry>> {
+    ent.dir.borrow().iter().find(|e| e.name == name).cloned()
+}
+
+fn unresolved(node: &str, name: &str) -> (String, String) {
+    (node.to_string(), name.to_string())
+}
+
+#[test]
+fn each_unresolved_reference_is_reported() {
+    let (_, diags) = load();
+    let mut typedefs = Vec::new();
+    let mut groupings = Vec::new();
+    let mut identities = Vec::new();
+    let mut bases = Vec::new();
+    let mut features = Vec::new();
+    for d in diags.iter() {
+        match d {
+            Diagnostic::UnresolvedTypedef { module, node, name } => {
+                assert_eq!(module, "unresolved-refs");
+                typedefs.push(unresolved(node, name));
+            }
+            Diagnostic::UnresolvedGrouping { module, node, name } => {
+                assert_eq!(module, "unresolved-refs");
+                groupings.push(unresolved(node, name));
+            }
+            Diagnostic::UnresolvedIdentity { module, node, name } => {
+                assert_eq!(module, "unresolved-refs");
+                identities.push(unresolved(node, name));
+            }
+            Diagnostic::UnresolvedBase { module, node, name } => {
+                assert_eq!(module, "unresolved-refs");
+                bases.push(unresolved(node, name));
+            }
+            Diagnostic::UndefinedFeature { module, name } => {
+                assert_eq!(module, "unresolved-refs");
+                features.push(name.clone());
+            }
+            other => panic!("unexpected diagnostic {other:?}"),
+        }
+    }
+    typedefs.sort();
+    assert_eq!(
+        typedefs,
+        vec![
+            unresolved("broken", "missing-inner"),
+            unresolved("plain", "missing-type"),
+        ]
+    );
+    assert_eq!(groupings, vec![unresolved("top", "missing-grouping")]);
+    assert_eq!(identities, vec![unresolved("family", "missing-identity")]);
+    assert_eq!(bases, vec![unresolved("orphan", "missing-base")]);
+    features.sort();
+    assert_eq!(features, ["missing-feature", "missing-inner-feature"]);
+}
+
+#[test]
+fn undefined_features_do_not_hold() {
+    let (root, _) = load();
+    let top = find_child(&root, "top").expect("top container");
+    assert!(find_child(&top, "optional").is_none());
+    assert!(find_child(&top, "nested").is_none());
+}
+
+#[test]
+fn unresolved_leaves_stay_in_the_tree_without_a_type() {
+    let (root, _) = load();
+    let top = find_child(&root, "top").expect("top container");
+    let plain = find_child(&top, "plain").expect("plain leaf");
+    assert!(plain.type_node.is_none());
+    let good = find_child(&top, "good").expect("good leaf");
+    assert!(good.type_node.is_some());
+}
+
+#[test]
+fn display_names_module_node_and_missing_name() {
+    let (_, diags) = load();
+    let rendered: Vec<String> = diags.iter().map(|d| d.to_string()).collect();
+    assert!(
+        rendered.contains(
+            &"unresolved-refs: grouping \"missing-grouping\" used in \"top\" not found".to_string()
+        ),
+        "{rendered:?}"
+    );
+    assert!(
+        rendered.contains(
+            &"unresolved-refs: base identity \"missing-base\" of identity \"orphan\" not found"
+                .to_string()
+        ),
+        "{rendered:?}"
+    );
+    assert!(
+        rendered.contains(
+            &"unresolved-refs: if-feature names undefined feature \"missing-feature\"".to_string()
+        ),
+        "{rendered:?}"
+    );
+}
@@ -0,0 +1,344 @@
+// A module set described by an ietf-yang-library instance (RFC 8525)
+// is loaded as listed: each module at its revision, with only the
+// listed features enabled and the deviations of the listed deviation
+// modules applied.
+//
+// tests/yang/lib-library.json implements lib-base with feature `fast`
+// of `fast` and `secure`, deviated by lib-dev, and lists the older of
+// two revisions of rev-types (import-only) and of lib-base-part, which
+// lib-base imports and includes unpinned. lib-dev deviates data nodes,
+// an RPC input and a notification.
+//
+// The other way round, the library of a loaded store is generated as
+// `yang-library` and legacy `modules-state` data, with the NETCONF
+// capabilities that advertise it; tests/yang/lib-legacy.yang is a
+// YANG 1.0 module, advertised with a capability of its own.
+
+use libyang::{
+    Diagnostic, Entry, EntryOptions, EntryTree, SchemaContext, YangError, YangLibrary, YangStore,
+    YangType, to_entry, to_entry_with,
+};
+use std::rc::Rc;
+
+fn library() -> YangLibrary {
+    let text = std::fs::read_to_string("tests/yang/lib-library.json").expect("fixture");
+    YangLibrary::from_json(&text).expect("library")
+}
+
+fn store() -> YangStore {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store.load_yang_library(&library()).expect("module set");
+    store.identity_resolve();
+    store
+}
+
+fn child(parent: &Rc<Entry>, name: &str) -> Option<Rc<Entry>> {
+    parent.dir.borrow().iter().find(|e| e.name == name).cloned()
+}
+
+fn system(store: &YangStore) -> (Rc<Entry>, Rc<Entry>) {
+    let options = EntryOptions::new().tree(EntryTree::Full);
+    let root = to_entry_with(store, store.find_module("lib-base").unwrap(), &options);
+    let system = child(&root, "system").expect("system");
+    (root, system)
+}
+
+#[test]
+fn instance_is_read() {
+    let library = library();
+    assert_eq!(library.content_id, "1");
+    let set = library.find_module_set("default").expect("module set");
+    assert_eq!(set.module[0].feature, ["fast"]);
+    assert_eq!(set.module[0].deviation, ["lib-dev"]);
+    assert_eq!(set.module[1].revision, None);
+    assert_eq!(set.import_only_module[0].name, "rev-types");
+    assert_eq!(library.implemented(), ["lib-base", "lib-dev"]);
+}
+
+#[test]
+fn listed_revisions_are_loaded() {
+    let store = store();
+    let types = store.find_module("rev-types").expect("rev-types");
+    assert_eq!(types.latest_revision(), Some("2010-01-01"));
+    let part = store.find_submodule("lib-base-part").expect("submodule");
+    assert_eq!(part.latest_revision(), Some("2021-01-01"));
+
+    let (root, system) = system(&store);
+    assert!(child(&root, "retries").is_some());
+    assert!(child(&root, "attempts").is_none());
+    let timeout = child(&system, "timeout").expect("timeout");
+    let units = timeout.type_node.as_ref().and_then(|t| t.units.as_deref());
+    assert_eq!(units, Some("seconds"));
+}
+
+#[test]
+fn only_listed_features_are_enabled() {
+    let store = store();
+    assert!(store.feature_enabled("lib-base", "fast"));
+    assert!(!store.feature_enabled("lib-base", "secure"));
+
+    let (_, system) = system(&store);
+    assert!(child(&system, "turbo").is_some());
+    assert!(child(&system, "tls").is_none());
+    assert!(child(&system, "plain").is_some());
+
+    let context = SchemaContext::new(&store, &["lib-base"]).expect("context");
+    assert!(context.find_rpc("lib-base:reboot").is_some());
+    assert!(context.find_rpc("lib-base:wipe").is_none());
+}
+
+#[test]
+fn feature_needs_its_own_if_feature() {
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store.read_with_resolve("lib-base").expect("lib-base");
+    store.enable_features("lib-base", ["turbo"]);
+    assert!(!store.feature_enabled("lib-base", "turbo"));
+    store.enable_features("lib-base", ["fast", "turbo"]);
+    assert!(store.feature_enabled("lib-base", "turbo"));
+}
+
+#[test]
+fn deviations_are_applied() {
+    let store = store();
+    let (_, system) = system(&store);
+
+    assert!(child(&system, "legacy").is_none());
+
+    let mtu = child(&system, "mtu").expect("mtu");
+    let t = mtu.type_node.as_ref().expect("type");
+    assert_eq!(t.kind, YangType::Uint32);
+    assert_eq!(t.units.as_deref(), Some("octets"));
+    assert_eq!(mtu.provenance().deviation, ["lib-dev"]);
+    assert!(Rc::ptr_eq(&mtu.parent().unwrap(), &system));
+
+    let hostname = child(&system, "hostname").expect("hostname");
+    let default = hostname
+        .type_node
+        .as_ref()
+        .and_then(|t| t.default.as_deref());
+    assert_eq!(default, Some("router"));
+
+    // The full tree keeps the state data the deviation made.
+    let stats = child(&system, "stats").expect("stats");
+    assert!(!stats.config);
+    let count = child(&stats, "count").expect("count");
+    assert!(!count.config);
+    assert!(Rc::ptr_eq(&count.parent().unwrap(), &stats));
+
+    let servers = child(&system, "servers").expect("servers");
+    assert_eq!(servers.list_attr.as_ref().unwrap().max_elements, 3);
+
+    assert_eq!(
+        store.take_diagnostics(),
+        [Diagnostic::DeviationTargetNotFound {
+            module: "lib-dev".to_string(),
+            target: "/lb:system/lb:missing".to_string(),
+            missing: "lb:missing".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn deviations_follow_the_implemented_modules() {
+    let store = store();
+    let library = library();
+    let context = SchemaContext::new(&store, &library.implemented()).expect("context");
+    assert_eq!(context.import_only(), ["rev-types"]);
+
+    // Without lib-dev implemented, lib-base is not deviated.
+    let context = SchemaContext::new(&store, &["lib-base"]).expect("context");
+    let system = context.find("lib-base:system").expect("system");
+    assert!(child(&system, "legacy").is_some());
+}
+
+#[test]
+fn loaded_deviations_apply_to_entry_trees() {
+    // Without a library, to_entry applies the deviations of every
+    // loaded module, as it does their augments.
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    store.read_with_resolve("lib-base").expect("lib-base");
+    let root = to_entry(&store, store.find_module("lib-base").unwrap());
+    assert!(child(&child(&root, "system").unwrap(), "legacy").is_some());
+
+    store.read_with_resolve("lib-dev").expect("lib-dev");
+    let root = to_entry(&store, store.find_module("lib-base").unwrap());
+    assert!(child(&child(&root, "system").unwrap(), "legacy").is_none());
+}
+
+#[test]
+fn operations_are_deviated() {
+    let store = store();
+    let context = SchemaContext::new(&store, &["lib-base", "lib-dev"]).expect("context");
+    let reboot = context.find_rpc("lib-base:reboot").expect("reboot");
+    let input = child(&reboot, "input").expect("input");
+    assert!(child(&input, "delay").is_none());
+    assert!(child(&input, "force").is_some());
+
+    let restarted = context
+        .find_notification("lib-base:restarted")
+        .expect("restarted");
+    let uptime = child(&restarted, "uptime").expect("uptime");
+    assert_eq!(uptime.type_node.as_ref().unwrap().kind, YangType::Uint64);
+    assert!(Rc::ptr_eq(&uptime.parent().unwrap(), &restarted));
+
+    // Only the missing node is reported.
+    let diags = store.take_diagnostics();
+    assert!(
+        diags.iter().all(|d| matches!(
+            d,
+            Diagnostic::DeviationTargetNotFound { missing, .. } if missing == "lb:missing"
+        )),
+        "{diags:?}"
+    );
+}
+
+#[test]
+fn unlisted_module_is_an_error() {
+    let text = r#"{
+      "ietf-yang-library:yang-library": {
+        "module-set": [
+          { "name": "partial", "module": [ { "name": "rev-main" } ] }
+        ],
+        "content-id": "2"
+      }
+    }"#;
+    let mut store = YangStore::new();
+    store.add_path("tests/yang");
+    let library = YangLibrary::from_json(text).expect("library");
+    match store.load_yang_library(&library) {
+        Err(YangError::InvalidLibrary { reason }) => {
+            assert!(reason.contains("`rev-main-part`"), "{reason}")
+        }
+        other => panic!("expected InvalidLibrary, got {other:?}"),
+    }
+}
+
+#[test]
+fn legacy_modules_state_is_read() {
+    let text = r#"{
+      "ietf-yang-library:modules-state": {
+        "module-set-id": "7",
+        "module": [
+          {
+            "name": "lib-base",
+            "revision": "2021-01-01",
+            "namespace": "urn:example:lib-base",
+            "feature": ["fast"],
+            "deviation": [ { "name": "lib-dev", "revision": "" } ],
+            "conformance-type": "implement",
+            "submodule": [ { "name": "lib-base-part", "revision": "2021-01-01" } ]
+          },
+          {
+            "name": "lib-dev",
+            "revision": "",
+            "namespace": "urn:example:lib-dev",
+            "conformance-type": "implement"
+          },
+          {
+            "name": "rev-types",
+            "revision": "2010-01-01",
+            "namespace": "urn:example:rev-types",
+            "conformance-type": "import"
+          }
+        ]
+      }
+    }"#;
+    let legacy = YangLibrary::from_json(text).expect("library");
+    assert_eq!(legacy.content_id, "7");
+    let set = &legacy.module_set[0];
+    assert_eq!(set.name, "modules-state");
+    assert_eq!(set.module, library().module_set[0].module);
+    assert_eq!(
+        set.import_only_module,
+        library().module_set[0].import_only_module
+    );
+}
+
+#[test]
+fn malformed_instance_is_an_error() {
+    for text in [
+        "not json",
+        "{}",
+        r#"{ "ietf-yang-library:yang-library": { "module-set": [] } }"#,
+        r#"{ "ietf-yang-library:yang-library": { "content-id": 1 } }"#,
+    ] {
+        assert!(
+            matches!(
+                YangLibrary::from_json(text),
+                Err(YangError::InvalidLibrary { .. })
+            ),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn library_is_generated_from_the_store() {
+    let store = store();
+    let loaded = library();
+    let context = SchemaContext::new(&store, &loaded.implemented()).expect("context");
+    let library = YangLibrary::from_context(&store, &context);
+
+    // The same modules, revisions, namespaces, submodules, features
+    // and deviations as the library the store was loaded from.
+    assert_eq!(library.module_set.len(), 1);
+    assert_eq!(library.module_set[0].module, loaded.module_set[0].module);
+    assert_eq!(
+        library.module_set[0].import_only_module,
+        loaded.module_set[0].import_only_module
+    );
+    assert_eq!(library.schema[0].module_set, ["default"]);
+    assert_eq!(library.datastore[1].name, "ietf-datastores:operational");
+
+    // What is written reads back as it was.
+    let text = library.to_json();
+    assert!(text.contains("\"ietf-yang-library:yang-library\""));
+    assert_eq!(YangLibrary::from_json(&text).expect("round trip"), library);
+
+    let legacy = YangLibrary::from_json(&library.to_modules_state_json()).expect("legacy");
+    assert_eq!(legacy.content_id, library.content_id);
+    assert_eq!(legacy.module_set[0].module, library.module_set[0].module);
+}
+
+#[test]
+fn content_id_follows_the_content() {
+    let mut store = store();
+    let context = SchemaContext::new(&store, &["lib-base", "lib-dev"]).expect("context");
+    let library = YangLibrary::from_context(&store, &context);
+    assert_eq!(library.content_id.len(), 16);
+    assert_eq!(
+        YangLibrary::from_context(&store, &context).content_id,
+        library.content_id
+    );
+
+    store.enable_features("lib-base", ["fast", "secure"]);
+    let changed = YangLibrary::from_context(&store, &context);
+    assert_eq!(changed.module_set[0].module[0].feature, ["fast", "secure"]);
+    assert_ne!(changed.content_id, library.content_id);
+}
+
+#[test]
+fn hello_capabilities_are_generated() {
+    let mut store = store();
+    store.read_with_resolve("lib-legacy").expect("lib-legacy");
+    let implemented = ["lib-base", "lib-dev", "lib-legacy"];
+    let context = SchemaContext::new(&store, &implemented).expect("context");
+    let library = YangLibrary::from_context(&store, &context);
+    // lib-base and lib-dev are YANG 1.1, advertised through the library
+    // alone; lib-legacy is YANG 1.0 and advertised on its own as well.
+    assert_eq!(
+        library.capabilities(&store),
+        [
+            format!(
+                "urn:ietf:params:netconf:capability:yang-library:1.1\
+                 ?revision=2019-01-04&content-id={}",
+                library.content_id
+            ),
+            "urn:example:lib-legacy?module=lib-legacy&revision=2015-01-01&features=extra"
+                .to_string(),
+        ]
+    );
+}